@@ -1,15 +1,17 @@
 use leptos::prelude::*;
-use leptos_auth::components::ProtectedRoute;
+use leptos_auth::components::{ProtectedRoute, RoleGuardedRoute};
 use leptos_auth::context::AuthProvider;
+use leptos_auth::AuthRole;
 use leptos_router::components::{ParentRoute, Route, Router, Routes};
 use leptos_router::path;
 
 use crate::pages::{
     cache::CachePage, dashboard::Dashboard, email_settings::EmailSettingsPage, events::EventsPage,
-    installer::InstallerPage, login::Login, module_admin::ModuleAdminPage, modules::Modules,
-    not_found::NotFound, oauth_apps::OAuthAppsPage, profile::Profile, register::Register,
-    reset::ResetPassword, roles::RolesPage, security::Security, user_details::UserDetails,
-    users::Users, workflow_detail::WorkflowDetailPage, workflows::Workflows,
+    forbidden::Forbidden, installer::InstallerPage, login::Login, module_admin::ModuleAdminPage,
+    modules::Modules, not_found::NotFound, oauth_apps::OAuthAppsPage, profile::Profile,
+    register::Register, reset::ResetPassword, roles::RolesPage, security::Security,
+    user_details::UserDetails, users::Users, workflow_detail::WorkflowDetailPage,
+    workflows::Workflows,
 };
 use crate::widgets::app_shell::AppLayout;
 use crate::I18nContextProvider;
@@ -25,29 +27,32 @@ pub fn App() -> impl IntoView {
                         <Route path=path!("/register") view=Register />
                         <Route path=path!("/reset") view=ResetPassword />
                         <Route path=path!("/install") view=InstallerPage />
+                        <Route path=path!("/forbidden") view=Forbidden />
 
                         <ParentRoute path=path!("") view=ProtectedRoute>
                             <ParentRoute path=path!("") view=AppLayout>
                                 <Route path=path!("/dashboard") view=Dashboard />
                                 <Route path=path!("/profile") view=Profile />
                                 <Route path=path!("/security") view=Security />
-                                <Route path=path!("/modules/:module_slug") view=ModuleAdminPage />
-                                <Route
-                                    path=path!("/modules/:module_slug/*module_path")
-                                    view=ModuleAdminPage
-                                />
-                                <Route path=path!("/modules") view=Modules />
-                                <Route path=path!("/users") view=Users />
-                                <Route path=path!("/users/:id") view=UserDetails />
-                                <Route path=path!("/apps") view=OAuthAppsPage />
                                 <Route path=path!("/ai") view=rustok_ai_admin::AiAdmin />
                                 <Route path=path!("/ai/diagnostics") view=rustok_ai_admin::AiAdmin />
-                                <Route path=path!("/workflows") view=Workflows />
-                                <Route path=path!("/workflows/:id") view=WorkflowDetailPage />
-                                <Route path=path!("/roles") view=RolesPage />
-                                <Route path=path!("/email") view=EmailSettingsPage />
-                                <Route path=path!("/cache") view=CachePage />
-                                <Route path=path!("/events") view=EventsPage />
+                                <ParentRoute path=path!("") view=AdminOnlyRoute>
+                                    <Route path=path!("/modules/:module_slug") view=ModuleAdminPage />
+                                    <Route
+                                        path=path!("/modules/:module_slug/*module_path")
+                                        view=ModuleAdminPage
+                                    />
+                                    <Route path=path!("/modules") view=Modules />
+                                    <Route path=path!("/users") view=Users />
+                                    <Route path=path!("/users/:id") view=UserDetails />
+                                    <Route path=path!("/apps") view=OAuthAppsPage />
+                                    <Route path=path!("/workflows") view=Workflows />
+                                    <Route path=path!("/workflows/:id") view=WorkflowDetailPage />
+                                    <Route path=path!("/roles") view=RolesPage />
+                                    <Route path=path!("/email") view=EmailSettingsPage />
+                                    <Route path=path!("/cache") view=CachePage />
+                                    <Route path=path!("/events") view=EventsPage />
+                                </ParentRoute>
                                 <Route path=path!("") view=Dashboard />
                             </ParentRoute>
                         </ParentRoute>
@@ -59,3 +64,10 @@ pub fn App() -> impl IntoView {
         </I18nContextProvider>
     }
 }
+
+/// Guards platform administration pages; customers and managers land on
+/// `/forbidden` instead.
+#[component]
+fn AdminOnlyRoute() -> impl IntoView {
+    view! { <RoleGuardedRoute required_role=AuthRole::Admin /> }
+}
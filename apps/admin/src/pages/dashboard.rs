@@ -2,6 +2,7 @@
 use chrono::{Duration, Utc};
 use leptos::prelude::*;
 use leptos_auth::hooks::{use_current_user, use_tenant, use_token};
+use leptos_use::use_interval_fn;
 #[cfg(feature = "ssr")]
 use sea_orm::{ConnectionTrait, DbBackend, Statement};
 use serde::{Deserialize, Serialize};
@@ -9,15 +10,20 @@ use serde_json::json;
 
 use crate::app::modules::{components_for_slot, AdminSlot};
 use crate::app::providers::enabled_modules::use_enabled_modules;
-use crate::shared::api::queries::{DASHBOARD_STATS_QUERY, RECENT_ACTIVITY_QUERY};
-use crate::shared::api::request;
+use crate::shared::api::queries::{
+    DASHBOARD_STATS_QUERY, RECENT_ACTIVITY_QUERY, RECENT_ACTIVITY_QUERY_HASH,
+};
 use crate::shared::api::ApiError;
+use crate::shared::api::{request, request_with_persisted};
 use crate::shared::ui::{
     Badge, BadgeVariant, Card, CardContent, CardDescription, CardHeader, CardTitle, PageHeader,
 };
 use crate::widgets::stats_card::StatsCard;
 use crate::{t_string, use_i18n};
 
+/// How often the activity feed re-reads the event log.
+const ACTIVITY_POLL_INTERVAL_MS: u64 = 30_000;
+
 fn local_resource<S, Fut, T>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fut + 'static,
@@ -68,6 +74,8 @@ struct ActivityItem {
     #[serde(rename = "type")]
     r#type: String,
     description: String,
+    #[serde(default)]
+    target: Option<String>,
     timestamp: String,
     user: Option<ActivityUser>,
 }
@@ -118,9 +126,10 @@ async fn fetch_recent_activity_graphql(
     tenant_slug: Option<String>,
     limit: i64,
 ) -> Result<RecentActivityResponse, ApiError> {
-    request::<_, RecentActivityResponse>(
+    request_with_persisted::<_, RecentActivityResponse>(
         RECENT_ACTIVITY_QUERY,
         json!({ "limit": limit }),
+        RECENT_ACTIVITY_QUERY_HASH,
         token,
         tenant_slug,
     )
@@ -289,9 +298,15 @@ pub fn Dashboard() -> impl IntoView {
         },
     );
 
+    let (activity_tick, set_activity_tick) = signal(0_u64);
+    let _activity_poll = use_interval_fn(
+        move || set_activity_tick.update(|tick| *tick += 1),
+        ACTIVITY_POLL_INTERVAL_MS,
+    );
+
     let recent_activity = local_resource(
-        move || (token.get(), tenant.get()),
-        move |(token_value, tenant_value)| async move {
+        move || (token.get(), tenant.get(), activity_tick.get()),
+        move |(token_value, tenant_value, _)| async move {
             fetch_recent_activity(token_value, tenant_value, 10).await
         },
     );
@@ -435,6 +450,7 @@ pub fn Dashboard() -> impl IntoView {
                                                 .as_ref()
                                                 .and_then(|u| u.name.clone())
                                                 .unwrap_or_else(|| t_string!(i18n, app.dashboard.activity.system).to_string());
+                                            let target = item.target.clone();
                                             view! {
                                                 <div class="flex items-start justify-between gap-4 py-3 first:pt-0 last:pb-0">
                                                     <div class="min-w-0">
@@ -444,6 +460,9 @@ pub fn Dashboard() -> impl IntoView {
                                                         </div>
                                                         <p class="mt-1 text-sm text-muted-foreground">
                                                             {format!("by {}", user_name)}
+                                                            {target.map(|target| view! {
+                                                                <span class="ml-2 font-mono text-xs">{target}</span>
+                                                            })}
                                                         </p>
                                                     </div>
                                                     <span class="shrink-0 text-xs text-muted-foreground">
@@ -471,8 +490,10 @@ pub fn Dashboard() -> impl IntoView {
     }
 }
 
+/// Relative time for recent entries, falling back to a date in the viewer's
+/// local time zone once an entry is older than a month.
 fn format_time_ago(timestamp: &str) -> String {
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, Local, Utc};
 
     let i18n = use_i18n();
 
@@ -496,7 +517,7 @@ fn format_time_ago(timestamp: &str) -> String {
     } else if days < 30 {
         format!("{}{}", days, t_string!(i18n, app.time.daysAgo))
     } else {
-        dt.format("%d.%m.%Y").to_string()
+        dt.with_timezone(&Local).format("%d.%m.%Y").to_string()
     }
 }
 
@@ -677,51 +698,74 @@ async fn load_recent_activity(
     tenant_id: uuid::Uuid,
     limit: i64,
 ) -> std::result::Result<Vec<ActivityItem>, sea_orm::DbErr> {
+    let entries = rustok_outbox::load_audit_entries(db, tenant_id, limit).await?;
+    let mut items = Vec::with_capacity(entries.len());
+    let mut actor_ids = Vec::new();
+    for entry in entries {
+        if let Some(actor_id) = entry.actor_id {
+            if !actor_ids.contains(&actor_id) {
+                actor_ids.push(actor_id);
+            }
+        }
+
+        items.push(ActivityItem {
+            id: entry.id.to_string(),
+            description: rustok_outbox::describe_event_type(&entry.event_type),
+            target: entry.target,
+            r#type: entry.event_type,
+            timestamp: entry.created_at.to_rfc3339(),
+            user: entry.actor_id.map(|actor_id| ActivityUser {
+                id: actor_id.to_string(),
+                name: None,
+            }),
+        });
+    }
+
+    let actors = load_actor_names(db, tenant_id, &actor_ids).await?;
+    for item in &mut items {
+        if let Some(user) = item.user.as_mut() {
+            user.name = actors.get(&user.id).cloned();
+        }
+    }
+
+    Ok(items)
+}
+
+#[cfg(feature = "ssr")]
+async fn load_actor_names(
+    db: &sea_orm::DatabaseConnection,
+    tenant_id: uuid::Uuid,
+    actor_ids: &[uuid::Uuid],
+) -> std::result::Result<std::collections::HashMap<String, String>, sea_orm::DbErr> {
+    if actor_ids.is_empty() {
+        return Ok(std::collections::HashMap::new());
+    }
+
     let backend = db.get_database_backend();
-    let statement = match backend {
-        DbBackend::Sqlite => Statement::from_sql_and_values(
-            backend,
-            r#"
-            SELECT id, email, name, created_at
-            FROM users
-            WHERE tenant_id = ?1
-            ORDER BY created_at DESC
-            LIMIT ?2
-            "#,
-            vec![tenant_id.into(), limit.into()],
-        ),
-        _ => Statement::from_sql_and_values(
-            backend,
-            r#"
-            SELECT id, email, name, created_at
-            FROM users
-            WHERE tenant_id = $1
-            ORDER BY created_at DESC
-            LIMIT $2
-            "#,
-            vec![tenant_id.into(), limit.into()],
-        ),
+    let placeholder = |index: usize| match backend {
+        DbBackend::Sqlite => format!("?{index}"),
+        _ => format!("${index}"),
     };
+    let id_placeholders = (2..actor_ids.len() + 2)
+        .map(placeholder)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!(
+        "SELECT id, email, name FROM users WHERE tenant_id = {} AND id IN ({id_placeholders})",
+        placeholder(1)
+    );
+    let mut values: Vec<sea_orm::Value> = vec![tenant_id.into()];
+    values.extend(actor_ids.iter().map(|id| (*id).into()));
 
-    let rows = db.query_all(statement).await?;
+    let rows = db
+        .query_all(Statement::from_sql_and_values(backend, sql, values))
+        .await?;
     rows.into_iter()
         .map(|row| {
             let id: uuid::Uuid = row.try_get("", "id")?;
             let email: String = row.try_get("", "email")?;
             let name: Option<String> = row.try_get("", "name")?;
-            let created_at: chrono::DateTime<chrono::FixedOffset> =
-                row.try_get("", "created_at")?;
-
-            Ok(ActivityItem {
-                id: id.to_string(),
-                r#type: "user.created".to_string(),
-                description: format!("New user {email} joined"),
-                timestamp: created_at.to_rfc3339(),
-                user: Some(ActivityUser {
-                    id: id.to_string(),
-                    name,
-                }),
-            })
+            Ok((id.to_string(), name.unwrap_or(email)))
         })
         .collect()
 }
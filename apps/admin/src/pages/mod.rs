@@ -2,6 +2,7 @@ pub mod cache;
 pub mod dashboard;
 pub mod email_settings;
 pub mod events;
+pub mod forbidden;
 pub mod installer;
 pub mod login;
 pub mod module_admin;
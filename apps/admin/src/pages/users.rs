@@ -81,6 +81,8 @@ struct GraphqlUser {
 struct GraphqlPageInfo {
     #[serde(rename = "totalCount")]
     total_count: i64,
+    #[serde(rename = "hasNextPage", default)]
+    has_next_page: bool,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -284,29 +286,7 @@ COALESCE((
 
         let where_sql = conditions.join(" AND ");
 
-        let count_statement = Statement::from_sql_and_values(
-            backend,
-            format!(
-                r#"
-                SELECT CAST(COUNT(*) AS INTEGER) AS total_count
-                FROM users u
-                WHERE {where_sql}
-                "#
-            ),
-            values.clone(),
-        );
-
-        let total_count = app_ctx
-            .db
-            .query_one(count_statement)
-            .await
-            .map_err(|err| server_error(err.to_string()))?
-            .map(|row| row.try_get("", "total_count"))
-            .transpose()
-            .map_err(|err| server_error(err.to_string()))?
-            .unwrap_or(0i64);
-
-        let mut page_values = values;
+        let mut page_values = values.clone();
         let limit_placeholder = placeholder(next_index);
         page_values.push(limit.into());
         next_index += 1;
@@ -323,7 +303,8 @@ COALESCE((
                     u.name,
                     {role_sql} AS role,
                     u.status,
-                    u.created_at
+                    u.created_at,
+                    CAST(COUNT(*) OVER() AS INTEGER) AS total_count
                 FROM users u
                 WHERE {where_sql}
                 ORDER BY u.created_at DESC
@@ -334,11 +315,44 @@ COALESCE((
             page_values,
         );
 
-        let edges = app_ctx
+        let rows = app_ctx
             .db
             .query_all(page_statement)
             .await
-            .map_err(|err| server_error(err.to_string()))?
+            .map_err(|err| server_error(err.to_string()))?;
+
+        // The windowed count rides along with the page; only an out-of-range
+        // page (no rows) needs a separate COUNT.
+        let total_count = match rows.first() {
+            Some(row) => row
+                .try_get::<i64>("", "total_count")
+                .map_err(|err| server_error(err.to_string()))?,
+            None if offset == 0 => 0,
+            None => {
+                let count_statement = Statement::from_sql_and_values(
+                    backend,
+                    format!(
+                        r#"
+                        SELECT CAST(COUNT(*) AS INTEGER) AS total_count
+                        FROM users u
+                        WHERE {where_sql}
+                        "#
+                    ),
+                    values,
+                );
+                app_ctx
+                    .db
+                    .query_one(count_statement)
+                    .await
+                    .map_err(|err| server_error(err.to_string()))?
+                    .map(|row| row.try_get("", "total_count"))
+                    .transpose()
+                    .map_err(|err| server_error(err.to_string()))?
+                    .unwrap_or(0i64)
+            }
+        };
+
+        let edges = rows
             .into_iter()
             .enumerate()
             .map(|(index, row)| {
@@ -370,11 +384,15 @@ COALESCE((
                 })
             })
             .collect::<Result<Vec<_>, ServerFnError>>()?;
+        let page = rustok_core::Page::offset(edges, total_count.max(0) as u64, offset as u64);
 
         Ok(GraphqlUsersResponse {
             users: GraphqlUsersConnection {
-                edges,
-                page_info: GraphqlPageInfo { total_count },
+                edges: page.items,
+                page_info: GraphqlPageInfo {
+                    total_count,
+                    has_next_page: page.has_more,
+                },
             },
         })
     }
@@ -636,6 +654,7 @@ pub fn Users() -> impl IntoView {
                         None => view! { <div>{users_table_skeleton()}</div> }.into_any(),
                         Some(Ok(response)) => {
                             let total_count = response.users.page_info.total_count;
+                            let has_next_page = response.users.page_info.has_next_page;
                             let edges = response.users.edges;
                             view! {
                             <div>
@@ -736,10 +755,7 @@ pub fn Users() -> impl IntoView {
                                     <Button
                                         on_click=next_page
                                         class="border border-input bg-transparent text-foreground hover:bg-accent hover:text-accent-foreground"
-                                        disabled=Signal::derive(move || {
-                                            let total = total_count;
-                                            page.get() * limit.get() >= total
-                                        })
+                                        disabled=Signal::derive(move || !has_next_page)
                                     >
                                         {move || t_string!(i18n, users.pagination.next)}
                                     </Button>
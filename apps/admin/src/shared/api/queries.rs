@@ -12,10 +12,10 @@ pub const USER_DETAILS_QUERY_HASH: &str =
 pub const DASHBOARD_STATS_QUERY: &str =
     "query DashboardStats { dashboardStats { totalUsers totalPosts totalOrders totalRevenue usersChange postsChange ordersChange revenueChange } }";
 
-pub const RECENT_ACTIVITY_QUERY: &str = "query RecentActivity($limit: Int!) { recentActivity(limit: $limit) { id type description timestamp user { id name } } }";
+pub const RECENT_ACTIVITY_QUERY: &str = "query RecentActivity($limit: Int!) { recentActivity(limit: $limit) { id type description target timestamp user { id name } } }";
 
 pub const RECENT_ACTIVITY_QUERY_HASH: &str =
-    "a1b2c3d4e5f6g7h8i9j0k1l2m3n4o5p6q7r8s9t0u1v2w3x4y5z6a7b8c9d0e1f2";
+    "a5d9ad08c39a6cfa269e6ff42c2d58d2c32568d7a4776badb165dbfa857034ad";
 
 pub const ADMIN_GLOBAL_SEARCH_QUERY: &str = "query AdminGlobalSearch($input: SearchPreviewInput!) { adminGlobalSearch(input: $input) { queryLogId total tookMs engine items { id entityType sourceModule title snippet score locale url payload } } }";
 
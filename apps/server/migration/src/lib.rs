@@ -45,6 +45,7 @@ mod m20260419_000001_normalize_registry_governance_event_payloads;
 mod m20260426_000001_create_install_sessions;
 mod m20260501_000001_create_platform_composition_state;
 mod m20260522_000001_add_module_operation_correlation_id;
+mod m20261016_000001_index_sys_events_by_tenant;
 
 pub struct Migrator;
 
@@ -249,6 +250,9 @@ impl MigratorTrait for Migrator {
         all.push(Box::new(
             m20260522_000001_add_module_operation_correlation_id::Migration,
         ));
+        all.push(Box::new(
+            m20261016_000001_index_sys_events_by_tenant::Migration,
+        ));
         let dependencies = collect_migration_descriptors();
 
         all.sort_by(|a, b| a.name().cmp(b.name()));
@@ -10,6 +10,7 @@ use loco_rs::{
     Result,
 };
 use std::path::Path;
+use std::time::Duration;
 
 use sea_orm::EntityTrait;
 
@@ -21,6 +22,7 @@ use crate::seeds;
 use crate::services::app_lifecycle::{apply_boot_database_fallback, connect_runtime_workers};
 use crate::services::app_router::compose_application_router;
 use crate::services::app_runtime::bootstrap_app_runtime;
+use crate::services::slow_query_log::install_slow_query_logger;
 use crate::tasks;
 use loco_rs::prelude::Queue;
 
@@ -71,6 +73,14 @@ impl Hooks for App {
         // This must happen before after_routes so every request handler
         // can call email_service_from_ctx() and get a working Loco mailer.
         if let Ok(settings) = RustokSettings::from_settings(&ctx.config.settings) {
+            if let Some(threshold_ms) = settings.database.slow_query_threshold_ms {
+                install_slow_query_logger(
+                    &mut ctx.db,
+                    std::time::Duration::from_millis(threshold_ms),
+                );
+                tracing::info!(threshold_ms, "Slow-query logging enabled");
+            }
+
             if settings.email.provider == EmailProvider::Loco {
                 match loco_rs::mailer::EmailSender::smtp(&loco_rs::config::SmtpMailer {
                     enable: settings.email.enabled,
@@ -124,6 +134,8 @@ impl Hooks for App {
                 .add_route(controllers::metrics::routes())
                 .add_route(controllers::swagger::routes())
                 .add_route(controllers::admin_events::routes())
+                .add_route(controllers::admin_permissions::routes())
+                .add_route(controllers::admin_tenant_usage::routes())
                 .add_route(controllers::auth::routes())
                 .add_route(controllers::channel::routes())
                 .add_route(controllers::flex::routes())
@@ -144,6 +156,12 @@ impl Hooks for App {
         if !registry_only {
             routes = routes.add_route(channels::builds::routes());
         }
+        #[cfg(feature = "mod-content")]
+        {
+            if !registry_only {
+                routes = routes.add_route(controllers::admin_content::routes());
+            }
+        }
 
         routes
     }
@@ -214,16 +232,40 @@ impl Hooks for App {
         seeds::seed(ctx, path).await
     }
 
-    /// Graceful shutdown: stop background workers and flush telemetry.
+    /// Graceful shutdown: stop producers, drain the event dispatcher, flush
+    /// the outbox and forwarder, then close the database pool.
     async fn on_shutdown(ctx: &AppContext) {
-        use crate::services::app_lifecycle::StopHandle;
+        use crate::services::app_lifecycle::shutdown_coordinator_from_context;
+        use rustok_core::ShutdownPhase;
+
+        let coordinator = shutdown_coordinator_from_context(ctx);
+        let phase_timeout = Duration::from_millis(
+            RustokSettings::from_settings(&ctx.config.settings)
+                .map(|settings| settings.runtime.background_workers)
+                .unwrap_or_default()
+                .shutdown_phase_timeout_ms,
+        );
+
+        let mut database = coordinator.token("database", ShutdownPhase::Storage);
+        let db = ctx.db.clone();
+        tokio::spawn(async move {
+            database.triggered().await;
+            if let Err(error) = db.close().await {
+                tracing::warn!(%error, "Failed to close database pool");
+            }
+            database.acknowledge();
+        });
 
-        if let Some(handle) = ctx.shared_store.get::<StopHandle>() {
-            tracing::info!("Stopping background workers…");
-            handle.stop().await;
+        tracing::info!("Stopping background workers…");
+        let report = coordinator.shutdown(phase_timeout).await;
+        if report.is_clean() {
+            tracing::info!("RusTok server shut down cleanly");
+        } else {
+            tracing::warn!(
+                timed_out = ?report.timed_out,
+                "RusTok server shut down with unfinished background tasks"
+            );
         }
-
-        tracing::info!("RusTok server shut down cleanly");
     }
 }
 
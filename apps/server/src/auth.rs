@@ -1,10 +1,15 @@
 // Re-export types from rustok-auth (these don't need error conversion).
 pub use rustok_auth::{
-    AuthConfig, AuthError, AuthSettingsOverrides, Claims, EmailVerificationClaims, InviteClaims,
-    JwtAlgorithm, PasswordResetClaims,
+    Argon2PasswordHasher, AuthConfig, AuthError, AuthSettingsOverrides, Claims,
+    EmailVerificationClaims, InviteClaims, JwtAlgorithm, PasswordHasher, PasswordHashingParams,
+    PasswordResetClaims,
 };
 
+use axum::http::StatusCode;
 use loco_rs::app::AppContext;
+use rustok_api::ApiError;
+use rustok_core::CodedError;
+use std::sync::Arc;
 
 use crate::error::{Error, Result};
 use serde::Deserialize;
@@ -24,15 +29,43 @@ pub fn auth_config_from_ctx(ctx: &AppContext) -> Result<AuthConfig> {
         .and_then(|auth| auth.jwt.as_ref())
         .ok_or_else(|| Error::InternalServerError)?;
 
-    let app_settings = ctx
-        .config
-        .settings
-        .as_ref()
-        .and_then(|value| serde_json::from_value::<AppSettings>(value.clone()).ok());
+    auth_config_from_parts(
+        auth.secret.clone(),
+        auth.expiration,
+        auth_settings_from_ctx(ctx),
+    )
+}
 
-    let auth_settings = app_settings.and_then(|s| s.auth).unwrap_or_default();
+/// Password hasher shared through `AppContext::shared_store`.
+///
+/// Tests and alternative runtimes can insert their own hasher before the
+/// first auth call; otherwise an Argon2 hasher is built from settings.
+#[derive(Clone)]
+pub struct SharedPasswordHasher(pub Arc<dyn PasswordHasher>);
+
+/// Resolve the password hasher for sign-up, sign-in and password changes.
+pub fn password_hasher_from_ctx(ctx: &AppContext) -> Result<Arc<dyn PasswordHasher>> {
+    if let Some(shared) = ctx.shared_store.get::<SharedPasswordHasher>() {
+        return Ok(shared.0);
+    }
 
-    auth_config_from_parts(auth.secret.clone(), auth.expiration, auth_settings)
+    let params = auth_settings_from_ctx(ctx)
+        .password_hashing
+        .unwrap_or_default();
+    let hasher: Arc<dyn PasswordHasher> =
+        Arc::new(Argon2PasswordHasher::new(params).map_err(auth_err)?);
+    ctx.shared_store
+        .insert(SharedPasswordHasher(Arc::clone(&hasher)));
+    Ok(hasher)
+}
+
+fn auth_settings_from_ctx(ctx: &AppContext) -> AuthSettingsOverrides {
+    ctx.config
+        .settings
+        .as_ref()
+        .and_then(|value| serde_json::from_value::<AppSettings>(value.clone()).ok())
+        .and_then(|settings| settings.auth)
+        .unwrap_or_default()
 }
 
 fn auth_config_from_parts(
@@ -55,6 +88,9 @@ fn auth_config_from_parts(
     if let Some(algorithm) = auth_settings.algorithm {
         config.algorithm = algorithm;
     }
+    if let Some(secs) = auth_settings.clock_leeway_secs {
+        config = config.with_clock_leeway(secs);
+    }
 
     config.rsa_private_key_pem = resolve_key_material(
         auth_settings.rsa_private_key_pem,
@@ -194,19 +230,21 @@ pub fn hash_refresh_token(token: &str) -> String {
 
 // ─── Error conversion ────────────────────────────────────────────────
 
-/// Convert `AuthError` → `loco_rs::Error`.
+/// Convert `AuthError` → `loco_rs::Error` with an [`ApiError`] body carrying
+/// the stable `auth.*` code.
 pub fn auth_err(err: AuthError) -> Error {
     match err {
-        AuthError::InvalidCredentials | AuthError::InvalidAccessToken => {
-            Error::Unauthorized(err.to_string())
-        }
-        AuthError::InvalidResetToken
+        AuthError::InvalidCredentials
+        | AuthError::InvalidAccessToken
+        | AuthError::InvalidResetToken
         | AuthError::InvalidVerificationToken
-        | AuthError::InvalidInviteToken => Error::Unauthorized(err.to_string()),
-        AuthError::TokenEncodingFailed | AuthError::PasswordHashFailed => {
-            Error::InternalServerError
+        | AuthError::InvalidInviteToken => {
+            ApiError::from_error(&err).into_loco_error(StatusCode::UNAUTHORIZED)
+        }
+        AuthError::TokenEncodingFailed | AuthError::PasswordHashFailed | AuthError::Internal(_) => {
+            ApiError::new(err.error_code(), "Internal Server Error")
+                .into_loco_error(StatusCode::INTERNAL_SERVER_ERROR)
         }
-        AuthError::Internal(_) => Error::InternalServerError,
     }
 }
 
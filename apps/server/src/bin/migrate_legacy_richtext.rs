@@ -3,7 +3,7 @@ use std::{env, fs, path::PathBuf, str::FromStr, time::Duration};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use rustok_content::entities::{body, node};
-use rustok_core::{validate_and_sanitize_rt_json, RtJsonValidationConfig};
+use rustok_core::{redact_urls_in, validate_and_sanitize_rt_json, RtJsonValidationConfig};
 use sea_orm::{
     sea_query::Expr, ColumnTrait, Condition, Database, EntityTrait, QueryFilter, QueryOrder,
     QuerySelect,
@@ -42,7 +42,10 @@ struct Checkpoint {
 async fn main() -> Result<()> {
     let cli = parse_args()?;
     let database_url = env::var("DATABASE_URL").context("DATABASE_URL is required")?;
-    let db = Database::connect(&database_url).await?;
+    let db = Database::connect(&database_url)
+        .await
+        .map_err(|error| anyhow::anyhow!(redact_urls_in(&error.to_string())))
+        .context("failed to connect to DATABASE_URL")?;
 
     let mut checkpoint = load_checkpoint(&cli.checkpoint_file)?;
     let mut metrics = Metrics::default();
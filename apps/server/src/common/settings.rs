@@ -1,5 +1,6 @@
 use ipnet::IpNet;
 use rustok_core::tenant_validation::TenantIdentifierValidator;
+use rustok_core::ConcurrencyScope;
 use rustok_iggy::IggyConfig;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -7,6 +8,7 @@ use uuid::Uuid;
 
 use rustok_storage::StorageConfig;
 
+use crate::middleware::cors::normalize_origin;
 use crate::services::redis_runtime::resolve_redis_url;
 
 const DEFAULT_TENANT_ID: Uuid = Uuid::from_u128(1);
@@ -30,11 +32,115 @@ pub struct RustokSettings {
     #[serde(default)]
     pub cache: CacheSettings,
     #[serde(default)]
+    pub database: DatabaseSettings,
+    #[serde(default)]
     pub registry: RegistrySettings,
     #[serde(default)]
     pub runtime: RuntimeSettings,
     #[serde(default)]
     pub storage: StorageConfig,
+    #[serde(default)]
+    pub cors: CorsSettings,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    #[serde(default)]
+    pub content: ContentSettings,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct ContentSettings {
+    /// Key signing bulk delete confirmation tokens. Must match on every
+    /// instance; a key derived from the JWT secret is used when unset.
+    #[serde(default)]
+    pub bulk_delete_secret: Option<String>,
+}
+
+/// Notification fan-out. Tenants override `routes` per notification kind in
+/// their own `settings.notifications.routes`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationSettings {
+    #[serde(default = "default_notifications_enabled")]
+    pub enabled: bool,
+    /// Channels (`email`, `webhook`, `log`) per notification kind.
+    #[serde(default = "default_notification_routes")]
+    pub routes: std::collections::BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub webhook: NotificationWebhookSettings,
+    /// Attempts per channel before a delivery counts as failed.
+    #[serde(default = "default_notification_delivery_attempts")]
+    pub delivery_attempts: u32,
+    /// Delay before the first retry; doubled for every further one.
+    #[serde(default = "default_notification_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationWebhookSettings {
+    /// Endpoint for tenants without their own `settings.notifications.webhook`.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Key signing payloads sent to `url`; required when `url` is set.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Send the recipient's address to `url`. Off by default.
+    #[serde(default)]
+    pub include_recipient: bool,
+    #[serde(default = "default_notification_webhook_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_notifications_enabled(),
+            routes: default_notification_routes(),
+            webhook: NotificationWebhookSettings::default(),
+            delivery_attempts: default_notification_delivery_attempts(),
+            retry_backoff_ms: default_notification_retry_backoff_ms(),
+        }
+    }
+}
+
+impl Default for NotificationWebhookSettings {
+    fn default() -> Self {
+        Self {
+            url: None,
+            secret: None,
+            include_recipient: false,
+            timeout_ms: default_notification_webhook_timeout_ms(),
+        }
+    }
+}
+
+fn default_notifications_enabled() -> bool {
+    true
+}
+
+fn default_notification_routes() -> std::collections::BTreeMap<String, Vec<String>> {
+    std::collections::BTreeMap::from([("order.receipt".to_string(), vec!["email".to_string()])])
+}
+
+fn default_notification_webhook_timeout_ms() -> u64 {
+    5_000
+}
+
+fn default_notification_delivery_attempts() -> u32 {
+    3
+}
+
+fn default_notification_retry_backoff_ms() -> u64 {
+    500
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct CorsSettings {
+    /// Reject cross-origin auth requests from origins that are not allowed
+    /// globally or by the tenant's `settings.cors.allowed_origins`.
+    #[serde(default)]
+    pub enforce_auth_origins: bool,
+    /// Origins allowed for every tenant, as `scheme://host[:port]`.
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -74,6 +180,18 @@ pub struct CacheSettings {
     pub redis_url: Option<String>,
 }
 
+/// Database observability settings.
+///
+/// `slow_query_threshold_ms` enables slow-query logging: statements that take longer are
+/// logged at `warn` with their SQL and elapsed time and counted in
+/// `rustok_db_slow_queries_total`. Unset disables it; this is independent of
+/// `database.enable_logging`, which logs every statement.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct DatabaseSettings {
+    #[serde(default)]
+    pub slow_query_threshold_ms: Option<u64>,
+}
+
 /// Email transport provider selector.
 ///
 /// - `smtp` (default): sends via lettre directly using the `[email.smtp]` config
@@ -160,6 +278,10 @@ pub struct EventSettings {
     pub relay_interval_ms: u64,
     #[serde(default = "default_event_channel_capacity")]
     pub channel_capacity: usize,
+    /// Largest serialized envelope `EventBus` accepts; bigger events are
+    /// rejected before they reach the transport.
+    #[serde(default = "default_max_event_bytes")]
+    pub max_event_bytes: usize,
     #[serde(default)]
     pub relay_retry_policy: RelayRetryPolicy,
     #[serde(default)]
@@ -167,7 +289,64 @@ pub struct EventSettings {
     #[serde(default)]
     pub backpressure: EventBackpressureSettings,
     #[serde(default)]
+    pub dispatcher: EventDispatcherSettings,
+    #[serde(default)]
     pub iggy: IggyConfig,
+    #[serde(default)]
+    pub routing: EventRoutingSettings,
+}
+
+/// Rules the event forwarder uses to pick sinks for each event. Without
+/// rules every event goes to `default_destinations`, which by default is
+/// the configured transport.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EventRoutingSettings {
+    #[serde(default = "default_event_routing_destinations")]
+    pub default_destinations: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<EventRoutingRuleSettings>,
+}
+
+impl Default for EventRoutingSettings {
+    fn default() -> Self {
+        Self {
+            default_destinations: default_event_routing_destinations(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// First matching rule wins; empty `destinations` drops the event.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct EventRoutingRuleSettings {
+    pub name: String,
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    #[serde(default)]
+    pub tenant_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub destinations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EventDispatcherSettings {
+    #[serde(default = "default_dispatcher_max_concurrent_handlers")]
+    pub max_concurrent_handlers: usize,
+    #[serde(default)]
+    pub concurrency_scope: ConcurrencyScope,
+    /// How long shutdown lets in-flight event handlers finish.
+    #[serde(default = "default_dispatcher_drain_timeout_ms")]
+    pub drain_timeout_ms: u64,
+}
+
+impl Default for EventDispatcherSettings {
+    fn default() -> Self {
+        Self {
+            max_concurrent_handlers: default_dispatcher_max_concurrent_handlers(),
+            concurrency_scope: ConcurrencyScope::default(),
+            drain_timeout_ms: default_dispatcher_drain_timeout_ms(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -389,6 +568,10 @@ pub struct RuntimeBackgroundWorkerSettings {
     pub workflow_cron_enabled: bool,
     #[serde(default = "default_true")]
     pub seo_bulk_enabled: bool,
+    /// How long shutdown waits for the tasks of each phase (producers,
+    /// dispatch, flush, storage) before moving on.
+    #[serde(default = "default_shutdown_phase_timeout_ms")]
+    pub shutdown_phase_timeout_ms: u64,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, Default, Eq, PartialEq)]
@@ -536,10 +719,13 @@ impl Default for EventSettings {
             allow_relay_target_fallback: false,
             relay_interval_ms: default_relay_interval_ms(),
             channel_capacity: default_event_channel_capacity(),
+            max_event_bytes: default_max_event_bytes(),
             relay_retry_policy: RelayRetryPolicy::default(),
             dlq: DlqSettings::default(),
             backpressure: EventBackpressureSettings::default(),
+            dispatcher: EventDispatcherSettings::default(),
             iggy: IggyConfig::default(),
+            routing: EventRoutingSettings::default(),
         }
     }
 }
@@ -587,6 +773,7 @@ impl Default for RuntimeBackgroundWorkerSettings {
         Self {
             workflow_cron_enabled: true,
             seo_bulk_enabled: true,
+            shutdown_phase_timeout_ms: default_shutdown_phase_timeout_ms(),
         }
     }
 }
@@ -672,6 +859,22 @@ impl RustokSettings {
             })?;
         }
 
+        parsed.cors.allowed_origins = parsed
+            .cors
+            .allowed_origins
+            .iter()
+            .map(|origin| {
+                normalize_origin(origin).ok_or_else(|| {
+                    serde_json::Error::io(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "invalid rustok.cors.allowed_origins entry `{origin}`: expected scheme://host[:port]"
+                        ),
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
         if parsed.events.relay_retry_policy.max_attempts <= 0 {
             return Err(serde_json::Error::io(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -686,6 +889,23 @@ impl RustokSettings {
             )));
         }
 
+        let mut rule_names = std::collections::HashSet::new();
+        for rule in &parsed.events.routing.rules {
+            if rule.name.trim().is_empty() || !rule_names.insert(rule.name.as_str()) {
+                return Err(serde_json::Error::io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "rustok.events.routing.rules[].name must be non-empty and unique",
+                )));
+            }
+        }
+
+        if parsed.events.max_event_bytes == 0 {
+            return Err(serde_json::Error::io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "rustok.events.max_event_bytes must be > 0",
+            )));
+        }
+
         if parsed.events.channel_capacity == 0 {
             return Err(serde_json::Error::io(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -719,6 +939,20 @@ impl RustokSettings {
             }
         }
 
+        if parsed.database.slow_query_threshold_ms == Some(0) {
+            return Err(serde_json::Error::io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "rustok.database.slow_query_threshold_ms must be > 0",
+            )));
+        }
+
+        if parsed.events.dispatcher.max_concurrent_handlers == 0 {
+            return Err(serde_json::Error::io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "rustok.events.dispatcher.max_concurrent_handlers must be > 0",
+            )));
+        }
+
         if parsed.search.reindex.parallelism == 0 {
             return Err(serde_json::Error::io(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -1078,6 +1312,14 @@ fn default_relay_backoff_max_ms() -> u64 {
     60_000
 }
 
+fn default_max_event_bytes() -> usize {
+    rustok_core::events::DEFAULT_MAX_EVENT_BYTES
+}
+
+fn default_event_routing_destinations() -> Vec<String> {
+    vec![crate::services::event_bus::PRIMARY_TRANSPORT_SINK.to_string()]
+}
+
 fn default_dlq_enabled() -> bool {
     true
 }
@@ -1086,6 +1328,18 @@ fn default_dlq_max_attempts() -> i32 {
     10
 }
 
+fn default_dispatcher_max_concurrent_handlers() -> usize {
+    10
+}
+
+fn default_dispatcher_drain_timeout_ms() -> u64 {
+    5_000
+}
+
+fn default_shutdown_phase_timeout_ms() -> u64 {
+    10_000
+}
+
 fn default_backpressure_max_queue_depth() -> usize {
     10_000
 }
@@ -1328,6 +1582,135 @@ mod tests {
             .contains("rustok.events.channel_capacity must be > 0"));
     }
 
+    #[test]
+    fn reads_event_dispatcher_concurrency_settings() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let _env_guard = EnvVarGuard::clear(EVENT_TRANSPORT_ENV);
+        let _redis_guard = EnvVarGuard::clear(RUSTOK_REDIS_URL_ENV);
+        let _redis_url_guard = EnvVarGuard::clear(REDIS_URL_ENV);
+
+        let raw = serde_json::json!({
+            "rustok": {
+                "events": {
+                    "dispatcher": {
+                        "max_concurrent_handlers": 4,
+                        "concurrency_scope": "per_handler"
+                    }
+                }
+            }
+        });
+
+        let settings = RustokSettings::from_settings(&Some(raw)).unwrap();
+        assert_eq!(settings.events.dispatcher.max_concurrent_handlers, 4);
+        assert_eq!(
+            settings.events.dispatcher.concurrency_scope,
+            ConcurrencyScope::PerHandler
+        );
+
+        let raw = serde_json::json!({
+            "rustok": {
+                "events": {
+                    "dispatcher": { "max_concurrent_handlers": 0 }
+                }
+            }
+        });
+        let err = RustokSettings::from_settings(&Some(raw)).expect_err("dispatcher validation");
+        assert!(err
+            .to_string()
+            .contains("rustok.events.dispatcher.max_concurrent_handlers must be > 0"));
+    }
+
+    #[test]
+    fn reads_event_routing_rules() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let _env_guard = EnvVarGuard::clear(EVENT_TRANSPORT_ENV);
+        let _redis_guard = EnvVarGuard::clear(RUSTOK_REDIS_URL_ENV);
+        let _redis_url_guard = EnvVarGuard::clear(REDIS_URL_ENV);
+
+        let settings =
+            RustokSettings::from_settings(&Some(serde_json::json!({ "rustok": {} }))).unwrap();
+        assert_eq!(settings.events.routing.default_destinations, ["transport"]);
+        assert_eq!(
+            settings.events.max_event_bytes,
+            rustok_core::events::DEFAULT_MAX_EVENT_BYTES
+        );
+        assert!(settings.events.routing.rules.is_empty());
+
+        let raw = serde_json::json!({
+            "rustok": { "events": { "routing": { "rules": [
+                { "name": "drop_audit", "event_types": ["audit.*"] },
+                { "name": "orders", "event_types": ["order.*"], "destinations": ["transport", "webhooks"] }
+            ] } } }
+        });
+        let settings = RustokSettings::from_settings(&Some(raw)).unwrap();
+        let rules = &settings.events.routing.rules;
+        assert_eq!(rules.len(), 2);
+        assert!(rules[0].destinations.is_empty());
+        assert_eq!(rules[1].destinations, ["transport", "webhooks"]);
+
+        let raw = serde_json::json!({
+            "rustok": { "events": { "routing": { "rules": [
+                { "name": "dup" }, { "name": "dup" }
+            ] } } }
+        });
+        let err = RustokSettings::from_settings(&Some(raw)).expect_err("duplicate rule names");
+        assert!(err
+            .to_string()
+            .contains("rustok.events.routing.rules[].name must be non-empty and unique"));
+    }
+
+    #[test]
+    fn normalizes_and_validates_cors_origins() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let _env_guard = EnvVarGuard::clear(EVENT_TRANSPORT_ENV);
+        let _redis_guard = EnvVarGuard::clear(RUSTOK_REDIS_URL_ENV);
+        let _redis_url_guard = EnvVarGuard::clear(REDIS_URL_ENV);
+
+        let raw = serde_json::json!({
+            "rustok": { "cors": {
+                "enforce_auth_origins": true,
+                "allowed_origins": ["https://Admin.Example.com/"]
+            } }
+        });
+        let settings = RustokSettings::from_settings(&Some(raw)).unwrap();
+        assert!(settings.cors.enforce_auth_origins);
+        assert_eq!(settings.cors.allowed_origins, ["https://admin.example.com"]);
+
+        let raw = serde_json::json!({
+            "rustok": { "cors": { "allowed_origins": ["https://example.com/app"] } }
+        });
+        let err = RustokSettings::from_settings(&Some(raw)).expect_err("origin with path");
+        assert!(err
+            .to_string()
+            .contains("invalid rustok.cors.allowed_origins entry"));
+    }
+
+    #[test]
+    fn reads_database_slow_query_threshold() {
+        let _guard = env_lock().lock().expect("env lock poisoned");
+        let _env_guard = EnvVarGuard::clear(EVENT_TRANSPORT_ENV);
+        let _redis_guard = EnvVarGuard::clear(RUSTOK_REDIS_URL_ENV);
+        let _redis_url_guard = EnvVarGuard::clear(REDIS_URL_ENV);
+
+        let settings =
+            RustokSettings::from_settings(&Some(serde_json::json!({ "rustok": {} }))).unwrap();
+        assert_eq!(settings.database.slow_query_threshold_ms, None);
+
+        let raw = serde_json::json!({
+            "rustok": { "database": { "slow_query_threshold_ms": 250 } }
+        });
+        let settings = RustokSettings::from_settings(&Some(raw)).unwrap();
+        assert_eq!(settings.database.slow_query_threshold_ms, Some(250));
+
+        let raw = serde_json::json!({
+            "rustok": { "database": { "slow_query_threshold_ms": 0 } }
+        });
+        let err = RustokSettings::from_settings(&Some(raw)).expect_err("threshold validation");
+        assert!(err
+            .to_string()
+            .contains("rustok.database.slow_query_threshold_ms must be > 0"));
+    }
+
     #[test]
     fn reads_rate_limit_backend_defaults() {
         let _guard = env_lock().lock().expect("env lock poisoned");
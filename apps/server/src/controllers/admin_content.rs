@@ -0,0 +1,99 @@
+use axum::{extract::State, http::StatusCode, Json};
+use loco_rs::{app::AppContext, controller::Routes};
+use rustok_api::ApiError;
+use rustok_content::{BulkDeleteCriteria, BulkDeletePlan, ContentError};
+use rustok_core::error::RichError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::{Error, Result};
+use crate::extractors::rbac::RequireNodesDelete;
+use crate::extractors::tenant::CurrentTenant;
+use crate::services::content_nodes::node_service_from_context;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExecuteBulkDeleteRequest {
+    /// Token returned by the prepare step.
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExecuteBulkDeleteResponse {
+    pub deleted: u64,
+}
+
+/// Compute which nodes `criteria` would soft-delete and return a short-lived
+/// token authorising exactly that set. Nothing is deleted.
+#[utoipa::path(
+    post,
+    path = "/api/admin/content/bulk-delete/prepare",
+    request_body = BulkDeleteCriteria,
+    responses(
+        (status = 200, description = "Affected count and confirmation token", body = BulkDeletePlan),
+        (status = 400, description = "Empty criteria or too many matching nodes"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn prepare_bulk_delete(
+    State(ctx): State<AppContext>,
+    RequireNodesDelete(user): RequireNodesDelete,
+    CurrentTenant(tenant): CurrentTenant,
+    Json(criteria): Json<BulkDeleteCriteria>,
+) -> Result<Json<BulkDeletePlan>> {
+    let plan = node_service_from_context(&ctx, &tenant)?
+        .prepare_bulk_delete(tenant.id, user.security_context(), criteria)
+        .await
+        .map_err(content_http_error)?;
+    Ok(Json(plan))
+}
+
+/// Soft-delete the nodes authorised by a prepared token. Fails without
+/// deleting anything if the token is invalid or the matching set changed.
+#[utoipa::path(
+    post,
+    path = "/api/admin/content/bulk-delete/execute",
+    request_body = ExecuteBulkDeleteRequest,
+    responses(
+        (status = 200, description = "Nodes deleted", body = ExecuteBulkDeleteResponse),
+        (status = 400, description = "Invalid or expired token, or the matching set changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Token issued to another tenant or user"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn execute_bulk_delete(
+    State(ctx): State<AppContext>,
+    RequireNodesDelete(user): RequireNodesDelete,
+    CurrentTenant(tenant): CurrentTenant,
+    Json(request): Json<ExecuteBulkDeleteRequest>,
+) -> Result<Json<ExecuteBulkDeleteResponse>> {
+    let deleted = node_service_from_context(&ctx, &tenant)?
+        .execute_bulk_delete(tenant.id, user.security_context(), &request.token)
+        .await
+        .map_err(content_http_error)?;
+    Ok(Json(ExecuteBulkDeleteResponse { deleted }))
+}
+
+fn content_http_error(err: ContentError) -> Error {
+    let api_error = ApiError::from_error(&err);
+    let rich: RichError = err.into();
+    let status = StatusCode::from_u16(rich.status_code).unwrap_or(StatusCode::BAD_REQUEST);
+    api_error.into_loco_error(status)
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/admin/content")
+        .add(
+            "/bulk-delete/prepare",
+            axum::routing::post(prepare_bulk_delete),
+        )
+        .add(
+            "/bulk-delete/execute",
+            axum::routing::post(execute_bulk_delete),
+        )
+}
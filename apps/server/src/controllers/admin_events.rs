@@ -1,12 +1,16 @@
 use crate::error::Error;
 use crate::error::Result;
 use axum::{
+    body::{Body, Bytes},
     extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use chrono::{DateTime, Utc};
 use loco_rs::app::AppContext;
-use loco_rs::controller::Routes;
+use loco_rs::controller::{ErrorDetail, Routes};
+use rustok_core::{EventConsumerRuntime, EventEnvelope};
 use rustok_outbox::entity::{self, SysEventStatus};
 use rustok_telemetry::metrics;
 use sea_orm::{
@@ -15,11 +19,15 @@ use sea_orm::{
     QueryOrder, QuerySelect, Set, Value,
 };
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use std::time::Instant;
+use tokio::sync::broadcast::error::RecvError;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::extractors::rbac::RequireLogsRead;
+use crate::extractors::tenant::CurrentTenant;
+use crate::services::event_bus::event_bus_from_context;
 
 #[derive(Debug, Deserialize)]
 pub struct DlqQuery {
@@ -30,6 +38,15 @@ pub struct DlqQuery {
     pub limit: u64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct EventStreamQuery {
+    pub tenant_id: Option<Uuid>,
+    pub event_type: Option<String>,
+    /// Stream every tenant's events; platform admins only.
+    #[serde(default)]
+    pub all_tenants: bool,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct DlqEventItem {
     pub id: Uuid,
@@ -188,11 +205,129 @@ pub async fn replay_dlq_event(
     }))
 }
 
+/// Streams live bus events as newline-delimited JSON, one `EventEnvelope`
+/// per line. The subscription lives as long as the response body, so it is
+/// dropped as soon as the client disconnects.
+///
+/// Events are limited to the current tenant. Only platform admins may pick
+/// another `tenant_id` or pass `all_tenants=true`.
+#[utoipa::path(
+    get,
+    path = "/api/admin/events/stream",
+    params(
+        ("tenant_id" = Option<Uuid>, Query, description = "Stream events of this tenant instead of the current one (platform admins only)"),
+        ("event_type" = Option<String>, Query, description = "Only stream events of this type"),
+        ("all_tenants" = Option<bool>, Query, description = "Stream events of every tenant (platform admins only)"),
+    ),
+    responses(
+        (status = 200, description = "NDJSON stream of event envelopes", content_type = "application/x-ndjson"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn stream_events(
+    State(ctx): State<AppContext>,
+    RequireLogsRead(user): RequireLogsRead,
+    CurrentTenant(tenant): CurrentTenant,
+    Query(query): Query<EventStreamQuery>,
+) -> Result<Response> {
+    let tenant_id = stream_tenant_scope(
+        tenant.id,
+        query.tenant_id,
+        query.all_tenants,
+        user.is_platform_admin(),
+    )?;
+    let event_type = query
+        .event_type
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    let receiver = event_bus_from_context(&ctx).subscribe_filtered(move |envelope| {
+        tenant_id.is_none_or(|tenant_id| envelope.tenant_id == tenant_id)
+            && event_type
+                .as_deref()
+                .is_none_or(|event_type| envelope.event_type == event_type)
+    });
+    let consumer_runtime = EventConsumerRuntime::new("admin_event_stream");
+
+    let stream = futures_util::stream::unfold(receiver, move |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(envelope) => match envelope_line(&envelope) {
+                    Some(line) => return Some((Ok::<_, Infallible>(line), receiver)),
+                    None => continue,
+                },
+                Err(RecvError::Lagged(skipped)) => consumer_runtime.lagged(skipped),
+                Err(RecvError::Closed) => {
+                    consumer_runtime.closed();
+                    return None;
+                }
+            }
+        }
+    });
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson"),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        Body::from_stream(stream),
+    )
+        .into_response())
+}
+
 pub fn routes() -> Routes {
     Routes::new()
         .prefix("api/admin/events")
         .add("/dlq", axum::routing::get(list_dlq))
         .add("/dlq/{id}/replay", axum::routing::post(replay_dlq_event))
+        .add("/stream", axum::routing::get(stream_events))
+}
+
+fn envelope_line(envelope: &EventEnvelope) -> Option<Bytes> {
+    match serde_json::to_vec(envelope) {
+        Ok(mut line) => {
+            line.push(b'\n');
+            Some(Bytes::from(line))
+        }
+        Err(error) => {
+            tracing::warn!(
+                event_id = %envelope.id,
+                event_type = %envelope.event_type,
+                %error,
+                "Skipping event that failed to serialize for the admin event stream"
+            );
+            None
+        }
+    }
+}
+
+/// Tenant filter for the event stream; `None` streams every tenant.
+fn stream_tenant_scope(
+    current_tenant: Uuid,
+    requested_tenant: Option<Uuid>,
+    all_tenants: bool,
+    platform_admin: bool,
+) -> Result<Option<Uuid>> {
+    let cross_tenant =
+        all_tenants || requested_tenant.is_some_and(|tenant_id| tenant_id != current_tenant);
+    if cross_tenant && !platform_admin {
+        return Err(Error::CustomError(
+            StatusCode::FORBIDDEN,
+            ErrorDetail::new(
+                "forbidden".to_string(),
+                "Streaming events of other tenants requires a platform admin".to_string(),
+            ),
+        ));
+    }
+
+    if all_tenants {
+        Ok(None)
+    } else {
+        Ok(Some(requested_tenant.unwrap_or(current_tenant)))
+    }
 }
 
 fn default_limit() -> u64 {
@@ -210,3 +345,40 @@ fn sys_event_tenant_condition(backend: DbBackend, tenant_id: Uuid) -> SimpleExpr
 
     Expr::cust_with_values(sql, vec![Value::from(tenant_id)])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::stream_tenant_scope;
+    use uuid::Uuid;
+
+    #[test]
+    fn stream_is_limited_to_the_current_tenant() {
+        let current = Uuid::new_v4();
+
+        assert_eq!(
+            stream_tenant_scope(current, None, false, false).unwrap(),
+            Some(current)
+        );
+        assert_eq!(
+            stream_tenant_scope(current, Some(current), false, false).unwrap(),
+            Some(current)
+        );
+        assert!(stream_tenant_scope(current, Some(Uuid::new_v4()), false, false).is_err());
+        assert!(stream_tenant_scope(current, None, true, false).is_err());
+    }
+
+    #[test]
+    fn platform_admin_may_stream_other_or_all_tenants() {
+        let current = Uuid::new_v4();
+        let other = Uuid::new_v4();
+
+        assert_eq!(
+            stream_tenant_scope(current, Some(other), false, true).unwrap(),
+            Some(other)
+        );
+        assert_eq!(
+            stream_tenant_scope(current, None, true, true).unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use axum::Json;
+use loco_rs::controller::Routes;
+use rustok_core::{all_permissions, permissions_for_role, Permission, UserRole};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::error::Result;
+use crate::extractors::rbac::RequireSettingsRead;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PermissionCatalogResponse {
+    /// Every `resource:action` pair the platform defines.
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RolePermissions {
+    pub role: UserRole,
+    /// Permissions granted by the static role mapping. `resource:manage`
+    /// implies every other action on that resource.
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RolePermissionsResponse {
+    pub roles: Vec<RolePermissions>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/permissions",
+    responses(
+        (status = 200, description = "Full permission catalog", body = PermissionCatalogResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn list_permissions(
+    _user: RequireSettingsRead,
+) -> Result<Json<PermissionCatalogResponse>> {
+    Ok(Json(PermissionCatalogResponse {
+        permissions: render(all_permissions()),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/permissions/roles",
+    responses(
+        (status = 200, description = "Permissions granted by each platform role", body = RolePermissionsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn list_role_permissions(
+    _user: RequireSettingsRead,
+) -> Result<Json<RolePermissionsResponse>> {
+    let roles = UserRole::ALL
+        .into_iter()
+        .map(|role| RolePermissions {
+            permissions: render(permissions_for_role(role.clone())),
+            role,
+        })
+        .collect();
+
+    Ok(Json(RolePermissionsResponse { roles }))
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/admin/permissions")
+        .add("/", axum::routing::get(list_permissions))
+        .add("/roles", axum::routing::get(list_role_permissions))
+}
+
+fn render(permissions: Vec<Permission>) -> Vec<String> {
+    permissions.iter().map(Permission::to_string).collect()
+}
@@ -0,0 +1,76 @@
+use axum::{extract::Path, http::StatusCode, Json};
+use loco_rs::controller::{ErrorDetail, Routes};
+use rustok_telemetry::metrics::TenantUsage;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::{Error, Result};
+use crate::extractors::rbac::RequireAnalyticsRead;
+use crate::extractors::tenant::CurrentTenant;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantUsageResponse {
+    pub tenant_id: Uuid,
+    /// `false` when metrics collection is off; every counter is then zero.
+    pub metrics_enabled: bool,
+    pub requests: u64,
+    /// Requests that finished with a `5xx` status.
+    pub errors: u64,
+    pub events_published: u64,
+}
+
+impl TenantUsageResponse {
+    fn new(tenant_id: Uuid, usage: Option<TenantUsage>) -> Self {
+        let metrics_enabled = usage.is_some();
+        let usage = usage.unwrap_or_default();
+        Self {
+            tenant_id,
+            metrics_enabled,
+            requests: usage.requests,
+            errors: usage.errors,
+            events_published: usage.events_published,
+        }
+    }
+}
+
+/// Usage of `tenant_id`. Callers may only read their own tenant unless they
+/// are platform admins.
+#[utoipa::path(
+    get,
+    path = "/api/admin/tenants/{tenant_id}/usage",
+    params(
+        ("tenant_id" = Uuid, Path, description = "Tenant UUID"),
+    ),
+    responses(
+        (status = 200, description = "Per-tenant rollup of tenant-labeled metrics since process start", body = TenantUsageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "admin"
+)]
+pub async fn tenant_usage(
+    RequireAnalyticsRead(user): RequireAnalyticsRead,
+    CurrentTenant(tenant): CurrentTenant,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<Json<TenantUsageResponse>> {
+    if tenant_id != tenant.id && !user.is_platform_admin() {
+        return Err(Error::CustomError(
+            StatusCode::FORBIDDEN,
+            ErrorDetail::new(
+                "forbidden".to_string(),
+                "Usage of other tenants requires a platform admin".to_string(),
+            ),
+        ));
+    }
+
+    let usage = rustok_telemetry::tenant_usage_snapshot(&tenant_id.to_string());
+    Ok(Json(TenantUsageResponse::new(tenant_id, usage)))
+}
+
+pub fn routes() -> Routes {
+    Routes::new()
+        .prefix("api/admin/tenants")
+        .add("/{tenant_id}/usage", axum::routing::get(tenant_usage))
+}
@@ -4,7 +4,7 @@ use axum::response::Response;
 use axum::{
     extract::State,
     extract::{ConnectInfo, Path, Query},
-    http::header::USER_AGENT,
+    http::{header::USER_AGENT, StatusCode},
     routing::{delete, get, post},
     Json,
 };
@@ -12,6 +12,8 @@ use chrono::Utc;
 use loco_rs::app::AppContext;
 use loco_rs::controller::format;
 use loco_rs::controller::Routes;
+use rustok_api::ApiError;
+use rustok_core::TimeBounds;
 use rustok_telemetry::metrics;
 use sea_orm::{
     sea_query::Expr, ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder,
@@ -22,10 +24,8 @@ use std::net::SocketAddr;
 use utoipa::ToSchema;
 
 use crate::auth::{
-    auth_config_from_ctx, decode_email_verification_token, decode_invite_token,
-    encode_email_verification_token, encode_password_reset_token, hash_refresh_token,
+    auth_config_from_ctx, decode_invite_token, encode_password_reset_token, hash_refresh_token,
 };
-use crate::common::settings::RustokSettings;
 use crate::common::RequestContext;
 use crate::extractors::{auth::CurrentUser, tenant::CurrentTenant};
 use crate::models::{
@@ -33,12 +33,9 @@ use crate::models::{
     users::{self, Entity as Users},
 };
 use crate::services::auth_lifecycle::{AuthLifecycleError, AuthLifecycleService};
-use crate::services::email::{
-    email_service_from_ctx, password_reset_url, EmailVerificationEmail, PasswordResetEmail,
-};
+use crate::services::email::{email_service_from_ctx, password_reset_url, PasswordResetEmail};
 
 const DEFAULT_RESET_TOKEN_TTL_SECS: u64 = 15 * 60;
-const DEFAULT_VERIFY_TOKEN_TTL_SECS: u64 = 24 * 60 * 60;
 
 #[derive(Deserialize, ToSchema)]
 pub struct LoginParams {
@@ -148,6 +145,7 @@ pub struct UserResponse {
     pub email: String,
     pub name: Option<String>,
     pub role: String,
+    pub status: rustok_core::UserStatus,
 }
 
 impl UserResponse {
@@ -157,6 +155,7 @@ impl UserResponse {
             email: m.email,
             name: m.name,
             role: role.to_string(),
+            status: m.status,
         }
     }
 }
@@ -287,7 +286,10 @@ async fn logout(
     let token_hash = hash_refresh_token(&params.refresh_token);
     let session = sessions::Entity::find_by_token_hash(&ctx.db, tenant.id, &token_hash)
         .await?
-        .ok_or_else(|| Error::Unauthorized("Invalid refresh token".into()))?;
+        .ok_or_else(|| {
+            ApiError::new("auth.invalid_refresh_token", "Invalid refresh token")
+                .into_loco_error(StatusCode::UNAUTHORIZED)
+        })?;
 
     if session.revoked_at.is_none() {
         let mut session_model: sessions::ActiveModel = session.into();
@@ -321,7 +323,10 @@ async fn accept_invite(
     let claims = decode_invite_token(&config, &params.token)?;
 
     if claims.tenant_id != tenant.id {
-        return Err(Error::Unauthorized("Invalid invite token".into()));
+        return Err(
+            ApiError::new("auth.invalid_invite_token", "Invalid invite token")
+                .into_loco_error(StatusCode::UNAUTHORIZED),
+        );
     }
 
     let email = claims.sub.clone();
@@ -338,9 +343,11 @@ async fn accept_invite(
     )
     .await
     .map_err(|e: AuthLifecycleError| match e {
-        AuthLifecycleError::EmailAlreadyExists => {
-            Error::BadRequest("A user with this email already exists".into())
-        }
+        AuthLifecycleError::EmailAlreadyExists => ApiError::new(
+            "auth.email_already_exists",
+            "A user with this email already exists",
+        )
+        .into_loco_error(StatusCode::BAD_REQUEST),
         other => Error::from(other),
     })?;
 
@@ -428,49 +435,54 @@ async fn request_verification(
     request_context: RequestContext,
     Json(params): Json<RequestVerificationParams>,
 ) -> Result<Response> {
-    let config = auth_config_from_ctx(&ctx)?;
-    let settings = RustokSettings::from_settings(&ctx.config.settings)
-        .map_err(|_| Error::InternalServerError)?;
-
     let user = Users::find_by_email(&ctx.db, tenant.id, &params.email).await?;
 
     let expose_token = std::env::var("RUSTOK_DEMO_MODE")
         .map(|value| value == "1")
         .unwrap_or(false);
 
-    let verification_token = if settings.features.email_verification {
-        user.filter(|record| record.email_verified_at.is_none())
-            .map(|record| {
-                encode_email_verification_token(
-                    &config,
-                    tenant.id,
-                    &record.email,
-                    DEFAULT_VERIFY_TOKEN_TTL_SECS,
-                )
-            })
-            .transpose()?
-    } else {
-        None
+    let verification_token = match user {
+        Some(user) => AuthLifecycleService::request_email_verification(
+            &ctx,
+            tenant.id,
+            user.id,
+            request_context.locale.as_str(),
+        )
+        .await
+        .map_err(|e: AuthLifecycleError| Error::from(e))?,
+        None => None,
     };
 
-    if let Some(verification_token_value) = verification_token.as_ref() {
-        let email_service = email_service_from_ctx(&ctx, request_context.locale.as_str())
-            .map_err(|_| Error::InternalServerError)?;
-        let recipient = params.email.clone();
-        let verification_token = verification_token_value.clone();
+    format::json(VerificationRequestResponse {
+        status: "ok",
+        verification_token: if expose_token {
+            verification_token
+        } else {
+            None
+        },
+    })
+}
 
-        tokio::spawn(async move {
-            if let Err(error) = email_service
-                .send_email_verification(EmailVerificationEmail {
-                    to: recipient,
-                    verification_token,
-                })
-                .await
-            {
-                tracing::warn!(error = %error, "Failed to send email verification email");
-            }
-        });
-    }
+#[utoipa::path(post, path = "/api/auth/verify/resend", tag = "auth", security(("bearer_auth" = [])),
+    responses((status = 200, description = "Verification email queued", body = VerificationRequestResponse)))]
+async fn resend_verification(
+    State(ctx): State<AppContext>,
+    CurrentTenant(tenant): CurrentTenant,
+    current: CurrentUser,
+    request_context: RequestContext,
+) -> Result<Response> {
+    let expose_token = std::env::var("RUSTOK_DEMO_MODE")
+        .map(|value| value == "1")
+        .unwrap_or(false);
+
+    let verification_token = AuthLifecycleService::request_email_verification(
+        &ctx,
+        tenant.id,
+        current.user.id,
+        request_context.locale.as_str(),
+    )
+    .await
+    .map_err(|e: AuthLifecycleError| Error::from(e))?;
 
     format::json(VerificationRequestResponse {
         status: "ok",
@@ -489,22 +501,9 @@ async fn confirm_verification(
     CurrentTenant(tenant): CurrentTenant,
     Json(params): Json<ConfirmVerificationParams>,
 ) -> Result<Response> {
-    let config = auth_config_from_ctx(&ctx)?;
-    let claims = decode_email_verification_token(&config, &params.token)?;
-
-    if claims.tenant_id != tenant.id {
-        return Err(Error::Unauthorized("Invalid verification token".into()));
-    }
-
-    let user = Users::find_by_email(&ctx.db, tenant.id, &claims.sub)
-        .await?
-        .ok_or_else(|| Error::Unauthorized("Invalid verification token".into()))?;
-
-    if user.email_verified_at.is_none() {
-        let mut user_active: users::ActiveModel = user.into();
-        user_active.email_verified_at = Set(Some(Utc::now().into()));
-        user_active.update(&ctx.db).await?;
-    }
+    AuthLifecycleService::verify_email(&ctx, tenant.id, &params.token)
+        .await
+        .map_err(|e: AuthLifecycleError| Error::from(e))?;
 
     format::json(GenericStatusResponse { status: "ok" })
 }
@@ -522,12 +521,17 @@ async fn list_sessions(
 ) -> Result<Response> {
     let requested_limit = params.limit;
     let limit = clamp_session_limit(params.limit);
+    let leeway = auth_config_from_ctx(&ctx)?.clock_leeway();
 
     let rows = sessions::Entity::find()
         .filter(sessions::Column::TenantId.eq(tenant.id))
         .filter(sessions::Column::UserId.eq(current.user.id))
         .filter(sessions::Column::RevokedAt.is_null())
-        .filter(sessions::Column::ExpiresAt.gt(Utc::now()))
+        .filter(
+            sessions::Column::ExpiresAt.gt(TimeBounds::default()
+                .with_leeway(leeway)
+                .expiry_cutoff(Utc::now())),
+        )
         .order_by_desc(sessions::Column::CreatedAt)
         .limit(limit)
         .all(&ctx.db)
@@ -700,6 +704,7 @@ pub fn routes() -> Routes {
         .add("/reset/request", post(request_reset))
         .add("/reset/confirm", post(confirm_reset))
         .add("/verify/request", post(request_verification))
+        .add("/verify/resend", post(resend_verification))
         .add("/verify/confirm", post(confirm_verification))
         .add("/sessions", get(list_sessions))
         .add("/sessions/revoke-all", post(revoke_all_sessions))
@@ -167,7 +167,7 @@ async fn apply(
             .filter(|value| !value.trim().is_empty())
             .unwrap_or_else(|| "http".to_string()),
         lock_ttl_secs: request.lock_ttl_secs.unwrap_or(900),
-        pg_admin_url: request.pg_admin_url,
+        pg_admin_url: request.pg_admin_url.map(Into::into),
     };
     INSTALL_JOBS.lock().await.insert(
         job_id,
@@ -201,7 +201,7 @@ async fn apply(
             }
             Err(error) => {
                 job.status = InstallJobState::Failed;
-                job.error = Some(error.to_string());
+                job.error = Some(rustok_core::redact_urls_in(&error.to_string()));
             }
         }
         job.finished_at = Some(finished_at);
@@ -9,6 +9,7 @@ use loco_rs::controller::Routes;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::auth::auth_config_from_ctx;
 use crate::error::Result;
 use crate::extractors::{
     rbac::{RequireMcpManage, RequireMcpRead},
@@ -199,7 +200,8 @@ async fn get_client(
     let details = McpManagementService::get_client_details(&ctx.db, tenant.id, client_id)
         .await?
         .ok_or(crate::error::Error::NotFound)?;
-    Ok(Json(map_client_details(details)))
+    let leeway = auth_config_from_ctx(&ctx)?.clock_leeway();
+    Ok(Json(map_client_details(details, leeway)))
 }
 
 async fn create_client(
@@ -208,6 +210,7 @@ async fn create_client(
     RequireMcpManage(user): RequireMcpManage,
     Json(input): Json<CreateMcpClientRequest>,
 ) -> Result<Json<CreateMcpClientResponse>> {
+    let leeway = auth_config_from_ctx(&ctx)?.clock_leeway();
     let result = McpManagementService::create_client(
         &ctx.db,
         tenant.id,
@@ -232,7 +235,7 @@ async fn create_client(
     Ok(Json(CreateMcpClientResponse {
         client: map_client_summary(result.client),
         policy: map_policy(result.policy),
-        token: map_token(result.token),
+        token: map_token(result.token, leeway),
         plaintext_token: result.plaintext_token,
     }))
 }
@@ -244,6 +247,7 @@ async fn rotate_token(
     Path(client_id): Path<Uuid>,
     Json(input): Json<RotateMcpTokenRequest>,
 ) -> Result<Json<RotateMcpTokenResponse>> {
+    let leeway = auth_config_from_ctx(&ctx)?.clock_leeway();
     let result = McpManagementService::rotate_token(
         &ctx.db,
         tenant.id,
@@ -260,7 +264,7 @@ async fn rotate_token(
 
     Ok(Json(RotateMcpTokenResponse {
         client: map_client_summary(result.client),
-        token: map_token(result.token),
+        token: map_token(result.token, leeway),
         plaintext_token: result.plaintext_token,
     }))
 }
@@ -465,8 +469,11 @@ fn map_policy(model: crate::models::mcp_policies::Model) -> McpPolicyResponse {
     }
 }
 
-fn map_token(model: crate::models::mcp_tokens::Model) -> McpTokenResponse {
-    let is_active = model.is_active();
+fn map_token(
+    model: crate::models::mcp_tokens::Model,
+    leeway: chrono::Duration,
+) -> McpTokenResponse {
+    let is_active = model.is_active(leeway);
     McpTokenResponse {
         id: model.id,
         token_name: model.token_name,
@@ -498,14 +505,21 @@ fn map_scaffold_draft(
     }
 }
 
-fn map_client_details(details: McpClientDetails) -> McpClientDetailsResponse {
+fn map_client_details(
+    details: McpClientDetails,
+    leeway: chrono::Duration,
+) -> McpClientDetailsResponse {
     McpClientDetailsResponse {
         client: map_client_summary(details.client.clone()),
         description: details.client.description,
         delegated_user_id: details.client.delegated_user_id,
         metadata: details.client.metadata,
         policy: details.policy.map(map_policy),
-        tokens: details.tokens.into_iter().map(map_token).collect(),
+        tokens: details
+            .tokens
+            .into_iter()
+            .map(|token| map_token(token, leeway))
+            .collect(),
         effective_access_context: details
             .effective_access_context
             .and_then(|value| serde_json::to_value(value).ok()),
@@ -1,16 +1,20 @@
 use axum::{
     extract::State,
-    http::{header::CONTENT_TYPE, StatusCode},
+    http::{
+        header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE, VARY},
+        HeaderMap, StatusCode,
+    },
     response::{IntoResponse, Response},
     routing::get,
 };
 use loco_rs::{app::AppContext, controller::Routes};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use rustok_outbox::entity::{Column as SysEventsColumn, Entity as SysEventsEntity, SysEventStatus};
 use sea_orm::{
     ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, PaginatorTrait, QueryFilter, Statement,
 };
+use std::io::Write;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
@@ -27,6 +31,7 @@ use crate::services::runtime_guardrails::{
     collect_runtime_guardrail_snapshot, RuntimeGuardrailSnapshot,
 };
 use rustok_telemetry::metrics::update_queue_depth;
+use rustok_telemetry::MetricsHandle;
 use tracing::warn;
 
 static RBAC_CONSISTENCY_QUERY_FAILURES_TOTAL: AtomicU64 = AtomicU64::new(0);
@@ -39,34 +44,91 @@ static RBAC_CONSISTENCY_QUERY_LATENCY_SAMPLES: AtomicU64 = AtomicU64::new(0);
     path = "/metrics",
     tag = "observability",
     responses(
-        (status = 200, description = "Prometheus metrics in text format", content_type = "text/plain"),
-        (status = 503, description = "Metrics collection disabled")
+        (status = 200, description = "Prometheus metrics in text format (gzip-encoded when the scraper sends `Accept-Encoding: gzip`); only a `# metrics disabled` comment when metrics collection is off", content_type = "text/plain"),
     )
 )]
-pub async fn metrics(State(ctx): State<AppContext>) -> Result<Response> {
-    match rustok_telemetry::metrics_handle() {
-        Some(handle) => {
-            sync_rate_limit_metrics(&ctx).await;
-            let mut payload = handle.render();
-            payload.push('\n');
-            payload.push_str(&render_tenant_cache_metrics(&ctx).await);
-            payload.push_str(&render_tenant_activity_metrics(&ctx).await);
-            payload.push_str(&render_tenant_locale_cache_metrics(&ctx).await);
-            payload.push_str(&render_outbox_metrics(&ctx).await);
-            payload.push_str(&render_auth_lifecycle_metrics());
-            payload.push_str(&render_rbac_metrics(&ctx).await);
-            payload.push_str(&render_search_metrics(&ctx).await);
-            payload.push_str(&render_runtime_guardrail_metrics(&ctx).await);
-
-            Ok((
-                StatusCode::OK,
-                [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
-                payload,
-            )
-                .into_response())
-        }
-        None => Ok((StatusCode::SERVICE_UNAVAILABLE, "metrics disabled").into_response()),
+pub async fn metrics(State(ctx): State<AppContext>, headers: HeaderMap) -> Result<Response> {
+    let content_type = [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")];
+    let Some(handle) = rustok_telemetry::metrics_handle() else {
+        // An empty exposition keeps scrapers healthy when metrics are off.
+        let body = rustok_telemetry::render_metrics_or_empty()
+            .map_err(|error| Error::Message(format!("failed to encode metrics: {error}")))?;
+        return Ok((StatusCode::OK, content_type, body).into_response());
+    };
+
+    sync_rate_limit_metrics(&ctx).await;
+    let sections = [
+        render_tenant_cache_metrics(&ctx).await,
+        render_tenant_activity_metrics(&ctx).await,
+        render_tenant_locale_cache_metrics(&ctx).await,
+        render_outbox_metrics(&ctx).await,
+        render_auth_lifecycle_metrics(),
+        render_rbac_metrics(&ctx).await,
+        render_search_metrics(&ctx).await,
+        render_runtime_guardrail_metrics(&ctx).await,
+    ];
+
+    // The registry is encoded straight into the response buffer (through the
+    // gzip encoder when negotiated) instead of an intermediate `String`.
+    if accepts_gzip(&headers) {
+        let mut writer = rustok_telemetry::gzip_writer(Vec::new());
+        write_metrics_payload(&handle, &sections, &mut writer)?;
+        let body = writer.finish()?;
+        Ok((
+            StatusCode::OK,
+            content_type,
+            [(CONTENT_ENCODING, "gzip"), (VARY, "accept-encoding")],
+            body,
+        )
+            .into_response())
+    } else {
+        let mut body = Vec::new();
+        write_metrics_payload(&handle, &sections, &mut body)?;
+        Ok((
+            StatusCode::OK,
+            content_type,
+            [(VARY, "accept-encoding")],
+            body,
+        )
+            .into_response())
+    }
+}
+
+fn write_metrics_payload<W: Write>(
+    handle: &MetricsHandle,
+    sections: &[String],
+    writer: &mut W,
+) -> Result<()> {
+    handle
+        .encode_to(writer)
+        .map_err(|error| Error::Message(format!("failed to encode metrics: {error}")))?;
+    writer.write_all(b"\n")?;
+    for section in sections {
+        writer.write_all(section.as_bytes())?;
     }
+    Ok(())
+}
+
+/// Whether the scraper accepts a gzip-encoded body (`gzip` or `*` with a
+/// non-zero quality in `Accept-Encoding`).
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|entry| {
+            let mut parts = entry.trim().split(';');
+            let coding = parts.next().unwrap_or_default().trim();
+            let quality = parts
+                .find_map(|part| {
+                    let (key, value) = part.trim().split_once('=')?;
+                    key.trim().eq_ignore_ascii_case("q").then_some(value.trim())
+                })
+                .and_then(|value| value.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (coding.eq_ignore_ascii_case("gzip") || coding == "*") && quality > 0.0
+        })
 }
 
 pub fn routes() -> Routes {
@@ -541,7 +603,7 @@ fn format_rbac_metrics(
 #[cfg(test)]
 mod tests {
     use super::{
-        format_outbox_metrics, format_rbac_metrics, format_runtime_guardrail_metrics,
+        accepts_gzip, format_outbox_metrics, format_rbac_metrics, format_runtime_guardrail_metrics,
         format_tenant_activity_metrics, format_tenant_cache_metrics,
         format_tenant_locale_cache_metrics, render_auth_lifecycle_metrics,
     };
@@ -576,6 +638,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn accepts_gzip_honours_accept_encoding_quality() {
+        use axum::http::{header::ACCEPT_ENCODING, HeaderMap, HeaderValue};
+
+        let headers_with = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(value));
+            headers
+        };
+
+        assert!(accepts_gzip(&headers_with("gzip")));
+        assert!(accepts_gzip(&headers_with("deflate, GZIP;q=0.5")));
+        assert!(accepts_gzip(&headers_with("*")));
+        assert!(!accepts_gzip(&headers_with("gzip;q=0")));
+        assert!(!accepts_gzip(&headers_with("identity")));
+        assert!(!accepts_gzip(&HeaderMap::new()));
+    }
+
     #[test]
     fn rbac_metrics_include_claim_role_mismatch_counter() {
         let payload = format_rbac_metrics(RbacService::metrics_snapshot(), 0, 0, 0);
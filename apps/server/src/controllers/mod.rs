@@ -1,4 +1,8 @@
+#[cfg(feature = "mod-content")]
+pub mod admin_content;
 pub mod admin_events;
+pub mod admin_permissions;
+pub mod admin_tenant_usage;
 pub mod auth;
 #[cfg(feature = "mod-blog")]
 pub mod blog;
@@ -31,6 +31,7 @@ use utoipa::OpenApi;
         crate::controllers::auth::me,
         crate::controllers::auth::accept_invite,
         crate::controllers::auth::request_verification,
+        crate::controllers::auth::resend_verification,
         crate::controllers::auth::confirm_verification,
         // Health
         crate::controllers::health::health,
@@ -57,6 +58,12 @@ use utoipa::OpenApi;
         // Admin Events
         crate::controllers::admin_events::list_dlq,
         crate::controllers::admin_events::replay_dlq_event,
+        crate::controllers::admin_events::stream_events,
+        // Admin Permissions
+        crate::controllers::admin_permissions::list_permissions,
+        crate::controllers::admin_permissions::list_role_permissions,
+        // Admin Tenant Usage
+        crate::controllers::admin_tenant_usage::tenant_usage,
         // Flex standalone
         crate::controllers::flex::list_schemas,
         crate::controllers::flex::get_schema,
@@ -113,6 +120,14 @@ use utoipa::OpenApi;
             crate::controllers::admin_events::DlqListResponse,
             crate::controllers::admin_events::DlqReplayResponse,
 
+            // Admin Permissions
+            crate::controllers::admin_permissions::PermissionCatalogResponse,
+            crate::controllers::admin_permissions::RolePermissions,
+            crate::controllers::admin_permissions::RolePermissionsResponse,
+
+            // Admin Tenant Usage
+            crate::controllers::admin_tenant_usage::TenantUsageResponse,
+
             // Flex standalone
             crate::controllers::flex::CreateFlexSchemaRequest,
             crate::controllers::flex::UpdateFlexSchemaRequest,
@@ -208,6 +223,22 @@ pub struct BlogApiDoc;
 )]
 pub struct ForumApiDoc;
 
+#[cfg(feature = "mod-content")]
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::controllers::admin_content::prepare_bulk_delete,
+        crate::controllers::admin_content::execute_bulk_delete,
+    ),
+    components(schemas(
+        rustok_content::BulkDeleteCriteria,
+        rustok_content::BulkDeletePlan,
+        crate::controllers::admin_content::ExecuteBulkDeleteRequest,
+        crate::controllers::admin_content::ExecuteBulkDeleteResponse,
+    ))
+)]
+pub struct ContentAdminApiDoc;
+
 #[cfg(feature = "mod-pages")]
 #[derive(OpenApi)]
 #[openapi(
@@ -243,6 +274,7 @@ pub struct PagesApiDoc;
     paths(
         crate::controllers::commerce::store::list_products,
         crate::controllers::commerce::store::show_product,
+        crate::controllers::commerce::store::show_product_availability,
         crate::controllers::commerce::store::list_regions,
         crate::controllers::commerce::store::list_shipping_options,
         crate::controllers::commerce::store::create_cart,
@@ -301,6 +333,8 @@ pub struct PagesApiDoc;
             rustok_commerce::dto::CreateProductInput,
             rustok_commerce::dto::UpdateProductInput,
             rustok_commerce::dto::ProductResponse,
+            rustok_commerce::dto::Availability,
+            rustok_commerce::dto::UnavailableReason,
             rustok_commerce::dto::ProductTranslationInput,
             rustok_commerce::dto::ProductOptionInput,
             rustok_commerce::dto::ProductTranslationResponse,
@@ -387,6 +421,8 @@ const REGISTRY_ONLY_OPENAPI_PATHS: &[&str] = &[
 
 fn build_openapi_document(settings: &RustokSettings) -> OpenApiDoc {
     let mut openapi = ApiDoc::openapi();
+    #[cfg(feature = "mod-content")]
+    openapi.merge(ContentAdminApiDoc::openapi());
     #[cfg(feature = "mod-blog")]
     openapi.merge(BlogApiDoc::openapi());
     #[cfg(feature = "mod-forum")]
@@ -7,7 +7,8 @@ use axum::{http::StatusCode, response::Response};
 use loco_rs::app::AppContext;
 use loco_rs::controller::Routes;
 use loco_rs::controller::{format, ErrorDetail};
-use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use rustok_core::fetch_with_total;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -107,9 +108,9 @@ async fn list_users(
         query = query.filter(UserColumn::Status.eq(status.as_str()));
     }
 
-    let paginator = query.paginate(&ctx.db, page_size);
-    let total = paginator.num_items().await.unwrap_or(0);
-    let rows = paginator.fetch_page(page - 1).await.unwrap_or_default();
+    let (rows, total) = fetch_with_total(&ctx.db, query, (page - 1) * page_size, page_size)
+        .await
+        .unwrap_or_default();
 
     format::json(UsersResponse {
         users: rows.into_iter().map(map_user).collect(),
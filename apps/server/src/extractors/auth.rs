@@ -38,6 +38,12 @@ impl CurrentUser {
             self.permissions.iter().copied(),
         )
     }
+
+    /// Platform admins (`super_admin`) may read data of tenants other than
+    /// the one the request resolved to.
+    pub fn is_platform_admin(&self) -> bool {
+        self.inferred_role == UserRole::SuperAdmin
+    }
 }
 
 async fn resolve_service_token_permissions(
@@ -128,7 +134,7 @@ pub async fn resolve_current_user_from_access_token(
             .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "Database error"))?
             .ok_or((StatusCode::UNAUTHORIZED, "Session not found"))?;
 
-        if session.tenant_id != tenant_id || !session.is_active() {
+        if session.tenant_id != tenant_id || !session.is_active(auth_config.clock_leeway()) {
             return Err((StatusCode::UNAUTHORIZED, "Session expired"));
         }
     }
@@ -42,6 +42,12 @@ fn map_auth_lifecycle_error(error: AuthLifecycleError, locale: Locale) -> FieldE
         AuthLifecycleError::InvalidResetToken => {
             unauthenticated_auth_error(&t("auth.invalid_reset_token"))
         }
+        AuthLifecycleError::InvalidVerificationToken => {
+            unauthenticated_auth_error(&t("auth.invalid_verification_token"))
+        }
+        AuthLifecycleError::EmailNotVerified => {
+            <FieldError as GraphQLError>::permission_denied(&t("auth.email_not_verified"))
+        }
         AuthLifecycleError::Internal(err) => {
             <FieldError as GraphQLError>::internal_error(&err.to_string())
         }
@@ -298,6 +304,46 @@ impl AuthMutation {
         Ok(ResetPasswordPayload { success: true })
     }
 
+    /// Send an email verification link to the currently authenticated user
+    async fn request_email_verification(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<RequestEmailVerificationPayload> {
+        let app_ctx = ctx.data::<AppContext>()?;
+        let auth = ctx
+            .data::<AuthContext>()
+            .map_err(|_| <FieldError as GraphQLError>::unauthenticated())?;
+        let tenant = ctx.data::<TenantContext>()?;
+
+        let locale = locale_from_ctx(ctx);
+        AuthLifecycleService::request_email_verification(
+            app_ctx,
+            tenant.id,
+            auth.user_id,
+            locale.as_str(),
+        )
+        .await
+        .map_err(|e| map_auth_lifecycle_error(e, locale))?;
+
+        Ok(RequestEmailVerificationPayload { success: true })
+    }
+
+    /// Confirm an email address using a verification token
+    async fn verify_email(
+        &self,
+        ctx: &Context<'_>,
+        input: VerifyEmailInput,
+    ) -> Result<VerifyEmailPayload> {
+        let app_ctx = ctx.data::<AppContext>()?;
+        let tenant = ctx.data::<TenantContext>()?;
+
+        AuthLifecycleService::verify_email(app_ctx, tenant.id, &input.token)
+            .await
+            .map_err(|e| map_auth_lifecycle_error(e, locale_from_ctx(ctx)))?;
+
+        Ok(VerifyEmailPayload { success: true })
+    }
+
     /// Log out: revoke the current session.
     async fn logout(&self, ctx: &Context<'_>) -> Result<SignOutPayload> {
         let app_ctx = ctx.data::<AppContext>()?;
@@ -76,6 +76,21 @@ pub struct ResetPasswordPayload {
     pub success: bool,
 }
 
+#[derive(Debug, Clone, InputObject)]
+pub struct VerifyEmailInput {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct VerifyEmailPayload {
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RequestEmailVerificationPayload {
+    pub success: bool,
+}
+
 #[derive(Debug, Clone, InputObject)]
 pub struct UpdateProfileInput {
     pub name: Option<String>,
@@ -2,8 +2,10 @@ pub mod mutation;
 pub mod query;
 pub mod types;
 
-use async_graphql::{FieldError, Result};
+use async_graphql::{Context, FieldError, Result};
+use loco_rs::app::AppContext;
 
+use crate::auth::auth_config_from_ctx;
 use crate::context::AuthContext;
 use crate::graphql::errors::GraphQLError;
 use rustok_core::Permission;
@@ -29,6 +31,14 @@ pub(super) fn ensure_mcp_manage(auth: &AuthContext) -> Result<()> {
     }
 }
 
+/// Clock leeway from the auth config, so token `is_active` flags match enforcement.
+pub(super) fn token_clock_leeway(ctx: &Context<'_>) -> Result<chrono::Duration> {
+    let app_ctx = ctx.data::<AppContext>()?;
+    auth_config_from_ctx(app_ctx)
+        .map(|config| config.clock_leeway())
+        .map_err(|e| <FieldError as GraphQLError>::internal_error(&e.to_string()))
+}
+
 pub use mutation::McpMutation;
 pub use query::McpQuery;
 pub use types::*;
@@ -14,11 +14,12 @@ use crate::services::mcp_management::{
 use rustok_mcp::ScaffoldModuleRequest;
 
 use super::{
-    ensure_mcp_manage,
+    ensure_mcp_manage, token_clock_leeway,
     types::{
         parse_metadata, ApplyMcpModuleScaffoldDraftInput, CreateMcpClientInput,
-        CreateMcpClientResultGql, McpModuleScaffoldDraftGql, McpPolicyGql, RotateMcpTokenInput,
-        RotateMcpTokenResultGql, StageMcpModuleScaffoldDraftInput, UpdateMcpPolicyInput,
+        CreateMcpClientResultGql, McpModuleScaffoldDraftGql, McpPolicyGql, McpTokenGql,
+        RotateMcpTokenInput, RotateMcpTokenResultGql, StageMcpModuleScaffoldDraftInput,
+        UpdateMcpPolicyInput,
     },
 };
 
@@ -40,6 +41,7 @@ impl McpMutation {
         let auth = require_auth_context(ctx)?;
         ensure_mcp_manage(auth)?;
         let db = ctx.data::<DatabaseConnection>()?;
+        let leeway = token_clock_leeway(ctx)?;
 
         let metadata = parse_metadata(input.metadata)?;
         let result = McpManagementService::create_client(
@@ -67,7 +69,7 @@ impl McpMutation {
         Ok(CreateMcpClientResultGql {
             client: result.client.into(),
             policy: result.policy.into(),
-            token: result.token.into(),
+            token: McpTokenGql::from_model(result.token, leeway),
             plaintext_token: result.plaintext_token,
         })
     }
@@ -81,6 +83,7 @@ impl McpMutation {
         let auth = require_auth_context(ctx)?;
         ensure_mcp_manage(auth)?;
         let db = ctx.data::<DatabaseConnection>()?;
+        let leeway = token_clock_leeway(ctx)?;
 
         let metadata = parse_metadata(input.metadata)?;
         let result = McpManagementService::rotate_token(
@@ -100,7 +103,7 @@ impl McpMutation {
 
         Ok(RotateMcpTokenResultGql {
             client: result.client.into(),
-            token: result.token.into(),
+            token: McpTokenGql::from_model(result.token, leeway),
             plaintext_token: result.plaintext_token,
         })
     }
@@ -7,10 +7,10 @@ use crate::graphql::errors::GraphQLError;
 use crate::services::mcp_management::{McpAuditFilters, McpManagementService};
 
 use super::{
-    ensure_mcp_manage, ensure_mcp_read,
+    ensure_mcp_manage, ensure_mcp_read, token_clock_leeway,
     types::{
         serialize_access_context, McpAuditEventGql, McpClientDetailsGql, McpClientGql,
-        McpModuleScaffoldDraftGql,
+        McpModuleScaffoldDraftGql, McpTokenGql,
     },
 };
 
@@ -48,6 +48,7 @@ impl McpQuery {
         let auth = require_auth_context(ctx)?;
         ensure_mcp_read(auth)?;
         let db = ctx.data::<DatabaseConnection>()?;
+        let leeway = token_clock_leeway(ctx)?;
 
         let details = McpManagementService::get_client_details(db, auth.tenant_id, id)
             .await
@@ -58,7 +59,11 @@ impl McpQuery {
                 Ok(McpClientDetailsGql {
                     client: details.client.into(),
                     policy: details.policy.map(Into::into),
-                    tokens: details.tokens.into_iter().map(Into::into).collect(),
+                    tokens: details
+                        .tokens
+                        .into_iter()
+                        .map(|token| McpTokenGql::from_model(token, leeway))
+                        .collect(),
                     effective_access_context: serialize_access_context(
                         details.effective_access_context.as_ref(),
                     )?,
@@ -135,9 +135,9 @@ pub struct McpTokenGql {
     pub created_at: DateTime<Utc>,
 }
 
-impl From<mcp_tokens::Model> for McpTokenGql {
-    fn from(value: mcp_tokens::Model) -> Self {
-        let is_active = value.is_active();
+impl McpTokenGql {
+    pub fn from_model(value: mcp_tokens::Model, leeway: chrono::Duration) -> Self {
+        let is_active = value.is_active(leeway);
         Self {
             id: value.id,
             client_id: value.client_id,
@@ -1,7 +1,7 @@
 use async_graphql::{Context, ErrorExtensions, FieldError, Object, Result};
 use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set, TransactionTrait};
 
-use crate::auth::hash_password;
+use crate::auth::password_hasher_from_ctx;
 use crate::common::RequestContext;
 use crate::context::{AuthContext, TenantContext};
 #[cfg(all(
@@ -235,26 +235,7 @@ fn parse_build_id(build_id: &str) -> Result<Uuid> {
     feature = "mod-comments"
 ))]
 fn map_content_error(err: rustok_content::ContentError) -> FieldError {
-    match err {
-        rustok_content::ContentError::Validation(message)
-        | rustok_content::ContentError::Forbidden(message) => FieldError::new(message),
-        rustok_content::ContentError::NodeNotFound(_)
-        | rustok_content::ContentError::CategoryNotFound(_)
-        | rustok_content::ContentError::TranslationNotFound { .. }
-        | rustok_content::ContentError::DuplicateSlug { .. }
-        | rustok_content::ContentError::ConcurrentModification { .. } => {
-            FieldError::new(err.to_string())
-        }
-        rustok_content::ContentError::Database(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-        rustok_content::ContentError::Core(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-        rustok_content::ContentError::Rich(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-    }
+    rustok_api::ApiError::from_error(&err).into_graphql_error()
 }
 
 async fn ensure_modules_manage_permission(
@@ -748,7 +729,9 @@ impl RootMutation {
         }
 
         if let Some(password) = input.password {
-            let password_hash = hash_password(&password)
+            let password_hash = password_hasher_from_ctx(app_ctx)
+                .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?
+                .hash(&password)
                 .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?;
             model.password_hash = Set(password_hash);
         }
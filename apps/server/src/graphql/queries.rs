@@ -65,26 +65,7 @@ fn requested_collection_limit(limit: Option<i32>) -> Option<u64> {
 
 #[cfg(feature = "mod-content")]
 fn map_content_error(err: rustok_content::ContentError) -> FieldError {
-    match err {
-        rustok_content::ContentError::Validation(message)
-        | rustok_content::ContentError::Forbidden(message) => FieldError::new(message),
-        rustok_content::ContentError::NodeNotFound(_)
-        | rustok_content::ContentError::CategoryNotFound(_)
-        | rustok_content::ContentError::TranslationNotFound { .. }
-        | rustok_content::ContentError::DuplicateSlug { .. }
-        | rustok_content::ContentError::ConcurrentModification { .. } => {
-            FieldError::new(err.to_string())
-        }
-        rustok_content::ContentError::Database(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-        rustok_content::ContentError::Core(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-        rustok_content::ContentError::Rich(inner) => {
-            <FieldError as GraphQLError>::internal_error(&inner.to_string())
-        }
-    }
+    rustok_api::ApiError::from_error(&err).into_graphql_error()
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -260,6 +241,7 @@ async fn load_order_stats_snapshot(
     })
 }
 
+/// `node.status_changed` -> `Node status changed`.
 fn humanize_slug(slug: &str) -> String {
     slug.split('-')
         .map(|part| {
@@ -1598,32 +1580,47 @@ impl RootQuery {
         let requested_limit = limit.max(0) as u64;
         let limit = limit.clamp(1, 50);
 
-        let recent_users_started_at = Instant::now();
-        let recent_users = users::Entity::find()
-            .filter(UsersColumn::TenantId.eq(tenant.id))
-            .order_by_desc(UsersColumn::CreatedAt)
-            .limit(limit as u64)
-            .all(&app_ctx.db)
+        let recent_events_started_at = Instant::now();
+        let entries = rustok_outbox::load_audit_entries(&app_ctx.db, tenant.id, limit)
             .await
             .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?;
         metrics::record_read_path_query(
             "graphql",
             "root.recent_activity",
-            "recent_users",
-            recent_users_started_at.elapsed().as_secs_f64(),
-            recent_users.len() as u64,
+            "recent_events",
+            recent_events_started_at.elapsed().as_secs_f64(),
+            entries.len() as u64,
         );
 
-        let activities = recent_users
+        let actor_ids = entries
+            .iter()
+            .filter_map(|entry| entry.actor_id)
+            .collect::<HashSet<_>>();
+        let actors = if actor_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users::Entity::find()
+                .filter(UsersColumn::TenantId.eq(tenant.id))
+                .filter(UsersColumn::Id.is_in(actor_ids))
+                .all(&app_ctx.db)
+                .await
+                .map_err(|err| <FieldError as GraphQLError>::internal_error(&err.to_string()))?
+                .into_iter()
+                .map(|user| (user.id, user.name.unwrap_or(user.email)))
+                .collect::<HashMap<_, _>>()
+        };
+
+        let activities = entries
             .into_iter()
-            .map(|user| ActivityItem {
-                id: user.id.to_string(),
-                r#type: "user.created".to_string(),
-                description: format!("New user {} joined", user.email),
-                timestamp: user.created_at.to_rfc3339(),
-                user: Some(ActivityUser {
-                    id: user.id.to_string(),
-                    name: user.name,
+            .map(|entry| ActivityItem {
+                id: entry.id.to_string(),
+                description: rustok_outbox::describe_event_type(&entry.event_type),
+                r#type: entry.event_type,
+                target: entry.target,
+                timestamp: entry.created_at.to_rfc3339(),
+                user: entry.actor_id.map(|actor_id| ActivityUser {
+                    id: actor_id.to_string(),
+                    name: actors.get(&actor_id).cloned(),
                 }),
             })
             .collect::<Vec<_>>();
@@ -858,6 +858,8 @@ pub struct ActivityItem {
     pub id: String,
     pub r#type: String,
     pub description: String,
+    /// `kind:id` of the entity the event is about, when it names one.
+    pub target: Option<String>,
     pub timestamp: String,
     pub user: Option<ActivityUser>,
 }
@@ -1,5 +1,6 @@
 use eyre::{bail, eyre, Result};
 use migration::Migrator;
+use rustok_core::{redact_urls_in, RedactedUrl};
 use rustok_installer::{
     evaluate_preflight, redact_install_plan, AdminBootstrap, DatabaseConfig, DatabaseEngine,
     InstallEnvironment, InstallPlan, InstallProfile, InstallReceipt, InstallState, InstallStep,
@@ -25,7 +26,7 @@ use crate::services::rbac_service::RbacService;
 pub struct InstallerApplyOptions {
     pub lock_owner: String,
     pub lock_ttl_secs: i64,
-    pub pg_admin_url: Option<String>,
+    pub pg_admin_url: Option<RedactedUrl>,
 }
 
 impl Default for InstallerApplyOptions {
@@ -114,10 +115,10 @@ struct InstallCliOptions {
     environment: InstallEnvironment,
     profile: InstallProfile,
     database_engine: DatabaseEngine,
-    database_url: String,
+    database_url: RedactedUrl,
     database_secret_ref: Option<SecretRef>,
     create_database: bool,
-    pg_admin_url: Option<String>,
+    pg_admin_url: Option<RedactedUrl>,
     admin_email: String,
     admin_password: Option<String>,
     admin_password_ref: Option<SecretRef>,
@@ -141,14 +142,17 @@ impl InstallCliOptions {
             database_engine: parse_env_var("RUSTOK_INSTALL_DATABASE_ENGINE", parse_database_engine)
                 .unwrap_or(DatabaseEngine::Postgres),
             database_url: std::env::var("DATABASE_URL")
-                .unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string()),
+                .unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string())
+                .into(),
             database_secret_ref: std::env::var("RUSTOK_INSTALL_DATABASE_SECRET_REF")
                 .ok()
                 .as_deref()
                 .map(parse_secret_ref)
                 .transpose()?,
             create_database: false,
-            pg_admin_url: std::env::var("RUSTOK_INSTALL_PG_ADMIN_URL").ok(),
+            pg_admin_url: std::env::var("RUSTOK_INSTALL_PG_ADMIN_URL")
+                .ok()
+                .map(RedactedUrl::from),
             admin_email: std::env::var("SUPERADMIN_EMAIL")
                 .or_else(|_| std::env::var("SEED_ADMIN_EMAIL"))
                 .unwrap_or_else(|_| DEFAULT_ADMIN_EMAIL.to_string()),
@@ -194,7 +198,7 @@ impl InstallCliOptions {
                         parse_database_engine(&take_value(args, &mut index)?)?;
                 }
                 "--database-url" => {
-                    options.database_url = take_value(args, &mut index)?;
+                    options.database_url = take_value(args, &mut index)?.into();
                     options.database_secret_ref = None;
                 }
                 "--database-secret-ref" => {
@@ -203,7 +207,7 @@ impl InstallCliOptions {
                 }
                 "--create-database" => options.create_database = true,
                 "--pg-admin-url" => {
-                    options.pg_admin_url = Some(take_value(args, &mut index)?);
+                    options.pg_admin_url = Some(take_value(args, &mut index)?.into());
                 }
                 "--admin-email" => {
                     options.admin_email = take_value(args, &mut index)?;
@@ -271,7 +275,7 @@ impl InstallCliOptions {
         let database_url = match self.database_secret_ref {
             Some(reference) => SecretValue::Reference { reference },
             None => SecretValue::Plaintext {
-                value: self.database_url,
+                value: self.database_url.into_inner(),
             },
         };
 
@@ -327,7 +331,12 @@ pub async fn apply_plan(
     }
 
     let database_url = resolve_database_url(&plan)?;
-    let database_ready = prepare_database(&plan, &database_url, pg_admin_url.as_deref()).await?;
+    let database_ready = prepare_database(
+        &plan,
+        &database_url,
+        pg_admin_url.as_ref().map(RedactedUrl::expose),
+    )
+    .await?;
     apply_schema_migrations(&database_ready.connection).await?;
     let plan_snapshot = redact_install_plan(&plan);
     let db = database_ready.connection.clone();
@@ -563,14 +572,14 @@ async fn prepare_database(
 
     let connection = Database::connect(database_url)
         .await
-        .map_err(|error| eyre!("failed to connect installer database: {error}"))?;
+        .map_err(|error| database_error("failed to connect installer database", error))?;
     connection
         .query_one(Statement::from_string(
             connection.get_database_backend(),
             "SELECT 1".to_string(),
         ))
         .await
-        .map_err(|error| eyre!("failed installer database readiness query: {error}"))?;
+        .map_err(|error| database_error("failed installer database readiness query", error))?;
 
     Ok(DatabaseReady {
         connection,
@@ -788,6 +797,12 @@ struct DatabaseTarget {
     password: Option<String>,
 }
 
+/// Driver errors can echo the connection string; never let its password
+/// reach logs or the installer job status.
+fn database_error(context: &str, error: impl std::fmt::Display) -> eyre::Report {
+    eyre!("{context}: {}", redact_urls_in(&error.to_string()))
+}
+
 fn parse_database_target(engine: &DatabaseEngine, database_url: &str) -> Result<DatabaseTarget> {
     if *engine == DatabaseEngine::Sqlite {
         return Ok(DatabaseTarget {
@@ -835,7 +850,7 @@ async fn ensure_postgres_database(admin_url: &str, target: &DatabaseTarget) -> R
 
     let admin = Database::connect(admin_url)
         .await
-        .map_err(|error| eyre!("failed to connect postgres admin database: {error}"))?;
+        .map_err(|error| database_error("failed to connect postgres admin database", error))?;
     let role_exists = admin
         .query_one(Statement::from_string(
             DbBackend::Postgres,
@@ -0,0 +1 @@
+Receipt for order {{ order_id }}
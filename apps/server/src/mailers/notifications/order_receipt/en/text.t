@@ -0,0 +1,3 @@
+Thank you for your payment.
+Order: {{ order_id }}
+Total: {{ total_amount }} {{ currency_code }}
@@ -0,0 +1 @@
+Чек по заказу {{ order_id }}
@@ -0,0 +1,3 @@
+Спасибо за оплату.
+Заказ: {{ order_id }}
+Сумма: {{ total_amount }} {{ currency_code }}
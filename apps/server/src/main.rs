@@ -11,7 +11,7 @@ async fn main() -> eyre::Result<()> {
         rustok_telemetry::init(telemetry_cfg)?
     } else {
         // Loco owns the global tracing subscriber for CLI commands.
-        rustok_telemetry::init_metrics(telemetry_cfg.metrics)?
+        rustok_telemetry::init_metrics(&telemetry_cfg)?
     };
     let args = std::env::args().collect::<Vec<_>>();
     if rustok_server::installer_cli::try_handle(&args).await? {
@@ -51,5 +51,7 @@ fn telemetry_config() -> TelemetryConfig {
         log_format,
         metrics,
         otel,
+        histogram_buckets: Default::default(),
+        capture_panics: true,
     }
 }
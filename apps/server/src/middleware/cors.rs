@@ -0,0 +1,397 @@
+/// Tenant-aware CORS for the auth surface.
+///
+/// Browsers attach `Origin` to cross-origin requests. When
+/// `rustok.cors.enforce_auth_origins` is on, auth requests (REST `/api/auth/*`,
+/// native `/api/fn/auth/*` and `/api/graphql`) from an origin that is neither
+/// the server itself, a globally allowed origin, nor listed in the tenant's
+/// `settings.cors.allowed_origins` are rejected with `403` and the
+/// `origin_not_allowed` error code. Requests without `Origin` (server-side
+/// callers, CLI tools) are not affected. CORS headers are only emitted for
+/// allowed origins; with enforcement off both middlewares pass requests
+/// through untouched.
+///
+/// Preflights are answered before tenant resolution because browsers do not
+/// send tenant headers with them, so an origin passes the preflight when it is
+/// allowed globally or by any active tenant. The union of tenant origins is
+/// kept in the shared [`TenantOriginCache`] registered at bootstrap, reloaded
+/// after `tenant.created` / `tenant.updated` events or after
+/// [`TENANT_ORIGIN_CACHE_TTL`]; without it only global origins pass. The real
+/// request is then checked against its own tenant.
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, HeaderMap, HeaderValue, Method, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use loco_rs::app::AppContext;
+use rustok_core::events::{EventHandler, HandlerResult};
+use rustok_events::{DomainEvent, EventEnvelope};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use serde_json::json;
+use tokio::sync::RwLock;
+
+use crate::common::settings::{RustokSettings, SharedRustokSettings};
+use crate::context::TenantContextExtension;
+use crate::models::_entities::tenants;
+
+/// Error code returned to clients for a disallowed origin.
+pub const ORIGIN_NOT_ALLOWED: &str = "origin_not_allowed";
+
+const AUTH_PATH_PREFIXES: &[&str] = &["/api/auth/", "/api/fn/auth/"];
+const GRAPHQL_PATH: &str = "/api/graphql";
+const PREFLIGHT_MAX_AGE_SECS: &str = "600";
+/// Upper bound on how long a tenant origin change can go unseen by
+/// preflights, e.g. when the event was handled by another instance.
+pub const TENANT_ORIGIN_CACHE_TTL: Duration = Duration::from_secs(60);
+/// Request headers auth clients send; the tenant header from settings is
+/// appended.
+const PREFLIGHT_ALLOWED_HEADERS: &[&str] = &[
+    "accept",
+    "accept-language",
+    "authorization",
+    "content-type",
+    "x-channel-id",
+    "x-channel-slug",
+    "x-tenant-slug",
+];
+
+fn is_auth_path(path: &str) -> bool {
+    path == GRAPHQL_PATH
+        || AUTH_PATH_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+}
+
+/// Lowercased `scheme://host[:port]`, or `None` when `raw` is not a bare
+/// http(s) origin.
+pub fn normalize_origin(raw: &str) -> Option<String> {
+    let origin = raw.trim().trim_end_matches('/').to_ascii_lowercase();
+    let authority = origin
+        .strip_prefix("https://")
+        .or_else(|| origin.strip_prefix("http://"))?;
+    if authority.is_empty() || authority.contains(['/', '?', '#', '@']) {
+        return None;
+    }
+    Some(origin)
+}
+
+fn request_origin(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok())
+        .and_then(normalize_origin)
+}
+
+fn is_same_origin(origin: &str, headers: &HeaderMap) -> bool {
+    let Some(host) = headers
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    origin
+        .split_once("://")
+        .is_some_and(|(_, authority)| authority.eq_ignore_ascii_case(host.trim()))
+}
+
+/// Origins a tenant allows in `settings.cors.allowed_origins`.
+pub fn tenant_allowed_origins(tenant_settings: &serde_json::Value) -> Vec<String> {
+    tenant_settings
+        .pointer("/cors/allowed_origins")
+        .and_then(serde_json::Value::as_array)
+        .map(|origins| {
+            origins
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .filter_map(normalize_origin)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Origins allowed by any active tenant, loaded once and reused by
+/// preflights until invalidated or older than [`TENANT_ORIGIN_CACHE_TTL`].
+#[derive(Default)]
+pub struct TenantOriginCache {
+    origins: RwLock<Option<(Instant, Arc<HashSet<String>>)>>,
+}
+
+impl TenantOriginCache {
+    /// Whether any active tenant lists `origin` in `settings.cors.allowed_origins`.
+    async fn allows(&self, db: &DatabaseConnection, origin: &str) -> bool {
+        if let Some((loaded_at, origins)) = self.origins.read().await.as_ref() {
+            if loaded_at.elapsed() < TENANT_ORIGIN_CACHE_TTL {
+                return origins.contains(origin);
+            }
+        }
+
+        let mut cached = self.origins.write().await;
+        if let Some((loaded_at, origins)) = cached.as_ref() {
+            if loaded_at.elapsed() < TENANT_ORIGIN_CACHE_TTL {
+                return origins.contains(origin);
+            }
+        }
+        match load_tenant_origins(db).await {
+            Ok(origins) => {
+                let allowed = origins.contains(origin);
+                *cached = Some((Instant::now(), Arc::new(origins)));
+                allowed
+            }
+            Err(error) => {
+                tracing::warn!(error = %error, "Failed to load tenant CORS origins for preflight");
+                false
+            }
+        }
+    }
+
+    /// Drop the loaded set; the next preflight reloads it.
+    pub async fn invalidate(&self) {
+        self.origins.write().await.take();
+    }
+}
+
+async fn load_tenant_origins(db: &DatabaseConnection) -> Result<HashSet<String>, sea_orm::DbErr> {
+    let settings = tenants::Entity::find()
+        .select_only()
+        .column(tenants::Column::Settings)
+        .filter(tenants::Column::IsActive.eq(true))
+        .into_tuple::<serde_json::Value>()
+        .all(db)
+        .await?;
+    Ok(settings.iter().flat_map(tenant_allowed_origins).collect())
+}
+
+/// Host event handler that invalidates [`TenantOriginCache`] whenever a
+/// tenant is created or its settings change.
+pub struct TenantOriginCacheInvalidator {
+    cache: Arc<TenantOriginCache>,
+}
+
+impl TenantOriginCacheInvalidator {
+    pub fn new(cache: Arc<TenantOriginCache>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl EventHandler for TenantOriginCacheInvalidator {
+    fn name(&self) -> &'static str {
+        "cors_tenant_origins"
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        matches!(
+            event,
+            DomainEvent::TenantCreated { .. } | DomainEvent::TenantUpdated { .. }
+        )
+    }
+
+    async fn handle(&self, _envelope: &EventEnvelope) -> HandlerResult {
+        self.cache.invalidate().await;
+        Ok(())
+    }
+}
+
+fn preflight_allowed_headers(settings: &RustokSettings) -> String {
+    let tenant_header = settings.tenant.header_name.to_ascii_lowercase();
+    let mut allowed = PREFLIGHT_ALLOWED_HEADERS.to_vec();
+    if !allowed.contains(&tenant_header.as_str()) {
+        allowed.push(&tenant_header);
+    }
+    allowed.join(", ")
+}
+
+fn apply_cors_headers(headers: &mut HeaderMap, origin: &str) {
+    if let Ok(value) = HeaderValue::from_str(origin) {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, value);
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            HeaderValue::from_static("true"),
+        );
+        headers.append(header::VARY, HeaderValue::from_static("Origin"));
+    }
+}
+
+fn origin_not_allowed(origin: &str) -> Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(json!({
+            "error": ORIGIN_NOT_ALLOWED,
+            "message": format!("Origin {origin} is not allowed for this tenant"),
+        })),
+    )
+        .into_response()
+}
+
+/// Answer CORS preflights for auth paths. Mounted outside tenant resolution.
+pub async fn auth_preflight(
+    State(ctx): State<AppContext>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if req.method() != Method::OPTIONS || !is_auth_path(req.uri().path()) {
+        return next.run(req).await;
+    }
+    let Some(origin) = request_origin(req.headers()) else {
+        return next.run(req).await;
+    };
+    let Some(settings) = ctx.shared_store.get::<SharedRustokSettings>() else {
+        return next.run(req).await;
+    };
+    let settings = &settings.0;
+    if !settings.cors.enforce_auth_origins || is_same_origin(&origin, req.headers()) {
+        return next.run(req).await;
+    }
+
+    let allowed = settings.cors.allowed_origins.contains(&origin)
+        || match ctx.shared_store.get::<Arc<TenantOriginCache>>() {
+            Some(cache) => cache.allows(&ctx.db, &origin).await,
+            None => false,
+        };
+    if !allowed {
+        tracing::warn!(origin = %origin, "Rejecting auth preflight from disallowed origin");
+        return origin_not_allowed(&origin);
+    }
+
+    let mut response = StatusCode::NO_CONTENT.into_response();
+    let headers = response.headers_mut();
+    apply_cors_headers(headers, &origin);
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("GET, POST, OPTIONS"),
+    );
+    if let Ok(allowed_headers) = HeaderValue::from_str(&preflight_allowed_headers(settings)) {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, allowed_headers);
+    }
+    headers.insert(
+        header::ACCESS_CONTROL_MAX_AGE,
+        HeaderValue::from_static(PREFLIGHT_MAX_AGE_SECS),
+    );
+    response
+}
+
+/// Reject cross-origin auth requests from origins the tenant does not allow.
+/// Mounted inside tenant resolution.
+pub async fn enforce_auth_origin(
+    State(ctx): State<AppContext>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if !is_auth_path(req.uri().path()) {
+        return next.run(req).await;
+    }
+    let Some(origin) = request_origin(req.headers()) else {
+        return next.run(req).await;
+    };
+    if is_same_origin(&origin, req.headers()) {
+        return next.run(req).await;
+    }
+    let Some(settings) = ctx.shared_store.get::<SharedRustokSettings>() else {
+        return next.run(req).await;
+    };
+    let cors = &settings.0.cors;
+    if !cors.enforce_auth_origins {
+        return next.run(req).await;
+    }
+
+    let tenant = req.extensions().get::<TenantContextExtension>().cloned();
+    let allowed = cors.allowed_origins.contains(&origin)
+        || tenant
+            .as_ref()
+            .is_some_and(|tenant| tenant_allowed_origins(&tenant.0.settings).contains(&origin));
+    if !allowed {
+        tracing::warn!(
+            origin = %origin,
+            tenant_id = ?tenant.as_ref().map(|tenant| tenant.0.id),
+            "Rejecting auth request from disallowed origin"
+        );
+        return origin_not_allowed(&origin);
+    }
+
+    let mut response = next.run(req).await;
+    apply_cors_headers(response.headers_mut(), &origin);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_bare_origins_only() {
+        assert_eq!(
+            normalize_origin(" HTTPS://Admin.Example.com/ ").as_deref(),
+            Some("https://admin.example.com")
+        );
+        assert_eq!(
+            normalize_origin("http://localhost:3001").as_deref(),
+            Some("http://localhost:3001")
+        );
+        assert_eq!(normalize_origin("https://example.com/path"), None);
+        assert_eq!(normalize_origin("ftp://example.com"), None);
+        assert_eq!(normalize_origin("null"), None);
+    }
+
+    #[test]
+    fn reads_tenant_allowed_origins() {
+        let settings = json!({
+            "cors": { "allowed_origins": ["https://shop.example.com", "not an origin"] }
+        });
+
+        assert_eq!(
+            tenant_allowed_origins(&settings),
+            vec!["https://shop.example.com".to_string()]
+        );
+        assert!(tenant_allowed_origins(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn auth_paths_cover_rest_native_and_graphql() {
+        assert!(is_auth_path("/api/auth/login"));
+        assert!(is_auth_path("/api/fn/auth/sign-in"));
+        assert!(is_auth_path("/api/graphql"));
+        assert!(!is_auth_path("/api/graphql/ws"));
+        assert!(!is_auth_path("/api/fn/admin/dashboard-stats"));
+    }
+
+    #[test]
+    fn preflight_allows_fixed_headers_plus_tenant_header() {
+        let mut settings = RustokSettings::default();
+        settings.tenant.header_name = "X-Tenant-ID".to_string();
+
+        let allowed = preflight_allowed_headers(&settings);
+        assert!(allowed.contains("authorization"));
+        assert!(allowed.ends_with("x-tenant-id"));
+        assert!(!allowed.contains("x-user-id"));
+    }
+
+    #[test]
+    fn origin_cache_is_invalidated_by_tenant_events() {
+        let invalidator = TenantOriginCacheInvalidator::new(Arc::default());
+        let tenant_id = uuid::Uuid::new_v4();
+
+        assert!(invalidator.handles(&DomainEvent::TenantUpdated { tenant_id }));
+        assert!(invalidator.handles(&DomainEvent::TenantCreated { tenant_id }));
+        assert!(!invalidator.handles(&DomainEvent::TenantModuleToggled {
+            tenant_id,
+            module_slug: "blog".to_string(),
+            enabled: true,
+        }));
+    }
+
+    #[test]
+    fn same_origin_compares_authority_with_host() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::HOST, HeaderValue::from_static("api.example.com"));
+
+        assert!(is_same_origin("https://api.example.com", &headers));
+        assert!(!is_same_origin("https://evil.example.com", &headers));
+    }
+}
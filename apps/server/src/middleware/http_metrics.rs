@@ -0,0 +1,37 @@
+/// HTTP Metrics Middleware
+///
+/// Records `rustok_http_requests_total` and `rustok_http_request_duration_seconds`
+/// for every request. The `path` label is the matched route template
+/// (`/api/users/{id}`) taken from axum's `MatchedPath` and passed through
+/// `rustok_telemetry::metrics::normalize_path`; unmatched requests all
+/// share `rustok_telemetry::metrics::UNMATCHED_PATH_LABEL`, so raw paths never
+/// reach Prometheus as label values.
+///
+/// Mounted globally in `services::app_router` via `axum::middleware::from_fn`.
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use rustok_telemetry::metrics;
+use std::time::Instant;
+
+pub async fn track_http_metrics(request: Request, next: Next) -> Response {
+    let started_at = Instant::now();
+    let method = request.method().as_str().to_string();
+    let route_template = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string());
+
+    let response = next.run(request).await;
+
+    metrics::record_http_request(
+        &method,
+        route_template.as_deref(),
+        response.status().as_u16(),
+        started_at.elapsed().as_secs_f64(),
+    );
+
+    response
+}
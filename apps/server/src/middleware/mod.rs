@@ -1,6 +1,8 @@
 pub mod auth_context;
 pub mod block_rest_auth;
 pub mod channel;
+pub mod cors;
+pub mod http_metrics;
 pub mod locale;
 pub mod rate_limit;
 pub mod security_headers;
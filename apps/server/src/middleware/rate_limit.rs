@@ -22,6 +22,7 @@ use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
 use rustok_cache::CacheService;
+use rustok_core::{PeriodicJob, PeriodicJobHandle};
 
 use crate::auth::{decode_access_token, AuthConfig};
 use crate::common::{
@@ -733,13 +734,23 @@ pub async fn rate_limit_for_paths(
     }
 }
 
-pub async fn cleanup_task(limiter: Arc<RateLimiter>) {
-    let mut interval = tokio::time::interval(Duration::from_secs(300));
-
-    loop {
-        interval.tick().await;
-        limiter.cleanup_expired().await;
-    }
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Periodically drop expired in-memory entries until `stop_signal` is set.
+pub fn spawn_cleanup_job(
+    limiter: Arc<RateLimiter>,
+    stop_signal: tokio::sync::watch::Receiver<bool>,
+) -> PeriodicJobHandle {
+    let name = format!("rate_limit_cleanup_{}", limiter.namespace());
+    PeriodicJob::new(name, CLEANUP_INTERVAL, move || {
+        let limiter = limiter.clone();
+        async move {
+            limiter.cleanup_expired().await;
+            Ok::<(), std::convert::Infallible>(())
+        }
+    })
+    .with_stop_signal(stop_signal)
+    .spawn()
 }
 
 #[cfg(test)]
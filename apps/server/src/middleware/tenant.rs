@@ -694,9 +694,7 @@ pub async fn resolve(
     }
 
     if let Some(cached_context) = infra.get_cached_tenant(&cache_key).await? {
-        req.extensions_mut()
-            .insert(TenantContextExtension(cached_context));
-        return Ok(next.run(req).await);
+        return Ok(run_for_tenant(req, next, cached_context).await);
     }
 
     let db = ctx.db.clone();
@@ -738,8 +736,17 @@ pub async fn resolve(
         })
         .await?;
 
+    Ok(run_for_tenant(req, next, context).await)
+}
+
+/// Run the rest of the stack with `context` attached and count the request
+/// in the tenant's usage.
+async fn run_for_tenant(mut req: Request<Body>, next: Next, context: TenantContext) -> Response {
+    let tenant_id = context.id.to_string();
     req.extensions_mut().insert(TenantContextExtension(context));
-    Ok(next.run(req).await)
+    let response = next.run(req).await;
+    rustok_telemetry::metrics::record_tenant_http_request(&tenant_id, response.status().as_u16());
+    response
 }
 
 fn should_bypass_tenant_resolution(path: &str) -> bool {
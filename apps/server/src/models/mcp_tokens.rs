@@ -1,4 +1,5 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
+use rustok_core::TimeBounds;
 use sea_orm::{entity::prelude::*, Condition, QueryFilter, QueryOrder};
 
 pub use super::_entities::mcp_tokens::{ActiveModel, Column, Entity, Model, Relation};
@@ -7,6 +8,7 @@ impl Entity {
     pub async fn find_active_by_hash(
         db: &DatabaseConnection,
         token_hash: &str,
+        leeway: Duration,
     ) -> Result<Option<Model>, DbErr> {
         Self::find()
             .filter(
@@ -14,9 +16,11 @@ impl Entity {
                     .add(Column::TokenHash.eq(token_hash))
                     .add(Column::RevokedAt.is_null())
                     .add(
-                        Condition::any()
-                            .add(Column::ExpiresAt.is_null())
-                            .add(Column::ExpiresAt.gt(Utc::now())),
+                        Condition::any().add(Column::ExpiresAt.is_null()).add(
+                            Column::ExpiresAt.gt(TimeBounds::default()
+                                .with_leeway(leeway)
+                                .expiry_cutoff(Utc::now())),
+                        ),
                     ),
             )
             .one(db)
@@ -36,15 +40,14 @@ impl Entity {
 }
 
 impl Model {
-    pub fn is_active(&self) -> bool {
+    pub fn is_active(&self, leeway: Duration) -> bool {
         let not_revoked = self.revoked_at.is_none();
-        let not_expired = self
-            .expires_at
-            .map(|expires_at| {
-                let expires_at_utc: chrono::DateTime<Utc> = expires_at.into();
-                expires_at_utc > Utc::now()
-            })
-            .unwrap_or(true);
+        let not_expired = TimeBounds::until_opt(
+            self.expires_at
+                .map(|expires_at| expires_at.with_timezone(&Utc)),
+        )
+        .with_leeway(leeway)
+        .is_valid();
         not_revoked && not_expired
     }
 }
@@ -1,14 +1,15 @@
+use rustok_core::TimeBounds;
 use sea_orm::prelude::*;
 
 use super::_entities::oauth_authorization_codes::{self};
 pub use super::_entities::oauth_authorization_codes::{ActiveModel, Column, Entity, Model};
 
 impl Model {
-    pub fn is_active(&self) -> bool {
-        let now_utc = chrono::Utc::now();
-        let expires_at_utc = self.expires_at.with_timezone(&chrono::Utc);
-
-        self.used_at.is_none() && expires_at_utc > now_utc
+    pub fn is_active(&self, leeway: chrono::Duration) -> bool {
+        self.used_at.is_none()
+            && TimeBounds::until(self.expires_at.with_timezone(&chrono::Utc))
+                .with_leeway(leeway)
+                .is_valid()
     }
 
     pub fn scopes_list(&self) -> Vec<String> {
@@ -1,14 +1,17 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sea_orm::prelude::*;
 
-use rustok_core::generate_id;
+use rustok_core::{generate_id, TimeBounds};
 
 use super::_entities::sessions::{self};
 pub use super::_entities::sessions::{ActiveModel, Column, Entity, Model};
 
 impl Model {
-    pub fn is_active(&self) -> bool {
-        self.revoked_at.is_none() && self.expires_at > Utc::now()
+    pub fn is_active(&self, leeway: Duration) -> bool {
+        self.revoked_at.is_none()
+            && TimeBounds::until(self.expires_at.with_timezone(&Utc))
+                .with_leeway(leeway)
+                .is_valid()
     }
 }
 
@@ -2,6 +2,7 @@ use loco_rs::{app::AppContext, config::Config};
 
 use crate::error::{Error, Result};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::task::JoinHandle;
 
@@ -16,37 +17,46 @@ use crate::services::registry_governance::RegistryGovernanceService;
 use crate::services::release_backend::ReleaseDeploymentService;
 #[cfg(feature = "mod-seo")]
 use rustok_api::loco::transactional_event_bus_from_context;
+use rustok_core::{
+    PeriodicJob, PeriodicJobHandle, ShutdownCoordinator, ShutdownPhase, ShutdownToken,
+};
 #[cfg(feature = "mod-seo")]
 use rustok_seo::SeoService;
 
-// ── Graceful-shutdown handle ──────────────────────────────────────────────────
+// ── Graceful shutdown ────────────────────────────────────────────────────────
 
-/// Stored in `ctx.shared_store`; `on_shutdown` calls `stop()` to abort workers.
-#[derive(Clone)]
-pub struct StopHandle {
-    stop_tx: tokio::sync::watch::Sender<bool>,
-}
+/// Stored in `ctx.shared_store`; `on_shutdown` runs it to stop workers in
+/// phase order.
+#[derive(Clone, Debug)]
+pub struct SharedShutdownCoordinator(pub ShutdownCoordinator);
 
-impl StopHandle {
-    pub fn new() -> (Self, tokio::sync::watch::Receiver<bool>) {
-        let (tx, rx) = tokio::sync::watch::channel(false);
-        (Self { stop_tx: tx }, rx)
+/// Shutdown coordinator shared by every background task, registering it on
+/// first use.
+pub fn shutdown_coordinator_from_context(ctx: &AppContext) -> ShutdownCoordinator {
+    if let Some(shared) = ctx.shared_store.get::<SharedShutdownCoordinator>() {
+        return shared.0;
     }
 
-    /// Create a new `Receiver` subscribed to the shutdown signal.
-    ///
-    /// The returned receiver immediately sees the current value and will be
-    /// notified when [`StopHandle::stop`] is called.  Clone it once per background worker
-    /// so each worker gets its own independent view of the channel.
-    pub fn subscribe(&self) -> tokio::sync::watch::Receiver<bool> {
-        self.stop_tx.subscribe()
-    }
+    let coordinator = ShutdownCoordinator::new();
+    ctx.shared_store
+        .insert(SharedShutdownCoordinator(coordinator.clone()));
+    coordinator
+}
 
-    pub async fn stop(&self) {
-        let _ = self.stop_tx.send(true);
-        // Yield so spawned tasks have a chance to notice the signal.
-        tokio::task::yield_now().await;
-    }
+/// Producer-phase stop signal for best-effort background tasks that are not
+/// awaited on shutdown. Each call returns an independent receiver.
+pub fn stop_signal_from_context(ctx: &AppContext) -> tokio::sync::watch::Receiver<bool> {
+    shutdown_coordinator_from_context(ctx).signal(ShutdownPhase::Producers)
+}
+
+/// Stop `job` once `token` is triggered and acknowledge after its current
+/// run has finished.
+fn stop_job_on_shutdown(mut token: ShutdownToken, job: PeriodicJobHandle) {
+    tokio::spawn(async move {
+        token.triggered().await;
+        job.stop().await;
+        token.acknowledge();
+    });
 }
 
 static OUTBOX_RELAY_WORKER_INSTANCE_IDS: AtomicU64 = AtomicU64::new(1);
@@ -72,7 +82,6 @@ impl OutboxRelayWorkerHandle {
 
 pub struct BuildWorkerHandle {
     instance_id: u64,
-    _handle: JoinHandle<()>,
 }
 
 impl BuildWorkerHandle {
@@ -83,7 +92,6 @@ impl BuildWorkerHandle {
 
 pub struct RemoteExecutorReaperHandle {
     instance_id: u64,
-    _handle: JoinHandle<()>,
 }
 
 impl RemoteExecutorReaperHandle {
@@ -95,7 +103,6 @@ impl RemoteExecutorReaperHandle {
 #[cfg(feature = "mod-seo")]
 pub struct SeoBulkWorkerHandle {
     instance_id: u64,
-    _handle: JoinHandle<()>,
 }
 
 #[cfg(feature = "mod-seo")]
@@ -128,20 +135,7 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
         return Ok(());
     }
 
-    // Register graceful-shutdown handle if not already present.
-    if !ctx.shared_store.contains::<StopHandle>() {
-        let (handle, _rx) = StopHandle::new();
-        ctx.shared_store.insert(handle);
-    }
-
-    // Obtain a stop receiver from the stored handle so workers can observe
-    // the shutdown signal.  `subscribe()` creates a new independent receiver
-    // from the existing sender — safe to call multiple times.
-    let stop_handle = ctx
-        .shared_store
-        .get_ref::<StopHandle>()
-        .expect("StopHandle must be registered before spawning workers");
-    let stop_rx = stop_handle.subscribe();
+    let shutdown = shutdown_coordinator_from_context(ctx);
 
     if ctx.shared_store.contains::<OutboxRelayWorkerHandle>() {
         // Keep going: build worker may still need to be attached.
@@ -152,8 +146,10 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
             .ok_or_else(|| Error::Message("EventRuntime not initialized".to_string()))?;
 
         if let Some(relay_config) = event_runtime.relay_config.clone() {
-            ctx.shared_store
-                .insert(spawn_relay_worker_handle(relay_config, stop_rx.clone()));
+            ctx.shared_store.insert(spawn_relay_worker_handle(
+                relay_config,
+                shutdown.token("outbox_relay", ShutdownPhase::Flush),
+            ));
         }
     }
 
@@ -161,7 +157,7 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
         ctx.shared_store.insert(spawn_build_worker_handle(
             ctx.clone(),
             settings.build,
-            stop_rx.clone(),
+            shutdown.token("build_worker", ShutdownPhase::Producers),
         ));
     }
 
@@ -171,14 +167,16 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
         ctx.shared_store.insert(spawn_remote_executor_reaper_handle(
             ctx.clone(),
             settings.registry.remote_executor.requeue_scan_interval_ms,
-            stop_rx.clone(),
+            shutdown.token("remote_executor_reaper", ShutdownPhase::Producers),
         ));
     }
 
     #[cfg(feature = "mod-seo")]
     if seo_bulk_worker_enabled && !ctx.shared_store.contains::<SeoBulkWorkerHandle>() {
-        ctx.shared_store
-            .insert(spawn_seo_bulk_worker_handle(ctx.clone(), stop_rx.clone()));
+        ctx.shared_store.insert(spawn_seo_bulk_worker_handle(
+            ctx.clone(),
+            shutdown.token("seo_bulk_worker", ShutdownPhase::Producers),
+        ));
     } else if !seo_bulk_worker_enabled {
         tracing::info!("SEO bulk worker disabled by runtime.background_workers config");
     }
@@ -188,201 +186,150 @@ pub async fn connect_runtime_workers(ctx: &AppContext) -> Result<()> {
 
 fn spawn_relay_worker_handle(
     relay_config: RelayRuntimeConfig,
-    stop_rx: tokio::sync::watch::Receiver<bool>,
+    shutdown: ShutdownToken,
 ) -> OutboxRelayWorkerHandle {
     OutboxRelayWorkerHandle {
         instance_id: OUTBOX_RELAY_WORKER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
-        _handle: spawn_outbox_relay_worker(relay_config, stop_rx),
+        _handle: spawn_outbox_relay_worker(relay_config, shutdown),
     }
 }
 
 fn spawn_build_worker_handle(
     ctx: AppContext,
     config: crate::common::settings::BuildRuntimeSettings,
-    stop_rx: tokio::sync::watch::Receiver<bool>,
+    shutdown: ShutdownToken,
 ) -> BuildWorkerHandle {
+    let executor = Arc::new(BuildExecutionService::new(&ctx));
+    let release_backend = Arc::new(ReleaseDeploymentService::new(&ctx, config.clone()));
+    let poll_interval = Duration::from_millis(config.poll_interval_ms);
+    let job = PeriodicJob::new("build_worker", poll_interval, move || {
+        let executor = executor.clone();
+        let release_backend = release_backend.clone();
+        let config = config.clone();
+        async move { execute_next_queued_build(&executor, &release_backend, &config).await }
+    });
+    stop_job_on_shutdown(shutdown, job.spawn());
+
     BuildWorkerHandle {
         instance_id: BUILD_WORKER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
-        _handle: tokio::spawn(build_worker_loop(ctx, config, stop_rx)),
     }
 }
 
 fn spawn_remote_executor_reaper_handle(
     ctx: AppContext,
     scan_interval_ms: u64,
-    stop_rx: tokio::sync::watch::Receiver<bool>,
+    shutdown: ShutdownToken,
 ) -> RemoteExecutorReaperHandle {
+    let governance = Arc::new(RegistryGovernanceService::new(ctx.db.clone()));
+    let poll_interval = Duration::from_millis(scan_interval_ms.max(1));
+    let job = PeriodicJob::new("remote_executor_reaper", poll_interval, move || {
+        let governance = governance.clone();
+        async move {
+            let requeued = governance
+                .requeue_expired_remote_validation_claims()
+                .await?;
+            if requeued > 0 {
+                tracing::info!(
+                    requeued,
+                    "Remote executor reaper requeued expired validation stage claims"
+                );
+            }
+            Ok::<(), anyhow::Error>(())
+        }
+    });
+    stop_job_on_shutdown(shutdown, job.spawn());
+
     RemoteExecutorReaperHandle {
         instance_id: REMOTE_EXECUTOR_REAPER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
-        _handle: tokio::spawn(remote_executor_reaper_loop(ctx, scan_interval_ms, stop_rx)),
     }
 }
 
 #[cfg(feature = "mod-seo")]
-fn spawn_seo_bulk_worker_handle(
-    ctx: AppContext,
-    stop_rx: tokio::sync::watch::Receiver<bool>,
-) -> SeoBulkWorkerHandle {
-    SeoBulkWorkerHandle {
-        instance_id: SEO_BULK_WORKER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
-        _handle: tokio::spawn(seo_bulk_worker_loop(ctx, stop_rx)),
-    }
-}
-
-async fn build_worker_loop(
-    ctx: AppContext,
-    config: crate::common::settings::BuildRuntimeSettings,
-    mut stop_rx: tokio::sync::watch::Receiver<bool>,
-) {
-    let executor = BuildExecutionService::new(&ctx);
-    let release_backend = ReleaseDeploymentService::new(&ctx, config.clone());
-    let poll_interval = Duration::from_millis(config.poll_interval_ms);
-
-    loop {
-        // Check for shutdown before doing any work so a stop signal received
-        // before the first iteration is honoured immediately.
-        if *stop_rx.borrow() {
-            tracing::info!("Build worker received shutdown signal, exiting");
-            return;
-        }
-
-        match executor.execute_next_queued_build(false).await {
-            Ok(Some(report)) => {
-                tracing::info!(
-                    build_id = %report.build_id,
-                    cargo_command = %report.cargo_command,
-                    "Executed queued build plan"
-                );
-
-                if report.status == "success" {
-                    if let Some(environment) = config.auto_release_environment.as_deref() {
-                        match executor
-                            .ensure_release_for_build(report.build_id, environment, false)
-                            .await
-                        {
-                            Ok(release) => match release_backend
-                                .publish_release(&release.id, config.auto_activate_release)
-                                .await
-                            {
-                                Ok(published_release) => tracing::info!(
-                                    build_id = %report.build_id,
-                                    release_id = %published_release.id,
-                                    release_status = ?published_release.status,
-                                    "Published release from successful build"
-                                ),
-                                Err(error) => tracing::error!(
-                                    build_id = %report.build_id,
-                                    release_id = %release.id,
-                                    error = %error,
-                                    "Failed to publish release from successful build"
-                                ),
-                            },
-                            Err(error) => tracing::error!(
-                                build_id = %report.build_id,
-                                error = %error,
-                                "Failed to create release record from successful build"
-                            ),
-                        }
+fn spawn_seo_bulk_worker_handle(ctx: AppContext, shutdown: ShutdownToken) -> SeoBulkWorkerHandle {
+    let event_bus = transactional_event_bus_from_context(&ctx);
+    let runtime_extensions = module_runtime_extensions_from_ctx(&ctx);
+    match SeoService::from_runtime_extensions(ctx.db.clone(), event_bus, &runtime_extensions) {
+        Ok(service) => {
+            let service = Arc::new(service);
+            let poll_interval = Duration::from_millis(SEO_BULK_WORKER_POLL_INTERVAL_MS);
+            let job = PeriodicJob::new("seo_bulk_worker", poll_interval, move || {
+                let service = service.clone();
+                async move {
+                    if let Some(job) = service.execute_next_bulk_job().await? {
+                        tracing::info!(
+                            job_id = %job.id,
+                            operation = %job.operation_kind.as_str(),
+                            status = %job.status.as_str(),
+                            "Executed queued SEO bulk job"
+                        );
                     }
+                    Ok::<(), rustok_seo::SeoError>(())
                 }
-            }
-            Ok(None) => {}
-            Err(error) => {
-                tracing::error!(error = %error, "Background build worker failed to execute queued build");
-            }
+            });
+            stop_job_on_shutdown(shutdown, job.spawn());
         }
-
-        // Wait for the next poll interval or a shutdown signal — whichever
-        // comes first.  This replaces the unconditional sleep so the worker
-        // exits promptly rather than waiting a full poll interval.
-        tokio::select! {
-            _ = tokio::time::sleep(poll_interval) => {}
-            _ = stop_rx.changed() => {
-                tracing::info!("Build worker received shutdown signal, exiting");
-                return;
-            }
+        Err(error) => {
+            tracing::error!(error = %error, "Failed to initialize SEO bulk worker registry");
         }
     }
-}
-
-async fn remote_executor_reaper_loop(
-    ctx: AppContext,
-    scan_interval_ms: u64,
-    mut stop_rx: tokio::sync::watch::Receiver<bool>,
-) {
-    let governance = RegistryGovernanceService::new(ctx.db.clone());
-    let poll_interval = Duration::from_millis(scan_interval_ms.max(1));
 
-    loop {
-        if *stop_rx.borrow() {
-            tracing::info!("Remote executor reaper received shutdown signal, exiting");
-            return;
-        }
-
-        match governance.requeue_expired_remote_validation_claims().await {
-            Ok(requeued) if requeued > 0 => tracing::info!(
-                requeued,
-                "Remote executor reaper requeued expired validation stage claims"
-            ),
-            Ok(_) => {}
-            Err(error) => tracing::error!(
-                error = %error,
-                "Remote executor reaper failed to process expired validation stage claims"
-            ),
-        }
-
-        tokio::select! {
-            _ = tokio::time::sleep(poll_interval) => {}
-            _ = stop_rx.changed() => {
-                tracing::info!("Remote executor reaper received shutdown signal, exiting");
-                return;
-            }
-        }
+    SeoBulkWorkerHandle {
+        instance_id: SEO_BULK_WORKER_INSTANCE_IDS.fetch_add(1, Ordering::Relaxed),
     }
 }
 
-#[cfg(feature = "mod-seo")]
-async fn seo_bulk_worker_loop(ctx: AppContext, mut stop_rx: tokio::sync::watch::Receiver<bool>) {
-    let event_bus = transactional_event_bus_from_context(&ctx);
-    let runtime_extensions = module_runtime_extensions_from_ctx(&ctx);
-    let service =
-        match SeoService::from_runtime_extensions(ctx.db.clone(), event_bus, &runtime_extensions) {
-            Ok(service) => service,
-            Err(error) => {
-                tracing::error!(error = %error, "Failed to initialize SEO bulk worker registry");
-                return;
-            }
-        };
-    let poll_interval = Duration::from_millis(SEO_BULK_WORKER_POLL_INTERVAL_MS);
-
-    loop {
-        if *stop_rx.borrow() {
-            tracing::info!("SEO bulk worker received shutdown signal, exiting");
-            return;
-        }
+/// Run the next queued build, if any, and publish a release for it when
+/// `auto_release_environment` is configured. Release failures are logged and
+/// do not fail the run.
+async fn execute_next_queued_build(
+    executor: &BuildExecutionService,
+    release_backend: &ReleaseDeploymentService,
+    config: &crate::common::settings::BuildRuntimeSettings,
+) -> anyhow::Result<()> {
+    let Some(report) = executor.execute_next_queued_build(false).await? else {
+        return Ok(());
+    };
+    tracing::info!(
+        build_id = %report.build_id,
+        cargo_command = %report.cargo_command,
+        "Executed queued build plan"
+    );
 
-        match service.execute_next_bulk_job().await {
-            Ok(Some(job)) => tracing::info!(
-                job_id = %job.id,
-                operation = %job.operation_kind.as_str(),
-                status = %job.status.as_str(),
-                "Executed queued SEO bulk job"
+    if report.status != "success" {
+        return Ok(());
+    }
+    let Some(environment) = config.auto_release_environment.as_deref() else {
+        return Ok(());
+    };
+    match executor
+        .ensure_release_for_build(report.build_id, environment, false)
+        .await
+    {
+        Ok(release) => match release_backend
+            .publish_release(&release.id, config.auto_activate_release)
+            .await
+        {
+            Ok(published_release) => tracing::info!(
+                build_id = %report.build_id,
+                release_id = %published_release.id,
+                release_status = ?published_release.status,
+                "Published release from successful build"
             ),
-            Ok(None) => {}
             Err(error) => tracing::error!(
+                build_id = %report.build_id,
+                release_id = %release.id,
                 error = %error,
-                "SEO bulk worker failed to execute queued job"
+                "Failed to publish release from successful build"
             ),
-        }
-
-        tokio::select! {
-            _ = tokio::time::sleep(poll_interval) => {}
-            _ = stop_rx.changed() => {
-                tracing::info!("SEO bulk worker received shutdown signal, exiting");
-                return;
-            }
-        }
+        },
+        Err(error) => tracing::error!(
+            build_id = %report.build_id,
+            error = %error,
+            "Failed to create release record from successful build"
+        ),
     }
+
+    Ok(())
 }
 
 fn should_use_local_sqlite_fallback(database_url_present: bool, current_uri: &str) -> bool {
@@ -470,9 +417,9 @@ mod tests {
         assert_eq!(first_instance_id, second_instance_id);
 
         // Gracefully shut down background workers to avoid hanging tests
-        if let Some(stop_handle) = ctx.shared_store.get_ref::<super::StopHandle>() {
-            stop_handle.stop().await;
-        };
+        super::shutdown_coordinator_from_context(&ctx)
+            .shutdown(Duration::from_secs(5))
+            .await;
     }
 
     #[tokio::test]
@@ -515,8 +462,8 @@ mod tests {
         assert_eq!(first_instance_id, second_instance_id);
 
         // Gracefully shut down background workers to avoid hanging tests
-        if let Some(stop_handle) = ctx.shared_store.get_ref::<super::StopHandle>() {
-            stop_handle.stop().await;
-        };
+        super::shutdown_coordinator_from_context(&ctx)
+            .shutdown(Duration::from_secs(5))
+            .await;
     }
 }
@@ -145,6 +145,9 @@ pub fn compose_application_router(
             ))
             .layer(axum_middleware::from_fn(
                 middleware::security_headers::security_headers,
+            ))
+            .layer(axum_middleware::from_fn(
+                middleware::http_metrics::track_http_metrics,
             ));
     }
 
@@ -199,13 +202,26 @@ pub fn compose_application_router(
         ctx.clone(),
         middleware::locale::resolve_locale,
     ))
+    // The origin check needs the resolved tenant, while preflights carry no
+    // tenant headers and must be answered before tenant resolution.
+    .layer(axum_middleware::from_fn_with_state(
+        ctx.clone(),
+        middleware::cors::enforce_auth_origin,
+    ))
     .layer(axum_middleware::from_fn_with_state(
         ctx.clone(),
         middleware::tenant::resolve,
     ))
+    .layer(axum_middleware::from_fn_with_state(
+        ctx.clone(),
+        middleware::cors::auth_preflight,
+    ))
     .layer(axum_middleware::from_fn(
         middleware::security_headers::security_headers,
     ))
+    .layer(axum_middleware::from_fn(
+        middleware::http_metrics::track_http_metrics,
+    ))
 }
 
 #[cfg(test)]
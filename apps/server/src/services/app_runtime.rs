@@ -9,12 +9,15 @@ use crate::auth::auth_config_from_ctx;
 use crate::common::settings::{RustokSettings, SharedRustokSettings};
 use crate::graphql::AppSchema;
 use crate::middleware;
+use crate::middleware::cors::{TenantOriginCache, TenantOriginCacheInvalidator};
 use crate::middleware::rate_limit::{
-    cleanup_task, PathRateLimitMiddlewareState, PathRateLimitPolicy, RateLimitConfig, RateLimiter,
-    SharedApiRateLimiter, SharedAuthRateLimiter, SharedOAuthRateLimiter, SharedSearchRateLimiter,
+    spawn_cleanup_job, PathRateLimitMiddlewareState, PathRateLimitPolicy, RateLimitConfig,
+    RateLimiter, SharedApiRateLimiter, SharedAuthRateLimiter, SharedOAuthRateLimiter,
+    SharedSearchRateLimiter,
 };
 use crate::modules;
 use crate::modules::{DeploymentSurfaceContract, ManifestManager};
+use crate::services::app_lifecycle::stop_signal_from_context;
 use crate::services::content_orchestration::init_content_orchestration;
 use crate::services::event_transport_factory::build_event_runtime;
 use crate::services::graphql_schema::init_graphql_schema;
@@ -24,6 +27,7 @@ use crate::services::marketplace_catalog::{
 use crate::services::module_event_dispatcher::{
     build_shared_runtime_extensions, spawn_module_event_dispatcher,
 };
+use crate::services::notifications::{build_notification_router, NotificationEventHandler};
 use crate::services::oauth_app::sync_manifest_managed_apps_for_all_tenants;
 use crate::services::platform_composition::PlatformCompositionService;
 use rustok_cache::CacheService;
@@ -62,6 +66,10 @@ pub async fn bootstrap_app_runtime(
 ) -> Result<AppRuntimeBootstrap> {
     let cache_service = CacheService::from_url(settings.cache.redis_url.as_deref());
     ctx.shared_store.insert(cache_service.clone());
+    #[cfg(feature = "mod-content")]
+    crate::services::content_nodes::init_content_read_cache(ctx, &cache_service).await;
+    let tenant_origins = Arc::new(TenantOriginCache::default());
+    ctx.shared_store.insert(tenant_origins.clone());
 
     // Cache parsed settings so per-request middleware avoids repeated JSON deserialization.
     ctx.shared_store
@@ -107,10 +115,30 @@ pub async fn bootstrap_app_runtime(
     if !settings.runtime.is_registry_only() {
         let event_runtime = build_event_runtime(ctx).await?;
         ctx.shared_store.insert(event_runtime.transport.clone());
-        spawn_module_event_dispatcher(ctx, &registry, runtime_extensions.clone());
+        let mut host_handlers: Vec<Arc<dyn rustok_core::events::EventHandler>> = vec![Arc::new(
+            TenantOriginCacheInvalidator::new(tenant_origins.clone()),
+        )];
+        if let Some(router) = build_notification_router(ctx, settings)? {
+            ctx.shared_store.insert(router.clone());
+            host_handlers.push(Arc::new(NotificationEventHandler::new(
+                ctx.db.clone(),
+                router,
+            )));
+        }
+        spawn_module_event_dispatcher(
+            ctx,
+            &registry,
+            runtime_extensions.clone(),
+            &settings.events.dispatcher,
+            host_handlers,
+        );
         ctx.shared_store.insert(Arc::new(event_runtime));
-        ctx.shared_store
-            .insert(crate::services::mcp_runtime::DbBackedMcpRuntimeBridge::shared(ctx.db.clone()));
+        let mut mcp_bridge =
+            crate::services::mcp_runtime::DbBackedMcpRuntimeBridge::new(ctx.db.clone());
+        if let Ok(auth_config) = auth_config_from_ctx(ctx) {
+            mcp_bridge = mcp_bridge.with_clock_leeway(auth_config.clock_leeway());
+        }
+        ctx.shared_store.insert(Arc::new(mcp_bridge));
         sync_manifest_managed_apps_for_all_tenants(&ctx.db, &manifest)
             .await
             .map_err(|error| {
@@ -205,12 +233,7 @@ fn init_workflow_runtime(ctx: &AppContext) {
 
     // Start the cron scheduler
     let scheduler = WorkflowCronScheduler::new(db);
-    let handle = scheduler.start();
-    tokio::spawn(async move {
-        if let Err(error) = handle.await {
-            tracing::error!("Workflow cron scheduler panicked: {:?}", error);
-        }
-    });
+    scheduler.start(stop_signal_from_context(ctx));
 
     tracing::info!("Workflow runtime initialized (cron scheduler)");
 }
@@ -344,10 +367,7 @@ fn build_namespaced_rate_limiter(
     }
 
     if settings.rate_limit.enabled {
-        let limiter_for_cleanup = limiter.clone();
-        tokio::spawn(async move {
-            cleanup_task(limiter_for_cleanup).await;
-        });
+        spawn_cleanup_job(limiter.clone(), stop_signal_from_context(ctx));
     }
 
     Ok(limiter)
@@ -1,17 +1,23 @@
 use crate::error::Error;
+use axum::http::StatusCode;
 use chrono::{Duration, Utc};
 use loco_rs::app::AppContext;
+use rustok_api::ApiError;
 use sea_orm::{
     sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
     QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 
 use crate::auth::{
-    auth_config_from_ctx, decode_password_reset_token, encode_access_token, generate_refresh_token,
-    hash_password, hash_refresh_token, verify_password, AuthConfig,
+    auth_config_from_ctx, decode_email_verification_token, decode_password_reset_token,
+    encode_access_token, encode_email_verification_token, generate_refresh_token,
+    hash_refresh_token, password_hasher_from_ctx, AuthConfig, PasswordHasher,
 };
+use crate::common::settings::RustokSettings;
 use crate::context::infer_user_role_from_permissions;
 use crate::models::{sessions, users};
+use crate::services::email::{email_service_from_ctx, EmailVerificationEmail};
+use rustok_core::{TimeBounds, UserStatus};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::rbac_service::RbacService;
@@ -32,6 +38,8 @@ pub enum AuthLifecycleError {
     SessionExpired,
     UserNotFound,
     InvalidResetToken,
+    InvalidVerificationToken,
+    EmailNotVerified,
     Internal(Error),
 }
 
@@ -53,31 +61,62 @@ impl From<rustok_auth::AuthError> for AuthLifecycleError {
     }
 }
 
+impl AuthLifecycleError {
+    /// Stable code in the `rustok_core::CodedError` format; `rustok_core::i18n`
+    /// carries translations for every code listed here.
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            AuthLifecycleError::EmailAlreadyExists => "auth.email_already_exists",
+            AuthLifecycleError::InvalidCredentials => rustok_api::AUTH_INVALID_CREDENTIALS_CODE,
+            AuthLifecycleError::UserInactive => "auth.user_inactive",
+            AuthLifecycleError::InvalidRefreshToken => "auth.invalid_refresh_token",
+            AuthLifecycleError::SessionExpired => "auth.session_expired",
+            AuthLifecycleError::UserNotFound => "auth.user_not_found",
+            AuthLifecycleError::InvalidResetToken => "auth.invalid_reset_token",
+            AuthLifecycleError::InvalidVerificationToken => "auth.invalid_verification_token",
+            AuthLifecycleError::EmailNotVerified => "auth.email_not_verified",
+            AuthLifecycleError::Internal(_) => "auth.internal",
+        }
+    }
+}
+
 impl From<AuthLifecycleError> for Error {
     fn from(value: AuthLifecycleError) -> Self {
-        match value {
+        let code = value.error_code();
+        let (status, message) = match value {
             AuthLifecycleError::EmailAlreadyExists => {
-                Error::BadRequest("Email already exists".into())
+                (StatusCode::BAD_REQUEST, "Email already exists")
             }
             AuthLifecycleError::InvalidCredentials => {
-                Error::Unauthorized("Invalid credentials".into())
+                (StatusCode::UNAUTHORIZED, "Invalid credentials")
             }
-            AuthLifecycleError::UserInactive => Error::Unauthorized("User is inactive".into()),
+            AuthLifecycleError::UserInactive => (StatusCode::UNAUTHORIZED, "User is inactive"),
             AuthLifecycleError::InvalidRefreshToken => {
-                Error::Unauthorized("Invalid refresh token".into())
+                (StatusCode::UNAUTHORIZED, "Invalid refresh token")
             }
-            AuthLifecycleError::SessionExpired => Error::Unauthorized("Session expired".into()),
-            AuthLifecycleError::UserNotFound => Error::Unauthorized("User not found".into()),
+            AuthLifecycleError::SessionExpired => (StatusCode::UNAUTHORIZED, "Session expired"),
+            AuthLifecycleError::UserNotFound => (StatusCode::UNAUTHORIZED, "User not found"),
             AuthLifecycleError::InvalidResetToken => {
-                Error::Unauthorized("Invalid reset token".into())
+                (StatusCode::UNAUTHORIZED, "Invalid reset token")
             }
-            AuthLifecycleError::Internal(err) => err,
-        }
+            AuthLifecycleError::InvalidVerificationToken => {
+                (StatusCode::UNAUTHORIZED, "Invalid verification token")
+            }
+            AuthLifecycleError::EmailNotVerified => {
+                (StatusCode::FORBIDDEN, "Email address is not verified")
+            }
+            AuthLifecycleError::Internal(err) => return err,
+        };
+        ApiError::new(code, message).into_loco_error(status)
     }
 }
 
 pub struct AuthLifecycleService;
 
+/// Lifetime of email verification tokens issued by
+/// [`AuthLifecycleService::request_email_verification`].
+pub const EMAIL_VERIFICATION_TOKEN_TTL_SECS: u64 = 24 * 60 * 60;
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct AuthLifecycleMetricsSnapshot {
     pub password_reset_sessions_revoked_total: u64,
@@ -125,11 +164,24 @@ impl AuthLifecycleService {
         role: rustok_core::UserRole,
         status: Option<rustok_core::UserStatus>,
     ) -> std::result::Result<users::Model, AuthLifecycleError> {
-        Self::create_user_db(&ctx.db, tenant_id, email, password, name, role, status).await
+        let hasher = password_hasher_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
+        Self::create_user_db(
+            &ctx.db,
+            hasher.as_ref(),
+            tenant_id,
+            email,
+            password,
+            name,
+            role,
+            status,
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn create_user_db(
         db: &DatabaseConnection,
+        hasher: &dyn PasswordHasher,
         tenant_id: uuid::Uuid,
         email: &str,
         password: &str,
@@ -145,7 +197,7 @@ impl AuthLifecycleService {
             return Err(AuthLifecycleError::EmailAlreadyExists);
         }
 
-        let password_hash = hash_password(password).map_err(AuthLifecycleError::from)?;
+        let password_hash = hasher.hash(password).map_err(AuthLifecycleError::from)?;
 
         let tx = db.begin().await.map_err(AuthLifecycleError::from)?;
 
@@ -251,16 +303,26 @@ impl AuthLifecycleService {
         user_agent: Option<String>,
     ) -> std::result::Result<(users::Model, AuthTokens), AuthLifecycleError> {
         let config = auth_config_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
+        let hasher = password_hasher_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
 
         Self::login_with_config(
-            &ctx.db, &config, tenant_id, email, password, ip_address, user_agent,
+            &ctx.db,
+            &config,
+            hasher.as_ref(),
+            tenant_id,
+            email,
+            password,
+            ip_address,
+            user_agent,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn login_with_config(
         db: &DatabaseConnection,
         config: &AuthConfig,
+        hasher: &dyn PasswordHasher,
         tenant_id: uuid::Uuid,
         email: &str,
         password: &str,
@@ -272,7 +334,10 @@ impl AuthLifecycleService {
             .map_err(AuthLifecycleError::from)?
             .ok_or(AuthLifecycleError::InvalidCredentials)?;
 
-        if !verify_password(password, &user.password_hash).map_err(AuthLifecycleError::from)? {
+        if !hasher
+            .verify(password, &user.password_hash)
+            .map_err(AuthLifecycleError::from)?
+        {
             return Err(AuthLifecycleError::InvalidCredentials);
         }
 
@@ -319,7 +384,7 @@ impl AuthLifecycleService {
             .map_err(AuthLifecycleError::from)?
             .ok_or(AuthLifecycleError::InvalidRefreshToken)?;
 
-        if !session.is_active() {
+        if !session.is_active(config.clock_leeway()) {
             return Err(AuthLifecycleError::SessionExpired);
         }
 
@@ -376,12 +441,22 @@ impl AuthLifecycleService {
         password: &str,
     ) -> std::result::Result<(), AuthLifecycleError> {
         let config = auth_config_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
-        Self::confirm_password_reset_with_config(&ctx.db, &config, tenant_id, token, password).await
+        let hasher = password_hasher_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
+        Self::confirm_password_reset_with_config(
+            &ctx.db,
+            &config,
+            hasher.as_ref(),
+            tenant_id,
+            token,
+            password,
+        )
+        .await
     }
 
     async fn confirm_password_reset_with_config(
         db: &DatabaseConnection,
         config: &AuthConfig,
+        hasher: &dyn PasswordHasher,
         tenant_id: uuid::Uuid,
         token: &str,
         password: &str,
@@ -398,7 +473,155 @@ impl AuthLifecycleService {
             .map_err(AuthLifecycleError::from)?
             .ok_or(AuthLifecycleError::InvalidResetToken)?;
 
-        Self::reset_password_and_revoke_sessions(db, tenant_id, user, password, None).await
+        Self::reset_password_and_revoke_sessions(db, hasher, tenant_id, user, password, None).await
+    }
+
+    /// Issue an email verification token for `user_id` and send it by email.
+    ///
+    /// Returns `None` when the `email_verification` feature is disabled or the
+    /// address is already verified. Delivery happens in the background; a
+    /// failed send is logged and does not fail the request.
+    pub async fn request_email_verification(
+        ctx: &AppContext,
+        tenant_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        locale: &str,
+    ) -> std::result::Result<Option<String>, AuthLifecycleError> {
+        if !Self::email_verification_required(ctx)? {
+            return Ok(None);
+        }
+
+        let config = auth_config_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
+        let Some((user, token)) =
+            Self::issue_email_verification_token(&ctx.db, &config, tenant_id, user_id).await?
+        else {
+            return Ok(None);
+        };
+
+        let email_service =
+            email_service_from_ctx(ctx, locale).map_err(AuthLifecycleError::from)?;
+        let verification_token = token.clone();
+        tokio::spawn(async move {
+            if let Err(error) = email_service
+                .send_email_verification(EmailVerificationEmail {
+                    to: user.email,
+                    verification_token,
+                })
+                .await
+            {
+                tracing::warn!(error = %error, "Failed to send email verification email");
+            }
+        });
+
+        Ok(Some(token))
+    }
+
+    async fn issue_email_verification_token(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        tenant_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> std::result::Result<Option<(users::Model, String)>, AuthLifecycleError> {
+        let user = users::Entity::find_by_id(user_id)
+            .filter(users::Column::TenantId.eq(tenant_id))
+            .one(db)
+            .await
+            .map_err(AuthLifecycleError::from)?
+            .ok_or(AuthLifecycleError::UserNotFound)?;
+
+        if user.is_email_verified() {
+            return Ok(None);
+        }
+
+        let token = encode_email_verification_token(
+            config,
+            tenant_id,
+            &user.email,
+            EMAIL_VERIFICATION_TOKEN_TTL_SECS,
+        )
+        .map_err(AuthLifecycleError::from)?;
+
+        Ok(Some((user, token)))
+    }
+
+    /// Confirm an email verification token.
+    ///
+    /// Marks the address as verified and moves an `Inactive` user to
+    /// `Active`. Banned users stay banned. Repeating the call with a still
+    /// valid token is a no-op.
+    pub async fn verify_email(
+        ctx: &AppContext,
+        tenant_id: uuid::Uuid,
+        token: &str,
+    ) -> std::result::Result<users::Model, AuthLifecycleError> {
+        let config = auth_config_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
+        Self::verify_email_with_config(&ctx.db, &config, tenant_id, token).await
+    }
+
+    async fn verify_email_with_config(
+        db: &DatabaseConnection,
+        config: &AuthConfig,
+        tenant_id: uuid::Uuid,
+        token: &str,
+    ) -> std::result::Result<users::Model, AuthLifecycleError> {
+        let claims = decode_email_verification_token(config, token)
+            .map_err(|_| AuthLifecycleError::InvalidVerificationToken)?;
+
+        if claims.tenant_id != tenant_id {
+            return Err(AuthLifecycleError::InvalidVerificationToken);
+        }
+
+        let user = users::Entity::find_by_email(db, tenant_id, &claims.sub)
+            .await
+            .map_err(AuthLifecycleError::from)?
+            .ok_or(AuthLifecycleError::InvalidVerificationToken)?;
+
+        let activate = user.status == UserStatus::Inactive;
+        if user.is_email_verified() && !activate {
+            return Ok(user);
+        }
+
+        let mut user_active: users::ActiveModel = user.clone().into();
+        if !user.is_email_verified() {
+            user_active.email_verified_at = Set(Some(Utc::now().into()));
+        }
+        if activate {
+            user_active.status = Set(UserStatus::Active);
+        }
+
+        user_active
+            .update(db)
+            .await
+            .map_err(AuthLifecycleError::from)
+    }
+
+    /// Reject sensitive actions for users whose email is not yet verified.
+    ///
+    /// Only enforced when the `email_verification` feature is enabled.
+    pub fn ensure_email_verified(
+        ctx: &AppContext,
+        user: &users::Model,
+    ) -> std::result::Result<(), AuthLifecycleError> {
+        Self::check_email_verified(Self::email_verification_required(ctx)?, user)
+    }
+
+    fn check_email_verified(
+        required: bool,
+        user: &users::Model,
+    ) -> std::result::Result<(), AuthLifecycleError> {
+        if required && !user.is_email_verified() {
+            return Err(AuthLifecycleError::EmailNotVerified);
+        }
+
+        Ok(())
+    }
+
+    fn email_verification_required(
+        ctx: &AppContext,
+    ) -> std::result::Result<bool, AuthLifecycleError> {
+        let settings = RustokSettings::from_settings(&ctx.config.settings)
+            .map_err(|error| AuthLifecycleError::Internal(Error::Message(error.to_string())))?;
+        Ok(settings.features.email_verification)
     }
 
     pub async fn change_password(
@@ -415,16 +638,20 @@ impl AuthLifecycleService {
             .await
             .map_err(AuthLifecycleError::from)?
             .ok_or(AuthLifecycleError::InvalidCredentials)?;
+        Self::ensure_email_verified(ctx, &user)?;
+        let hasher = password_hasher_from_ctx(ctx).map_err(AuthLifecycleError::from)?;
 
-        if !verify_password(current_password, &user.password_hash)
+        if !hasher
+            .verify(current_password, &user.password_hash)
             .map_err(AuthLifecycleError::from)?
         {
             return Err(AuthLifecycleError::InvalidCredentials);
         }
 
         let mut user_active: users::ActiveModel = user.into();
-        user_active.password_hash =
-            Set(hash_password(new_password).map_err(AuthLifecycleError::from)?);
+        user_active.password_hash = Set(hasher
+            .hash(new_password)
+            .map_err(AuthLifecycleError::from)?);
         user_active
             .update(&ctx.db)
             .await
@@ -462,11 +689,18 @@ impl AuthLifecycleService {
         user_id: uuid::Uuid,
         limit: u64,
     ) -> std::result::Result<Vec<sessions::Model>, AuthLifecycleError> {
+        let leeway = auth_config_from_ctx(ctx)
+            .map_err(AuthLifecycleError::from)?
+            .clock_leeway();
         let rows = sessions::Entity::find()
             .filter(sessions::Column::TenantId.eq(tenant_id))
             .filter(sessions::Column::UserId.eq(user_id))
             .filter(sessions::Column::RevokedAt.is_null())
-            .filter(sessions::Column::ExpiresAt.gt(Utc::now()))
+            .filter(
+                sessions::Column::ExpiresAt.gt(TimeBounds::default()
+                    .with_leeway(leeway)
+                    .expiry_cutoff(Utc::now())),
+            )
             .order_by_desc(sessions::Column::CreatedAt)
             .limit(limit)
             .all(&ctx.db)
@@ -507,6 +741,7 @@ impl AuthLifecycleService {
 
     async fn reset_password_and_revoke_sessions(
         db: &DatabaseConnection,
+        hasher: &dyn PasswordHasher,
         tenant_id: uuid::Uuid,
         user: users::Model,
         new_password: &str,
@@ -514,8 +749,9 @@ impl AuthLifecycleService {
     ) -> std::result::Result<(), AuthLifecycleError> {
         let user_id = user.id;
         let mut user_active: users::ActiveModel = user.into();
-        user_active.password_hash =
-            Set(hash_password(new_password).map_err(AuthLifecycleError::from)?);
+        user_active.password_hash = Set(hasher
+            .hash(new_password)
+            .map_err(AuthLifecycleError::from)?);
         user_active
             .update(db)
             .await
@@ -626,16 +862,16 @@ mod tests {
         AUTH_CHANGE_PASSWORD_SESSIONS_REVOKED_TOTAL, AUTH_FLOW_INCONSISTENCY_TOTAL,
         AUTH_LOGIN_INACTIVE_USER_ATTEMPT_TOTAL, AUTH_PASSWORD_RESET_SESSIONS_REVOKED_TOTAL,
     };
-    use crate::auth::{
-        decode_access_token, hash_password, hash_refresh_token, verify_password, AuthConfig,
-    };
+    use crate::auth::{decode_access_token, hash_refresh_token, AuthConfig, PasswordHasher};
     use crate::models::_entities::user_roles;
     use crate::models::{sessions, tenants, users};
     use crate::services::rbac_service::RbacService;
+    use axum::http::StatusCode;
     use chrono::{Duration, Utc};
     use migration::Migrator;
     use rustok_core::UserStatus;
     use rustok_test_utils::db::setup_test_db_with_migrations;
+    use rustok_test_utils::NoopPasswordHasher;
     use sea_orm::{
         ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
         QuerySelect, Set,
@@ -707,49 +943,85 @@ mod tests {
         assert_eq!(snapshot.login_inactive_user_attempt_total, 0);
     }
 
-    #[test]
-    fn maps_email_exists_to_bad_request() {
-        let err: Error = AuthLifecycleError::EmailAlreadyExists.into();
+    fn assert_api_error(err: Error, status: StatusCode, code: &str, message: &str) {
         match err {
-            Error::BadRequest(msg) => assert_eq!(msg, "Email already exists"),
+            Error::CustomError(actual, detail) => {
+                assert_eq!(actual, status);
+                assert_eq!(detail.error.as_deref(), Some(code));
+                assert_eq!(detail.description.as_deref(), Some(message));
+            }
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
 
+    #[test]
+    fn maps_email_exists_to_bad_request() {
+        assert_api_error(
+            AuthLifecycleError::EmailAlreadyExists.into(),
+            StatusCode::BAD_REQUEST,
+            "auth.email_already_exists",
+            "Email already exists",
+        );
+    }
+
     #[test]
     fn maps_invalid_credentials_to_unauthorized() {
-        let err: Error = AuthLifecycleError::InvalidCredentials.into();
-        match err {
-            Error::Unauthorized(msg) => assert_eq!(msg, "Invalid credentials"),
-            other => panic!("unexpected error variant: {other:?}"),
-        }
+        assert_api_error(
+            AuthLifecycleError::InvalidCredentials.into(),
+            StatusCode::UNAUTHORIZED,
+            "auth.invalid_credentials",
+            "Invalid credentials",
+        );
     }
 
     #[test]
     fn maps_user_inactive_to_unauthorized() {
-        let err: Error = AuthLifecycleError::UserInactive.into();
-        match err {
-            Error::Unauthorized(msg) => assert_eq!(msg, "User is inactive"),
-            other => panic!("unexpected error variant: {other:?}"),
-        }
+        assert_api_error(
+            AuthLifecycleError::UserInactive.into(),
+            StatusCode::UNAUTHORIZED,
+            "auth.user_inactive",
+            "User is inactive",
+        );
     }
 
     #[test]
     fn maps_invalid_reset_token_to_unauthorized() {
-        let err: Error = AuthLifecycleError::InvalidResetToken.into();
-        match err {
-            Error::Unauthorized(msg) => assert_eq!(msg, "Invalid reset token"),
-            other => panic!("unexpected error variant: {other:?}"),
-        }
+        assert_api_error(
+            AuthLifecycleError::InvalidResetToken.into(),
+            StatusCode::UNAUTHORIZED,
+            "auth.invalid_reset_token",
+            "Invalid reset token",
+        );
+    }
+
+    #[test]
+    fn maps_invalid_verification_token_to_unauthorized() {
+        assert_api_error(
+            AuthLifecycleError::InvalidVerificationToken.into(),
+            StatusCode::UNAUTHORIZED,
+            "auth.invalid_verification_token",
+            "Invalid verification token",
+        );
+    }
+
+    #[test]
+    fn maps_email_not_verified_to_forbidden() {
+        assert_api_error(
+            AuthLifecycleError::EmailNotVerified.into(),
+            StatusCode::FORBIDDEN,
+            "auth.email_not_verified",
+            "Email address is not verified",
+        );
     }
 
     #[test]
     fn maps_user_not_found_to_unauthorized() {
-        let err: Error = AuthLifecycleError::UserNotFound.into();
-        match err {
-            Error::Unauthorized(msg) => assert_eq!(msg, "User not found"),
-            other => panic!("unexpected error variant: {other:?}"),
-        }
+        assert_api_error(
+            AuthLifecycleError::UserNotFound.into(),
+            StatusCode::UNAUTHORIZED,
+            "auth.user_not_found",
+            "User not found",
+        );
     }
 
     #[test]
@@ -804,6 +1076,7 @@ mod tests {
 
         AuthLifecycleService::reset_password_and_revoke_sessions(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             user.clone(),
             "new-password",
@@ -839,6 +1112,7 @@ mod tests {
 
         let user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "manager@example.com",
             "password123",
@@ -884,6 +1158,7 @@ mod tests {
 
         let user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "profile@example.com",
             "Password123!",
@@ -931,6 +1206,7 @@ mod tests {
 
         let user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant_a.id,
             "tenant-a@example.com",
             "Password123!",
@@ -962,6 +1238,7 @@ mod tests {
 
         let lifecycle_user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "lifecycle@example.com",
             "Password123!",
@@ -972,7 +1249,9 @@ mod tests {
         .await
         .expect("create_user via lifecycle path should succeed");
 
-        let password_hash = hash_password("Password123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("Password123!")
+            .expect("failed to hash password");
         let legacy_user = users::ActiveModel::new(tenant.id, "legacy@example.com", &password_hash);
         let legacy_user = legacy_user
             .insert(&db)
@@ -1015,7 +1294,9 @@ mod tests {
             .await
             .expect("failed to create tenant");
 
-        let password_hash = hash_password("Password123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("Password123!")
+            .expect("failed to hash password");
         let user = users::ActiveModel::new(tenant.id, "relation-role@example.com", &password_hash);
         let user = user.insert(&db).await.expect("user insert should succeed");
 
@@ -1048,6 +1329,7 @@ mod tests {
 
         let default_status_user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "default-status@example.com",
             "Password123!",
@@ -1062,6 +1344,7 @@ mod tests {
 
         let explicit_inactive_user = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "explicit-inactive@example.com",
             "Password123!",
@@ -1089,6 +1372,7 @@ mod tests {
 
         AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "dup@example.com",
             "Password123!",
@@ -1101,6 +1385,7 @@ mod tests {
 
         let result = AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             "dup@example.com",
             "Password123!",
@@ -1126,7 +1411,9 @@ mod tests {
             .await
             .expect("failed to create tenant");
 
-        let password_hash = hash_password("Password123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("Password123!")
+            .expect("failed to hash password");
         let mut user = users::ActiveModel::new(tenant.id, "inactive@example.com", &password_hash);
         user.status = sea_orm::Set(UserStatus::Inactive);
         user.insert(&db)
@@ -1139,6 +1426,7 @@ mod tests {
         let result = AuthLifecycleService::login_with_config(
             &db,
             &config,
+            &NoopPasswordHasher,
             tenant.id,
             "inactive@example.com",
             "Password123!",
@@ -1163,7 +1451,9 @@ mod tests {
             .await
             .expect("failed to create tenant");
 
-        let password_hash = hash_password("Password123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("Password123!")
+            .expect("failed to hash password");
         let user =
             users::ActiveModel::new(tenant.id, "expired-session@example.com", &password_hash)
                 .insert(&db)
@@ -1227,7 +1517,9 @@ mod tests {
                 .await
                 .expect("failed to create tenant");
 
-        let password_hash = hash_password("Password123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("Password123!")
+            .expect("failed to hash password");
         let mut user =
             users::ActiveModel::new(tenant.id, "refresh-inactive@example.com", &password_hash);
         user.status = Set(UserStatus::Inactive);
@@ -1266,7 +1558,9 @@ mod tests {
             .await
             .expect("failed to create tenant A");
 
-        let password_hash = hash_password("OldPassword123!").expect("failed to hash password");
+        let password_hash = NoopPasswordHasher
+            .hash("OldPassword123!")
+            .expect("failed to hash password");
         users::ActiveModel::new(tenant.id, "tenant-a-user@example.com", &password_hash)
             .insert(&db)
             .await
@@ -1277,6 +1571,7 @@ mod tests {
         let err = AuthLifecycleService::confirm_password_reset_with_config(
             &db,
             &config,
+            &NoopPasswordHasher,
             tenant.id,
             "not-a-jwt",
             "NewPassword123!",
@@ -1287,6 +1582,129 @@ mod tests {
         assert!(matches!(err, AuthLifecycleError::InvalidResetToken));
     }
 
+    #[tokio::test]
+    async fn verify_email_marks_user_verified_and_activates_inactive_user() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant = tenants::ActiveModel::new("Tenant A", "tenant-a")
+            .insert(&db)
+            .await
+            .expect("failed to create tenant");
+
+        let mut user_active = users::ActiveModel::new(tenant.id, "verify@example.com", "hash");
+        user_active.status = Set(UserStatus::Inactive);
+        let user = user_active
+            .insert(&db)
+            .await
+            .expect("failed to create user");
+
+        let config = test_auth_config("verify-secret", 3600, 7200);
+        let (_, token) =
+            AuthLifecycleService::issue_email_verification_token(&db, &config, tenant.id, user.id)
+                .await
+                .expect("token issue should succeed")
+                .expect("unverified user must get a token");
+
+        let verified =
+            AuthLifecycleService::verify_email_with_config(&db, &config, tenant.id, &token)
+                .await
+                .expect("verification should succeed");
+
+        assert!(verified.is_email_verified());
+        assert_eq!(verified.status, UserStatus::Active);
+
+        let reissued =
+            AuthLifecycleService::issue_email_verification_token(&db, &config, tenant.id, user.id)
+                .await
+                .expect("token issue should succeed");
+        assert!(reissued.is_none(), "verified user must not get a new token");
+    }
+
+    #[tokio::test]
+    async fn verify_email_keeps_banned_user_banned() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant = tenants::ActiveModel::new("Tenant A", "tenant-a")
+            .insert(&db)
+            .await
+            .expect("failed to create tenant");
+
+        let mut user_active = users::ActiveModel::new(tenant.id, "banned@example.com", "hash");
+        user_active.status = Set(UserStatus::Banned);
+        let user = user_active
+            .insert(&db)
+            .await
+            .expect("failed to create user");
+
+        let config = test_auth_config("verify-secret", 3600, 7200);
+        let (_, token) =
+            AuthLifecycleService::issue_email_verification_token(&db, &config, tenant.id, user.id)
+                .await
+                .expect("token issue should succeed")
+                .expect("unverified user must get a token");
+
+        let verified =
+            AuthLifecycleService::verify_email_with_config(&db, &config, tenant.id, &token)
+                .await
+                .expect("verification should succeed");
+
+        assert!(verified.is_email_verified());
+        assert_eq!(verified.status, UserStatus::Banned);
+    }
+
+    #[tokio::test]
+    async fn verify_email_rejects_token_from_another_tenant() {
+        let db = setup_test_db_with_migrations::<Migrator>().await;
+        let tenant_a = tenants::ActiveModel::new("Tenant A", "tenant-a")
+            .insert(&db)
+            .await
+            .expect("failed to create tenant A");
+        let tenant_b = tenants::ActiveModel::new("Tenant B", "tenant-b")
+            .insert(&db)
+            .await
+            .expect("failed to create tenant B");
+
+        let user = users::ActiveModel::new(tenant_a.id, "verify@example.com", "hash")
+            .insert(&db)
+            .await
+            .expect("failed to create user");
+
+        let config = test_auth_config("verify-secret", 3600, 7200);
+        let (_, token) = AuthLifecycleService::issue_email_verification_token(
+            &db,
+            &config,
+            tenant_a.id,
+            user.id,
+        )
+        .await
+        .expect("token issue should succeed")
+        .expect("unverified user must get a token");
+
+        let err = AuthLifecycleService::verify_email_with_config(&db, &config, tenant_b.id, &token)
+            .await
+            .expect_err("cross-tenant token must be rejected");
+        assert!(matches!(err, AuthLifecycleError::InvalidVerificationToken));
+
+        let err =
+            AuthLifecycleService::verify_email_with_config(&db, &config, tenant_a.id, "not-a-jwt")
+                .await
+                .expect_err("malformed token must be rejected");
+        assert!(matches!(err, AuthLifecycleError::InvalidVerificationToken));
+    }
+
+    #[test]
+    fn check_email_verified_only_enforced_when_required() {
+        let mut user =
+            users::Model::default_service_user(uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+
+        assert!(AuthLifecycleService::check_email_verified(false, &user).is_ok());
+        assert!(matches!(
+            AuthLifecycleService::check_email_verified(true, &user),
+            Err(AuthLifecycleError::EmailNotVerified)
+        ));
+
+        user.email_verified_at = Some(Utc::now().into());
+        assert!(AuthLifecycleService::check_email_verified(true, &user).is_ok());
+    }
+
     #[tokio::test]
     #[serial]
     async fn reset_password_and_revoke_sessions_updates_password_and_revokes_all_sessions() {
@@ -1299,7 +1717,9 @@ mod tests {
 
         let old_password = "OldPassword123!";
         let new_password = "NewPassword123!";
-        let old_hash = hash_password(old_password).expect("failed to hash old password");
+        let old_hash = NoopPasswordHasher
+            .hash(old_password)
+            .expect("failed to hash old password");
         let user = users::ActiveModel::new(tenant.id, "reset-positive@example.com", &old_hash)
             .insert(&db)
             .await
@@ -1333,6 +1753,7 @@ mod tests {
 
         AuthLifecycleService::reset_password_and_revoke_sessions(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             user.clone(),
             new_password,
@@ -1346,9 +1767,11 @@ mod tests {
             .await
             .expect("failed to query updated user")
             .expect("updated user should exist");
-        assert!(verify_password(new_password, &updated_user.password_hash)
+        assert!(NoopPasswordHasher
+            .verify(new_password, &updated_user.password_hash)
             .expect("new password should verify"));
-        assert!(!verify_password(old_password, &updated_user.password_hash)
+        assert!(!NoopPasswordHasher
+            .verify(old_password, &updated_user.password_hash)
             .expect("old password must not verify"));
 
         let active_sessions = sessions::Entity::find()
@@ -1591,6 +2014,7 @@ mod tests {
 
         AuthLifecycleService::reset_password_and_revoke_sessions(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             user.clone(),
             "new-password",
@@ -1666,6 +2090,7 @@ mod tests {
 
         AuthLifecycleService::reset_password_and_revoke_sessions(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             user.clone(),
             "new-password",
@@ -1688,6 +2113,7 @@ mod tests {
 
         AuthLifecycleService::reset_password_and_revoke_sessions(
             &db,
+            &NoopPasswordHasher,
             tenant.id,
             user,
             "new-password-2",
@@ -1723,6 +2149,7 @@ mod tests {
 
         AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant_a.id,
             "shared@example.com",
             "Password123!",
@@ -1735,6 +2162,7 @@ mod tests {
 
         AuthLifecycleService::create_user_db(
             &db,
+            &NoopPasswordHasher,
             tenant_b.id,
             "shared@example.com",
             "Password123!",
@@ -0,0 +1,107 @@
+//! `index_content` as a [`RebuildableProjection`].
+//!
+//! The rebuild runs the regular [`ContentIndexer`] against a shadow table
+//! created with `LIKE index_content`, then swaps it in inside one
+//! transaction. The indexer rebuilds rows from the current `nodes` tables,
+//! so the replay only decides *which* nodes end up in the index.
+//!
+//! Events handled by the live indexer between the last replayed message and
+//! the swap land in the retired table; run a `ReindexRequested` for content
+//! afterwards if writes were not paused.
+
+use async_trait::async_trait;
+use rustok_core::events::EventHandler;
+use rustok_core::{Error, Result};
+use rustok_events::{DomainEvent, EventEnvelope};
+use rustok_iggy::RebuildableProjection;
+use rustok_index::content::{ContentIndexer, INDEX_CONTENT_TABLE};
+use rustok_index::IndexerRuntimeConfig;
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, TransactionTrait};
+
+/// Shadow table filled during a rebuild.
+const SHADOW_TABLE: &str = "index_content_rebuild";
+/// Name the live table briefly takes during the swap.
+const RETIRED_TABLE: &str = "index_content_retired";
+
+pub struct ContentIndexProjection {
+    db: DatabaseConnection,
+    shadow: ContentIndexer,
+}
+
+impl ContentIndexProjection {
+    pub fn new(db: DatabaseConnection) -> Self {
+        let shadow = ContentIndexer::with_runtime(db.clone(), IndexerRuntimeConfig::load())
+            .with_table(SHADOW_TABLE);
+        Self { db, shadow }
+    }
+
+    fn ensure_postgres(&self) -> Result<()> {
+        if self.db.get_database_backend() == DatabaseBackend::Postgres {
+            Ok(())
+        } else {
+            Err(Error::Validation(
+                "index_content rebuild requires PostgreSQL".to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl RebuildableProjection for ContentIndexProjection {
+    fn name(&self) -> &'static str {
+        INDEX_CONTENT_TABLE
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        EventHandler::handles(&self.shadow, event)
+    }
+
+    async fn begin_rebuild(&self) -> Result<()> {
+        self.ensure_postgres()?;
+        // `LIKE ... INCLUDING ALL` copies columns, defaults and indexes but not
+        // triggers, so the search vector trigger is attached explicitly.
+        self.db
+            .execute_unprepared(&format!(
+                r#"
+                DROP TABLE IF EXISTS {shadow};
+                CREATE TABLE {shadow} (LIKE {live} INCLUDING ALL);
+                CREATE TRIGGER index_content_search_update
+                    BEFORE INSERT OR UPDATE ON {shadow}
+                    FOR EACH ROW
+                    EXECUTE FUNCTION index_content_search_trigger();
+                "#,
+                shadow = SHADOW_TABLE,
+                live = INDEX_CONTENT_TABLE,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn apply_rebuild(&self, envelope: &EventEnvelope) -> Result<()> {
+        self.shadow.handle(envelope).await
+    }
+
+    async fn commit_rebuild(&self) -> Result<()> {
+        let txn = self.db.begin().await?;
+        txn.execute_unprepared(&format!(
+            r#"
+            ALTER TABLE {live} RENAME TO {retired};
+            ALTER TABLE {shadow} RENAME TO {live};
+            DROP TABLE {retired};
+            "#,
+            live = INDEX_CONTENT_TABLE,
+            retired = RETIRED_TABLE,
+            shadow = SHADOW_TABLE,
+        ))
+        .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn abort_rebuild(&self) -> Result<()> {
+        self.db
+            .execute_unprepared(&format!("DROP TABLE IF EXISTS {SHADOW_TABLE}"))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! `NodeService` wired with server configuration.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, KeyInit, Mac};
+use loco_rs::app::AppContext;
+use rustok_cache::CacheService;
+use rustok_content::{MetadataVisibility, NodeService};
+use rustok_core::CacheBackend;
+use sha2::Sha256;
+
+use crate::common::settings::RustokSettings;
+use crate::context::TenantContext;
+use crate::error::{Error, Result};
+use crate::services::event_bus::transactional_event_bus_from_context;
+
+/// Context string mixed into the JWT secret when deriving the bulk delete key.
+const BULK_DELETE_KEY_CONTEXT: &[u8] = b"bulk-delete";
+
+const CONTENT_READ_CACHE_NAME: &str = "content-nodes";
+const CONTENT_READ_CACHE_TTL: Duration = Duration::from_secs(60);
+const CONTENT_READ_CACHE_MAX_CAPACITY: u64 = 10_000;
+
+/// Node read cache shared by every `NodeService` the server builds, so a
+/// write through one instance evicts what the others serve.
+#[derive(Clone)]
+pub struct SharedContentReadCache(pub Arc<dyn CacheBackend>);
+
+pub async fn init_content_read_cache(ctx: &AppContext, cache_service: &CacheService) {
+    if ctx.shared_store.contains::<SharedContentReadCache>() {
+        return;
+    }
+    let cache = cache_service
+        .backend(
+            CONTENT_READ_CACHE_NAME,
+            CONTENT_READ_CACHE_TTL,
+            CONTENT_READ_CACHE_MAX_CAPACITY,
+        )
+        .await;
+    ctx.shared_store.insert(SharedContentReadCache(cache));
+}
+
+/// Build a `NodeService` whose bulk delete tokens verify on every instance,
+/// whose reads apply the tenant's metadata visibility rules and go through
+/// the shared read cache once [`init_content_read_cache`] has run.
+///
+/// Tokens are signed with `rustok.content.bulk_delete_secret`. When that is
+/// unset the key is derived as HMAC(jwt_secret, "bulk-delete"), so neither
+/// kind of token verifies as the other.
+pub fn node_service_from_context(ctx: &AppContext, tenant: &TenantContext) -> Result<NodeService> {
+    let settings = RustokSettings::from_settings(&ctx.config.settings)
+        .map_err(|error| Error::BadRequest(format!("Invalid rustok settings: {error}")))?;
+    let secret = bulk_delete_secret(
+        settings.content.bulk_delete_secret.as_deref(),
+        ctx.config
+            .auth
+            .as_ref()
+            .and_then(|auth| auth.jwt.as_ref())
+            .map(|jwt| jwt.secret.as_str()),
+    )
+    .ok_or_else(|| {
+        Error::Message(
+            "Bulk delete requires rustok.content.bulk_delete_secret or a JWT secret".to_string(),
+        )
+    })?;
+
+    let mut service = NodeService::new(ctx.db.clone(), transactional_event_bus_from_context(ctx))
+        .with_bulk_delete_secret(secret)
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
+    if let Some(cache) = ctx.shared_store.get::<SharedContentReadCache>() {
+        service = service.with_read_cache(cache.0);
+    }
+    Ok(service)
+}
+
+fn bulk_delete_secret(configured: Option<&str>, jwt: Option<&str>) -> Option<Vec<u8>> {
+    if let Some(secret) = configured.filter(|secret| !secret.is_empty()) {
+        return Some(secret.as_bytes().to_vec());
+    }
+    let jwt = jwt.filter(|secret| !secret.is_empty())?;
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(jwt.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(BULK_DELETE_KEY_CONTEXT);
+    Some(mac.finalize().into_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bulk_delete_secret;
+
+    #[test]
+    fn configured_secret_wins_over_jwt_secret() {
+        assert_eq!(
+            bulk_delete_secret(Some("content-key"), Some("jwt-key")),
+            Some(b"content-key".to_vec())
+        );
+        assert_eq!(bulk_delete_secret(None, None), None);
+    }
+
+    #[test]
+    fn jwt_fallback_derives_a_separate_key() {
+        let derived = bulk_delete_secret(Some(""), Some("jwt-key")).unwrap();
+        assert_ne!(derived, b"jwt-key".to_vec());
+        assert_eq!(derived.len(), 32);
+        assert_eq!(bulk_delete_secret(None, Some("jwt-key")), Some(derived));
+        assert_ne!(
+            bulk_delete_secret(None, Some("other-jwt-key")),
+            bulk_delete_secret(None, Some("jwt-key"))
+        );
+    }
+}
@@ -2189,7 +2189,7 @@ mod tests {
         assert_eq!(post_tags.len(), 2);
 
         let comments_service = CommentsService::new(db.clone());
-        let (comments, total) = comments_service
+        let comments = comments_service
             .list_comments_for_target(
                 tenant_id,
                 SecurityContext::system(),
@@ -2204,10 +2204,10 @@ mod tests {
             )
             .await
             .expect("comments for promoted post should list");
-        assert_eq!(total, 2);
-        assert_eq!(comments.len(), 2);
-        assert_eq!(comments[0].body_preview, "First forum reply");
-        assert_eq!(comments[1].body_preview, "Second forum reply");
+        assert_eq!(comments.total, Some(2));
+        assert_eq!(comments.items.len(), 2);
+        assert_eq!(comments.items[0].body_preview, "First forum reply");
+        assert_eq!(comments.items[1].body_preview, "Second forum reply");
 
         let stored_comments = comment::Entity::find()
             .all(&db)
@@ -2418,7 +2418,7 @@ mod tests {
         assert_eq!(topic_translation.body, "Original blog body");
 
         let reply_service = ReplyService::new(db.clone(), events.clone());
-        let (replies, total) = reply_service
+        let replies = reply_service
             .list_response_for_topic_with_locale_fallback(
                 tenant_id,
                 SecurityContext::system(),
@@ -2432,11 +2432,12 @@ mod tests {
             )
             .await
             .expect("replies for demoted topic should list");
-        assert_eq!(total, 2);
-        assert_eq!(replies.len(), 2);
-        assert_eq!(replies[0].content, "First blog comment");
-        assert_eq!(replies[1].content, "Second blog comment");
+        assert_eq!(replies.total, Some(2));
+        assert_eq!(replies.items.len(), 2);
+        assert_eq!(replies.items[0].content, "First blog comment");
+        assert_eq!(replies.items[1].content, "Second blog comment");
         assert!(replies
+            .items
             .iter()
             .all(|reply| reply.status == reply_status::PENDING));
 
@@ -2453,7 +2454,7 @@ mod tests {
         assert_eq!(stored_reply_bodies.len(), 2);
 
         let comments_service = CommentsService::new(db.clone());
-        let (_, remaining_comments) = comments_service
+        let remaining_comments = comments_service
             .list_comments_for_target(
                 tenant_id,
                 SecurityContext::system(),
@@ -2468,7 +2469,7 @@ mod tests {
             )
             .await
             .expect("legacy blog comments should be queryable");
-        assert_eq!(remaining_comments, 0);
+        assert_eq!(remaining_comments.total, Some(0));
 
         let canonical = CanonicalUrlService::new(db.clone());
         let alias_resolution = canonical
@@ -334,36 +334,44 @@ pub fn email_service_from_ctx(
             )))
         }
 
-        EmailProvider::Smtp => {
-            // Return cached transport if already initialised (connection pool reuse).
-            if let Some(shared) = ctx.shared_store.get::<SharedSmtpEmailService>() {
-                return Ok(Box::new(TemplatedSmtpMailerAdapter::new(
-                    shared.0.clone(),
-                    locale,
-                )));
-            }
-
-            let config = rustok_email::EmailConfig {
-                enabled: settings.email.enabled,
-                smtp: rustok_email::SmtpConfig {
-                    host: settings.email.smtp.host,
-                    port: settings.email.smtp.port,
-                    username: settings.email.smtp.username,
-                    password: settings.email.smtp.password,
-                },
-                from: settings.email.from,
-                reset_base_url: settings.email.reset_base_url,
-            };
-            let service = EmailService::from_config(&config).map_err(email_err)?;
-            let EmailService::Smtp(sender) = service else {
-                return Ok(Box::new(DisabledBuiltInAuthEmailSender));
-            };
-            let sender = Arc::new(*sender);
-            ctx.shared_store
-                .insert(SharedSmtpEmailService(sender.clone()));
-            Ok(Box::new(TemplatedSmtpMailerAdapter::new(sender, locale)))
-        }
+        EmailProvider::Smtp => match shared_smtp_sender(ctx, &settings)? {
+            Some(sender) => Ok(Box::new(TemplatedSmtpMailerAdapter::new(sender, locale))),
+            None => Ok(Box::new(DisabledBuiltInAuthEmailSender)),
+        },
+    }
+}
+
+/// SMTP transport cached in `shared_store`, built on first use.
+///
+/// Returns `None` when email is disabled in settings.
+pub fn shared_smtp_sender(
+    ctx: &AppContext,
+    settings: &RustokSettings,
+) -> Result<Option<Arc<rustok_email::SmtpEmailSender>>> {
+    // Return cached transport if already initialised (connection pool reuse).
+    if let Some(shared) = ctx.shared_store.get::<SharedSmtpEmailService>() {
+        return Ok(Some(shared.0));
     }
+
+    let config = rustok_email::EmailConfig {
+        enabled: settings.email.enabled,
+        smtp: rustok_email::SmtpConfig {
+            host: settings.email.smtp.host.clone(),
+            port: settings.email.smtp.port,
+            username: settings.email.smtp.username.clone(),
+            password: settings.email.smtp.password.clone(),
+        },
+        from: settings.email.from.clone(),
+        reset_base_url: settings.email.reset_base_url.clone(),
+    };
+    let service = EmailService::from_config(&config).map_err(email_err)?;
+    let EmailService::Smtp(sender) = service else {
+        return Ok(None);
+    };
+    let sender = Arc::new(*sender);
+    ctx.shared_store
+        .insert(SharedSmtpEmailService(sender.clone()));
+    Ok(Some(sender))
 }
 
 #[cfg(test)]
@@ -1,18 +1,32 @@
 use std::sync::Arc;
 
 use loco_rs::app::AppContext;
-use rustok_core::events::{BackpressureConfig, BackpressureController, EventTransport};
-use rustok_core::{EventBus, EventConsumerRuntime};
+use rustok_core::events::{
+    required_reliability, BackpressureConfig, BackpressureController, EventTransport,
+    ReliabilityLevel, RoutingRule, RoutingTable, EVENT_SCHEMAS,
+};
+use rustok_core::{EventBus, EventConsumerRuntime, EventEnvelope, ShutdownPhase};
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
 use tokio::task::JoinHandle;
 
-use crate::common::settings::RustokSettings;
+use crate::common::settings::{EventRoutingSettings, RustokSettings};
+use crate::services::app_lifecycle::shutdown_coordinator_from_context;
 
 pub use rustok_api::loco::transactional_event_bus_from_context;
 pub use rustok_api::loco::SharedTransactionalEventBus;
 
+/// Sink name under which the configured [`EventTransport`] is registered in
+/// the forwarder's [`RoutingTable`].
+pub const PRIMARY_TRANSPORT_SINK: &str = "transport";
+
 #[derive(Clone)]
 pub struct SharedEventBus(pub Arc<EventBus>);
 
+/// Routing table used by the event forwarder. Other components may register
+/// extra sinks on it; [`reload_event_routing`] swaps the rules at runtime.
+#[derive(Clone)]
+pub struct SharedEventRoutingTable(pub Arc<RoutingTable>);
+
 pub struct EventForwarderHandle {
     _handle: JoinHandle<()>,
 }
@@ -26,26 +40,45 @@ pub fn event_bus_from_context(ctx: &AppContext) -> EventBus {
     let bus = Arc::new(build_event_bus(ctx, settings.as_ref()));
 
     if let Some(transport) = ctx.shared_store.get::<Arc<dyn EventTransport>>() {
+        let routing = event_routing_table(ctx, settings.as_ref());
+        warn_on_unmet_reliability(transport.reliability_level());
+        routing.register_sink(PRIMARY_TRANSPORT_SINK, transport);
         let mut receiver = bus.subscribe();
         let consumer_runtime = EventConsumerRuntime::new("server_event_forwarder");
+        let mut shutdown = shutdown_coordinator_from_context(ctx)
+            .token("server_event_forwarder", ShutdownPhase::Flush);
         let handle = tokio::spawn(async move {
             consumer_runtime.restarted("startup");
             loop {
-                match receiver.recv().await {
-                    Ok(envelope) => {
-                        if let Err(error) = transport.publish(envelope).await {
-                            tracing::error!("Failed to publish domain event to transport: {error}");
+                tokio::select! {
+                    biased;
+                    _ = shutdown.triggered() => {
+                        // The dispatcher has drained by now; forward what is
+                        // still buffered so it reaches the transport.
+                        loop {
+                            match receiver.try_recv() {
+                                Ok(envelope) => forward_if_durable(&routing, envelope).await,
+                                Err(TryRecvError::Lagged(skipped)) => {
+                                    consumer_runtime.lagged(skipped);
+                                }
+                                Err(_) => break,
+                            }
                         }
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
-                        consumer_runtime.lagged(skipped);
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                        consumer_runtime.closed();
                         break;
                     }
+                    received = receiver.recv() => match received {
+                        Ok(envelope) => forward_if_durable(&routing, envelope).await,
+                        Err(RecvError::Lagged(skipped)) => {
+                            consumer_runtime.lagged(skipped);
+                        }
+                        Err(RecvError::Closed) => {
+                            consumer_runtime.closed();
+                            break;
+                        }
+                    },
                 }
             }
+            shutdown.acknowledge();
         });
         ctx.shared_store
             .insert(EventForwarderHandle { _handle: handle });
@@ -59,7 +92,86 @@ pub fn event_bus_from_context(ctx: &AppContext) -> EventBus {
     (*bus).clone()
 }
 
+/// Best-effort events already reached in-process listeners through the bus;
+/// only durable ones leave the process.
+async fn forward_if_durable(routing: &RoutingTable, envelope: EventEnvelope) {
+    if envelope.event.required_reliability().is_durable() {
+        routing.dispatch(envelope).await;
+    }
+}
+
+/// Log once at startup when the configured transport is weaker than what
+/// some event types require.
+fn warn_on_unmet_reliability(transport_level: ReliabilityLevel) {
+    let unmet = EVENT_SCHEMAS
+        .iter()
+        .map(|schema| schema.event_type)
+        .filter(|event_type| !transport_level.satisfies(required_reliability(event_type)))
+        .collect::<Vec<_>>();
+
+    if !unmet.is_empty() {
+        tracing::warn!(
+            transport_reliability = transport_level.as_str(),
+            event_types = ?unmet,
+            "Configured event transport does not meet the reliability required by some event types; they will be lost on restart"
+        );
+    }
+}
+
+/// Routing table shared through the app context, created from settings on
+/// first use.
+pub fn event_routing_table(
+    ctx: &AppContext,
+    settings: Option<&RustokSettings>,
+) -> Arc<RoutingTable> {
+    if let Some(shared) = ctx.shared_store.get::<SharedEventRoutingTable>() {
+        return shared.0;
+    }
+
+    let routing = settings
+        .map(|s| &s.events.routing)
+        .cloned()
+        .unwrap_or_default();
+    let table = Arc::new(RoutingTable::new(
+        routing_rules(&routing),
+        routing.default_destinations,
+    ));
+    ctx.shared_store
+        .insert(SharedEventRoutingTable(table.clone()));
+    table
+}
+
+/// Replace the forwarder's routing rules without restarting it.
+pub fn reload_event_routing(ctx: &AppContext, routing: &EventRoutingSettings) {
+    let table = event_routing_table(ctx, None);
+    table.reload(routing_rules(routing), routing.default_destinations.clone());
+    tracing::info!(rules = routing.rules.len(), "Event routing rules reloaded");
+}
+
+fn routing_rules(routing: &EventRoutingSettings) -> Vec<RoutingRule> {
+    routing
+        .rules
+        .iter()
+        .map(|rule| {
+            rule.destinations.iter().fold(
+                RoutingRule::new(rule.name.clone())
+                    .event_types(rule.event_types.iter().cloned())
+                    .tenants(rule.tenant_ids.iter().copied()),
+                |built, destination| built.to(destination.clone()),
+            )
+        })
+        .collect()
+}
+
 fn build_event_bus(ctx: &AppContext, settings: Option<&RustokSettings>) -> EventBus {
+    let bus = build_event_bus_channel(ctx, settings);
+    match settings {
+        Some(settings) => bus.with_max_event_bytes(settings.events.max_event_bytes),
+        None => bus,
+    }
+}
+
+fn build_event_bus_channel(ctx: &AppContext, settings: Option<&RustokSettings>) -> EventBus {
     let Some(runtime) = ctx
         .shared_store
         .get::<Arc<crate::services::event_transport_factory::EventRuntime>>()
@@ -88,3 +200,72 @@ fn build_event_bus(ctx: &AppContext, settings: Option<&RustokSettings>) -> Event
 
     EventBus::with_capacity(runtime.channel_capacity)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::event_bus_from_context;
+    use loco_rs::tests_cfg::app::get_app_context;
+    use rustok_core::events::{DomainEvent, EventTransport};
+    use rustok_test_utils::InMemoryTransport;
+    use std::{sync::Arc, time::Duration};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn event_bus_forwards_durable_events_to_shared_transport() {
+        let ctx = get_app_context().await;
+        let transport = InMemoryTransport::new();
+        ctx.shared_store
+            .insert::<Arc<dyn EventTransport>>(Arc::new(transport.clone()));
+
+        let bus = event_bus_from_context(&ctx);
+        let tenant_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        bus.publish(tenant_id, None, DomainEvent::UserDeleted { user_id })
+            .expect("event should be accepted by the bus");
+
+        let forwarded = transport
+            .wait_for(
+                |envelope| envelope.tenant_id == tenant_id,
+                Duration::from_secs(2),
+            )
+            .await
+            .expect("forwarder should publish the event to the transport");
+        assert!(matches!(
+            forwarded.event,
+            DomainEvent::UserDeleted { user_id: forwarded_id } if forwarded_id == user_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn event_bus_keeps_best_effort_events_in_memory() {
+        let ctx = get_app_context().await;
+        let transport = InMemoryTransport::new();
+        ctx.shared_store
+            .insert::<Arc<dyn EventTransport>>(Arc::new(transport.clone()));
+
+        let bus = event_bus_from_context(&ctx);
+        let mut receiver = bus.subscribe();
+        let tenant_id = Uuid::new_v4();
+        bus.publish(
+            tenant_id,
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        )
+        .expect("event should be accepted by the bus");
+
+        let local = receiver
+            .recv()
+            .await
+            .expect("bus subscribers see the event");
+        assert_eq!(local.tenant_id, tenant_id);
+        assert!(transport
+            .wait_for(
+                |envelope| envelope.tenant_id == tenant_id,
+                Duration::from_millis(200),
+            )
+            .await
+            .is_none());
+    }
+}
@@ -4,6 +4,7 @@ use std::time::Duration;
 use crate::error::{Error, Result};
 use loco_rs::app::AppContext;
 use rustok_core::events::{EventTransport, MemoryTransport};
+use rustok_core::ShutdownToken;
 use rustok_iggy::{IggyConfig, IggyTransport};
 use rustok_outbox::{OutboxRelay, OutboxTransport, RelayConfig};
 use tokio::task::JoinHandle;
@@ -77,23 +78,21 @@ pub async fn build_event_runtime(ctx: &AppContext) -> Result<EventRuntime> {
     }
 }
 
+/// Run the outbox relay until the [`ShutdownPhase::Flush`](rustok_core::ShutdownPhase)
+/// token fires, then relay whatever is still pending once more and
+/// acknowledge, so events written by the drained dispatcher are not left
+/// behind.
 pub fn spawn_outbox_relay_worker(
     config: RelayRuntimeConfig,
-    mut stop_rx: tokio::sync::watch::Receiver<bool>,
+    mut shutdown: ShutdownToken,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
         loop {
-            // Check for shutdown before spawning the inner worker.
-            if *stop_rx.borrow() {
-                tracing::info!("Outbox relay supervisor received shutdown signal, exiting");
-                return;
-            }
-
             let relay = config.relay.clone();
             let interval = config.interval;
 
             // The inner worker is aborted explicitly when the supervisor receives
-            // the stop signal, so it does not need its own stop_rx.
+            // the stop signal, so it does not need its own token.
             let mut inner_handle = tokio::spawn(async move {
                 loop {
                     if let Err(error) = relay.process_pending_once().await {
@@ -105,10 +104,6 @@ pub fn spawn_outbox_relay_worker(
 
             tokio::select! {
                 result = &mut inner_handle => {
-                    if *stop_rx.borrow() {
-                        tracing::info!("Outbox relay supervisor received shutdown signal, exiting");
-                        return;
-                    }
                     if let Err(panic) = result {
                         tracing::error!(
                             "Outbox relay worker panicked: {:?}. Restarting in 5s.",
@@ -116,23 +111,23 @@ pub fn spawn_outbox_relay_worker(
                         );
                         tokio::select! {
                             _ = tokio::time::sleep(Duration::from_secs(5)) => {}
-                            _ = stop_rx.changed() => {
-                                tracing::info!(
-                                    "Outbox relay supervisor received shutdown signal during restart delay, exiting"
-                                );
-                                return;
-                            }
+                            _ = shutdown.triggered() => break,
                         }
                     }
                     // Inner task completed normally (shouldn't happen); loop back.
                 }
-                _ = stop_rx.changed() => {
-                    tracing::info!("Outbox relay supervisor received shutdown signal, exiting");
+                _ = shutdown.triggered() => {
                     inner_handle.abort();
-                    return;
+                    break;
                 }
             }
         }
+
+        tracing::info!("Outbox relay received shutdown signal, flushing pending events");
+        if let Err(error) = config.relay.process_pending_once().await {
+            tracing::error!("Final outbox relay flush failed: {error}");
+        }
+        shutdown.acknowledge();
     })
 }
 
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
 };
@@ -14,7 +14,7 @@ use crate::services::mcp_management::{
     ApplyMcpScaffoldDraftInput, McpManagementService, RecordMcpAuditEventInput,
     StageMcpScaffoldDraftInput,
 };
-use rustok_core::ModuleRegistry;
+use rustok_core::{ModuleRegistry, DEFAULT_CLOCK_LEEWAY};
 use rustok_mcp::{
     ApplyModuleScaffoldRequest, ApplyModuleScaffoldResponse, McpAccessContext, McpAccessPolicy,
     McpAccessResolver, McpActorType, McpAuditSink, McpIdentity, McpRuntimeBinding,
@@ -26,11 +26,21 @@ use rustok_mcp::{
 
 pub struct DbBackedMcpRuntimeBridge {
     db: DatabaseConnection,
+    clock_leeway: Duration,
 }
 
 impl DbBackedMcpRuntimeBridge {
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self {
+            db,
+            clock_leeway: DEFAULT_CLOCK_LEEWAY,
+        }
+    }
+
+    /// Expiry skew tolerated for MCP tokens; pass the auth config's `clock_leeway()`.
+    pub fn with_clock_leeway(mut self, clock_leeway: Duration) -> Self {
+        self.clock_leeway = clock_leeway;
+        self
     }
 
     pub fn shared(db: DatabaseConnection) -> Arc<Self> {
@@ -52,10 +62,14 @@ impl DbBackedMcpRuntimeBridge {
         &self,
         plaintext_token: &str,
     ) -> Result<McpRuntimeBinding> {
-        let token = mcp_tokens::Entity::find_active_by_hash(&self.db, &hash_token(plaintext_token))
-            .await
-            .map_err(map_db_err)?
-            .ok_or_else(|| Error::Unauthorized("Invalid or expired MCP token".into()))?;
+        let token = mcp_tokens::Entity::find_active_by_hash(
+            &self.db,
+            &hash_token(plaintext_token),
+            self.clock_leeway,
+        )
+        .await
+        .map_err(map_db_err)?
+        .ok_or_else(|| Error::Unauthorized("Invalid or expired MCP token".into()))?;
 
         let client = mcp_clients::Entity::find_by_id(token.client_id)
             .filter(mcp_clients::Column::TenantId.eq(token.tenant_id))
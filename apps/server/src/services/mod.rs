@@ -4,6 +4,9 @@ pub mod app_runtime;
 pub mod auth_lifecycle;
 pub mod build_event_hub;
 pub mod build_executor;
+pub mod content_index_projection;
+#[cfg(feature = "mod-content")]
+pub mod content_nodes;
 pub mod content_orchestration;
 pub mod effective_module_policy;
 pub mod email;
@@ -15,6 +18,7 @@ pub mod mcp_management;
 pub mod mcp_runtime;
 pub mod module_event_dispatcher;
 pub mod module_lifecycle;
+pub mod notifications;
 pub mod oauth_app;
 pub mod platform_composition;
 
@@ -33,6 +37,8 @@ pub mod registry_principal;
 pub mod release_backend;
 pub mod runtime_guardrails;
 pub mod settings_service;
+pub mod slow_query_log;
+pub mod tenant_settings;
 pub mod topic_field_service;
 pub mod user_field_service;
 
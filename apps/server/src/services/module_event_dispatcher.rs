@@ -1,32 +1,47 @@
 use loco_rs::app::AppContext;
-use rustok_core::events::{DispatcherConfig, EventDispatcher};
-use rustok_core::{EventBus, ModuleEventListenerContext, ModuleRegistry, ModuleRuntimeExtensions};
+use rustok_core::events::{DispatcherConfig, EventDispatcher, EventHandler};
+use rustok_core::{
+    BackoffPolicy, EventBus, ModuleEventListenerContext, ModuleRegistry, ModuleRuntimeExtensions,
+    ShutdownPhase,
+};
 use rustok_index::IndexerRuntimeConfig;
 use rustok_telemetry::metrics;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::common::settings::RustokSettings;
+use crate::common::settings::{EventDispatcherSettings, RustokSettings};
 
 pub fn spawn_module_event_dispatcher(
     ctx: &AppContext,
     registry: &ModuleRegistry,
     extensions: Arc<ModuleRuntimeExtensions>,
+    settings: &EventDispatcherSettings,
+    host_handlers: Vec<Arc<dyn EventHandler>>,
 ) {
     let bus = crate::services::event_bus::event_bus_from_context(ctx);
     let db = ctx.db.clone();
-    let dispatcher = build_module_event_dispatcher(registry, bus, db, extensions.as_ref());
+    let mut dispatcher =
+        build_module_event_dispatcher(registry, bus, db, extensions.as_ref(), settings);
+    for handler in host_handlers {
+        if let Err(error) = dispatcher.register_boxed(handler) {
+            tracing::error!(%error, "Skipping duplicate host event handler");
+        }
+    }
     let handler_count = dispatcher.handler_count();
     if handler_count == 0 {
-        tracing::info!("No module-owned event listeners registered in ModuleRegistry");
+        tracing::info!("No event listeners registered in ModuleRegistry or by the host");
         return;
     }
 
     let running = dispatcher.start();
+    let mut shutdown = crate::services::app_lifecycle::shutdown_coordinator_from_context(ctx)
+        .token("module_event_dispatcher", ShutdownPhase::Dispatch);
+    let drain_timeout = Duration::from_millis(settings.drain_timeout_ms);
     tokio::spawn(async move {
-        if let Err(error) = running.join().await {
-            tracing::error!("Module event dispatcher panicked: {:?}", error);
-        }
+        shutdown.triggered().await;
+        running.stop_and_drain(drain_timeout).await;
+        shutdown.acknowledge();
     });
 
     tracing::info!(handler_count, "Module event dispatcher initialized");
@@ -61,6 +76,7 @@ pub fn build_shared_runtime_extensions(
         settings.search.reindex.yield_every,
     );
     extensions.insert(indexer_runtime);
+    crate::services::tenant_settings::register_server_tenant_settings(&mut extensions);
     Arc::new(extensions)
 }
 
@@ -69,6 +85,7 @@ pub fn build_module_event_dispatcher(
     bus: EventBus,
     db: DatabaseConnection,
     extensions: &ModuleRuntimeExtensions,
+    settings: &EventDispatcherSettings,
 ) -> EventDispatcher {
     let listener_ctx = ModuleEventListenerContext { db, extensions };
     let handlers = registry.build_event_listeners(&listener_ctx);
@@ -76,13 +93,22 @@ pub fn build_module_event_dispatcher(
         bus,
         DispatcherConfig {
             retry_count: 3,
-            retry_delay_ms: 500,
+            retry_backoff: BackoffPolicy::ExponentialJittered {
+                base: Duration::from_millis(500),
+                max: Duration::from_secs(5),
+                multiplier: 2.0,
+                jitter: 0.2,
+            },
+            max_concurrent: settings.max_concurrent_handlers,
+            concurrency_scope: settings.concurrency_scope,
             ..DispatcherConfig::default()
         },
     );
 
     for handler in handlers {
-        dispatcher.register_boxed(handler);
+        if let Err(error) = dispatcher.register_boxed(handler) {
+            tracing::error!(%error, "Skipping duplicate module event listener");
+        }
     }
 
     dispatcher
@@ -110,8 +136,13 @@ mod tests {
         let db = Database::connect("sqlite::memory:")
             .await
             .expect("in-memory sqlite should connect");
-        let dispatcher =
-            build_module_event_dispatcher(&registry, EventBus::default(), db, extensions.as_ref());
+        let dispatcher = build_module_event_dispatcher(
+            &registry,
+            EventBus::default(),
+            db,
+            extensions.as_ref(),
+            &settings.events.dispatcher,
+        );
 
         let expected = if cfg!(feature = "mod-workflow") { 5 } else { 4 };
         assert_eq!(dispatcher.handler_count(), expected);
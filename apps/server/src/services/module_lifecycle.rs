@@ -1,3 +1,5 @@
+use std::collections::{BTreeSet, HashSet};
+
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
     Set, TransactionTrait,
@@ -232,6 +234,61 @@ pub enum UpdateModuleSettingsError {
     Database(#[from] DbErr),
 }
 
+/// `module_slug` and its enabled transitive dependents, ordered so every
+/// module comes before the modules it depends on.
+fn cascade_disable_order(
+    registry: &ModuleRegistry,
+    enabled_set: &HashSet<String>,
+    module_slug: &str,
+) -> Vec<String> {
+    let mut cascade = BTreeSet::from([module_slug.to_string()]);
+    loop {
+        let dependents: Vec<String> = registry
+            .list()
+            .into_iter()
+            .filter(|module| enabled_set.contains(module.slug()))
+            .filter(|module| !cascade.contains(module.slug()))
+            .filter(|module| {
+                module
+                    .dependencies()
+                    .iter()
+                    .any(|dependency| cascade.contains(*dependency))
+            })
+            .map(|module| module.slug().to_string())
+            .collect();
+        if dependents.is_empty() {
+            break;
+        }
+        cascade.extend(dependents);
+    }
+
+    let mut order = Vec::with_capacity(cascade.len());
+    while !cascade.is_empty() {
+        // A module is ready once no remaining module depends on it.
+        let ready: Vec<String> = cascade
+            .iter()
+            .filter(|slug| {
+                !cascade.iter().any(|other| {
+                    registry
+                        .get(other)
+                        .is_some_and(|module| module.dependencies().contains(&slug.as_str()))
+                })
+            })
+            .cloned()
+            .collect();
+        if ready.is_empty() {
+            // Dependency cycle: fall back to a stable order for the rest.
+            order.extend(std::mem::take(&mut cascade));
+            break;
+        }
+        for slug in ready {
+            cascade.remove(&slug);
+            order.push(slug);
+        }
+    }
+    order
+}
+
 impl ModuleLifecycleService {
     fn generate_correlation_id() -> String {
         uuid::Uuid::new_v4().to_string()
@@ -247,6 +304,51 @@ impl ModuleLifecycleService {
         Self::toggle_module_with_actor(db, registry, tenant_id, module_slug, enabled, None).await
     }
 
+    /// Disable `module_slug` together with every enabled module that depends
+    /// on it, directly or transitively.
+    ///
+    /// Dependents are disabled first, each through
+    /// [`Self::toggle_module_with_actor`] so hooks and the operation journal
+    /// behave as for individual toggles. Nothing is disabled when a module in
+    /// the cascade is a core module; a hook failure stops the cascade with the
+    /// modules disabled so far left disabled. Returns the disabled modules in
+    /// the order they were disabled.
+    pub async fn disable_module_cascade(
+        db: &DatabaseConnection,
+        registry: &ModuleRegistry,
+        tenant_id: uuid::Uuid,
+        module_slug: &str,
+        requested_by: Option<String>,
+    ) -> Result<Vec<tenant_modules::Model>, ToggleModuleError> {
+        if registry.get(module_slug).is_none() {
+            return Err(ToggleModuleError::UnknownModule);
+        }
+        let enabled_set = EffectiveModulePolicyService::resolve_enabled(db, registry, tenant_id)
+            .await
+            .map_err(|error| ToggleModuleError::Policy(error.to_string()))?;
+
+        let order = cascade_disable_order(registry, &enabled_set, module_slug);
+        if let Some(core) = order.iter().find(|slug| registry.is_core(slug)) {
+            return Err(ToggleModuleError::CoreModuleCannotBeDisabled(core.clone()));
+        }
+
+        let mut disabled = Vec::with_capacity(order.len());
+        for slug in order {
+            disabled.push(
+                Self::toggle_module_with_actor(
+                    db,
+                    registry,
+                    tenant_id,
+                    &slug,
+                    false,
+                    requested_by.clone(),
+                )
+                .await?,
+            );
+        }
+        Ok(disabled)
+    }
+
     pub async fn toggle_module_with_actor(
         db: &DatabaseConnection,
         registry: &ModuleRegistry,
@@ -0,0 +1,1212 @@
+//! Notification fan-out.
+//!
+//! Domain events are translated into [`Notification`]s and handed to the
+//! [`NotificationRouter`], which delivers them to every channel configured
+//! for the tenant and notification kind. Channels implement [`Notifier`];
+//! `email`, `webhook` and `log` ship with the server and further channels
+//! (SMS, push) only need another implementation registered on the router.
+//!
+//! Routing comes from `settings.rustok.notifications.routes` and can be
+//! overridden per tenant in the tenant's `settings.notifications.routes`:
+//!
+//! ```json
+//! { "notifications": { "routes": { "order.receipt": ["email", "webhook"] } } }
+//! ```
+//!
+//! Webhooks go to the tenant's `settings.notifications.webhook` endpoint, or
+//! to the platform one from `settings.rustok.notifications.webhook`. Payloads
+//! are signed with the endpoint secret and carry the recipient's address only
+//! when the endpoint sets `include_recipient`. Tenant endpoints must be
+//! `https` and are only connected to on public addresses; redirects are never
+//! followed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use hmac::{Hmac, KeyInit, Mac};
+use loco_rs::app::AppContext;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use thiserror::Error;
+use uuid::Uuid;
+
+use rustok_core::events::{EventHandler, HandlerResult};
+use rustok_email::template::render_tera_string;
+use rustok_email::{EmailError, RenderedEmail, SmtpEmailSender};
+use rustok_events::{DomainEvent, EventEnvelope};
+use rustok_telemetry::metrics;
+
+use crate::common::settings::{EmailProvider, NotificationSettings, RustokSettings};
+use crate::error::{Error, Result};
+use crate::models::tenants;
+
+/// Receipt sent to the customer once an order is paid.
+pub const ORDER_RECEIPT: &str = "order.receipt";
+
+/// Channel names accepted in notification routes.
+pub const NOTIFICATION_CHANNELS: &[&str] = &["email", "webhook", "log"];
+
+/// Header carrying `sha256=<hex>` of `"{timestamp}.{body}"`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Rustok-Signature";
+/// Header carrying the unix timestamp covered by the signature.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Rustok-Timestamp";
+
+type HmacSha256 = Hmac<Sha256>;
+
+mod templates {
+    pub const ORDER_RECEIPT_EN_SUBJECT: &str =
+        include_str!("../mailers/notifications/order_receipt/en/subject.t");
+    pub const ORDER_RECEIPT_EN_TEXT: &str =
+        include_str!("../mailers/notifications/order_receipt/en/text.t");
+
+    pub const ORDER_RECEIPT_RU_SUBJECT: &str =
+        include_str!("../mailers/notifications/order_receipt/ru/subject.t");
+    pub const ORDER_RECEIPT_RU_TEXT: &str =
+        include_str!("../mailers/notifications/order_receipt/ru/text.t");
+}
+
+/// A channel-agnostic message about something that happened in a tenant.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Notification {
+    pub tenant_id: Uuid,
+    /// Notification kind used for routing, e.g. [`ORDER_RECEIPT`].
+    pub kind: String,
+    /// Address for channels that deliver to a person (`email`).
+    pub recipient: Option<String>,
+    pub locale: Option<String>,
+    pub subject: String,
+    pub body: String,
+    /// Structured payload for machine consumers (`webhook`).
+    pub data: Value,
+}
+
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    #[error("notification channel `{0}` is not configured")]
+    UnknownChannel(String),
+    #[error("notification channel `{0}` requires a recipient")]
+    MissingRecipient(&'static str),
+    #[error("no webhook endpoint is configured for tenant {0}")]
+    MissingEndpoint(Uuid),
+    #[error("notification delivery failed: {0}")]
+    Delivery(String),
+}
+
+/// A notification delivery channel.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Channel name referenced by routing configuration.
+    fn channel(&self) -> &'static str;
+
+    async fn notify(&self, notification: Notification) -> std::result::Result<(), NotifyError>;
+}
+
+/// Resolves which channels a notification kind goes to for a tenant.
+#[async_trait]
+pub trait NotificationRoutes: Send + Sync {
+    async fn channels_for(&self, tenant_id: Uuid, kind: &str) -> Vec<String>;
+}
+
+/// The same routes for every tenant.
+#[derive(Debug, Clone, Default)]
+pub struct StaticNotificationRoutes(pub BTreeMap<String, Vec<String>>);
+
+#[async_trait]
+impl NotificationRoutes for StaticNotificationRoutes {
+    async fn channels_for(&self, _tenant_id: Uuid, kind: &str) -> Vec<String> {
+        self.0.get(kind).cloned().unwrap_or_default()
+    }
+}
+
+/// Tenant `settings.notifications.routes` over platform defaults.
+pub struct TenantNotificationRoutes {
+    db: DatabaseConnection,
+    defaults: StaticNotificationRoutes,
+}
+
+impl TenantNotificationRoutes {
+    pub fn new(db: DatabaseConnection, defaults: BTreeMap<String, Vec<String>>) -> Self {
+        Self {
+            db,
+            defaults: StaticNotificationRoutes(defaults),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationRoutes for TenantNotificationRoutes {
+    async fn channels_for(&self, tenant_id: Uuid, kind: &str) -> Vec<String> {
+        match tenants::Entity::find_by_id(&self.db, tenant_id).await {
+            Ok(Some(tenant)) => {
+                if let Some(channels) = tenant_notification_channels(&tenant.settings, kind) {
+                    return channels;
+                }
+            }
+            Ok(None) => {}
+            Err(error) => tracing::warn!(
+                tenant_id = %tenant_id,
+                kind,
+                error = %error,
+                "Failed to load tenant notification routes; using platform defaults"
+            ),
+        }
+        self.defaults.channels_for(tenant_id, kind).await
+    }
+}
+
+/// Channels a tenant configured for `kind` in `settings.notifications.routes`.
+/// An empty list disables the notification for that tenant.
+pub fn tenant_notification_channels(tenant_settings: &Value, kind: &str) -> Option<Vec<String>> {
+    let channels = tenant_settings
+        .pointer("/notifications/routes")?
+        .get(kind)?
+        .as_array()?;
+    Some(
+        channels
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|channel| channel.trim().to_ascii_lowercase())
+            .filter(|channel| !channel.is_empty())
+            .collect(),
+    )
+}
+
+/// Per-channel outcome of one [`NotificationRouter::dispatch`].
+#[derive(Debug, Default)]
+pub struct NotificationReport {
+    pub delivered: Vec<String>,
+    pub failed: Vec<(String, NotifyError)>,
+}
+
+/// Fans notifications out to the channels routed for their tenant and kind.
+pub struct NotificationRouter {
+    channels: HashMap<&'static str, Arc<dyn Notifier>>,
+    routes: Arc<dyn NotificationRoutes>,
+    attempts: u32,
+    backoff: Duration,
+}
+
+impl NotificationRouter {
+    pub fn new(routes: Arc<dyn NotificationRoutes>) -> Self {
+        Self {
+            channels: HashMap::new(),
+            routes,
+            attempts: 1,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    /// Try a failed delivery up to `attempts` times, waiting `backoff` before
+    /// the first retry and twice as long before each further one. Only
+    /// [`NotifyError::Delivery`] is retried.
+    pub fn with_retries(mut self, attempts: u32, backoff: Duration) -> Self {
+        self.attempts = attempts.max(1);
+        self.backoff = backoff;
+        self
+    }
+
+    /// Register a channel; a later channel with the same name replaces it.
+    pub fn with_channel(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.channels.insert(notifier.channel(), notifier);
+        self
+    }
+
+    pub fn channel_names(&self) -> Vec<&'static str> {
+        let mut names = self.channels.keys().copied().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// Deliver `notification` on every routed channel concurrently. A failing
+    /// channel does not affect the others; each outcome is counted in
+    /// `rustok_notifications_total` and each retry in
+    /// `rustok_retry_attempts_total{operation="notification.<channel>"}`.
+    pub async fn dispatch(&self, notification: Notification) -> NotificationReport {
+        let mut channels = self
+            .routes
+            .channels_for(notification.tenant_id, &notification.kind)
+            .await;
+        channels.sort();
+        channels.dedup();
+
+        let deliveries = channels.into_iter().map(|channel| {
+            let notifier = self.channels.get(channel.as_str()).cloned();
+            let notification = notification.clone();
+            async move {
+                let result = match notifier {
+                    Some(notifier) => self.deliver(notifier.as_ref(), notification).await,
+                    None => Err(NotifyError::UnknownChannel(channel.clone())),
+                };
+                (channel, result)
+            }
+        });
+
+        let mut report = NotificationReport::default();
+        for (channel, result) in join_all(deliveries).await {
+            // Route lists come from tenant settings; keep arbitrary names out
+            // of metric labels.
+            let metric_channel = if self.channels.contains_key(channel.as_str()) {
+                channel.as_str()
+            } else {
+                "unknown"
+            };
+            metrics::record_notification(metric_channel, &notification.kind, result.is_ok());
+            match result {
+                Ok(()) => report.delivered.push(channel),
+                Err(error) => {
+                    tracing::warn!(
+                        tenant_id = %notification.tenant_id,
+                        kind = %notification.kind,
+                        channel = %channel,
+                        error = %error,
+                        "Notification delivery failed"
+                    );
+                    report.failed.push((channel, error));
+                }
+            }
+        }
+        report
+    }
+
+    async fn deliver(
+        &self,
+        notifier: &dyn Notifier,
+        notification: Notification,
+    ) -> std::result::Result<(), NotifyError> {
+        let mut backoff = self.backoff;
+        let mut attempt = 1;
+        loop {
+            match notifier.notify(notification.clone()).await {
+                Err(NotifyError::Delivery(error)) if attempt < self.attempts => {
+                    tracing::debug!(
+                        tenant_id = %notification.tenant_id,
+                        kind = %notification.kind,
+                        channel = notifier.channel(),
+                        attempt,
+                        error = %error,
+                        "Notification delivery failed; retrying"
+                    );
+                    metrics::record_retry_attempt(&format!("notification.{}", notifier.channel()));
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Sends notifications to their recipient through the shared SMTP transport.
+pub struct EmailNotifier {
+    sender: Arc<SmtpEmailSender>,
+}
+
+impl EmailNotifier {
+    pub fn new(sender: Arc<SmtpEmailSender>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    fn channel(&self) -> &'static str {
+        "email"
+    }
+
+    async fn notify(&self, notification: Notification) -> std::result::Result<(), NotifyError> {
+        let recipient = notification
+            .recipient
+            .as_deref()
+            .ok_or(NotifyError::MissingRecipient("email"))?;
+        let rendered = RenderedEmail {
+            html: format!(
+                "<p>{}</p>",
+                rustok_core::html_escape(&notification.body).replace('\n', "<br>")
+            ),
+            subject: notification.subject,
+            text: notification.body,
+        };
+        self.sender
+            .send_rendered(recipient, &rendered)
+            .await
+            .map_err(|error| NotifyError::Delivery(error.to_string()))
+    }
+}
+
+/// Where a tenant's `webhook` notifications go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookEndpoint {
+    pub url: String,
+    /// Key for [`WEBHOOK_SIGNATURE_HEADER`].
+    pub secret: String,
+    /// Send [`Notification::recipient`]; the address is left out otherwise.
+    pub include_recipient: bool,
+    /// Tenant-managed endpoint: `https` only, delivered to public addresses
+    /// only. The operator's platform endpoint may point inside the network.
+    pub public_only: bool,
+}
+
+/// Resolves the webhook endpoint of a tenant.
+#[async_trait]
+pub trait WebhookEndpoints: Send + Sync {
+    async fn endpoint_for(&self, tenant_id: Uuid) -> Option<WebhookEndpoint>;
+}
+
+/// Tenant `settings.notifications.webhook` over the platform endpoint.
+pub struct TenantWebhookEndpoints {
+    db: DatabaseConnection,
+    default: Option<WebhookEndpoint>,
+}
+
+impl TenantWebhookEndpoints {
+    pub fn new(db: DatabaseConnection, default: Option<WebhookEndpoint>) -> Self {
+        Self { db, default }
+    }
+}
+
+#[async_trait]
+impl WebhookEndpoints for TenantWebhookEndpoints {
+    async fn endpoint_for(&self, tenant_id: Uuid) -> Option<WebhookEndpoint> {
+        match tenants::Entity::find_by_id(&self.db, tenant_id).await {
+            Ok(Some(tenant)) => {
+                if let Some(endpoint) = tenant_webhook_endpoint(&tenant.settings) {
+                    return Some(endpoint);
+                }
+            }
+            Ok(None) => {}
+            Err(error) => tracing::warn!(
+                tenant_id = %tenant_id,
+                error = %error,
+                "Failed to load tenant webhook endpoint; using the platform endpoint"
+            ),
+        }
+        self.default.clone()
+    }
+}
+
+/// Endpoint a tenant configured in `settings.notifications.webhook`. Both
+/// `url` and `secret` are required.
+pub fn tenant_webhook_endpoint(tenant_settings: &Value) -> Option<WebhookEndpoint> {
+    let webhook = tenant_settings.pointer("/notifications/webhook")?;
+    let non_empty = |key: &str| {
+        webhook
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    Some(WebhookEndpoint {
+        url: non_empty("url")?,
+        secret: non_empty("secret")?,
+        include_recipient: webhook
+            .get("include_recipient")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        public_only: true,
+    })
+}
+
+/// Whether a webhook may connect to `ip`: rejects loopback, private,
+/// link-local (including the `169.254.169.254` metadata service), shared,
+/// reserved and multicast ranges, also when wrapped in IPv6.
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_public_ipv4(mapped);
+            }
+            let segments = ip.segments();
+            // NAT64 (64:ff9b::/96) reaches the embedded IPv4 address.
+            if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                let [.., high, low] = segments;
+                return is_public_ipv4(Ipv4Addr::from((u32::from(high) << 16) | u32::from(low)));
+            }
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || (segments[0] == 0x2001 && segments[1] == 0x0db8))
+        }
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || first == 0
+        || (first == 100 && (64..128).contains(&second))
+        || (first == 198 && (second == 18 || second == 19))
+        || first >= 240)
+}
+
+/// Checks a tenant webhook URL before it is stored or called: `https`, a
+/// host, and no loopback name or non-public IP literal. Hostnames are
+/// checked again when resolved, see [`PublicAddressResolver`].
+pub fn check_public_webhook_url(raw: &str) -> std::result::Result<(), String> {
+    let url = url::Url::parse(raw).map_err(|_| "webhook url must be an https URL".to_string())?;
+    if url.scheme() != "https" {
+        return Err("webhook url must use https".to_string());
+    }
+    match url.host() {
+        None => Err("webhook url must have a host".to_string()),
+        Some(url::Host::Ipv4(ip)) if !is_public_address(IpAddr::V4(ip)) => {
+            Err("webhook url must not point to a private address".to_string())
+        }
+        Some(url::Host::Ipv6(ip)) if !is_public_address(IpAddr::V6(ip)) => {
+            Err("webhook url must not point to a private address".to_string())
+        }
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            if domain == "localhost" || domain.ends_with(".localhost") {
+                Err("webhook url must not point to localhost".to_string())
+            } else {
+                Ok(())
+            }
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// DNS resolver for tenant webhooks that drops non-public addresses, so the
+/// address connected to is the one checked, even if DNS changes after the
+/// URL was validated.
+struct PublicAddressResolver;
+
+impl reqwest::dns::Resolve for PublicAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(resolve_public(name.as_str().to_string()))
+    }
+}
+
+async fn resolve_public(
+    host: String,
+) -> std::result::Result<reqwest::dns::Addrs, Box<dyn std::error::Error + Send + Sync>> {
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+        .await?
+        .filter(|address| is_public_address(address.ip()))
+        .collect();
+    if addresses.is_empty() {
+        return Err(format!("{host} does not resolve to a public address").into());
+    }
+    Ok(Box::new(addresses.into_iter()))
+}
+
+/// Webhook body: the notification, with `recipient` only when the endpoint
+/// opted in.
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    tenant_id: Uuid,
+    kind: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient: Option<&'a str>,
+    locale: Option<&'a str>,
+    subject: &'a str,
+    body: &'a str,
+    data: &'a Value,
+}
+
+/// `sha256=<hex>` HMAC of `"{timestamp}.{body}"`, sent in
+/// [`WEBHOOK_SIGNATURE_HEADER`].
+pub fn webhook_signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// POSTs notifications as signed JSON to the tenant's webhook endpoint.
+/// Redirects are not followed; tenant endpoints go through
+/// [`PublicAddressResolver`].
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    public_client: reqwest::Client,
+    endpoints: Arc<dyn WebhookEndpoints>,
+}
+
+impl WebhookNotifier {
+    pub fn new(endpoints: Arc<dyn WebhookEndpoints>, timeout: Duration) -> Result<Self> {
+        let builder = || {
+            reqwest::Client::builder()
+                .timeout(timeout)
+                .redirect(reqwest::redirect::Policy::none())
+        };
+        let client = builder()
+            .build()
+            .map_err(|error| Error::Message(error.to_string()))?;
+        let public_client = builder()
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            .build()
+            .map_err(|error| Error::Message(error.to_string()))?;
+        Ok(Self {
+            client,
+            public_client,
+            endpoints,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn channel(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, notification: Notification) -> std::result::Result<(), NotifyError> {
+        let endpoint = self
+            .endpoints
+            .endpoint_for(notification.tenant_id)
+            .await
+            .ok_or(NotifyError::MissingEndpoint(notification.tenant_id))?;
+        let payload = WebhookPayload {
+            tenant_id: notification.tenant_id,
+            kind: &notification.kind,
+            recipient: notification
+                .recipient
+                .as_deref()
+                .filter(|_| endpoint.include_recipient),
+            locale: notification.locale.as_deref(),
+            subject: &notification.subject,
+            body: &notification.body,
+            data: &notification.data,
+        };
+        let body = serde_json::to_vec(&payload)
+            .map_err(|error| NotifyError::Delivery(error.to_string()))?;
+        let timestamp = chrono::Utc::now().timestamp();
+        // IP literals bypass the resolver, so check the URL itself as well.
+        let client = if endpoint.public_only {
+            check_public_webhook_url(&endpoint.url).map_err(NotifyError::Delivery)?;
+            &self.public_client
+        } else {
+            &self.client
+        };
+
+        let response = client
+            .post(&endpoint.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Rustok-Notification-Kind", notification.kind.as_str())
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(
+                WEBHOOK_SIGNATURE_HEADER,
+                webhook_signature(&endpoint.secret, timestamp, &body),
+            )
+            .body(body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|error| NotifyError::Delivery(error.to_string()))?;
+        if response.status().is_redirection() {
+            return Err(NotifyError::Delivery(format!(
+                "webhook endpoint answered {}; redirects are not followed",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Writes notifications to the log; useful in development and as an audit
+/// trail next to real channels.
+#[derive(Debug, Default)]
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    fn channel(&self) -> &'static str {
+        "log"
+    }
+
+    async fn notify(&self, notification: Notification) -> std::result::Result<(), NotifyError> {
+        tracing::info!(
+            tenant_id = %notification.tenant_id,
+            kind = %notification.kind,
+            recipient = ?notification.recipient.as_deref().map(mask_email),
+            subject = %notification.subject,
+            "Notification"
+        );
+        Ok(())
+    }
+}
+
+/// `buyer@example.com` -> `b***@example.com`, so logs identify the domain
+/// without the address.
+fn mask_email(address: &str) -> String {
+    match address.split_once('@') {
+        Some((local, domain)) => {
+            let first = local.chars().next().map(String::from).unwrap_or_default();
+            format!("{first}***@{domain}")
+        }
+        None => "***".to_string(),
+    }
+}
+
+/// Build the router from settings: `log` and `webhook` are always available
+/// (webhooks fail for tenants without an endpoint) and `email` when the SMTP
+/// provider is enabled. Returns `None` when notifications are disabled.
+pub fn build_notification_router(
+    ctx: &AppContext,
+    settings: &RustokSettings,
+) -> Result<Option<Arc<NotificationRouter>>> {
+    let config: &NotificationSettings = &settings.notifications;
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let routes = TenantNotificationRoutes::new(ctx.db.clone(), config.routes.clone());
+    let mut router = NotificationRouter::new(Arc::new(routes))
+        .with_retries(
+            config.delivery_attempts,
+            Duration::from_millis(config.retry_backoff_ms),
+        )
+        .with_channel(Arc::new(LogNotifier));
+
+    if settings.email.provider == EmailProvider::Smtp {
+        if let Some(sender) = crate::services::email::shared_smtp_sender(ctx, settings)? {
+            router = router.with_channel(Arc::new(EmailNotifier::new(sender)));
+        }
+    }
+    let endpoints = TenantWebhookEndpoints::new(ctx.db.clone(), platform_webhook_endpoint(config)?);
+    router = router.with_channel(Arc::new(WebhookNotifier::new(
+        Arc::new(endpoints),
+        Duration::from_millis(config.webhook.timeout_ms),
+    )?));
+
+    tracing::info!(channels = ?router.channel_names(), "Notification router initialized");
+    Ok(Some(Arc::new(router)))
+}
+
+fn platform_webhook_endpoint(config: &NotificationSettings) -> Result<Option<WebhookEndpoint>> {
+    let non_empty = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let Some(url) = non_empty(&config.webhook.url) else {
+        return Ok(None);
+    };
+    let secret = non_empty(&config.webhook.secret).ok_or_else(|| {
+        Error::Message(
+            "settings.rustok.notifications.webhook.secret is required when the webhook url is set"
+                .to_string(),
+        )
+    })?;
+    Ok(Some(WebhookEndpoint {
+        url,
+        secret,
+        include_recipient: config.webhook.include_recipient,
+        public_only: false,
+    }))
+}
+
+/// Translates domain events into notifications.
+pub struct NotificationEventHandler {
+    db: DatabaseConnection,
+    router: Arc<NotificationRouter>,
+}
+
+impl NotificationEventHandler {
+    pub fn new(db: DatabaseConnection, router: Arc<NotificationRouter>) -> Self {
+        Self { db, router }
+    }
+
+    #[cfg(all(feature = "mod-order", feature = "mod-customer"))]
+    async fn order_receipt(&self, tenant_id: Uuid, order_id: Uuid) -> HandlerResult {
+        use rustok_customer::entities::customer;
+        use rustok_order::entities::order;
+        use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+        let Some(order) = order::Entity::find_by_id(order_id)
+            .filter(order::Column::TenantId.eq(tenant_id))
+            .one(&self.db)
+            .await?
+        else {
+            return Ok(());
+        };
+        let customer = match order.customer_id {
+            Some(customer_id) => {
+                customer::Entity::find_by_id(customer_id)
+                    .filter(customer::Column::TenantId.eq(tenant_id))
+                    .one(&self.db)
+                    .await?
+            }
+            None => None,
+        };
+
+        let locale = match customer
+            .as_ref()
+            .and_then(|customer| customer.locale.clone())
+        {
+            Some(locale) => Some(locale),
+            None => tenants::Entity::find_by_id(&self.db, tenant_id)
+                .await?
+                .map(|tenant| tenant.default_locale),
+        };
+
+        let notification = order_receipt_notification(
+            tenant_id,
+            order.id,
+            &order.currency_code,
+            &order.total_amount.to_string(),
+            customer.map(|customer| customer.email),
+            locale,
+        )
+        .map_err(|error| rustok_core::Error::External(error.to_string()))?;
+        self.router.dispatch(notification).await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler for NotificationEventHandler {
+    fn name(&self) -> &'static str {
+        "notifications"
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        matches!(
+            event,
+            DomainEvent::OrderStatusChanged { new_status, .. } if new_status == "paid"
+        )
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+        match &envelope.event {
+            #[cfg(all(feature = "mod-order", feature = "mod-customer"))]
+            DomainEvent::OrderStatusChanged {
+                order_id,
+                new_status,
+                ..
+            } if new_status == "paid" => self.order_receipt(envelope.tenant_id, *order_id).await,
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Receipt for a paid order, worded for `locale` (English for unknown
+/// locales). Delivery channels decide what they need: `email` uses
+/// `recipient`, `webhook` the `data` payload.
+pub fn order_receipt_notification(
+    tenant_id: Uuid,
+    order_id: Uuid,
+    currency_code: &str,
+    total_amount: &str,
+    recipient: Option<String>,
+    locale: Option<String>,
+) -> std::result::Result<Notification, EmailError> {
+    let data = serde_json::json!({
+        "order_id": order_id,
+        "currency_code": currency_code,
+        "total_amount": total_amount,
+    });
+    let (subject, body) = if locale
+        .as_deref()
+        .is_some_and(|locale| locale.starts_with("ru"))
+    {
+        (
+            templates::ORDER_RECEIPT_RU_SUBJECT,
+            templates::ORDER_RECEIPT_RU_TEXT,
+        )
+    } else {
+        (
+            templates::ORDER_RECEIPT_EN_SUBJECT,
+            templates::ORDER_RECEIPT_EN_TEXT,
+        )
+    };
+
+    Ok(Notification {
+        tenant_id,
+        kind: ORDER_RECEIPT.to_string(),
+        recipient,
+        locale,
+        subject: render_tera_string(subject.trim(), &data)?,
+        body: render_tera_string(body.trim_end(), &data)?,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        sent: Mutex<Vec<Notification>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        fn channel(&self) -> &'static str {
+            "email"
+        }
+
+        async fn notify(&self, notification: Notification) -> std::result::Result<(), NotifyError> {
+            self.sent.lock().unwrap().push(notification);
+            Ok(())
+        }
+    }
+
+    struct FailingNotifier;
+
+    #[async_trait]
+    impl Notifier for FailingNotifier {
+        fn channel(&self) -> &'static str {
+            "webhook"
+        }
+
+        async fn notify(&self, _: Notification) -> std::result::Result<(), NotifyError> {
+            Err(NotifyError::Delivery("connection refused".to_string()))
+        }
+    }
+
+    fn routes(channels: &[&str]) -> Arc<dyn NotificationRoutes> {
+        Arc::new(StaticNotificationRoutes(BTreeMap::from([(
+            ORDER_RECEIPT.to_string(),
+            channels.iter().map(|channel| channel.to_string()).collect(),
+        )])))
+    }
+
+    fn receipt() -> Notification {
+        order_receipt_notification(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "EUR",
+            "48.75",
+            Some("buyer@example.com".to_string()),
+            None,
+        )
+        .unwrap()
+    }
+
+    /// Fails with a delivery error until `failures` attempts were made.
+    struct FlakyNotifier {
+        failures: u32,
+        attempts: Mutex<u32>,
+    }
+
+    #[async_trait]
+    impl Notifier for FlakyNotifier {
+        fn channel(&self) -> &'static str {
+            "webhook"
+        }
+
+        async fn notify(&self, _: Notification) -> std::result::Result<(), NotifyError> {
+            let mut attempts = self.attempts.lock().unwrap();
+            *attempts += 1;
+            if *attempts <= self.failures {
+                return Err(NotifyError::Delivery("503 Service Unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    struct FixedEndpoint(Option<WebhookEndpoint>);
+
+    #[async_trait]
+    impl WebhookEndpoints for FixedEndpoint {
+        async fn endpoint_for(&self, _: Uuid) -> Option<WebhookEndpoint> {
+            self.0.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_fans_out_and_isolates_channel_failures() {
+        let email = Arc::new(RecordingNotifier::default());
+        let router = NotificationRouter::new(routes(&["email", "webhook", "sms", "email"]))
+            .with_channel(email.clone())
+            .with_channel(Arc::new(FailingNotifier))
+            .with_channel(Arc::new(LogNotifier));
+
+        let report = router.dispatch(receipt()).await;
+
+        assert_eq!(report.delivered, vec!["email".to_string()]);
+        let failed = report
+            .failed
+            .iter()
+            .map(|(channel, error)| (channel.as_str(), error.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            failed,
+            vec![
+                (
+                    "sms",
+                    "notification channel `sms` is not configured".to_string()
+                ),
+                (
+                    "webhook",
+                    "notification delivery failed: connection refused".to_string()
+                ),
+            ]
+        );
+        let sent = email.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].recipient.as_deref(), Some("buyer@example.com"));
+    }
+
+    #[tokio::test]
+    async fn unrouted_kinds_are_not_delivered() {
+        let email = Arc::new(RecordingNotifier::default());
+        let router = NotificationRouter::new(routes(&["email"])).with_channel(email.clone());
+
+        let report = router
+            .dispatch(Notification {
+                kind: "content.published".to_string(),
+                ..receipt()
+            })
+            .await;
+
+        assert!(report.delivered.is_empty() && report.failed.is_empty());
+        assert!(email.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn dispatch_retries_delivery_errors() {
+        let flaky = Arc::new(FlakyNotifier {
+            failures: 2,
+            attempts: Mutex::new(0),
+        });
+        let router = NotificationRouter::new(routes(&["webhook"]))
+            .with_retries(3, Duration::ZERO)
+            .with_channel(flaky.clone());
+
+        let report = router.dispatch(receipt()).await;
+
+        assert_eq!(report.delivered, vec!["webhook".to_string()]);
+        assert_eq!(*flaky.attempts.lock().unwrap(), 3);
+
+        let flaky = Arc::new(FlakyNotifier {
+            failures: 5,
+            attempts: Mutex::new(0),
+        });
+        let router = NotificationRouter::new(routes(&["webhook"]))
+            .with_retries(2, Duration::ZERO)
+            .with_channel(flaky.clone());
+
+        let report = router.dispatch(receipt()).await;
+
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(*flaky.attempts.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn webhook_without_endpoint_is_not_retried() {
+        let notifier =
+            WebhookNotifier::new(Arc::new(FixedEndpoint(None)), Duration::from_secs(1)).unwrap();
+        let router = NotificationRouter::new(routes(&["webhook"]))
+            .with_retries(3, Duration::ZERO)
+            .with_channel(Arc::new(notifier));
+
+        let report = router.dispatch(receipt()).await;
+
+        assert!(matches!(
+            report.failed.as_slice(),
+            [(_, NotifyError::MissingEndpoint(_))]
+        ));
+    }
+
+    #[test]
+    fn webhook_payload_omits_recipient_unless_opted_in() {
+        let notification = receipt();
+        let payload = |include_recipient: bool| {
+            serde_json::to_value(WebhookPayload {
+                tenant_id: notification.tenant_id,
+                kind: &notification.kind,
+                recipient: notification
+                    .recipient
+                    .as_deref()
+                    .filter(|_| include_recipient),
+                locale: notification.locale.as_deref(),
+                subject: &notification.subject,
+                body: &notification.body,
+                data: &notification.data,
+            })
+            .unwrap()
+        };
+
+        assert!(payload(false).get("recipient").is_none());
+        assert_eq!(payload(true)["recipient"], "buyer@example.com");
+    }
+
+    #[test]
+    fn webhook_signature_covers_timestamp_and_body() {
+        let signature = webhook_signature("secret", 1_700_000_000, br#"{"kind":"order.receipt"}"#);
+
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature.len(), "sha256=".len() + 64);
+        assert_eq!(
+            signature,
+            webhook_signature("secret", 1_700_000_000, br#"{"kind":"order.receipt"}"#)
+        );
+        assert_ne!(
+            signature,
+            webhook_signature("secret", 1_700_000_001, br#"{"kind":"order.receipt"}"#)
+        );
+        assert_ne!(
+            signature,
+            webhook_signature("other", 1_700_000_000, br#"{"kind":"order.receipt"}"#)
+        );
+    }
+
+    #[test]
+    fn reads_tenant_webhook_endpoint() {
+        let settings = json!({
+            "notifications": {
+                "webhook": {
+                    "url": " https://hooks.example.com/rustok ",
+                    "secret": "tenant-key",
+                    "include_recipient": true
+                }
+            }
+        });
+
+        assert_eq!(
+            tenant_webhook_endpoint(&settings),
+            Some(WebhookEndpoint {
+                url: "https://hooks.example.com/rustok".to_string(),
+                secret: "tenant-key".to_string(),
+                include_recipient: true,
+                public_only: true,
+            })
+        );
+        assert_eq!(
+            tenant_webhook_endpoint(&json!({
+                "notifications": { "webhook": { "url": "https://hooks.example.com" } }
+            })),
+            None
+        );
+    }
+
+    #[test]
+    fn webhook_addresses_must_be_public() {
+        for blocked in [
+            "127.0.0.1",
+            "10.0.0.5",
+            "172.16.3.4",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a9fe:a9fe",
+        ] {
+            assert!(
+                !is_public_address(blocked.parse().unwrap()),
+                "{blocked} should be rejected"
+            );
+        }
+        for allowed in ["93.184.216.34", "2606:4700:4700::1111"] {
+            assert!(is_public_address(allowed.parse().unwrap()));
+        }
+    }
+
+    #[test]
+    fn tenant_webhook_url_must_be_public_https() {
+        assert!(check_public_webhook_url("https://hooks.example.com/rustok").is_ok());
+        for rejected in [
+            "http://hooks.example.com",
+            "https://localhost:8443/hook",
+            "https://api.localhost/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/hook",
+            "https://10.1.2.3/hook",
+            "not a url",
+        ] {
+            assert!(
+                check_public_webhook_url(rejected).is_err(),
+                "{rejected} should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn public_resolver_refuses_loopback_names() {
+        use reqwest::dns::Resolve;
+
+        let name: reqwest::dns::Name = "localhost".parse().unwrap();
+        assert!(PublicAddressResolver.resolve(name).await.is_err());
+    }
+
+    #[test]
+    fn masks_recipient_email() {
+        assert_eq!(mask_email("buyer@example.com"), "b***@example.com");
+        assert_eq!(mask_email("not-an-address"), "***");
+    }
+
+    #[test]
+    fn reads_tenant_notification_channels() {
+        let settings = json!({
+            "notifications": {
+                "routes": {
+                    "order.receipt": ["Email", " webhook ", 3, ""],
+                    "content.published": []
+                }
+            }
+        });
+
+        assert_eq!(
+            tenant_notification_channels(&settings, ORDER_RECEIPT),
+            Some(vec!["email".to_string(), "webhook".to_string()])
+        );
+        assert_eq!(
+            tenant_notification_channels(&settings, "content.published"),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            tenant_notification_channels(&settings, "order.shipped"),
+            None
+        );
+        assert_eq!(
+            tenant_notification_channels(&json!({}), ORDER_RECEIPT),
+            None
+        );
+    }
+
+    #[test]
+    fn order_receipt_carries_total_for_every_channel() {
+        let notification = receipt();
+
+        assert_eq!(notification.kind, ORDER_RECEIPT);
+        assert!(notification.subject.starts_with("Receipt for order"));
+        assert!(notification.body.contains("48.75 EUR"));
+        assert_eq!(notification.data["total_amount"], "48.75");
+    }
+
+    #[test]
+    fn order_receipt_uses_the_locale() {
+        let notification = order_receipt_notification(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "RUB",
+            "1200.00",
+            None,
+            Some("ru-RU".to_string()),
+        )
+        .unwrap();
+
+        assert!(notification.subject.starts_with("Чек по заказу"));
+        assert!(notification.body.contains("Сумма: 1200.00 RUB"));
+    }
+}
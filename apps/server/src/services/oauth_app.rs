@@ -404,7 +404,7 @@ impl OAuthAppService {
             .ok_or_else(|| Error::Unauthorized("Invalid or expired code".into()))?;
 
         // Validations
-        if !auth_code.is_active() {
+        if !auth_code.is_active(auth_config.clock_leeway()) {
             return Err(Error::Unauthorized(
                 "Code is expired or already used".into(),
             ));
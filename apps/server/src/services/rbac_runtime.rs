@@ -1,13 +1,14 @@
 use crate::error::Error;
 use crate::error::Result;
 use async_trait::async_trait;
-use moka::future::Cache;
 use once_cell::sync::Lazy;
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use serde::Serialize;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use rustok_core::{Action, Permission, Resource, UserRole};
+use rustok_core::{Action, CacheBackend, InMemoryCacheBackend, Permission, Resource, UserRole};
 use rustok_rbac::{
     authorize_all_permissions, authorize_any_permission, authorize_permission,
     invalidate_cached_permissions, AuthorizationDecision, DeniedReasonKind, PermissionCache,
@@ -23,7 +24,7 @@ use super::rbac_persistence::{
 
 pub(crate) type ServerRuntimePermissionResolver = RuntimePermissionResolver<
     SeaOrmRelationPermissionStore,
-    MokaPermissionCache,
+    BackendPermissionCache,
     ServerRoleAssignmentStore,
     Error,
 >;
@@ -40,7 +41,7 @@ pub(crate) struct AuthorizationRuntimeOutcome {
     pub latency_ms: u64,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct RbacResolverMetricsSnapshot {
     pub permission_cache_hits: u64,
     pub permission_cache_misses: u64,
@@ -75,19 +76,21 @@ static RBAC_ENGINE_DECISIONS_CASBIN_TOTAL: AtomicU64 = AtomicU64::new(0);
 static RBAC_ENGINE_EVAL_DURATION_MS_TOTAL: AtomicU64 = AtomicU64::new(0);
 static RBAC_ENGINE_EVAL_DURATION_SAMPLES: AtomicU64 = AtomicU64::new(0);
 
-static USER_PERMISSION_CACHE: Lazy<Cache<(uuid::Uuid, uuid::Uuid), Vec<Permission>>> =
-    Lazy::new(|| {
-        Cache::builder()
-            .max_capacity(20_000)
-            .time_to_live(Duration::from_secs(60))
-            .build()
-    });
+// Hit/miss and size metrics are reported by the resolver under
+// `rustok_rbac::PERMISSION_CACHE_METRICS_NAME`, so the backend is not wrapped
+// in `InstrumentedCacheBackend` to avoid double counting.
+static USER_PERMISSION_CACHE: Lazy<Arc<dyn CacheBackend>> =
+    Lazy::new(|| Arc::new(InMemoryCacheBackend::new(Duration::from_secs(60), 20_000)));
+
+fn permission_cache_key(tenant_id: &uuid::Uuid, user_id: &uuid::Uuid) -> String {
+    format!("{tenant_id}:{user_id}")
+}
 
 pub(crate) async fn invalidate_user_permissions_cache(
     tenant_id: &uuid::Uuid,
     user_id: &uuid::Uuid,
 ) {
-    let cache = MokaPermissionCache;
+    let cache = BackendPermissionCache;
     invalidate_cached_permissions(&cache, tenant_id, user_id).await;
 }
 
@@ -136,7 +139,7 @@ pub(crate) fn observe_authorization_decision(decision: &AuthorizationDecision, l
 pub(crate) fn resolver(db: &DatabaseConnection) -> ServerRuntimePermissionResolver {
     RuntimePermissionResolver::new(
         SeaOrmRelationPermissionStore { db: db.clone() },
-        MokaPermissionCache,
+        BackendPermissionCache,
         ServerRoleAssignmentStore { db: db.clone() },
     )
 }
@@ -243,7 +246,7 @@ pub(crate) struct SeaOrmRelationPermissionStore {
 }
 
 #[derive(Clone)]
-pub(crate) struct MokaPermissionCache;
+pub(crate) struct BackendPermissionCache;
 
 #[derive(Clone)]
 pub(crate) struct ServerRoleAssignmentStore {
@@ -251,9 +254,17 @@ pub(crate) struct ServerRoleAssignmentStore {
 }
 
 #[async_trait]
-impl PermissionCache for MokaPermissionCache {
+impl PermissionCache for BackendPermissionCache {
     async fn get(&self, tenant_id: &uuid::Uuid, user_id: &uuid::Uuid) -> Option<Vec<Permission>> {
-        USER_PERMISSION_CACHE.get(&(*tenant_id, *user_id)).await
+        let key = permission_cache_key(tenant_id, user_id);
+        match USER_PERMISSION_CACHE.get(&key).await {
+            Ok(Some(payload)) => serde_json::from_slice(&payload).ok(),
+            Ok(None) => None,
+            Err(error) => {
+                tracing::debug!(%error, "RBAC permission cache read failed");
+                None
+            }
+        }
     }
 
     async fn insert(
@@ -262,15 +273,28 @@ impl PermissionCache for MokaPermissionCache {
         user_id: &uuid::Uuid,
         permissions: Vec<Permission>,
     ) {
-        USER_PERMISSION_CACHE
-            .insert((*tenant_id, *user_id), permissions)
-            .await;
+        let Ok(payload) = serde_json::to_vec(&permissions) else {
+            return;
+        };
+        if let Err(error) = USER_PERMISSION_CACHE
+            .set(permission_cache_key(tenant_id, user_id), payload)
+            .await
+        {
+            tracing::debug!(%error, "RBAC permission cache write failed");
+        }
     }
 
     async fn invalidate(&self, tenant_id: &uuid::Uuid, user_id: &uuid::Uuid) {
-        USER_PERMISSION_CACHE
-            .invalidate(&(*tenant_id, *user_id))
-            .await;
+        if let Err(error) = USER_PERMISSION_CACHE
+            .invalidate(&permission_cache_key(tenant_id, user_id))
+            .await
+        {
+            tracing::debug!(%error, "RBAC permission cache invalidation failed");
+        }
+    }
+
+    fn entry_count(&self) -> Option<u64> {
+        Some(USER_PERMISSION_CACHE.stats().entries)
     }
 }
 
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use sea_orm::DatabaseConnection;
+
+/// Longest SQL text written to a single slow-query log line.
+const MAX_LOGGED_STATEMENT_LEN: usize = 2_000;
+
+/// Log every statement on `db` that runs longer than `threshold` and count it in
+/// `rustok_db_slow_queries_total`. Works regardless of `database.enable_logging`.
+pub fn install_slow_query_logger(db: &mut DatabaseConnection, threshold: Duration) {
+    db.set_metric_callback(move |info| {
+        if info.elapsed < threshold {
+            return;
+        }
+
+        let sql = info.statement.sql.as_str();
+        let query_type = query_type(sql);
+        rustok_telemetry::metrics::record_db_slow_query(query_type);
+        tracing::warn!(
+            query_type,
+            elapsed_ms = info.elapsed.as_millis() as u64,
+            threshold_ms = threshold.as_millis() as u64,
+            failed = info.failed,
+            statement = truncate_statement(sql),
+            "Slow database query"
+        );
+    });
+}
+
+/// Leading SQL keyword, upper-cased, for the metric label; `OTHER` when unknown.
+fn query_type(sql: &str) -> &'static str {
+    let keyword = sql
+        .trim_start()
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .unwrap_or_default();
+    match keyword.to_ascii_uppercase().as_str() {
+        "SELECT" => "SELECT",
+        "INSERT" => "INSERT",
+        "UPDATE" => "UPDATE",
+        "DELETE" => "DELETE",
+        "WITH" => "WITH",
+        _ => "OTHER",
+    }
+}
+
+fn truncate_statement(sql: &str) -> &str {
+    if sql.len() <= MAX_LOGGED_STATEMENT_LEN {
+        return sql;
+    }
+    let mut end = MAX_LOGGED_STATEMENT_LEN;
+    while !sql.is_char_boundary(end) {
+        end -= 1;
+    }
+    &sql[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{query_type, truncate_statement, MAX_LOGGED_STATEMENT_LEN};
+
+    #[test]
+    fn classifies_leading_keyword() {
+        assert_eq!(query_type("SELECT * FROM nodes"), "SELECT");
+        assert_eq!(query_type("  insert into orders values (1)"), "INSERT");
+        assert_eq!(query_type("WITH x AS (SELECT 1) SELECT * FROM x"), "WITH");
+        assert_eq!(query_type("VACUUM"), "OTHER");
+        assert_eq!(query_type(""), "OTHER");
+    }
+
+    #[test]
+    fn truncates_long_statements_on_char_boundary() {
+        let sql = "é".repeat(MAX_LOGGED_STATEMENT_LEN);
+        let truncated = truncate_statement(&sql);
+        assert!(truncated.len() <= MAX_LOGGED_STATEMENT_LEN);
+        assert!(sql.starts_with(truncated));
+        assert_eq!(truncate_statement("SELECT 1"), "SELECT 1");
+    }
+}
@@ -0,0 +1,152 @@
+//! Tenant settings owned by the server itself rather than a module.
+//!
+//! Modules declare their own keys from `register_runtime_extensions`; the
+//! keys below are read by server middleware and services, so they are
+//! declared next to the module ones when the runtime extensions are built.
+
+use rustok_core::ModuleRuntimeExtensions;
+use rustok_tenant::{register_tenant_setting, SettingDefinition, SettingType};
+use serde_json::{json, Value};
+
+use crate::middleware::cors::normalize_origin;
+use crate::services::notifications::{check_public_webhook_url, NOTIFICATION_CHANNELS};
+
+pub const CORS_ALLOWED_ORIGINS_SETTING: &str = "cors.allowed_origins";
+pub const NOTIFICATION_ROUTES_SETTING: &str = "notifications.routes";
+pub const NOTIFICATION_WEBHOOK_SETTING: &str = "notifications.webhook";
+
+pub fn register_server_tenant_settings(extensions: &mut ModuleRuntimeExtensions) {
+    for definition in [
+        cors_allowed_origins_setting(),
+        notification_routes_setting(),
+        notification_webhook_setting(),
+    ] {
+        register_tenant_setting(extensions, definition)
+            .expect("server tenant settings should register once");
+    }
+}
+
+fn cors_allowed_origins_setting() -> SettingDefinition {
+    SettingDefinition::new(CORS_ALLOWED_ORIGINS_SETTING, SettingType::Array, json!([]))
+        .with_description("Extra origins allowed to call auth endpoints, as scheme://host[:port]")
+        .with_validator(|value| {
+            for origin in value.as_array().into_iter().flatten() {
+                let valid = origin.as_str().and_then(normalize_origin).is_some();
+                if !valid {
+                    return Err(format!("invalid origin {origin}"));
+                }
+            }
+            Ok(())
+        })
+}
+
+fn notification_routes_setting() -> SettingDefinition {
+    SettingDefinition::new(NOTIFICATION_ROUTES_SETTING, SettingType::Object, json!({}))
+        .with_description("Notification channels per kind; an empty list disables the kind")
+        .with_validator(|value| {
+            for (kind, channels) in value.as_object().into_iter().flatten() {
+                let channels = channels
+                    .as_array()
+                    .ok_or_else(|| format!("routes for '{kind}' must be an array"))?;
+                for channel in channels {
+                    let known = channel
+                        .as_str()
+                        .is_some_and(|channel| NOTIFICATION_CHANNELS.contains(&channel));
+                    if !known {
+                        return Err(format!(
+                            "unknown channel {channel} for '{kind}'; expected one of {}",
+                            NOTIFICATION_CHANNELS.join(", ")
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        })
+}
+
+fn notification_webhook_setting() -> SettingDefinition {
+    SettingDefinition::new(NOTIFICATION_WEBHOOK_SETTING, SettingType::Object, json!({}))
+        .with_description(
+            "Webhook endpoint as {url, secret, include_recipient}; url must be public https, \
+             payloads are signed with secret, which is write-only",
+        )
+        .with_write_only_fields(&["secret"])
+        .with_validator(|value| {
+            let Some(webhook) = value.as_object().filter(|webhook| !webhook.is_empty()) else {
+                return Ok(());
+            };
+            let url = webhook
+                .get("url")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            check_public_webhook_url(url.trim())?;
+            let has_secret = webhook
+                .get("secret")
+                .and_then(Value::as_str)
+                .is_some_and(|secret| !secret.trim().is_empty());
+            if !has_secret {
+                return Err("webhook secret is required".to_string());
+            }
+            if webhook
+                .get("include_recipient")
+                .is_some_and(|include| !include.is_boolean())
+            {
+                return Err("include_recipient must be a boolean".to_string());
+            }
+            Ok(())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rustok_tenant::settings_schema_from_extensions;
+    use serde_json::json;
+
+    use super::{
+        CORS_ALLOWED_ORIGINS_SETTING, NOTIFICATION_ROUTES_SETTING, NOTIFICATION_WEBHOOK_SETTING,
+    };
+    use crate::common::settings::RustokSettings;
+    use crate::services::module_event_dispatcher::build_shared_runtime_extensions;
+
+    #[test]
+    fn server_runtime_declares_tenant_settings() {
+        let registry = crate::modules::build_registry();
+        let extensions = build_shared_runtime_extensions(&registry, &RustokSettings::default());
+        let schema = settings_schema_from_extensions(&extensions)
+            .expect("server runtime should declare tenant settings");
+
+        let cors = schema.definition(CORS_ALLOWED_ORIGINS_SETTING).unwrap();
+        assert!(cors.validate(&json!(["https://shop.example.com"])).is_ok());
+        assert!(cors.validate(&json!(["shop.example.com"])).is_err());
+
+        let routes = schema.definition(NOTIFICATION_ROUTES_SETTING).unwrap();
+        assert!(routes
+            .validate(&json!({"order.receipt": ["email", "webhook"]}))
+            .is_ok());
+        assert!(routes.validate(&json!({"order.receipt": ["sms"]})).is_err());
+
+        let webhook = schema.definition(NOTIFICATION_WEBHOOK_SETTING).unwrap();
+        assert!(webhook
+            .validate(&json!({"url": "https://hooks.example.com", "secret": "key"}))
+            .is_ok());
+        assert!(webhook.validate(&json!({})).is_ok());
+        assert!(webhook
+            .validate(&json!({"url": "https://hooks.example.com"}))
+            .is_err());
+        assert!(webhook
+            .validate(&json!({"url": "http://hooks.example.com", "secret": "key"}))
+            .is_err());
+        assert!(webhook
+            .validate(&json!({"url": "https://169.254.169.254/latest", "secret": "key"}))
+            .is_err());
+        assert_eq!(
+            webhook.redacted(&json!({"url": "https://hooks.example.com", "secret": "key"})),
+            json!({"url": "https://hooks.example.com"})
+        );
+
+        #[cfg(feature = "mod-content")]
+        assert!(schema.get("content.internal_metadata_keys").is_some());
+        #[cfg(feature = "mod-pages")]
+        assert!(schema.get("pages.layouts").is_some());
+    }
+}
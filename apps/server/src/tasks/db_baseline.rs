@@ -196,8 +196,17 @@ fn hot_path_specs(backend: DbBackend, tenant_id: Uuid) -> Vec<HotPathSpec> {
     let current_period_start = now - Duration::days(30);
     let previous_period_start = current_period_start - Duration::days(30);
     let tenant_id_string = tenant_id.to_string();
+    let recent_events = rustok_outbox::audit::audit_entries_statement(backend, tenant_id, 20);
+    let recent_events = HotPathSpec {
+        name: "root.recent_activity.recent_events",
+        sql: recent_events.sql,
+        values: recent_events
+            .values
+            .map(|values| values.0)
+            .unwrap_or_default(),
+    };
 
-    match backend {
+    let mut specs = match backend {
         DbBackend::Sqlite => vec![
             HotPathSpec {
                 name: "root.users.count",
@@ -274,12 +283,6 @@ fn hot_path_specs(backend: DbBackend, tenant_id: Uuid) -> Vec<HotPathSpec> {
                     previous_period_start.into(),
                 ],
             },
-            HotPathSpec {
-                name: "root.recent_activity.recent_users",
-                sql: "SELECT * FROM users WHERE tenant_id = ?1 ORDER BY created_at DESC LIMIT ?2"
-                    .to_string(),
-                values: vec![tenant_id.into(), 20_i64.into()],
-            },
         ],
         _ => vec![
             HotPathSpec {
@@ -357,14 +360,10 @@ fn hot_path_specs(backend: DbBackend, tenant_id: Uuid) -> Vec<HotPathSpec> {
                     previous_period_start.into(),
                 ],
             },
-            HotPathSpec {
-                name: "root.recent_activity.recent_users",
-                sql: "SELECT * FROM users WHERE tenant_id = $1 ORDER BY created_at DESC LIMIT $2"
-                    .to_string(),
-                values: vec![tenant_id.into(), 20_i64.into()],
-            },
         ],
-    }
+    };
+    specs.push(recent_events);
+    specs
 }
 
 fn explain_sql(backend: DbBackend, sql: &str) -> String {
@@ -12,6 +12,7 @@ mod media_cleanup;
 #[cfg(feature = "mod-profiles")]
 mod profiles_backfill;
 mod rebuild;
+mod rebuild_projection;
 
 /// Register all available tasks
 pub fn register(tasks: &mut Tasks) {
@@ -23,4 +24,5 @@ pub fn register(tasks: &mut Tasks) {
     #[cfg(feature = "mod-profiles")]
     tasks.register(profiles_backfill::ProfilesBackfillTask);
     tasks.register(rebuild::RebuildTask);
+    tasks.register(rebuild_projection::RebuildProjectionTask);
 }
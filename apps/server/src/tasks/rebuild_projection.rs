@@ -0,0 +1,77 @@
+//! Projection Rebuild Task
+//!
+//! Replays the Iggy domain topic through a single read-model projection into a
+//! shadow table and swaps it in. Other event handlers do not see the replay.
+//!
+//! Run manually:
+//! ```text
+//! cargo loco task --name rebuild_projection projection:index_content
+//! cargo loco task --name rebuild_projection projection:index_content from:2026-01-01T00:00:00Z
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use loco_rs::{
+    app::AppContext,
+    task::{Task, TaskInfo, Vars},
+    Error, Result,
+};
+use rustok_iggy::IggyTransport;
+
+use crate::common::settings::RustokSettings;
+use crate::services::content_index_projection::ContentIndexProjection;
+
+pub struct RebuildProjectionTask;
+
+#[async_trait]
+impl Task for RebuildProjectionTask {
+    fn task(&self) -> TaskInfo {
+        TaskInfo {
+            name: "rebuild_projection".to_string(),
+            detail: "Rebuild one read-model projection from the Iggy domain topic (projection:index_content [from:<RFC 3339>])".to_string(),
+        }
+    }
+
+    async fn run(&self, ctx: &AppContext, vars: &Vars) -> Result<()> {
+        let projection = vars
+            .cli
+            .get("projection")
+            .ok_or_else(|| Error::Message("projection is required".to_string()))?;
+        let from = vars
+            .cli
+            .get("from")
+            .map(|raw| parse_from(raw))
+            .transpose()?;
+
+        let settings = RustokSettings::from_settings(&ctx.config.settings)
+            .map_err(|error| Error::Message(format!("Invalid rustok settings: {error}")))?;
+        let transport = IggyTransport::new(settings.events.iggy.clone())
+            .await
+            .map_err(|error| Error::Message(format!("Failed to connect to Iggy: {error}")))?;
+
+        let rebuilder = transport
+            .projection_rebuilder()
+            .register(Arc::new(ContentIndexProjection::new(ctx.db.clone())));
+        let result = rebuilder.rebuild_projection(projection, from).await;
+
+        if let Err(error) = transport.shutdown().await {
+            tracing::warn!(error = %error, "Failed to shut down Iggy transport after rebuild");
+        }
+        let report = result
+            .map_err(|error| Error::Message(format!("Projection rebuild failed: {error}")))?;
+
+        let payload = serde_json::to_string_pretty(&report).map_err(|error| {
+            Error::Message(format!("Failed to serialize rebuild report: {error}"))
+        })?;
+        println!("{payload}");
+        Ok(())
+    }
+}
+
+fn parse_from(raw: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|error| Error::Message(format!("Invalid from '{raw}': {error}")))
+}
@@ -577,6 +577,57 @@ async fn dependent_validation_failure_does_not_create_journal_row() {
     assert!(operations[0].requested_enabled);
 }
 
+#[tokio::test]
+async fn cascade_disable_turns_off_transitive_dependents_first() {
+    let db = setup_db().await;
+    let tenant_id = uuid::Uuid::new_v4();
+    seed_tenant(&db, tenant_id).await;
+
+    let registry = ModuleRegistry::new()
+        .register(TestModule::new("pricing"))
+        .register(DependentModule {
+            slug: "checkout",
+            dependency: "pricing",
+        })
+        .register(DependentModule {
+            slug: "express-checkout",
+            dependency: "checkout",
+        })
+        .register(TestModule::new("unrelated"));
+
+    for slug in ["pricing", "checkout", "express-checkout", "unrelated"] {
+        ModuleLifecycleService::toggle_module(&db, &registry, tenant_id, slug, true)
+            .await
+            .expect("enable module");
+    }
+
+    let disabled = ModuleLifecycleService::disable_module_cascade(
+        &db,
+        &registry,
+        tenant_id,
+        "pricing",
+        Some("admin@example.com".to_string()),
+    )
+    .await
+    .expect("cascade disable should succeed");
+
+    let slugs: Vec<_> = disabled
+        .iter()
+        .map(|module| module.module_slug.as_str())
+        .collect();
+    assert_eq!(slugs, vec!["express-checkout", "checkout", "pricing"]);
+    assert!(disabled.iter().all(|module| !module.enabled));
+
+    let unrelated = tenant_modules::Entity::find()
+        .filter(tenant_modules::Column::TenantId.eq(tenant_id))
+        .filter(tenant_modules::Column::ModuleSlug.eq("unrelated"))
+        .one(&db)
+        .await
+        .expect("query unrelated module")
+        .expect("unrelated module row");
+    assert!(unrelated.enabled);
+}
+
 #[tokio::test]
 async fn unknown_module_failure_does_not_create_journal_row() {
     let db = setup_db().await;
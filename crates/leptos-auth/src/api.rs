@@ -76,6 +76,22 @@ mutation ForgotPassword($input: ForgotPasswordInput!) {
 }
 "#;
 
+const VERIFY_EMAIL_MUTATION: &str = r#"
+mutation VerifyEmail($input: VerifyEmailInput!) {
+    verifyEmail(input: $input) {
+        success
+    }
+}
+"#;
+
+const REQUEST_EMAIL_VERIFICATION_MUTATION: &str = r#"
+mutation RequestEmailVerification {
+    requestEmailVerification {
+        success
+    }
+}
+"#;
+
 const CURRENT_USER_QUERY: &str = r#"
 query CurrentUser {
     me {
@@ -88,6 +104,9 @@ query CurrentUser {
 }
 "#;
 
+#[cfg(feature = "ssr")]
+const LOGIN_PATH: &str = "/api/auth/login";
+
 #[cfg(feature = "ssr")]
 const RESET_REQUEST_MESSAGE: &str = "If the email exists, a password reset link has been sent";
 
@@ -133,6 +152,20 @@ struct ForgotPasswordResponse {
     forgot_password: ForgotPasswordPayload,
 }
 
+#[derive(Debug, Deserialize)]
+struct VerifyEmailResponse {
+    #[serde(rename = "verifyEmail")]
+    #[allow(dead_code)]
+    verify_email: SuccessPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct RequestEmailVerificationResponse {
+    #[serde(rename = "requestEmailVerification")]
+    #[allow(dead_code)]
+    request_email_verification: SuccessPayload,
+}
+
 #[derive(Debug, Deserialize)]
 struct CurrentUserResponse {
     me: Option<AuthUserGraphql>,
@@ -155,7 +188,6 @@ struct AuthUserGraphql {
     email: String,
     name: Option<String>,
     role: String,
-    #[allow(dead_code)]
     status: String,
 }
 
@@ -165,6 +197,12 @@ struct SignOutPayload {
     success: bool,
 }
 
+#[derive(Debug, Deserialize)]
+struct SuccessPayload {
+    #[allow(dead_code)]
+    success: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct ForgotPasswordPayload {
     #[allow(dead_code)]
@@ -190,7 +228,6 @@ struct RestUserInfo {
     email: String,
     name: Option<String>,
     role: String,
-    #[allow(dead_code)]
     status: String,
 }
 
@@ -201,6 +238,8 @@ struct RestUserResponse {
     email: String,
     name: Option<String>,
     role: String,
+    #[serde(default)]
+    status: Option<String>,
 }
 
 #[cfg(feature = "ssr")]
@@ -214,7 +253,6 @@ struct RestStatusResponse {
 #[derive(Debug, Clone, Deserialize)]
 struct RestApiErrorPayload {
     error: Option<String>,
-    message: Option<String>,
 }
 
 fn get_api_url() -> String {
@@ -266,6 +304,7 @@ fn auth_payload_to_session(payload: AuthPayload, tenant: String) -> (AuthUser, A
         email: payload.user.email,
         name: payload.user.name,
         role: payload.user.role,
+        status: Some(payload.user.status),
     };
 
     let session = AuthSession {
@@ -286,6 +325,7 @@ fn rest_payload_to_native(payload: RestAuthResponse, tenant: String) -> NativeAu
             email: payload.user.email,
             name: payload.user.name,
             role: payload.user.role,
+            status: Some(payload.user.status),
         },
         session: AuthSession {
             token: payload.access_token,
@@ -315,11 +355,20 @@ fn map_graphql_auth_error(error: leptos_graphql::GraphqlHttpError, is_login: boo
                 AuthError::Http(400)
             }
         }
+        leptos_graphql::GraphqlHttpError::Http(status)
+            if status.ends_with(crate::ORIGIN_NOT_ALLOWED_CODE) =>
+        {
+            AuthError::OriginNotAllowed
+        }
         leptos_graphql::GraphqlHttpError::Http(status) => status
-            .parse::<u16>()
+            .split_whitespace()
+            .next()
+            .and_then(|code| code.parse::<u16>().ok())
             .map(AuthError::Http)
             .unwrap_or(AuthError::Http(500)),
-        leptos_graphql::GraphqlHttpError::Network => AuthError::Network,
+        leptos_graphql::GraphqlHttpError::Network | leptos_graphql::GraphqlHttpError::Aborted => {
+            AuthError::Network
+        }
     }
 }
 
@@ -433,6 +482,46 @@ async fn forgot_password_graphql(email: String, tenant: String) -> Result<String
     Ok(response.forgot_password.message)
 }
 
+async fn verify_email_graphql(token: String, tenant: String) -> Result<(), AuthError> {
+    let variables = json!({
+        "input": {
+            "token": token,
+        }
+    });
+
+    let _response: VerifyEmailResponse = execute(
+        &get_graphql_url(),
+        GraphqlRequest::new(VERIFY_EMAIL_MUTATION, Some(variables)),
+        None,
+        Some(tenant),
+        None,
+    )
+    .await
+    .map_err(|error| map_graphql_auth_error(error, false))?;
+
+    Ok(())
+}
+
+async fn request_email_verification_graphql(
+    token: String,
+    tenant: String,
+) -> Result<(), AuthError> {
+    let _response: RequestEmailVerificationResponse = execute(
+        &get_graphql_url(),
+        GraphqlRequest::new(
+            REQUEST_EMAIL_VERIFICATION_MUTATION,
+            None::<serde_json::Value>,
+        ),
+        Some(token),
+        Some(tenant),
+        None,
+    )
+    .await
+    .map_err(|error| map_graphql_auth_error(error, false))?;
+
+    Ok(())
+}
+
 async fn fetch_current_user_graphql(
     token: String,
     tenant: String,
@@ -452,6 +541,7 @@ async fn fetch_current_user_graphql(
         email: user.email,
         name: user.name,
         role: user.role,
+        status: Some(user.status),
     }))
 }
 
@@ -462,6 +552,13 @@ pub async fn sign_in(
 ) -> Result<(AuthUser, AuthSession), AuthError> {
     match sign_in_native(email.clone(), password.clone(), tenant.clone()).await {
         Ok(payload) => Ok((payload.user, payload.session)),
+        // Wrong credentials are an answer, not a transport failure: retrying
+        // over GraphQL would only repeat the rejected login.
+        Err(ServerFnError::ServerError(message))
+            if message == AuthError::InvalidCredentials.to_string() =>
+        {
+            Err(AuthError::InvalidCredentials)
+        }
         Err(_) => sign_in_graphql(email, password, tenant).await,
     }
 }
@@ -513,6 +610,22 @@ pub async fn forgot_password(email: String, tenant: String) -> Result<String, Au
     }
 }
 
+/// Confirm an email address with the token from the verification email.
+pub async fn verify_email(token: String, tenant: String) -> Result<(), AuthError> {
+    match verify_email_native(token.clone(), tenant.clone()).await {
+        Ok(()) => Ok(()),
+        Err(_) => verify_email_graphql(token, tenant).await,
+    }
+}
+
+/// Ask the server to (re)send the verification email for the signed-in user.
+pub async fn request_email_verification(token: String, tenant: String) -> Result<(), AuthError> {
+    match request_email_verification_native(token.clone(), tenant.clone()).await {
+        Ok(()) => Ok(()),
+        Err(_) => request_email_verification_graphql(token, tenant).await,
+    }
+}
+
 pub async fn fetch_current_user(
     token: String,
     tenant: String,
@@ -523,6 +636,51 @@ pub async fn fetch_current_user(
     }
 }
 
+/// A token expiring within this many seconds is refreshed before use.
+pub const TOKEN_REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Run `request` with an access token that is not about to expire.
+///
+/// When `session` expires within [`TOKEN_REFRESH_MARGIN_SECS`], it is
+/// refreshed first and replaced in place, so callers can persist it. A
+/// rejected refresh is [`AuthError::Unauthorized`] and `request` is not run;
+/// a network failure is returned as is.
+pub async fn with_fresh_token<F, Fut, T>(
+    session: &mut AuthSession,
+    request: F,
+) -> Result<T, AuthError>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: std::future::Future<Output = Result<T, AuthError>>,
+{
+    with_fresh_token_using(session, now_unix_secs(), refresh_token, request).await
+}
+
+async fn with_fresh_token_using<R, RFut, F, Fut, T>(
+    session: &mut AuthSession,
+    now: i64,
+    refresh: R,
+    request: F,
+) -> Result<T, AuthError>
+where
+    R: FnOnce(String, String) -> RFut,
+    RFut: std::future::Future<Output = Result<(AuthSession, AuthUser), AuthError>>,
+    F: FnOnce(String) -> Fut,
+    Fut: std::future::Future<Output = Result<T, AuthError>>,
+{
+    if now >= session.expires_at - TOKEN_REFRESH_MARGIN_SECS {
+        let (refreshed, _user) = refresh(session.refresh_token.clone(), session.tenant.clone())
+            .await
+            .map_err(|error| match error {
+                AuthError::Network => AuthError::Network,
+                _ => AuthError::Unauthorized,
+            })?;
+        *session = refreshed;
+    }
+
+    request(session.token.clone()).await
+}
+
 #[server(prefix = "/api/fn", endpoint = "auth/sign-in")]
 async fn sign_in_native(
     email: String,
@@ -532,7 +690,7 @@ async fn sign_in_native(
     #[cfg(feature = "ssr")]
     {
         let response: RestAuthResponse = auth_rest_post(
-            "/api/auth/login",
+            LOGIN_PATH,
             &json!({
                 "email": email,
                 "password": password,
@@ -663,6 +821,57 @@ async fn forgot_password_native(email: String, tenant: String) -> Result<String,
     }
 }
 
+#[server(prefix = "/api/fn", endpoint = "auth/verify-email")]
+async fn verify_email_native(token: String, tenant: String) -> Result<(), ServerFnError> {
+    #[cfg(feature = "ssr")]
+    {
+        let _response: RestStatusResponse = auth_rest_post(
+            "/api/auth/verify/confirm",
+            &json!({
+                "token": token,
+            }),
+            None,
+            Some(tenant),
+        )
+        .await?;
+
+        Ok(())
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = (token, tenant);
+        Err(ServerFnError::new(
+            "auth/verify-email requires the `ssr` feature",
+        ))
+    }
+}
+
+#[server(prefix = "/api/fn", endpoint = "auth/request-email-verification")]
+async fn request_email_verification_native(
+    token: String,
+    tenant: String,
+) -> Result<(), ServerFnError> {
+    #[cfg(feature = "ssr")]
+    {
+        let _response: RestStatusResponse = auth_rest_post(
+            "/api/auth/verify/resend",
+            &json!({}),
+            Some(token),
+            Some(tenant),
+        )
+        .await?;
+
+        Ok(())
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = (token, tenant);
+        Err(ServerFnError::new(
+            "auth/request-email-verification requires the `ssr` feature",
+        ))
+    }
+}
+
 #[server(prefix = "/api/fn", endpoint = "auth/current-user")]
 async fn current_user_native(
     token: String,
@@ -679,6 +888,7 @@ async fn current_user_native(
                 email: response.email,
                 name: response.name,
                 role: response.role,
+                status: response.status,
             }),
         })
     }
@@ -701,27 +911,15 @@ async fn auth_rest_post<T>(
 where
     T: for<'de> Deserialize<'de>,
 {
-    use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
-
-    let client = reqwest::Client::new();
-    let mut request = client
-        .post(format!("{}{}", get_api_url(), path))
-        .header(CONTENT_TYPE, "application/json")
-        .json(body);
-
-    if let Some(token) = token {
-        request = request.header(AUTHORIZATION, format!("Bearer {token}"));
-    }
-    if let Some(tenant) = tenant {
-        request = request.header("X-Tenant-ID", tenant);
-    }
-
-    let response = request.send().await.map_err(ServerFnError::new)?;
-    if !response.status().is_success() {
-        return Err(ServerFnError::new(rest_error_message(response).await));
-    }
-
-    response.json::<T>().await.map_err(ServerFnError::new)
+    fetch_json(
+        reqwest::Method::POST,
+        &format!("{}{}", get_api_url(), path),
+        Some(body),
+        token,
+        tenant,
+    )
+    .await
+    .map_err(ServerFnError::new)
 }
 
 #[cfg(feature = "ssr")]
@@ -733,49 +931,70 @@ async fn auth_rest_get<T>(
 where
     T: for<'de> Deserialize<'de>,
 {
-    use reqwest::header::AUTHORIZATION;
+    fetch_json(
+        reqwest::Method::GET,
+        &format!("{}{}", get_api_url(), path),
+        None,
+        token,
+        tenant,
+    )
+    .await
+    .map_err(ServerFnError::new)
+}
 
-    let client = reqwest::Client::new();
-    let mut request = client.get(format!("{}{}", get_api_url(), path));
+/// Send a REST auth request with the bearer token and tenant slug headers.
+///
+/// 200/201 decode the body; other statuses are mapped by [`rest_error`].
+#[cfg(feature = "ssr")]
+async fn fetch_json<T>(
+    method: reqwest::Method,
+    url: &str,
+    body: Option<&serde_json::Value>,
+    token: Option<String>,
+    tenant: Option<String>,
+) -> Result<T, AuthError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    use reqwest::header::AUTHORIZATION;
 
+    let mut request = reqwest::Client::new().request(method, url);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
     if let Some(token) = token {
         request = request.header(AUTHORIZATION, format!("Bearer {token}"));
     }
     if let Some(tenant) = tenant {
-        request = request.header("X-Tenant-ID", tenant);
+        request = request.header(leptos_graphql::TENANT_HEADER, tenant);
     }
 
-    let response = request.send().await.map_err(ServerFnError::new)?;
-    if !response.status().is_success() {
-        return Err(ServerFnError::new(rest_error_message(response).await));
+    let is_login = url.ends_with(LOGIN_PATH);
+    let response = request.send().await.map_err(|_| AuthError::Network)?;
+    match response.status().as_u16() {
+        200 | 201 => response.json::<T>().await.map_err(|_| AuthError::Network),
+        status => {
+            let body = response.text().await.unwrap_or_default();
+            Err(rest_error(status, &body, is_login))
+        }
     }
-
-    response.json::<T>().await.map_err(ServerFnError::new)
 }
 
+/// Map a non-success REST auth response to an [`AuthError`].
+///
+/// A structured `{"error": "<code>"}` body wins over the status; otherwise a
+/// 401 from the login endpoint means wrong credentials and from any other
+/// endpoint an invalid or expired session.
 #[cfg(feature = "ssr")]
-async fn rest_error_message(response: reqwest::Response) -> String {
-    let status = response.status();
-    let text = response.text().await.unwrap_or_default();
-    let trimmed = text.trim();
-
-    if trimmed.is_empty() {
-        return format!("request failed with status {status}");
-    }
-
-    if let Ok(payload) = serde_json::from_str::<RestApiErrorPayload>(trimmed) {
-        if let Some(message) = payload
-            .message
-            .as_deref()
-            .or(payload.error.as_deref())
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-        {
-            return message.to_string();
-        }
+fn rest_error(status: u16, body: &str, is_login: bool) -> AuthError {
+    let code = serde_json::from_str::<RestApiErrorPayload>(body)
+        .ok()
+        .and_then(|payload| payload.error);
+    match code.as_deref() {
+        Some(rustok_api::AUTH_INVALID_CREDENTIALS_CODE) => AuthError::InvalidCredentials,
+        Some(crate::ORIGIN_NOT_ALLOWED_CODE) => AuthError::OriginNotAllowed,
+        _ => AuthError::from_status(status, is_login),
     }
-
-    trimmed.to_string()
 }
 
 #[cfg(test)]
@@ -796,9 +1015,252 @@ mod tests {
         assert!(SIGN_UP_MUTATION.contains("user"));
     }
 
+    #[test]
+    fn test_verify_email_mutations() {
+        assert!(VERIFY_EMAIL_MUTATION.contains("mutation VerifyEmail"));
+        assert!(VERIFY_EMAIL_MUTATION.contains("verifyEmail(input: $input)"));
+        assert!(REQUEST_EMAIL_VERIFICATION_MUTATION.contains("requestEmailVerification"));
+    }
+
+    #[test]
+    fn maps_origin_rejection_to_distinct_error() {
+        let error =
+            leptos_graphql::GraphqlHttpError::Http("403 Forbidden: origin_not_allowed".to_string());
+        assert_eq!(
+            map_graphql_auth_error(error, true),
+            AuthError::OriginNotAllowed
+        );
+
+        let error = leptos_graphql::GraphqlHttpError::Http("403 Forbidden".to_string());
+        assert_eq!(map_graphql_auth_error(error, true), AuthError::Http(403));
+    }
+
+    fn session_expiring_at(expires_at: i64) -> AuthSession {
+        AuthSession {
+            token: "old-access".to_string(),
+            refresh_token: "old-refresh".to_string(),
+            expires_at,
+            tenant: "acme".to_string(),
+        }
+    }
+
+    fn refreshed_session() -> (AuthSession, AuthUser) {
+        let session = AuthSession {
+            token: "new-access".to_string(),
+            refresh_token: "new-refresh".to_string(),
+            expires_at: 10_000,
+            tenant: "acme".to_string(),
+        };
+        let user = AuthUser {
+            id: "user-1".to_string(),
+            email: "a@example.com".to_string(),
+            name: None,
+            role: "admin".to_string(),
+            status: Some("active".to_string()),
+        };
+        (session, user)
+    }
+
+    #[tokio::test]
+    async fn valid_token_is_used_without_refresh() {
+        let mut session = session_expiring_at(1_000);
+
+        let token = with_fresh_token_using(
+            &mut session,
+            500,
+            // Any refresh would surface as an error here.
+            |_, _| async { Err(AuthError::Network) },
+            |token| async move { Ok(token) },
+        )
+        .await;
+
+        assert_eq!(token.unwrap(), "old-access");
+        assert_eq!(session, session_expiring_at(1_000));
+    }
+
+    #[tokio::test]
+    async fn expired_token_is_refreshed_before_the_request() {
+        let mut session = session_expiring_at(1_000);
+
+        let token = with_fresh_token_using(
+            &mut session,
+            1_000 - TOKEN_REFRESH_MARGIN_SECS,
+            |refresh_token, tenant| async move {
+                assert_eq!(refresh_token, "old-refresh");
+                assert_eq!(tenant, "acme");
+                Ok(refreshed_session())
+            },
+            |token| async move { Ok(token) },
+        )
+        .await;
+
+        assert_eq!(token.unwrap(), "new-access");
+        assert_eq!(session, refreshed_session().0);
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_is_unauthorized_and_skips_the_request() {
+        let mut session = session_expiring_at(1_000);
+        let mut request_ran = false;
+
+        let result = with_fresh_token_using(
+            &mut session,
+            2_000,
+            |_, _| async { Err(AuthError::Http(401)) },
+            |_| {
+                request_ran = true;
+                async { Ok(()) }
+            },
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), AuthError::Unauthorized);
+        assert!(!request_ran);
+        assert_eq!(session, session_expiring_at(1_000));
+    }
+
     #[test]
     fn test_graphql_url_shape() {
         let url = get_graphql_url();
         assert!(url.contains("/api/graphql"));
     }
+
+    /// Local REST stand-in: `/api/auth/login` answers 201 only with the
+    /// expected headers, `/api/auth/me` is 401 and `/api/auth/refresh` is 500.
+    #[cfg(feature = "ssr")]
+    async fn mock_auth_server() -> String {
+        use axum::http::{HeaderMap, StatusCode};
+        use axum::routing::{get, post};
+        use axum::Json;
+
+        async fn login(headers: HeaderMap) -> (StatusCode, Json<serde_json::Value>) {
+            let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+            if header("authorization") != Some("Bearer token-1")
+                || header("x-tenant-slug") != Some("acme")
+            {
+                return (StatusCode::BAD_REQUEST, Json(json!({})));
+            }
+            (StatusCode::CREATED, Json(json!({ "status": "ok" })))
+        }
+
+        let app = axum::Router::new()
+            .route("/api/auth/login", post(login))
+            .route("/api/auth/me", get(|| async { StatusCode::UNAUTHORIZED }))
+            .route(
+                "/api/auth/refresh",
+                post(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+            )
+            .route(
+                "/api/auth/change-password",
+                post(|| async {
+                    (
+                        StatusCode::UNAUTHORIZED,
+                        error_body(rustok_api::AUTH_INVALID_CREDENTIALS_CODE),
+                    )
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{address}")
+    }
+
+    /// Error body in the shape the server sends through `ApiError::into_loco_error`.
+    #[cfg(feature = "ssr")]
+    fn error_body(code: &str) -> String {
+        serde_json::to_string(&loco_rs::controller::ErrorDetail::new(code, "description")).unwrap()
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn rest_401_on_login_is_invalid_credentials() {
+        assert_eq!(rest_error(401, "", true), AuthError::InvalidCredentials);
+        assert_eq!(
+            rest_error(401, &error_body("auth.unauthorized"), true),
+            AuthError::InvalidCredentials
+        );
+        assert_eq!(rest_error(500, "", true), AuthError::Http(500));
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn rest_401_on_refresh_stays_unauthorized() {
+        assert_eq!(rest_error(401, "", false), AuthError::Unauthorized);
+        assert_eq!(
+            rest_error(401, "Invalid refresh token", false),
+            AuthError::Unauthorized
+        );
+        assert_eq!(
+            rest_error(
+                401,
+                &error_body(rustok_api::AUTH_INVALID_CREDENTIALS_CODE),
+                false
+            ),
+            AuthError::InvalidCredentials
+        );
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn rest_origin_rejection_is_distinct() {
+        assert_eq!(
+            rest_error(403, r#"{"error":"origin_not_allowed"}"#, false),
+            AuthError::OriginNotAllowed
+        );
+        assert_eq!(rest_error(403, "", false), AuthError::Http(403));
+    }
+
+    #[cfg(feature = "ssr")]
+    #[tokio::test]
+    async fn fetch_json_decodes_success_and_sends_auth_headers() {
+        let base = mock_auth_server().await;
+
+        let response: RestStatusResponse = fetch_json(
+            reqwest::Method::POST,
+            &format!("{base}/api/auth/login"),
+            Some(&json!({ "email": "a@example.com" })),
+            Some("token-1".to_string()),
+            Some("acme".to_string()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status, "ok");
+    }
+
+    #[cfg(feature = "ssr")]
+    #[tokio::test]
+    async fn fetch_json_maps_error_statuses() {
+        let base = mock_auth_server().await;
+
+        let unauthorized = fetch_json::<RestStatusResponse>(
+            reqwest::Method::GET,
+            &format!("{base}/api/auth/me"),
+            None,
+            Some("token-1".to_string()),
+            Some("acme".to_string()),
+        )
+        .await;
+        assert_eq!(unauthorized.unwrap_err(), AuthError::Unauthorized);
+
+        let server_error = fetch_json::<RestStatusResponse>(
+            reqwest::Method::POST,
+            &format!("{base}/api/auth/refresh"),
+            Some(&json!({})),
+            None,
+            Some("acme".to_string()),
+        )
+        .await;
+        assert_eq!(server_error.unwrap_err(), AuthError::Http(500));
+
+        let wrong_password = fetch_json::<RestStatusResponse>(
+            reqwest::Method::POST,
+            &format!("{base}/api/auth/change-password"),
+            Some(&json!({})),
+            Some("token-1".to_string()),
+            Some("acme".to_string()),
+        )
+        .await;
+        assert_eq!(wrong_password.unwrap_err(), AuthError::InvalidCredentials);
+    }
 }
@@ -2,7 +2,8 @@ use leptos::prelude::*;
 use leptos_router::components::Outlet;
 use leptos_router::hooks::use_navigate;
 
-use crate::hooks::{use_is_authenticated, use_is_loading};
+use crate::hooks::{use_current_user, use_is_authenticated, use_is_loading};
+use crate::AuthRole;
 
 #[component]
 pub fn ProtectedRoute() -> impl IntoView {
@@ -31,6 +32,49 @@ pub fn ProtectedRoute() -> impl IntoView {
     }
 }
 
+/// Renders nested routes only for users whose role is at least
+/// `required_role`. Waits for the current user to load before deciding,
+/// sends visitors without a user to `/login` and redirects everyone else to
+/// `/forbidden`. Nest it inside `ProtectedRoute`.
+#[component]
+pub fn RoleGuardedRoute(required_role: AuthRole) -> impl IntoView {
+    let current_user = use_current_user();
+    let is_loading = use_is_loading();
+    let login = "/login".to_string();
+    let redirect_to = "/forbidden".to_string();
+    let navigate = use_navigate();
+
+    let is_allowed = Signal::derive(move || {
+        current_user
+            .get()
+            .is_some_and(|user| user.has_role(required_role))
+    });
+
+    Effect::new(move |_| {
+        if is_loading.get() {
+            return;
+        }
+        if current_user.get().is_none() {
+            navigate(&login, Default::default());
+        } else if !is_allowed.get() {
+            navigate(&redirect_to, Default::default());
+        }
+    });
+
+    view! {
+        <Show
+            when=move || !is_loading.get() && is_allowed.get()
+            fallback=|| view! {
+                <div class="flex items-center justify-center min-h-screen">
+                    <div class="animate-spin rounded-full h-12 w-12 border-b-2 border-gray-900"></div>
+                </div>
+            }
+        >
+            <Outlet />
+        </Show>
+    }
+}
+
 #[component]
 pub fn GuestRoute() -> impl IntoView {
     let is_authenticated = use_is_authenticated();
@@ -123,7 +123,7 @@ impl AuthContext {
     pub fn is_token_expired(&self) -> bool {
         self.session
             .get()
-            .map(|s| now_unix_secs() >= s.expires_at - 60)
+            .map(|s| now_unix_secs() >= s.expires_at - api::TOKEN_REFRESH_MARGIN_SECS)
             .unwrap_or(true)
     }
 
@@ -155,6 +155,26 @@ impl AuthContext {
         }
     }
 
+    /// Run `request` with a fresh access token via [`api::with_fresh_token`],
+    /// persisting the session when it had to be refreshed.
+    pub async fn with_fresh_token<F, Fut, T>(&self, request: F) -> Result<T, AuthError>
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: std::future::Future<Output = Result<T, AuthError>>,
+    {
+        let mut session = self
+            .session
+            .get_untracked()
+            .ok_or(AuthError::Unauthorized)?;
+        let previous_token = session.token.clone();
+        let result = api::with_fresh_token(&mut session, request).await;
+        if session.token != previous_token {
+            let _ = storage::save_session(&session);
+            self.session.set(Some(session));
+        }
+        result
+    }
+
     pub async fn fetch_current_user(&self) -> Result<(), AuthError> {
         if let Some(session) = self.session.get_untracked() {
             let user =
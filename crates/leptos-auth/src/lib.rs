@@ -17,6 +17,51 @@ pub struct AuthUser {
     pub email: String,
     pub name: Option<String>,
     pub role: String,
+    /// Account status (`active`, `inactive`, ...); users stored before the
+    /// field existed load as `None`.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+impl AuthUser {
+    /// Parsed platform role, or `None` when the server sent an unknown value.
+    pub fn auth_role(&self) -> Option<AuthRole> {
+        AuthRole::parse(&self.role)
+    }
+
+    /// Returns `true` when the user's role is at least `required`.
+    pub fn has_role(&self, required: AuthRole) -> bool {
+        self.auth_role().is_some_and(|role| role >= required)
+    }
+}
+
+/// Platform roles ordered from least to most privileged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuthRole {
+    Customer,
+    Manager,
+    Admin,
+    SuperAdmin,
+}
+
+impl AuthRole {
+    /// Accepts `super_admin`, `SUPER_ADMIN` and `SuperAdmin` spellings alike,
+    /// since REST and GraphQL serialize roles differently.
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized: String = value
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        match normalized.as_str() {
+            "customer" => Some(Self::Customer),
+            "manager" => Some(Self::Manager),
+            "admin" => Some(Self::Admin),
+            "superadmin" => Some(Self::SuperAdmin),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -37,8 +82,14 @@ pub enum AuthError {
     Network,
     #[error("HTTP error: {0}")]
     Http(u16),
+    #[error("Origin not allowed for this tenant")]
+    OriginNotAllowed,
 }
 
+/// Error code the server returns when the request origin is not allowed for
+/// the tenant.
+pub const ORIGIN_NOT_ALLOWED_CODE: &str = "origin_not_allowed";
+
 impl AuthError {
     pub fn from_status(status: u16, is_login: bool) -> Self {
         match status {
@@ -49,9 +100,68 @@ impl AuthError {
     }
 }
 
-pub use components::{GuestRoute, ProtectedRoute, RequireAuth};
+pub use components::{GuestRoute, ProtectedRoute, RequireAuth, RoleGuardedRoute};
 pub use context::{AuthContext, AuthProvider};
 pub use hooks::{
     use_auth, use_auth_error, use_current_user, use_is_authenticated, use_is_loading,
     use_is_token_valid, use_session, use_tenant, use_token,
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_with_role(role: &str) -> AuthUser {
+        AuthUser {
+            id: "user-1".to_string(),
+            email: "user@example.com".to_string(),
+            name: None,
+            role: role.to_string(),
+            status: Some("active".to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_role_spellings() {
+        assert_eq!(AuthRole::parse("super_admin"), Some(AuthRole::SuperAdmin));
+        assert_eq!(AuthRole::parse("SUPER_ADMIN"), Some(AuthRole::SuperAdmin));
+        assert_eq!(AuthRole::parse("SuperAdmin"), Some(AuthRole::SuperAdmin));
+        assert_eq!(AuthRole::parse("Customer"), Some(AuthRole::Customer));
+        assert_eq!(AuthRole::parse("guest"), None);
+    }
+
+    #[test]
+    fn has_role_respects_hierarchy() {
+        assert!(user_with_role("super_admin").has_role(AuthRole::Admin));
+        assert!(user_with_role("admin").has_role(AuthRole::Admin));
+        assert!(!user_with_role("manager").has_role(AuthRole::Admin));
+        assert!(!user_with_role("customer").has_role(AuthRole::Manager));
+        assert!(!user_with_role("unknown").has_role(AuthRole::Customer));
+    }
+
+    #[test]
+    fn auth_user_round_trips_role_and_status() {
+        let user: AuthUser = serde_json::from_str(
+            r#"{"id":"user-1","email":"a@example.com","name":"Ann","role":"admin","status":"active"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(user.auth_role(), Some(AuthRole::Admin));
+        assert_eq!(user.status.as_deref(), Some("active"));
+        assert_eq!(
+            serde_json::from_str::<AuthUser>(&serde_json::to_string(&user).unwrap()).unwrap(),
+            user
+        );
+    }
+
+    #[test]
+    fn stored_user_without_status_still_loads() {
+        let user: AuthUser = serde_json::from_str(
+            r#"{"id":"user-1","email":"a@example.com","name":null,"role":"manager"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(user.status, None);
+        assert!(user.has_role(AuthRole::Manager));
+    }
+}
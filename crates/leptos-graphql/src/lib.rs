@@ -1,10 +1,12 @@
 pub mod hooks;
+pub mod retry;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use std::str::FromStr;
 
 pub use hooks::{use_lazy_query, use_mutation, use_query, MutationResult, QueryResult};
+pub use retry::{ExecuteOptions, RetryPolicy};
 
 pub const GRAPHQL_ENDPOINT: &str = "/api/graphql";
 pub const TENANT_HEADER: &str = "X-Tenant-Slug";
@@ -46,6 +48,12 @@ pub struct GraphqlError {
     pub message: String,
 }
 
+/// JSON body of non-GraphQL rejections, e.g. from server middleware.
+#[derive(Debug, Deserialize)]
+struct HttpErrorBody {
+    error: Option<String>,
+}
+
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GraphqlHttpError {
     #[error("Network error")]
@@ -56,6 +64,8 @@ pub enum GraphqlHttpError {
     Http(String),
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("Request aborted")]
+    Aborted,
 }
 
 impl FromStr for GraphqlHttpError {
@@ -70,6 +80,10 @@ impl FromStr for GraphqlHttpError {
             return Ok(Self::Unauthorized);
         }
 
+        if value == "Request aborted" {
+            return Ok(Self::Aborted);
+        }
+
         if let Some(message) = value.strip_prefix("GraphQL error: ") {
             return Ok(Self::Graphql(message.to_string()));
         }
@@ -91,6 +105,7 @@ pub fn persisted_query_extension(sha256_hash: &str) -> Value {
     })
 }
 
+/// Execute a GraphQL request with the default [`RetryPolicy`].
 pub async fn execute<V, T>(
     endpoint: &str,
     request: GraphqlRequest<V>,
@@ -98,33 +113,89 @@ pub async fn execute<V, T>(
     tenant_slug: Option<String>,
     locale: Option<String>,
 ) -> Result<T, GraphqlHttpError>
+where
+    V: Serialize,
+    T: DeserializeOwned,
+{
+    execute_with_options(
+        endpoint,
+        request,
+        token,
+        tenant_slug,
+        locale,
+        &ExecuteOptions::default(),
+    )
+    .await
+}
+
+/// Execute a GraphQL request, retrying network failures per `options.retry`
+/// (queries only unless the policy opts mutations in).
+pub async fn execute_with_options<V, T>(
+    endpoint: &str,
+    request: GraphqlRequest<V>,
+    token: Option<String>,
+    tenant_slug: Option<String>,
+    locale: Option<String>,
+    options: &ExecuteOptions,
+) -> Result<T, GraphqlHttpError>
 where
     V: Serialize,
     T: DeserializeOwned,
 {
     let client = reqwest::Client::new();
-    let mut req = client.post(endpoint).json(&request);
+    let max_attempts = options.retry.max_attempts_for(&request.query);
+    let mut attempt = 1;
 
-    if let Some(t) = token {
-        req = req.header(AUTH_HEADER, format!("Bearer {}", t));
-    }
+    let res = loop {
+        if options.is_aborted() {
+            return Err(GraphqlHttpError::Aborted);
+        }
 
-    if let Some(slug) = tenant_slug {
-        req = req.header(TENANT_HEADER, slug);
-    }
+        let mut req = client.post(endpoint).json(&request);
 
-    if let Some(locale) = locale {
-        req = req.header(ACCEPT_LANGUAGE_HEADER, locale);
-    }
+        if let Some(t) = token.as_deref() {
+            req = req.header(AUTH_HEADER, format!("Bearer {}", t));
+        }
+
+        if let Some(slug) = tenant_slug.as_deref() {
+            req = req.header(TENANT_HEADER, slug);
+        }
 
-    let res = req.send().await.map_err(|_| GraphqlHttpError::Network)?;
+        if let Some(locale) = locale.as_deref() {
+            req = req.header(ACCEPT_LANGUAGE_HEADER, locale);
+        }
+
+        match retry::until_aborted(options, req.send()).await {
+            None => return Err(GraphqlHttpError::Aborted),
+            Some(Ok(res)) => break res,
+            Some(Err(_)) if attempt < max_attempts => {
+                let backoff = retry::sleep(options.retry.backoff(attempt));
+                if retry::until_aborted(options, backoff).await.is_none() {
+                    return Err(GraphqlHttpError::Aborted);
+                }
+                attempt += 1;
+            }
+            Some(Err(_)) => return Err(GraphqlHttpError::Network),
+        }
+    };
 
     if res.status() == 401 {
         return Err(GraphqlHttpError::Unauthorized);
     }
 
     if !res.status().is_success() {
-        return Err(GraphqlHttpError::Http(res.status().to_string()));
+        // Keep the server's error code, if any, so callers can tell apart
+        // rejections that share a status ("403 Forbidden: origin_not_allowed").
+        let status = res.status().to_string();
+        let code = res
+            .json::<HttpErrorBody>()
+            .await
+            .ok()
+            .and_then(|body| body.error);
+        return Err(GraphqlHttpError::Http(match code {
+            Some(code) => format!("{status}: {code}"),
+            None => status,
+        }));
     }
 
     let body: GraphqlResponse<T> = res.json().await.map_err(|_| GraphqlHttpError::Network)?;
@@ -0,0 +1,271 @@
+use std::future::Future;
+use std::time::Duration;
+
+/// Retry policy for network-level failures in [`crate::execute_with_options`].
+///
+/// Only failures to reach the server are retried. HTTP statuses and GraphQL
+/// `errors[]` are deterministic and returned on the first attempt.
+///
+/// Only query documents are retried by default: a mutation whose response was
+/// lost may already have been applied, so retrying it can duplicate a write.
+/// Opt in with [`RetryPolicy::with_mutation_retries`] for idempotent mutations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one; `1` disables retries.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: u32,
+    /// Also retry mutations, subscriptions and documentless (persisted)
+    /// requests.
+    pub retry_mutations: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(2),
+            multiplier: 2,
+            retry_mutations: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_mutation_retries(mut self) -> Self {
+        self.retry_mutations = true;
+        self
+    }
+
+    /// Attempts allowed for `document` under this policy.
+    pub(crate) fn max_attempts_for(&self, document: &str) -> u32 {
+        if self.retry_mutations || is_query_document(document) {
+            self.max_attempts.max(1)
+        } else {
+            1
+        }
+    }
+
+    /// Delay before the attempt following `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1)
+            .saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Options for [`crate::execute_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct ExecuteOptions {
+    pub retry: RetryPolicy,
+    /// Aborting the signal cancels the in-flight request, the pending backoff
+    /// and any further attempts.
+    #[cfg(target_arch = "wasm32")]
+    pub abort_signal: Option<web_sys::AbortSignal>,
+}
+
+impl ExecuteOptions {
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_abort_signal(mut self, signal: web_sys::AbortSignal) -> Self {
+        self.abort_signal = Some(signal);
+        self
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn is_aborted(&self) -> bool {
+        self.abort_signal
+            .as_ref()
+            .is_some_and(|signal| signal.aborted())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn is_aborted(&self) -> bool {
+        false
+    }
+}
+
+/// Whether `document` is a read-only operation: its first operation is a
+/// `query` or the `{ ... }` shorthand. Fragment definitions before it are
+/// skipped; an empty document (persisted query by hash) is not a query.
+pub(crate) fn is_query_document(document: &str) -> bool {
+    let mut chars = document.chars().peekable();
+    let mut depth = 0usize;
+    let mut in_fragment = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let mut escaped = false;
+                for c in chars.by_ref() {
+                    match c {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => break,
+                        _ => escaped = false,
+                    }
+                }
+            }
+            '{' => {
+                if depth == 0 && !in_fragment {
+                    return true;
+                }
+                depth += 1;
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    in_fragment = false;
+                }
+            }
+            c if depth == 0 && (c.is_ascii_alphabetic() || c == '_') => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_alphanumeric() || next == '_' {
+                        word.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                match word.as_str() {
+                    "query" if !in_fragment => return true,
+                    "mutation" | "subscription" if !in_fragment => return false,
+                    "fragment" => in_fragment = true,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Run `future` unless the abort signal fires first. Dropping the request
+/// future aborts its in-flight `fetch`, so `None` means nothing is left
+/// running.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn until_aborted<F: Future>(
+    options: &ExecuteOptions,
+    future: F,
+) -> Option<F::Output> {
+    use futures_util::future::{select, Either};
+
+    let Some(signal) = options.abort_signal.as_ref() else {
+        return Some(future.await);
+    };
+    match select(Box::pin(future), Box::pin(aborted(signal))).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn until_aborted<F: Future>(
+    _options: &ExecuteOptions,
+    future: F,
+) -> Option<F::Output> {
+    Some(future.await)
+}
+
+/// Resolves once `signal` is aborted.
+#[cfg(target_arch = "wasm32")]
+async fn aborted(signal: &web_sys::AbortSignal) {
+    if signal.aborted() {
+        return;
+    }
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        let listener_options = web_sys::AddEventListenerOptions::new();
+        listener_options.set_once(true);
+        let _ = signal.add_event_listener_with_callback_and_add_event_listener_options(
+            "abort",
+            &resolve,
+            &listener_options,
+        );
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(10), Duration::from_secs(2));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn none_disables_retries() {
+        assert_eq!(RetryPolicy::none().max_attempts, 1);
+        assert!(!ExecuteOptions::default().is_aborted());
+    }
+
+    #[test]
+    fn only_queries_are_retried_by_default() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_attempts_for("query Me { me { id } }"), 3);
+        assert_eq!(policy.max_attempts_for("  { me { id } }"), 3);
+        assert_eq!(policy.max_attempts_for("mutation Logout { logout }"), 1);
+        assert_eq!(policy.max_attempts_for(""), 1);
+
+        let opted_in = policy.with_mutation_retries();
+        assert_eq!(opted_in.max_attempts_for("mutation Logout { logout }"), 3);
+    }
+
+    #[test]
+    fn detects_operation_after_comments_and_fragments() {
+        assert!(is_query_document("# mutation in a comment\nquery Q { a }"));
+        assert!(!is_query_document(
+            "fragment F on User { query: id }\nmutation M { m { ...F } }"
+        ));
+        assert!(is_query_document(
+            "fragment F on User { id name(arg: \"}\") }\nquery Q { me { ...F } }"
+        ));
+        assert!(!is_query_document("subscription S { events { id } }"));
+    }
+}
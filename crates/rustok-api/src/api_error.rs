@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Code sent by the auth endpoints for a wrong email/password pair.
+pub const AUTH_INVALID_CREDENTIALS_CODE: &str = "auth.invalid_credentials";
+
+/// Transport-neutral error payload shared by HTTP and GraphQL adapters.
+///
+/// `code` is the stable machine-readable code from
+/// `rustok_core::CodedError::error_code` (e.g. `page.not_found`); clients
+/// branch on it instead of parsing `message`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
+}
+
+impl ApiError {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+#[cfg(feature = "server")]
+impl ApiError {
+    /// Build from a module error, keeping its `Display` text as the message.
+    pub fn from_error<E: rustok_core::CodedError + ?Sized>(error: &E) -> Self {
+        Self::new(error.error_code(), error.to_string())
+    }
+
+    /// Build from a module error with the message translated for `locale`.
+    ///
+    /// Codes without a translation keep the error's `Display` text.
+    pub fn localized<E: rustok_core::CodedError + ?Sized>(
+        error: &E,
+        locale: rustok_core::i18n::Locale,
+    ) -> Self {
+        let code = error.error_code();
+        let message = rustok_core::i18n::translate(locale, code);
+        if message == code {
+            Self::from_error(error)
+        } else {
+            Self::new(code, message)
+        }
+    }
+
+    /// Convert into a GraphQL error with `code` (and `details`) in extensions.
+    pub fn into_graphql_error(self) -> async_graphql::Error {
+        use async_graphql::ErrorExtensions;
+
+        let Self {
+            code,
+            message,
+            details,
+        } = self;
+        async_graphql::Error::new(message).extend_with(|_, extensions| {
+            extensions.set("code", code.as_str());
+            if let Some(details) = details {
+                if let Ok(value) = async_graphql::Value::from_json(details) {
+                    extensions.set("details", value);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(feature = "loco-adapter")]
+impl ApiError {
+    /// Convert into a Loco HTTP error; the JSON body carries `code` as `error`.
+    pub fn into_loco_error(self, status: axum::http::StatusCode) -> loco_rs::Error {
+        loco_rs::Error::CustomError(
+            status,
+            loco_rs::controller::ErrorDetail::new(self.code.as_str(), self.message.as_str()),
+        )
+    }
+}
+
+#[cfg(feature = "server")]
+impl From<ApiError> for async_graphql::Error {
+    fn from(error: ApiError) -> Self {
+        error.into_graphql_error()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ApiError;
+
+    #[test]
+    fn serializes_code_message_and_details() {
+        let error = ApiError::new("page.not_found", "Page not found")
+            .with_details(json!({ "page_id": "42" }));
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "page.not_found",
+                "message": "Page not found",
+                "details": { "page_id": "42" }
+            })
+        );
+    }
+
+    #[test]
+    fn omits_missing_details() {
+        let error = ApiError::new(super::AUTH_INVALID_CREDENTIALS_CODE, "Invalid credentials");
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({ "code": "auth.invalid_credentials", "message": "Invalid credentials" })
+        );
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn graphql_error_carries_code_extension() {
+        let error: async_graphql::Error =
+            ApiError::new("page.unknown_layout", "Unknown layout: campaign")
+                .with_details(json!({ "layout": "campaign" }))
+                .into();
+
+        let extensions = error.extensions.expect("extensions");
+        assert_eq!(
+            extensions.get("code"),
+            Some(&async_graphql::Value::from("page.unknown_layout"))
+        );
+        assert!(extensions.get("details").is_some());
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn localized_uses_translation_when_available() {
+        let error = rustok_core::Error::NotFound("page".into());
+
+        let localized = ApiError::localized(&error, rustok_core::i18n::Locale::Ru);
+        assert_eq!(localized.code, "error.not_found");
+        assert_ne!(localized.message, "error.not_found");
+    }
+}
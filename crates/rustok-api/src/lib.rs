@@ -1,3 +1,4 @@
+pub mod api_error;
 pub mod context;
 #[cfg(feature = "server")]
 pub mod graphql;
@@ -10,6 +11,7 @@ pub mod route_selection;
 pub mod ui;
 pub mod write_path_feedback;
 
+pub use api_error::{ApiError, AUTH_INVALID_CREDENTIALS_CODE};
 #[cfg(feature = "server")]
 pub use context::{
     has_any_effective_permission, has_effective_permission, infer_user_role_from_permissions,
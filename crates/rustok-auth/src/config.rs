@@ -1,5 +1,8 @@
+use rustok_core::DEFAULT_CLOCK_LEEWAY;
 use serde::{Deserialize, Serialize};
 
+use crate::hasher::PasswordHashingParams;
+
 const DEFAULT_ACCESS_EXPIRATION_SECS: u64 = 900; // 15 minutes
 const DEFAULT_REFRESH_EXPIRATION_SECS: u64 = 60 * 60 * 24 * 30; // 30 days
 
@@ -33,6 +36,8 @@ pub struct AuthConfig {
     pub rsa_private_key_pem: Option<String>,
     /// RSA public key in PEM format. Required when `algorithm = RS256` for token decoding.
     pub rsa_public_key_pem: Option<String>,
+    /// Clock skew tolerated when checking `exp`, in seconds.
+    pub clock_leeway_secs: u64,
 }
 
 impl AuthConfig {
@@ -46,6 +51,7 @@ impl AuthConfig {
             algorithm: JwtAlgorithm::HS256,
             rsa_private_key_pem: None,
             rsa_public_key_pem: None,
+            clock_leeway_secs: DEFAULT_CLOCK_LEEWAY.num_seconds() as u64,
         }
     }
 
@@ -65,6 +71,19 @@ impl AuthConfig {
         self
     }
 
+    pub fn with_clock_leeway(mut self, secs: u64) -> Self {
+        self.clock_leeway_secs = secs;
+        self
+    }
+
+    /// Skew tolerance for session and token expiry checks outside JWT validation.
+    pub fn clock_leeway(&self) -> chrono::Duration {
+        i64::try_from(self.clock_leeway_secs)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .unwrap_or(chrono::Duration::MAX)
+    }
+
     pub fn with_rs256(
         mut self,
         private_key_pem: impl Into<String>,
@@ -88,6 +107,9 @@ pub struct AuthSettingsOverrides {
     pub rsa_public_key_pem: Option<String>,
     pub rsa_private_key_env: Option<String>,
     pub rsa_public_key_env: Option<String>,
+    pub clock_leeway_secs: Option<u64>,
+    /// Argon2 cost parameters; the server builds its `PasswordHasher` from these.
+    pub password_hashing: Option<PasswordHashingParams>,
 }
 
 impl AuthSettingsOverrides {
@@ -111,5 +133,8 @@ impl AuthSettingsOverrides {
         if let Some(v) = self.rsa_public_key_pem {
             config.rsa_public_key_pem = Some(v);
         }
+        if let Some(v) = self.clock_leeway_secs {
+            config.clock_leeway_secs = v;
+        }
     }
 }
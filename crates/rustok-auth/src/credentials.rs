@@ -1,26 +1,17 @@
-use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
-    Argon2,
-};
+use argon2::password_hash::rand_core::OsRng;
 use sha2::{Digest, Sha256};
 
-use crate::error::{AuthError, Result};
+use crate::error::Result;
+use crate::hasher::{Argon2PasswordHasher, PasswordHasher};
 
-/// Hash a password with Argon2id and a random salt.
+/// Hash a password with Argon2id (default cost) and a random salt.
 pub fn hash_password(password: &str) -> Result<String> {
-    let salt = SaltString::generate(&mut OsRng);
-    Argon2::default()
-        .hash_password(password.as_bytes(), &salt)
-        .map(|hash| hash.to_string())
-        .map_err(|_| AuthError::PasswordHashFailed)
+    Argon2PasswordHasher::default().hash(password)
 }
 
 /// Verify a password against an Argon2 hash.
 pub fn verify_password(password: &str, password_hash: &str) -> Result<bool> {
-    let parsed = PasswordHash::new(password_hash).map_err(|_| AuthError::InvalidCredentials)?;
-    Ok(Argon2::default()
-        .verify_password(password.as_bytes(), &parsed)
-        .is_ok())
+    Argon2PasswordHasher::default().verify(password, password_hash)
 }
 
 /// Generate a cryptographically secure 256-bit refresh token (64 hex chars).
@@ -1,3 +1,4 @@
+use rustok_core::CodedError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -28,3 +29,39 @@ pub enum AuthError {
 }
 
 pub type Result<T> = std::result::Result<T, AuthError>;
+
+impl CodedError for AuthError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            AuthError::InvalidCredentials => "auth.invalid_credentials",
+            AuthError::InvalidAccessToken => "auth.invalid_access_token",
+            AuthError::InvalidResetToken => "auth.invalid_reset_token",
+            AuthError::InvalidVerificationToken => "auth.invalid_verification_token",
+            AuthError::InvalidInviteToken => "auth.invalid_invite_token",
+            AuthError::TokenEncodingFailed => "auth.token_encoding_failed",
+            AuthError::PasswordHashFailed => "auth.password_hash_failed",
+            AuthError::Internal(_) => "auth.internal",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_are_stable() {
+        assert_eq!(
+            AuthError::InvalidCredentials.error_code(),
+            "auth.invalid_credentials"
+        );
+        assert_eq!(
+            AuthError::InvalidInviteToken.error_code(),
+            "auth.invalid_invite_token"
+        );
+        assert_eq!(
+            AuthError::Internal("boom".into()).error_code(),
+            "auth.internal"
+        );
+    }
+}
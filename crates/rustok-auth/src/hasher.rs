@@ -0,0 +1,134 @@
+use argon2::{
+    password_hash::{
+        rand_core::OsRng, PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString,
+    },
+    Algorithm, Argon2, Params, Version,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AuthError, Result};
+
+/// Password hashing seam used by sign-up, sign-in and password changes.
+///
+/// The server uses [`Argon2PasswordHasher`]; tests can swap in a cheap
+/// implementation so they don't pay the Argon2 cost on every user fixture.
+pub trait PasswordHasher: Send + Sync {
+    /// Hash `password` into a self-describing string suitable for storage.
+    fn hash(&self, password: &str) -> Result<String>;
+
+    /// Check `password` against a hash previously produced by [`Self::hash`].
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool>;
+}
+
+/// Argon2id cost parameters.
+///
+/// Defaults follow the `argon2` crate recommendations; production can raise
+/// them and local/dev environments can lower them via settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PasswordHashingParams {
+    /// Memory cost in KiB.
+    pub memory_kib: u32,
+    /// Number of iterations.
+    pub iterations: u32,
+    /// Degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for PasswordHashingParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// Argon2id [`PasswordHasher`] with configurable cost parameters.
+///
+/// Verification reads the parameters embedded in the stored hash, so hashes
+/// created with older cost settings keep verifying after the settings change.
+#[derive(Debug, Clone, Default)]
+pub struct Argon2PasswordHasher {
+    params: Params,
+}
+
+impl Argon2PasswordHasher {
+    pub fn new(params: PasswordHashingParams) -> Result<Self> {
+        let params = Params::new(
+            params.memory_kib,
+            params.iterations,
+            params.parallelism,
+            None,
+        )
+        .map_err(|error| AuthError::Internal(format!("invalid argon2 parameters: {error}")))?;
+
+        Ok(Self { params })
+    }
+
+    fn argon2(&self) -> Argon2<'static> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+    fn hash(&self, password: &str) -> Result<String> {
+        let salt = SaltString::generate(&mut OsRng);
+        self.argon2()
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|_| AuthError::PasswordHashFailed)
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool> {
+        let parsed = PasswordHash::new(password_hash).map_err(|_| AuthError::InvalidCredentials)?;
+        Ok(self
+            .argon2()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cheap_params() -> PasswordHashingParams {
+        PasswordHashingParams {
+            memory_kib: 8,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn custom_params_are_encoded_in_hash() {
+        let hasher = Argon2PasswordHasher::new(cheap_params()).unwrap();
+        let hash = hasher.hash("Password123!").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(hash.contains("m=8,t=1,p=1"));
+        assert!(hasher.verify("Password123!", &hash).unwrap());
+        assert!(!hasher.verify("wrong", &hash).unwrap());
+    }
+
+    #[test]
+    fn verify_accepts_hashes_created_with_other_params() {
+        let cheap = Argon2PasswordHasher::new(cheap_params()).unwrap();
+        let hash = cheap.hash("Password123!").unwrap();
+        assert!(Argon2PasswordHasher::default()
+            .verify("Password123!", &hash)
+            .unwrap());
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        let error = Argon2PasswordHasher::new(PasswordHashingParams {
+            memory_kib: 0,
+            iterations: 0,
+            parallelism: 0,
+        })
+        .unwrap_err();
+        assert!(matches!(error, AuthError::Internal(_)));
+    }
+}
@@ -282,8 +282,9 @@ fn decoding_key(config: &AuthConfig) -> Result<DecodingKey> {
 fn strict_jwt_validation(config: &AuthConfig) -> Validation {
     let mut validation = Validation::new(jwt_algorithm(config));
     validation.validate_exp = true;
-    validation.leeway = 0;
-    // RFC 7519: token MUST NOT be accepted on or after `exp`.
+    // Same skew tolerance as `rustok_core::TimeBounds`; with zero leeway a
+    // token is rejected on or after `exp` (RFC 7519).
+    validation.leeway = config.clock_leeway_secs;
     validation.reject_tokens_expiring_in_less_than = 1;
     validation.set_issuer(&[config.issuer.as_str()]);
     validation.set_audience(&[config.audience.as_str()]);
@@ -345,6 +346,7 @@ xwIDAQAB
             algorithm: JwtAlgorithm::HS256,
             rsa_private_key_pem: None,
             rsa_public_key_pem: None,
+            clock_leeway_secs: 0,
         }
     }
 
@@ -457,6 +459,25 @@ xwIDAQAB
         assert!(result.is_err(), "Expired JWT MUST be rejected");
     }
 
+    #[test]
+    fn clock_leeway_accepts_token_expired_within_skew() {
+        let config = AuthConfig {
+            access_expiration: 0,
+            ..test_config()
+        }
+        .with_clock_leeway(30);
+        let token = encode_access_token(
+            &config,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            UserRole::Customer,
+            Uuid::new_v4(),
+        )
+        .unwrap();
+
+        assert!(decode_access_token(&config, &token).is_ok());
+    }
+
     #[test]
     fn rfc7519_jwt_issuer_validated() {
         let config = test_config();
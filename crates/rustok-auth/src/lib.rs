@@ -1,6 +1,7 @@
 pub mod config;
 pub mod credentials;
 pub mod error;
+pub mod hasher;
 pub mod jwt;
 pub mod migrations;
 
@@ -8,6 +9,7 @@ pub mod migrations;
 pub use config::{AuthConfig, AuthSettingsOverrides, JwtAlgorithm};
 pub use credentials::{generate_refresh_token, hash_password, hash_refresh_token, verify_password};
 pub use error::AuthError;
+pub use hasher::{Argon2PasswordHasher, PasswordHasher, PasswordHashingParams};
 pub use jwt::{
     decode_access_token, decode_email_verification_token, decode_invite_token,
     decode_password_reset_token, encode_access_token, encode_email_verification_token,
@@ -8,6 +8,7 @@ use rustok_api::{
     has_any_effective_permission, loco::transactional_event_bus_from_context, AuthContext,
     RequestContext, TenantContext,
 };
+use rustok_content::MetadataVisibility;
 use rustok_core::Permission;
 use rustok_telemetry::metrics;
 use std::{collections::HashMap, time::Instant};
@@ -43,7 +44,8 @@ pub async fn list_posts(
     query.locale = query.locale.or(Some(request_context.locale.clone()));
     let requested_limit = query.per_page.map(u64::from);
     let effective_limit = query.per_page() as u64;
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let list_started_at = Instant::now();
     let result = service
         .list_posts_with_locale_fallback(
@@ -107,7 +109,8 @@ pub async fn get_post(
         .get("locale")
         .map(String::as_str)
         .unwrap_or(request_context.locale.as_str());
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let post = service
         .get_post_with_locale_fallback(
             tenant.id,
@@ -146,7 +149,8 @@ pub async fn create_post(
         "Permission denied: blog_posts:create required",
     )?;
 
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let post_id = service
         .create_post(tenant.id, auth.security_context(), input)
         .await
@@ -183,7 +187,8 @@ pub async fn update_post(
         "Permission denied: blog_posts:update required",
     )?;
 
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     service
         .update_post(tenant.id, id, auth.security_context(), input)
         .await
@@ -218,7 +223,8 @@ pub async fn delete_post(
         "Permission denied: blog_posts:delete required",
     )?;
 
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     service
         .delete_post(tenant.id, id, auth.security_context())
         .await
@@ -253,7 +259,8 @@ pub async fn publish_post(
         "Permission denied: blog_posts:publish required",
     )?;
 
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     service
         .publish_post(tenant.id, id, auth.security_context())
         .await
@@ -288,7 +295,8 @@ pub async fn unpublish_post(
         "Permission denied: blog_posts:publish required",
     )?;
 
-    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PostService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     service
         .unpublish_post(tenant.id, id, auth.security_context())
         .await
@@ -4,6 +4,7 @@ use rustok_api::{
     AuthContext, RequestContext, TenantContext,
 };
 use rustok_channel::ChannelService;
+use rustok_content::MetadataVisibility;
 use rustok_core::SecurityContext;
 use rustok_outbox::TransactionalEventBus;
 use rustok_profiles::{
@@ -43,7 +44,8 @@ impl BlogQuery {
         let tenant_id = tenant_id.unwrap_or(tenant.id);
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
-        let service = PostService::new(db.clone(), event_bus.clone());
+        let service = PostService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let post = match service
             .get_post_with_locale_fallback(
                 tenant_id,
@@ -102,7 +104,8 @@ impl BlogQuery {
         let tenant_id = tenant_id.unwrap_or(tenant.id);
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
-        let service = PostService::new(db.clone(), event_bus.clone());
+        let service = PostService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let post = service
             .get_post_by_slug_with_locale_fallback(
                 tenant_id,
@@ -1,13 +1,13 @@
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    EntityTrait, ModelTrait, QueryFilter, QueryOrder,
 };
 use tracing::instrument;
 use uuid::Uuid;
 
 use rustok_content::PLATFORM_FALLBACK_LOCALE;
-use rustok_core::{Action, Resource, SecurityContext};
+use rustok_core::{fetch_with_total, Action, Page, Resource, SecurityContext};
 
 use crate::dto::{
     CategoryListItem, CategoryResponse, CreateCategoryInput, ListCategoriesFilter,
@@ -202,20 +202,22 @@ impl CategoryService {
         tenant_id: Uuid,
         security: SecurityContext,
         filter: ListCategoriesFilter,
-    ) -> BlogResult<(Vec<CategoryListItem>, u64)> {
+    ) -> BlogResult<Page<CategoryListItem>> {
         enforce_scope(&security, Resource::Categories, Action::List)?;
         let locale = filter
             .locale
             .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string());
         let page = filter.page.max(1);
 
-        let paginator = blog_category::Entity::find()
-            .filter(blog_category::Column::TenantId.eq(tenant_id))
-            .order_by_asc(blog_category::Column::Position)
-            .paginate(&self.db, filter.per_page.max(1));
-
-        let total = paginator.num_items().await?;
-        let categories = paginator.fetch_page(page - 1).await?;
+        let (categories, total) = fetch_with_total(
+            &self.db,
+            blog_category::Entity::find()
+                .filter(blog_category::Column::TenantId.eq(tenant_id))
+                .order_by_asc(blog_category::Column::Position),
+            (page - 1) * filter.per_page.max(1),
+            filter.per_page.max(1),
+        )
+        .await?;
         let category_ids: Vec<Uuid> = categories.iter().map(|category| category.id).collect();
         let all_translations = if category_ids.is_empty() {
             Vec::new()
@@ -254,7 +256,11 @@ impl CategoryService {
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            (page - 1) * filter.per_page.max(1),
+        ))
     }
 
     pub(crate) async fn ensure_exists_in_tx(
@@ -13,7 +13,7 @@ use rustok_comments::{
     UpdateCommentInput as DomainUpdateCommentInput,
 };
 use rustok_content::PLATFORM_FALLBACK_LOCALE;
-use rustok_core::{prepare_content_payload, Action, Resource, SecurityContext};
+use rustok_core::{prepare_content_payload, Action, Page, Resource, SecurityContext};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 
@@ -263,7 +263,7 @@ impl CommentService {
         security: SecurityContext,
         post_id: Uuid,
         filter: ListCommentsFilter,
-    ) -> BlogResult<(Vec<CommentListItem>, u64)> {
+    ) -> BlogResult<Page<CommentListItem>> {
         self.list_for_post_with_locale_fallback(tenant_id, security, post_id, filter, None)
             .await
     }
@@ -276,14 +276,14 @@ impl CommentService {
         post_id: Uuid,
         filter: ListCommentsFilter,
         fallback_locale: Option<&str>,
-    ) -> BlogResult<(Vec<CommentListItem>, u64)> {
+    ) -> BlogResult<Page<CommentListItem>> {
         self.ensure_post_exists(tenant_id, post_id).await?;
 
         let locale = filter
             .locale
             .clone()
             .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string());
-        let (items, total) = self
+        let comments = self
             .comments
             .list_comments_for_target(
                 tenant_id,
@@ -300,13 +300,7 @@ impl CommentService {
             .await
             .map_err(BlogError::from)?;
 
-        Ok((
-            items
-                .into_iter()
-                .map(Self::map_comment_list_item)
-                .collect::<Vec<_>>(),
-            total,
-        ))
+        Ok(comments.map(Self::map_comment_list_item))
     }
 
     async fn ensure_post_exists(&self, tenant_id: Uuid, post_id: Uuid) -> BlogResult<()> {
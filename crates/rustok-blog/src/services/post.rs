@@ -1,7 +1,7 @@
 use sea_orm::{
     sea_query::{Expr, Query, SelectStatement},
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, Select, Set, TransactionTrait,
+    QueryFilter, QueryOrder, Select, Set, TransactionTrait,
 };
 use std::collections::HashMap;
 use tracing::instrument;
@@ -18,9 +18,9 @@ struct PostTranslationUpsertInput {
 
 use rustok_content::{
     available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
-    PLATFORM_FALLBACK_LOCALE,
+    MetadataVisibility, PLATFORM_FALLBACK_LOCALE,
 };
-use rustok_core::{prepare_content_payload, Action, Resource, SecurityContext};
+use rustok_core::{fetch_with_total, prepare_content_payload, Action, Resource, SecurityContext};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 use serde_json::Value;
@@ -40,6 +40,7 @@ use crate::state_machine::BlogPostStatus;
 pub struct PostService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
+    metadata_visibility: MetadataVisibility,
 }
 
 struct ResolvedTranslationRecord<'a> {
@@ -49,7 +50,17 @@ struct ResolvedTranslationRecord<'a> {
 
 impl PostService {
     pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
-        Self { db, event_bus }
+        Self {
+            db,
+            event_bus,
+            metadata_visibility: MetadataVisibility::default(),
+        }
+    }
+
+    /// Override which metadata keys single-post reads treat as internal.
+    pub fn with_metadata_visibility(mut self, metadata_visibility: MetadataVisibility) -> Self {
+        self.metadata_visibility = metadata_visibility;
+        self
     }
 
     #[instrument(skip(self, security, input))]
@@ -508,14 +519,18 @@ impl PostService {
         }
         let translations = self.load_translations(post_id).await?;
         let channel_slugs = self.load_channel_slugs(post_id).await?;
-        self.build_post_response(
-            post,
-            translations,
-            channel_slugs,
-            &locale,
-            fallback_locale.as_deref(),
-        )
-        .await
+        let mut response = self
+            .build_post_response(
+                post,
+                translations,
+                channel_slugs,
+                &locale,
+                fallback_locale.as_deref(),
+            )
+            .await?;
+        self.metadata_visibility
+            .apply(&security, &mut response.metadata);
+        Ok(response)
     }
 
     #[instrument(skip(self))]
@@ -560,15 +575,18 @@ impl PostService {
 
         let translations = self.load_translations(post.id).await?;
         let channel_slugs = self.load_channel_slugs(post.id).await?;
-        self.build_post_response(
-            post,
-            translations,
-            channel_slugs,
-            &locale,
-            fallback_locale.as_deref(),
-        )
-        .await
-        .map(Some)
+        let mut response = self
+            .build_post_response(
+                post,
+                translations,
+                channel_slugs,
+                &locale,
+                fallback_locale.as_deref(),
+            )
+            .await?;
+        self.metadata_visibility
+            .apply(&security, &mut response.metadata);
+        Ok(Some(response))
     }
 
     #[instrument(skip(self, security))]
@@ -631,12 +649,15 @@ impl PostService {
 
         select = apply_post_sort(select, &query);
 
-        let paginator = select.paginate(&self.db, query.per_page() as u64);
-        let total = paginator.num_items().await.map_err(BlogError::from)?;
-        let posts = paginator
-            .fetch_page((query.page().saturating_sub(1)) as u64)
-            .await
-            .map_err(BlogError::from)?;
+        let per_page = query.per_page() as u64;
+        let (posts, total) = fetch_with_total(
+            &self.db,
+            select,
+            query.page().saturating_sub(1) as u64 * per_page,
+            per_page,
+        )
+        .await
+        .map_err(BlogError::from)?;
         let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
 
         let translations_map = self.load_translations_map(&post_ids).await?;
@@ -729,12 +750,15 @@ impl PostService {
         select = apply_public_post_channel_filter(select, tenant_id, channel_slug);
         select = apply_post_sort(select, &query);
 
-        let paginator = select.paginate(&self.db, query.per_page() as u64);
-        let total = paginator.num_items().await.map_err(BlogError::from)?;
-        let posts = paginator
-            .fetch_page((query.page().saturating_sub(1)) as u64)
-            .await
-            .map_err(BlogError::from)?;
+        let per_page = query.per_page() as u64;
+        let (posts, total) = fetch_with_total(
+            &self.db,
+            select,
+            query.page().saturating_sub(1) as u64 * per_page,
+            per_page,
+        )
+        .await
+        .map_err(BlogError::from)?;
         let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
 
         let translations_map = self.load_translations_map(&post_ids).await?;
@@ -1683,6 +1707,70 @@ mod tests {
         assert_eq!(listed.total, 0);
     }
 
+    #[tokio::test]
+    async fn internal_metadata_is_hidden_from_customers_only() {
+        let db = setup_test_db().await;
+        ensure_blog_schema(&db).await;
+
+        let transport = MemoryTransport::new();
+        let _receiver = transport.subscribe();
+        let event_bus = TransactionalEventBus::new(Arc::new(transport));
+        let post_service = PostService::new(db.clone(), event_bus).with_metadata_visibility(
+            MetadataVisibility::from_tenant_settings(&serde_json::json!({
+                "content": { "internal_metadata_keys": ["editor_notes"] }
+            })),
+        );
+
+        let tenant_id = Uuid::new_v4();
+        let admin = SecurityContext::new(UserRole::Admin, Some(Uuid::new_v4()));
+        let customer = SecurityContext::new(UserRole::Customer, Some(Uuid::new_v4()));
+
+        let post_id = post_service
+            .create_post(
+                tenant_id,
+                admin.clone(),
+                CreatePostInput {
+                    locale: "en".to_string(),
+                    title: "Annotated".to_string(),
+                    body: "Body".to_string(),
+                    body_format: "markdown".to_string(),
+                    content_json: None,
+                    excerpt: None,
+                    slug: Some("annotated".to_string()),
+                    publish: true,
+                    tags: vec![],
+                    category_id: None,
+                    featured_image_url: None,
+                    seo_title: None,
+                    seo_description: None,
+                    channel_slugs: None,
+                    metadata: Some(serde_json::json!({
+                        "_review": "pending",
+                        "editor_notes": "check sources",
+                        "reading_time": 3
+                    })),
+                },
+            )
+            .await
+            .expect("post should be created");
+
+        let staff_view = post_service
+            .get_post(tenant_id, admin, post_id, "en")
+            .await
+            .expect("admin should read the post");
+        assert_eq!(staff_view.metadata["_review"], "pending");
+        assert_eq!(staff_view.metadata["editor_notes"], "check sources");
+
+        let public_view = post_service
+            .get_post_by_slug(tenant_id, customer, "en", "annotated")
+            .await
+            .expect("customer should read the post")
+            .expect("published post should be found");
+        assert!(public_view.metadata.get("_review").is_none());
+        assert!(public_view.metadata.get("editor_notes").is_none());
+        assert_eq!(public_view.metadata["reading_time"], 3);
+    }
+
     #[tokio::test]
     async fn create_and_update_post_store_channel_visibility_in_typed_relation() {
         let db = setup_test_db().await;
@@ -12,7 +12,7 @@ use uuid::Uuid;
 use rustok_content::{
     normalize_locale_code, resolve_by_locale_with_fallback, PLATFORM_FALLBACK_LOCALE,
 };
-use rustok_core::{Action, Resource, SecurityContext};
+use rustok_core::{Action, Page, Resource, SecurityContext};
 use rustok_taxonomy::{
     entities::{taxonomy_term, taxonomy_term_alias, taxonomy_term_translation},
     CreateTaxonomyTermInput, TaxonomyScopeType, TaxonomyService, TaxonomyTermKind,
@@ -159,7 +159,7 @@ impl TagService {
         tenant_id: Uuid,
         security: SecurityContext,
         filter: ListTagsFilter,
-    ) -> BlogResult<(Vec<TagListItem>, u64)> {
+    ) -> BlogResult<Page<TagListItem>> {
         enforce_scope(&security, Resource::Tags, Action::List)?;
         let locale =
             normalize_locale(filter.locale.as_deref().unwrap_or(PLATFORM_FALLBACK_LOCALE))?;
@@ -168,7 +168,7 @@ impl TagService {
 
         let terms = self.list_visible_terms(tenant_id).await?;
         if terms.is_empty() {
-            return Ok((Vec::new(), 0));
+            return Ok(Page::empty());
         }
 
         let term_ids = terms.iter().map(|term| term.id).collect::<Vec<_>>();
@@ -219,7 +219,7 @@ impl TagService {
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset as u64))
     }
 
     async fn find_visible_term(
@@ -454,7 +454,7 @@ async fn test_category_crud() -> TestResult<()> {
     assert_eq!(category.slug, "technology");
     assert_eq!(category.description.as_deref(), Some("Tech articles"));
 
-    let (list, total) = category_service
+    let list = category_service
         .list(
             tenant_id,
             admin.clone(),
@@ -465,8 +465,8 @@ async fn test_category_crud() -> TestResult<()> {
             },
         )
         .await?;
-    assert_eq!(total, 1);
-    assert_eq!(list[0].name, "Technology");
+    assert_eq!(list.total, Some(1));
+    assert_eq!(list.items[0].name, "Technology");
 
     category_service
         .delete(tenant_id, category_id, admin.clone())
@@ -523,7 +523,7 @@ async fn test_tag_crud_and_slug_normalization() -> TestResult<()> {
         )
         .await?;
 
-    let (list, total) = tag_service
+    let list = tag_service
         .list_tags(
             tenant_id,
             admin.clone(),
@@ -534,13 +534,13 @@ async fn test_tag_crud_and_slug_normalization() -> TestResult<()> {
             },
         )
         .await?;
-    assert_eq!(total, 2);
+    assert_eq!(list.total, Some(2));
 
     tag_service
         .delete_tag(tenant_id, tag_id2, admin.clone())
         .await?;
 
-    let (list_after, total_after) = tag_service
+    let list_after = tag_service
         .list_tags(
             tenant_id,
             admin.clone(),
@@ -551,8 +551,8 @@ async fn test_tag_crud_and_slug_normalization() -> TestResult<()> {
             },
         )
         .await?;
-    assert_eq!(total_after, 1);
-    assert_eq!(list_after[0].slug, "rust-language");
+    assert_eq!(list_after.total, Some(1));
+    assert_eq!(list_after.items[0].slug, "rust-language");
 
     let _ = list;
 
@@ -918,7 +918,7 @@ async fn test_comment_threaded_locale_fallback_update_delete_and_list() -> TestR
         BlogError::Comments(CommentsError::CommentNotFound(_))
     ));
 
-    let (page_one, total) = comment_service
+    let page_one = comment_service
         .list_for_post(
             tenant_id,
             SecurityContext::system(),
@@ -930,10 +930,10 @@ async fn test_comment_threaded_locale_fallback_update_delete_and_list() -> TestR
             },
         )
         .await?;
-    assert_eq!(total, 1);
-    assert_eq!(page_one.len(), 1);
+    assert_eq!(page_one.total, Some(1));
+    assert_eq!(page_one.items.len(), 1);
 
-    let (page_two, _) = comment_service
+    let page_two = comment_service
         .list_for_post(
             tenant_id,
             SecurityContext::system(),
@@ -945,10 +945,11 @@ async fn test_comment_threaded_locale_fallback_update_delete_and_list() -> TestR
             },
         )
         .await?;
-    assert_eq!(page_two.len(), 0);
+    assert_eq!(page_two.items.len(), 0);
     let statuses: Vec<String> = page_one
+        .items
         .iter()
-        .chain(page_two.iter())
+        .chain(page_two.items.iter())
         .map(|c| c.status.clone())
         .collect();
     assert!(statuses.iter().any(|s| s == "pending"));
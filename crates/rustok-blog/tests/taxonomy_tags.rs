@@ -123,7 +123,7 @@ async fn post_tags_create_blog_scoped_taxonomy_terms_and_usage_counts() {
         .all(|term| term.scope_type == TaxonomyScopeType::Module));
     assert!(terms.iter().all(|term| term.scope_value == "blog"));
 
-    let (tags, total) = tag_service
+    let tags = tag_service
         .list_tags(
             tenant_id,
             security,
@@ -135,8 +135,8 @@ async fn post_tags_create_blog_scoped_taxonomy_terms_and_usage_counts() {
         )
         .await
         .expect("blog tags should list");
-    assert_eq!(total, 2);
-    assert!(tags.iter().all(|item| item.use_count == 1));
+    assert_eq!(tags.total, Some(2));
+    assert!(tags.items.iter().all(|item| item.use_count == 1));
 }
 
 #[tokio::test]
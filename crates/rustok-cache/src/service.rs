@@ -1,9 +1,13 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use serde::Serialize;
+
 #[cfg(feature = "redis-cache")]
 use rustok_core::RedisCacheBackend;
-use rustok_core::{CacheBackend, FallbackCacheBackend, InMemoryCacheBackend};
+use rustok_core::{
+    CacheBackend, FallbackCacheBackend, InMemoryCacheBackend, InstrumentedCacheBackend,
+};
 
 /// Shared cache service providing backend creation from a centralized Redis connection.
 ///
@@ -80,7 +84,8 @@ impl CacheService {
     /// Create a cache backend with the given prefix, TTL, and capacity.
     ///
     /// If Redis is available, returns a `FallbackCacheBackend` (Redis primary + in-memory fallback).
-    /// Otherwise returns a pure in-memory backend.
+    /// Otherwise returns a pure in-memory backend. Either way the backend is wrapped in an
+    /// `InstrumentedCacheBackend` labelled with `prefix`, so cache metrics are recorded.
     pub async fn backend(
         &self,
         prefix: &str,
@@ -90,17 +95,28 @@ impl CacheService {
         #[cfg(feature = "redis-cache")]
         if let Some(url) = &self.redis_url {
             if let Ok(redis_backend) = RedisCacheBackend::new(url, prefix, ttl).await {
-                let memory = Arc::new(InMemoryCacheBackend::new(ttl, max_capacity));
-                return Arc::new(FallbackCacheBackend::new(Arc::new(redis_backend), memory));
+                let memory = Arc::new(InMemoryCacheBackend::named(prefix, ttl, max_capacity));
+                return Arc::new(InstrumentedCacheBackend::new(
+                    prefix,
+                    Arc::new(FallbackCacheBackend::new(Arc::new(redis_backend), memory)),
+                ));
             }
         }
 
-        Arc::new(InMemoryCacheBackend::new(ttl, max_capacity))
+        self.memory_backend(prefix, ttl, max_capacity)
     }
 
-    /// Create a pure in-memory backend (no Redis).
-    pub fn memory_backend(&self, ttl: Duration, max_capacity: u64) -> Arc<dyn CacheBackend> {
-        Arc::new(InMemoryCacheBackend::new(ttl, max_capacity))
+    /// Create a pure in-memory backend (no Redis), instrumented under `name`.
+    pub fn memory_backend(
+        &self,
+        name: &str,
+        ttl: Duration,
+        max_capacity: u64,
+    ) -> Arc<dyn CacheBackend> {
+        Arc::new(InstrumentedCacheBackend::new(
+            name,
+            Arc::new(InMemoryCacheBackend::named(name, ttl, max_capacity)),
+        ))
     }
 
     /// Health check: verify Redis connectivity (if configured).
@@ -139,7 +155,7 @@ impl CacheService {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CacheHealthReport {
     pub redis_configured: bool,
     pub redis_healthy: bool,
@@ -1,11 +1,11 @@
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
 };
 use tracing::instrument;
 use uuid::Uuid;
 
-use rustok_core::generate_id;
+use rustok_core::{fetch_with_total, generate_id, Page};
 
 use crate::dto::{
     BindChannelModuleInput, BindChannelOauthAppInput, ChannelDetailResponse,
@@ -184,16 +184,23 @@ impl ChannelService {
         tenant_id: Uuid,
         page: u64,
         per_page: u64,
-    ) -> ChannelResult<(Vec<ChannelResponse>, u64)> {
-        let paginator = channel::Entity::find()
-            .filter(channel::Column::TenantId.eq(tenant_id))
-            .order_by_desc(channel::Column::IsDefault)
-            .order_by_asc(channel::Column::CreatedAt)
-            .paginate(&self.db, per_page);
-        let total = paginator.num_items().await?;
-        let models = paginator.fetch_page(page.saturating_sub(1)).await?;
+    ) -> ChannelResult<Page<ChannelResponse>> {
+        let (models, total) = fetch_with_total(
+            &self.db,
+            channel::Entity::find()
+                .filter(channel::Column::TenantId.eq(tenant_id))
+                .order_by_desc(channel::Column::IsDefault)
+                .order_by_asc(channel::Column::CreatedAt),
+            page.saturating_sub(1) * per_page,
+            per_page,
+        )
+        .await?;
         let items = models.into_iter().map(to_channel_response).collect();
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            page.saturating_sub(1) * per_page,
+        ))
     }
 
     pub async fn list_channel_details(
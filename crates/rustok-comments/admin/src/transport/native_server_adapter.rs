@@ -69,7 +69,7 @@ async fn comments_threads_native(
             .map_err(ServerFnError::new)?;
         let service = rustok_comments::CommentsService::new(app_ctx.db.clone());
         let security = auth.security_context();
-        let (items, total) = service
+        let threads = service
             .list_threads(
                 tenant.id,
                 security,
@@ -81,7 +81,10 @@ async fn comments_threads_native(
             )
             .await
             .map_err(ServerFnError::new)?;
-        Ok(CommentThreadsPayload { items, total })
+        Ok(CommentThreadsPayload {
+            items: threads.items,
+            total: threads.total.unwrap_or_default(),
+        })
     }
     #[cfg(not(feature = "ssr"))]
     {
@@ -1,7 +1,7 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait, JoinType,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, QueryTrait, Set, TransactionTrait,
 };
 use std::{collections::HashMap, time::Instant};
 use tracing::instrument;
@@ -10,7 +10,7 @@ use uuid::Uuid;
 use rustok_content::{
     dto::validation::validate_body_format, normalize_locale_code, resolve_by_locale_with_fallback,
 };
-use rustok_core::{Action, PermissionScope, Resource, SecurityContext};
+use rustok_core::{fetch_with_total, Action, Page, PermissionScope, Resource, SecurityContext};
 use rustok_telemetry::metrics;
 
 use crate::dto::{
@@ -339,7 +339,7 @@ impl CommentsService {
         target_id: Uuid,
         filter: ListCommentsFilter,
         fallback_locale: Option<&str>,
-    ) -> CommentsResult<(Vec<CommentListItem>, u64)> {
+    ) -> CommentsResult<Page<CommentListItem>> {
         record_entrypoint("list_comments_for_target");
         let started = Instant::now();
         let result = async {
@@ -372,19 +372,21 @@ impl CommentsService {
                     effective_limit,
                     0,
                 );
-                return Ok((Vec::new(), 0));
+                return Ok(Page::empty());
             };
 
-            let paginator = comment::Entity::find()
-                .filter(comment::Column::TenantId.eq(tenant_id))
-                .filter(comment::Column::ThreadId.eq(thread.id))
-                .filter(comment::Column::DeletedAt.is_null())
-                .order_by_asc(comment::Column::Position)
-                .paginate(&self.db, effective_limit);
-
             let page_query_started = Instant::now();
-            let total = paginator.num_items().await?;
-            let comments = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
+            let (comments, total) = fetch_with_total(
+                &self.db,
+                comment::Entity::find()
+                    .filter(comment::Column::TenantId.eq(tenant_id))
+                    .filter(comment::Column::ThreadId.eq(thread.id))
+                    .filter(comment::Column::DeletedAt.is_null())
+                    .order_by_asc(comment::Column::Position),
+                filter.page.saturating_sub(1) * effective_limit,
+                effective_limit,
+            )
+            .await?;
             metrics::record_read_path_query(
                 LIBRARY_PATH,
                 "comments.list_comments_for_target",
@@ -447,7 +449,11 @@ impl CommentsService {
                 items.len(),
             );
 
-            Ok((items, total))
+            Ok(Page::offset(
+                items,
+                total,
+                filter.page.saturating_sub(1) * effective_limit,
+            ))
         }
         .await;
         record_operation_result("comments.list_comments_for_target", started, &result);
@@ -504,7 +510,7 @@ impl CommentsService {
         target_type: Option<&str>,
         thread_status: Option<crate::dto::CommentThreadStatus>,
         comment_status: Option<crate::dto::CommentStatus>,
-    ) -> CommentsResult<(Vec<CommentThreadSummary>, u64)> {
+    ) -> CommentsResult<Page<CommentThreadSummary>> {
         record_entrypoint("list_threads");
         let started = Instant::now();
         let result = async {
@@ -525,26 +531,35 @@ impl CommentsService {
             }
 
             if let Some(comment_status) = comment_status {
-                query = query
-                    .join(
-                        JoinType::InnerJoin,
-                        comment_thread::Relation::Comments.def(),
-                    )
-                    .filter(comment::Column::DeletedAt.is_null())
-                    .filter(comment::Column::Status.eq(comment_status))
-                    .distinct();
+                // A semi-join rather than JOIN + DISTINCT, so the windowed
+                // count sees one row per thread.
+                query = query.filter(
+                    comment_thread::Column::Id.in_subquery(
+                        comment::Entity::find()
+                            .select_only()
+                            .column(comment::Column::ThreadId)
+                            .filter(comment::Column::DeletedAt.is_null())
+                            .filter(comment::Column::Status.eq(comment_status))
+                            .into_query(),
+                    ),
+                );
             }
 
-            let paginator = query.paginate(&self.db, per_page.max(1));
-            let total = paginator.num_items().await?;
-            let threads = paginator.fetch_page(page.saturating_sub(1)).await?;
+            let (threads, total) = fetch_with_total(
+                &self.db,
+                query,
+                page.saturating_sub(1) * per_page.max(1),
+                per_page.max(1),
+            )
+            .await?;
 
-            Ok((
+            Ok(Page::offset(
                 threads
                     .into_iter()
                     .map(Self::map_thread_summary)
                     .collect::<Vec<_>>(),
                 total,
+                page.saturating_sub(1) * per_page.max(1),
             ))
         }
         .await;
@@ -579,14 +594,17 @@ impl CommentsService {
                     target_id: Uuid::nil(),
                 })?;
 
-            let paginator = comment::Entity::find()
-                .filter(comment::Column::TenantId.eq(tenant_id))
-                .filter(comment::Column::ThreadId.eq(thread.id))
-                .filter(comment::Column::DeletedAt.is_null())
-                .order_by_asc(comment::Column::Position)
-                .paginate(&self.db, per_page.max(1));
-            let total_comments = paginator.num_items().await?;
-            let comments = paginator.fetch_page(page.saturating_sub(1)).await?;
+            let (comments, total_comments) = fetch_with_total(
+                &self.db,
+                comment::Entity::find()
+                    .filter(comment::Column::TenantId.eq(tenant_id))
+                    .filter(comment::Column::ThreadId.eq(thread.id))
+                    .filter(comment::Column::DeletedAt.is_null())
+                    .order_by_asc(comment::Column::Position),
+                page.saturating_sub(1) * per_page.max(1),
+                per_page.max(1),
+            )
+            .await?;
 
             let comment_ids = comments.iter().map(|item| item.id).collect::<Vec<_>>();
             let mut bodies_map: HashMap<Uuid, Vec<comment_body::Model>> = HashMap::new();
@@ -97,12 +97,26 @@ pub struct ProductResponse {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Who created the product; stripped from storefront responses.
+    #[serde(default)]
+    pub created_by: Option<Uuid>,
+    /// Who last changed the product; stripped from storefront responses.
+    #[serde(default)]
+    pub updated_by: Option<Uuid>,
     pub translations: Vec<ProductTranslationResponse>,
     pub options: Vec<ProductOptionResponse>,
     pub variants: Vec<VariantResponse>,
     pub images: Vec<ProductImageResponse>,
 }
 
+impl ProductResponse {
+    /// Drop admin-only audit fields before handing the product to storefront readers.
+    pub fn strip_audit_actors(&mut self) {
+        self.created_by = None;
+        self.updated_by = None;
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProductTranslationResponse {
     pub locale: String,
@@ -168,3 +182,60 @@ pub struct PriceResponse {
     pub compare_at_amount: Option<Decimal>,
     pub on_sale: bool,
 }
+
+/// Why a product cannot be purchased right now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnavailableReason {
+    /// Draft or unpublished.
+    NotPublished,
+    Archived,
+    /// Hidden from the requesting storefront channel.
+    NotInChannel,
+    /// Published or sale window starts in the future.
+    NotYetAvailable,
+    /// Sale window has ended.
+    SaleEnded,
+    OutOfStock,
+}
+
+impl UnavailableReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotPublished => "not_published",
+            Self::Archived => "archived",
+            Self::NotInChannel => "not_in_channel",
+            Self::NotYetAvailable => "not_yet_available",
+            Self::SaleEnded => "sale_ended",
+            Self::OutOfStock => "out_of_stock",
+        }
+    }
+}
+
+/// Purchase eligibility of a product combining publish state, sale window
+/// and inventory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Availability {
+    pub purchasable: bool,
+    pub reason: Option<UnavailableReason>,
+    /// Sellable units across all variants; backordered variants count as zero.
+    pub available_quantity: i32,
+}
+
+impl Availability {
+    pub fn available(available_quantity: i32) -> Self {
+        Self {
+            purchasable: true,
+            reason: None,
+            available_quantity,
+        }
+    }
+
+    pub fn unavailable(reason: UnavailableReason, available_quantity: i32) -> Self {
+        Self {
+            purchasable: false,
+            reason: Some(reason),
+            available_quantity,
+        }
+    }
+}
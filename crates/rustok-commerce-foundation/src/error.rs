@@ -1,4 +1,4 @@
-use rustok_core::error::{Error as CoreError, ErrorKind, RichError};
+use rustok_core::error::{CodedError, Error as CoreError, ErrorKind, RichError};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -149,6 +149,30 @@ impl From<CommerceError> for RichError {
     }
 }
 
+impl CodedError for CommerceError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            CommerceError::Database(_) => ErrorKind::Database.api_code(),
+            CommerceError::ProductNotFound(_) => "commerce.product_not_found",
+            CommerceError::VariantNotFound(_) => "commerce.variant_not_found",
+            CommerceError::DuplicateHandle { .. } => "commerce.duplicate_handle",
+            CommerceError::DuplicateSku(_) => "commerce.duplicate_sku",
+            CommerceError::InvalidPrice(_) => "commerce.invalid_price",
+            CommerceError::InsufficientInventory { .. } => "commerce.insufficient_inventory",
+            CommerceError::InvalidOptionCombination => "commerce.invalid_option_combination",
+            CommerceError::Validation(_) => "commerce.validation",
+            CommerceError::ShippingProfileNotFound(_) => "commerce.shipping_profile_not_found",
+            CommerceError::DuplicateShippingProfileSlug(_) => {
+                "commerce.duplicate_shipping_profile_slug"
+            }
+            CommerceError::NoVariants => "commerce.no_variants",
+            CommerceError::CannotDeletePublished => "commerce.cannot_delete_published",
+            CommerceError::Rich(rich) => rich.error_code(),
+            CommerceError::Core(core) => core.error_code(),
+        }
+    }
+}
+
 /// Helper functions for creating common commerce errors
 impl CommerceError {
     /// Create a product not found error
@@ -222,4 +246,20 @@ mod tests {
         assert_eq!(rich.fields.get("requested"), Some(&"10".to_string()));
         assert_eq!(rich.fields.get("available"), Some(&"5".to_string()));
     }
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(
+            CommerceError::insufficient_inventory(10, 5).error_code(),
+            "commerce.insufficient_inventory"
+        );
+        assert_eq!(
+            CommerceError::product_not_found(Uuid::new_v4()).error_code(),
+            "commerce.product_not_found"
+        );
+        assert_eq!(
+            CommerceError::Core(CoreError::NotFound("x".into())).error_code(),
+            "error.not_found"
+        );
+    }
 }
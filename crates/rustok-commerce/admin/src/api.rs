@@ -929,7 +929,7 @@ async fn fetch_order_changes_native_with_context(
         ));
     }
 
-    let (items, total) = order_service_from_context(app_ctx)
+    let changes = order_service_from_context(app_ctx)
         .list_order_changes(
             tenant.id,
             rustok_order::dto::ListOrderChangesInput {
@@ -944,11 +944,11 @@ async fn fetch_order_changes_native_with_context(
         .map_err(ServerFnError::new)?;
 
     Ok(CommerceOrderChangeList {
-        items: items.into_iter().map(map_order_change).collect(),
-        total,
+        items: changes.items.into_iter().map(map_order_change).collect(),
+        total: changes.total.unwrap_or_default(),
         page: 1,
         per_page: 20,
-        has_next: total > 20,
+        has_next: changes.has_more,
     })
 }
 
@@ -34,7 +34,10 @@ use crate::{
 };
 
 use super::{
-    common::{ensure_permissions, PaginatedResponse},
+    common::{
+        commerce_bad_request, commerce_http_error, commerce_not_found, ensure_permissions,
+        PaginatedResponse,
+    },
     products::{ListProductsParams, ProductListItem},
 };
 
@@ -223,6 +226,8 @@ pub struct ListOrdersParams {
     pub pagination: Option<super::common::PaginationParams>,
     pub status: Option<String>,
     pub customer_id: Option<Uuid>,
+    /// Case-insensitive substring of the order number, e.g. `000123`.
+    pub order_number: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, ToSchema, utoipa::IntoParams)]
@@ -343,7 +348,7 @@ pub async fn create_product(
     let product = service
         .create_product(tenant.id, auth.user_id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok((StatusCode::CREATED, Json(product)))
 }
@@ -405,7 +410,7 @@ pub async fn update_product(
     let product = service
         .update_product(tenant.id, auth.user_id, id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(product))
 }
@@ -495,25 +500,29 @@ pub async fn list_orders(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (orders, total) =
-        OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
-            .list_orders_with_locale_fallback(
-                tenant.id,
-                rustok_order::dto::ListOrdersInput {
-                    page: pagination.page,
-                    per_page: pagination.limit(),
-                    status: params.status,
-                    customer_id: params.customer_id,
-                },
-                request_context.locale.as_str(),
-                Some(tenant.default_locale.as_str()),
-            )
-            .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?;
+    let orders = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .list_orders_with_locale_fallback(
+            tenant.id,
+            rustok_order::dto::ListOrdersInput {
+                page: pagination.page,
+                per_page: pagination.limit(),
+                status: params.status,
+                customer_id: params.customer_id,
+                order_number: params.order_number,
+            },
+            request_context.locale.as_str(),
+            Some(tenant.default_locale.as_str()),
+        )
+        .await
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(PaginatedResponse {
-        data: orders,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            orders.total.unwrap_or_default(),
+        ),
+        data: orders.items,
     }))
 }
 
@@ -553,17 +562,17 @@ pub async fn show_order(
         .map_err(|err| match err {
             rustok_order::error::OrderError::OrderNotFound(_)
             | rustok_order::error::OrderError::OrderReturnNotFound(_)
-            | rustok_order::error::OrderError::OrderChangeNotFound(_) => Error::NotFound,
-            other => Error::BadRequest(other.to_string()),
+            | rustok_order::error::OrderError::OrderChangeNotFound(_) => commerce_not_found(err),
+            other => commerce_bad_request(other),
         })?;
     let payment_collection = PaymentService::new(ctx.db.clone())
         .find_latest_collection_by_order(tenant.id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     let fulfillment = FulfillmentService::new(ctx.db.clone())
         .find_by_order(tenant.id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(AdminOrderDetailResponse {
         order,
@@ -596,7 +605,7 @@ pub async fn list_payment_collections(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (collections, total) = PaymentService::new(ctx.db.clone())
+    let collections = PaymentService::new(ctx.db.clone())
         .list_collections(
             tenant.id,
             ListPaymentCollectionsInput {
@@ -609,11 +618,15 @@ pub async fn list_payment_collections(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(PaginatedResponse {
-        data: collections,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: collections.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            collections.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -904,23 +917,26 @@ pub async fn list_order_returns(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (items, total) =
-        OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
-            .list_returns(
-                tenant.id,
-                ListOrderReturnsInput {
-                    page: pagination.page,
-                    per_page: pagination.limit(),
-                    order_id: params.order_id,
-                    status: params.status,
-                },
-            )
-            .await
-            .map_err(map_order_error)?;
+    let returns = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .list_returns(
+            tenant.id,
+            ListOrderReturnsInput {
+                page: pagination.page,
+                per_page: pagination.limit(),
+                order_id: params.order_id,
+                status: params.status,
+            },
+        )
+        .await
+        .map_err(map_order_error)?;
 
     Ok(Json(PaginatedResponse {
-        data: items,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: returns.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            returns.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1001,9 +1017,8 @@ pub async fn complete_order_return(
 
     if let Some(refund_input) = input.refund {
         if complete_input.refund_id.is_some() || complete_input.order_change_id.is_some() {
-            return Err(Error::BadRequest(
-                "refund helper cannot be combined with explicit refund_id or order_change_id"
-                    .to_string(),
+            return Err(commerce_bad_request(
+                "refund helper cannot be combined with explicit refund_id or order_change_id",
             ));
         }
         if complete_input
@@ -1012,8 +1027,8 @@ pub async fn complete_order_return(
             .map(|value| value.trim().eq_ignore_ascii_case("refund"))
             == Some(false)
         {
-            return Err(Error::BadRequest(
-                "refund helper requires resolution_type to be omitted or `refund`".to_string(),
+            return Err(commerce_bad_request(
+                "refund helper requires resolution_type to be omitted or `refund`",
             ));
         }
 
@@ -1164,24 +1179,27 @@ pub async fn list_order_changes(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (items, total) =
-        OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
-            .list_order_changes(
-                tenant.id,
-                ListOrderChangesInput {
-                    page: pagination.page,
-                    per_page: pagination.limit(),
-                    order_id: params.order_id,
-                    status: params.status,
-                    change_type: params.change_type,
-                },
-            )
-            .await
-            .map_err(map_order_error)?;
+    let changes = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .list_order_changes(
+            tenant.id,
+            ListOrderChangesInput {
+                page: pagination.page,
+                per_page: pagination.limit(),
+                order_id: params.order_id,
+                status: params.status,
+                change_type: params.change_type,
+            },
+        )
+        .await
+        .map_err(map_order_error)?;
 
     Ok(Json(PaginatedResponse {
-        data: items,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: changes.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            changes.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1343,7 +1361,7 @@ pub async fn list_refunds(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (refunds, total) = PaymentService::new(ctx.db.clone())
+    let refunds = PaymentService::new(ctx.db.clone())
         .list_refunds(
             tenant.id,
             ListRefundsInput {
@@ -1358,8 +1376,12 @@ pub async fn list_refunds(
         .map_err(map_payment_error)?;
 
     Ok(Json(PaginatedResponse {
-        data: refunds,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: refunds.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            refunds.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1488,7 +1510,7 @@ pub async fn list_shipping_profiles(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (items, total) = ShippingProfileService::new(ctx.db.clone())
+    let profiles = ShippingProfileService::new(ctx.db.clone())
         .list_shipping_profiles(
             tenant.id,
             ListShippingProfilesInput {
@@ -1502,11 +1524,15 @@ pub async fn list_shipping_profiles(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(PaginatedResponse {
-        data: items,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: profiles.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            profiles.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1536,7 +1562,7 @@ pub async fn create_shipping_profile(
     let profile = ShippingProfileService::new(ctx.db.clone())
         .create_shipping_profile(tenant.id, input)
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok((StatusCode::CREATED, Json(profile)))
 }
@@ -1574,7 +1600,7 @@ pub async fn show_shipping_profile(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(profile))
 }
@@ -1608,7 +1634,7 @@ pub async fn update_shipping_profile(
     let profile = ShippingProfileService::new(ctx.db.clone())
         .update_shipping_profile(tenant.id, id, input)
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(profile))
 }
@@ -1640,7 +1666,7 @@ pub async fn deactivate_shipping_profile(
     let profile = ShippingProfileService::new(ctx.db.clone())
         .deactivate_shipping_profile(tenant.id, id)
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(profile))
 }
@@ -1672,7 +1698,7 @@ pub async fn reactivate_shipping_profile(
     let profile = ShippingProfileService::new(ctx.db.clone())
         .reactivate_shipping_profile(tenant.id, id)
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(profile))
 }
@@ -1709,7 +1735,7 @@ pub async fn list_shipping_options(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     if let Some(active) = params.active {
         items.retain(|option| option.active == active);
     }
@@ -1771,7 +1797,7 @@ pub async fn create_shipping_option(
     let option = FulfillmentService::new(ctx.db.clone())
         .create_shipping_option(tenant.id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok((StatusCode::CREATED, Json(option)))
 }
@@ -1811,9 +1837,9 @@ pub async fn show_shipping_option(
         .await
         .map_err(|err| match err {
             rustok_fulfillment::error::FulfillmentError::ShippingOptionNotFound(_) => {
-                Error::NotFound
+                commerce_not_found(err)
             }
-            other => Error::BadRequest(other.to_string()),
+            other => commerce_bad_request(other),
         })?;
 
     Ok(Json(option))
@@ -1857,9 +1883,9 @@ pub async fn update_shipping_option(
         .await
         .map_err(|err| match err {
             rustok_fulfillment::error::FulfillmentError::ShippingOptionNotFound(_) => {
-                Error::NotFound
+                commerce_not_found(err)
             }
-            other => Error::BadRequest(other.to_string()),
+            other => commerce_bad_request(other),
         })?;
 
     Ok(Json(option))
@@ -1894,9 +1920,9 @@ pub async fn deactivate_shipping_option(
         .await
         .map_err(|err| match err {
             rustok_fulfillment::error::FulfillmentError::ShippingOptionNotFound(_) => {
-                Error::NotFound
+                commerce_not_found(err)
             }
-            other => Error::BadRequest(other.to_string()),
+            other => commerce_bad_request(other),
         })?;
 
     Ok(Json(option))
@@ -1931,9 +1957,9 @@ pub async fn reactivate_shipping_option(
         .await
         .map_err(|err| match err {
             rustok_fulfillment::error::FulfillmentError::ShippingOptionNotFound(_) => {
-                Error::NotFound
+                commerce_not_found(err)
             }
-            other => Error::BadRequest(other.to_string()),
+            other => commerce_bad_request(other),
         })?;
 
     Ok(Json(option))
@@ -1963,7 +1989,7 @@ pub async fn list_fulfillments(
     )?;
 
     let pagination = params.pagination.unwrap_or_default();
-    let (fulfillments, total) = FulfillmentService::new(ctx.db.clone())
+    let fulfillments = FulfillmentService::new(ctx.db.clone())
         .list_fulfillments(
             tenant.id,
             ListFulfillmentsInput {
@@ -1975,11 +2001,15 @@ pub async fn list_fulfillments(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(PaginatedResponse {
-        data: fulfillments,
-        meta: super::common::PaginationMeta::new(pagination.page, pagination.limit(), total),
+        data: fulfillments.items,
+        meta: super::common::PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            fulfillments.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -2331,7 +2361,7 @@ async fn resolve_return_refund_collection_id(
             .await
             .map_err(map_payment_error)?;
         if collection.order_id != Some(order_id) {
-            return Err(Error::BadRequest(format!(
+            return Err(commerce_bad_request(format!(
                 "payment collection {collection_id} is not attached to order {order_id}"
             )));
         }
@@ -2344,7 +2374,7 @@ async fn resolve_return_refund_collection_id(
         .map_err(map_payment_error)?
         .map(|collection| collection.id)
         .ok_or_else(|| {
-            Error::BadRequest(format!(
+            commerce_bad_request(format!(
                 "order {order_id} has no payment collection for return refund"
             ))
         })
@@ -2353,8 +2383,8 @@ async fn resolve_return_refund_collection_id(
 fn map_payment_error(error: rustok_payment::error::PaymentError) -> Error {
     match error {
         rustok_payment::error::PaymentError::PaymentCollectionNotFound(_)
-        | rustok_payment::error::PaymentError::RefundNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
+        | rustok_payment::error::PaymentError::RefundNotFound(_) => commerce_not_found(error),
+        other => commerce_bad_request(other),
     }
 }
 
@@ -2362,22 +2392,24 @@ fn map_order_error(error: rustok_order::error::OrderError) -> Error {
     match error {
         rustok_order::error::OrderError::OrderNotFound(_)
         | rustok_order::error::OrderError::OrderReturnNotFound(_)
-        | rustok_order::error::OrderError::OrderChangeNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
+        | rustok_order::error::OrderError::OrderChangeNotFound(_) => commerce_not_found(error),
+        other => commerce_bad_request(other),
     }
 }
 
 fn map_fulfillment_error(error: rustok_fulfillment::error::FulfillmentError) -> Error {
     match error {
-        rustok_fulfillment::error::FulfillmentError::FulfillmentNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
+        rustok_fulfillment::error::FulfillmentError::FulfillmentNotFound(_) => {
+            commerce_not_found(error)
+        }
+        other => commerce_bad_request(other),
     }
 }
 
 fn map_fulfillment_orchestration_error(error: FulfillmentOrchestrationError) -> Error {
     match error {
-        FulfillmentOrchestrationError::OrderNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
+        FulfillmentOrchestrationError::OrderNotFound(_) => commerce_not_found(error),
+        other => commerce_bad_request(other),
     }
 }
 
@@ -2391,10 +2423,10 @@ fn map_post_order_orchestration_error(error: PostOrderOrchestrationError) -> Err
         | PostOrderOrchestrationError::Payment(
             rustok_payment::error::PaymentError::PaymentCollectionNotFound(_)
             | rustok_payment::error::PaymentError::RefundNotFound(_),
-        ) => Error::NotFound,
-        PostOrderOrchestrationError::Order(other) => Error::BadRequest(other.to_string()),
-        PostOrderOrchestrationError::Payment(other) => Error::BadRequest(other.to_string()),
-        PostOrderOrchestrationError::Validation(message) => Error::BadRequest(message),
+        ) => commerce_not_found(error),
+        PostOrderOrchestrationError::Order(other) => commerce_bad_request(other),
+        PostOrderOrchestrationError::Payment(other) => commerce_bad_request(other),
+        PostOrderOrchestrationError::Validation(message) => commerce_bad_request(message),
     }
 }
 
@@ -2406,13 +2438,6 @@ fn decision_requires_payments_update(action: &str, has_refund_payload: bool) ->
     action.trim().to_ascii_lowercase().replace('-', "_") == "refund"
 }
 
-fn map_shipping_profile_error(error: crate::CommerceError) -> Error {
-    match error {
-        crate::CommerceError::ShippingProfileNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
-    }
-}
-
 async fn validate_product_shipping_profile_input(
     db: &sea_orm::DatabaseConnection,
     tenant_id: Uuid,
@@ -2425,7 +2450,7 @@ async fn validate_product_shipping_profile_input(
     ShippingProfileService::new(db.clone())
         .ensure_shipping_profile_slug_exists(tenant_id, &slug)
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(())
 }
@@ -2442,7 +2467,7 @@ async fn validate_shipping_option_profile_inputs(
     ShippingProfileService::new(db.clone())
         .ensure_shipping_profile_slugs_exist(tenant_id, slugs.iter())
         .await
-        .map_err(map_shipping_profile_error)?;
+        .map_err(commerce_http_error)?;
 
     Ok(())
 }
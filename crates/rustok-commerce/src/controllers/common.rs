@@ -1,9 +1,12 @@
+use axum::http::StatusCode;
 use loco_rs::{Error, Result};
-use rustok_api::{has_any_effective_permission, AuthContext};
-use rustok_core::Permission;
+use rustok_api::{has_any_effective_permission, ApiError, AuthContext};
+use rustok_core::{ErrorKind, Permission, RichError};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::CommerceError;
+
 #[derive(Debug, Clone, Deserialize, Default, IntoParams, ToSchema)]
 pub struct PaginationParams {
     #[serde(default = "default_page")]
@@ -73,3 +76,27 @@ pub(super) fn ensure_permissions(
 
     Ok(())
 }
+
+/// Map a catalog, shipping-profile or database error to an HTTP error with the
+/// status of its [`RichError`] kind and the stable [`rustok_core::CodedError`] code.
+pub(super) fn commerce_http_error(err: impl Into<CommerceError>) -> Error {
+    let err = err.into();
+    let api_error = ApiError::from_error(&err);
+    let rich: RichError = err.into();
+    let status = StatusCode::from_u16(rich.status_code).unwrap_or(StatusCode::BAD_REQUEST);
+    api_error.into_loco_error(status)
+}
+
+/// Order, payment, fulfillment and cart errors have no stable codes yet, so
+/// they are reported with the generic `error.not_found` code.
+pub(super) fn commerce_not_found(err: impl std::fmt::Display) -> Error {
+    ApiError::new(ErrorKind::NotFound.api_code(), err.to_string())
+        .into_loco_error(StatusCode::NOT_FOUND)
+}
+
+/// Counterpart of [`commerce_not_found`] for rejected input and state
+/// transitions, reported with the generic `error.validation` code.
+pub(super) fn commerce_bad_request(err: impl std::fmt::Display) -> Error {
+    ApiError::new(ErrorKind::Validation.api_code(), err.to_string())
+        .into_loco_error(StatusCode::BAD_REQUEST)
+}
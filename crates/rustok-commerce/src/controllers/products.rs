@@ -3,7 +3,7 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use loco_rs::{app::AppContext, Error, Result};
+use loco_rs::{app::AppContext, Result};
 use rustok_api::{
     loco::transactional_event_bus_from_context, AuthContext, RequestContext, TenantContext,
 };
@@ -24,7 +24,9 @@ use crate::{
     CatalogService,
 };
 
-use super::common::{ensure_permissions, PaginatedResponse, PaginationMeta, PaginationParams};
+use super::common::{
+    commerce_http_error, ensure_permissions, PaginatedResponse, PaginationMeta, PaginationParams,
+};
 
 /// Shared admin product list handler.
 pub async fn list_products(
@@ -74,7 +76,7 @@ pub async fn list_products(
         .clone()
         .count(&ctx.db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
     metrics::record_read_path_query(
         "http",
         "commerce.list_products",
@@ -90,7 +92,7 @@ pub async fn list_products(
         .limit(pagination.limit())
         .all(&ctx.db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
     metrics::record_read_path_query(
         "http",
         "commerce.list_products",
@@ -111,7 +113,7 @@ pub async fn list_products(
             .filter(product_translation::Column::ProductId.is_in(product_ids))
             .all(&ctx.db)
             .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?;
+            .map_err(commerce_http_error)?;
         metrics::record_read_path_query(
             "http",
             "commerce.list_products",
@@ -138,7 +140,7 @@ pub async fn list_products(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     let items = products
         .into_iter()
@@ -224,7 +226,7 @@ pub async fn show_product(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(product))
 }
@@ -246,7 +248,7 @@ pub async fn delete_product(
     service
         .delete_product(tenant.id, auth.user_id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -268,7 +270,7 @@ pub async fn publish_product(
     let product = service
         .publish_product(tenant.id, auth.user_id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(product))
 }
@@ -290,7 +292,7 @@ pub async fn unpublish_product(
     let product = service
         .unpublish_product(tenant.id, auth.user_id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     Ok(Json(product))
 }
@@ -12,6 +12,7 @@ use rustok_cart::CartError;
 use rustok_core::locale_tags_match;
 use rustok_inventory::check_variant_availability_for_public_channel;
 use rustok_pricing::PriceResolutionContext;
+use rustok_product::load_product_availability;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
@@ -22,11 +23,11 @@ use uuid::Uuid;
 
 use crate::{
     dto::{
-        AddCartLineItemInput, CartResponse, CompleteCheckoutInput, CompleteCheckoutResponse,
-        CreateCartInput, CreateOrderReturnInput, CustomerResponse, ListOrderReturnsInput,
-        ListRefundsInput, OrderResponse, OrderReturnResponse, PaymentCollectionResponse,
-        RefundResponse, RegionResponse, ResolveStoreContextInput, ShippingOptionResponse,
-        StoreContextResponse, UpdateCartContextInput,
+        AddCartLineItemInput, Availability, CartResponse, CompleteCheckoutInput,
+        CompleteCheckoutResponse, CreateCartInput, CreateOrderReturnInput, CustomerResponse,
+        ListOrderReturnsInput, ListRefundsInput, OrderResponse, OrderReturnResponse,
+        PaymentCollectionResponse, RefundResponse, RegionResponse, ResolveStoreContextInput,
+        ShippingOptionResponse, StoreContextResponse, UnavailableReason, UpdateCartContextInput,
     },
     entities::{product, product_translation, product_variant, variant_translation},
     search::product_translation_title_search_condition,
@@ -40,12 +41,15 @@ use crate::{
         is_shipping_option_compatible_with_profiles, load_cart_shipping_profile_slugs,
         normalize_shipping_profile_slug, shipping_profile_slug_from_product_metadata,
     },
-    CartService, CatalogService, CustomerService, FulfillmentService, OrderService, PaymentService,
-    PricingService, ProductResponse, RegionService, StoreContextService,
+    CartService, CatalogService, CommerceError, CustomerService, FulfillmentService, OrderService,
+    PaymentService, PricingService, ProductResponse, RegionService, StoreContextService,
 };
 
 use super::{
-    common::{PaginatedResponse, PaginationMeta, PaginationParams},
+    common::{
+        commerce_bad_request, commerce_http_error, commerce_not_found, PaginatedResponse,
+        PaginationMeta, PaginationParams,
+    },
     products::ProductListItem,
 };
 
@@ -53,6 +57,10 @@ pub fn routes() -> Routes {
     Routes::new()
         .add("/products", axum::routing::get(list_products))
         .add("/products/{id}", axum::routing::get(show_product))
+        .add(
+            "/products/{id}/availability",
+            axum::routing::get(show_product_availability),
+        )
         .add("/regions", axum::routing::get(list_regions))
         .add(
             "/shipping-options",
@@ -147,7 +155,7 @@ pub async fn list_products(
         .order_by_desc(product::Column::CreatedAt)
         .all(&ctx.db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .map_err(commerce_http_error)?
         .into_iter()
         .filter(|product| {
             is_metadata_visible_for_public_channel(
@@ -174,7 +182,7 @@ pub async fn list_products(
             .filter(product_translation::Column::ProductId.is_in(product_ids))
             .all(&ctx.db)
             .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?
+            .map_err(commerce_http_error)?
     };
 
     let mut translation_map =
@@ -194,7 +202,7 @@ pub async fn list_products(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     let items = products
         .into_iter()
@@ -259,7 +267,7 @@ pub async fn show_product(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     if product.status != product::ProductStatus::Active
         || product.published_at.is_none()
@@ -268,7 +276,7 @@ pub async fn show_product(
             public_channel_slug.as_deref(),
         )
     {
-        return Err(Error::NotFound);
+        return Err(commerce_http_error(CommerceError::ProductNotFound(id)));
     }
 
     apply_public_channel_inventory_to_product(
@@ -278,11 +286,39 @@ pub async fn show_product(
         public_channel_slug.as_deref(),
     )
     .await
-    .map_err(|err| Error::BadRequest(err.to_string()))?;
+    .map_err(commerce_http_error)?;
+    product.strip_audit_actors();
 
     Ok(Json(product))
 }
 
+/// Show whether a published storefront product can be purchased right now
+#[utoipa::path(
+    get,
+    path = "/store/products/{id}/availability",
+    tag = "store",
+    params(("id" = Uuid, Path, description = "Product ID")),
+    responses(
+        (status = 200, description = "Product availability", body = Availability),
+        (status = 404, description = "Product not found")
+    )
+)]
+pub async fn show_product_availability(
+    State(ctx): State<AppContext>,
+    tenant: TenantContext,
+    request_context: RequestContext,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Availability>> {
+    ensure_storefront_channel_enabled(&ctx, &request_context).await?;
+
+    let public_channel_slug = public_channel_slug_from_request(&request_context);
+    let availability =
+        load_store_product_availability(&ctx.db, tenant.id, id, public_channel_slug.as_deref())
+            .await?;
+
+    Ok(Json(availability))
+}
+
 /// List available storefront regions
 #[utoipa::path(
     get,
@@ -307,7 +343,7 @@ pub async fn list_regions(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     Ok(Json(regions))
 }
 
@@ -342,7 +378,7 @@ pub async fn list_shipping_options(
             let required_shipping_profiles =
                 load_cart_shipping_profile_slugs(&ctx.db, tenant.id, &cart)
                     .await
-                    .map_err(|err| Error::BadRequest(err.to_string()))?;
+                    .map_err(commerce_http_error)?;
             (
                 resolve_context_from_cart(&ctx, tenant.id, &request_context, &cart).await?,
                 storefront_public_channel_slug_for_cart(&cart, &request_context),
@@ -373,7 +409,7 @@ pub async fn list_shipping_options(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     if let Some(currency_code) = context.currency_code.as_deref() {
         options.retain(|option| option.currency_code.eq_ignore_ascii_case(currency_code));
@@ -422,9 +458,8 @@ pub async fn create_cart(
         .clone()
         .or(input.currency_code.clone())
         .ok_or_else(|| {
-            Error::BadRequest(
-                "currency_code is required unless it can be resolved from region/country"
-                    .to_string(),
+            commerce_bad_request(
+                "currency_code is required unless it can be resolved from region/country",
             )
         })?;
 
@@ -446,7 +481,7 @@ pub async fn create_cart(
             request_context.channel_slug.clone(),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     let cart = enrich_storefront_cart(
         &ctx,
         tenant.id,
@@ -488,7 +523,7 @@ pub async fn get_cart(
     let cart = service
         .get_cart(tenant.id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     ensure_store_cart_access(&cart, customer_id)?;
     Ok(Json(
         enrich_storefront_cart(
@@ -686,9 +721,9 @@ pub async fn update_cart_line_item(
         let resolved_price = pricing_service
             .resolve_variant_price(tenant.id, variant_id, pricing_context)
             .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?
+            .map_err(commerce_http_error)?
             .ok_or_else(|| {
-                Error::BadRequest(format!(
+                commerce_bad_request(format!(
                     "No storefront price for variant {} in currency {}",
                     variant_id, existing.currency_code
                 ))
@@ -812,7 +847,7 @@ pub async fn create_payment_collection(
     if let Some(existing) = service
         .find_reusable_collection_by_cart(tenant.id, cart.id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .map_err(commerce_bad_request)?
     {
         return Ok((StatusCode::OK, Json(existing)));
     }
@@ -829,7 +864,7 @@ pub async fn create_payment_collection(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok((StatusCode::CREATED, Json(collection)))
 }
@@ -861,7 +896,7 @@ pub async fn complete_cart_checkout(
     let mut cart = cart_service
         .get_cart(tenant.id, cart_id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     let customer_id = current_customer_id(&ctx, tenant.id, auth.0.as_ref()).await?;
     ensure_store_cart_access(&cart, customer_id)?;
     let actor_id = checkout_actor_id(auth.0.as_ref());
@@ -917,7 +952,7 @@ pub async fn complete_cart_checkout(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(response))
 }
@@ -944,7 +979,7 @@ pub async fn get_me(
     let customer = service
         .get_customer_by_user(tenant.id, auth.user_id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     Ok(Json(customer))
 }
 
@@ -981,7 +1016,7 @@ pub async fn get_order(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     if order.customer_id != Some(customer_id) {
         return Err(Error::Unauthorized(
@@ -1020,7 +1055,7 @@ pub async fn create_order_return(
     let created = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
         .create_return(tenant.id, id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok((StatusCode::CREATED, Json(created)))
 }
@@ -1053,23 +1088,26 @@ pub async fn list_order_returns(
 
     ensure_customer_owns_order(&ctx, tenant.id, Some(&auth), id).await?;
 
-    let (items, total) =
-        OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
-            .list_returns(
-                tenant.id,
-                ListOrderReturnsInput {
-                    page: params.pagination.page,
-                    per_page: params.pagination.per_page,
-                    order_id: Some(id),
-                    status: params.status,
-                },
-            )
-            .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?;
+    let returns = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .list_returns(
+            tenant.id,
+            ListOrderReturnsInput {
+                page: params.pagination.page,
+                per_page: params.pagination.per_page,
+                order_id: Some(id),
+                status: params.status,
+            },
+        )
+        .await
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(PaginatedResponse {
-        data: items,
-        meta: PaginationMeta::new(params.pagination.page, params.pagination.limit(), total),
+        data: returns.items,
+        meta: PaginationMeta::new(
+            params.pagination.page,
+            params.pagination.limit(),
+            returns.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1107,7 +1145,7 @@ pub async fn list_order_refunds(
     let order = order_service
         .get_order(tenant.id, id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
     if order.customer_id != Some(customer_id) {
         return Err(Error::Unauthorized(
             "Order does not belong to the current customer".to_string(),
@@ -1115,7 +1153,7 @@ pub async fn list_order_refunds(
     }
 
     let payment_service = PaymentService::new(ctx.db.clone());
-    let (items, total) = payment_service
+    let refunds = payment_service
         .list_refunds(
             tenant.id,
             ListRefundsInput {
@@ -1127,11 +1165,15 @@ pub async fn list_order_refunds(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     Ok(Json(PaginatedResponse {
-        data: items,
-        meta: PaginationMeta::new(params.pagination.page, params.pagination.limit(), total),
+        data: refunds.items,
+        meta: PaginationMeta::new(
+            params.pagination.page,
+            params.pagination.limit(),
+            refunds.total.unwrap_or_default(),
+        ),
     }))
 }
 
@@ -1156,7 +1198,7 @@ async fn resolve_context(
             },
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))
+        .map_err(commerce_bad_request)
 }
 
 async fn resolve_context_from_cart(
@@ -1189,7 +1231,7 @@ async fn ensure_customer_owns_order(
     let order = OrderService::new(ctx.db.clone(), transactional_event_bus_from_context(ctx))
         .get_order(tenant_id, order_id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     if order.customer_id != Some(customer_id) {
         return Err(Error::Unauthorized(
@@ -1213,7 +1255,7 @@ async fn current_customer_id(
     match service.get_customer_by_user(tenant_id, auth.user_id).await {
         Ok(customer) => Ok(Some(customer.id)),
         Err(rustok_customer::CustomerError::CustomerByUserNotFound(_)) => Ok(None),
-        Err(err) => Err(Error::BadRequest(err.to_string())),
+        Err(err) => Err(commerce_bad_request(err)),
     }
 }
 
@@ -1223,7 +1265,7 @@ async fn ensure_storefront_channel_enabled(
 ) -> Result<()> {
     let enabled = is_module_enabled_for_request_channel(&ctx.db, request_context, MODULE_SLUG)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_bad_request)?;
 
     if !enabled {
         return Err(Error::Unauthorized(format!(
@@ -1257,8 +1299,8 @@ fn ensure_store_cart_access(cart: &CartResponse, customer_id: Option<Uuid>) -> R
 
 fn ensure_cart_allows_payment_collection(cart: &CartResponse) -> Result<()> {
     if cart.status == "completed" {
-        return Err(Error::BadRequest(
-            "Cannot create payment collection for completed cart".to_string(),
+        return Err(commerce_bad_request(
+            "Cannot create payment collection for completed cart",
         ));
     }
 
@@ -1365,9 +1407,9 @@ async fn reprice_storefront_cart_line_items(
         let resolved_price = pricing_service
             .resolve_variant_price(tenant_id, variant_id, pricing_context)
             .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?
+            .map_err(commerce_http_error)?
             .ok_or_else(|| {
-                Error::BadRequest(format!(
+                commerce_bad_request(format!(
                     "No storefront price for variant {} in currency {}",
                     variant_id, cart.currency_code
                 ))
@@ -1496,7 +1538,7 @@ async fn enrich_storefront_cart(
         Some(tenant_default_locale),
     )
     .await
-    .map_err(|err| Error::BadRequest(err.to_string()))
+    .map_err(commerce_http_error)
 }
 
 fn requested_cart_context(
@@ -1544,9 +1586,8 @@ async fn validate_selected_shipping_option(
         shipping_selections.to_vec()
     } else if let Some(selected_shipping_option_id) = selected_shipping_option_id {
         if cart.delivery_groups.len() > 1 {
-            return Err(Error::BadRequest(
-                "selected_shipping_option_id can only be used for carts with a single delivery group"
-                    .to_string(),
+            return Err(commerce_bad_request(
+                "selected_shipping_option_id can only be used for carts with a single delivery group",
             ));
         }
         cart.delivery_groups
@@ -1580,21 +1621,21 @@ async fn validate_selected_shipping_option(
                 tenant_default_locale,
             )
             .await
-            .map_err(|err| Error::BadRequest(err.to_string()))?;
+            .map_err(commerce_bad_request)?;
         if !option.currency_code.eq_ignore_ascii_case(currency_code) {
-            return Err(Error::BadRequest(format!(
+            return Err(commerce_bad_request(format!(
                 "Shipping option {} uses currency {}, expected {}",
                 option.id, option.currency_code, currency_code
             )));
         }
         if !is_metadata_visible_for_public_channel(&option.metadata, public_channel_slug) {
-            return Err(Error::BadRequest(format!(
+            return Err(commerce_bad_request(format!(
                 "Shipping option {} is not available for the current channel",
                 option.id
             )));
         }
         if !is_shipping_option_compatible_with_profiles(&option, &required_shipping_profiles) {
-            return Err(Error::BadRequest(format!(
+            return Err(commerce_bad_request(format!(
                 "Shipping option {} is not compatible with shipping profile {}",
                 option.id, selection.shipping_profile_slug
             )));
@@ -1646,39 +1687,47 @@ async fn resolve_store_line_item_input(
         .filter(product_variant::Column::TenantId.eq(tenant_id))
         .one(db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
-        .ok_or(Error::NotFound)?;
+        .map_err(commerce_http_error)?
+        .ok_or_else(|| commerce_http_error(CommerceError::VariantNotFound(input.variant_id)))?;
 
     let product_model = product::Entity::find_by_id(variant.product_id)
         .filter(product::Column::TenantId.eq(tenant_id))
         .one(db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
-        .ok_or(Error::NotFound)?;
-    if product_model.status != product::ProductStatus::Active
-        || product_model.published_at.is_none()
-        || !is_metadata_visible_for_public_channel(&product_model.metadata, public_channel_slug)
+        .map_err(commerce_http_error)?
+        .ok_or_else(|| commerce_http_error(CommerceError::ProductNotFound(variant.product_id)))?;
+    let availability =
+        load_store_product_availability(db, tenant_id, product_model.id, public_channel_slug)
+            .await?;
+    // Stock is checked per variant below with the requested quantity.
+    if let Some(reason) = availability
+        .reason
+        .filter(|reason| *reason != UnavailableReason::OutOfStock)
     {
-        return Err(Error::NotFound);
+        return Err(commerce_bad_request(format!(
+            "Product {} is not available for purchase: {}",
+            product_model.id,
+            reason.as_str()
+        )));
     }
 
     let product_translation_models = product_translation::Entity::find()
         .filter(product_translation::Column::ProductId.eq(product_model.id))
         .all(db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
     let variant_translation_models = variant_translation::Entity::find()
         .filter(variant_translation::Column::VariantId.eq(variant.id))
         .all(db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(commerce_http_error)?;
 
     let resolved_price = pricing_service
         .resolve_variant_price(tenant_id, variant.id, pricing_context.clone())
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .map_err(commerce_http_error)?
         .ok_or_else(|| {
-            Error::BadRequest(format!(
+            commerce_bad_request(format!(
                 "No storefront price for variant {} in currency {}",
                 variant.id, pricing_context.currency_code
             ))
@@ -1774,7 +1823,7 @@ async fn validate_store_line_item_quantity(
         .filter(product_variant::Column::TenantId.eq(tenant_id))
         .one(db)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .map_err(commerce_http_error)?
     else {
         return Ok(());
     };
@@ -1789,6 +1838,29 @@ async fn validate_store_line_item_quantity(
     .await
 }
 
+/// Availability of a product that is visible to the storefront; draft,
+/// archived and channel-hidden products are reported as not found.
+async fn load_store_product_availability(
+    db: &sea_orm::DatabaseConnection,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    public_channel_slug: Option<&str>,
+) -> Result<Availability> {
+    let availability = load_product_availability(db, tenant_id, product_id, public_channel_slug)
+        .await
+        .map_err(commerce_http_error)?;
+    match availability.reason {
+        Some(
+            UnavailableReason::NotPublished
+            | UnavailableReason::Archived
+            | UnavailableReason::NotInChannel,
+        ) => Err(commerce_http_error(CommerceError::ProductNotFound(
+            product_id,
+        ))),
+        _ => Ok(availability),
+    }
+}
+
 async fn validate_store_variant_inventory(
     db: &sea_orm::DatabaseConnection,
     tenant_id: Uuid,
@@ -1804,9 +1876,9 @@ async fn validate_store_variant_inventory(
         public_channel_slug,
     )
     .await
-    .map_err(|err| Error::BadRequest(err.to_string()))?;
+    .map_err(commerce_http_error)?;
     if !available {
-        return Err(Error::BadRequest(format!(
+        return Err(commerce_bad_request(format!(
             "Variant {} does not have enough available inventory for the current channel",
             variant.id
         )));
@@ -1817,8 +1889,10 @@ async fn validate_store_variant_inventory(
 
 fn map_cart_error(error: CartError) -> Error {
     match error {
-        CartError::CartNotFound(_) | CartError::CartLineItemNotFound(_) => Error::NotFound,
-        other => Error::BadRequest(other.to_string()),
+        CartError::CartNotFound(_) | CartError::CartLineItemNotFound(_) => {
+            commerce_not_found(error)
+        }
+        other => commerce_bad_request(other),
     }
 }
 
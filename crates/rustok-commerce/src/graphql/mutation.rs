@@ -7,12 +7,14 @@ use rustok_api::{
 use rustok_core::{locale_tags_match, Permission};
 use rustok_inventory::check_variant_availability_for_public_channel;
 use rustok_pricing::PriceResolutionContext;
+use rustok_product::load_product_availability;
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use serde_json::Value;
 use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::{
+    dto::UnavailableReason,
     entities::{price_list, product, product_translation, product_variant, variant_translation},
     storefront_channel::{is_metadata_visible_for_public_channel, normalize_public_channel_slug},
     storefront_shipping::{
@@ -2992,11 +2994,23 @@ async fn resolve_storefront_line_item_input(
         .one(db)
         .await?
         .ok_or_else(|| async_graphql::Error::new("Product not found"))?;
-    if product_model.status != product::ProductStatus::Active
-        || product_model.published_at.is_none()
-        || !is_metadata_visible_for_public_channel(&product_model.metadata, public_channel_slug)
-    {
-        return Err(async_graphql::Error::new("Product not found"));
+    let availability =
+        load_product_availability(db, tenant_id, product_model.id, public_channel_slug).await?;
+    match availability.reason {
+        Some(
+            UnavailableReason::NotPublished
+            | UnavailableReason::Archived
+            | UnavailableReason::NotInChannel,
+        ) => return Err(async_graphql::Error::new("Product not found")),
+        // Stock is checked per variant below with the requested quantity.
+        Some(UnavailableReason::OutOfStock) | None => {}
+        Some(reason) => {
+            return Err(async_graphql::Error::new(format!(
+                "Product {} is not available for purchase: {}",
+                product_model.id,
+                reason.as_str()
+            )))
+        }
     }
 
     let product_translation_models = product_translation::Entity::find()
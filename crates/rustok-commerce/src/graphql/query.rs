@@ -123,12 +123,12 @@ impl CommerceQuery {
         let tenant = ctx.data::<TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
         let channel_service = rustok_channel::ChannelService::new(db.clone());
-        let (channels, _) = channel_service
+        let channels = channel_service
             .list_channels(tenant_id, 1, 250)
             .await
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
-        Ok(channels.into_iter().map(Into::into).collect())
+        Ok(channels.items.into_iter().map(Into::into).collect())
     }
 
     async fn storefront_active_price_lists(
@@ -410,7 +410,7 @@ impl CommerceQuery {
             });
         };
 
-        let (items, total) = OrderService::new(db.clone(), event_bus.clone())
+        let returns = OrderService::new(db.clone(), event_bus.clone())
             .list_returns(
                 tenant_id,
                 crate::dto::ListOrderReturnsInput {
@@ -424,11 +424,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlOrderReturnList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: returns.items.into_iter().map(Into::into).collect(),
+            total: returns.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: returns.has_more,
         })
     }
 
@@ -476,7 +476,7 @@ impl CommerceQuery {
             });
         };
 
-        let (items, total) = PaymentService::new(db.clone())
+        let refunds = PaymentService::new(db.clone())
             .list_refunds(
                 tenant_id,
                 crate::dto::ListRefundsInput {
@@ -491,11 +491,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlRefundList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: refunds.items.into_iter().map(Into::into).collect(),
+            total: refunds.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: refunds.has_more,
         })
     }
 
@@ -635,12 +635,13 @@ impl CommerceQuery {
         let filter = filter.unwrap_or(OrdersFilter {
             status: None,
             customer_id: None,
+            order_number: None,
             page: Some(1),
             per_page: Some(20),
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (orders, total) = OrderService::new(db.clone(), event_bus.clone())
+        let orders = OrderService::new(db.clone(), event_bus.clone())
             .list_orders_with_locale_fallback(
                 tenant_id,
                 crate::dto::ListOrdersInput {
@@ -648,6 +649,7 @@ impl CommerceQuery {
                     per_page,
                     status: filter.status,
                     customer_id: filter.customer_id,
+                    order_number: filter.order_number,
                 },
                 locale.as_str(),
                 Some(tenant.default_locale.as_str()),
@@ -656,11 +658,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlOrderList {
-            items: orders.into_iter().map(Into::into).collect(),
-            total,
+            total: orders.total.unwrap_or_default(),
+            has_next: orders.has_more,
+            items: orders.items.into_iter().map(Into::into).collect(),
             page,
             per_page,
-            has_next: page * per_page < total,
         })
     }
 
@@ -715,7 +717,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = OrderService::new(db.clone(), event_bus.clone())
+        let changes = OrderService::new(db.clone(), event_bus.clone())
             .list_order_changes(
                 tenant_id,
                 crate::dto::ListOrderChangesInput {
@@ -730,11 +732,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlOrderChangeList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: changes.items.into_iter().map(Into::into).collect(),
+            total: changes.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: changes.has_more,
         })
     }
 
@@ -788,7 +790,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = OrderService::new(db.clone(), event_bus.clone())
+        let returns = OrderService::new(db.clone(), event_bus.clone())
             .list_returns(
                 tenant_id,
                 crate::dto::ListOrderReturnsInput {
@@ -802,11 +804,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlOrderReturnList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: returns.items.into_iter().map(Into::into).collect(),
+            total: returns.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: returns.has_more,
         })
     }
 
@@ -862,7 +864,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = PaymentService::new(db.clone())
+        let collections = PaymentService::new(db.clone())
             .list_collections(
                 tenant_id,
                 crate::dto::ListPaymentCollectionsInput {
@@ -878,11 +880,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlPaymentCollectionList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: collections.items.into_iter().map(Into::into).collect(),
+            total: collections.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: collections.has_more,
         })
     }
 
@@ -935,7 +937,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = PaymentService::new(db.clone())
+        let refunds = PaymentService::new(db.clone())
             .list_refunds(
                 tenant_id,
                 crate::dto::ListRefundsInput {
@@ -950,11 +952,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlRefundList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: refunds.items.into_iter().map(Into::into).collect(),
+            total: refunds.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: refunds.has_more,
         })
     }
 
@@ -1117,7 +1119,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = ShippingProfileService::new(db.clone())
+        let profiles = ShippingProfileService::new(db.clone())
             .list_shipping_profiles(
                 tenant_id,
                 crate::dto::ListShippingProfilesInput {
@@ -1134,11 +1136,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlShippingProfileList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: profiles.items.into_iter().map(Into::into).collect(),
+            total: profiles.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: profiles.has_more,
         })
     }
 
@@ -1193,7 +1195,7 @@ impl CommerceQuery {
         });
         let page = filter.page.unwrap_or(1).max(1);
         let per_page = filter.per_page.unwrap_or(20).clamp(1, 100);
-        let (items, total) = FulfillmentService::new(db.clone())
+        let fulfillments = FulfillmentService::new(db.clone())
             .list_fulfillments(
                 tenant_id,
                 crate::dto::ListFulfillmentsInput {
@@ -1208,11 +1210,11 @@ impl CommerceQuery {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
 
         Ok(GqlFulfillmentList {
-            items: items.into_iter().map(Into::into).collect(),
-            total,
+            items: fulfillments.items.into_iter().map(Into::into).collect(),
+            total: fulfillments.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page * per_page < total,
+            has_next: fulfillments.has_more,
         })
     }
 
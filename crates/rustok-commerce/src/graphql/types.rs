@@ -505,6 +505,7 @@ pub struct GqlStoreCart {
 pub struct GqlOrder {
     pub id: Uuid,
     pub tenant_id: Uuid,
+    pub order_number: Option<String>,
     pub channel_id: Option<Uuid>,
     pub channel_slug: Option<String>,
     pub customer_id: Option<Uuid>,
@@ -889,6 +890,7 @@ pub struct StorefrontContextFilter {
 pub struct OrdersFilter {
     pub status: Option<String>,
     pub customer_id: Option<Uuid>,
+    pub order_number: Option<String>,
     pub page: Option<u64>,
     pub per_page: Option<u64>,
 }
@@ -1915,6 +1917,7 @@ impl From<dto::OrderResponse> for GqlOrder {
         Self {
             id: order.id,
             tenant_id: order.tenant_id,
+            order_number: order.order_number,
             channel_id: order.channel_id,
             channel_slug: order.channel_slug,
             customer_id: order.customer_id,
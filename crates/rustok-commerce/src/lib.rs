@@ -26,11 +26,13 @@ pub use error::{CommerceError, CommerceResult};
 pub use graphql::{CommerceMutation, CommerceQuery};
 pub use services::{
     CartService, CatalogService, CheckoutError, CheckoutResult, CheckoutService,
-    CreateReturnDecisionInput, CustomerService, FulfillmentService, InventoryService, OrderService,
-    PaymentService, PostOrderOrchestrationError, PostOrderOrchestrationService, PricingService,
-    RegionService, ReturnClaimDecisionInput, ReturnDecisionInput, ReturnDecisionResponse,
-    ReturnExchangeDecisionInput, ReturnRefundDecisionInput, ShippingProfileService,
-    StoreContextError, StoreContextResult, StoreContextService,
+    CreateReturnDecisionInput, CustomerDataBundle, CustomerDataError, CustomerDataResult,
+    CustomerDataService, CustomerService, ErasureMode, ErasureReport, FulfillmentService,
+    InventoryService, OrderService, PaymentService, PostOrderOrchestrationError,
+    PostOrderOrchestrationService, PricingService, RegionService, ReturnClaimDecisionInput,
+    ReturnDecisionInput, ReturnDecisionResponse, ReturnExchangeDecisionInput,
+    ReturnRefundDecisionInput, ShippingProfileService, StoreContextError, StoreContextResult,
+    StoreContextService,
 };
 pub(crate) use services::{FulfillmentOrchestrationError, FulfillmentOrchestrationService};
 pub use state_machine::{
@@ -6,21 +6,24 @@ use validator::Validate;
 use rustok_cart::error::CartError;
 use rustok_core::{normalize_locale_tag, PLATFORM_FALLBACK_LOCALE};
 use rustok_fulfillment::error::FulfillmentError;
-use rustok_inventory::check_variant_availability_for_public_channel;
+use rustok_inventory::{check_variant_availability_for_public_channel, OrderInventoryLine};
 use rustok_order::error::OrderError;
 use rustok_outbox::TransactionalEventBus;
 use rustok_payment::error::PaymentError;
+use rustok_product::load_product_availability;
 use sea_orm::{
     ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, Statement,
+    TransactionTrait,
 };
 use std::collections::BTreeSet;
 
 use crate::dto::{
     AuthorizePaymentInput, CancelPaymentInput, CompleteCheckoutInput, CompleteCheckoutResponse,
     CreateFulfillmentInput, CreateOrderAdjustmentInput, CreateOrderInput, CreateOrderLineItemInput,
-    CreateOrderTaxLineInput, CreatePaymentCollectionInput, ResolveStoreContextInput,
+    CreateOrderTaxLineInput, CreatePaymentCollectionInput, OrderResponse, ResolveStoreContextInput,
+    UnavailableReason,
 };
-use crate::entities::{product, product_variant};
+use crate::entities::product_variant;
 use crate::storefront_channel::{
     is_metadata_visible_for_public_channel, normalize_public_channel_slug,
 };
@@ -28,8 +31,8 @@ use crate::storefront_shipping::{
     is_shipping_option_compatible_with_profiles, load_current_shipping_profile_slug_for_line_item,
 };
 use crate::{
-    CartService, FulfillmentService, OrderService, PaymentService, StoreContextService,
-    UpdateCartContextInput,
+    CartService, CommerceError, FulfillmentService, InventoryService, OrderService, PaymentService,
+    StoreContextService, UpdateCartContextInput,
 };
 
 const MANUAL_PROVIDER_ID: &str = "manual";
@@ -58,6 +61,7 @@ pub struct CheckoutService {
     db: DatabaseConnection,
     cart_service: CartService,
     order_service: OrderService,
+    inventory_service: InventoryService,
     payment_service: PaymentService,
     fulfillment_service: FulfillmentService,
     context_service: StoreContextService,
@@ -68,7 +72,8 @@ impl CheckoutService {
         Self {
             db: db.clone(),
             cart_service: CartService::new(db.clone()),
-            order_service: OrderService::new(db.clone(), event_bus),
+            order_service: OrderService::new(db.clone(), event_bus.clone()),
+            inventory_service: InventoryService::new(db.clone(), event_bus),
             payment_service: PaymentService::new(db.clone()),
             fulfillment_service: FulfillmentService::new(db.clone()),
             context_service: StoreContextService::new(db),
@@ -431,17 +436,37 @@ impl CheckoutService {
                 .clone()
                 .unwrap_or_else(|| MANUAL_PROVIDER_ID.to_string());
 
-            let order = self
-                .order_service
-                .mark_paid(
+            let order = match self
+                .mark_order_paid(
                     tenant_id,
                     actor_id,
-                    order.id,
+                    &order,
                     payment_reference,
                     payment_method,
+                    context.locale.as_str(),
+                    context.default_locale.as_str(),
                 )
                 .await
-                .map_err(stage_error("mark_order_paid"))?;
+            {
+                Ok(order) => order,
+                Err(
+                    error @ CheckoutError::StageFailure {
+                        stage: "commit_inventory",
+                        ..
+                    },
+                ) => {
+                    self.compensate_payment_and_order(
+                        tenant_id,
+                        actor_id,
+                        captured_payment.id,
+                        order.id,
+                        "inventory_commit_failed",
+                    )
+                    .await;
+                    return Err(error);
+                }
+                Err(error) => return Err(error),
+            };
 
             let cart = self
                 .cart_service
@@ -492,33 +517,38 @@ impl CheckoutService {
             };
 
             let product_id = line_item.product_id.unwrap_or(variant.product_id);
-            let Some(product) = product::Entity::find_by_id(product_id)
-                .filter(product::Column::TenantId.eq(tenant_id))
-                .one(&self.db)
-                .await
-                .map_err(stage_error("load_product"))?
-            else {
-                return Err(CheckoutError::Validation(format!(
-                    "Product {} is no longer available for checkout",
-                    product_id
-                )));
+            let availability = match load_product_availability(
+                &self.db,
+                tenant_id,
+                product_id,
+                public_channel_slug.as_deref(),
+            )
+            .await
+            {
+                Ok(availability) => availability,
+                Err(CommerceError::ProductNotFound(_)) => {
+                    return Err(CheckoutError::Validation(format!(
+                        "Product {} is no longer available for checkout",
+                        product_id
+                    )));
+                }
+                Err(error) => return Err(stage_error("load_product")(error)),
             };
-            if product.status != product::ProductStatus::Active
-                || product.published_at.is_none()
-                || !is_metadata_visible_for_public_channel(
-                    &product.metadata,
-                    public_channel_slug.as_deref(),
-                )
+            // Stock is checked per variant below with the requested quantity.
+            if let Some(reason) = availability
+                .reason
+                .filter(|reason| *reason != UnavailableReason::OutOfStock)
             {
                 return Err(CheckoutError::Validation(format!(
-                    "Product {} is not available for the cart channel",
-                    product.id
+                    "Product {} is not available for the cart channel: {}",
+                    product_id,
+                    reason.as_str()
                 )));
             }
             let current_shipping_profile_slug = load_current_shipping_profile_slug_for_line_item(
                 &self.db,
                 tenant_id,
-                Some(product.id),
+                Some(product_id),
                 Some(variant.id),
             )
             .await
@@ -680,7 +710,7 @@ impl CheckoutService {
     async fn create_fulfillments_for_delivery_groups(
         &self,
         tenant_id: Uuid,
-        order: &crate::dto::OrderResponse,
+        order: &OrderResponse,
         customer_id: Option<Uuid>,
         cart: &rustok_cart::dto::CartResponse,
         metadata: serde_json::Value,
@@ -723,6 +753,61 @@ impl CheckoutService {
         Ok(fulfillments)
     }
 
+    /// Take the order lines out of stock and mark the order paid in one
+    /// transaction. Inventory rows are locked first, sorted by product id
+    /// (see `InventoryService::commit_order_inventory_in_tx`), and the order
+    /// row last, so concurrent checkouts sharing products cannot deadlock.
+    #[allow(clippy::too_many_arguments)]
+    async fn mark_order_paid(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        order: &OrderResponse,
+        payment_reference: String,
+        payment_method: String,
+        locale: &str,
+        default_locale: &str,
+    ) -> CheckoutResult<OrderResponse> {
+        let lines = order
+            .line_items
+            .iter()
+            .filter_map(|item| {
+                Some(OrderInventoryLine {
+                    product_id: item.product_id?,
+                    variant_id: item.variant_id?,
+                    quantity: item.quantity,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(stage_error("commit_inventory"))?;
+        self.inventory_service
+            .commit_order_inventory_in_tx(&txn, tenant_id, actor_id, &lines)
+            .await
+            .map_err(stage_error("commit_inventory"))?;
+        self.order_service
+            .mark_paid_in_tx(
+                &txn,
+                tenant_id,
+                actor_id,
+                order.id,
+                payment_reference,
+                payment_method,
+            )
+            .await
+            .map_err(stage_error("mark_order_paid"))?;
+        txn.commit().await.map_err(stage_error("mark_order_paid"))?;
+
+        self.order_service
+            .get_order_with_locale_fallback(tenant_id, order.id, locale, Some(default_locale))
+            .await
+            .map_err(stage_error("mark_order_paid"))
+    }
+
     async fn compensate_order(
         &self,
         tenant_id: Uuid,
@@ -887,7 +972,7 @@ fn cart_line_item_id_from_order_line_item(
 }
 
 fn fulfillment_items_for_delivery_group(
-    order: &crate::dto::OrderResponse,
+    order: &OrderResponse,
     delivery_group: &rustok_cart::dto::CartDeliveryGroupResponse,
 ) -> CheckoutResult<Vec<crate::dto::CreateFulfillmentItemInput>> {
     let mut items = Vec::with_capacity(delivery_group.line_item_ids.len());
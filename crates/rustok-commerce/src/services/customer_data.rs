@@ -0,0 +1,476 @@
+use std::collections::BTreeSet;
+
+use chrono::Utc;
+use rustok_cart::entities::cart;
+use rustok_core::DomainEvent;
+use rustok_customer::entities::customer;
+use rustok_fulfillment::entities::fulfillment;
+use rustok_order::entities::{
+    order, order_adjustment, order_change, order_line_item, order_line_item_translation,
+    order_return, order_return_item, order_tax_line,
+};
+use rustok_outbox::TransactionalEventBus;
+use rustok_payment::entities::payment_collection;
+use sea_orm::prelude::{DateTimeWithTimeZone, Expr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum CustomerDataError {
+    #[error("customer {0} not found")]
+    CustomerNotFound(Uuid),
+    #[error("event publish error: {0}")]
+    Event(#[from] rustok_core::Error),
+    #[error(transparent)]
+    Database(#[from] sea_orm::DbErr),
+}
+
+pub type CustomerDataResult<T> = Result<T, CustomerDataError>;
+
+/// Everything the commerce tables hold about one customer, for data subject
+/// access requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDataBundle {
+    pub exported_at: String,
+    pub customer: customer::Model,
+    pub orders: Vec<order::Model>,
+    pub order_line_items: Vec<order_line_item::Model>,
+    pub carts: Vec<cart::Model>,
+    pub payment_collections: Vec<payment_collection::Model>,
+    pub fulfillments: Vec<fulfillment::Model>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErasureMode {
+    /// Scrub PII in place; orders and payments stay for accounting.
+    Anonymize,
+    /// Remove the customer and every commerce record tied to them. Orders
+    /// that return items still reference are anonymized and kept instead.
+    Delete,
+}
+
+impl ErasureMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Anonymize => "anonymize",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// Row counts touched by [`CustomerDataService::erase_customer_data`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureReport {
+    pub orders: u64,
+    /// Orders kept in `Delete` mode because return items reference them.
+    pub retained_orders: u64,
+    /// Returns and return items whose free text and metadata were scrubbed.
+    pub returns: u64,
+    pub carts: u64,
+    pub payment_collections: u64,
+    pub fulfillments: u64,
+}
+
+/// Cross-module export and erasure of a single customer's commerce data.
+pub struct CustomerDataService {
+    db: DatabaseConnection,
+    event_bus: TransactionalEventBus,
+}
+
+impl CustomerDataService {
+    pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
+        Self { db, event_bus }
+    }
+
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn export_customer_data(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+    ) -> CustomerDataResult<CustomerDataBundle> {
+        let customer = find_customer(&self.db, tenant_id, customer_id).await?;
+
+        let orders = order::Entity::find()
+            .filter(order::Column::TenantId.eq(tenant_id))
+            .filter(order::Column::CustomerId.eq(customer_id))
+            .order_by_asc(order::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        let order_line_items = if orders.is_empty() {
+            Vec::new()
+        } else {
+            order_line_item::Entity::find()
+                .filter(order_line_item::Column::OrderId.is_in(orders.iter().map(|o| o.id)))
+                .all(&self.db)
+                .await?
+        };
+        let carts = cart::Entity::find()
+            .filter(cart::Column::TenantId.eq(tenant_id))
+            .filter(cart::Column::CustomerId.eq(customer_id))
+            .order_by_asc(cart::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        let payment_collections = payment_collection::Entity::find()
+            .filter(payment_collection::Column::TenantId.eq(tenant_id))
+            .filter(payment_collection::Column::CustomerId.eq(customer_id))
+            .order_by_asc(payment_collection::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        let fulfillments = fulfillment::Entity::find()
+            .filter(fulfillment::Column::TenantId.eq(tenant_id))
+            .filter(fulfillment::Column::CustomerId.eq(customer_id))
+            .order_by_asc(fulfillment::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        Ok(CustomerDataBundle {
+            exported_at: Utc::now().to_rfc3339(),
+            customer,
+            orders,
+            order_line_items,
+            carts,
+            payment_collections,
+            fulfillments,
+        })
+    }
+
+    /// Erase a customer's PII in one transaction and record a
+    /// `customer.erased` event. The event carries no PII.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id, mode = mode.as_str()))]
+    pub async fn erase_customer_data(
+        &self,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        customer_id: Uuid,
+        mode: ErasureMode,
+    ) -> CustomerDataResult<ErasureReport> {
+        let txn = self.db.begin().await?;
+        let customer = find_customer(&txn, tenant_id, customer_id).await?;
+
+        let report = match mode {
+            ErasureMode::Anonymize => anonymize(&txn, tenant_id, customer).await?,
+            ErasureMode::Delete => delete(&txn, tenant_id, customer_id).await?,
+        };
+
+        self.event_bus
+            .publish_in_tx(
+                &txn,
+                tenant_id,
+                Some(actor_id),
+                DomainEvent::CustomerErased {
+                    customer_id,
+                    mode: mode.as_str().to_string(),
+                },
+            )
+            .await?;
+        txn.commit().await?;
+
+        info!(?report, "Customer data erased");
+        Ok(report)
+    }
+}
+
+async fn find_customer<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    customer_id: Uuid,
+) -> CustomerDataResult<customer::Model> {
+    customer::Entity::find_by_id(customer_id)
+        .filter(customer::Column::TenantId.eq(tenant_id))
+        .one(conn)
+        .await?
+        .ok_or(CustomerDataError::CustomerNotFound(customer_id))
+}
+
+/// Placeholder email that keeps the per-tenant unique index satisfied.
+fn erased_email(customer_id: Uuid) -> String {
+    format!("erased+{customer_id}@invalid")
+}
+
+async fn anonymize<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    customer: customer::Model,
+) -> CustomerDataResult<ErasureReport> {
+    let customer_id = customer.id;
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    let empty = serde_json::json!({});
+
+    let mut active: customer::ActiveModel = customer.into();
+    active.user_id = Set(None);
+    active.email = Set(erased_email(customer_id));
+    active.first_name = Set(None);
+    active.last_name = Set(None);
+    active.phone = Set(None);
+    active.metadata = Set(empty.clone());
+    active.updated_at = Set(now);
+    active.update(conn).await?;
+
+    let order_ids = customer_order_ids(conn, tenant_id, customer_id).await?;
+    let orders = scrub_orders(conn, tenant_id, &order_ids, false, now).await?;
+    let returns = scrub_returns(conn, tenant_id, &order_ids, now).await?;
+    let carts = cart::Entity::update_many()
+        .col_expr(cart::Column::Email, Expr::value(Option::<String>::None))
+        .col_expr(cart::Column::Metadata, Expr::value(empty.clone()))
+        .col_expr(cart::Column::UpdatedAt, Expr::value(now))
+        .filter(cart::Column::TenantId.eq(tenant_id))
+        .filter(cart::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    let payment_collections = payment_collection::Entity::update_many()
+        .col_expr(
+            payment_collection::Column::Metadata,
+            Expr::value(empty.clone()),
+        )
+        .col_expr(payment_collection::Column::UpdatedAt, Expr::value(now))
+        .filter(payment_collection::Column::TenantId.eq(tenant_id))
+        .filter(payment_collection::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    let fulfillments = fulfillment::Entity::update_many()
+        .col_expr(fulfillment::Column::Metadata, Expr::value(empty))
+        .col_expr(
+            fulfillment::Column::DeliveredNote,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(fulfillment::Column::UpdatedAt, Expr::value(now))
+        .filter(fulfillment::Column::TenantId.eq(tenant_id))
+        .filter(fulfillment::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+
+    Ok(ErasureReport {
+        orders,
+        retained_orders: 0,
+        returns,
+        carts,
+        payment_collections,
+        fulfillments,
+    })
+}
+
+async fn customer_order_ids<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    customer_id: Uuid,
+) -> CustomerDataResult<Vec<Uuid>> {
+    Ok(order::Entity::find()
+        .filter(order::Column::TenantId.eq(tenant_id))
+        .filter(order::Column::CustomerId.eq(customer_id))
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|order| order.id)
+        .collect())
+}
+
+/// Clear PII on `order_ids`; `detach` also drops the customer link for
+/// orders that outlive a deleted customer.
+async fn scrub_orders<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    order_ids: &[Uuid],
+    detach: bool,
+    now: DateTimeWithTimeZone,
+) -> CustomerDataResult<u64> {
+    if order_ids.is_empty() {
+        return Ok(0);
+    }
+    let mut update = order::Entity::update_many()
+        .col_expr(order::Column::Metadata, Expr::value(serde_json::json!({})))
+        .col_expr(
+            order::Column::DeliveredSignature,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(order::Column::UpdatedAt, Expr::value(now));
+    if detach {
+        update = update.col_expr(order::Column::CustomerId, Expr::value(Option::<Uuid>::None));
+    }
+    Ok(update
+        .filter(order::Column::TenantId.eq(tenant_id))
+        .filter(order::Column::Id.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?
+        .rows_affected)
+}
+
+/// Clear the free-text reason, note and metadata of the returns on
+/// `order_ids` and of their items; quantities and statuses stay for
+/// accounting.
+async fn scrub_returns<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    order_ids: &[Uuid],
+    now: DateTimeWithTimeZone,
+) -> CustomerDataResult<u64> {
+    if order_ids.is_empty() {
+        return Ok(0);
+    }
+    let returns = order_return::Entity::update_many()
+        .col_expr(
+            order_return::Column::Reason,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(
+            order_return::Column::Note,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(
+            order_return::Column::Metadata,
+            Expr::value(serde_json::json!({})),
+        )
+        .col_expr(order_return::Column::UpdatedAt, Expr::value(now))
+        .filter(order_return::Column::TenantId.eq(tenant_id))
+        .filter(order_return::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    let return_items = order_return_item::Entity::update_many()
+        .col_expr(
+            order_return_item::Column::Reason,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(
+            order_return_item::Column::Note,
+            Expr::value(Option::<String>::None),
+        )
+        .col_expr(
+            order_return_item::Column::Metadata,
+            Expr::value(serde_json::json!({})),
+        )
+        .col_expr(order_return_item::Column::UpdatedAt, Expr::value(now))
+        .filter(order_return_item::Column::TenantId.eq(tenant_id))
+        .filter(order_return_item::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    Ok(returns + return_items)
+}
+
+/// Hard-delete `order_ids` with their line items and the rows hanging off
+/// them, child tables first so no foreign key depends on a cascade.
+async fn delete_orders<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    order_ids: &[Uuid],
+) -> CustomerDataResult<u64> {
+    if order_ids.is_empty() {
+        return Ok(0);
+    }
+    let line_item_ids: Vec<Uuid> = order_line_item::Entity::find()
+        .filter(order_line_item::Column::OrderId.is_in(order_ids.iter().copied()))
+        .all(conn)
+        .await?
+        .into_iter()
+        .map(|item| item.id)
+        .collect();
+
+    if !line_item_ids.is_empty() {
+        order_line_item_translation::Entity::delete_many()
+            .filter(order_line_item_translation::Column::OrderLineItemId.is_in(line_item_ids))
+            .exec(conn)
+            .await?;
+    }
+    order_tax_line::Entity::delete_many()
+        .filter(order_tax_line::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?;
+    order_adjustment::Entity::delete_many()
+        .filter(order_adjustment::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?;
+    order_change::Entity::delete_many()
+        .filter(order_change::Column::TenantId.eq(tenant_id))
+        .filter(order_change::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?;
+    order_return::Entity::delete_many()
+        .filter(order_return::Column::TenantId.eq(tenant_id))
+        .filter(order_return::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?;
+    order_line_item::Entity::delete_many()
+        .filter(order_line_item::Column::OrderId.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?;
+
+    Ok(order::Entity::delete_many()
+        .filter(order::Column::TenantId.eq(tenant_id))
+        .filter(order::Column::Id.is_in(order_ids.iter().copied()))
+        .exec(conn)
+        .await?
+        .rows_affected)
+}
+
+async fn delete<C: ConnectionTrait>(
+    conn: &C,
+    tenant_id: Uuid,
+    customer_id: Uuid,
+) -> CustomerDataResult<ErasureReport> {
+    let fulfillments = fulfillment::Entity::delete_many()
+        .filter(fulfillment::Column::TenantId.eq(tenant_id))
+        .filter(fulfillment::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    let payment_collections = payment_collection::Entity::delete_many()
+        .filter(payment_collection::Column::TenantId.eq(tenant_id))
+        .filter(payment_collection::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+
+    // Return items reference line items with `ON DELETE RESTRICT`, so orders
+    // with returns cannot be hard-deleted: keep them, detached and scrubbed.
+    let order_ids = customer_order_ids(conn, tenant_id, customer_id).await?;
+    let returned_order_ids: BTreeSet<Uuid> = if order_ids.is_empty() {
+        BTreeSet::new()
+    } else {
+        order_return_item::Entity::find()
+            .filter(order_return_item::Column::TenantId.eq(tenant_id))
+            .filter(order_return_item::Column::OrderId.is_in(order_ids.iter().copied()))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|item| item.order_id)
+            .collect()
+    };
+    let (retained, deletable): (Vec<Uuid>, Vec<Uuid>) = order_ids
+        .into_iter()
+        .partition(|order_id| returned_order_ids.contains(order_id));
+
+    let now: DateTimeWithTimeZone = Utc::now().into();
+    let retained_orders = scrub_orders(conn, tenant_id, &retained, true, now).await?;
+    let returns = scrub_returns(conn, tenant_id, &retained, now).await?;
+    let orders = delete_orders(conn, tenant_id, &deletable).await?;
+    let carts = cart::Entity::delete_many()
+        .filter(cart::Column::TenantId.eq(tenant_id))
+        .filter(cart::Column::CustomerId.eq(customer_id))
+        .exec(conn)
+        .await?
+        .rows_affected;
+    customer::Entity::delete_many()
+        .filter(customer::Column::Id.eq(customer_id))
+        .filter(customer::Column::TenantId.eq(tenant_id))
+        .exec(conn)
+        .await?;
+
+    Ok(ErasureReport {
+        orders,
+        retained_orders,
+        returns,
+        carts,
+        payment_collections,
+        fulfillments,
+    })
+}
@@ -1,5 +1,6 @@
 pub mod checkout;
 pub mod context;
+mod customer_data;
 mod fulfillment_orchestration;
 mod post_order;
 mod shipping_profile;
@@ -16,6 +17,10 @@ pub use rustok_region::services::region;
 
 pub use checkout::{CheckoutError, CheckoutResult, CheckoutService};
 pub use context::{StoreContextError, StoreContextResult, StoreContextService};
+pub use customer_data::{
+    CustomerDataBundle, CustomerDataError, CustomerDataResult, CustomerDataService, ErasureMode,
+    ErasureReport,
+};
 pub(crate) use fulfillment_orchestration::{
     FulfillmentOrchestrationError, FulfillmentOrchestrationService,
 };
@@ -27,7 +32,7 @@ pub use post_order::{
 pub use rustok_cart::CartService;
 pub use rustok_customer::CustomerService;
 pub use rustok_fulfillment::FulfillmentService;
-pub use rustok_inventory::InventoryService;
+pub use rustok_inventory::{InventoryService, OrderInventoryLine};
 pub use rustok_order::OrderService;
 pub use rustok_payment::PaymentService;
 pub use rustok_pricing::{
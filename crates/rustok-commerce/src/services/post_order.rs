@@ -230,7 +230,7 @@ impl PostOrderOrchestrationService {
         let collection_id = match input.payment_collection_id {
             Some(id) => id,
             None => {
-                let (collections, _) = payment_service
+                let collections = payment_service
                     .list_collections(
                         tenant_id,
                         ListPaymentCollectionsInput {
@@ -244,6 +244,7 @@ impl PostOrderOrchestrationService {
                     )
                     .await?;
                 collections
+                    .items
                     .into_iter()
                     .next()
                     .map(|collection| collection.id)
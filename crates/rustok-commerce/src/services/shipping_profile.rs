@@ -2,15 +2,15 @@ use chrono::Utc;
 use sea_orm::sea_query::Expr;
 use sea_orm::Condition;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, Value,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, Value,
 };
 use std::collections::{HashMap, HashSet};
 use tracing::instrument;
 use uuid::Uuid;
 use validator::Validate;
 
-use rustok_core::{generate_id, normalize_locale_tag};
+use rustok_core::{fetch_with_total, generate_id, normalize_locale_tag, Page};
 
 use crate::{
     dto::{
@@ -71,7 +71,7 @@ impl ShippingProfileService {
         input: ListShippingProfilesInput,
         requested_locale: Option<&str>,
         tenant_default_locale: Option<&str>,
-    ) -> CommerceResult<(Vec<ShippingProfileResponse>, u64)> {
+    ) -> CommerceResult<Page<ShippingProfileResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let offset = (page.saturating_sub(1)) * per_page;
@@ -97,13 +97,13 @@ impl ShippingProfileService {
             );
         }
 
-        let total = query.clone().count(&self.db).await?;
-        let rows = query
-            .order_by_asc(shipping_profile::Column::CreatedAt)
-            .offset(offset)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let (rows, total) = fetch_with_total(
+            &self.db,
+            query.order_by_asc(shipping_profile::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
         let items = load_profiles_with_translations(
             &self.db,
@@ -113,7 +113,7 @@ impl ShippingProfileService {
         )
         .await?;
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset))
     }
 
     pub async fn get_shipping_profile(
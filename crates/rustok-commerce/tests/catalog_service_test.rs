@@ -313,6 +313,33 @@ async fn test_update_product_success() {
     assert_eq!(updated.status, ProductStatus::Active);
 }
 
+#[tokio::test]
+async fn test_product_records_created_and_updated_by() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let creator_id = Uuid::new_v4();
+    let editor_id = Uuid::new_v4();
+
+    let product = service
+        .create_product(tenant_id, creator_id, create_test_product_input())
+        .await
+        .unwrap();
+    assert_eq!(product.created_by, Some(creator_id));
+    assert_eq!(product.updated_by, Some(creator_id));
+
+    let published = service
+        .publish_product(tenant_id, editor_id, product.id)
+        .await
+        .unwrap();
+    assert_eq!(published.created_by, Some(creator_id));
+    assert_eq!(published.updated_by, Some(editor_id));
+
+    let mut storefront = published.clone();
+    storefront.strip_audit_actors();
+    assert_eq!(storefront.created_by, None);
+    assert_eq!(storefront.updated_by, None);
+}
+
 #[tokio::test]
 async fn test_delete_product_success() {
     let (_db, service) = setup().await;
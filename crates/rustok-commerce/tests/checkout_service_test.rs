@@ -1323,6 +1323,118 @@ async fn complete_checkout_rejects_line_item_hidden_for_cart_channel() {
     }
 }
 
+#[tokio::test]
+async fn complete_checkout_rejects_line_item_after_sale_window() {
+    let (db, cart_service, checkout, fulfillment) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let channel_id = Uuid::new_v4();
+    seed_tenant_context(&db, tenant_id).await;
+    seed_channel_binding(&db, tenant_id, channel_id, "web-store").await;
+
+    let catalog = CatalogService::new(db.clone(), mock_transactional_event_bus());
+    let mut product_input = create_product_input();
+    product_input.metadata = serde_json::json!({
+        "sale_window": {
+            "ends_at": (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339()
+        }
+    });
+    let created = catalog
+        .create_product(tenant_id, actor_id, product_input)
+        .await
+        .expect("product should be created");
+    let published = catalog
+        .publish_product(tenant_id, actor_id, created.id)
+        .await
+        .expect("product should be published");
+    let variant = published
+        .variants
+        .first()
+        .expect("published product should include variant");
+
+    let shipping_option = fulfillment
+        .create_shipping_option(
+            tenant_id,
+            CreateShippingOptionInput {
+                translations: vec![ShippingOptionTranslationInput {
+                    locale: "en".to_string(),
+                    name: "Visible Shipping".to_string(),
+                }],
+                currency_code: "usd".to_string(),
+                amount: Decimal::from_str("9.99").expect("valid decimal"),
+                provider_id: None,
+                allowed_shipping_profile_slugs: None,
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+
+    let cart = cart_service
+        .create_cart_with_channel(
+            tenant_id,
+            CreateCartInput {
+                customer_id: Some(Uuid::new_v4()),
+                email: Some("buyer@example.com".to_string()),
+                region_id: None,
+                country_code: None,
+                locale_code: Some("de".to_string()),
+                selected_shipping_option_id: Some(shipping_option.id),
+                currency_code: "usd".to_string(),
+                metadata: serde_json::json!({ "source": "checkout-sale-ended" }),
+            },
+            Some(channel_id),
+            Some("web-store".to_string()),
+        )
+        .await
+        .unwrap();
+    let cart = cart_service
+        .add_line_item(
+            tenant_id,
+            cart.id,
+            AddCartLineItemInput {
+                product_id: Some(published.id),
+                variant_id: Some(variant.id),
+                shipping_profile_slug: None,
+                sku: variant.sku.clone(),
+                title: variant.title.clone(),
+                quantity: 1,
+                unit_price: Decimal::from_str("25.00").expect("valid decimal"),
+                metadata: serde_json::json!({ "slot": 1 }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let error = checkout
+        .complete_checkout(
+            tenant_id,
+            actor_id,
+            CompleteCheckoutInput {
+                cart_id: cart.id,
+                shipping_option_id: None,
+                shipping_selections: None,
+                region_id: None,
+                country_code: None,
+                locale: None,
+                create_fulfillment: true,
+                metadata: serde_json::json!({ "flow": "checkout-sale-ended" }),
+            },
+        )
+        .await
+        .expect_err("product past its sale window must fail checkout");
+
+    match error {
+        CheckoutError::Validation(message) => {
+            assert!(
+                message.contains("sale_ended"),
+                "unexpected validation message: {message}"
+            );
+        }
+        other => panic!("expected validation error, got {other:?}"),
+    }
+}
+
 #[tokio::test]
 async fn complete_checkout_rejects_line_item_without_channel_visible_inventory() {
     let (db, cart_service, checkout, fulfillment) = setup().await;
@@ -0,0 +1,180 @@
+use rust_decimal::Decimal;
+use rustok_commerce::{CustomerDataService, CustomerService, ErasureMode, OrderService};
+use rustok_customer::dto::CreateCustomerInput;
+use rustok_order::dto::{
+    CreateOrderInput, CreateOrderLineItemInput, CreateOrderReturnInput, CreateOrderReturnItemInput,
+};
+use rustok_test_utils::mock_transactional_event_bus;
+use sea_orm::{Database, DatabaseConnection};
+use uuid::Uuid;
+
+mod support;
+
+async fn seed_customer_with_order(db: &DatabaseConnection, tenant_id: Uuid) -> (Uuid, Uuid) {
+    let customer = CustomerService::new(db.clone())
+        .create_customer(
+            tenant_id,
+            CreateCustomerInput {
+                user_id: None,
+                email: "jane@example.com".to_string(),
+                first_name: Some("Jane".to_string()),
+                last_name: Some("Doe".to_string()),
+                phone: Some("+15550100".to_string()),
+                locale: Some("en".to_string()),
+                metadata: serde_json::json!({"address": "1 Main St"}),
+            },
+        )
+        .await
+        .unwrap();
+
+    let order = OrderService::new(db.clone(), mock_transactional_event_bus())
+        .create_order(
+            tenant_id,
+            Uuid::new_v4(),
+            CreateOrderInput {
+                customer_id: Some(customer.id),
+                currency_code: "usd".to_string(),
+                shipping_total: Decimal::ZERO,
+                line_items: vec![CreateOrderLineItemInput {
+                    product_id: None,
+                    variant_id: None,
+                    shipping_profile_slug: "default".to_string(),
+                    seller_id: None,
+                    sku: Some("GDPR-SKU-1".to_string()),
+                    title: "Export Candidate".to_string(),
+                    quantity: 1,
+                    unit_price: Decimal::new(1000, 2),
+                    metadata: serde_json::json!({}),
+                }],
+                adjustments: Vec::new(),
+                tax_lines: Vec::new(),
+                metadata: serde_json::json!({"shipping_address": "1 Main St"}),
+            },
+        )
+        .await
+        .unwrap();
+
+    (customer.id, order.id)
+}
+
+#[tokio::test]
+async fn export_collects_customer_orders_and_line_items() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    support::ensure_commerce_schema(&db).await;
+    let tenant_id = Uuid::new_v4();
+    let (customer_id, order_id) = seed_customer_with_order(&db, tenant_id).await;
+
+    let bundle = CustomerDataService::new(db.clone(), mock_transactional_event_bus())
+        .export_customer_data(tenant_id, customer_id)
+        .await
+        .unwrap();
+
+    assert_eq!(bundle.customer.email, "jane@example.com");
+    assert_eq!(bundle.orders.len(), 1);
+    assert_eq!(bundle.orders[0].id, order_id);
+    assert_eq!(bundle.order_line_items.len(), 1);
+    assert!(bundle.carts.is_empty());
+}
+
+#[tokio::test]
+async fn anonymize_scrubs_pii_but_keeps_orders() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    support::ensure_commerce_schema(&db).await;
+    let tenant_id = Uuid::new_v4();
+    let (customer_id, order_id) = seed_customer_with_order(&db, tenant_id).await;
+    let service = CustomerDataService::new(db.clone(), mock_transactional_event_bus());
+
+    let report = service
+        .erase_customer_data(
+            tenant_id,
+            Uuid::new_v4(),
+            customer_id,
+            ErasureMode::Anonymize,
+        )
+        .await
+        .unwrap();
+    assert_eq!(report.orders, 1);
+
+    let bundle = service
+        .export_customer_data(tenant_id, customer_id)
+        .await
+        .unwrap();
+    assert_ne!(bundle.customer.email, "jane@example.com");
+    assert_eq!(bundle.customer.first_name, None);
+    assert_eq!(bundle.customer.phone, None);
+    assert_eq!(bundle.customer.metadata, serde_json::json!({}));
+    assert_eq!(bundle.orders[0].id, order_id);
+    assert_eq!(bundle.orders[0].metadata, serde_json::json!({}));
+}
+
+#[tokio::test]
+async fn delete_removes_customer_and_orders() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    support::ensure_commerce_schema(&db).await;
+    let tenant_id = Uuid::new_v4();
+    let (customer_id, _) = seed_customer_with_order(&db, tenant_id).await;
+    let service = CustomerDataService::new(db.clone(), mock_transactional_event_bus());
+
+    let report = service
+        .erase_customer_data(tenant_id, Uuid::new_v4(), customer_id, ErasureMode::Delete)
+        .await
+        .unwrap();
+    assert_eq!(report.orders, 1);
+
+    let missing = service.export_customer_data(tenant_id, customer_id).await;
+    assert!(matches!(
+        missing,
+        Err(rustok_commerce::CustomerDataError::CustomerNotFound(id)) if id == customer_id
+    ));
+}
+
+#[tokio::test]
+async fn delete_keeps_scrubbed_orders_that_returns_reference() {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    support::ensure_commerce_schema(&db).await;
+    let tenant_id = Uuid::new_v4();
+    let (customer_id, order_id) = seed_customer_with_order(&db, tenant_id).await;
+    let orders = OrderService::new(db.clone(), mock_transactional_event_bus());
+    let line_item_id = orders
+        .get_order(tenant_id, order_id)
+        .await
+        .unwrap()
+        .line_items[0]
+        .id;
+    let created = orders
+        .create_return(
+            tenant_id,
+            order_id,
+            CreateOrderReturnInput {
+                reason: Some("Ship to 1 Main St instead".to_string()),
+                note: None,
+                items: vec![CreateOrderReturnItemInput {
+                    line_item_id,
+                    quantity: 1,
+                    reason: None,
+                    note: Some("Call +15550100".to_string()),
+                    metadata: serde_json::json!({}),
+                }],
+                metadata: serde_json::json!({"address": "1 Main St"}),
+            },
+        )
+        .await
+        .unwrap();
+
+    let report = CustomerDataService::new(db.clone(), mock_transactional_event_bus())
+        .erase_customer_data(tenant_id, Uuid::new_v4(), customer_id, ErasureMode::Delete)
+        .await
+        .unwrap();
+    assert_eq!(report.orders, 0);
+    assert_eq!(report.retained_orders, 1);
+    assert_eq!(report.returns, 2);
+
+    let order = orders.get_order(tenant_id, order_id).await.unwrap();
+    assert_eq!(order.customer_id, None);
+    assert_eq!(order.metadata, serde_json::json!({}));
+    let returned = orders.get_return(tenant_id, created.id).await.unwrap();
+    assert_eq!(returned.reason, None);
+    assert_eq!(returned.metadata, serde_json::json!({}));
+    assert_eq!(returned.items[0].note, None);
+    assert_eq!(returned.items[0].quantity, 1);
+}
@@ -84,10 +84,12 @@ async fn test_product_created_event_updates_index_projection() {
     let processed_count = Arc::new(AtomicUsize::new(0));
 
     let mut dispatcher = EventDispatcher::new(bus.clone());
-    dispatcher.register(ProductCreatedIndexHandler::new(
-        projection.clone(),
-        Arc::clone(&processed_count),
-    ));
+    dispatcher
+        .register(ProductCreatedIndexHandler::new(
+            projection.clone(),
+            Arc::clone(&processed_count),
+        ))
+        .expect("handler name must be unique");
     let running_dispatcher = dispatcher.start();
 
     bus.publish(tenant_id, None, DomainEvent::ProductCreated { product_id })
@@ -122,10 +124,12 @@ async fn test_product_created_event_repeat_is_idempotent_for_index_projection()
     let processed_count = Arc::new(AtomicUsize::new(0));
 
     let mut dispatcher = EventDispatcher::new(bus.clone());
-    dispatcher.register(ProductCreatedIndexHandler::new(
-        projection.clone(),
-        Arc::clone(&processed_count),
-    ));
+    dispatcher
+        .register(ProductCreatedIndexHandler::new(
+            projection.clone(),
+            Arc::clone(&processed_count),
+        ))
+        .expect("handler name must be unique");
     let running_dispatcher = dispatcher.start();
 
     bus.publish(tenant_id, None, DomainEvent::ProductCreated { product_id })
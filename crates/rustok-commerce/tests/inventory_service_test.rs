@@ -8,10 +8,18 @@ use rustok_commerce::dto::{
     ProductTranslationInput,
 };
 use rustok_commerce::entities;
-use rustok_commerce::services::{CatalogService, InventoryService};
+use rustok_commerce::services::{CatalogService, InventoryService, OrderInventoryLine};
 use rustok_commerce::CommerceError;
-use rustok_test_utils::{db::setup_test_db, helpers::unique_slug, mock_transactional_event_bus};
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter};
+use rustok_test_utils::{
+    db::{setup_test_db, TestDb},
+    helpers::unique_slug,
+    mock_transactional_event_bus,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, Schema, Set, TransactionTrait,
+};
+use sea_orm_migration::{MigrationName, MigrationTrait, MigratorTrait, SchemaManager};
 use std::str::FromStr;
 use uuid::Uuid;
 
@@ -776,3 +784,228 @@ async fn test_reserve_creates_reservation_and_syncs_available_shadow() {
         .await
         .unwrap());
 }
+
+// =============================================================================
+// Order Inventory Write-off Tests
+// =============================================================================
+
+async fn pay_order(
+    db: &DatabaseConnection,
+    service: &InventoryService,
+    tenant_id: Uuid,
+    lines: Vec<OrderInventoryLine>,
+) -> Result<(), CommerceError> {
+    let txn = db.begin().await?;
+    service
+        .commit_order_inventory_in_tx(&txn, tenant_id, Uuid::new_v4(), &lines)
+        .await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+/// Creates only the tables the order write-off touches. The real product
+/// and inventory migrations depend on tenant and taxonomy tables from other
+/// modules.
+struct InventoryTablesMigrator;
+
+impl MigratorTrait for InventoryTablesMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![Box::new(CreateInventoryTables)]
+    }
+}
+
+struct CreateInventoryTables;
+
+impl MigrationName for CreateInventoryTables {
+    fn name(&self) -> &str {
+        "m_test_create_inventory_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateInventoryTables {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let schema = Schema::new(manager.get_database_backend());
+        manager
+            .create_type(schema.create_enum_from_active_enum::<entities::product::ProductStatus>())
+            .await?;
+        for statement in [
+            schema.create_table_from_entity(entities::product::Entity),
+            schema.create_table_from_entity(entities::product_variant::Entity),
+            schema.create_table_from_entity(entities::stock_location::Entity),
+            schema.create_table_from_entity(entities::stock_location_translation::Entity),
+            schema.create_table_from_entity(entities::inventory_item::Entity),
+            schema.create_table_from_entity(entities::inventory_level::Entity),
+        ] {
+            manager.create_table(statement).await?;
+        }
+        Ok(())
+    }
+}
+
+async fn insert_test_variant(db: &DatabaseConnection, tenant_id: Uuid) -> (Uuid, Uuid) {
+    let now = chrono::Utc::now();
+    let product = entities::product::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        tenant_id: Set(tenant_id),
+        status: Set(entities::product::ProductStatus::Active),
+        seller_id: Set(None),
+        vendor: Set(None),
+        product_type: Set(None),
+        shipping_profile_slug: Set(None),
+        metadata: Set(serde_json::json!({})),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+        published_at: Set(None),
+        created_by: Set(None),
+        updated_by: Set(None),
+    }
+    .insert(db)
+    .await
+    .unwrap();
+    let variant = entities::product_variant::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        product_id: Set(product.id),
+        tenant_id: Set(tenant_id),
+        sku: Set(None),
+        barcode: Set(None),
+        shipping_profile_slug: Set(None),
+        ean: Set(None),
+        upc: Set(None),
+        inventory_policy: Set("deny".to_string()),
+        inventory_management: Set("manual".to_string()),
+        inventory_quantity: Set(0),
+        weight: Set(None),
+        weight_unit: Set(None),
+        option1: Set(None),
+        option2: Set(None),
+        option3: Set(None),
+        position: Set(0),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+    }
+    .insert(db)
+    .await
+    .unwrap();
+    (product.id, variant.id)
+}
+
+/// Needs Postgres: SQLite ignores `FOR UPDATE` and the in-memory pool has a
+/// single connection, so it can never deadlock.
+#[tokio::test]
+async fn test_concurrent_orders_sharing_products_in_opposite_order_do_not_deadlock() {
+    let Some(test_db) = TestDb::new::<InventoryTablesMigrator>().await else {
+        return;
+    };
+    let db = test_db.conn();
+    let service = InventoryService::new(db.clone(), mock_transactional_event_bus());
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let (product_a, variant_a) = insert_test_variant(db, tenant_id).await;
+    let (product_b, variant_b) = insert_test_variant(db, tenant_id).await;
+    for variant_id in [variant_a, variant_b] {
+        service
+            .set_inventory(tenant_id, actor_id, variant_id, 100)
+            .await
+            .unwrap();
+    }
+
+    let line_a = OrderInventoryLine {
+        product_id: product_a,
+        variant_id: variant_a,
+        quantity: 2,
+    };
+    let line_b = OrderInventoryLine {
+        product_id: product_b,
+        variant_id: variant_b,
+        quantity: 3,
+    };
+
+    // Both orders lock rows sorted by product id regardless of item order,
+    // so neither waits on a row the other holds while holding one it needs.
+    // Postgres would abort one of them with a deadlock error otherwise.
+    let rounds = 10;
+    for _ in 0..rounds {
+        let (first, second) = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            tokio::join!(
+                pay_order(db, &service, tenant_id, vec![line_a, line_b]),
+                pay_order(db, &service, tenant_id, vec![line_b, line_a]),
+            )
+        })
+        .await
+        .expect("concurrent orders should not deadlock");
+        first.unwrap();
+        second.unwrap();
+    }
+
+    let remaining_a = 100 - 2 * 2 * rounds;
+    let remaining_b = 100 - 2 * 3 * rounds;
+    assert!(service
+        .check_availability(tenant_id, variant_a, remaining_a)
+        .await
+        .unwrap());
+    assert!(!service
+        .check_availability(tenant_id, variant_a, remaining_a + 1)
+        .await
+        .unwrap());
+    assert!(service
+        .check_availability(tenant_id, variant_b, remaining_b)
+        .await
+        .unwrap());
+    assert!(!service
+        .check_availability(tenant_id, variant_b, remaining_b + 1)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_order_write_off_rolls_back_all_lines_on_insufficient_stock() {
+    let (db, service, catalog) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let (product_a, variant_a) = create_test_product(&catalog, tenant_id).await;
+    let (product_b, variant_b) = create_test_product(&catalog, tenant_id).await;
+    service
+        .set_inventory(tenant_id, actor_id, variant_a, 5)
+        .await
+        .unwrap();
+    service
+        .set_inventory(tenant_id, actor_id, variant_b, 1)
+        .await
+        .unwrap();
+
+    let result = pay_order(
+        &db,
+        &service,
+        tenant_id,
+        vec![
+            OrderInventoryLine {
+                product_id: product_a,
+                variant_id: variant_a,
+                quantity: 2,
+            },
+            OrderInventoryLine {
+                product_id: product_b,
+                variant_id: variant_b,
+                quantity: 2,
+            },
+        ],
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(CommerceError::InsufficientInventory {
+            requested: 2,
+            available: 1
+        })
+    ));
+    assert!(service
+        .check_availability(tenant_id, variant_a, 5)
+        .await
+        .unwrap());
+    assert!(service
+        .check_availability(tenant_id, variant_b, 1)
+        .await
+        .unwrap());
+}
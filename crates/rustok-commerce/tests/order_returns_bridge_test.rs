@@ -59,7 +59,7 @@ async fn commerce_test_schema_supports_order_returns_filters() {
         .await
         .unwrap();
 
-    let (filtered, filtered_total) = service
+    let filtered = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -72,12 +72,12 @@ async fn commerce_test_schema_supports_order_returns_filters() {
         .await
         .unwrap();
 
-    assert_eq!(filtered_total, 1);
-    assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered[0].id, created.id);
-    assert_eq!(filtered[0].order_id, order.id);
-    assert_eq!(filtered[0].status, "pending");
-    assert_eq!(filtered[0].reason.as_deref(), Some("damaged"));
+    assert_eq!(filtered.total, Some(1));
+    assert_eq!(filtered.items.len(), 1);
+    assert_eq!(filtered.items[0].id, created.id);
+    assert_eq!(filtered.items[0].order_id, order.id);
+    assert_eq!(filtered.items[0].status, "pending");
+    assert_eq!(filtered.items[0].reason.as_deref(), Some("damaged"));
 }
 
 #[tokio::test]
@@ -130,7 +130,7 @@ async fn commerce_order_returns_listing_ignores_blank_status_filter() {
         .await
         .unwrap();
 
-    let (rows, total) = service
+    let rows = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -143,9 +143,9 @@ async fn commerce_order_returns_listing_ignores_blank_status_filter() {
         .await
         .unwrap();
 
-    assert_eq!(total, 1);
-    assert_eq!(rows.len(), 1);
-    assert_eq!(rows[0].id, created.id);
+    assert_eq!(rows.total, Some(1));
+    assert_eq!(rows.items.len(), 1);
+    assert_eq!(rows.items[0].id, created.id);
 }
 
 #[tokio::test]
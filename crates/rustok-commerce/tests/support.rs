@@ -16,7 +16,7 @@ use rustok_fulfillment::entities::{
 };
 use rustok_order::entities::{
     order, order_adjustment, order_change, order_line_item, order_line_item_translation,
-    order_return, order_return_item, order_tax_line,
+    order_return, order_return_item, order_tax_line, sequence,
 };
 use rustok_payment::entities::{payment, payment_collection, refund};
 use rustok_product::entities::product_tag;
@@ -215,6 +215,12 @@ pub async fn ensure_commerce_schema(db: &DatabaseConnection) {
     )
     .await;
     create_entity_table(db, &builder, schema.create_table_from_entity(order::Entity)).await;
+    create_entity_table(
+        db,
+        &builder,
+        schema.create_table_from_entity(sequence::Entity),
+    )
+    .await;
     create_entity_table(
         db,
         &builder,
@@ -1,4 +1,5 @@
 use crate::entities::node::ContentStatus;
+use rustok_core::BodyFormat;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -6,7 +7,7 @@ use validator::Validate;
 
 use utoipa::ToSchema;
 
-use super::validation::{validate_body_format, validate_kind, validate_locale, validate_slug};
+use super::validation::{validate_kind, validate_locale, validate_slug};
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
 pub struct CreateNodeInput {
@@ -57,8 +58,8 @@ pub struct BodyInput {
     #[validate(length(max = 1_000_000, message = "Body too large (max 1MB)"))]
     pub body: Option<String>,
 
-    #[validate(custom(function = "validate_body_format", message = "Invalid body format"))]
-    pub format: Option<String>,
+    /// Unknown formats are rejected when the input is deserialized.
+    pub format: Option<BodyFormat>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema, Validate)]
@@ -96,6 +97,33 @@ pub struct ListNodesFilter {
     pub include_deleted: bool,
 }
 
+/// Which nodes a bulk delete targets. At least one field must be set.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, ToSchema)]
+pub struct BulkDeleteCriteria {
+    pub kind: Option<String>,
+    pub status: Option<ContentStatus>,
+    pub category_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
+}
+
+impl BulkDeleteCriteria {
+    pub fn is_empty(&self) -> bool {
+        self.kind.is_none()
+            && self.status.is_none()
+            && self.category_id.is_none()
+            && self.parent_id.is_none()
+    }
+}
+
+/// Result of `prepare_bulk_delete`: how many nodes would go, and the token
+/// that authorises exactly that set.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkDeletePlan {
+    pub affected_count: u64,
+    pub token: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
 fn default_page() -> u64 {
     1
 }
@@ -122,6 +150,10 @@ pub struct NodeResponse {
     pub published_at: Option<String>,
     pub deleted_at: Option<String>,
     pub version: i32,
+    /// Who created the node; only populated for staff readers.
+    pub created_by: Option<Uuid>,
+    /// Who last changed the node; only populated for staff readers.
+    pub updated_by: Option<Uuid>,
     pub translations: Vec<NodeTranslationResponse>,
     pub bodies: Vec<BodyResponse>,
 }
@@ -3,17 +3,19 @@
 /// Provides validation rules and custom validators for all content-related inputs.
 ///
 /// FIXED: Added i18n support for error messages
+use rustok_core::BodyFormat;
 use validator::ValidationError;
 
 /// Custom validator for body format
 ///
-/// `rt_json_v1` is canonical rich-text format; `rt_json` is an existing compatibility alias.
-/// `grapesjs_v1` is the canonical page-builder project payload format for pages.
+/// Accepts every [`BodyFormat`] spelling, including the legacy `rt_json`
+/// alias of `rt_json_v1`. Typed DTOs use [`BodyFormat`] directly; this is for
+/// callers that still carry the format as a string.
 pub fn validate_body_format(format: &str) -> Result<(), ValidationError> {
-    match format {
-        "markdown" | "html" | "plain" | "json" | "rt_json_v1" | "rt_json" | "grapesjs_v1" => Ok(()),
-        _ => Err(ValidationError::new("invalid_format")),
-    }
+    format
+        .parse::<BodyFormat>()
+        .map(|_| ())
+        .map_err(|_| ValidationError::new("invalid_format"))
 }
 
 /// Custom validator for kind
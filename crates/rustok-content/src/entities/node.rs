@@ -43,6 +43,10 @@ pub struct Model {
     /// Optimistic locking version
     #[sea_orm(default = 1)]
     pub version: i32,
+    /// User who created the node; `None` for system writes and legacy rows
+    pub created_by: Option<Uuid>,
+    /// User who last changed the node
+    pub updated_by: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,4 +1,4 @@
-use rustok_core::error::{ErrorKind, RichError};
+use rustok_core::error::{CodedError, ErrorKind, RichError};
 use sea_orm::DbErr;
 use thiserror::Error;
 use uuid::Uuid;
@@ -172,6 +172,23 @@ impl ContentError {
     }
 }
 
+impl CodedError for ContentError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            ContentError::Database(_) => ErrorKind::Database.api_code(),
+            ContentError::Core(core) => core.error_code(),
+            ContentError::NodeNotFound(_) => "content.node_not_found",
+            ContentError::CategoryNotFound(_) => "content.category_not_found",
+            ContentError::TranslationNotFound { .. } => "content.translation_not_found",
+            ContentError::DuplicateSlug { .. } => "content.duplicate_slug",
+            ContentError::ConcurrentModification { .. } => "content.concurrent_modification",
+            ContentError::Forbidden(_) => "content.forbidden",
+            ContentError::Validation(_) => "content.validation",
+            ContentError::Rich(rich) => rich.as_ref().error_code(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +235,16 @@ mod tests {
         assert_eq!(rich.fields.get("expected_version"), Some(&"3".to_string()));
         assert_eq!(rich.fields.get("actual_version"), Some(&"2".to_string()));
     }
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(
+            ContentError::node_not_found(Uuid::new_v4()).error_code(),
+            "content.node_not_found"
+        );
+        assert_eq!(
+            ContentError::concurrent_modification(3, 2).error_code(),
+            "content.concurrent_modification"
+        );
+    }
 }
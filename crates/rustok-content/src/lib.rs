@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use rustok_core::permissions::{Action, Permission, Resource};
-use rustok_core::{MigrationSource, RusToKModule};
+use rustok_core::{MigrationSource, ModuleRuntimeExtensions, RusToKModule};
 use sea_orm_migration::MigrationTrait;
 
 pub mod dto;
@@ -10,6 +10,7 @@ pub mod locale;
 pub mod migrations;
 pub mod services;
 pub mod state_machine;
+pub mod visibility;
 
 #[cfg(test)]
 mod state_machine_proptest;
@@ -31,6 +32,7 @@ pub use services::{
     ResolvedContentRoute, RetiredCanonicalTarget, SplitTopicInput, SplitTopicOutput,
 };
 pub use state_machine::{Archived, ContentNode, Draft, Published, ToContentStatus};
+pub use visibility::{internal_metadata_keys_setting, MetadataVisibility};
 
 pub struct ContentModule;
 
@@ -68,6 +70,11 @@ impl RusToKModule for ContentModule {
             Permission::new(Resource::BlogPosts, Action::Moderate),
         ]
     }
+
+    fn register_runtime_extensions(&self, extensions: &mut ModuleRuntimeExtensions) {
+        rustok_tenant::register_tenant_setting(extensions, internal_metadata_keys_setting())
+            .expect("content tenant settings should register once");
+    }
 }
 
 impl MigrationSource for ContentModule {
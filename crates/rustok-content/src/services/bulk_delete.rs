@@ -0,0 +1,145 @@
+//! Signed confirmation tokens for two-step bulk deletes.
+//!
+//! A token binds the tenant, the caller, the criteria and a fingerprint of
+//! the exact node set that `prepare_bulk_delete` saw. It is an HMAC-SHA256
+//! signed payload, so callers cannot widen the criteria between the two steps.
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use rustok_core::utils::{base64_decode, base64_encode, hex_decode, hex_encode};
+use rustok_core::TimeBounds;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::dto::BulkDeleteCriteria;
+use crate::error::{ContentError, ContentResult};
+
+/// How long a prepared plan stays executable.
+pub(crate) const BULK_DELETE_TOKEN_TTL: Duration = Duration::minutes(5);
+
+/// Upper bound on nodes a single plan may cover.
+pub(crate) const BULK_DELETE_MAX_NODES: usize = 1000;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub(crate) struct BulkDeleteClaims {
+    pub tenant_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub criteria: BulkDeleteCriteria,
+    pub affected_count: u64,
+    pub fingerprint: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Random per-process key used when the service was not given one. Tokens
+/// signed with it do not survive a restart or cross instances.
+pub(crate) fn process_secret() -> &'static [u8] {
+    static SECRET: OnceLock<Vec<u8>> = OnceLock::new();
+    SECRET.get_or_init(|| {
+        let mut bytes = Uuid::new_v4().as_bytes().to_vec();
+        bytes.extend_from_slice(Uuid::new_v4().as_bytes());
+        bytes
+    })
+}
+
+/// Order-independent digest of the node ids a plan covers.
+pub(crate) fn fingerprint(node_ids: &[Uuid]) -> String {
+    let mut sorted = node_ids.to_vec();
+    sorted.sort_unstable();
+    let mut hasher = Sha256::new();
+    for id in &sorted {
+        hasher.update(id.as_bytes());
+    }
+    hex_encode(&hasher.finalize())
+}
+
+pub(crate) fn sign(secret: &[u8], claims: &BulkDeleteClaims) -> ContentResult<String> {
+    let payload = serde_json::to_vec(claims)
+        .map_err(|e| ContentError::validation(format!("Failed to encode bulk delete plan: {e}")))?;
+    let payload = base64_encode(&payload);
+    let signature = hex_encode(&mac(secret, payload.as_bytes()).finalize().into_bytes());
+    Ok(format!("{payload}.{signature}"))
+}
+
+/// Check the signature and expiry and return the embedded claims.
+pub(crate) fn verify(secret: &[u8], token: &str) -> ContentResult<BulkDeleteClaims> {
+    let invalid = || ContentError::validation("Invalid bulk delete token");
+    let (payload, signature) = token.split_once('.').ok_or_else(invalid)?;
+
+    let signature = hex_decode(signature).map_err(|_| invalid())?;
+    mac(secret, payload.as_bytes())
+        .verify_slice(&signature)
+        .map_err(|_| invalid())?;
+
+    let bytes = base64_decode(payload).map_err(|_| invalid())?;
+    let claims: BulkDeleteClaims = serde_json::from_slice(&bytes).map_err(|_| invalid())?;
+    if TimeBounds::until(claims.expires_at).is_expired() {
+        return Err(ContentError::validation(
+            "Bulk delete token has expired; prepare the operation again",
+        ));
+    }
+    Ok(claims)
+}
+
+fn mac(secret: &[u8], message: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims() -> BulkDeleteClaims {
+        BulkDeleteClaims {
+            tenant_id: Uuid::new_v4(),
+            user_id: Some(Uuid::new_v4()),
+            criteria: BulkDeleteCriteria {
+                kind: Some("post".to_string()),
+                ..Default::default()
+            },
+            affected_count: 2,
+            fingerprint: fingerprint(&[Uuid::new_v4(), Uuid::new_v4()]),
+            expires_at: Utc::now() + BULK_DELETE_TOKEN_TTL,
+        }
+    }
+
+    #[test]
+    fn round_trips_signed_claims() {
+        let claims = claims();
+        let token = sign(b"secret", &claims).unwrap();
+        assert_eq!(verify(b"secret", &token).unwrap(), claims);
+    }
+
+    #[test]
+    fn rejects_other_secret_and_tampered_payload() {
+        let token = sign(b"secret", &claims()).unwrap();
+        assert!(verify(b"other", &token).is_err());
+
+        let (_, signature) = token.split_once('.').unwrap();
+        let mut forged = claims();
+        forged.affected_count = 10_000;
+        let forged_payload = base64_encode(&serde_json::to_vec(&forged).unwrap());
+        assert!(verify(b"secret", &format!("{forged_payload}.{signature}")).is_err());
+    }
+
+    #[test]
+    fn rejects_expired_token() {
+        let mut claims = claims();
+        claims.expires_at = Utc::now() - rustok_core::DEFAULT_CLOCK_LEEWAY - Duration::seconds(1);
+        let token = sign(b"secret", &claims).unwrap();
+        assert!(verify(b"secret", &token).is_err());
+    }
+
+    #[test]
+    fn fingerprint_ignores_order() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(fingerprint(&[a, b]), fingerprint(&[b, a]));
+        assert_ne!(fingerprint(&[a]), fingerprint(&[a, b]));
+    }
+}
@@ -1,12 +1,12 @@
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait,
-    PaginatorTrait, QueryFilter, QueryOrder,
+    QueryFilter, QueryOrder,
 };
 use tracing::instrument;
 use uuid::Uuid;
 
-use rustok_core::{SecurityContext, PLATFORM_FALLBACK_LOCALE};
+use rustok_core::{fetch_with_total, Page, SecurityContext, PLATFORM_FALLBACK_LOCALE};
 
 use crate::dto::category::{
     CategoryListItem, CategoryResponse, CreateCategoryInput, ListCategoriesFilter,
@@ -195,19 +195,21 @@ impl CategoryService {
         tenant_id: Uuid,
         _security: SecurityContext,
         filter: ListCategoriesFilter,
-    ) -> ContentResult<(Vec<CategoryListItem>, u64)> {
+    ) -> ContentResult<Page<CategoryListItem>> {
         let locale = filter
             .locale
             .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string());
         let page = filter.page.max(1);
 
-        let paginator = category::Entity::find()
-            .filter(category::Column::TenantId.eq(tenant_id))
-            .order_by_asc(category::Column::Position)
-            .paginate(&self.db, filter.per_page);
-
-        let total = paginator.num_items().await?;
-        let cats = paginator.fetch_page(page - 1).await?;
+        let (cats, total) = fetch_with_total(
+            &self.db,
+            category::Entity::find()
+                .filter(category::Column::TenantId.eq(tenant_id))
+                .order_by_asc(category::Column::Position),
+            (page - 1) * filter.per_page,
+            filter.per_page,
+        )
+        .await?;
 
         let ids: Vec<Uuid> = cats.iter().map(|c| c.id).collect();
         let all_translations = if ids.is_empty() {
@@ -241,7 +243,7 @@ impl CategoryService {
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, (page - 1) * filter.per_page))
     }
 }
 
@@ -1,3 +1,4 @@
+mod bulk_delete;
 mod canonical_url_service;
 mod category_service;
 mod content_orchestration_service;
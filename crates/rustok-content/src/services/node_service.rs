@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use chrono::Utc;
 use sea_orm::{
     prelude::DateTimeWithTimeZone, ActiveModelTrait, ColumnTrait, ConnectionTrait,
-    DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter, Set,
+    DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter, QuerySelect, Set,
     TransactionTrait,
 };
 use tracing::{debug, error, info, instrument, warn};
@@ -9,22 +11,24 @@ use uuid::Uuid;
 use validator::Validate;
 
 use rustok_core::{
-    prepare_content_payload, Action, DomainEvent, PermissionScope, Resource, SecurityContext,
-    PLATFORM_FALLBACK_LOCALE,
+    fetch_with_total, prepare_content_payload, Action, CacheBackend, DomainEvent, Page,
+    PermissionScope, Resource, SecurityContext, PLATFORM_FALLBACK_LOCALE,
 };
 use rustok_outbox::TransactionalEventBus;
 
 use crate::dto::{
-    BodyInput, BodyResponse, CreateNodeInput, ListNodesFilter, NodeListItem, NodeResponse,
-    NodeTranslationResponse, UpdateNodeInput,
+    BodyInput, BodyResponse, BulkDeleteCriteria, BulkDeletePlan, CreateNodeInput, ListNodesFilter,
+    NodeListItem, NodeResponse, NodeTranslationResponse, UpdateNodeInput,
 };
 use rustok_core::json_object_depth;
 use rustok_telemetry::metrics;
 
+use super::bulk_delete::{self, BulkDeleteClaims, BULK_DELETE_MAX_NODES, BULK_DELETE_TOKEN_TTL};
 use crate::entities::{body, node, node_translation};
 use crate::error::{ContentError, ContentResult};
 use crate::locale::resolve_by_locale_with_fallback;
 use crate::state_machine::validate_status_transition;
+use crate::visibility::MetadataVisibility;
 
 /// Maximum allowed JSON nesting depth for the `metadata` field.
 const METADATA_MAX_DEPTH: usize = 5;
@@ -32,17 +36,94 @@ const METADATA_MAX_DEPTH: usize = 5;
 pub struct NodeService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
+    metadata_visibility: MetadataVisibility,
+    read_cache: Option<Arc<dyn CacheBackend>>,
+    bulk_delete_secret: Option<Arc<[u8]>>,
 }
 
 impl NodeService {
     pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
-        Self { db, event_bus }
+        Self {
+            db,
+            event_bus,
+            metadata_visibility: MetadataVisibility::default(),
+            read_cache: None,
+            bulk_delete_secret: None,
+        }
+    }
+
+    /// Override which metadata keys reader-facing paths treat as internal.
+    pub fn with_metadata_visibility(mut self, metadata_visibility: MetadataVisibility) -> Self {
+        self.metadata_visibility = metadata_visibility;
+        self
+    }
+
+    /// Serve [`Self::get_node`] from `cache`, keyed by tenant and node id.
+    /// Writes made through this service evict the entry once committed;
+    /// callers of the `*_in_tx` methods call [`Self::evict_cached_nodes`]
+    /// after committing their transaction.
+    pub fn with_read_cache(mut self, cache: Arc<dyn CacheBackend>) -> Self {
+        self.read_cache = Some(cache);
+        self
+    }
+
+    /// Key used to sign bulk delete tokens. Without it a random per-process
+    /// key is used, so tokens only work on the instance that issued them.
+    pub fn with_bulk_delete_secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.bulk_delete_secret = Some(Arc::from(secret.as_ref()));
+        self
     }
 
     pub fn db(&self) -> &DatabaseConnection {
         &self.db
     }
 
+    fn node_cache_key(tenant_id: Uuid, node_id: Uuid) -> String {
+        format!("node:{tenant_id}:{node_id}")
+    }
+
+    async fn cached_node(&self, tenant_id: Uuid, node_id: Uuid) -> Option<NodeResponse> {
+        let cache = self.read_cache.as_ref()?;
+        match cache.get(&Self::node_cache_key(tenant_id, node_id)).await {
+            Ok(Some(payload)) => serde_json::from_slice(&payload).ok(),
+            Ok(None) => None,
+            Err(e) => {
+                debug!(error = %e, "Content read cache GET failed");
+                None
+            }
+        }
+    }
+
+    async fn cache_node(&self, response: &NodeResponse) {
+        let Some(cache) = self.read_cache.as_ref() else {
+            return;
+        };
+        let Ok(payload) = serde_json::to_vec(response) else {
+            return;
+        };
+        let key = Self::node_cache_key(response.tenant_id, response.id);
+        if let Err(e) = cache.set(key, payload).await {
+            debug!(error = %e, "Content read cache SET failed");
+        }
+    }
+
+    /// Drop cached reads of `node_ids`. Call it after the transaction that
+    /// changed them has committed, so a concurrent read cannot cache the old
+    /// row again.
+    pub async fn evict_cached_nodes(&self, tenant_id: Uuid, node_ids: &[Uuid]) {
+        let Some(cache) = self.read_cache.as_ref() else {
+            return;
+        };
+        for &node_id in node_ids {
+            if let Err(e) = cache
+                .invalidate(&Self::node_cache_key(tenant_id, node_id))
+                .await
+            {
+                warn!(error = %e, node_id = %node_id, "Content read cache eviction failed");
+            }
+        }
+    }
+
     fn kind_to_resource(kind: &str) -> ContentResult<Resource> {
         match kind {
             "post" | "article" | "custom" => Ok(Resource::Posts),
@@ -236,6 +317,8 @@ impl NodeService {
             },
             deleted_at: Set(None),
             version: Set(1),
+            created_by: Set(security.user_id),
+            updated_by: Set(security.user_id),
         }
         .insert(txn)
         .await?;
@@ -299,6 +382,7 @@ impl NodeService {
             .await
             .inspect_err(|e| metrics::record_span_error("content.node.update", e.kind()))?;
         txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &[node_id]).await;
         metrics::record_span_duration("content.node.update", started.elapsed().as_secs_f64());
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
@@ -391,6 +475,7 @@ impl NodeService {
         }
 
         active.updated_at = Set(now);
+        active.updated_by = Set(security.user_id);
         active.version = Set(node_model.version + 1);
 
         if let Some(translations) = update.translations {
@@ -495,6 +580,7 @@ impl NodeService {
             .await
             .inspect_err(|e| metrics::record_span_error(op, e.kind()))?;
         txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &[node_id]).await;
         metrics::record_span_duration(op, started.elapsed().as_secs_f64());
 
         let translations = node_translation::Entity::find()
@@ -539,6 +625,7 @@ impl NodeService {
 
         active.status = Set(new_status.clone());
         active.updated_at = Set(now);
+        active.updated_by = Set(security.user_id);
         active.version = Set(node_model.version + 1);
 
         match new_status {
@@ -636,6 +723,72 @@ impl NodeService {
         .await
     }
 
+    /// Publish several nodes in one transaction and emit a single
+    /// `NodesPublished` event for the ones that succeeded.
+    ///
+    /// Permission and status checks run per node; failures are reported in
+    /// the returned vector (same order as `node_ids`) and do not abort the
+    /// rest of the batch. Each node runs in its own savepoint, so a failed
+    /// write is rolled back without poisoning the outer transaction.
+    #[instrument(skip(self, security, node_ids), fields(tenant_id = %tenant_id, count = node_ids.len(), user_id = ?security.user_id))]
+    pub async fn publish_nodes(
+        &self,
+        tenant_id: Uuid,
+        security: SecurityContext,
+        node_ids: &[Uuid],
+    ) -> ContentResult<Vec<ContentResult<()>>> {
+        info!("Publishing nodes in batch");
+        let op = "content.node.publish_batch";
+        let started = std::time::Instant::now();
+        let txn = self.db.begin().await?;
+
+        let mut results = Vec::with_capacity(node_ids.len());
+        let mut published = Vec::new();
+        for &node_id in node_ids {
+            let savepoint = txn.begin().await?;
+            let result = match self
+                .transition_status_in_tx(
+                    &savepoint,
+                    tenant_id,
+                    node_id,
+                    security.clone(),
+                    node::ContentStatus::Published,
+                    Vec::new(),
+                )
+                .await
+            {
+                Ok(_) => savepoint.commit().await.map_err(ContentError::from),
+                Err(error) => {
+                    savepoint.rollback().await?;
+                    Err(error)
+                }
+            };
+            match &result {
+                Ok(()) => published.push(node_id),
+                Err(e) => metrics::record_span_error(op, e.kind()),
+            }
+            results.push(result);
+        }
+
+        if !published.is_empty() {
+            self.event_bus
+                .publish_in_tx(
+                    &txn,
+                    tenant_id,
+                    security.user_id,
+                    DomainEvent::NodesPublished {
+                        node_ids: published.clone(),
+                    },
+                )
+                .await?;
+        }
+        txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &published).await;
+        metrics::record_span_duration(op, started.elapsed().as_secs_f64());
+
+        Ok(results)
+    }
+
     /// Publish a node within an existing transaction. Does not begin or commit.
     pub async fn publish_node_in_tx(
         &self,
@@ -721,6 +874,7 @@ impl NodeService {
         self.delete_node_in_tx(&txn, tenant_id, node_id, security)
             .await?;
         txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &[node_id]).await;
         info!(node_id = %node_id, "Node soft-deleted successfully");
         Ok(())
     }
@@ -743,6 +897,7 @@ impl NodeService {
         let mut active: node::ActiveModel = node_model.clone().into();
         active.deleted_at = Set(Some(now));
         active.updated_at = Set(now);
+        active.updated_by = Set(security.user_id);
         active.version = Set(node_model.version + 1);
         active.update(txn).await?;
 
@@ -787,6 +942,7 @@ impl NodeService {
         let mut active: node::ActiveModel = node_model.clone().into();
         active.deleted_at = Set(None);
         active.updated_at = Set(now);
+        active.updated_by = Set(security.user_id);
         active.version = Set(node_model.version + 1);
         let updated = active.update(&txn).await?;
 
@@ -803,6 +959,7 @@ impl NodeService {
             .await?;
 
         txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &[node_id]).await;
 
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
@@ -851,11 +1008,146 @@ impl NodeService {
         node::Entity::delete_by_id(node_id).exec(&txn).await?;
 
         txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &[node_id]).await;
 
         info!(node_id = %node_id, "Node hard-deleted permanently");
         Ok(())
     }
 
+    /// First step of a guarded bulk soft-delete: compute which nodes match
+    /// `criteria` and return a short-lived token authorising exactly that set.
+    ///
+    /// Nothing is deleted here. Permission checks run per node so a plan is
+    /// never issued for nodes the caller could not delete.
+    #[instrument(skip(self, security), fields(tenant_id = %tenant_id, user_id = ?security.user_id))]
+    pub async fn prepare_bulk_delete(
+        &self,
+        tenant_id: Uuid,
+        security: SecurityContext,
+        criteria: BulkDeleteCriteria,
+    ) -> ContentResult<BulkDeletePlan> {
+        if criteria.is_empty() {
+            return Err(ContentError::validation(
+                "Bulk delete requires at least one criterion",
+            ));
+        }
+
+        let nodes = Self::find_bulk_delete_targets(&self.db, tenant_id, &criteria).await?;
+        if nodes.len() > BULK_DELETE_MAX_NODES {
+            return Err(ContentError::validation(format!(
+                "Bulk delete matches {} nodes; narrow the criteria to at most {BULK_DELETE_MAX_NODES}",
+                nodes.len()
+            )));
+        }
+        for node_model in &nodes {
+            let resource = Self::kind_to_resource(&node_model.kind)?;
+            let scope = security.get_scope(resource, Action::Delete);
+            self.enforce_scope(scope, node_model.author_id, security.user_id)?;
+        }
+
+        let node_ids: Vec<Uuid> = nodes.iter().map(|n| n.id).collect();
+        let claims = BulkDeleteClaims {
+            tenant_id,
+            user_id: security.user_id,
+            criteria,
+            affected_count: node_ids.len() as u64,
+            fingerprint: bulk_delete::fingerprint(&node_ids),
+            expires_at: Utc::now() + BULK_DELETE_TOKEN_TTL,
+        };
+        let token = bulk_delete::sign(self.bulk_delete_secret(), &claims)?;
+
+        info!(
+            affected_count = claims.affected_count,
+            "Bulk delete prepared"
+        );
+        Ok(BulkDeletePlan {
+            affected_count: claims.affected_count,
+            token,
+            expires_at: claims.expires_at,
+        })
+    }
+
+    /// Second step: soft-delete the nodes authorised by a token from
+    /// [`Self::prepare_bulk_delete`], in one transaction.
+    ///
+    /// Fails without deleting anything if the token is invalid, expired,
+    /// issued to another tenant or user, or if the matching set has changed
+    /// since the plan was prepared.
+    #[instrument(skip(self, security, token), fields(tenant_id = %tenant_id, user_id = ?security.user_id))]
+    pub async fn execute_bulk_delete(
+        &self,
+        tenant_id: Uuid,
+        security: SecurityContext,
+        token: &str,
+    ) -> ContentResult<u64> {
+        let claims = bulk_delete::verify(self.bulk_delete_secret(), token)?;
+        if claims.tenant_id != tenant_id || claims.user_id != security.user_id {
+            return Err(ContentError::forbidden(
+                "Bulk delete token was issued for another tenant or user",
+            ));
+        }
+
+        let txn = self.db.begin().await?;
+        let nodes = Self::find_bulk_delete_targets(&txn, tenant_id, &claims.criteria).await?;
+        let node_ids: Vec<Uuid> = nodes.iter().map(|n| n.id).collect();
+        if node_ids.len() as u64 > claims.affected_count {
+            return Err(ContentError::validation(format!(
+                "Bulk delete now matches {} nodes instead of {}; prepare the operation again",
+                node_ids.len(),
+                claims.affected_count
+            )));
+        }
+        if bulk_delete::fingerprint(&node_ids) != claims.fingerprint {
+            return Err(ContentError::validation(
+                "Nodes matching the bulk delete changed since it was prepared; prepare the operation again",
+            ));
+        }
+
+        for &node_id in &node_ids {
+            self.delete_node_in_tx(&txn, tenant_id, node_id, security.clone())
+                .await?;
+        }
+        txn.commit().await?;
+        self.evict_cached_nodes(tenant_id, &node_ids).await;
+
+        info!(deleted = node_ids.len(), "Bulk delete executed");
+        Ok(node_ids.len() as u64)
+    }
+
+    fn bulk_delete_secret(&self) -> &[u8] {
+        match self.bulk_delete_secret.as_deref() {
+            Some(secret) => secret,
+            None => bulk_delete::process_secret(),
+        }
+    }
+
+    async fn find_bulk_delete_targets(
+        conn: &impl ConnectionTrait,
+        tenant_id: Uuid,
+        criteria: &BulkDeleteCriteria,
+    ) -> ContentResult<Vec<node::Model>> {
+        let mut query = node::Entity::find()
+            .filter(node::Column::TenantId.eq(tenant_id))
+            .filter(node::Column::DeletedAt.is_null());
+        if let Some(kind) = &criteria.kind {
+            query = query.filter(node::Column::Kind.eq(kind.as_str()));
+        }
+        if let Some(status) = &criteria.status {
+            query = query.filter(node::Column::Status.eq(status.clone()));
+        }
+        if let Some(category_id) = criteria.category_id {
+            query = query.filter(node::Column::CategoryId.eq(category_id));
+        }
+        if let Some(parent_id) = criteria.parent_id {
+            query = query.filter(node::Column::ParentId.eq(parent_id));
+        }
+        // One row past the cap is enough to reject an oversized plan.
+        Ok(query
+            .limit(BULK_DELETE_MAX_NODES as u64 + 1)
+            .all(conn)
+            .await?)
+    }
+
     pub async fn find_node(&self, tenant_id: Uuid, node_id: Uuid) -> ContentResult<node::Model> {
         Self::find_node_on(&self.db, tenant_id, node_id).await
     }
@@ -888,6 +1180,9 @@ impl NodeService {
     #[instrument(skip(self), fields(tenant_id = %tenant_id, node_id = %node_id))]
     pub async fn get_node(&self, tenant_id: Uuid, node_id: Uuid) -> ContentResult<NodeResponse> {
         debug!("Fetching node");
+        if let Some(cached) = self.cached_node(tenant_id, node_id).await {
+            return Ok(cached);
+        }
         let node_model = self.find_node(tenant_id, node_id).await?;
         let translations = node_translation::Entity::find()
             .filter(node_translation::Column::NodeId.eq(node_id))
@@ -898,7 +1193,33 @@ impl NodeService {
             .all(&self.db)
             .await?;
 
-        Ok(Self::to_response(node_model, translations, bodies))
+        let response = Self::to_response(node_model, translations, bodies);
+        self.cache_node(&response).await;
+        Ok(response)
+    }
+
+    /// Reader-facing [`Self::get_node`]: internal metadata keys are stripped
+    /// unless `security` may see them.
+    pub async fn get_node_for(
+        &self,
+        tenant_id: Uuid,
+        security: &SecurityContext,
+        node_id: Uuid,
+    ) -> ContentResult<NodeResponse> {
+        let mut response = self.get_node(tenant_id, node_id).await?;
+        self.shape_for_reader(security, &mut response);
+        Ok(response)
+    }
+
+    /// Strip what `security` may not see from a node response: internal
+    /// metadata keys and the audit user ids.
+    fn shape_for_reader(&self, security: &SecurityContext, response: &mut NodeResponse) {
+        self.metadata_visibility
+            .apply(security, &mut response.metadata);
+        if !MetadataVisibility::can_view_internal(security) {
+            response.created_by = None;
+            response.updated_by = None;
+        }
     }
 
     /// Fetch multiple nodes by ID in a single batch query.
@@ -982,13 +1303,29 @@ impl NodeService {
         }
     }
 
+    /// Reader-facing [`Self::get_by_slug`], shaped like [`Self::get_node_for`].
+    pub async fn get_by_slug_for(
+        &self,
+        tenant_id: Uuid,
+        security: &SecurityContext,
+        kind: &str,
+        locale: &str,
+        slug: &str,
+    ) -> ContentResult<Option<NodeResponse>> {
+        let mut response = self.get_by_slug(tenant_id, kind, locale, slug).await?;
+        if let Some(response) = response.as_mut() {
+            self.shape_for_reader(security, response);
+        }
+        Ok(response)
+    }
+
     #[instrument(skip(self, security, filter), fields(tenant_id = %tenant_id, user_id = ?security.user_id, kind = ?filter.kind))]
     pub async fn list_nodes(
         &self,
         tenant_id: Uuid,
         security: SecurityContext,
         filter: ListNodesFilter,
-    ) -> ContentResult<(Vec<NodeListItem>, u64)> {
+    ) -> ContentResult<Page<NodeListItem>> {
         self.list_nodes_with_locale_fallback(tenant_id, security, filter, None)
             .await
     }
@@ -1000,7 +1337,7 @@ impl NodeService {
         security: SecurityContext,
         mut filter: ListNodesFilter,
         fallback_locale: Option<&str>,
-    ) -> ContentResult<(Vec<NodeListItem>, u64)> {
+    ) -> ContentResult<Page<NodeListItem>> {
         debug!(
             page = filter.page,
             per_page = filter.per_page,
@@ -1053,9 +1390,13 @@ impl NodeService {
             query = query.filter(node::Column::CategoryId.eq(category_id));
         }
 
-        let paginator = query.clone().paginate(&self.db, filter.per_page);
-        let total = paginator.num_items().await?;
-        let nodes = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
+        let (nodes, total) = fetch_with_total(
+            &self.db,
+            query,
+            filter.page.saturating_sub(1) * filter.per_page,
+            filter.per_page,
+        )
+        .await?;
 
         let node_ids: Vec<Uuid> = nodes.iter().map(|node| node.id).collect();
         let translations = node_translation::Entity::find()
@@ -1106,30 +1447,35 @@ impl NodeService {
                     }),
                     author_id: node.author_id,
                     category_id: node.category_id,
-                    metadata: node.metadata,
+                    metadata: {
+                        let mut metadata = node.metadata;
+                        self.metadata_visibility.apply(&security, &mut metadata);
+                        metadata
+                    },
                     created_at: node.created_at.to_rfc3339(),
                     published_at: node.published_at.map(|date| date.to_rfc3339()),
                 }
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            filter.page.saturating_sub(1) * filter.per_page,
+        ))
     }
 }
 
 fn normalize_body_input(input: BodyInput) -> ContentResult<BodyInput> {
-    let format = input
-        .format
-        .clone()
-        .unwrap_or_else(|| "markdown".to_string());
+    let format = input.format.unwrap_or_default();
 
-    if format == "rt_json" || format == "rt_json_v1" || format == "grapesjs_v1" {
+    if format.is_structured() {
         let locale = input.locale.clone();
         let body = input
             .body
             .ok_or_else(|| ContentError::Validation(format!("{format} body is required")))?;
         let prepared = prepare_content_payload(
-            Some(&format),
+            Some(format.as_str()),
             Some(&body),
             None,
             &locale,
@@ -1140,7 +1486,7 @@ fn normalize_body_input(input: BodyInput) -> ContentResult<BodyInput> {
         return Ok(BodyInput {
             locale,
             body: Some(prepared.body),
-            format: Some(prepared.format),
+            format: Some(format),
         });
     }
 
@@ -1193,7 +1539,7 @@ where
         .one(db)
         .await?;
 
-    let format = input.format.unwrap_or_else(|| "markdown".to_string());
+    let format = input.format.unwrap_or_default().to_string();
 
     let model = if let Some(existing) = existing {
         let mut active: body::ActiveModel = existing.into();
@@ -1242,6 +1588,8 @@ impl NodeService {
             published_at: node.published_at.map(|date| date.to_rfc3339()),
             deleted_at: node.deleted_at.map(|date| date.to_rfc3339()),
             version: node.version,
+            created_by: node.created_by,
+            updated_by: node.updated_by,
             translations: translations
                 .into_iter()
                 .map(|translation| NodeTranslationResponse {
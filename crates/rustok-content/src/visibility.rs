@@ -0,0 +1,149 @@
+use std::collections::BTreeSet;
+
+use rustok_core::{SecurityContext, UserRole};
+use rustok_tenant::{SettingDefinition, SettingType};
+use serde_json::Value;
+
+/// Metadata keys starting with this prefix are internal by convention.
+pub const INTERNAL_METADATA_PREFIX: &str = "_";
+
+/// Dotted tenant setting key holding an optional array of extra internal keys.
+pub const INTERNAL_METADATA_KEYS_SETTING: &str = "content.internal_metadata_keys";
+
+/// Declaration of [`INTERNAL_METADATA_KEYS_SETTING`] for the tenant settings
+/// schema: an array of non-empty strings, empty by default.
+pub fn internal_metadata_keys_setting() -> SettingDefinition {
+    SettingDefinition::new(
+        INTERNAL_METADATA_KEYS_SETTING,
+        SettingType::Array,
+        Value::Array(Vec::new()),
+    )
+    .with_description("Metadata keys hidden from public readers in addition to `_`-prefixed ones")
+    .with_validator(|value| {
+        let all_keys = value.as_array().is_some_and(|items| {
+            items
+                .iter()
+                .all(|item| item.as_str().is_some_and(|key| !key.trim().is_empty()))
+        });
+        if all_keys {
+            Ok(())
+        } else {
+            Err("expected an array of non-empty strings".to_string())
+        }
+    })
+}
+
+/// Decides which top-level `metadata` keys are hidden from public readers.
+///
+/// A key is internal when it starts with [`INTERNAL_METADATA_PREFIX`] or is
+/// listed in the tenant allowlist. Internal keys are kept for staff (any role
+/// above `Customer`) and stripped for everyone else, including anonymous
+/// readers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataVisibility {
+    internal_keys: BTreeSet<String>,
+}
+
+impl MetadataVisibility {
+    pub fn new(internal_keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            internal_keys: internal_keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Build the policy from tenant settings
+    /// (`{"content": {"internal_metadata_keys": [...]}}`).
+    /// Missing or malformed settings fall back to the naming convention only.
+    pub fn from_tenant_settings(settings: &Value) -> Self {
+        let keys = settings
+            .pointer("/content/internal_metadata_keys")
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Self::new(keys)
+    }
+
+    pub fn is_internal_key(&self, key: &str) -> bool {
+        key.starts_with(INTERNAL_METADATA_PREFIX) || self.internal_keys.contains(key)
+    }
+
+    pub fn can_view_internal(security: &SecurityContext) -> bool {
+        !matches!(security.role, UserRole::Customer)
+    }
+
+    /// Strip internal keys from `metadata` unless `security` may see them.
+    pub fn apply(&self, security: &SecurityContext, metadata: &mut Value) {
+        if Self::can_view_internal(security) {
+            return;
+        }
+
+        if let Value::Object(map) = metadata {
+            map.retain(|key, _| !self.is_internal_key(key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn metadata() -> Value {
+        json!({
+            "template": "landing",
+            "_editor_notes": "rewrite hero",
+            "review_state": "pending",
+        })
+    }
+
+    #[test]
+    fn strips_prefixed_and_configured_keys_for_public_readers() {
+        let policy = MetadataVisibility::from_tenant_settings(&json!({
+            "content": { "internal_metadata_keys": ["review_state", " "] }
+        }));
+        let mut value = metadata();
+
+        policy.apply(&SecurityContext::new(UserRole::Customer, None), &mut value);
+
+        assert_eq!(value, json!({ "template": "landing" }));
+    }
+
+    #[test]
+    fn keeps_internal_keys_for_staff() {
+        let policy = MetadataVisibility::new(["review_state"]);
+        let mut value = metadata();
+
+        policy.apply(
+            &SecurityContext::new(UserRole::Manager, Some(Uuid::new_v4())),
+            &mut value,
+        );
+
+        assert_eq!(value, metadata());
+    }
+
+    #[test]
+    fn missing_settings_fall_back_to_naming_convention() {
+        let policy = MetadataVisibility::from_tenant_settings(&json!({}));
+        assert!(policy.is_internal_key("_draft"));
+        assert!(!policy.is_internal_key("review_state"));
+    }
+
+    #[test]
+    fn setting_declaration_accepts_only_string_arrays() {
+        let setting = internal_metadata_keys_setting();
+
+        assert!(setting.validate(&json!(["review_state"])).is_ok());
+        assert!(setting.validate(&json!([""])).is_err());
+        assert!(setting.validate(&json!([1])).is_err());
+        assert!(setting.validate(&json!("review_state")).is_err());
+    }
+}
@@ -4,7 +4,7 @@
 use rustok_content::dto::{BodyInput, CreateNodeInput, NodeTranslationInput, UpdateNodeInput};
 use rustok_content::entities::node::ContentStatus;
 use rustok_content::services::NodeService;
-use rustok_core::{SecurityContext, UserRole};
+use rustok_core::{BodyFormat, SecurityContext, UserRole};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 use rustok_test_utils::MockEventTransport;
@@ -77,7 +77,9 @@ async fn ensure_content_schema(db: &DatabaseConnection) {
             updated_at TEXT NOT NULL,
             published_at TEXT NULL,
             deleted_at TEXT NULL,
-            version INTEGER NOT NULL DEFAULT 1
+            version INTEGER NOT NULL DEFAULT 1,
+            created_by TEXT NULL,
+            updated_by TEXT NULL
         )"
         .to_string(),
     ))
@@ -145,7 +147,7 @@ fn test_node_creation_triggers_event_and_indexing() {
             bodies: vec![BodyInput {
                 locale: "en".to_string(),
                 body: Some("Hello, RusToK!".to_string()),
-                format: Some("markdown".to_string()),
+                format: Some(BodyFormat::Markdown),
             }],
             status: None,
             parent_id: None,
@@ -212,7 +214,7 @@ fn test_node_update_triggers_event() {
             bodies: vec![BodyInput {
                 locale: "en".to_string(),
                 body: Some("Original content".to_string()),
-                format: Some("markdown".to_string()),
+                format: Some(BodyFormat::Markdown),
             }],
             status: None,
             parent_id: None,
@@ -291,7 +293,7 @@ fn test_node_deletion_triggers_event() {
             bodies: vec![BodyInput {
                 locale: "en".to_string(),
                 body: Some("Content to be deleted".to_string()),
-                format: Some("markdown".to_string()),
+                format: Some(BodyFormat::Markdown),
             }],
             status: None,
             parent_id: None,
@@ -357,7 +359,7 @@ fn test_transactional_event_persistence() {
             bodies: vec![BodyInput {
                 locale: "en".to_string(),
                 body: Some("Transactional content".to_string()),
-                format: Some("markdown".to_string()),
+                format: Some(BodyFormat::Markdown),
             }],
             status: None,
             parent_id: None,
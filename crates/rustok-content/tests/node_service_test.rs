@@ -3,16 +3,20 @@
 // and multi-language support for content nodes.
 
 use rustok_content::dto::{
-    BodyInput, CreateNodeInput, ListNodesFilter, NodeTranslationInput, UpdateNodeInput,
+    BodyInput, BulkDeleteCriteria, CreateNodeInput, ListNodesFilter, NodeTranslationInput,
+    UpdateNodeInput,
 };
 use rustok_content::entities::node::ContentStatus;
 use rustok_content::services::NodeService;
-use rustok_content::ContentError;
+use rustok_content::{ContentError, MetadataVisibility};
+use rustok_core::{BodyFormat, CacheBackend, InMemoryCacheBackend, UserRole};
 use rustok_test_utils::{
     db::setup_test_db, helpers::admin_context, helpers::customer_context, helpers::manager_context,
-    helpers::unique_slug, mock_transactional_event_bus,
+    helpers::unique_slug, helpers::user_context, mock_transactional_event_bus,
 };
 use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
 async fn ensure_content_schema(db: &DatabaseConnection) {
@@ -38,7 +42,9 @@ async fn ensure_content_schema(db: &DatabaseConnection) {
             updated_at TEXT NOT NULL,
             published_at TEXT NULL,
             deleted_at TEXT NULL,
-            version INTEGER NOT NULL DEFAULT 1
+            version INTEGER NOT NULL DEFAULT 1,
+            created_by TEXT NULL,
+            updated_by TEXT NULL
         )"
         .to_string(),
     ))
@@ -100,7 +106,7 @@ fn create_test_input() -> CreateNodeInput {
         bodies: vec![BodyInput {
             locale: "en".to_string(),
             body: Some("# Test Content\n\nThis is test content.".to_string()),
-            format: Some("markdown".to_string()),
+            format: Some(BodyFormat::Markdown),
         }],
         status: Some(ContentStatus::Draft),
         parent_id: None,
@@ -230,6 +236,77 @@ async fn test_update_node_success() {
     assert_eq!(updated.status, ContentStatus::Published);
 }
 
+#[tokio::test]
+async fn test_read_cache_is_evicted_on_update() {
+    let (_db, service) = setup().await;
+    let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+    let service = service.with_read_cache(cache.clone());
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let node = service
+        .create_node(tenant_id, security.clone(), create_test_input())
+        .await
+        .unwrap();
+    service.get_node(tenant_id, node.id).await.unwrap();
+    assert_eq!(cache.stats().entries, 1);
+
+    let update_input = UpdateNodeInput {
+        translations: Some(vec![NodeTranslationInput {
+            locale: "en".to_string(),
+            title: Some("Cached Then Updated".to_string()),
+            slug: None,
+            excerpt: None,
+        }]),
+        ..UpdateNodeInput::default()
+    };
+    service
+        .update_node(tenant_id, node.id, security.clone(), update_input)
+        .await
+        .unwrap();
+
+    let fetched = service.get_node(tenant_id, node.id).await.unwrap();
+    assert_eq!(
+        fetched.translations[0].title,
+        Some("Cached Then Updated".to_string())
+    );
+
+    service
+        .publish_nodes(tenant_id, security.clone(), &[node.id])
+        .await
+        .unwrap();
+    let fetched = service.get_node(tenant_id, node.id).await.unwrap();
+    assert_eq!(fetched.status, ContentStatus::Published);
+
+    service
+        .delete_node(tenant_id, node.id, security)
+        .await
+        .unwrap();
+    assert!(matches!(
+        service.get_node(tenant_id, node.id).await,
+        Err(ContentError::NodeNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_read_cache_is_scoped_to_tenant() {
+    let (_db, service) = setup().await;
+    let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+    let service = service.with_read_cache(cache);
+    let tenant_id = Uuid::new_v4();
+
+    let node = service
+        .create_node(tenant_id, admin_context(), create_test_input())
+        .await
+        .unwrap();
+    service.get_node(tenant_id, node.id).await.unwrap();
+
+    assert!(matches!(
+        service.get_node(Uuid::new_v4(), node.id).await,
+        Err(ContentError::NodeNotFound(_))
+    ));
+}
+
 #[tokio::test]
 async fn test_delete_node_success() {
     let (_db, service) = setup().await;
@@ -249,6 +326,128 @@ async fn test_delete_node_success() {
     assert!(get_result.is_err());
 }
 
+#[tokio::test]
+async fn test_bulk_delete_removes_prepared_set() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    for _ in 0..2 {
+        service
+            .create_node(tenant_id, security.clone(), create_test_input())
+            .await
+            .unwrap();
+    }
+    let mut published = create_test_input();
+    published.status = Some(ContentStatus::Published);
+    let kept = service
+        .create_node(tenant_id, security.clone(), published)
+        .await
+        .unwrap();
+
+    let criteria = BulkDeleteCriteria {
+        status: Some(ContentStatus::Draft),
+        ..Default::default()
+    };
+    let plan = service
+        .prepare_bulk_delete(tenant_id, security.clone(), criteria)
+        .await
+        .unwrap();
+    assert_eq!(plan.affected_count, 2);
+
+    let deleted = service
+        .execute_bulk_delete(tenant_id, security, &plan.token)
+        .await
+        .unwrap();
+    assert_eq!(deleted, 2);
+    assert!(service.get_node(tenant_id, kept.id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_bulk_delete_rejects_grown_set_and_bad_tokens() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let node = service
+        .create_node(tenant_id, security.clone(), create_test_input())
+        .await
+        .unwrap();
+
+    let empty = service
+        .prepare_bulk_delete(tenant_id, security.clone(), BulkDeleteCriteria::default())
+        .await;
+    assert!(matches!(empty, Err(ContentError::Validation(_))));
+
+    let criteria = BulkDeleteCriteria {
+        kind: Some("post".to_string()),
+        ..Default::default()
+    };
+    let plan = service
+        .prepare_bulk_delete(tenant_id, security.clone(), criteria)
+        .await
+        .unwrap();
+    assert_eq!(plan.affected_count, 1);
+
+    let other_tenant = service
+        .execute_bulk_delete(Uuid::new_v4(), security.clone(), &plan.token)
+        .await;
+    assert!(matches!(other_tenant, Err(ContentError::Forbidden(_))));
+
+    let tampered = format!("{}0", plan.token);
+    let tampered = service
+        .execute_bulk_delete(tenant_id, security.clone(), &tampered)
+        .await;
+    assert!(matches!(tampered, Err(ContentError::Validation(_))));
+
+    service
+        .create_node(tenant_id, security.clone(), create_test_input())
+        .await
+        .unwrap();
+    let grown = service
+        .execute_bulk_delete(tenant_id, security, &plan.token)
+        .await;
+    assert!(matches!(grown, Err(ContentError::Validation(_))));
+    assert!(service.get_node(tenant_id, node.id).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_node_records_created_and_updated_by() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let author = user_context(UserRole::Admin, Uuid::new_v4());
+    let editor = user_context(UserRole::Admin, Uuid::new_v4());
+
+    let node = service
+        .create_node(tenant_id, author.clone(), create_test_input())
+        .await
+        .unwrap();
+    assert_eq!(node.created_by, author.user_id);
+    assert_eq!(node.updated_by, author.user_id);
+
+    let updated = service
+        .update_node(
+            tenant_id,
+            node.id,
+            editor.clone(),
+            UpdateNodeInput {
+                metadata: Some(serde_json::json!({"featured": true})),
+                ..UpdateNodeInput::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.created_by, author.user_id);
+    assert_eq!(updated.updated_by, editor.user_id);
+
+    let public = service
+        .get_node_for(tenant_id, &customer_context(), node.id)
+        .await
+        .unwrap();
+    assert_eq!(public.created_by, None);
+    assert_eq!(public.updated_by, None);
+}
+
 // =============================================================================
 // Multi-Language Translation Tests
 // =============================================================================
@@ -320,6 +519,53 @@ async fn test_get_by_slug_success() {
     assert_eq!(node.translations[0].slug, Some(slug));
 }
 
+#[tokio::test]
+async fn test_get_by_slug_for_strips_internal_fields_for_customers() {
+    let db = setup_test_db().await;
+    ensure_content_schema(&db).await;
+    let service = NodeService::new(db.clone(), mock_transactional_event_bus())
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(
+            &serde_json::json!({"content": {"internal_metadata_keys": ["moderation_notes"]}}),
+        ));
+    let tenant_id = Uuid::new_v4();
+
+    let slug = unique_slug("test-post");
+    let mut input = create_test_input();
+    input.translations[0].slug = Some(slug.clone());
+    input.metadata = serde_json::json!({
+        "featured": true,
+        "moderation_notes": "flagged",
+        "_source": "import"
+    });
+    service
+        .create_node(tenant_id, admin_context(), input)
+        .await
+        .unwrap();
+
+    let public = service
+        .get_by_slug_for(tenant_id, &customer_context(), "post", "en", &slug)
+        .await
+        .unwrap()
+        .expect("node should exist for slug");
+    assert_eq!(public.metadata, serde_json::json!({"featured": true}));
+    assert_eq!(public.created_by, None);
+    assert_eq!(public.updated_by, None);
+
+    let staff = service
+        .get_by_slug_for(tenant_id, &manager_context(), "post", "en", &slug)
+        .await
+        .unwrap()
+        .expect("node should exist for slug");
+    assert_eq!(staff.metadata["moderation_notes"], "flagged");
+    assert!(staff.created_by.is_some());
+
+    let missing = service
+        .get_by_slug_for(tenant_id, &customer_context(), "post", "en", "no-such-slug")
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
 // =============================================================================
 // Content Status & Publishing Tests
 // =============================================================================
@@ -387,6 +633,104 @@ async fn test_unpublish_node() {
     assert_eq!(unpublished.status, ContentStatus::Draft);
 }
 
+#[tokio::test]
+async fn test_publish_nodes_reports_per_id_results() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let mut draft_ids = Vec::new();
+    for _ in 0..2 {
+        let mut input = create_test_input();
+        input.status = Some(ContentStatus::Draft);
+        let node = service
+            .create_node(tenant_id, security.clone(), input)
+            .await
+            .unwrap();
+        draft_ids.push(node.id);
+    }
+    let missing_id = Uuid::new_v4();
+    let ids = vec![draft_ids[0], missing_id, draft_ids[1]];
+
+    let results = service
+        .publish_nodes(tenant_id, security.clone(), &ids)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(ContentError::NodeNotFound(_))));
+    assert!(results[2].is_ok());
+    for id in draft_ids {
+        let node = service.get_node(tenant_id, id).await.unwrap();
+        assert_eq!(node.status, ContentStatus::Published);
+        assert!(node.published_at.is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_publish_nodes_keeps_batch_after_failed_write() {
+    let (db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let security = admin_context();
+
+    let mut ids = Vec::new();
+    for position in [0, 99, 1] {
+        let mut input = create_test_input();
+        input.position = Some(position);
+        let node = service
+            .create_node(tenant_id, security.clone(), input)
+            .await
+            .unwrap();
+        ids.push(node.id);
+    }
+    db.execute_unprepared(
+        "CREATE TRIGGER reject_position_99 BEFORE UPDATE ON nodes \
+         WHEN OLD.position = 99 BEGIN SELECT RAISE(ABORT, 'rejected'); END",
+    )
+    .await
+    .unwrap();
+
+    let results = service
+        .publish_nodes(tenant_id, security, &ids)
+        .await
+        .unwrap();
+
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(ContentError::Database(_))));
+    assert!(results[2].is_ok());
+    for (id, expected) in ids.iter().zip([
+        ContentStatus::Published,
+        ContentStatus::Draft,
+        ContentStatus::Published,
+    ]) {
+        let node = service.get_node(tenant_id, *id).await.unwrap();
+        assert_eq!(node.status, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_publish_nodes_checks_permissions_per_node() {
+    let (_db, service) = setup().await;
+    let tenant_id = Uuid::new_v4();
+
+    let mut input = create_test_input();
+    input.status = Some(ContentStatus::Draft);
+    let node = service
+        .create_node(tenant_id, admin_context(), input)
+        .await
+        .unwrap();
+
+    let results = service
+        .publish_nodes(tenant_id, customer_context(), &[node.id])
+        .await
+        .unwrap();
+
+    assert!(matches!(results[0], Err(ContentError::Forbidden(_))));
+    let node = service.get_node(tenant_id, node.id).await.unwrap();
+    assert_eq!(node.status, ContentStatus::Draft);
+}
+
 // =============================================================================
 // Hierarchical Content Tests
 // =============================================================================
@@ -469,9 +813,9 @@ async fn test_list_nodes_empty() {
     let result = service.list_nodes(tenant_id, security, filter).await;
 
     assert!(result.is_ok());
-    let (nodes, total) = result.unwrap();
-    assert_eq!(nodes.len(), 0);
-    assert_eq!(total, 0);
+    let nodes = result.unwrap();
+    assert_eq!(nodes.items.len(), 0);
+    assert_eq!(nodes.total, Some(0));
 }
 
 #[tokio::test]
@@ -499,9 +843,9 @@ async fn test_list_nodes_with_filter() {
     let result = service.list_nodes(tenant_id, security, filter).await;
 
     assert!(result.is_ok());
-    let (nodes, total) = result.unwrap();
-    assert_eq!(nodes.len(), 5);
-    assert_eq!(total, 5);
+    let nodes = result.unwrap();
+    assert_eq!(nodes.items.len(), 5);
+    assert_eq!(nodes.total, Some(5));
 }
 
 #[tokio::test]
@@ -529,9 +873,9 @@ async fn test_list_nodes_pagination() {
         .list_nodes(tenant_id, security.clone(), filter_page1)
         .await;
     assert!(result1.is_ok());
-    let (nodes1, total1) = result1.unwrap();
-    assert_eq!(nodes1.len(), 3);
-    assert_eq!(total1, 7);
+    let nodes1 = result1.unwrap();
+    assert_eq!(nodes1.items.len(), 3);
+    assert_eq!(nodes1.total, Some(7));
 
     let filter_page2 = ListNodesFilter {
         page: 2,
@@ -541,11 +885,11 @@ async fn test_list_nodes_pagination() {
 
     let result2 = service.list_nodes(tenant_id, security, filter_page2).await;
     assert!(result2.is_ok());
-    let (nodes2, total2) = result2.unwrap();
-    assert_eq!(nodes2.len(), 3);
-    assert_eq!(total2, 7);
+    let nodes2 = result2.unwrap();
+    assert_eq!(nodes2.items.len(), 3);
+    assert_eq!(nodes2.total, Some(7));
 
-    assert_ne!(nodes1[0].id, nodes2[0].id);
+    assert_ne!(nodes1.items[0].id, nodes2.items[0].id);
 }
 
 // =============================================================================
@@ -824,7 +1168,7 @@ async fn test_node_with_multiple_body_locales() {
     input.bodies.push(BodyInput {
         locale: "ru".to_string(),
         body: Some("# Русский контент\n\nЭто русский текст.".to_string()),
-        format: Some("markdown".to_string()),
+        format: Some(BodyFormat::Markdown),
     });
 
     let result = service.create_node(tenant_id, security, input).await;
@@ -902,9 +1246,9 @@ async fn test_filter_by_status() {
     let result = service.list_nodes(tenant_id, security, filter).await;
 
     assert!(result.is_ok());
-    let (nodes, total) = result.unwrap();
-    assert_eq!(total, 1);
-    assert_eq!(nodes[0].status, ContentStatus::Published);
+    let nodes = result.unwrap();
+    assert_eq!(nodes.total, Some(1));
+    assert_eq!(nodes.items[0].status, ContentStatus::Published);
 }
 
 #[tokio::test]
@@ -929,10 +1273,11 @@ async fn test_list_nodes_includes_metadata_and_category_id() {
         ..Default::default()
     };
 
-    let (items, _) = service
+    let items = service
         .list_nodes(tenant_id, security, filter)
         .await
-        .unwrap();
+        .unwrap()
+        .items;
 
     assert_eq!(items.len(), 1);
     assert_eq!(items[0].category_id, Some(category_id));
@@ -1017,10 +1362,11 @@ async fn test_list_nodes_does_not_leak_across_tenants() {
         per_page: 10,
         ..Default::default()
     };
-    let (items, _) = service
+    let items = service
         .list_nodes(tenant_b, security, filter)
         .await
-        .unwrap();
+        .unwrap()
+        .items;
     assert!(
         items.is_empty(),
         "tenant_b must see zero nodes, not tenant_a's"
@@ -1044,7 +1390,7 @@ async fn test_create_node_rejects_unsafe_rt_json_payload() {
             })
             .to_string(),
         ),
-        format: Some("rt_json".to_string()),
+        format: Some(BodyFormat::RtJsonV1),
     }];
 
     let result = service.create_node(tenant_id, security, input).await;
@@ -1073,7 +1419,7 @@ async fn test_create_node_sanitizes_unknown_rt_json_nodes() {
             })
             .to_string(),
         ),
-        format: Some("rt_json".to_string()),
+        format: Some(BodyFormat::RtJsonV1),
     }];
 
     let created = service
@@ -1106,7 +1452,7 @@ async fn test_create_node_rejects_oversized_rt_json_payload() {
             })
             .to_string(),
         ),
-        format: Some("rt_json".to_string()),
+        format: Some(BodyFormat::RtJsonV1),
     }];
 
     let result = service.create_node(tenant_id, security, input).await;
@@ -1130,7 +1476,7 @@ async fn test_create_node_accepts_rt_json_v1_and_keeps_canonical_format() {
             })
             .to_string(),
         ),
-        format: Some("rt_json_v1".to_string()),
+        format: Some(BodyFormat::RtJsonV1),
     }];
 
     let created = service
@@ -1160,7 +1506,7 @@ async fn test_create_node_rt_json_alias_payload_gets_wrapped_to_v1() {
             })
             .to_string(),
         ),
-        format: Some("rt_json".to_string()),
+        format: Some(BodyFormat::RtJsonV1),
     }];
 
     let created = service
@@ -37,6 +37,8 @@ use std::time::Duration;
 
 use futures::stream::{FuturesUnordered, StreamExt};
 
+use crate::resilience::BackoffPolicy;
+
 /// Run futures in parallel with a limit on concurrent execution
 pub async fn parallel<T, F, Fut, E>(
     items: Vec<T>,
@@ -146,19 +148,19 @@ impl BackoffConfig {
         self
     }
 
-    /// Calculate delay for a specific attempt
-    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
-        if attempt == 0 {
-            return Duration::ZERO;
+    /// Equivalent [`BackoffPolicy`]
+    pub fn policy(&self) -> BackoffPolicy {
+        BackoffPolicy::ExponentialJittered {
+            base: self.initial_delay,
+            max: self.max_delay,
+            multiplier: self.multiplier,
+            jitter: self.jitter,
         }
+    }
 
-        let base_delay =
-            self.initial_delay.as_millis() as f64 * self.multiplier.powi(attempt as i32 - 1);
-
-        let jitter_amount = base_delay * self.jitter * (rand::random::<f64>() * 2.0 - 1.0);
-        let delay_ms = (base_delay + jitter_amount).min(self.max_delay.as_millis() as f64) as u64;
-
-        Duration::from_millis(delay_ms)
+    /// Calculate delay for a specific attempt
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        self.policy().delay_for_attempt(attempt)
     }
 }
 
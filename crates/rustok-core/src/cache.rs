@@ -1,10 +1,13 @@
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
 use async_trait::async_trait;
 use moka::future::Cache;
+use moka::notification::RemovalCause;
 use moka::Expiry;
+use rustok_telemetry::metrics::{EvictionReason, OpResult};
 
 use crate::context::CacheBackend;
 #[cfg(feature = "redis-cache")]
@@ -22,6 +25,14 @@ pub struct CacheStats {
 pub struct InMemoryCacheBackend {
     cache: Cache<String, InMemoryCacheValue>,
     default_ttl: Duration,
+    evictions: Arc<EvictionCounts>,
+}
+
+/// Entries moka dropped on its own, as reported by its eviction listener.
+#[derive(Default)]
+struct EvictionCounts {
+    expired: AtomicU64,
+    capacity: AtomicU64,
 }
 
 #[derive(Clone)]
@@ -55,16 +66,73 @@ impl Expiry<String, InMemoryCacheValue> for InMemoryCacheExpiry {
 
 impl InMemoryCacheBackend {
     pub fn new(ttl: Duration, max_capacity: u64) -> Self {
+        Self::build(None, ttl, max_capacity)
+    }
+
+    /// Like [`new`](Self::new), but also reports TTL and capacity evictions to
+    /// `rustok_cache_evictions_total` under `name`. Explicit invalidations are
+    /// reported by [`InstrumentedCacheBackend`].
+    pub fn named(name: impl Into<String>, ttl: Duration, max_capacity: u64) -> Self {
+        Self::build(Some(name.into()), ttl, max_capacity)
+    }
+
+    fn build(name: Option<String>, ttl: Duration, max_capacity: u64) -> Self {
+        let evictions = Arc::new(EvictionCounts::default());
+        let counts = evictions.clone();
         let cache = Cache::builder()
             .expire_after(InMemoryCacheExpiry)
             .max_capacity(max_capacity)
+            .eviction_listener(move |_key, _value, cause| {
+                let reason = match cause {
+                    RemovalCause::Expired => {
+                        counts.expired.fetch_add(1, Ordering::Relaxed);
+                        EvictionReason::Ttl
+                    }
+                    RemovalCause::Size => {
+                        counts.capacity.fetch_add(1, Ordering::Relaxed);
+                        EvictionReason::Capacity
+                    }
+                    RemovalCause::Explicit | RemovalCause::Replaced => return,
+                };
+                if let Some(name) = &name {
+                    rustok_telemetry::metrics::record_cache_eviction(name, reason);
+                }
+            })
             .build();
 
         Self {
             cache,
             default_ttl: ttl,
+            evictions,
         }
     }
+
+    /// Purge entries whose TTL has passed and return how many were removed.
+    ///
+    /// Expired entries are never returned by `get`, but moka only drops them
+    /// during its housekeeping; this runs it now.
+    pub async fn evict_expired(&self) -> u64 {
+        let before = self.evictions.expired.load(Ordering::Relaxed);
+        self.cache.run_pending_tasks().await;
+        self.evictions.expired.load(Ordering::Relaxed) - before
+    }
+
+    /// Call [`evict_expired`](Self::evict_expired) every `interval` until the
+    /// cache is dropped.
+    pub fn spawn_sweeper(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let cache = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let Some(cache) = cache.upgrade() else {
+                    break;
+                };
+                cache.evict_expired().await;
+            }
+        })
+    }
 }
 
 #[cfg(feature = "redis-cache")]
@@ -113,6 +181,20 @@ impl RedisCacheBackend {
             format!("{}:{key}", self.prefix)
         }
     }
+
+    /// `SCAN MATCH` pattern for every key under `prefix`, with glob
+    /// metacharacters in the prefix escaped so they match literally.
+    fn prefix_pattern(&self, prefix: &str) -> String {
+        let mut pattern = String::new();
+        for ch in self.key(prefix).chars() {
+            if matches!(ch, '*' | '?' | '[' | ']' | '\\') {
+                pattern.push('\\');
+            }
+            pattern.push(ch);
+        }
+        pattern.push('*');
+        pattern
+    }
 }
 
 #[async_trait]
@@ -147,9 +229,24 @@ impl CacheBackend for InMemoryCacheBackend {
         Ok(())
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        let keys: Vec<Arc<String>> = self
+            .cache
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, _)| key)
+            .collect();
+        for key in keys {
+            self.cache.invalidate(key.as_str()).await;
+        }
+        Ok(())
+    }
+
     fn stats(&self) -> CacheStats {
         CacheStats {
             entries: self.cache.iter().count() as u64,
+            evictions: self.evictions.expired.load(Ordering::Relaxed)
+                + self.evictions.capacity.load(Ordering::Relaxed),
             ..CacheStats::default()
         }
     }
@@ -262,6 +359,47 @@ impl CacheBackend for RedisCacheBackend {
             })
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        let mut manager = self.manager.clone();
+        let pattern = self.prefix_pattern(prefix);
+
+        self.circuit_breaker
+            .call(|| async move {
+                let mut cursor: u64 = 0;
+                loop {
+                    let (next, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                        .arg(cursor)
+                        .arg("MATCH")
+                        .arg(&pattern)
+                        .arg("COUNT")
+                        .arg(500)
+                        .query_async(&mut manager)
+                        .await
+                        .map_err(|err| crate::Error::Cache(err.to_string()))?;
+                    if !keys.is_empty() {
+                        redis::cmd("DEL")
+                            .arg(keys)
+                            .query_async::<()>(&mut manager)
+                            .await
+                            .map_err(|err| crate::Error::Cache(err.to_string()))?;
+                    }
+                    if next == 0 {
+                        break;
+                    }
+                    cursor = next;
+                }
+                Ok::<(), crate::Error>(())
+            })
+            .await
+            .map_err(|e| match e {
+                CircuitBreakerError::Open => {
+                    tracing::debug!("Redis cache prefix DEL failed: circuit breaker open");
+                    crate::Error::Cache("Redis unavailable (circuit breaker open)".to_string())
+                }
+                CircuitBreakerError::Upstream(err) => err,
+            })
+    }
+
     fn stats(&self) -> CacheStats {
         CacheStats::default()
     }
@@ -353,11 +491,160 @@ impl CacheBackend for FallbackCacheBackend {
         }
     }
 
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        let _ = self.fallback.invalidate_prefix(prefix).await;
+
+        match self.primary.invalidate_prefix(prefix).await {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                tracing::debug!(error = %e, prefix, "Primary cache INVALIDATE_PREFIX failed, in-memory entries removed");
+                Ok(())
+            }
+        }
+    }
+
     fn stats(&self) -> CacheStats {
         self.primary.stats()
     }
 }
 
+/// `InstrumentedCacheBackend` reports every operation of the wrapped backend to the
+/// `rustok_cache_*` telemetry series, labelled with the cache `name`. Hits and misses are
+/// also counted locally so `stats()` and the hit-rate gauge reflect real traffic.
+///
+/// `CacheService` wraps every backend it hands out, so services get metrics without
+/// recording them by hand.
+///
+/// # Example
+/// ```rust,ignore
+/// let memory = Arc::new(InMemoryCacheBackend::new(ttl, 1000));
+/// let cache: Arc<dyn CacheBackend> = Arc::new(InstrumentedCacheBackend::new("content_nodes", memory));
+/// ```
+pub struct InstrumentedCacheBackend {
+    name: String,
+    inner: Arc<dyn CacheBackend>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl InstrumentedCacheBackend {
+    pub fn new(name: impl Into<String>, inner: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            name: name.into(),
+            inner,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn observe<T>(&self, operation: &str, started: Instant, result: &Result<T>) {
+        let outcome = if result.is_ok() {
+            OpResult::Success
+        } else {
+            OpResult::Failure
+        };
+        rustok_telemetry::metrics::record_cache_operation(&self.name, operation, outcome);
+        rustok_telemetry::metrics::record_cache_duration(
+            &self.name,
+            operation,
+            started.elapsed().as_secs_f64(),
+        );
+    }
+
+    fn record_lookup(&self, hit: bool) {
+        let (hits, misses) = if hit {
+            (
+                self.hits.fetch_add(1, Ordering::Relaxed) + 1,
+                self.misses.load(Ordering::Relaxed),
+            )
+        } else {
+            (
+                self.hits.load(Ordering::Relaxed),
+                self.misses.fetch_add(1, Ordering::Relaxed) + 1,
+            )
+        };
+        rustok_telemetry::metrics::update_cache_hit_rate(
+            &self.name,
+            hits as f64 / (hits + misses) as f64,
+        );
+    }
+}
+
+#[async_trait]
+impl CacheBackend for InstrumentedCacheBackend {
+    async fn health(&self) -> Result<()> {
+        self.inner.health().await
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let started = Instant::now();
+        let result = self.inner.get(key).await;
+        let outcome = match &result {
+            Ok(Some(_)) => OpResult::Hit,
+            Ok(None) => OpResult::Miss,
+            Err(_) => OpResult::Failure,
+        };
+        rustok_telemetry::metrics::record_cache_operation(&self.name, "get", outcome);
+        rustok_telemetry::metrics::record_cache_duration(
+            &self.name,
+            "get",
+            started.elapsed().as_secs_f64(),
+        );
+        if let Ok(value) = &result {
+            self.record_lookup(value.is_some());
+        }
+        result
+    }
+
+    async fn set(&self, key: String, value: Vec<u8>) -> Result<()> {
+        let started = Instant::now();
+        let result = self.inner.set(key, value).await;
+        self.observe("set", started, &result);
+        result
+    }
+
+    async fn set_with_ttl(&self, key: String, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        let started = Instant::now();
+        let result = self.inner.set_with_ttl(key, value, ttl).await;
+        self.observe("set", started, &result);
+        result
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<()> {
+        let started = Instant::now();
+        let result = self.inner.invalidate(key).await;
+        self.observe("delete", started, &result);
+        if result.is_ok() {
+            rustok_telemetry::metrics::record_cache_eviction(&self.name, EvictionReason::Explicit);
+        }
+        result
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()> {
+        let started = Instant::now();
+        let result = self.inner.invalidate_prefix(prefix).await;
+        self.observe("invalidate_prefix", started, &result);
+        if result.is_ok() {
+            rustok_telemetry::metrics::record_cache_eviction(&self.name, EvictionReason::Explicit);
+        }
+        result
+    }
+
+    fn stats(&self) -> CacheStats {
+        let stats = CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..self.inner.stats()
+        };
+        rustok_telemetry::metrics::update_cache_size(&self.name, stats.entries as i64);
+        stats
+    }
+}
+
 #[cfg(test)]
 #[path = "cache_tests.rs"]
 mod tests;
@@ -112,7 +112,8 @@ mod redis_circuit_breaker_tests {
 #[cfg(test)]
 mod in_memory_cache_tests {
     use crate::context::CacheBackend;
-    use crate::InMemoryCacheBackend;
+    use crate::{InMemoryCacheBackend, InstrumentedCacheBackend};
+    use std::sync::Arc;
     use std::time::Duration;
 
     #[tokio::test]
@@ -211,4 +212,90 @@ mod in_memory_cache_tests {
         let expired = cache.get("default").await.unwrap();
         assert_eq!(expired, None);
     }
+
+    #[tokio::test]
+    async fn test_in_memory_cache_evict_expired_purges_stale_entries() {
+        let cache =
+            InMemoryCacheBackend::named("test_evict_expired", Duration::from_secs(300), 100);
+
+        cache
+            .set_with_ttl(
+                "short".to_string(),
+                b"value".to_vec(),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap();
+        cache
+            .set("long".to_string(), b"value".to_vec())
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(90)).await;
+
+        assert_eq!(cache.evict_expired().await, 1);
+        assert_eq!(cache.evict_expired().await, 0);
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(
+            rustok_telemetry::metrics::CACHE_EVICTIONS_TOTAL
+                .with_label_values(&["test_evict_expired", "ttl"])
+                .get(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_cache_sweeper_evicts_without_access() {
+        let cache = Arc::new(InMemoryCacheBackend::new(Duration::from_millis(30), 100));
+        cache
+            .set("key".to_string(), b"value".to_vec())
+            .await
+            .unwrap();
+
+        let sweeper = cache.spawn_sweeper(Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(120)).await;
+
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.stats().entries, 0);
+        drop(cache);
+        tokio::time::timeout(Duration::from_secs(1), sweeper)
+            .await
+            .expect("sweeper should stop once the cache is dropped")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_cache_invalidate_prefix() {
+        let cache = InMemoryCacheBackend::new(Duration::from_secs(300), 100);
+
+        for key in ["tenant-a:1", "tenant-a:2", "tenant-b:1"] {
+            cache.set(key.to_string(), b"value".to_vec()).await.unwrap();
+        }
+
+        cache.invalidate_prefix("tenant-a:").await.unwrap();
+
+        assert_eq!(cache.get("tenant-a:1").await.unwrap(), None);
+        assert_eq!(cache.get("tenant-a:2").await.unwrap(), None);
+        assert!(cache.get("tenant-b:1").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_instrumented_cache_counts_hits_and_misses() {
+        let inner = Arc::new(InMemoryCacheBackend::new(Duration::from_secs(300), 100));
+        let cache = InstrumentedCacheBackend::new("test_instrumented", inner);
+
+        cache
+            .set("key".to_string(), b"value".to_vec())
+            .await
+            .unwrap();
+        assert!(cache.get("key").await.unwrap().is_some());
+        assert!(cache.get("missing").await.unwrap().is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.entries, 1);
+    }
 }
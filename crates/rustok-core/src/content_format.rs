@@ -1,4 +1,9 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use utoipa::ToSchema;
 
 use crate::grapesjs::validate_grapesjs_project;
 use crate::rt_json::{validate_and_sanitize_rt_json, RtJsonValidationConfig};
@@ -8,6 +13,78 @@ pub const CONTENT_FORMAT_RT_JSON_V1: &str = "rt_json_v1";
 pub const CONTENT_FORMAT_GRAPESJS_V1: &str = "grapesjs_v1";
 const LEGACY_CONTENT_FORMAT_RT_JSON: &str = "rt_json";
 
+/// Format of a stored content body.
+///
+/// Serialized (and stored in `format` columns) as its canonical lowercase
+/// string; `rt_json` is accepted as a legacy alias of `rt_json_v1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BodyFormat {
+    #[default]
+    Markdown,
+    Html,
+    #[serde(rename = "plain", alias = "plain_text")]
+    PlainText,
+    Json,
+    #[serde(alias = "rt_json")]
+    RtJsonV1,
+    GrapesjsV1,
+}
+
+impl BodyFormat {
+    pub const ALL: [Self; 6] = [
+        Self::Markdown,
+        Self::Html,
+        Self::PlainText,
+        Self::Json,
+        Self::RtJsonV1,
+        Self::GrapesjsV1,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Markdown => CONTENT_FORMAT_MARKDOWN,
+            Self::Html => "html",
+            Self::PlainText => "plain",
+            Self::Json => "json",
+            Self::RtJsonV1 => CONTENT_FORMAT_RT_JSON_V1,
+            Self::GrapesjsV1 => CONTENT_FORMAT_GRAPESJS_V1,
+        }
+    }
+
+    /// Whether the body is a JSON document validated by
+    /// [`prepare_content_payload`] rather than free text.
+    pub const fn is_structured(self) -> bool {
+        matches!(self, Self::RtJsonV1 | Self::GrapesjsV1)
+    }
+}
+
+impl fmt::Display for BodyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unsupported body format '{0}'")]
+pub struct BodyFormatParseError(pub String);
+
+impl FromStr for BodyFormat {
+    type Err = BodyFormatParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            LEGACY_CONTENT_FORMAT_RT_JSON => Ok(Self::RtJsonV1),
+            "plain_text" => Ok(Self::PlainText),
+            other => Self::ALL
+                .into_iter()
+                .find(|format| format.as_str() == other)
+                .ok_or(BodyFormatParseError(normalized)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PreparedContent {
     pub format: String,
@@ -15,20 +92,23 @@ pub struct PreparedContent {
 }
 
 pub fn normalize_content_format(format: Option<&str>) -> Result<String, String> {
-    let normalized = format
-        .unwrap_or(CONTENT_FORMAT_MARKDOWN)
-        .trim()
-        .to_ascii_lowercase();
-
-    match normalized.as_str() {
-        CONTENT_FORMAT_MARKDOWN => Ok(CONTENT_FORMAT_MARKDOWN.to_string()),
-        CONTENT_FORMAT_RT_JSON_V1 | LEGACY_CONTENT_FORMAT_RT_JSON => {
-            Ok(CONTENT_FORMAT_RT_JSON_V1.to_string())
+    let unsupported = |value: &str| {
+        format!(
+            "Unsupported content format '{value}'. Supported formats: markdown, rt_json_v1, grapesjs_v1"
+        )
+    };
+    let parsed = match format {
+        Some(value) => value
+            .parse::<BodyFormat>()
+            .map_err(|error| unsupported(&error.0))?,
+        None => BodyFormat::Markdown,
+    };
+
+    match parsed {
+        BodyFormat::Markdown | BodyFormat::RtJsonV1 | BodyFormat::GrapesjsV1 => {
+            Ok(parsed.as_str().to_string())
         }
-        CONTENT_FORMAT_GRAPESJS_V1 => Ok(CONTENT_FORMAT_GRAPESJS_V1.to_string()),
-        _ => Err(format!(
-            "Unsupported content format '{normalized}'. Supported formats: markdown, rt_json_v1, grapesjs_v1"
-        )),
+        other => Err(unsupported(other.as_str())),
     }
 }
 
@@ -95,6 +175,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn body_format_round_trips_through_string_and_serde() {
+        for format in BodyFormat::ALL {
+            assert_eq!(format.as_str().parse::<BodyFormat>(), Ok(format));
+            assert_eq!(
+                serde_json::to_value(format).expect("serialize"),
+                serde_json::json!(format.as_str())
+            );
+        }
+        assert_eq!(" Markdown ".parse::<BodyFormat>(), Ok(BodyFormat::Markdown));
+        assert_eq!("rt_json".parse::<BodyFormat>(), Ok(BodyFormat::RtJsonV1));
+        assert_eq!(
+            serde_json::from_value::<BodyFormat>(serde_json::json!("rt_json")).expect("alias"),
+            BodyFormat::RtJsonV1
+        );
+        assert!("markdwon".parse::<BodyFormat>().is_err());
+        assert!(serde_json::from_value::<BodyFormat>(serde_json::json!("xml")).is_err());
+    }
+
+    #[test]
+    fn normalize_rejects_formats_outside_the_pipeline() {
+        assert!(normalize_content_format(Some("html")).is_err());
+        assert!(normalize_content_format(Some("xml")).is_err());
+    }
+
     #[test]
     fn normalize_accepts_grapesjs_format() {
         assert_eq!(
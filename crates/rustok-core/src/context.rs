@@ -15,6 +15,8 @@ pub trait CacheBackend: Send + Sync {
     async fn set(&self, key: String, value: Vec<u8>) -> Result<()>;
     async fn set_with_ttl(&self, key: String, value: Vec<u8>, ttl: Duration) -> Result<()>;
     async fn invalidate(&self, key: &str) -> Result<()>;
+    /// Drop every entry whose key starts with `prefix`.
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<()>;
     fn stats(&self) -> CacheStats;
 }
 
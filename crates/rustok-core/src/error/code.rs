@@ -0,0 +1,89 @@
+//! Machine-readable error codes shared by module errors and API layers.
+//!
+//! Codes are stable dotted strings (`{module}.{key}`, e.g. `page.not_found`,
+//! `commerce.insufficient_inventory`). Clients branch on them instead of
+//! matching error messages, and [`crate::i18n::translate`] resolves them to a
+//! localized message.
+
+use super::{Error, ErrorKind, RichError};
+
+/// Error type that exposes a stable machine-readable code.
+pub trait CodedError: std::error::Error {
+    /// Stable dotted code, e.g. `page.not_found`.
+    fn error_code(&self) -> &'static str;
+}
+
+impl ErrorKind {
+    /// Generic dotted code for errors that carry no module-specific code.
+    ///
+    /// Unlike [`ErrorKind::error_code`] (the legacy `SCREAMING_CASE` value kept
+    /// in [`RichError::error_code`]), this follows the [`CodedError`] format.
+    pub fn api_code(&self) -> &'static str {
+        match self {
+            ErrorKind::Validation => "error.validation",
+            ErrorKind::Unauthenticated => "error.unauthenticated",
+            ErrorKind::Forbidden => "error.forbidden",
+            ErrorKind::NotFound => "error.not_found",
+            ErrorKind::Conflict => "error.conflict",
+            ErrorKind::RateLimited => "error.rate_limited",
+            ErrorKind::Database => "error.database",
+            ErrorKind::ExternalService => "error.external_service",
+            ErrorKind::Internal => "error.internal",
+            ErrorKind::Timeout => "error.timeout",
+            ErrorKind::BusinessLogic => "error.business_logic",
+        }
+    }
+}
+
+impl CodedError for Error {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Error::InvalidIdFormat(_) => "error.invalid_id_format",
+            Error::Database(_) => ErrorKind::Database.api_code(),
+            Error::Serialization(_) => "error.serialization",
+            Error::Auth(_) => ErrorKind::Unauthenticated.api_code(),
+            Error::NotFound(_) => ErrorKind::NotFound.api_code(),
+            Error::Forbidden(_) => ErrorKind::Forbidden.api_code(),
+            Error::Cache(_) => "error.cache",
+            Error::Scripting(_) => "error.scripting",
+            Error::Validation(_) => ErrorKind::Validation.api_code(),
+            Error::External(_) => ErrorKind::ExternalService.api_code(),
+            Error::Unavailable(_) => "error.unavailable",
+        }
+    }
+}
+
+impl CodedError for RichError {
+    /// `RichError::error_code` is free-form, so the code falls back to the
+    /// error kind.
+    fn error_code(&self) -> &'static str {
+        self.kind.api_code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_errors_map_to_generic_codes() {
+        assert_eq!(
+            Error::NotFound("node".into()).error_code(),
+            "error.not_found"
+        );
+        assert_eq!(
+            Error::InvalidIdFormat("x".into()).error_code(),
+            "error.invalid_id_format"
+        );
+        assert_eq!(
+            Error::Validation("bad".into()).error_code(),
+            "error.validation"
+        );
+    }
+
+    #[test]
+    fn rich_error_code_follows_kind() {
+        let rich = RichError::new(ErrorKind::Conflict, "duplicate");
+        assert_eq!(CodedError::error_code(&rich), "error.conflict");
+    }
+}
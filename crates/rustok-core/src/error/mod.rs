@@ -139,9 +139,11 @@
 /// - Context addition: ~50ns per field
 /// - JSON serialization: ~1-2μs
 /// - Zero cost when not used (Result<T, E> is optimized)
+pub mod code;
 pub mod context;
 pub mod response;
 
+pub use code::CodedError;
 pub use context::{ErrorContext, ErrorKind, RichError};
 pub use response::{ErrorResponse, FieldError, ValidationErrorBuilder};
 
@@ -181,6 +183,11 @@ pub enum Error {
 
     #[error("External error: {0}")]
     External(String),
+
+    /// A dependency is temporarily unreachable (e.g. reconnecting); callers
+    /// may retry later.
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
 }
 
 // Conversion from old Error to RichError
@@ -197,6 +204,7 @@ impl From<Error> for RichError {
             Error::Scripting(_) => ErrorKind::Internal,
             Error::Validation(_) => ErrorKind::Validation,
             Error::External(_) => ErrorKind::ExternalService,
+            Error::Unavailable(_) => ErrorKind::ExternalService,
         };
 
         RichError::new(kind, err.to_string())
@@ -11,11 +11,96 @@ use super::{DomainEvent, EventEnvelope};
 
 const DEFAULT_CHANNEL_CAPACITY: usize = 128;
 
+/// Default upper bound on a serialized envelope (1 MiB).
+pub const DEFAULT_MAX_EVENT_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventBusError {
+    #[error("Event payload too large: {size} bytes exceeds limit of {limit} bytes")]
+    PayloadTooLarge { size: usize, limit: usize },
+    #[error("Event payload could not be serialized: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl EventBusError {
+    fn metric_label(&self) -> &'static str {
+        match self {
+            EventBusError::PayloadTooLarge { .. } => "payload_too_large",
+            EventBusError::Serialization(_) => "serialization",
+        }
+    }
+}
+
+impl From<EventBusError> for crate::Error {
+    fn from(error: EventBusError) -> Self {
+        match error {
+            EventBusError::PayloadTooLarge { .. } => crate::Error::Validation(error.to_string()),
+            EventBusError::Serialization(error) => crate::Error::Serialization(error),
+        }
+    }
+}
+
+/// Write sink that only counts bytes, so measuring a payload does not
+/// allocate a copy of it.
+struct ByteCounter(usize);
+
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+type EnvelopeFilter = Arc<dyn Fn(&EventEnvelope) -> bool + Send + Sync>;
+
+/// Subscription that only yields envelopes accepted by its filter.
+///
+/// Non-matching envelopes are skipped inside `recv`, so consumers never see
+/// them. `Lagged` counts refer to the underlying channel and may include
+/// envelopes the filter would have skipped anyway.
+pub struct FilteredReceiver {
+    receiver: broadcast::Receiver<EventEnvelope>,
+    filter: EnvelopeFilter,
+}
+
+impl FilteredReceiver {
+    pub async fn recv(&mut self) -> Result<EventEnvelope, broadcast::error::RecvError> {
+        loop {
+            let envelope = self.receiver.recv().await?;
+            if (self.filter)(&envelope) {
+                return Ok(envelope);
+            }
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Result<EventEnvelope, broadcast::error::TryRecvError> {
+        loop {
+            let envelope = self.receiver.try_recv()?;
+            if (self.filter)(&envelope) {
+                return Ok(envelope);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for FilteredReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilteredReceiver")
+            .field("receiver", &self.receiver)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug)]
 pub struct EventBus {
     sender: broadcast::Sender<EventEnvelope>,
     stats: Arc<EventBusStats>,
     backpressure: Option<Arc<BackpressureController>>,
+    max_event_bytes: usize,
 }
 
 #[derive(Debug, Default)]
@@ -50,6 +135,7 @@ impl EventBus {
             sender,
             stats: Arc::new(EventBusStats::default()),
             backpressure: None,
+            max_event_bytes: DEFAULT_MAX_EVENT_BYTES,
         }
     }
 
@@ -60,7 +146,34 @@ impl EventBus {
             sender,
             stats: Arc::new(EventBusStats::default()),
             backpressure: Some(Arc::new(backpressure)),
+            max_event_bytes: DEFAULT_MAX_EVENT_BYTES,
+        }
+    }
+
+    /// Reject envelopes whose JSON serialization exceeds `limit` bytes.
+    pub fn with_max_event_bytes(mut self, limit: usize) -> Self {
+        self.max_event_bytes = limit;
+        self
+    }
+
+    pub fn max_event_bytes(&self) -> usize {
+        self.max_event_bytes
+    }
+
+    /// Serialized size of `envelope`, or `PayloadTooLarge` if it exceeds
+    /// [`Self::max_event_bytes`]. An envelope that cannot be serialized is
+    /// rejected too.
+    pub fn check_payload_size(&self, envelope: &EventEnvelope) -> Result<usize, EventBusError> {
+        let mut counter = ByteCounter(0);
+        serde_json::to_writer(&mut counter, envelope)?;
+        let size = counter.0;
+        if size > self.max_event_bytes {
+            return Err(EventBusError::PayloadTooLarge {
+                size,
+                limit: self.max_event_bytes,
+            });
         }
+        Ok(size)
     }
 
     pub fn stats(&self) -> Arc<EventBusStats> {
@@ -80,6 +193,34 @@ impl EventBus {
         receiver
     }
 
+    /// Subscribe to envelopes accepted by `filter`.
+    pub fn subscribe_filtered<F>(&self, filter: F) -> FilteredReceiver
+    where
+        F: Fn(&EventEnvelope) -> bool + Send + Sync + 'static,
+    {
+        FilteredReceiver {
+            receiver: self.subscribe(),
+            filter: Arc::new(filter),
+        }
+    }
+
+    /// Subscribe to envelopes whose `event_type` is one of `kinds`, e.g.
+    /// `&["node.created"]`.
+    ///
+    /// Filtering happens on the receive side: the broadcast channel still
+    /// delivers every envelope and wakes the subscriber's task, and `recv`
+    /// skips the kinds it was not asked for. The gain is a simpler handler,
+    /// not less work per event.
+    pub fn subscribe_kinds(&self, kinds: &[&str]) -> FilteredReceiver {
+        let kinds: Vec<String> = kinds.iter().map(|kind| kind.to_string()).collect();
+        self.subscribe_filtered(move |envelope| kinds.contains(&envelope.event_type))
+    }
+
+    /// Subscribe to envelopes of a single tenant.
+    pub fn subscribe_tenant(&self, tenant_id: Uuid) -> FilteredReceiver {
+        self.subscribe_filtered(move |envelope| envelope.tenant_id == tenant_id)
+    }
+
     #[tracing::instrument(
         name = "eventbus.publish",
         skip(self, event),
@@ -122,6 +263,22 @@ impl EventBus {
         )
     )]
     pub fn publish_envelope(&self, envelope: EventEnvelope) -> crate::Result<()> {
+        // Reject oversize payloads before they take a backpressure slot or
+        // reach a transport.
+        if let Err(error) = self.check_payload_size(&envelope) {
+            tracing::warn!(
+                error = %error,
+                event_type = envelope.event.event_type(),
+                "Event rejected: invalid payload"
+            );
+            rustok_telemetry::metrics::record_event_error(
+                envelope.event.event_type(),
+                error.metric_label(),
+            );
+            self.stats.events_dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(error.into());
+        }
+
         // Check backpressure if enabled
         if let Some(backpressure) = &self.backpressure {
             if let Err(e) = backpressure.try_acquire() {
@@ -182,6 +339,118 @@ impl Clone for EventBus {
             sender: self.sender.clone(),
             stats: Arc::clone(&self.stats),
             backpressure: self.backpressure.as_ref().map(Arc::clone),
+            max_event_bytes: self.max_event_bytes,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn subscribe_tenant_skips_other_tenants() {
+        let bus = EventBus::new();
+        let tenant_a = Uuid::new_v4();
+        let tenant_b = Uuid::new_v4();
+        let mut receiver = bus.subscribe_tenant(tenant_a);
+
+        let tag_a = Uuid::new_v4();
+        bus.publish(
+            tenant_b,
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        )
+        .unwrap();
+        bus.publish(tenant_a, None, DomainEvent::TagCreated { tag_id: tag_a })
+            .unwrap();
+
+        let envelope = receiver.recv().await.unwrap();
+        assert_eq!(envelope.tenant_id, tenant_a);
+        assert!(matches!(
+            envelope.event,
+            DomainEvent::TagCreated { tag_id } if tag_id == tag_a
+        ));
+        assert!(matches!(
+            receiver.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    #[tokio::test]
+    async fn subscribe_kinds_only_yields_requested_event_types() {
+        let bus = EventBus::new();
+        let tenant_id = Uuid::new_v4();
+        let mut receiver = bus.subscribe_kinds(&["tag.created", "product.created"]);
+
+        let events = [
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+            DomainEvent::TagAttached {
+                tag_id: Uuid::new_v4(),
+                target_type: "node".to_string(),
+                target_id: Uuid::new_v4(),
+            },
+            DomainEvent::ProductCreated {
+                product_id: Uuid::new_v4(),
+            },
+            DomainEvent::TagDetached {
+                tag_id: Uuid::new_v4(),
+                target_type: "node".to_string(),
+                target_id: Uuid::new_v4(),
+            },
+        ];
+        for event in events {
+            bus.publish(tenant_id, None, event).unwrap();
+        }
+
+        assert_eq!(receiver.recv().await.unwrap().event_type, "tag.created");
+        assert_eq!(receiver.recv().await.unwrap().event_type, "product.created");
+        assert!(matches!(
+            receiver.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_payloads_over_max_event_bytes() {
+        let bus = EventBus::new().with_max_event_bytes(512);
+        let mut receiver = bus.subscribe();
+        let tenant_id = Uuid::new_v4();
+
+        let small = EventEnvelope::new(
+            tenant_id,
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        );
+        assert_eq!(
+            bus.check_payload_size(&small).unwrap(),
+            serde_json::to_vec(&small).unwrap().len()
+        );
+
+        let huge = EventEnvelope::new(
+            tenant_id,
+            None,
+            DomainEvent::CustomerErased {
+                customer_id: Uuid::new_v4(),
+                mode: "x".repeat(1024),
+            },
+        );
+        assert!(matches!(
+            bus.check_payload_size(&huge),
+            Err(EventBusError::PayloadTooLarge { limit: 512, size }) if size > 1024
+        ));
+
+        let error = bus.publish_envelope(huge).unwrap_err();
+        assert!(matches!(error, crate::Error::Validation(_)));
+        assert_eq!(bus.stats().events_dropped(), 1);
+
+        bus.publish_envelope(small).unwrap();
+        assert_eq!(receiver.recv().await.unwrap().tenant_id, tenant_id);
+    }
+}
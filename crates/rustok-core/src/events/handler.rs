@@ -1,14 +1,20 @@
 use async_trait::async_trait;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::{broadcast, Semaphore};
-use tokio::task::JoinHandle;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, watch, OwnedSemaphorePermit, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
 use tracing::{debug, error, info, warn, Instrument};
+use uuid::Uuid;
 
 use super::bus::EventBus;
 use super::consumer::EventConsumerRuntime;
 use super::types::{DomainEvent, EventEnvelope};
+use crate::resilience::BackoffPolicy;
 use crate::Error;
 
 pub type HandlerResult = Result<(), Error>;
@@ -32,13 +38,71 @@ pub trait EventHandler: Send + Sync + 'static {
     }
 }
 
+/// Returned when a handler name is already taken on an [`EventDispatcher`].
+/// Use [`EventDispatcher::register_or_replace`] for intentional overrides.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Event handler `{name}` is already registered")]
+pub struct DuplicateHandlerError {
+    pub name: &'static str,
+}
+
+impl From<DuplicateHandlerError> for Error {
+    fn from(error: DuplicateHandlerError) -> Self {
+        Error::Validation(error.to_string())
+    }
+}
+
+/// How [`DispatcherConfig::max_concurrent`] is applied to handler invocations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConcurrencyScope {
+    /// At most `max_concurrent` handler invocations run across all handlers.
+    #[default]
+    Global,
+    /// Each handler gets its own budget of `max_concurrent` invocations.
+    PerHandler,
+}
+
+/// A handler invocation that failed for good: retries were exhausted or the
+/// handler panicked.
+#[derive(Clone, Debug)]
+pub struct HandlerFailure {
+    pub handler: &'static str,
+    pub envelope: EventEnvelope,
+    pub error: String,
+    pub attempts: usize,
+}
+
+/// Callback that receives every [`HandlerFailure`], e.g. to park the
+/// envelope for later inspection instead of dropping it.
+#[derive(Clone)]
+pub struct FailureSink(Arc<dyn Fn(HandlerFailure) + Send + Sync>);
+
+impl FailureSink {
+    pub fn new(sink: impl Fn(HandlerFailure) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(sink))
+    }
+}
+
+impl std::fmt::Debug for FailureSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FailureSink")
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DispatcherConfig {
     pub fail_fast: bool,
+    /// Upper bound on concurrently running handler invocations. Invocations
+    /// over the limit wait for a permit instead of being dropped.
     pub max_concurrent: usize,
+    pub concurrency_scope: ConcurrencyScope,
     pub retry_count: usize,
-    pub retry_delay_ms: u64,
+    /// Delay before each of the `retry_count` retries.
+    pub retry_backoff: BackoffPolicy,
     pub max_queue_depth: usize,
+    /// Receives envelopes whose handler still failed after all retries.
+    pub failure_sink: Option<FailureSink>,
 }
 
 impl Default for DispatcherConfig {
@@ -46,9 +110,11 @@ impl Default for DispatcherConfig {
         Self {
             fail_fast: false,
             max_concurrent: 10,
+            concurrency_scope: ConcurrencyScope::Global,
             retry_count: 0,
-            retry_delay_ms: 100,
+            retry_backoff: BackoffPolicy::Fixed(Duration::from_millis(100)),
             max_queue_depth: 10000,
+            failure_sink: None,
         }
     }
 }
@@ -57,6 +123,7 @@ pub struct EventDispatcher {
     bus: EventBus,
     handlers: Vec<Arc<dyn EventHandler>>,
     config: DispatcherConfig,
+    tenant_id: Option<Uuid>,
 }
 
 impl EventDispatcher {
@@ -65,6 +132,7 @@ impl EventDispatcher {
             bus,
             handlers: Vec::new(),
             config: DispatcherConfig::default(),
+            tenant_id: None,
         }
     }
 
@@ -73,21 +141,72 @@ impl EventDispatcher {
             bus,
             handlers: Vec::new(),
             config,
+            tenant_id: None,
         }
     }
 
-    pub fn register<H: EventHandler>(&mut self, handler: H) -> &mut Self {
-        info!(handler = handler.name(), "Registering event handler");
-        self.handlers.push(Arc::new(handler));
+    /// Only dispatch events of `tenant_id`. Other tenants' events are
+    /// filtered out at the subscription, so handlers never see them.
+    pub fn for_tenant(mut self, tenant_id: Uuid) -> Self {
+        self.tenant_id = Some(tenant_id);
         self
     }
 
-    pub fn register_boxed(&mut self, handler: Arc<dyn EventHandler>) -> &mut Self {
-        info!(handler = handler.name(), "Registering event handler");
+    /// Registers `handler`, rejecting it when a handler with the same
+    /// [`EventHandler::name`] is already registered.
+    pub fn register<H: EventHandler>(
+        &mut self,
+        handler: H,
+    ) -> Result<&mut Self, DuplicateHandlerError> {
+        self.register_boxed(Arc::new(handler))
+    }
+
+    pub fn register_boxed(
+        &mut self,
+        handler: Arc<dyn EventHandler>,
+    ) -> Result<&mut Self, DuplicateHandlerError> {
+        let name = handler.name();
+        if self.position(name).is_some() {
+            warn!(handler = name, "Rejecting duplicate event handler");
+            return Err(DuplicateHandlerError { name });
+        }
+
+        info!(handler = name, "Registering event handler");
         self.handlers.push(handler);
+        Ok(self)
+    }
+
+    /// Registers `handler`, replacing a handler with the same name in place.
+    pub fn register_or_replace<H: EventHandler>(&mut self, handler: H) -> &mut Self {
+        self.register_or_replace_boxed(Arc::new(handler))
+    }
+
+    pub fn register_or_replace_boxed(&mut self, handler: Arc<dyn EventHandler>) -> &mut Self {
+        let name = handler.name();
+        match self.position(name) {
+            Some(index) => {
+                info!(handler = name, "Replacing event handler");
+                self.handlers[index] = handler;
+            }
+            None => {
+                info!(handler = name, "Registering event handler");
+                self.handlers.push(handler);
+            }
+        }
         self
     }
 
+    /// Names of the registered handlers, in registration order.
+    pub fn registered_handlers(&self) -> Vec<&'static str> {
+        self.handlers.iter().map(|handler| handler.name()).collect()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.handlers
+            .iter()
+            .position(|handler| handler.name() == name)
+    }
+
     pub fn handler_count(&self) -> usize {
         self.handlers.len()
     }
@@ -95,72 +214,129 @@ impl EventDispatcher {
     pub fn start(self) -> RunningDispatcher {
         let handlers = Arc::new(self.handlers);
         let config = self.config;
-        let mut receiver = self.bus.subscribe();
+        let mut receiver = match self.tenant_id {
+            Some(tenant_id) => self.bus.subscribe_tenant(tenant_id),
+            None => self.bus.subscribe_filtered(|_| true),
+        };
         let bus = self.bus.clone();
         let backpressure = bus.backpressure();
         let consumer_runtime = EventConsumerRuntime::new("event_dispatcher");
+        let tracker = Arc::new(DispatchTracker::new());
+        let limiter = Arc::new(ConcurrencyLimiter::new(
+            consumer_runtime,
+            &config,
+            handlers.as_slice(),
+        ));
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let loop_tracker = Arc::clone(&tracker);
+        let loop_limiter = Arc::clone(&limiter);
 
         let handle = tokio::spawn(
             async move {
                 consumer_runtime.restarted("startup");
-                info!(handlers = handlers.len(), "Event dispatcher started");
-                let max_concurrent = config.max_concurrent.max(1);
-                let semaphore = Arc::new(Semaphore::new(max_concurrent));
+                let _running = RunningGuard::new(consumer_runtime, handlers.len());
+                // Dropping the set aborts every dispatch still running, so aborting
+                // this loop also stops the handlers it started.
+                let mut dispatches = JoinSet::new();
+                let dispatch = |dispatches: &mut JoinSet<()>, envelope: EventEnvelope| {
+                    dispatches.spawn(Self::dispatch_task(
+                        envelope,
+                        handlers.clone(),
+                        config.clone(),
+                        Arc::clone(&loop_limiter),
+                        backpressure.clone(),
+                        consumer_runtime,
+                        Arc::clone(&loop_tracker),
+                    ));
+                };
 
                 loop {
-                    match receiver.recv().await {
-                        Ok(envelope) => {
-                            let span = tracing::info_span!(
-                                "event_dispatch",
-                                event_type = envelope.event.event_type(),
-                                event_id = %envelope.id,
-                                tenant_id = %envelope.tenant_id
-                            );
-
-                            let bp = backpressure.clone();
-                            let handlers = handlers.clone();
-                            let config = config.clone();
-                            let semaphore = semaphore.clone();
-                            let consumer_runtime = consumer_runtime;
-
-                            tokio::spawn(
-                                async move {
-                                    Self::dispatch_to_handlers(
-                                        envelope,
-                                        handlers,
-                                        config,
-                                        semaphore,
-                                        bp,
-                                        consumer_runtime,
-                                    )
-                                    .await;
+                    tokio::select! {
+                        biased;
+                        Ok(()) = shutdown_rx.changed() => {
+                            // Stop accepting new events, but hand everything already
+                            // buffered in the subscription over to the handlers.
+                            loop {
+                                match receiver.try_recv() {
+                                    Ok(envelope) => dispatch(&mut dispatches, envelope),
+                                    Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                                        consumer_runtime.lagged(skipped);
+                                    }
+                                    Err(_) => break,
                                 }
-                                .instrument(span),
-                            );
-                        }
-                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                            consumer_runtime.lagged(skipped);
-                        }
-                        Err(broadcast::error::RecvError::Closed) => {
-                            consumer_runtime.closed();
+                            }
                             break;
                         }
+                        Some(_) = dispatches.join_next(), if !dispatches.is_empty() => {}
+                        received = receiver.recv() => match received {
+                            Ok(envelope) => dispatch(&mut dispatches, envelope),
+                            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                                consumer_runtime.lagged(skipped);
+                            }
+                            Err(broadcast::error::RecvError::Closed) => {
+                                consumer_runtime.closed();
+                                break;
+                            }
+                        },
                     }
                 }
+
+                while dispatches.join_next().await.is_some() {}
             }
             .in_current_span(),
         );
 
-        RunningDispatcher { handle, bus }
+        RunningDispatcher {
+            handle,
+            bus,
+            shutdown: shutdown_tx,
+            tracker,
+            limiter,
+        }
+    }
+
+    /// Counts the event as in flight and returns the task that hands it to the
+    /// handlers.
+    fn dispatch_task(
+        envelope: EventEnvelope,
+        handlers: Arc<Vec<Arc<dyn EventHandler>>>,
+        config: DispatcherConfig,
+        limiter: Arc<ConcurrencyLimiter>,
+        backpressure: Option<Arc<super::backpressure::BackpressureController>>,
+        consumer_runtime: EventConsumerRuntime,
+        tracker: Arc<DispatchTracker>,
+    ) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let span = tracing::info_span!(
+            "event_dispatch",
+            event_type = envelope.event.event_type(),
+            event_id = %envelope.id,
+            tenant_id = %envelope.tenant_id
+        );
+
+        tracker.begin();
+        async move {
+            Self::dispatch_to_handlers(
+                envelope,
+                handlers,
+                config,
+                limiter,
+                backpressure,
+                consumer_runtime,
+                tracker,
+            )
+            .await;
+        }
+        .instrument(span)
     }
 
     async fn dispatch_to_handlers(
         envelope: EventEnvelope,
         handlers: Arc<Vec<Arc<dyn EventHandler>>>,
         config: DispatcherConfig,
-        semaphore: Arc<Semaphore>,
+        limiter: Arc<ConcurrencyLimiter>,
         backpressure: Option<Arc<super::backpressure::BackpressureController>>,
         consumer_runtime: EventConsumerRuntime,
+        tracker: Arc<DispatchTracker>,
     ) {
         let dispatch_started_at = Instant::now();
         let event_type = envelope.event.event_type().to_string();
@@ -177,6 +353,7 @@ impl EventDispatcher {
                 bp.release();
             }
             consumer_runtime.record_dispatch_latency(&event_type, dispatch_started_at);
+            tracker.finish();
             return;
         }
 
@@ -191,6 +368,7 @@ impl EventDispatcher {
         if config.fail_fast {
             for handler in matching_handlers {
                 let envelope = envelope.clone();
+                let _permit = limiter.acquire(handler.name()).await;
                 if let Err(error) = Self::handle_with_retry(handler, envelope, &config).await {
                     error!(
                         event_type = event_type.as_str(),
@@ -205,21 +383,24 @@ impl EventDispatcher {
                 bp.release();
             }
             consumer_runtime.record_dispatch_latency(&event_type, dispatch_started_at);
+            tracker.finish();
             return;
         }
 
         // For concurrent execution, track handler completion
         let completion_count = Arc::new(AtomicUsize::new(0));
+        let mut handler_tasks = JoinSet::new();
 
         for handler in matching_handlers {
             let envelope = envelope.clone();
             let config = config.clone();
-            let permit = semaphore.clone().acquire_owned().await;
+            let permit = limiter.acquire(handler.name()).await;
             let bp = backpressure.clone();
             let count = Arc::clone(&completion_count);
             let event_type = event_type.clone();
+            let tracker = Arc::clone(&tracker);
 
-            tokio::spawn(async move {
+            handler_tasks.spawn(async move {
                 let _permit = permit;
 
                 struct CompletionGuard {
@@ -229,6 +410,7 @@ impl EventDispatcher {
                     consumer_runtime: EventConsumerRuntime,
                     event_type: String,
                     dispatch_started_at: Instant,
+                    tracker: Arc<DispatchTracker>,
                 }
 
                 impl Drop for CompletionGuard {
@@ -242,6 +424,7 @@ impl EventDispatcher {
                                 &self.event_type,
                                 self.dispatch_started_at,
                             );
+                            self.tracker.finish();
                         }
                     }
                 }
@@ -253,11 +436,15 @@ impl EventDispatcher {
                     consumer_runtime,
                     event_type,
                     dispatch_started_at,
+                    tracker,
                 };
 
                 let _ = Self::handle_with_retry(handler, envelope, &config).await;
             });
         }
+
+        // Outlive the handlers so that aborting this dispatch aborts them too.
+        while handler_tasks.join_next().await.is_some() {}
     }
 
     async fn handle_with_retry(
@@ -270,7 +457,32 @@ impl EventDispatcher {
 
         loop {
             attempts += 1;
-            match handler.handle(&envelope).await {
+            // A panicking handler is a bug, not a transient failure: report it
+            // once and keep the dispatch task alive so its bookkeeping runs.
+            let result = match AssertUnwindSafe(handler.handle(&envelope))
+                .catch_unwind()
+                .await
+            {
+                Ok(result) => result,
+                Err(panic) => {
+                    let error = Error::External(format!(
+                        "event handler {} panicked: {}",
+                        handler.name(),
+                        panic_message(panic.as_ref())
+                    ));
+                    error!(
+                        handler = handler.name(),
+                        event_type = envelope.event.event_type(),
+                        error = %error,
+                        "Event handler panicked"
+                    );
+                    handler.on_error(&envelope, &error).await;
+                    report_failure(config, handler.name(), &envelope, &error, attempts);
+                    return Err(error);
+                }
+            };
+
+            match result {
                 Ok(()) => {
                     debug!(
                         handler = handler.name(),
@@ -288,12 +500,12 @@ impl EventDispatcher {
                             error = %error,
                             "Handler failed, retrying"
                         );
-                        tokio::time::sleep(tokio::time::Duration::from_millis(
-                            config.retry_delay_ms,
-                        ))
-                        .await;
+                        rustok_telemetry::metrics::record_retry_attempt(handler.name());
+                        tokio::time::sleep(config.retry_backoff.delay_for_attempt(attempts as u32))
+                            .await;
                     } else {
                         handler.on_error(&envelope, &error).await;
+                        report_failure(config, handler.name(), &envelope, &error, attempts);
                         return Err(error);
                     }
                 }
@@ -302,9 +514,226 @@ impl EventDispatcher {
     }
 }
 
+fn report_failure(
+    config: &DispatcherConfig,
+    handler: &'static str,
+    envelope: &EventEnvelope,
+    error: &Error,
+    attempts: usize,
+) {
+    if let Some(sink) = &config.failure_sink {
+        (sink.0)(HandlerFailure {
+            handler,
+            envelope: envelope.clone(),
+            error: error.to_string(),
+            attempts,
+        });
+    }
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload")
+}
+
+/// Bounds concurrent handler invocations and reports in-flight/queued counts.
+struct ConcurrencyLimiter {
+    consumer_runtime: EventConsumerRuntime,
+    global: Option<Arc<Semaphore>>,
+    per_handler: HashMap<&'static str, Arc<Semaphore>>,
+    in_flight: AtomicUsize,
+    queued: AtomicUsize,
+}
+
+impl ConcurrencyLimiter {
+    fn new(
+        consumer_runtime: EventConsumerRuntime,
+        config: &DispatcherConfig,
+        handlers: &[Arc<dyn EventHandler>],
+    ) -> Self {
+        let limit = config.max_concurrent.max(1);
+        let (global, per_handler) = match config.concurrency_scope {
+            ConcurrencyScope::Global => (Some(Arc::new(Semaphore::new(limit))), HashMap::new()),
+            ConcurrencyScope::PerHandler => (
+                None,
+                handlers
+                    .iter()
+                    .map(|handler| (handler.name(), Arc::new(Semaphore::new(limit))))
+                    .collect(),
+            ),
+        };
+
+        Self {
+            consumer_runtime,
+            global,
+            per_handler,
+            in_flight: AtomicUsize::new(0),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    fn semaphore_for(&self, handler: &str) -> Option<Arc<Semaphore>> {
+        self.global
+            .clone()
+            .or_else(|| self.per_handler.get(handler).cloned())
+    }
+
+    /// Waits for a permit; the invocation is queued, never dropped.
+    async fn acquire(self: &Arc<Self>, handler: &str) -> HandlerPermit {
+        let queued = QueuedGuard::new(self);
+        let permit = match self.semaphore_for(handler) {
+            Some(semaphore) => semaphore.acquire_owned().await.ok(),
+            None => None,
+        };
+        drop(queued);
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        rustok_telemetry::metrics::record_dispatcher_handler_started(
+            self.consumer_runtime.consumer(),
+        );
+
+        HandlerPermit {
+            _permit: permit,
+            limiter: Arc::clone(self),
+        }
+    }
+
+    fn release(&self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        rustok_telemetry::metrics::record_dispatcher_handler_finished(
+            self.consumer_runtime.consumer(),
+        );
+    }
+
+    fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+}
+
+/// Counts an invocation as queued until it gets a permit or its `acquire`
+/// future is dropped, so the inc/dec gauge cannot leak on cancellation.
+struct QueuedGuard<'a> {
+    limiter: &'a ConcurrencyLimiter,
+}
+
+impl<'a> QueuedGuard<'a> {
+    fn new(limiter: &'a ConcurrencyLimiter) -> Self {
+        limiter.queued.fetch_add(1, Ordering::SeqCst);
+        rustok_telemetry::metrics::record_dispatcher_handler_queued(
+            limiter.consumer_runtime.consumer(),
+        );
+        Self { limiter }
+    }
+}
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.limiter.queued.fetch_sub(1, Ordering::SeqCst);
+        rustok_telemetry::metrics::record_dispatcher_handler_dequeued(
+            self.limiter.consumer_runtime.consumer(),
+        );
+    }
+}
+
+struct HandlerPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+    limiter: Arc<ConcurrencyLimiter>,
+}
+
+impl Drop for HandlerPermit {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
+
+/// Tracks events handed to handlers that have not finished dispatching yet.
+struct DispatchTracker {
+    in_flight: watch::Sender<usize>,
+    processed: AtomicUsize,
+}
+
+impl DispatchTracker {
+    fn new() -> Self {
+        let (in_flight, _) = watch::channel(0);
+        Self {
+            in_flight,
+            processed: AtomicUsize::new(0),
+        }
+    }
+
+    fn begin(&self) {
+        self.in_flight.send_modify(|count| *count += 1);
+    }
+
+    fn finish(&self) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+        self.in_flight
+            .send_modify(|count| *count = count.saturating_sub(1));
+    }
+
+    fn in_flight(&self) -> usize {
+        *self.in_flight.borrow()
+    }
+
+    fn processed(&self) -> usize {
+        self.processed.load(Ordering::Relaxed)
+    }
+
+    async fn wait_idle(&self) {
+        let mut receiver = self.in_flight.subscribe();
+        let _ = receiver.wait_for(|count| *count == 0).await;
+    }
+}
+
+/// Emits the start/stop lifecycle signals for a dispatcher loop. Stop is
+/// recorded on drop so aborted loops are accounted for as well.
+struct RunningGuard {
+    consumer_runtime: EventConsumerRuntime,
+}
+
+impl RunningGuard {
+    fn new(consumer_runtime: EventConsumerRuntime, handlers: usize) -> Self {
+        rustok_telemetry::metrics::record_dispatcher_started(consumer_runtime.consumer());
+        info!(
+            consumer = consumer_runtime.consumer(),
+            handlers, "Event dispatcher started"
+        );
+        Self { consumer_runtime }
+    }
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        rustok_telemetry::metrics::record_dispatcher_stopped(self.consumer_runtime.consumer());
+        info!(
+            consumer = self.consumer_runtime.consumer(),
+            "Event dispatcher stopped"
+        );
+    }
+}
+
+/// Outcome of [`RunningDispatcher::stop_and_drain`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Events whose dispatch finished while draining.
+    pub processed: usize,
+    /// Events still being handled when the drain timeout elapsed; their
+    /// handlers are aborted.
+    pub abandoned: usize,
+}
+
 pub struct RunningDispatcher {
     handle: JoinHandle<()>,
     bus: EventBus,
+    shutdown: watch::Sender<bool>,
+    tracker: Arc<DispatchTracker>,
+    limiter: Arc<ConcurrencyLimiter>,
 }
 
 impl RunningDispatcher {
@@ -312,10 +741,65 @@ impl RunningDispatcher {
         &self.bus
     }
 
+    /// Number of events currently being handled.
+    pub fn in_flight(&self) -> usize {
+        self.tracker.in_flight()
+    }
+
+    /// Number of handler invocations currently running.
+    pub fn handlers_in_flight(&self) -> usize {
+        self.limiter.in_flight()
+    }
+
+    /// Number of handler invocations waiting for a concurrency permit.
+    pub fn handlers_queued(&self) -> usize {
+        self.limiter.queued()
+    }
+
+    /// Aborts the dispatch loop immediately, cancelling handlers that are
+    /// already running; use [`Self::stop_and_drain`] for a clean shutdown.
     pub fn stop(self) {
         self.handle.abort();
     }
 
+    /// Stops accepting new events, dispatches whatever is still buffered in the
+    /// subscription and waits for all handlers to finish, up to `timeout`.
+    pub async fn stop_and_drain(mut self, timeout: Duration) -> DrainReport {
+        let processed_before = self.tracker.processed();
+        let _ = self.shutdown.send(true);
+
+        let tracker = Arc::clone(&self.tracker);
+        let handle = &mut self.handle;
+        let drained = tokio::time::timeout(timeout, async move {
+            let _ = handle.await;
+            tracker.wait_idle().await;
+        })
+        .await;
+
+        let report = DrainReport {
+            processed: self.tracker.processed() - processed_before,
+            abandoned: self.tracker.in_flight(),
+        };
+
+        if drained.is_err() {
+            self.handle.abort();
+            let _ = (&mut self.handle).await;
+        }
+
+        if report.abandoned > 0 {
+            warn!(
+                processed = report.processed,
+                abandoned = report.abandoned,
+                timeout_ms = timeout.as_millis() as u64,
+                "Event dispatcher drain timed out"
+            );
+        } else {
+            info!(processed = report.processed, "Event dispatcher drained");
+        }
+
+        report
+    }
+
     pub async fn join(self) -> Result<(), tokio::task::JoinError> {
         self.handle.await
     }
@@ -375,3 +859,514 @@ macro_rules! event_handler {
         $crate::events::handler::HandlerBuilder::new($name, $predicate, $handler)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+    use uuid::Uuid;
+
+    fn product_created() -> DomainEvent {
+        DomainEvent::ProductCreated {
+            product_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn stop_and_drain_processes_buffered_events() {
+        let bus = EventBus::new();
+        let handled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&handled);
+
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher
+            .register(HandlerBuilder::new(
+                "counting",
+                |_: &DomainEvent| true,
+                move |_: EventEnvelope| {
+                    let counter = Arc::clone(&counter);
+                    async move {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                },
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        for _ in 0..5 {
+            bus.publish(Uuid::new_v4(), None, product_created())
+                .expect("publish must succeed");
+        }
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+
+        assert_eq!(report.abandoned, 0);
+        assert_eq!(report.processed, 5);
+        assert_eq!(handled.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn tenant_dispatcher_only_sees_its_tenant() {
+        let bus = EventBus::new();
+        let tenant_id = Uuid::new_v4();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+
+        let mut dispatcher = EventDispatcher::new(bus.clone()).for_tenant(tenant_id);
+        dispatcher
+            .register(HandlerBuilder::new(
+                "tenant_scoped",
+                |_: &DomainEvent| true,
+                move |envelope: EventEnvelope| {
+                    let sink = Arc::clone(&sink);
+                    async move {
+                        sink.lock().unwrap().push(envelope.tenant_id);
+                        Ok(())
+                    }
+                },
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        for tenant in [Uuid::new_v4(), tenant_id, Uuid::new_v4(), tenant_id] {
+            bus.publish(tenant, None, product_created())
+                .expect("publish must succeed");
+        }
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+
+        assert_eq!(report.abandoned, 0);
+        assert_eq!(*seen.lock().unwrap(), vec![tenant_id, tenant_id]);
+    }
+
+    #[tokio::test]
+    async fn stop_and_drain_reports_abandoned_handlers_on_timeout() {
+        let bus = EventBus::new();
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher
+            .register(HandlerBuilder::new(
+                "stuck",
+                |_: &DomainEvent| true,
+                |_: EventEnvelope| async {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    Ok(())
+                },
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        bus.publish(Uuid::new_v4(), None, product_created())
+            .expect("publish must succeed");
+
+        let report = running.stop_and_drain(Duration::from_millis(50)).await;
+
+        assert_eq!(report.processed, 0);
+        assert_eq!(report.abandoned, 1);
+    }
+
+    #[tokio::test]
+    async fn stop_and_drain_aborts_abandoned_handlers() {
+        struct DropFlag(Arc<AtomicBool>);
+
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let bus = EventBus::new();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&dropped);
+
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher
+            .register(HandlerBuilder::new(
+                "stuck",
+                |_: &DomainEvent| true,
+                move |_: EventEnvelope| {
+                    let guard = DropFlag(Arc::clone(&flag));
+                    async move {
+                        let _guard = guard;
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        Ok(())
+                    }
+                },
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        bus.publish(Uuid::new_v4(), None, product_created())
+            .expect("publish must succeed");
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        let report = running.stop_and_drain(Duration::from_millis(50)).await;
+        assert_eq!(report.abandoned, 1);
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !dropped.load(Ordering::SeqCst) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("abandoned handler must be aborted");
+    }
+
+    fn noop_handler(name: &'static str) -> impl EventHandler {
+        HandlerBuilder::new(
+            name,
+            |_: &DomainEvent| true,
+            |_: EventEnvelope| async { Ok(()) },
+        )
+    }
+
+    #[test]
+    fn register_rejects_duplicate_handler_names() {
+        let mut dispatcher = EventDispatcher::new(EventBus::new());
+        dispatcher
+            .register(noop_handler("indexer"))
+            .expect("first registration must succeed");
+
+        let error = dispatcher
+            .register(noop_handler("indexer"))
+            .err()
+            .expect("duplicate name must be rejected");
+
+        assert_eq!(error, DuplicateHandlerError { name: "indexer" });
+        assert_eq!(dispatcher.registered_handlers(), vec!["indexer"]);
+    }
+
+    async fn panicking_handler(_: EventEnvelope) -> HandlerResult {
+        panic!("handler bug")
+    }
+
+    #[tokio::test]
+    async fn panicking_handler_does_not_stop_dispatch() {
+        for fail_fast in [false, true] {
+            let bus = EventBus::new();
+            let handled = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&handled);
+
+            let mut dispatcher = EventDispatcher::with_config(
+                bus.clone(),
+                DispatcherConfig {
+                    fail_fast,
+                    ..DispatcherConfig::default()
+                },
+            );
+            dispatcher
+                .register(HandlerBuilder::new(
+                    "counting",
+                    |_: &DomainEvent| true,
+                    move |_: EventEnvelope| {
+                        let counter = Arc::clone(&counter);
+                        async move {
+                            counter.fetch_add(1, Ordering::SeqCst);
+                            Ok(())
+                        }
+                    },
+                ))
+                .expect("handler name must be unique");
+            dispatcher
+                .register(HandlerBuilder::new(
+                    "panicking",
+                    |_: &DomainEvent| true,
+                    panicking_handler,
+                ))
+                .expect("handler name must be unique");
+            let running = dispatcher.start();
+
+            for _ in 0..3 {
+                bus.publish(Uuid::new_v4(), None, product_created())
+                    .expect("publish must succeed");
+            }
+
+            let report = running.stop_and_drain(Duration::from_secs(2)).await;
+
+            assert_eq!(report.abandoned, 0, "fail_fast: {fail_fast}");
+            assert_eq!(report.processed, 3, "fail_fast: {fail_fast}");
+            assert_eq!(handled.load(Ordering::SeqCst), 3, "fail_fast: {fail_fast}");
+        }
+    }
+
+    #[tokio::test]
+    async fn each_event_is_delivered_once_per_handler() {
+        let bus = EventBus::new();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher
+            .register(HandlerBuilder::new(
+                "recording",
+                |_: &DomainEvent| true,
+                move |envelope: EventEnvelope| {
+                    let sink = Arc::clone(&sink);
+                    async move {
+                        sink.lock().unwrap().push(envelope.id);
+                        Ok(())
+                    }
+                },
+            ))
+            .expect("handler name must be unique");
+        dispatcher
+            .register(HandlerBuilder::new(
+                "uninterested",
+                |_: &DomainEvent| false,
+                panicking_handler,
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        let mut published = Vec::new();
+        for _ in 0..20 {
+            let envelope = EventEnvelope::new(Uuid::new_v4(), None, product_created());
+            published.push(envelope.id);
+            bus.publish_envelope(envelope)
+                .expect("publish must succeed");
+        }
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+        assert_eq!(report.abandoned, 0);
+
+        let mut delivered = seen.lock().unwrap().clone();
+        delivered.sort();
+        published.sort();
+        assert_eq!(delivered, published);
+    }
+
+    fn flaky_handler(failures: usize, calls: Arc<AtomicUsize>) -> impl EventHandler {
+        HandlerBuilder::new(
+            "flaky",
+            |_: &DomainEvent| true,
+            move |_: EventEnvelope| {
+                let calls = Arc::clone(&calls);
+                async move {
+                    if calls.fetch_add(1, Ordering::SeqCst) < failures {
+                        Err(Error::External("transient".to_string()))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+    }
+
+    fn retrying_config(retry_count: usize) -> DispatcherConfig {
+        DispatcherConfig {
+            retry_count,
+            retry_backoff: BackoffPolicy::Fixed(Duration::from_millis(1)),
+            ..DispatcherConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn failing_handler_is_retried_until_it_succeeds() {
+        let bus = EventBus::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let failures = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&failures);
+
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                failure_sink: Some(FailureSink::new(move |failure| {
+                    sink.lock().unwrap().push(failure)
+                })),
+                ..retrying_config(2)
+            },
+        );
+        dispatcher
+            .register(flaky_handler(2, Arc::clone(&calls)))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        bus.publish(Uuid::new_v4(), None, product_created())
+            .expect("publish must succeed");
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+        assert_eq!(report.abandoned, 0);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(failures.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn exhausted_retries_forward_envelope_to_failure_sink() {
+        let bus = EventBus::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let failures = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&failures);
+
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                failure_sink: Some(FailureSink::new(move |failure| {
+                    sink.lock().unwrap().push(failure)
+                })),
+                ..retrying_config(1)
+            },
+        );
+        dispatcher
+            .register(flaky_handler(usize::MAX, Arc::clone(&calls)))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        let envelope = EventEnvelope::new(Uuid::new_v4(), None, product_created());
+        let event_id = envelope.id;
+        bus.publish_envelope(envelope)
+            .expect("publish must succeed");
+
+        running.stop_and_drain(Duration::from_secs(2)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let failures = failures.lock().unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].handler, "flaky");
+        assert_eq!(failures[0].envelope.id, event_id);
+        assert_eq!(failures[0].attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn register_or_replace_swaps_handler_in_place() {
+        let bus = EventBus::new();
+        let handled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&handled);
+
+        let mut dispatcher = EventDispatcher::new(bus.clone());
+        dispatcher
+            .register(noop_handler("first"))
+            .expect("handler name must be unique");
+        dispatcher
+            .register(noop_handler("second"))
+            .expect("handler name must be unique");
+        dispatcher.register_or_replace(HandlerBuilder::new(
+            "first",
+            |_: &DomainEvent| true,
+            move |_: EventEnvelope| {
+                let counter = Arc::clone(&counter);
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            },
+        ));
+
+        assert_eq!(dispatcher.registered_handlers(), vec!["first", "second"]);
+
+        let running = dispatcher.start();
+        bus.publish(Uuid::new_v4(), None, product_created())
+            .expect("publish must succeed");
+        running.stop_and_drain(Duration::from_secs(2)).await;
+
+        assert_eq!(handled.load(Ordering::SeqCst), 1);
+    }
+
+    fn peak_tracking_handler(
+        name: &'static str,
+        current: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    ) -> impl EventHandler {
+        HandlerBuilder::new(
+            name,
+            |_: &DomainEvent| true,
+            move |_: EventEnvelope| {
+                let current = Arc::clone(&current);
+                let peak = Arc::clone(&peak);
+                async move {
+                    let running = current.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(running, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    current.fetch_sub(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn global_concurrency_limit_queues_excess_invocations() {
+        let bus = EventBus::new();
+        let current = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                max_concurrent: 2,
+                ..DispatcherConfig::default()
+            },
+        );
+        dispatcher
+            .register(peak_tracking_handler(
+                "first",
+                Arc::clone(&current),
+                Arc::clone(&peak),
+            ))
+            .expect("handler name must be unique");
+        dispatcher
+            .register(peak_tracking_handler(
+                "second",
+                Arc::clone(&current),
+                Arc::clone(&peak),
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        for _ in 0..4 {
+            bus.publish(Uuid::new_v4(), None, product_created())
+                .expect("publish must succeed");
+        }
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+
+        assert_eq!(report.processed, 4);
+        assert_eq!(report.abandoned, 0);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn per_handler_concurrency_limit_is_independent() {
+        let bus = EventBus::new();
+        let first_current = Arc::new(AtomicUsize::new(0));
+        let first_peak = Arc::new(AtomicUsize::new(0));
+        let second_current = Arc::new(AtomicUsize::new(0));
+        let second_peak = Arc::new(AtomicUsize::new(0));
+
+        let mut dispatcher = EventDispatcher::with_config(
+            bus.clone(),
+            DispatcherConfig {
+                max_concurrent: 1,
+                concurrency_scope: ConcurrencyScope::PerHandler,
+                ..DispatcherConfig::default()
+            },
+        );
+        dispatcher
+            .register(peak_tracking_handler(
+                "first",
+                first_current,
+                Arc::clone(&first_peak),
+            ))
+            .expect("handler name must be unique");
+        dispatcher
+            .register(peak_tracking_handler(
+                "second",
+                second_current,
+                Arc::clone(&second_peak),
+            ))
+            .expect("handler name must be unique");
+        let running = dispatcher.start();
+
+        for _ in 0..3 {
+            bus.publish(Uuid::new_v4(), None, product_created())
+                .expect("publish must succeed");
+        }
+
+        let report = running.stop_and_drain(Duration::from_secs(2)).await;
+
+        assert_eq!(report.processed, 3);
+        assert_eq!(first_peak.load(Ordering::SeqCst), 1);
+        assert_eq!(second_peak.load(Ordering::SeqCst), 1);
+    }
+}
@@ -3,6 +3,7 @@ mod bus;
 mod consumer;
 mod handler;
 mod memory;
+mod routing;
 mod schema;
 mod transport;
 mod types;
@@ -12,14 +13,17 @@ pub use backpressure::{
     BackpressureConfig, BackpressureController, BackpressureError, BackpressureMetrics,
     BackpressureState,
 };
-pub use bus::{EventBus, EventBusStats};
+pub use bus::{EventBus, EventBusError, EventBusStats, FilteredReceiver, DEFAULT_MAX_EVENT_BYTES};
 pub use consumer::EventConsumerRuntime;
 pub use handler::{
-    DispatcherConfig, EventDispatcher, EventHandler, HandlerBuilder, HandlerResult,
-    RunningDispatcher,
+    ConcurrencyScope, DispatcherConfig, DrainReport, DuplicateHandlerError, EventDispatcher,
+    EventHandler, FailureSink, HandlerBuilder, HandlerFailure, HandlerResult, RunningDispatcher,
 };
 pub use memory::MemoryTransport;
-pub use schema::{event_schema, EventSchema, FieldSchema, EVENT_SCHEMAS};
-pub use transport::{EventTransport, ReliabilityLevel};
+pub use routing::{EventPredicate, RoutingRule, RoutingTable};
+pub use schema::{
+    event_schema, EventSchema, FieldSchema, UpcastError, Upcaster, UpcasterRegistry, EVENT_SCHEMAS,
+};
+pub use transport::{required_reliability, EventTransport, ReliabilityLevel};
 pub use types::{DomainEvent, EventEnvelope};
 pub use validation::{EventValidationError, ValidateEvent};
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use uuid::Uuid;
+
+use super::{EventEnvelope, EventTransport};
+
+/// Extra condition a [`RoutingRule`] can check beyond type and tenant.
+pub type EventPredicate = Arc<dyn Fn(&EventEnvelope) -> bool + Send + Sync>;
+
+/// One entry of a [`RoutingTable`].
+///
+/// A rule matches when every configured condition holds: the event type
+/// matches one of `event_types` (exact, `prefix.*` or `*`; empty means any),
+/// the tenant is listed in `tenant_ids` (empty means any), and the predicate,
+/// if set, returns `true`. A matching rule with no destinations drops the
+/// event.
+#[derive(Clone)]
+pub struct RoutingRule {
+    name: String,
+    event_types: Vec<String>,
+    tenant_ids: Vec<Uuid>,
+    predicate: Option<EventPredicate>,
+    destinations: Vec<String>,
+}
+
+impl RoutingRule {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            event_types: Vec::new(),
+            tenant_ids: Vec::new(),
+            predicate: None,
+            destinations: Vec::new(),
+        }
+    }
+
+    pub fn event_types<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.event_types = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn tenants(mut self, tenant_ids: impl IntoIterator<Item = Uuid>) -> Self {
+        self.tenant_ids = tenant_ids.into_iter().collect();
+        self
+    }
+
+    pub fn when<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&EventEnvelope) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    pub fn to(mut self, destination: impl Into<String>) -> Self {
+        self.destinations.push(destination.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn destinations(&self) -> &[String] {
+        &self.destinations
+    }
+
+    pub fn matches(&self, envelope: &EventEnvelope) -> bool {
+        let type_matches = self.event_types.is_empty()
+            || self
+                .event_types
+                .iter()
+                .any(|pattern| event_type_matches(pattern, &envelope.event_type));
+        let tenant_matches =
+            self.tenant_ids.is_empty() || self.tenant_ids.contains(&envelope.tenant_id);
+
+        type_matches
+            && tenant_matches
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|predicate| predicate(envelope))
+    }
+}
+
+impl fmt::Debug for RoutingRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoutingRule")
+            .field("name", &self.name)
+            .field("event_types", &self.event_types)
+            .field("tenant_ids", &self.tenant_ids)
+            .field("predicate", &self.predicate.is_some())
+            .field("destinations", &self.destinations)
+            .finish()
+    }
+}
+
+fn event_type_matches(pattern: &str, event_type: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => event_type.starts_with(prefix),
+        None => pattern == event_type,
+    }
+}
+
+#[derive(Debug, Default)]
+struct RoutingRules {
+    rules: Vec<RoutingRule>,
+    default_destinations: Vec<String>,
+}
+
+/// Routes envelopes to named sinks by the first matching rule.
+///
+/// Events no rule matches go to the default destinations. Rules can be
+/// swapped at runtime with [`RoutingTable::reload`]; sinks are registered
+/// separately so reloads never need transport handles.
+#[derive(Default)]
+pub struct RoutingTable {
+    rules: RwLock<Arc<RoutingRules>>,
+    sinks: RwLock<HashMap<String, Arc<dyn EventTransport>>>,
+}
+
+impl RoutingTable {
+    pub fn new(rules: Vec<RoutingRule>, default_destinations: Vec<String>) -> Self {
+        let table = Self::default();
+        table.reload(rules, default_destinations);
+        table
+    }
+
+    /// Table that sends every event to `destination`.
+    pub fn single(destination: impl Into<String>) -> Self {
+        Self::new(Vec::new(), vec![destination.into()])
+    }
+
+    pub fn register_sink(&self, name: impl Into<String>, sink: Arc<dyn EventTransport>) {
+        self.sinks
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(name.into(), sink);
+    }
+
+    /// Atomically replace the rule set. In-flight dispatches finish with the
+    /// rules they started with.
+    pub fn reload(&self, rules: Vec<RoutingRule>, default_destinations: Vec<String>) {
+        let next = Arc::new(RoutingRules {
+            rules,
+            default_destinations,
+        });
+        *self
+            .rules
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = next;
+    }
+
+    fn snapshot(&self) -> Arc<RoutingRules> {
+        self.rules
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Destination names for `envelope`, recording the matched rule.
+    pub fn route(&self, envelope: &EventEnvelope) -> Vec<String> {
+        let rules = self.snapshot();
+        match rules.rules.iter().find(|rule| rule.matches(envelope)) {
+            Some(rule) => {
+                rustok_telemetry::metrics::record_event_route_match(&rule.name);
+                rule.destinations.clone()
+            }
+            None => {
+                rustok_telemetry::metrics::record_event_route_match("default");
+                rules.default_destinations.clone()
+            }
+        }
+    }
+
+    /// Publish `envelope` to every routed sink and return how many accepted
+    /// it. Unknown sinks and sink failures are logged and skipped so one
+    /// broken destination does not starve the others.
+    pub async fn dispatch(&self, envelope: EventEnvelope) -> usize {
+        let destinations = self.route(&envelope);
+        let sinks: Vec<(String, Arc<dyn EventTransport>)> = {
+            let registered = self
+                .sinks
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            destinations
+                .into_iter()
+                .filter_map(|name| match registered.get(&name) {
+                    Some(sink) => Some((name, sink.clone())),
+                    None => {
+                        tracing::warn!(
+                            destination = %name,
+                            event_type = %envelope.event_type,
+                            "Event route points at an unregistered sink"
+                        );
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        let mut delivered = 0;
+        for (name, sink) in sinks {
+            match sink.publish(envelope.clone()).await {
+                Ok(()) => delivered += 1,
+                Err(error) => tracing::error!(
+                    destination = %name,
+                    event_type = %envelope.event_type,
+                    "Failed to publish domain event to sink: {error}"
+                ),
+            }
+        }
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{DomainEvent, MemoryTransport};
+
+    fn envelope(tenant_id: Uuid) -> EventEnvelope {
+        EventEnvelope::new(
+            tenant_id,
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        )
+    }
+
+    #[test]
+    fn event_type_patterns() {
+        assert!(event_type_matches("*", "tag.created"));
+        assert!(event_type_matches("tag.*", "tag.created"));
+        assert!(event_type_matches("tag.created", "tag.created"));
+        assert!(!event_type_matches("node.*", "tag.created"));
+        assert!(!event_type_matches("tag.updated", "tag.created"));
+    }
+
+    #[test]
+    fn first_matching_rule_wins_and_empty_destinations_drop() {
+        let tenant = Uuid::new_v4();
+        let table = RoutingTable::new(
+            vec![
+                RoutingRule::new("drop_other_tenant").tenants([Uuid::new_v4()]),
+                RoutingRule::new("tags")
+                    .event_types(["tag.*"])
+                    .to("iggy")
+                    .to("webhooks"),
+                RoutingRule::new("never").to("outbox"),
+            ],
+            vec!["outbox".to_string()],
+        );
+
+        assert_eq!(table.route(&envelope(tenant)), vec!["iggy", "webhooks"]);
+
+        table.reload(
+            vec![RoutingRule::new("drop_all").when(|_| true)],
+            vec!["outbox".to_string()],
+        );
+        assert!(table.route(&envelope(tenant)).is_empty());
+
+        table.reload(Vec::new(), vec!["outbox".to_string()]);
+        assert_eq!(table.route(&envelope(tenant)), vec!["outbox"]);
+    }
+
+    #[tokio::test]
+    async fn dispatch_publishes_to_registered_sinks_only() {
+        let table = RoutingTable::new(
+            vec![RoutingRule::new("tags")
+                .event_types(["tag.created"])
+                .to("memory")
+                .to("missing")],
+            Vec::new(),
+        );
+        let sink = MemoryTransport::new();
+        let mut receiver = sink.subscribe();
+        table.register_sink("memory", Arc::new(sink));
+
+        let delivered = table.dispatch(envelope(Uuid::new_v4())).await;
+
+        assert_eq!(delivered, 1);
+        assert_eq!(receiver.recv().await.unwrap().event_type, "tag.created");
+    }
+}
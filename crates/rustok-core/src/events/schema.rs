@@ -1 +1,3 @@
-pub use rustok_events::{event_schema, EventSchema, FieldSchema, EVENT_SCHEMAS};
+pub use rustok_events::{
+    event_schema, EventSchema, FieldSchema, UpcastError, Upcaster, UpcasterRegistry, EVENT_SCHEMAS,
+};
@@ -6,12 +6,7 @@ use crate::Result;
 
 use super::EventEnvelope;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum ReliabilityLevel {
-    InMemory,
-    Outbox,
-    Streaming,
-}
+pub use rustok_events::{required_reliability, ReliabilityLevel};
 
 #[async_trait]
 pub trait EventTransport: Send + Sync {
@@ -94,12 +94,49 @@ fn translate_en(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token" => "Invalid reset token",
         "auth.invalid_invite_token" => "Invalid invite token",
         "auth.invalid_verification_token" => "Invalid verification token",
+        "auth.email_not_verified" => "Email address is not verified",
         "auth.invalid_or_expired_code" => "Invalid or expired code",
         // OAuth
         "oauth.auth_config_error" => "Authentication configuration error",
         "oauth.pkce_invalid" => "PKCE code verifier is invalid",
         "oauth.redirect_uri_mismatch" => "Redirect URI mismatch",
         "oauth.refresh_no_user" => "Refresh token has no associated user",
+        // Error codes (`CodedError::error_code`)
+        "error.validation" => "Invalid input data",
+        "error.unauthenticated" => "Authentication required",
+        "error.forbidden" => "Permission denied",
+        "error.not_found" => "Resource not found",
+        "error.conflict" => "Resource already exists or was modified",
+        "error.rate_limited" => "Too many requests",
+        "error.database" => "Unable to access data",
+        "error.external_service" => "External service is unavailable",
+        "error.internal" => "Internal server error",
+        "error.timeout" => "Request timed out",
+        "error.business_logic" => "Operation is not allowed",
+        "auth.invalid_access_token" => "Invalid access token",
+        "page.not_found" => "Page not found",
+        "page.block_not_found" => "Page block not found",
+        "page.menu_not_found" => "Menu not found",
+        "page.duplicate_slug" => "A page with this slug already exists",
+        "page.cannot_delete_published" => "Published pages cannot be deleted",
+        "page.feature_disabled" => "This feature is disabled for the tenant",
+        "page.unknown_layout" => "The selected page layout is not available",
+        "content.node_not_found" => "Content not found",
+        "content.category_not_found" => "Category not found",
+        "content.translation_not_found" => "Translation not found",
+        "content.duplicate_slug" => "Content with this slug already exists",
+        "content.concurrent_modification" => {
+            "Content was modified by another request. Reload and try again"
+        }
+        "commerce.product_not_found" => "Product not found",
+        "commerce.variant_not_found" => "Product variant not found",
+        "commerce.duplicate_handle" => "A product with this handle already exists",
+        "commerce.duplicate_sku" => "A product with this SKU already exists",
+        "commerce.invalid_price" => "Invalid price value",
+        "commerce.insufficient_inventory" => "Not enough items in stock",
+        "commerce.invalid_option_combination" => "The selected product options are not available",
+        "commerce.no_variants" => "Product must have at least one variant",
+        "commerce.cannot_delete_published" => "Published products cannot be deleted",
         _ => return None,
     })
 }
@@ -132,12 +169,49 @@ fn translate_ru(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token" => "Недействительный токен сброса пароля",
         "auth.invalid_invite_token" => "Недействительный токен приглашения",
         "auth.invalid_verification_token" => "Недействительный токен подтверждения",
+        "auth.email_not_verified" => "Email не подтверждён",
         "auth.invalid_or_expired_code" => "Недействительный или просроченный код",
         // OAuth
         "oauth.auth_config_error" => "Ошибка конфигурации аутентификации",
         "oauth.pkce_invalid" => "Неверный верификатор кода PKCE",
         "oauth.redirect_uri_mismatch" => "Несоответствие redirect URI",
         "oauth.refresh_no_user" => "Refresh-токен не связан ни с одним пользователем",
+        // Error codes (`CodedError::error_code`)
+        "error.validation" => "Некорректные входные данные",
+        "error.unauthenticated" => "Требуется аутентификация",
+        "error.forbidden" => "Доступ запрещён",
+        "error.not_found" => "Ресурс не найден",
+        "error.conflict" => "Ресурс уже существует или был изменён",
+        "error.rate_limited" => "Слишком много запросов",
+        "error.database" => "Не удалось получить доступ к данным",
+        "error.external_service" => "Внешний сервис недоступен",
+        "error.internal" => "Внутренняя ошибка сервера",
+        "error.timeout" => "Превышено время ожидания запроса",
+        "error.business_logic" => "Операция недопустима",
+        "auth.invalid_access_token" => "Недействительный токен доступа",
+        "page.not_found" => "Страница не найдена",
+        "page.block_not_found" => "Блок страницы не найден",
+        "page.menu_not_found" => "Меню не найдено",
+        "page.duplicate_slug" => "Страница с таким slug уже существует",
+        "page.cannot_delete_published" => "Опубликованную страницу нельзя удалить",
+        "page.feature_disabled" => "Функция отключена для тенанта",
+        "page.unknown_layout" => "Выбранный макет страницы недоступен",
+        "content.node_not_found" => "Контент не найден",
+        "content.category_not_found" => "Категория не найдена",
+        "content.translation_not_found" => "Перевод не найден",
+        "content.duplicate_slug" => "Контент с таким slug уже существует",
+        "content.concurrent_modification" => {
+            "Контент был изменён другим запросом. Обновите данные и повторите попытку"
+        }
+        "commerce.product_not_found" => "Товар не найден",
+        "commerce.variant_not_found" => "Вариант товара не найден",
+        "commerce.duplicate_handle" => "Товар с таким handle уже существует",
+        "commerce.duplicate_sku" => "Товар с таким SKU уже существует",
+        "commerce.invalid_price" => "Некорректная цена",
+        "commerce.insufficient_inventory" => "Недостаточно товара на складе",
+        "commerce.invalid_option_combination" => "Выбранные опции товара недоступны",
+        "commerce.no_variants" => "У товара должен быть хотя бы один вариант",
+        "commerce.cannot_delete_published" => "Опубликованный товар нельзя удалить",
         _ => return None,
     })
 }
@@ -172,6 +246,7 @@ fn translate_es(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token" => "Token de restablecimiento inválido",
         "auth.invalid_invite_token" => "Token de invitación inválido",
         "auth.invalid_verification_token" => "Token de verificación inválido",
+        "auth.email_not_verified" => "El correo electrónico no está verificado",
         "auth.invalid_or_expired_code" => "Código inválido o expirado",
         // OAuth
         "oauth.auth_config_error" => "Error de configuración de autenticación",
@@ -212,6 +287,7 @@ fn translate_de(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token" => "Ungültiges Passwort-Reset-Token",
         "auth.invalid_invite_token" => "Ungültiges Einladungstoken",
         "auth.invalid_verification_token" => "Ungültiges Verifizierungstoken",
+        "auth.email_not_verified" => "E-Mail-Adresse ist nicht bestätigt",
         "auth.invalid_or_expired_code" => "Ungültiger oder abgelaufener Code",
         // OAuth
         "oauth.auth_config_error" => "Fehler in der Authentifizierungskonfiguration",
@@ -250,6 +326,7 @@ fn translate_fr(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token"          => "Jeton de réinitialisation invalide",
         "auth.invalid_invite_token"         => "Jeton d'invitation invalide",
         "auth.invalid_verification_token"   => "Jeton de vérification invalide",
+        "auth.email_not_verified"           => "L'adresse email n'est pas vérifiée",
         "auth.invalid_or_expired_code"      => "Code invalide ou expiré",
         // OAuth
         "oauth.auth_config_error"           => "Erreur de configuration d'authentification",
@@ -288,6 +365,7 @@ fn translate_zh(key: &str) -> Option<&'static str> {
         "auth.invalid_reset_token" => "密码重置令牌无效",
         "auth.invalid_invite_token" => "邀请令牌无效",
         "auth.invalid_verification_token" => "验证令牌无效",
+        "auth.email_not_verified" => "邮箱尚未验证",
         "auth.invalid_or_expired_code" => "验证码无效或已过期",
         // OAuth
         "oauth.auth_config_error" => "身份验证配置错误",
@@ -406,6 +484,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_translate_error_codes() {
+        assert_eq!(
+            translate(Locale::En, "commerce.insufficient_inventory"),
+            "Not enough items in stock",
+        );
+        assert_eq!(
+            translate(Locale::Ru, "page.not_found"),
+            "Страница не найдена"
+        );
+        // Locales without an entry fall back to English
+        assert_eq!(translate(Locale::De, "page.not_found"), "Page not found");
+    }
+
     #[test]
     fn test_translate_fallback_to_english() {
         // Russian missing key falls back to English
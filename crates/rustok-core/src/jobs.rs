@@ -0,0 +1,333 @@
+//! Periodic background jobs.
+//!
+//! [`PeriodicJob`] runs an async closure on a fixed interval with the
+//! behaviour every background loop needs: a random startup stagger so
+//! replicas do not fire in lockstep, panic isolation so one bad run does not
+//! end the loop, optional retry backoff after failures, graceful stop and the
+//! `rustok_job_*` metrics.
+//!
+//! ```ignore
+//! let handle = PeriodicJob::new("session_sweeper", Duration::from_secs(60), move || {
+//!     let db = db.clone();
+//!     async move { sweep_expired_sessions(&db).await }
+//! })
+//! .with_stop_signal(stop_rx)
+//! .spawn();
+//!
+//! handle.stop().await;
+//! ```
+
+use std::fmt::Display;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::async_utils::BackoffConfig;
+
+/// Upper bound of the default startup stagger.
+pub const DEFAULT_MAX_STAGGER: Duration = Duration::from_secs(30);
+
+/// Outcome of a single job run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobRunOutcome {
+    Succeeded,
+    Failed,
+    Panicked,
+}
+
+impl JobRunOutcome {
+    fn error_kind(self) -> Option<&'static str> {
+        match self {
+            Self::Succeeded => None,
+            Self::Failed => Some("error"),
+            Self::Panicked => Some("panic"),
+        }
+    }
+}
+
+/// A named async task run every `interval` until stopped.
+pub struct PeriodicJob<F> {
+    name: String,
+    interval: Duration,
+    max_stagger: Duration,
+    backoff: Option<BackoffConfig>,
+    stop_signal: Option<watch::Receiver<bool>>,
+    run: F,
+}
+
+impl<F, Fut, E> PeriodicJob<F>
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: Display + Send + 'static,
+{
+    /// Job that waits `interval` between the end of one run and the start
+    /// of the next. The first run is delayed by a random stagger of up to
+    /// `min(interval, DEFAULT_MAX_STAGGER)`.
+    pub fn new(name: impl Into<String>, interval: Duration, run: F) -> Self {
+        Self {
+            name: name.into(),
+            interval,
+            max_stagger: interval.min(DEFAULT_MAX_STAGGER),
+            backoff: None,
+            stop_signal: None,
+            run,
+        }
+    }
+
+    /// Upper bound of the random startup delay; `Duration::ZERO` runs the
+    /// first iteration immediately.
+    pub fn with_max_stagger(mut self, max_stagger: Duration) -> Self {
+        self.max_stagger = max_stagger;
+        self
+    }
+
+    /// After a failed run, retry with `backoff` delays (capped by the
+    /// interval) for up to `max_retries` consecutive failures before falling
+    /// back to the regular interval.
+    pub fn with_retry(mut self, backoff: BackoffConfig) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
+
+    /// Also stop when `signal` becomes `true`, e.g. a shared shutdown channel.
+    pub fn with_stop_signal(mut self, signal: watch::Receiver<bool>) -> Self {
+        self.stop_signal = Some(signal);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Run the job once, recording metrics. Panics are caught and reported
+    /// as [`JobRunOutcome::Panicked`].
+    pub async fn run_once(&self) -> JobRunOutcome {
+        let started = Instant::now();
+        let outcome = match AssertUnwindSafe(async { (self.run)().await })
+            .catch_unwind()
+            .await
+        {
+            Ok(Ok(())) => JobRunOutcome::Succeeded,
+            Ok(Err(error)) => {
+                tracing::error!(job = %self.name, error = %error, "Periodic job run failed");
+                JobRunOutcome::Failed
+            }
+            Err(_) => {
+                tracing::error!(job = %self.name, "Periodic job run panicked");
+                JobRunOutcome::Panicked
+            }
+        };
+        rustok_telemetry::metrics::record_job_run(
+            &self.name,
+            started.elapsed().as_secs_f64(),
+            outcome.error_kind(),
+        );
+        outcome
+    }
+
+    /// Start the loop on the current Tokio runtime.
+    pub fn spawn(self) -> PeriodicJobHandle {
+        let (stop_tx, stop_rx) = watch::channel(false);
+        let name = self.name.clone();
+        let handle = tokio::spawn(self.run_loop(stop_rx));
+        PeriodicJobHandle {
+            name,
+            stop_tx,
+            handle,
+        }
+    }
+
+    async fn run_loop(self, mut own_stop: watch::Receiver<bool>) {
+        let mut external_stop = self.stop_signal.clone();
+        let stagger = self.max_stagger.mul_f64(rand::random::<f64>());
+        tracing::info!(job = %self.name, interval = ?self.interval, "Periodic job started");
+
+        let mut delay = stagger;
+        let mut consecutive_failures = 0u32;
+        loop {
+            if !sleep_unless_stopped(delay, &mut own_stop, external_stop.as_mut()).await {
+                break;
+            }
+
+            delay = match self.run_once().await {
+                JobRunOutcome::Succeeded => {
+                    consecutive_failures = 0;
+                    self.interval
+                }
+                JobRunOutcome::Failed | JobRunOutcome::Panicked => {
+                    consecutive_failures = consecutive_failures.saturating_add(1);
+                    self.retry_delay(consecutive_failures)
+                }
+            };
+        }
+
+        tracing::info!(job = %self.name, "Periodic job stopped");
+    }
+
+    fn retry_delay(&self, consecutive_failures: u32) -> Duration {
+        match &self.backoff {
+            Some(backoff) if consecutive_failures <= backoff.max_retries => backoff
+                .delay_for_attempt(consecutive_failures)
+                .min(self.interval),
+            _ => self.interval,
+        }
+    }
+}
+
+/// Sleep for `delay`; returns `false` as soon as either stop signal is set.
+async fn sleep_unless_stopped(
+    delay: Duration,
+    own_stop: &mut watch::Receiver<bool>,
+    external_stop: Option<&mut watch::Receiver<bool>>,
+) -> bool {
+    let external = async {
+        match external_stop {
+            Some(signal) => wait_for_stop(signal).await,
+            None => std::future::pending().await,
+        }
+    };
+
+    tokio::select! {
+        biased;
+        _ = wait_for_stop(own_stop) => false,
+        _ = external => false,
+        _ = tokio::time::sleep(delay) => true,
+    }
+}
+
+/// Resolves once the flag is `true`; never resolves if the sender is gone so
+/// a dropped handle detaches the job instead of stopping it.
+async fn wait_for_stop(signal: &mut watch::Receiver<bool>) {
+    if signal.wait_for(|stop| *stop).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Handle to a spawned [`PeriodicJob`]. Dropping it leaves the job running.
+pub struct PeriodicJobHandle {
+    name: String,
+    stop_tx: watch::Sender<bool>,
+    handle: JoinHandle<()>,
+}
+
+impl PeriodicJobHandle {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Ask the job to stop and wait for an in-progress run to finish.
+    pub async fn stop(self) {
+        let _ = self.stop_tx.send(true);
+        if let Err(error) = self.handle.await {
+            tracing::error!(job = %self.name, error = %error, "Periodic job task failed");
+        }
+    }
+}
+
+impl std::fmt::Debug for PeriodicJobHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeriodicJobHandle")
+            .field("name", &self.name)
+            .field("finished", &self.handle.is_finished())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn counting_job(
+        runs: Arc<AtomicU32>,
+        fail_every: u32,
+    ) -> PeriodicJob<
+        impl Fn() -> futures::future::BoxFuture<'static, Result<(), String>> + Send + Sync + 'static,
+    > {
+        PeriodicJob::new("test_job", Duration::from_millis(10), move || {
+            let runs = runs.clone();
+            async move {
+                let run = runs.fetch_add(1, Ordering::SeqCst) + 1;
+                if fail_every > 0 && run.is_multiple_of(fail_every) {
+                    panic!("run {run} panicked");
+                }
+                Ok(())
+            }
+            .boxed()
+        })
+        .with_max_stagger(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn keeps_running_after_a_panic_and_stops_gracefully() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let handle = counting_job(runs.clone(), 2).spawn();
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert!(!handle.is_finished());
+        handle.stop().await;
+
+        let after_stop = runs.load(Ordering::SeqCst);
+        assert!(after_stop >= 3, "expected several runs, got {after_stop}");
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), after_stop);
+    }
+
+    #[tokio::test]
+    async fn run_once_reports_outcome() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let job = counting_job(runs, 2);
+
+        assert_eq!(job.run_once().await, JobRunOutcome::Succeeded);
+        assert_eq!(job.run_once().await, JobRunOutcome::Panicked);
+
+        let failing = PeriodicJob::new("failing_job", Duration::from_secs(1), || async {
+            Err::<(), _>("boom")
+        });
+        assert_eq!(failing.run_once().await, JobRunOutcome::Failed);
+    }
+
+    #[tokio::test]
+    async fn external_stop_signal_ends_the_loop() {
+        let (stop_tx, stop_rx) = watch::channel(false);
+        let handle = counting_job(Arc::new(AtomicU32::new(0)), 0)
+            .with_stop_signal(stop_rx)
+            .spawn();
+
+        stop_tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !handle.is_finished() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("job should stop on the external signal");
+    }
+
+    #[test]
+    fn retry_delay_backs_off_then_returns_to_interval() {
+        let job = PeriodicJob::new("retry_job", Duration::from_secs(60), || async {
+            Ok::<(), String>(())
+        })
+        .with_retry(
+            BackoffConfig::default()
+                .with_initial_delay(Duration::from_secs(1))
+                .with_jitter(0.0)
+                .with_max_retries(2),
+        );
+
+        assert_eq!(job.retry_delay(1), Duration::from_secs(1));
+        assert_eq!(job.retry_delay(2), Duration::from_secs(2));
+        assert_eq!(job.retry_delay(3), Duration::from_secs(60));
+    }
+}
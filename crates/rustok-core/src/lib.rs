@@ -10,18 +10,22 @@ pub mod grapesjs;
 pub mod health;
 pub mod i18n;
 pub mod id;
+pub mod jobs;
 pub mod locale;
 pub mod metrics;
 pub mod migrations;
 pub mod module;
+pub mod pagination;
 pub mod permissions;
 pub mod rbac;
 pub mod registry;
 pub mod resilience;
 pub mod rt_json;
 pub mod security;
+pub mod shutdown;
 pub mod state_machine;
 pub mod tenant_validation;
+pub mod time_bounds;
 pub mod tracing;
 pub mod typed_error;
 pub mod types;
@@ -35,25 +39,27 @@ pub use async_utils::{
 };
 #[cfg(feature = "redis-cache")]
 pub use cache::RedisCacheBackend;
-pub use cache::{CacheStats, FallbackCacheBackend, InMemoryCacheBackend};
+pub use cache::{CacheStats, FallbackCacheBackend, InMemoryCacheBackend, InstrumentedCacheBackend};
 pub use config::{
     Config, ConfigError, ConfigLoader, ConfigSource, ConfigValue, DatabaseConfig, Secret,
     ServerConfig,
 };
 pub use content_format::{
-    normalize_content_format, prepare_content_payload, PreparedContent, CONTENT_FORMAT_GRAPESJS_V1,
-    CONTENT_FORMAT_MARKDOWN, CONTENT_FORMAT_RT_JSON_V1,
+    normalize_content_format, prepare_content_payload, BodyFormat, BodyFormatParseError,
+    PreparedContent, CONTENT_FORMAT_GRAPESJS_V1, CONTENT_FORMAT_MARKDOWN,
+    CONTENT_FORMAT_RT_JSON_V1,
 };
 pub use context::{AppContext, CacheBackend, SearchBackend};
 pub use error::{
-    Error, ErrorContext, ErrorKind, ErrorResponse, FieldError, Result, RichError,
+    CodedError, Error, ErrorContext, ErrorKind, ErrorResponse, FieldError, Result, RichError,
     ValidationErrorBuilder,
 };
 pub use events::{
-    event_schema, DispatcherConfig, DomainEvent, EventBus, EventBusStats, EventConsumerRuntime,
-    EventDispatcher, EventEnvelope, EventHandler, EventSchema, EventTransport, FieldSchema,
-    HandlerBuilder, HandlerResult, MemoryTransport, ReliabilityLevel, RunningDispatcher,
-    EVENT_SCHEMAS,
+    event_schema, ConcurrencyScope, DispatcherConfig, DomainEvent, DrainReport,
+    DuplicateHandlerError, EventBus, EventBusStats, EventConsumerRuntime, EventDispatcher,
+    EventEnvelope, EventHandler, EventSchema, EventTransport, FailureSink, FieldSchema,
+    FilteredReceiver, HandlerBuilder, HandlerFailure, HandlerResult, MemoryTransport,
+    ReliabilityLevel, RoutingRule, RoutingTable, RunningDispatcher, EVENT_SCHEMAS,
 };
 pub use field_schema::{
     create_field_definitions_table, drop_field_definitions_table, is_valid_field_key,
@@ -69,6 +75,7 @@ pub use health::{
 };
 pub use i18n::{extract_locale_from_header, extract_locale_tag_from_header, translate, Locale};
 pub use id::generate_id;
+pub use jobs::{JobRunOutcome, PeriodicJob, PeriodicJobHandle};
 pub use locale::{
     build_locale_candidates, is_valid_locale_tag, locale_primary_language, locale_tags_match,
     normalize_locale_tag, push_locale_candidate, PLATFORM_FALLBACK_LOCALE,
@@ -79,23 +86,26 @@ pub use module::{
     MigrationSource, ModuleContext, ModuleEventListenerContext, ModuleEventListenerRegistry,
     ModuleKind, ModuleRuntimeExtensions, RusToKModule,
 };
-pub use permissions::{Action, Permission, Resource};
-pub use rbac::{PermissionScope, Rbac, SecurityContext};
+pub use pagination::{fetch_with_total, Page};
+pub use permissions::{all_permissions, Action, Permission, Resource};
+pub use rbac::{permissions_for_role, PermissionScope, Rbac, SecurityContext};
 pub use registry::ModuleRegistry;
 pub use resilience::{
-    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState, RetryPolicy,
-    RetryStrategy,
+    BackoffPolicy, CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState,
+    RetryPolicy, RetryStrategy,
 };
 pub use rt_json::{
     sanitize_rt_json_before_html_render, validate_and_sanitize_rt_json, RtJsonValidationConfig,
     RtJsonValidationResult,
 };
 pub use security::{
-    audit::AuditEventType, headers::FrameOptions, run_security_audit, AuditEvent, AuditLogger,
-    InputValidator, RateLimitConfig, RateLimitResult, RateLimiter, SecurityAudit,
-    SecurityAuditResult, SecurityCategory, SecurityConfig, SecurityFinding, SecurityHeaders,
-    SecurityHeadersConfig, Severity, SsrfProtection, ValidationResult,
+    audit::AuditEventType, headers::FrameOptions, redact_urls_in, run_security_audit, AuditEvent,
+    AuditLogger, InputValidator, RateLimitConfig, RateLimitResult, RateLimiter, RedactedUrl,
+    SecurityAudit, SecurityAuditResult, SecurityCategory, SecurityConfig, SecurityFinding,
+    SecurityHeaders, SecurityHeadersConfig, Severity, SsrfProtection, ValidationResult,
 };
+pub use shutdown::{ShutdownCoordinator, ShutdownPhase, ShutdownReport, ShutdownToken};
+pub use time_bounds::{TimeBounds, DEFAULT_CLOCK_LEEWAY};
 pub use typed_error::{
     DomainError, ErrorCategory, ErrorCode, ErrorResponseBody, IntoTypedResult, TypedResult,
 };
@@ -114,10 +124,10 @@ pub mod prelude {
     pub use crate::domain_err;
     pub use crate::error::{Error, Result};
     pub use crate::events::{
-        event_schema, DispatcherConfig, DomainEvent, EventBus, EventBusStats, EventConsumerRuntime,
-        EventDispatcher, EventEnvelope, EventHandler, EventSchema, EventTransport, FieldSchema,
-        HandlerBuilder, HandlerResult, MemoryTransport, ReliabilityLevel, RunningDispatcher,
-        EVENT_SCHEMAS,
+        event_schema, DispatcherConfig, DomainEvent, DrainReport, EventBus, EventBusStats,
+        EventConsumerRuntime, EventDispatcher, EventEnvelope, EventHandler, EventSchema,
+        EventTransport, FieldSchema, HandlerBuilder, HandlerResult, MemoryTransport,
+        ReliabilityLevel, RoutingRule, RoutingTable, RunningDispatcher, EVENT_SCHEMAS,
     };
     pub use crate::field_schema::{
         CustomFieldsSchema, FieldDefinition, FieldType, HasCustomFields,
@@ -136,7 +146,7 @@ pub mod prelude {
     pub use crate::RedisCacheBackend;
     pub use crate::{
         AppContext, CacheBackend, CacheStats, FallbackCacheBackend, InMemoryCacheBackend,
-        SearchBackend,
+        InstrumentedCacheBackend, SearchBackend,
     };
     pub use uuid::Uuid;
 }
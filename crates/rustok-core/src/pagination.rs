@@ -0,0 +1,184 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, PaginatorTrait, QuerySelect, QueryTrait,
+    Select,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+const WINDOWED_TOTAL_ALIAS: &str = "__page_total";
+
+/// Standard envelope returned by list services.
+///
+/// Offset pagination fills `total` from [`fetch_with_total`]; keyset
+/// pagination leaves it `None` and hands out an opaque `next_cursor` instead.
+/// `has_more` is always set, so UI footers can drive "next" buttons without
+/// knowing which mode the service uses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: Option<u64>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Page of an offset-paginated listing with a known `total`.
+    pub fn offset(items: Vec<T>, total: u64, offset: u64) -> Self {
+        let has_more = offset.saturating_add(items.len() as u64) < total;
+        Self {
+            items,
+            total: Some(total),
+            next_cursor: None,
+            has_more,
+        }
+    }
+
+    /// Page of a keyset-paginated listing; `next_cursor` is `None` on the last page.
+    pub fn cursor(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            has_more: next_cursor.is_some(),
+            items,
+            total: None,
+            next_cursor,
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            items: Vec::new(),
+            total: Some(0),
+            next_cursor: None,
+            has_more: false,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            next_cursor: self.next_cursor,
+            has_more: self.has_more,
+        }
+    }
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Fetch `limit` rows of `select` starting at `offset` together with the
+/// total row count, taken from a windowed `COUNT(*) OVER()` in the same query.
+///
+/// A page past the end returns no rows and therefore no count; only then is a
+/// separate `COUNT` issued. Do not pass `DISTINCT` selects: the window is
+/// evaluated before de-duplication and would count duplicates.
+pub async fn fetch_with_total<E, C>(
+    db: &C,
+    select: Select<E>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<E::Model>, u64), DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+    C: ConnectionTrait,
+{
+    let statement = select
+        .clone()
+        .expr_as(Expr::cust("COUNT(*) OVER()"), WINDOWED_TOTAL_ALIAS)
+        .offset(offset)
+        .limit(limit)
+        .build(db.get_database_backend());
+    let rows = db.query_all(statement).await?;
+
+    let total = match rows.first() {
+        Some(row) => u64::try_from(row.try_get::<i64>("", WINDOWED_TOTAL_ALIAS)?).unwrap_or(0),
+        None if offset == 0 && limit > 0 => 0,
+        None => select.count(db).await?,
+    };
+    let items = rows
+        .iter()
+        .map(|row| E::Model::from_query_result(row, ""))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((items, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_page_reports_more_until_total_is_reached() {
+        let page = Page::offset(vec![1, 2], 5, 0);
+        assert_eq!(page.total, Some(5));
+        assert!(page.has_more);
+
+        let last = Page::offset(vec![5], 5, 4);
+        assert!(!last.has_more);
+        assert!(last.next_cursor.is_none());
+    }
+
+    #[test]
+    fn cursor_page_omits_total() {
+        let page = Page::cursor(vec!["a"], Some("next".to_string()));
+        assert!(page.has_more);
+        assert_eq!(page.total, None);
+
+        let last = Page::<&str>::cursor(Vec::new(), None);
+        assert!(!last.has_more);
+    }
+
+    mod item {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "page_items")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[tokio::test]
+    async fn fetch_with_total_reads_total_from_window() {
+        use sea_orm::{ConnectionTrait, Database, EntityTrait, QueryOrder};
+
+        let db = Database::connect("sqlite::memory:")
+            .await
+            .expect("sqlite in-memory connection should work");
+        db.execute_unprepared(
+            "CREATE TABLE page_items (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+             INSERT INTO page_items (id, name) VALUES (1, 'a'), (2, 'b'), (3, 'c');",
+        )
+        .await
+        .expect("table setup should succeed");
+        let select = || item::Entity::find().order_by_asc(item::Column::Id);
+
+        let (items, total) = fetch_with_total(&db, select(), 1, 1).await.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "b");
+        assert_eq!(total, 3);
+
+        let (past_end, total) = fetch_with_total(&db, select(), 10, 2).await.unwrap();
+        assert!(past_end.is_empty());
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn map_preserves_metadata() {
+        let page = Page::offset(vec![1, 2], 3, 0).map(|value| value * 10);
+        assert_eq!(page.items, vec![10, 20]);
+        assert_eq!(page.total, Some(3));
+        assert!(page.has_more);
+    }
+}
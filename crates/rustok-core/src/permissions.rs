@@ -58,6 +58,58 @@ pub enum Resource {
     WorkflowExecutions,
 }
 
+impl Resource {
+    /// Все варианты в порядке объявления.
+    pub const ALL: [Self; 46] = [
+        Self::Users,
+        Self::Tenants,
+        Self::Modules,
+        Self::Settings,
+        Self::FlexSchemas,
+        Self::FlexEntries,
+        Self::Products,
+        Self::Categories,
+        Self::Orders,
+        Self::Customers,
+        Self::Profiles,
+        Self::Regions,
+        Self::Payments,
+        Self::Fulfillments,
+        Self::Inventory,
+        Self::Discounts,
+        Self::Posts,
+        Self::Pages,
+        Self::Nodes,
+        Self::Media,
+        Self::Seo,
+        Self::Comments,
+        Self::Tags,
+        Self::Taxonomy,
+        Self::Analytics,
+        Self::Logs,
+        Self::Webhooks,
+        Self::BlogPosts,
+        Self::ForumCategories,
+        Self::ForumTopics,
+        Self::ForumReplies,
+        Self::Scripts,
+        Self::Mcp,
+        Self::AiProviders,
+        Self::AiTaskProfiles,
+        Self::AiSessions,
+        Self::AiRuns,
+        Self::AiApprovals,
+        Self::AiRouter,
+        Self::AiTextTasks,
+        Self::AiImageTasks,
+        Self::AiCodeTasks,
+        Self::AiAlloyTasks,
+        Self::AiMultimodalTasks,
+        Self::Workflows,
+        Self::WorkflowExecutions,
+    ];
+}
+
 impl fmt::Display for Resource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
@@ -189,6 +241,27 @@ pub enum Action {
     Override,
 }
 
+impl Action {
+    /// Все варианты в порядке объявления.
+    pub const ALL: [Self; 15] = [
+        Self::Create,
+        Self::Read,
+        Self::Update,
+        Self::Delete,
+        Self::List,
+        Self::Export,
+        Self::Import,
+        Self::Manage,
+        Self::Publish,
+        Self::Moderate,
+        Self::Execute,
+        Self::Run,
+        Self::Cancel,
+        Self::Resolve,
+        Self::Override,
+    ];
+}
+
 impl fmt::Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
@@ -250,6 +323,19 @@ impl Permission {
     }
 }
 
+/// Полный каталог разрешений: каждая пара [`Resource`] × [`Action`] в порядке
+/// объявления ресурсов, затем действий.
+pub fn all_permissions() -> Vec<Permission> {
+    Resource::ALL
+        .into_iter()
+        .flat_map(|resource| {
+            Action::ALL
+                .into_iter()
+                .map(move |action| Permission::new(resource, action))
+        })
+        .collect()
+}
+
 impl FromStr for Permission {
     type Err = String;
 
@@ -478,3 +564,23 @@ impl Permission {
     pub const WORKFLOW_EXECUTIONS_LIST: Self =
         Self::new(Resource::WorkflowExecutions, Action::List);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_permissions_cover_every_resource_action_pair() {
+        let permissions = all_permissions();
+
+        assert_eq!(permissions.len(), Resource::ALL.len() * Action::ALL.len());
+        assert_eq!(
+            permissions.first(),
+            Some(&Permission::new(Resource::Users, Action::Create))
+        );
+        assert!(permissions.contains(&Permission::WORKFLOW_EXECUTIONS_LIST));
+        for permission in permissions {
+            assert_eq!(permission.to_string().parse::<Permission>(), Ok(permission));
+        }
+    }
+}
@@ -309,6 +309,17 @@ impl Rbac {
     }
 }
 
+/// Статическое отображение роли в разрешения, отсортированное по строковому
+/// представлению (`resource:action`). `manage` на ресурсе подразумевает все
+/// остальные действия над ним (см. [`Rbac::has_permission`]), но в списке
+/// не разворачивается.
+pub fn permissions_for_role(role: UserRole) -> Vec<Permission> {
+    let mut permissions: Vec<Permission> =
+        Rbac::permissions_for_role(&role).iter().copied().collect();
+    permissions.sort_by_cached_key(Permission::to_string);
+    permissions
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum PermissionScope {
     All,
@@ -446,4 +457,20 @@ mod tests {
             &Permission::WORKFLOW_EXECUTIONS_LIST
         ));
     }
+
+    #[test]
+    fn permissions_for_role_is_sorted_and_matches_static_mapping() {
+        for role in UserRole::ALL {
+            let permissions = permissions_for_role(role.clone());
+            let rendered: Vec<String> = permissions.iter().map(Permission::to_string).collect();
+            let mut sorted = rendered.clone();
+            sorted.sort();
+
+            assert_eq!(rendered, sorted);
+            assert_eq!(permissions.len(), Rbac::permissions_for_role(&role).len());
+            assert!(permissions
+                .iter()
+                .all(|permission| crate::permissions::all_permissions().contains(permission)));
+        }
+    }
 }
@@ -0,0 +1,147 @@
+/// Backoff Policy
+///
+/// Shared delay schedule for every retry path (event handlers, outbox relay,
+/// workflow steps, `async_utils::retry`).
+use std::time::Duration;
+
+/// Delay schedule between retry attempts.
+///
+/// Attempts are numbered from 1 (the first retry); attempt 0 is the initial
+/// call and never waits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackoffPolicy {
+    /// Same delay before every retry.
+    Fixed(Duration),
+
+    /// `base * multiplier^(attempt - 1)`, capped at `max`.
+    Exponential {
+        base: Duration,
+        max: Duration,
+        multiplier: f64,
+    },
+
+    /// [`BackoffPolicy::Exponential`] randomized by `±jitter` (a fraction in
+    /// `0.0..=1.0`) of the delay and still capped at `max`, so concurrent
+    /// retriers spread out instead of firing together.
+    ExponentialJittered {
+        base: Duration,
+        max: Duration,
+        multiplier: f64,
+        jitter: f64,
+    },
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self::ExponentialJittered {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.1,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Delay to wait before retry number `attempt`.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+
+        match *self {
+            Self::Fixed(delay) => delay,
+
+            Self::Exponential {
+                base,
+                max,
+                multiplier,
+            } => exponential(base, max, multiplier, attempt),
+
+            Self::ExponentialJittered {
+                base,
+                max,
+                multiplier,
+                jitter,
+            } => {
+                let delay = exponential(base, max, multiplier, attempt).as_secs_f64();
+                let spread = delay * jitter.clamp(0.0, 1.0) * (rand::random::<f64>() * 2.0 - 1.0);
+                Duration::from_secs_f64((delay + spread).max(0.0)).min(max)
+            }
+        }
+    }
+}
+
+fn exponential(base: Duration, max: Duration, multiplier: f64, attempt: u32) -> Duration {
+    let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+    let secs = base.as_secs_f64() * multiplier.max(0.0).powi(exponent);
+
+    if !secs.is_finite() || secs >= max.as_secs_f64() {
+        max
+    } else {
+        Duration::from_secs_f64(secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initial_attempt_has_no_delay() {
+        assert_eq!(
+            BackoffPolicy::Fixed(Duration::from_secs(1)).delay_for_attempt(0),
+            Duration::ZERO
+        );
+        assert_eq!(
+            BackoffPolicy::default().delay_for_attempt(0),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_fixed_backoff() {
+        let policy = BackoffPolicy::Fixed(Duration::from_millis(250));
+
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_millis(250));
+        assert_eq!(policy.delay_for_attempt(7), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_exponential_backoff_caps_at_max() {
+        let policy = BackoffPolicy::Exponential {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            multiplier: 2.0,
+        };
+
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for_attempt(4), Duration::from_millis(800));
+        assert_eq!(policy.delay_for_attempt(5), Duration::from_secs(1));
+        assert_eq!(policy.delay_for_attempt(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_jittered_backoff_stays_within_bounds() {
+        let policy = BackoffPolicy::ExponentialJittered {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            multiplier: 2.0,
+            jitter: 0.5,
+        };
+
+        for _ in 0..1_000 {
+            let first = policy.delay_for_attempt(1);
+            assert!(first >= Duration::from_millis(50), "{first:?} below bound");
+            assert!(first <= Duration::from_millis(150), "{first:?} above bound");
+
+            let capped = policy.delay_for_attempt(10);
+            assert!(
+                capped >= Duration::from_millis(500),
+                "{capped:?} below bound"
+            );
+            assert!(capped <= Duration::from_secs(1), "{capped:?} above max");
+        }
+    }
+}
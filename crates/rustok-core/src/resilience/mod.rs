@@ -2,14 +2,17 @@
 ///
 /// This module provides:
 /// - Circuit Breaker: Prevent cascading failures
+/// - Backoff: Shared retry delay schedule
 /// - Retry: Automatic retry with backoff
 /// - Timeout: Enforce operation deadlines
 /// - Bulkhead: Isolate resources
+pub mod backoff;
 pub mod bulkhead;
 pub mod circuit_breaker;
 pub mod retry;
 pub mod timeout;
 
+pub use backoff::BackoffPolicy;
 pub use bulkhead::{Bulkhead, BulkheadConfig, BulkheadError, BulkheadStats};
 pub use circuit_breaker::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState,
@@ -3,6 +3,8 @@
 /// Automatically retries failed operations with configurable strategies.
 use std::time::Duration;
 
+use super::backoff::BackoffPolicy;
+
 /// Retry strategy
 #[derive(Debug, Clone)]
 pub enum RetryStrategy {
@@ -14,6 +16,9 @@ pub enum RetryStrategy {
 
     /// Linear backoff: delay = base * attempt
     Linear { base: Duration, max: Duration },
+
+    /// Delay taken from a shared [`BackoffPolicy`]
+    Backoff(BackoffPolicy),
 }
 
 impl RetryStrategy {
@@ -30,6 +35,8 @@ impl RetryStrategy {
                 let delay = base.saturating_mul(attempt);
                 delay.min(*max)
             }
+
+            RetryStrategy::Backoff(policy) => policy.delay_for_attempt(attempt),
         }
     }
 }
@@ -15,11 +15,13 @@
 pub mod audit;
 pub mod headers;
 pub mod rate_limit;
+pub mod redaction;
 pub mod validation;
 
 pub use audit::{AuditEvent, AuditEventType, AuditLogger, SecurityAudit, SiemConfig};
 pub use headers::{FrameOptions, SecurityHeaders, SecurityHeadersConfig};
 pub use rate_limit::{RateLimitConfig, RateLimitResult, RateLimiter};
+pub use redaction::{redact_urls_in, RedactedUrl};
 pub use validation::{InputValidator, SsrfProtection, ValidationResult};
 
 use serde::{Deserialize, Serialize};
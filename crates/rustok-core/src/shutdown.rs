@@ -0,0 +1,374 @@
+//! Coordinated shutdown of long-lived background tasks.
+//!
+//! Every task that must stop cleanly takes a [`ShutdownToken`] for the
+//! [`ShutdownPhase`] it belongs to, waits on [`ShutdownToken::triggered`] and
+//! drops (or [acknowledges](ShutdownToken::acknowledge)) the token once it has
+//! finished. [`ShutdownCoordinator::shutdown`] triggers the phases one after
+//! another and only moves on when every token of the current phase has been
+//! acknowledged or the phase timeout has elapsed, so producers are quiet
+//! before the dispatcher drains and the dispatcher is drained before the
+//! outbox is flushed.
+//!
+//! ```ignore
+//! let mut token = coordinator.token("outbox_relay", ShutdownPhase::Flush);
+//! tokio::spawn(async move {
+//!     token.triggered().await;
+//!     relay.process_pending_once().await.ok();
+//!     token.acknowledge();
+//! });
+//!
+//! let report = coordinator.shutdown(Duration::from_secs(10)).await;
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use tokio::sync::{oneshot, watch};
+use tokio::time::Instant;
+
+/// Shutdown stages, triggered in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShutdownPhase {
+    /// Tasks that create new work: schedulers, pollers, periodic jobs.
+    Producers,
+    /// In-process event dispatch, drained after producers have stopped.
+    Dispatch,
+    /// Outbox relay and event transports flushing what is left.
+    Flush,
+    /// Shared resources such as database pools, closed last.
+    Storage,
+}
+
+impl ShutdownPhase {
+    pub const ALL: [Self; 4] = [Self::Producers, Self::Dispatch, Self::Flush, Self::Storage];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Producers => "producers",
+            Self::Dispatch => "dispatch",
+            Self::Flush => "flush",
+            Self::Storage => "storage",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl std::fmt::Display for ShutdownPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Outcome of [`ShutdownCoordinator::shutdown`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Tokens acknowledged before their phase timed out.
+    pub acknowledged: usize,
+    /// Tasks still running when their phase timed out.
+    pub timed_out: Vec<(ShutdownPhase, String)>,
+}
+
+impl ShutdownReport {
+    pub fn is_clean(&self) -> bool {
+        self.timed_out.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct PendingTask {
+    id: u64,
+    phase: ShutdownPhase,
+    name: String,
+    done: oneshot::Receiver<()>,
+}
+
+type PendingTasks = Mutex<Vec<PendingTask>>;
+
+struct Inner {
+    phases: [watch::Sender<bool>; 4],
+    /// Live tokens not yet taken by a shutdown phase; a token removes itself
+    /// on drop, so released tokens do not pile up.
+    pending: Arc<PendingTasks>,
+    next_id: AtomicU64,
+}
+
+/// Hands out [`ShutdownToken`]s and triggers them phase by phase. Cloning is
+/// cheap and every clone controls the same set of tokens.
+#[derive(Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                phases: std::array::from_fn(|_| watch::channel(false).0),
+                pending: Arc::new(Mutex::new(Vec::new())),
+                next_id: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Register a task that [`Self::shutdown`] waits for in `phase`.
+    ///
+    /// A token taken after its phase has already run is triggered right away
+    /// but is not waited for.
+    pub fn token(&self, name: impl Into<String>, phase: ShutdownPhase) -> ShutdownToken {
+        let name = name.into();
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let (ack, done) = oneshot::channel();
+        lock_pending(&self.inner.pending).push(PendingTask {
+            id,
+            phase,
+            name: name.clone(),
+            done,
+        });
+
+        ShutdownToken {
+            id,
+            name,
+            phase,
+            signal: self.signal(phase),
+            registry: Arc::downgrade(&self.inner.pending),
+            _ack: ack,
+        }
+    }
+
+    /// Stop signal for `phase` that is not waited for, for best-effort tasks
+    /// that only take a `watch::Receiver<bool>`.
+    pub fn signal(&self, phase: ShutdownPhase) -> watch::Receiver<bool> {
+        self.inner.phases[phase.index()].subscribe()
+    }
+
+    pub fn is_triggered(&self, phase: ShutdownPhase) -> bool {
+        *self.inner.phases[phase.index()].borrow()
+    }
+
+    /// Trigger every phase in order, waiting up to `phase_timeout` for the
+    /// tokens of each phase to be acknowledged before moving on.
+    pub async fn shutdown(&self, phase_timeout: Duration) -> ShutdownReport {
+        let mut report = ShutdownReport::default();
+
+        for phase in ShutdownPhase::ALL {
+            self.inner.phases[phase.index()].send_replace(true);
+            let tasks = self.take_pending(phase);
+            tracing::info!(phase = %phase, tasks = tasks.len(), "Shutdown phase started");
+
+            let deadline = Instant::now() + phase_timeout;
+            for task in tasks {
+                // A dropped token closes the channel, which counts as an
+                // acknowledgement as well.
+                if tokio::time::timeout_at(deadline, task.done).await.is_ok() {
+                    report.acknowledged += 1;
+                } else {
+                    tracing::warn!(
+                        phase = %phase,
+                        task = %task.name,
+                        timeout_ms = phase_timeout.as_millis() as u64,
+                        "Task did not acknowledge shutdown in time"
+                    );
+                    report.timed_out.push((phase, task.name));
+                }
+            }
+        }
+
+        tracing::info!(
+            acknowledged = report.acknowledged,
+            timed_out = report.timed_out.len(),
+            "Shutdown sequence finished"
+        );
+        report
+    }
+
+    fn take_pending(&self, phase: ShutdownPhase) -> Vec<PendingTask> {
+        let mut pending = lock_pending(&self.inner.pending);
+        let (current, rest): (Vec<_>, Vec<_>) =
+            pending.drain(..).partition(|task| task.phase == phase);
+        *pending = rest;
+        current
+    }
+}
+
+fn lock_pending(pending: &PendingTasks) -> std::sync::MutexGuard<'_, Vec<PendingTask>> {
+    pending
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl std::fmt::Debug for ShutdownCoordinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pending = self
+            .inner
+            .pending
+            .lock()
+            .map(|pending| pending.len())
+            .unwrap_or_default();
+        f.debug_struct("ShutdownCoordinator")
+            .field("pending", &pending)
+            .finish()
+    }
+}
+
+/// A task's registration with a [`ShutdownCoordinator`]. Dropping the token
+/// acknowledges the shutdown, so keep it alive until the task has finished.
+#[derive(Debug)]
+pub struct ShutdownToken {
+    id: u64,
+    name: String,
+    phase: ShutdownPhase,
+    signal: watch::Receiver<bool>,
+    registry: Weak<PendingTasks>,
+    _ack: oneshot::Sender<()>,
+}
+
+impl ShutdownToken {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn phase(&self) -> ShutdownPhase {
+        self.phase
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.signal.borrow()
+    }
+
+    /// Resolves once the token's phase has been triggered. Never resolves if
+    /// the coordinator is gone, since then nobody can request a shutdown.
+    pub async fn triggered(&mut self) {
+        if self.signal.wait_for(|triggered| *triggered).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+
+    /// The phase signal as a plain `watch` receiver, e.g. for
+    /// [`crate::PeriodicJob::with_stop_signal`].
+    pub fn signal(&self) -> watch::Receiver<bool> {
+        self.signal.clone()
+    }
+
+    /// Report that the task has stopped.
+    pub fn acknowledge(self) {}
+}
+
+impl Drop for ShutdownToken {
+    fn drop(&mut self) {
+        // Still registered only if its phase has not been taken by a
+        // shutdown yet; otherwise dropping `_ack` acknowledges it.
+        if let Some(pending) = self.registry.upgrade() {
+            lock_pending(&pending).retain(|task| task.id != self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn phases_run_in_order_and_wait_for_acknowledgement() {
+        let coordinator = ShutdownCoordinator::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        // Registered in reverse so the order comes from the phases alone.
+        for phase in ShutdownPhase::ALL.into_iter().rev() {
+            let mut token = coordinator.token(phase.as_str(), phase);
+            let order = order.clone();
+            tokio::spawn(async move {
+                token.triggered().await;
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                order.lock().unwrap().push(token.phase());
+                token.acknowledge();
+            });
+        }
+
+        let report = coordinator.shutdown(Duration::from_secs(1)).await;
+
+        assert!(report.is_clean());
+        assert_eq!(report.acknowledged, 4);
+        assert_eq!(*order.lock().unwrap(), ShutdownPhase::ALL.to_vec());
+    }
+
+    #[tokio::test]
+    async fn later_phases_are_not_triggered_before_earlier_ones_finish() {
+        let coordinator = ShutdownCoordinator::new();
+        let mut producer = coordinator.token("producer", ShutdownPhase::Producers);
+        let storage = coordinator.signal(ShutdownPhase::Storage);
+        let storage_seen_early = Arc::new(AtomicUsize::new(0));
+
+        let seen = storage_seen_early.clone();
+        tokio::spawn(async move {
+            producer.triggered().await;
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            if *storage.borrow() {
+                seen.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        coordinator.shutdown(Duration::from_secs(1)).await;
+
+        assert_eq!(storage_seen_early.load(Ordering::SeqCst), 0);
+        assert!(coordinator.is_triggered(ShutdownPhase::Storage));
+    }
+
+    #[tokio::test]
+    async fn unacknowledged_tokens_time_out_without_blocking_later_phases() {
+        let coordinator = ShutdownCoordinator::new();
+        let stuck = coordinator.token("stuck_dispatcher", ShutdownPhase::Dispatch);
+        let mut flush = coordinator.token("outbox", ShutdownPhase::Flush);
+        let flushed = tokio::spawn(async move {
+            flush.triggered().await;
+        });
+
+        let report = coordinator.shutdown(Duration::from_millis(20)).await;
+
+        assert_eq!(
+            report.timed_out,
+            vec![(ShutdownPhase::Dispatch, "stuck_dispatcher".to_string())]
+        );
+        assert_eq!(report.acknowledged, 1);
+        flushed.await.unwrap();
+        drop(stuck);
+    }
+
+    #[test]
+    fn released_tokens_are_unregistered() {
+        let coordinator = ShutdownCoordinator::new();
+        let kept = coordinator.token("kept", ShutdownPhase::Producers);
+        for _ in 0..100 {
+            coordinator
+                .token("short_lived", ShutdownPhase::Producers)
+                .acknowledge();
+            drop(coordinator.token("dropped", ShutdownPhase::Flush));
+        }
+
+        let pending = lock_pending(&coordinator.inner.pending);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].name, kept.name());
+    }
+
+    #[tokio::test]
+    async fn token_taken_after_its_phase_is_already_triggered() {
+        let coordinator = ShutdownCoordinator::new();
+        coordinator.shutdown(Duration::from_millis(10)).await;
+
+        let mut late = coordinator.token("late", ShutdownPhase::Producers);
+        assert!(late.is_triggered());
+        tokio::time::timeout(Duration::from_secs(1), late.triggered())
+            .await
+            .expect("late token should resolve immediately");
+    }
+}
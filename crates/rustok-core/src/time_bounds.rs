@@ -0,0 +1,120 @@
+//! Validity windows for tokens, sessions and TTL-bound entries.
+//!
+//! Every expiry check goes through [`TimeBounds`] so clock skew between the
+//! machine that issued a timestamp and the one checking it is tolerated the
+//! same way everywhere.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Skew tolerated by [`TimeBounds`] unless a caller picks another leeway.
+pub const DEFAULT_CLOCK_LEEWAY: Duration = Duration::seconds(30);
+
+/// A `[not_before, not_after)` window widened by `leeway` on both ends.
+///
+/// An unset bound is open. With the leeway an entry stays valid until
+/// `not_after + leeway` and becomes valid at `not_before - leeway`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBounds {
+    pub not_before: Option<DateTime<Utc>>,
+    pub not_after: Option<DateTime<Utc>>,
+    pub leeway: Duration,
+}
+
+impl Default for TimeBounds {
+    fn default() -> Self {
+        Self {
+            not_before: None,
+            not_after: None,
+            leeway: DEFAULT_CLOCK_LEEWAY,
+        }
+    }
+}
+
+impl TimeBounds {
+    /// Window that ends at `not_after`.
+    pub fn until(not_after: DateTime<Utc>) -> Self {
+        Self {
+            not_after: Some(not_after),
+            ..Self::default()
+        }
+    }
+
+    /// Window that ends at `not_after`, or never when it is `None`.
+    pub fn until_opt(not_after: Option<DateTime<Utc>>) -> Self {
+        Self {
+            not_after,
+            ..Self::default()
+        }
+    }
+
+    pub fn not_before(mut self, not_before: DateTime<Utc>) -> Self {
+        self.not_before = Some(not_before);
+        self
+    }
+
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.not_after
+            .is_some_and(|not_after| now - self.leeway >= not_after)
+    }
+
+    pub fn is_premature_at(&self, now: DateTime<Utc>) -> bool {
+        self.not_before
+            .is_some_and(|not_before| now + self.leeway < not_before)
+    }
+
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        !self.is_expired_at(now) && !self.is_premature_at(now)
+    }
+
+    /// Oldest `not_after` still valid at `now`, for filtering rows in SQL
+    /// (`expires_at > cutoff`) with the same leeway as [`Self::is_expired_at`].
+    pub fn expiry_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.leeway
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_at(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerates_skew_within_leeway() {
+        let now = Utc::now();
+        let bounds = TimeBounds::until(now - Duration::seconds(10));
+
+        assert!(bounds.is_valid_at(now));
+        assert!(bounds.is_expired_at(now + DEFAULT_CLOCK_LEEWAY));
+        assert!(bounds.with_leeway(Duration::zero()).is_expired_at(now));
+        assert!(bounds.not_after.unwrap() > bounds.expiry_cutoff(now));
+    }
+
+    #[test]
+    fn not_before_is_widened_by_leeway() {
+        let now = Utc::now();
+        let bounds = TimeBounds::default()
+            .not_before(now + Duration::seconds(5))
+            .with_leeway(Duration::seconds(10));
+
+        assert!(bounds.is_valid_at(now));
+        assert!(bounds.is_premature_at(now - Duration::seconds(6)));
+    }
+
+    #[test]
+    fn open_bounds_never_expire() {
+        assert!(TimeBounds::until_opt(None).is_valid());
+        assert!(!TimeBounds::default().is_expired_at(Utc::now() + Duration::days(365)));
+    }
+}
@@ -36,6 +36,10 @@ pub enum UserRole {
     Customer,
 }
 
+impl UserRole {
+    pub const ALL: [Self; 4] = [Self::SuperAdmin, Self::Admin, Self::Manager, Self::Customer];
+}
+
 impl fmt::Display for UserRole {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
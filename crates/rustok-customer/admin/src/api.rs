@@ -263,7 +263,7 @@ async fn customer_list_native(
         let page = page.max(1);
         let per_page = per_page.clamp(1, 100);
         let service = CustomerService::new(app_ctx.db.clone());
-        let (items, total) = service
+        let customers = service
             .list_customers(
                 tenant.id,
                 ListCustomersInput {
@@ -276,11 +276,15 @@ async fn customer_list_native(
             .map_err(ServerFnError::new)?;
 
         Ok(CustomerList {
-            items: items.into_iter().map(map_customer_list_item).collect(),
-            total,
+            items: customers
+                .items
+                .into_iter()
+                .map(map_customer_list_item)
+                .collect(),
+            total: customers.total.unwrap_or_default(),
             page,
             per_page,
-            has_next: page.saturating_mul(per_page) < total,
+            has_next: customers.has_more,
         })
     }
     #[cfg(not(feature = "ssr"))]
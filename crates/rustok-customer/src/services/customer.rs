@@ -1,13 +1,13 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
 };
 use tracing::instrument;
 use uuid::Uuid;
 use validator::Validate;
 
-use rustok_core::generate_id;
+use rustok_core::{fetch_with_total, generate_id, Page};
 use rustok_profiles::ProfilesReader;
 
 use crate::dto::{
@@ -95,7 +95,7 @@ impl CustomerService {
         &self,
         tenant_id: Uuid,
         input: ListCustomersInput,
-    ) -> CustomerResult<(Vec<CustomerResponse>, u64)> {
+    ) -> CustomerResult<Page<CustomerResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
 
@@ -117,18 +117,16 @@ impl CustomerService {
             );
         }
 
-        let paginator = query
-            .order_by_desc(entities::customer::Column::UpdatedAt)
-            .paginate(&self.db, per_page);
-        let total = paginator.num_items().await?;
-        let items = paginator
-            .fetch_page(page.saturating_sub(1))
-            .await?
-            .into_iter()
-            .map(map_customer)
-            .collect();
+        let (rows, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::customer::Column::UpdatedAt),
+            (page - 1) * per_page,
+            per_page,
+        )
+        .await?;
+        let items = rows.into_iter().map(map_customer).collect();
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, (page - 1) * per_page))
     }
 
     pub async fn get_customer_with_profile<R: ProfilesReader>(
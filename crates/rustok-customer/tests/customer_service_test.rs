@@ -129,7 +129,7 @@ async fn list_customers_filters_by_search_and_paginates() {
         .await
         .unwrap();
 
-    let (items, total) = service
+    let customers = service
         .list_customers(
             tenant_id,
             ListCustomersInput {
@@ -141,9 +141,9 @@ async fn list_customers_filters_by_search_and_paginates() {
         .await
         .unwrap();
 
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].email, "beta@example.com");
+    assert_eq!(customers.total, Some(1));
+    assert_eq!(customers.items.len(), 1);
+    assert_eq!(customers.items[0].email, "beta@example.com");
 }
 
 #[tokio::test]
@@ -1,11 +1,15 @@
 //! Canonical event contracts crate for RusToK.
 
+mod reliability;
 mod schema;
 mod types;
+mod upcast;
 pub mod validation;
 
+pub use reliability::{required_reliability, ReliabilityLevel};
 pub use schema::{event_schema, EventSchema, FieldSchema, EVENT_SCHEMAS};
-pub use types::{DomainEvent, EventEnvelope};
+pub use types::{DomainEvent, EventEnvelope, UNKNOWN_EVENT_SOURCE};
+pub use upcast::{decode_envelope, UpcastError, Upcaster, UpcasterRegistry};
 pub use validation::{EventValidationError, ValidateEvent};
 
 pub use DomainEvent as RootDomainEvent;
@@ -0,0 +1,96 @@
+/// Delivery guarantee a transport offers or an event type needs.
+///
+/// Levels are ordered by durability, so a transport satisfies a requirement
+/// when its level is at least the required one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ReliabilityLevel {
+    InMemory,
+    Outbox,
+    Streaming,
+}
+
+impl ReliabilityLevel {
+    /// Whether the event must survive a process restart.
+    pub fn is_durable(self) -> bool {
+        self > Self::InMemory
+    }
+
+    pub fn satisfies(self, required: Self) -> bool {
+        self >= required
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::InMemory => "in_memory",
+            Self::Outbox => "outbox",
+            Self::Streaming => "streaming",
+        }
+    }
+}
+
+/// Event types other systems act on (orders, erasure, account and tenant
+/// lifecycle, builds). Exact names or `prefix.*` patterns.
+const DURABLE_EVENT_TYPES: &[&str] = &[
+    "order.*",
+    "customer.erased",
+    "user.registered",
+    "user.deleted",
+    "tenant.*",
+    "build.requested",
+];
+
+/// Reliability an event type needs. Everything not listed in
+/// [`DURABLE_EVENT_TYPES`] is best-effort and only travels on the in-memory
+/// bus.
+pub fn required_reliability(event_type: &str) -> ReliabilityLevel {
+    let durable = DURABLE_EVENT_TYPES
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => event_type.starts_with(prefix),
+            None => *pattern == event_type,
+        });
+
+    if durable {
+        ReliabilityLevel::Outbox
+    } else {
+        ReliabilityLevel::InMemory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durable_event_types_require_outbox() {
+        assert_eq!(
+            required_reliability("order.placed"),
+            ReliabilityLevel::Outbox
+        );
+        assert_eq!(
+            required_reliability("tenant.module.toggled"),
+            ReliabilityLevel::Outbox
+        );
+        assert_eq!(
+            required_reliability("user.deleted"),
+            ReliabilityLevel::Outbox
+        );
+        assert_eq!(
+            required_reliability("tag.created"),
+            ReliabilityLevel::InMemory
+        );
+        assert_eq!(
+            required_reliability("user.logged_in"),
+            ReliabilityLevel::InMemory
+        );
+    }
+
+    #[test]
+    fn levels_are_ordered_by_durability() {
+        assert!(ReliabilityLevel::Streaming.satisfies(ReliabilityLevel::Outbox));
+        assert!(ReliabilityLevel::Outbox.satisfies(ReliabilityLevel::InMemory));
+        assert!(!ReliabilityLevel::InMemory.satisfies(ReliabilityLevel::Outbox));
+        assert!(!ReliabilityLevel::InMemory.is_durable());
+        assert!(ReliabilityLevel::Streaming.is_durable());
+    }
+}
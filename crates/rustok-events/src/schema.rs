@@ -16,6 +16,15 @@ pub struct EventSchema {
 }
 
 impl EventSchema {
+    /// Stable identifier of this schema version,
+    /// `urn:rustok:event-schema:{event_type}:v{version}`.
+    pub fn uri(&self) -> String {
+        format!(
+            "urn:rustok:event-schema:{}:v{}",
+            self.event_type, self.version
+        )
+    }
+
     pub fn to_json_schema(&self) -> serde_json::Value {
         let properties: serde_json::Map<String, serde_json::Value> = self
             .fields
@@ -76,6 +85,7 @@ const NODE_PUBLISHED_FIELDS: &[FieldSchema] =
     &[field!("node_id", "uuid"), field!("kind", "string")];
 const NODE_UNPUBLISHED_FIELDS: &[FieldSchema] =
     &[field!("node_id", "uuid"), field!("kind", "string")];
+const NODES_PUBLISHED_FIELDS: &[FieldSchema] = &[field!("node_ids", "array<uuid>")];
 const NODE_DELETED_FIELDS: &[FieldSchema] = &[field!("node_id", "uuid"), field!("kind", "string")];
 const BODY_UPDATED_FIELDS: &[FieldSchema] =
     &[field!("node_id", "uuid"), field!("locale", "string")];
@@ -145,6 +155,8 @@ const ORDER_CANCELLED_FIELDS: &[FieldSchema] = &[
     field!("order_id", "uuid"),
     field!("reason", "string", optional),
 ];
+const CUSTOMER_ERASED_FIELDS: &[FieldSchema] =
+    &[field!("customer_id", "uuid"), field!("mode", "string")];
 
 const REINDEX_REQUESTED_FIELDS: &[FieldSchema] = &[
     field!("target_type", "string"),
@@ -329,6 +341,12 @@ pub const EVENT_SCHEMAS: &[EventSchema] = &[
         description: "A content node was unpublished.",
         fields: NODE_UNPUBLISHED_FIELDS,
     },
+    EventSchema {
+        event_type: "nodes.published",
+        version: 1,
+        description: "A batch of content nodes was published.",
+        fields: NODES_PUBLISHED_FIELDS,
+    },
     EventSchema {
         event_type: "node.deleted",
         version: 1,
@@ -503,6 +521,12 @@ pub const EVENT_SCHEMAS: &[EventSchema] = &[
         description: "Order cancelled.",
         fields: ORDER_CANCELLED_FIELDS,
     },
+    EventSchema {
+        event_type: "customer.erased",
+        version: 1,
+        description: "Customer data anonymized or deleted on an erasure request.",
+        fields: CUSTOMER_ERASED_FIELDS,
+    },
     EventSchema {
         event_type: "index.reindex_requested",
         version: 1,
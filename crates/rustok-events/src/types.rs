@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 use uuid::Uuid;
 
+use super::reliability::ReliabilityLevel;
+use super::schema::{event_schema, EventSchema};
 use super::validation::{validators, EventValidationError, ValidateEvent};
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -20,6 +22,22 @@ pub struct EventEnvelope {
     pub actor_id: Option<Uuid>,
     pub event: DomainEvent,
     pub retry_count: u32,
+    /// Crate that emitted the event, e.g. `rustok-content`. [`Self::new`]
+    /// fills in [`DomainEvent::source_module`]; envelopes stored before the
+    /// field existed deserialize as [`UNKNOWN_EVENT_SOURCE`].
+    #[serde(default = "unknown_event_source")]
+    pub source: String,
+    /// [`EventSchema::uri`] of the payload schema, when the event type is in
+    /// the schema registry.
+    #[serde(default)]
+    pub schema_uri: Option<String>,
+}
+
+/// [`EventEnvelope::source`] of envelopes that predate the field.
+pub const UNKNOWN_EVENT_SOURCE: &str = "rustok";
+
+fn unknown_event_source() -> String {
+    UNKNOWN_EVENT_SOURCE.to_string()
 }
 
 impl EventEnvelope {
@@ -27,6 +45,8 @@ impl EventEnvelope {
         let id = Uuid::from_bytes(Ulid::new().to_bytes());
         let event_type = event.event_type().to_string();
         let schema_version = event.schema_version();
+        let source = event.source_module().to_string();
+        let schema_uri = event_schema(&event_type).map(EventSchema::uri);
         Self {
             id,
             event_type,
@@ -39,8 +59,59 @@ impl EventEnvelope {
             actor_id,
             event,
             retry_count: 0,
+            source,
+            schema_uri,
         }
     }
+
+    /// This envelope as a structured-mode [CloudEvents 1.0] JSON object.
+    ///
+    /// `type` is `rustok.{event_type}`, `source` is `/rustok/{source}` (the
+    /// emitting crate), `dataschema` is [`Self::schema_uri`] when known and
+    /// `data` holds the event fields without the native `type`/`data`
+    /// wrapper. Envelope metadata without a CloudEvents attribute travels as
+    /// the extensions `tenantid`, `correlationid`, `causationid`, `actorid`,
+    /// `traceid` and `schemaversion`; absent optional values are omitted.
+    ///
+    /// The native serde form of [`EventEnvelope`] stays the wire format of
+    /// the outbox and transports; this is an interop view for external
+    /// consumers.
+    ///
+    /// [CloudEvents 1.0]: https://github.com/cloudevents/spec/blob/v1.0.2/cloudevents/spec.md
+    pub fn to_cloud_event(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut event = serde_json::Map::new();
+        event.insert("specversion".into(), "1.0".into());
+        event.insert("id".into(), self.id.to_string().into());
+        event.insert("source".into(), format!("/rustok/{}", self.source).into());
+        event.insert("type".into(), format!("rustok.{}", self.event_type).into());
+        event.insert("time".into(), self.timestamp.to_rfc3339().into());
+        event.insert("datacontenttype".into(), "application/json".into());
+        if let Some(schema_uri) = &self.schema_uri {
+            event.insert("dataschema".into(), schema_uri.clone().into());
+        }
+        event.insert("tenantid".into(), self.tenant_id.to_string().into());
+        event.insert(
+            "correlationid".into(),
+            self.correlation_id.to_string().into(),
+        );
+        if let Some(causation_id) = self.causation_id {
+            event.insert("causationid".into(), causation_id.to_string().into());
+        }
+        if let Some(actor_id) = self.actor_id {
+            event.insert("actorid".into(), actor_id.to_string().into());
+        }
+        if let Some(trace_id) = &self.trace_id {
+            event.insert("traceid".into(), trace_id.clone().into());
+        }
+        event.insert("schemaversion".into(), self.schema_version.into());
+        if let Some(data) = serde_json::to_value(&self.event)?
+            .as_object_mut()
+            .and_then(|native| native.remove("data"))
+        {
+            event.insert("data".into(), data);
+        }
+        Ok(serde_json::Value::Object(event))
+    }
 }
 
 impl std::fmt::Debug for EventEnvelope {
@@ -48,6 +119,7 @@ impl std::fmt::Debug for EventEnvelope {
         f.debug_struct("EventEnvelope")
             .field("id", &self.id)
             .field("type", &self.event_type)
+            .field("source", &self.source)
             .field("tenant_id", &self.tenant_id)
             .field("actor_id", &self.actor_id)
             .field("timestamp", &self.timestamp)
@@ -55,6 +127,14 @@ impl std::fmt::Debug for EventEnvelope {
     }
 }
 
+/// A typed domain event.
+///
+/// The serde representation is adjacently tagged and part of the wire
+/// contract: the variant name goes to `type` and its fields to `data`, e.g.
+/// `{"type":"OrderPlaced","data":{"order_id":"…","total":100,…}}`. Outbox
+/// rows, transports and the serialization tests rely on this shape; use
+/// [`EventEnvelope::to_cloud_event`] when a consumer needs a flat,
+/// standards-based form instead.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", content = "data")]
 pub enum DomainEvent {
@@ -82,6 +162,10 @@ pub enum DomainEvent {
         node_id: Uuid,
         kind: String,
     },
+    /// Several nodes published in one batch; replaces per-node `NodePublished`.
+    NodesPublished {
+        node_ids: Vec<Uuid>,
+    },
     NodeDeleted {
         node_id: Uuid,
         kind: String,
@@ -220,6 +304,12 @@ pub enum DomainEvent {
         order_id: Uuid,
         reason: Option<String>,
     },
+    /// Customer PII was scrubbed (`mode = "anonymize"`) or the customer and
+    /// their commerce records were removed (`mode = "delete"`).
+    CustomerErased {
+        customer_id: Uuid,
+        mode: String,
+    },
 
     // ════════════════════════════════════════════════════════════════
     // INDEX EVENTS (CQRS)
@@ -518,6 +608,7 @@ impl DomainEvent {
             Self::NodeTranslationUpdated { .. } => "node.translation.updated",
             Self::NodePublished { .. } => "node.published",
             Self::NodeUnpublished { .. } => "node.unpublished",
+            Self::NodesPublished { .. } => "nodes.published",
             Self::NodeDeleted { .. } => "node.deleted",
             Self::BodyUpdated { .. } => "body.updated",
 
@@ -552,6 +643,7 @@ impl DomainEvent {
             Self::OrderStatusChanged { .. } => "order.status_changed",
             Self::OrderCompleted { .. } => "order.completed",
             Self::OrderCancelled { .. } => "order.cancelled",
+            Self::CustomerErased { .. } => "customer.erased",
 
             Self::ReindexRequested { .. } => "index.reindex_requested",
             Self::IndexUpdated { .. } => "index.updated",
@@ -610,6 +702,105 @@ impl DomainEvent {
         }
     }
 
+    /// Crate that owns and emits this event type; the default
+    /// [`EventEnvelope::source`].
+    pub fn source_module(&self) -> &'static str {
+        match self {
+            Self::NodeCreated { .. }
+            | Self::NodeUpdated { .. }
+            | Self::NodeTranslationUpdated { .. }
+            | Self::NodePublished { .. }
+            | Self::NodeUnpublished { .. }
+            | Self::NodesPublished { .. }
+            | Self::NodeDeleted { .. }
+            | Self::BodyUpdated { .. }
+            | Self::TopicPromotedToPost { .. }
+            | Self::PostDemotedToTopic { .. }
+            | Self::TopicSplit { .. }
+            | Self::TopicsMerged { .. }
+            | Self::CanonicalUrlChanged { .. }
+            | Self::UrlAliasPurged { .. } => "rustok-content",
+
+            Self::CategoryCreated { .. }
+            | Self::CategoryUpdated { .. }
+            | Self::CategoryDeleted { .. }
+            | Self::TagCreated { .. }
+            | Self::TagAttached { .. }
+            | Self::TagDetached { .. } => "rustok-taxonomy",
+
+            Self::MediaUploaded { .. } | Self::MediaDeleted { .. } => "rustok-media",
+
+            Self::UserRegistered { .. }
+            | Self::UserLoggedIn { .. }
+            | Self::UserUpdated { .. }
+            | Self::UserDeleted { .. } => "rustok-auth",
+            Self::ProfileUpdated { .. } => "rustok-profiles",
+
+            Self::ProductCreated { .. }
+            | Self::ProductUpdated { .. }
+            | Self::ProductPublished { .. }
+            | Self::ProductDeleted { .. }
+            | Self::VariantCreated { .. }
+            | Self::VariantUpdated { .. }
+            | Self::VariantDeleted { .. } => "rustok-product",
+            Self::InventoryUpdated { .. } | Self::InventoryLow { .. } => "rustok-inventory",
+            Self::PriceUpdated { .. } => "rustok-pricing",
+            Self::OrderPlaced { .. }
+            | Self::OrderStatusChanged { .. }
+            | Self::OrderCompleted { .. }
+            | Self::OrderCancelled { .. } => "rustok-order",
+            Self::CustomerErased { .. } => "rustok-commerce",
+
+            Self::ReindexRequested { .. } | Self::IndexUpdated { .. } => "rustok-index",
+
+            Self::BlogPostCreated { .. }
+            | Self::BlogPostPublished { .. }
+            | Self::BlogPostUnpublished { .. }
+            | Self::BlogPostUpdated { .. }
+            | Self::BlogPostArchived { .. }
+            | Self::BlogPostDeleted { .. } => "rustok-blog",
+
+            Self::ForumTopicCreated { .. }
+            | Self::ForumTopicReplied { .. }
+            | Self::ForumTopicStatusChanged { .. }
+            | Self::ForumTopicPinned { .. }
+            | Self::ForumReplyStatusChanged { .. } => "rustok-forum",
+
+            Self::SeoMetaUpserted { .. }
+            | Self::SeoRevisionPublished { .. }
+            | Self::SeoRevisionRolledBack { .. }
+            | Self::SeoRedirectUpserted { .. }
+            | Self::SeoRedirectDisabled { .. }
+            | Self::SeoSitemapGenerated { .. }
+            | Self::SeoSitemapSubmitted { .. }
+            | Self::SeoBulkCompleted { .. }
+            | Self::SeoBulkPartial { .. }
+            | Self::SeoBulkFailed { .. } => "rustok-seo",
+
+            Self::TenantCreated { .. }
+            | Self::TenantUpdated { .. }
+            | Self::TenantModuleToggled { .. }
+            | Self::LocaleEnabled { .. }
+            | Self::LocaleDisabled { .. } => "rustok-tenant",
+            Self::SearchSettingsChanged { .. } | Self::SearchRebuildQueued { .. } => {
+                "rustok-search"
+            }
+
+            Self::BuildRequested { .. }
+            | Self::PlatformSettingsChanged { .. }
+            | Self::FieldDefinitionCreated { .. }
+            | Self::FieldDefinitionUpdated { .. }
+            | Self::FieldDefinitionDeleted { .. } => "rustok-server",
+
+            Self::FlexSchemaCreated { .. }
+            | Self::FlexSchemaUpdated { .. }
+            | Self::FlexSchemaDeleted { .. }
+            | Self::FlexEntryCreated { .. }
+            | Self::FlexEntryUpdated { .. }
+            | Self::FlexEntryDeleted { .. } => "flex",
+        }
+    }
+
     /// Returns the schema version for this event type.
     /// Increment this version when making breaking changes to the event structure.
     ///
@@ -623,6 +814,7 @@ impl DomainEvent {
             Self::NodeTranslationUpdated { .. } => 1,
             Self::NodePublished { .. } => 1,
             Self::NodeUnpublished { .. } => 1,
+            Self::NodesPublished { .. } => 1,
             Self::NodeDeleted { .. } => 1,
             Self::BodyUpdated { .. } => 1,
 
@@ -662,6 +854,7 @@ impl DomainEvent {
             Self::OrderStatusChanged { .. } => 1,
             Self::OrderCompleted { .. } => 1,
             Self::OrderCancelled { .. } => 1,
+            Self::CustomerErased { .. } => 1,
 
             // Index events (v1)
             Self::ReindexRequested { .. } => 1,
@@ -726,6 +919,11 @@ impl DomainEvent {
         }
     }
 
+    /// Delivery guarantee this event needs; see [`crate::required_reliability`].
+    pub fn required_reliability(&self) -> ReliabilityLevel {
+        crate::required_reliability(self.event_type())
+    }
+
     pub fn affects_index(&self) -> bool {
         matches!(
             self,
@@ -734,6 +932,7 @@ impl DomainEvent {
                 | Self::NodeTranslationUpdated { .. }
                 | Self::NodePublished { .. }
                 | Self::NodeUnpublished { .. }
+                | Self::NodesPublished { .. }
                 | Self::NodeDeleted { .. }
                 | Self::BodyUpdated { .. }
                 | Self::ProductCreated { .. }
@@ -811,6 +1010,18 @@ impl ValidateEvent for DomainEvent {
                 validators::validate_max_length("kind", kind, 64)?;
                 Ok(())
             }
+            Self::NodesPublished { node_ids } => {
+                if node_ids.is_empty() {
+                    return Err(EventValidationError::InvalidValue(
+                        "node_ids",
+                        "must not be empty".to_string(),
+                    ));
+                }
+                for id in node_ids {
+                    validators::validate_not_nil_uuid("node_ids[]", id)?;
+                }
+                Ok(())
+            }
             Self::BodyUpdated { node_id, locale } => {
                 validators::validate_not_nil_uuid("node_id", node_id)?;
                 validators::validate_not_empty("locale", locale)?;
@@ -1045,6 +1256,16 @@ impl ValidateEvent for DomainEvent {
                 }
                 Ok(())
             }
+            Self::CustomerErased { customer_id, mode } => {
+                validators::validate_not_nil_uuid("customer_id", customer_id)?;
+                if !matches!(mode.as_str(), "anonymize" | "delete") {
+                    return Err(EventValidationError::InvalidValue(
+                        "mode",
+                        "must be 'anonymize' or 'delete'".to_string(),
+                    ));
+                }
+                Ok(())
+            }
 
             // ════════════════════════════════════════════════════════════════
             // INDEX EVENTS
@@ -1900,4 +2121,81 @@ mod tests {
 
         assert!(event.validate().is_err());
     }
+
+    fn order_placed_envelope() -> EventEnvelope {
+        EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::OrderPlaced {
+                order_id: Uuid::new_v4(),
+                customer_id: None,
+                total: 4875,
+                currency: "EUR".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn domain_event_serializes_adjacently_tagged() {
+        let envelope = order_placed_envelope();
+
+        let native = serde_json::to_value(&envelope.event).unwrap();
+
+        assert_eq!(native["type"], "OrderPlaced");
+        assert_eq!(native["data"]["total"], 4875);
+        assert_eq!(native.as_object().unwrap().len(), 2);
+        assert_eq!(
+            serde_json::from_value::<DomainEvent>(native).unwrap(),
+            envelope.event
+        );
+    }
+
+    #[test]
+    fn to_cloud_event_produces_structured_cloudevent() {
+        let mut envelope = order_placed_envelope();
+        envelope.trace_id = Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string());
+        let actor_id = Uuid::new_v4();
+        envelope.actor_id = Some(actor_id);
+
+        let cloud_event = envelope.to_cloud_event().unwrap();
+
+        assert_eq!(cloud_event["specversion"], "1.0");
+        assert_eq!(cloud_event["id"], envelope.id.to_string());
+        assert_eq!(cloud_event["type"], "rustok.order.placed");
+        assert_eq!(cloud_event["source"], "/rustok/rustok-order");
+        assert_eq!(
+            cloud_event["dataschema"],
+            "urn:rustok:event-schema:order.placed:v1"
+        );
+        assert_eq!(cloud_event["datacontenttype"], "application/json");
+        assert_eq!(cloud_event["actorid"], actor_id.to_string());
+        assert_eq!(cloud_event["traceid"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(cloud_event["schemaversion"], 1);
+        assert_eq!(cloud_event["data"]["currency"], "EUR");
+        assert!(cloud_event["data"].get("type").is_none());
+        assert!(cloud_event.get("causationid").is_none());
+        assert!(DateTime::parse_from_rfc3339(cloud_event["time"].as_str().unwrap()).is_ok());
+        // Attribute names must be lowercase alphanumeric (CloudEvents 1.0).
+        assert!(cloud_event.as_object().unwrap().keys().all(|name| name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())));
+    }
+
+    #[test]
+    fn envelope_without_source_fields_still_deserializes() {
+        let mut json = serde_json::to_value(order_placed_envelope()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("source");
+        fields.remove("schema_uri");
+
+        let restored: EventEnvelope = serde_json::from_value(json).unwrap();
+
+        assert_eq!(restored.source, UNKNOWN_EVENT_SOURCE);
+        assert_eq!(restored.schema_uri, None);
+        assert!(restored
+            .to_cloud_event()
+            .unwrap()
+            .get("dataschema")
+            .is_none());
+    }
 }
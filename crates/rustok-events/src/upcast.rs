@@ -0,0 +1,334 @@
+//! Event schema upcasting
+//!
+//! Stored and replayed envelopes keep the `schema_version` they were written
+//! with. When an event type's shape changes, its [`EventSchema::version`] is
+//! bumped and an upcaster is registered that turns the previous version's
+//! `data` object into the next one. [`UpcasterRegistry::decode_envelope`]
+//! applies the chain before handing the JSON to serde; [`decode_envelope`]
+//! does the same with the steps registered in this crate and is what the
+//! outbox relay and the Iggy JSON serializer decode with.
+//!
+//! [`EventSchema::version`]: crate::EventSchema::version
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::schema::event_schema;
+use crate::types::EventEnvelope;
+
+/// Steps for every event type whose schema version has moved past 1.
+/// Register the new step here in the change that bumps the version.
+static REGISTERED_UPCASTERS: LazyLock<UpcasterRegistry> = LazyLock::new(UpcasterRegistry::new);
+
+/// Deserialize a stored envelope, upcasting it with the steps registered in
+/// this crate.
+pub fn decode_envelope(envelope: Value) -> Result<EventEnvelope, UpcastError> {
+    REGISTERED_UPCASTERS.decode_envelope(envelope)
+}
+
+/// Turns the `data` of one schema version into the `data` of the next.
+pub type Upcaster = fn(Value) -> Result<Value, UpcastError>;
+
+/// Errors that can occur while upcasting a stored event
+#[derive(Debug, Error)]
+pub enum UpcastError {
+    /// No upcaster is registered for a step of the chain
+    #[error("no upcaster for '{event_type}' v{from_version}")]
+    MissingUpcaster {
+        event_type: String,
+        from_version: u16,
+    },
+
+    /// The payload was written by a newer schema than this build knows
+    #[error("'{event_type}' v{version} is newer than the current v{current}")]
+    FutureVersion {
+        event_type: String,
+        version: u16,
+        current: u16,
+    },
+
+    /// An upcaster rejected the payload
+    #[error("invalid '{event_type}' payload: {reason}")]
+    InvalidPayload { event_type: String, reason: String },
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<UpcastError> for serde_json::Error {
+    fn from(error: UpcastError) -> Self {
+        match error {
+            UpcastError::Json(error) => error,
+            other => serde::de::Error::custom(other),
+        }
+    }
+}
+
+/// Upcasters keyed by event type and the version they upgrade from.
+#[derive(Clone, Debug, Default)]
+pub struct UpcasterRegistry {
+    steps: HashMap<(String, u16), Upcaster>,
+}
+
+impl UpcasterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the step `from_version -> from_version + 1` for `event_type`.
+    pub fn register(
+        mut self,
+        event_type: impl Into<String>,
+        from_version: u16,
+        upcaster: Upcaster,
+    ) -> Self {
+        self.steps
+            .insert((event_type.into(), from_version), upcaster);
+        self
+    }
+
+    /// Upgrade `data` of `event_type` from `version` to `target`, applying
+    /// each registered step in order.
+    pub fn upcast(
+        &self,
+        event_type: &str,
+        version: u16,
+        target: u16,
+        mut data: Value,
+    ) -> Result<Value, UpcastError> {
+        if version > target {
+            return Err(UpcastError::FutureVersion {
+                event_type: event_type.to_string(),
+                version,
+                current: target,
+            });
+        }
+        for from_version in version..target {
+            let upcaster = self
+                .steps
+                .get(&(event_type.to_string(), from_version))
+                .ok_or_else(|| UpcastError::MissingUpcaster {
+                    event_type: event_type.to_string(),
+                    from_version,
+                })?;
+            data = upcaster(data)?;
+        }
+        Ok(data)
+    }
+
+    /// Deserialize a stored envelope, upcasting its event to the current
+    /// schema version from [`crate::EVENT_SCHEMAS`] first.
+    ///
+    /// Event types without a registered schema are decoded as they are.
+    pub fn decode_envelope(&self, envelope: Value) -> Result<EventEnvelope, UpcastError> {
+        let current = envelope["event_type"]
+            .as_str()
+            .and_then(event_schema)
+            .map(|schema| schema.version);
+        self.decode_envelope_at(envelope, current)
+    }
+
+    /// [`Self::decode_envelope`] against an explicit current version.
+    fn decode_envelope_at(
+        &self,
+        mut envelope: Value,
+        current: Option<u16>,
+    ) -> Result<EventEnvelope, UpcastError> {
+        let event_type = envelope["event_type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        let version = envelope["schema_version"].as_u64().unwrap_or(1) as u16;
+
+        if let Some(current) = current {
+            if version != current {
+                let data = envelope["event"]["data"].take();
+                envelope["event"]["data"] = self.upcast(&event_type, version, current, data)?;
+                envelope["schema_version"] = current.into();
+            }
+        }
+
+        Ok(serde_json::from_value(envelope)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DomainEvent;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    /// Hypothetical v2 of `node.created` that added a `locale` field.
+    fn node_created_v1_to_v2(mut data: Value) -> Result<Value, UpcastError> {
+        let fields = data
+            .as_object_mut()
+            .ok_or_else(|| UpcastError::InvalidPayload {
+                event_type: "node.created".to_string(),
+                reason: "data is not an object".to_string(),
+            })?;
+        fields
+            .entry("locale")
+            .or_insert_with(|| Value::String("en".to_string()));
+        Ok(data)
+    }
+
+    /// Hypothetical v3 that renamed `kind` to `node_kind`.
+    fn node_created_v2_to_v3(mut data: Value) -> Result<Value, UpcastError> {
+        let kind = data["kind"].take();
+        data.as_object_mut().unwrap().remove("kind");
+        data["node_kind"] = kind;
+        Ok(data)
+    }
+
+    fn node_created_v1() -> Value {
+        json!({
+            "node_id": Uuid::new_v4(),
+            "kind": "article",
+            "author_id": null,
+        })
+    }
+
+    #[test]
+    fn upcasts_v1_node_created_with_new_field_defaulted() {
+        let registry = UpcasterRegistry::new().register("node.created", 1, node_created_v1_to_v2);
+        let v1 = node_created_v1();
+
+        let v2 = registry.upcast("node.created", 1, 2, v1.clone()).unwrap();
+
+        assert_eq!(v2["locale"], "en");
+        assert_eq!(v2["node_id"], v1["node_id"]);
+        assert_eq!(v2["kind"], "article");
+    }
+
+    #[test]
+    fn applies_upcaster_chain_in_version_order() {
+        let registry = UpcasterRegistry::new()
+            .register("node.created", 2, node_created_v2_to_v3)
+            .register("node.created", 1, node_created_v1_to_v2);
+
+        let v3 = registry
+            .upcast("node.created", 1, 3, node_created_v1())
+            .unwrap();
+
+        assert_eq!(v3["locale"], "en");
+        assert_eq!(v3["node_kind"], "article");
+        assert!(v3.get("kind").is_none());
+    }
+
+    #[test]
+    fn missing_step_and_future_version_are_errors() {
+        let registry = UpcasterRegistry::new().register("node.created", 1, node_created_v1_to_v2);
+
+        assert!(matches!(
+            registry.upcast("node.created", 1, 3, node_created_v1()),
+            Err(UpcastError::MissingUpcaster {
+                from_version: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            registry.upcast("node.created", 2, 1, node_created_v1()),
+            Err(UpcastError::FutureVersion {
+                version: 2,
+                current: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn decode_envelope_reads_current_version_unchanged() {
+        let envelope = EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::NodeCreated {
+                node_id: Uuid::new_v4(),
+                kind: "article".to_string(),
+                author_id: None,
+            },
+        );
+        let stored = serde_json::to_value(&envelope).unwrap();
+
+        let decoded = UpcasterRegistry::new().decode_envelope(stored).unwrap();
+
+        assert_eq!(decoded.id, envelope.id);
+        assert_eq!(decoded.event, envelope.event);
+        assert_eq!(decoded.schema_version, 1);
+    }
+
+    #[test]
+    fn decode_envelope_upcasts_v1_payload_to_the_current_version() {
+        let envelope = EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::NodeCreated {
+                node_id: Uuid::new_v4(),
+                kind: "article".to_string(),
+                author_id: None,
+            },
+        );
+        let stored = serde_json::to_value(&envelope).unwrap();
+        let registry = UpcasterRegistry::new().register("node.created", 1, node_created_v1_to_v2);
+
+        let decoded = registry.decode_envelope_at(stored, Some(2)).unwrap();
+
+        assert_eq!(decoded.id, envelope.id);
+        assert_eq!(decoded.schema_version, 2);
+        assert_eq!(decoded.event, envelope.event);
+    }
+
+    #[test]
+    fn registered_decoder_reads_stored_v1_envelope() {
+        let node_id = Uuid::new_v4();
+        let stored = json!({
+            "id": Uuid::new_v4(),
+            "event_type": "node.created",
+            "schema_version": 1,
+            "correlation_id": Uuid::new_v4(),
+            "causation_id": null,
+            "tenant_id": Uuid::new_v4(),
+            "trace_id": null,
+            "timestamp": "2025-01-01T00:00:00Z",
+            "actor_id": null,
+            "event": {
+                "type": "NodeCreated",
+                "data": { "node_id": node_id, "kind": "article", "author_id": null }
+            },
+            "retry_count": 0
+        });
+
+        let decoded = decode_envelope(stored).unwrap();
+
+        assert_eq!(
+            decoded.event,
+            DomainEvent::NodeCreated {
+                node_id,
+                kind: "article".to_string(),
+                author_id: None,
+            }
+        );
+        assert_eq!(decoded.source, crate::UNKNOWN_EVENT_SOURCE);
+    }
+
+    #[test]
+    fn decode_envelope_rejects_versions_newer_than_the_schema() {
+        let envelope = EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        );
+        let mut stored = serde_json::to_value(&envelope).unwrap();
+        stored["schema_version"] = 7.into();
+
+        assert!(matches!(
+            UpcasterRegistry::new().decode_envelope(stored),
+            Err(UpcastError::FutureVersion { version: 7, .. })
+        ));
+    }
+}
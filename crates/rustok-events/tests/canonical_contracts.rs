@@ -31,6 +31,9 @@ fn sample_events() -> Vec<DomainEvent> {
             node_id: id(6),
             kind: "article".to_string(),
         },
+        DomainEvent::NodesPublished {
+            node_ids: vec![id(5), id(6)],
+        },
         DomainEvent::NodeDeleted {
             node_id: id(7),
             kind: "article".to_string(),
@@ -127,6 +130,10 @@ fn sample_events() -> Vec<DomainEvent> {
             order_id: id(45),
             reason: Some("customer_request".to_string()),
         },
+        DomainEvent::CustomerErased {
+            customer_id: id(45),
+            mode: "anonymize".to_string(),
+        },
         DomainEvent::ReindexRequested {
             target_type: "product".to_string(),
             target_id: Some(id(46)),
@@ -393,6 +400,12 @@ fn every_domain_event_variant_roundtrips_through_envelope_json() {
         assert_eq!(restored.tenant_id, envelope.tenant_id);
         assert_eq!(restored.actor_id, envelope.actor_id);
         assert_eq!(restored.event, envelope.event);
+        assert_eq!(restored.source, event.source_module());
+        assert_eq!(
+            restored.schema_uri,
+            event_schema(event.event_type()).map(|schema| schema.uri())
+        );
+        assert!(restored.schema_uri.is_some());
     }
 }
 
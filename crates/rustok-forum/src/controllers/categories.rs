@@ -58,7 +58,7 @@ pub async fn list_categories(
     let pagination = params.pagination.unwrap_or_default();
     let service = CategoryService::new(ctx.db.clone());
     let list_started_at = Instant::now();
-    let (categories, _) = service
+    let categories = service
         .list_paginated_with_locale_fallback(
             tenant.id,
             auth.security_context(),
@@ -68,7 +68,8 @@ pub async fn list_categories(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .items;
     metrics::record_read_path_query(
         "http",
         "forum.list_categories",
@@ -56,7 +56,7 @@ pub async fn list_replies(
     filter.per_page = effective_limit;
     let service = ReplyService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
     let list_started_at = Instant::now();
-    let (replies, _) = service
+    let replies = service
         .list_for_topic_with_locale_fallback(
             tenant.id,
             auth.security_context(),
@@ -65,7 +65,8 @@ pub async fn list_replies(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .items;
     metrics::record_read_path_query(
         "http",
         "forum.list_replies",
@@ -8,6 +8,7 @@ use rustok_api::{
     has_any_effective_permission, loco::transactional_event_bus_from_context, AuthContext,
     RequestContext, TenantContext,
 };
+use rustok_content::MetadataVisibility;
 use rustok_core::Permission;
 use rustok_telemetry::metrics;
 use serde::Deserialize;
@@ -83,9 +84,10 @@ pub async fn list_topics(
     let requested_limit = Some(filter.per_page);
     let effective_limit = clamp_per_page(filter.per_page);
     filter.per_page = effective_limit;
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let list_started_at = Instant::now();
-    let (topics, _) = service
+    let topics = service
         .list_with_locale_fallback(
             tenant.id,
             auth.security_context(),
@@ -93,7 +95,8 @@ pub async fn list_topics(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(|err| Error::BadRequest(err.to_string()))?
+        .items;
     metrics::record_read_path_query(
         "http",
         "forum.list_topics",
@@ -166,7 +169,8 @@ pub async fn get_topic(
     let locale = filter
         .locale
         .unwrap_or_else(|| request_context.locale.clone());
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -204,7 +208,8 @@ pub async fn create_topic(
         "Permission denied: forum_topics:create required",
     )?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .create(tenant.id, auth.security_context(), input)
         .await
@@ -238,7 +243,8 @@ pub async fn update_topic(
         "Permission denied: forum_topics:update required",
     )?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .update(tenant.id, id, auth.security_context(), input)
         .await
@@ -270,7 +276,8 @@ pub async fn delete_topic(
         "Permission denied: forum_topics:delete required",
     )?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     service
         .delete(tenant.id, id, auth.security_context())
         .await
@@ -315,7 +322,8 @@ pub async fn mark_topic_solution(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), event_bus);
+    let service = TopicService::new(ctx.db.clone(), event_bus)
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -363,7 +371,8 @@ pub async fn clear_topic_solution(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), event_bus);
+    let service = TopicService::new(ctx.db.clone(), event_bus)
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -409,7 +418,8 @@ pub async fn set_topic_vote(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -452,7 +462,8 @@ pub async fn clear_topic_vote(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -495,7 +506,8 @@ pub async fn subscribe_topic(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -538,7 +550,8 @@ pub async fn unsubscribe_topic(
         .await
         .map_err(|err| Error::BadRequest(err.to_string()))?;
 
-    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = TopicService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let topic = service
         .get_with_locale_fallback(
             tenant.id,
@@ -4,7 +4,8 @@ use rustok_api::{
     has_any_effective_permission, AuthContext, RequestContext, TenantContext,
 };
 use rustok_channel::ChannelService;
-use rustok_core::{Permission, SecurityContext};
+use rustok_content::MetadataVisibility;
+use rustok_core::{Page, Permission, SecurityContext};
 use rustok_outbox::TransactionalEventBus;
 use rustok_profiles::{
     graphql::GqlProfileSummary, ProfileService, ProfileSummaryLoader, ProfileSummaryLoaderKey,
@@ -56,7 +57,7 @@ impl ForumQuery {
         let per_page = limit as u64;
 
         let list_started_at = Instant::now();
-        let (categories, total) = service
+        let categories = service
             .list_paginated_with_locale_fallback(
                 tenant_id,
                 auth.security_context(),
@@ -66,6 +67,7 @@ impl ForumQuery {
                 Some(tenant.default_locale.as_str()),
             )
             .await?;
+        let total = categories.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.categories",
@@ -75,6 +77,7 @@ impl ForumQuery {
         );
 
         let items = categories
+            .items
             .into_iter()
             .map(map_category_list_item)
             .collect::<Vec<_>>();
@@ -113,7 +116,8 @@ impl ForumQuery {
         )?;
 
         let tenant = ctx.data::<TenantContext>()?;
-        let service = TopicService::new(db.clone(), event_bus.clone());
+        let service = TopicService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let requested_limit = pagination.requested_limit();
         let (offset, limit) = pagination.normalize()?;
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
@@ -126,7 +130,7 @@ impl ForumQuery {
         };
 
         let list_started_at = Instant::now();
-        let (topics, total) = service
+        let topics = service
             .list_with_locale_fallback(
                 tenant_id,
                 auth.security_context(),
@@ -134,6 +138,7 @@ impl ForumQuery {
                 Some(tenant.default_locale.as_str()),
             )
             .await?;
+        let total = topics.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.topics",
@@ -146,12 +151,13 @@ impl ForumQuery {
             ctx,
             db,
             tenant_id,
-            topics.iter().map(|topic| topic.author_id),
+            topics.items.iter().map(|topic| topic.author_id),
             locale.as_str(),
             tenant.default_locale.as_str(),
         )
         .await?;
         let items = topics
+            .items
             .into_iter()
             .map(|topic| {
                 let author_profile = topic
@@ -206,7 +212,7 @@ impl ForumQuery {
         };
 
         let list_started_at = Instant::now();
-        let (replies, total) = service
+        let replies = service
             .list_response_for_topic_with_locale_fallback(
                 tenant_id,
                 auth.security_context(),
@@ -215,6 +221,7 @@ impl ForumQuery {
                 Some(tenant.default_locale.as_str()),
             )
             .await?;
+        let total = replies.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.replies",
@@ -227,12 +234,13 @@ impl ForumQuery {
             ctx,
             db,
             tenant_id,
-            replies.iter().map(|reply| reply.author_id),
+            replies.items.iter().map(|reply| reply.author_id),
             locale.as_str(),
             tenant.default_locale.as_str(),
         )
         .await?;
         let items = replies
+            .items
             .into_iter()
             .map(|reply| {
                 let author_profile = reply
@@ -316,7 +324,7 @@ impl ForumQuery {
         let per_page = limit as u64;
 
         let list_started_at = Instant::now();
-        let (categories, total) = service
+        let categories = service
             .list_paginated_with_locale_fallback(
                 resolved_tenant_id,
                 forum_security_or_system(ctx),
@@ -326,6 +334,7 @@ impl ForumQuery {
                 Some(tenant.default_locale.as_str()),
             )
             .await?;
+        let total = categories.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.storefront_categories",
@@ -335,6 +344,7 @@ impl ForumQuery {
         );
 
         let items = categories
+            .items
             .into_iter()
             .map(map_category_list_item)
             .collect::<Vec<_>>();
@@ -369,7 +379,8 @@ impl ForumQuery {
         let event_bus = ctx.data::<TransactionalEventBus>()?;
         let tenant = ctx.data::<TenantContext>()?;
         let resolved_tenant_id = tenant_id.unwrap_or(tenant.id);
-        let service = TopicService::new(db.clone(), event_bus.clone());
+        let service = TopicService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let requested_limit = pagination.requested_limit();
         let (offset, limit) = pagination.normalize()?;
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
@@ -382,7 +393,7 @@ impl ForumQuery {
         };
 
         let list_started_at = Instant::now();
-        let (topics, total) = list_public_storefront_topics(
+        let topics = list_public_storefront_topics(
             &service,
             resolved_tenant_id,
             forum_security_or_system(ctx),
@@ -391,6 +402,7 @@ impl ForumQuery {
             public_channel_slug(ctx),
         )
         .await?;
+        let total = topics.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.storefront_topics",
@@ -403,12 +415,13 @@ impl ForumQuery {
             ctx,
             db,
             resolved_tenant_id,
-            topics.iter().map(|topic| topic.author_id),
+            topics.items.iter().map(|topic| topic.author_id),
             locale.as_str(),
             tenant.default_locale.as_str(),
         )
         .await?;
         let items = topics
+            .items
             .into_iter()
             .map(|topic| {
                 let author_profile = topic
@@ -448,7 +461,8 @@ impl ForumQuery {
         let tenant = ctx.data::<TenantContext>()?;
         let resolved_tenant_id = tenant_id.unwrap_or(tenant.id);
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
-        let service = TopicService::new(db.clone(), event_bus.clone());
+        let service = TopicService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
 
         let topic = match service
             .get_with_locale_fallback(
@@ -545,7 +559,7 @@ impl ForumQuery {
         };
 
         let list_started_at = Instant::now();
-        let (replies, total) = service
+        let replies = service
             .list_response_for_topic_by_statuses_with_locale_fallback(
                 resolved_tenant_id,
                 forum_security_or_system(ctx),
@@ -555,6 +569,7 @@ impl ForumQuery {
                 Some(&PUBLIC_REPLY_STATUSES),
             )
             .await?;
+        let total = replies.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "forum.storefront_replies",
@@ -567,12 +582,13 @@ impl ForumQuery {
             ctx,
             db,
             resolved_tenant_id,
-            replies.iter().map(|reply| reply.author_id),
+            replies.items.iter().map(|reply| reply.author_id),
             locale.as_str(),
             tenant.default_locale.as_str(),
         )
         .await?;
         let items = replies
+            .items
             .into_iter()
             .map(|reply| {
                 let author_profile = reply
@@ -907,7 +923,7 @@ async fn list_public_storefront_topics(
     base_filter: crate::ListTopicsFilter,
     fallback_locale: Option<&str>,
     channel_slug: Option<String>,
-) -> ForumResult<(Vec<TopicListItem>, u64)> {
+) -> ForumResult<Page<TopicListItem>> {
     service
         .list_storefront_visible_with_locale_fallback(
             tenant_id,
@@ -1184,7 +1200,7 @@ mod tests {
             .await
             .expect("second visible topic should be created");
 
-        let (topics, total) = list_public_storefront_topics(
+        let topics = list_public_storefront_topics(
             &service,
             tenant_id,
             SecurityContext::system(),
@@ -1201,9 +1217,13 @@ mod tests {
         .await
         .expect("storefront topic list should succeed");
 
-        assert_eq!(total, 2);
-        assert_eq!(topics.len(), 2);
-        let ids = topics.into_iter().map(|topic| topic.id).collect::<Vec<_>>();
+        assert_eq!(topics.total, Some(2));
+        assert_eq!(topics.items.len(), 2);
+        let ids = topics
+            .items
+            .into_iter()
+            .map(|topic| topic.id)
+            .collect::<Vec<_>>();
         assert!(ids.contains(&first_visible.id));
         assert!(ids.contains(&second_visible.id));
     }
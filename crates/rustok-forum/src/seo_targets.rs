@@ -104,7 +104,7 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
         let mut summaries = Vec::new();
 
         loop {
-            let (items, total) = service
+            let categories = service
                 .list_paginated_with_locale_fallback(
                     request.tenant_id,
                     SecurityContext::system(),
@@ -114,11 +114,11 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
                     Some(request.default_locale),
                 )
                 .await?;
-            if items.is_empty() {
+            if categories.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in categories.items {
                 if let Some(summary) = load_category_summary(
                     &service,
                     request.tenant_id,
@@ -132,7 +132,7 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= categories.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
@@ -151,7 +151,7 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
         let mut candidates = Vec::new();
 
         loop {
-            let (items, total) = service
+            let categories = service
                 .list_paginated_with_locale_fallback(
                     request.tenant_id,
                     SecurityContext::system(),
@@ -161,11 +161,11 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
                     Some(request.default_locale),
                 )
                 .await?;
-            if items.is_empty() {
+            if categories.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in categories.items {
                 if let Some(candidate) = load_category_sitemap_candidate(
                     &service,
                     request.tenant_id,
@@ -178,7 +178,7 @@ impl SeoTargetProvider for ForumCategorySeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= categories.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
@@ -277,7 +277,7 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
         let mut summaries = Vec::new();
 
         loop {
-            let (items, total) = service
+            let topics = service
                 .list_with_locale_fallback(
                     request.tenant_id,
                     SecurityContext::system(),
@@ -291,11 +291,11 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
                     Some(request.default_locale),
                 )
                 .await?;
-            if items.is_empty() {
+            if topics.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in topics.items {
                 if let Some(summary) = load_topic_summary(
                     &service,
                     request.tenant_id,
@@ -309,7 +309,7 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= topics.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
@@ -328,7 +328,7 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
         let mut candidates = Vec::new();
 
         loop {
-            let (items, total) = service
+            let topics = service
                 .list_storefront_visible_with_locale_fallback(
                     request.tenant_id,
                     SecurityContext::system(),
@@ -343,11 +343,11 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
                     None,
                 )
                 .await?;
-            if items.is_empty() {
+            if topics.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in topics.items {
                 if let Some(candidate) = load_topic_sitemap_candidate(
                     &service,
                     request.tenant_id,
@@ -360,7 +360,7 @@ impl SeoTargetProvider for ForumTopicSeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= topics.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
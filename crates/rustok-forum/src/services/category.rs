@@ -1,7 +1,7 @@
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    EntityTrait, QueryFilter, QueryOrder,
 };
 use std::collections::HashMap;
 use tracing::instrument;
@@ -10,7 +10,7 @@ use uuid::Uuid;
 use rustok_content::{
     available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
 };
-use rustok_core::{Action, Resource, SecurityContext};
+use rustok_core::{fetch_with_total, Action, Page, Resource, SecurityContext};
 
 use crate::dto::{CategoryListItem, CategoryResponse, CreateCategoryInput, UpdateCategoryInput};
 use crate::entities::{forum_category, forum_category_translation};
@@ -235,10 +235,10 @@ impl CategoryService {
         security: SecurityContext,
         locale: &str,
     ) -> ForumResult<Vec<CategoryListItem>> {
-        let (items, _) = self
+        let categories = self
             .list_paginated_with_locale_fallback(tenant_id, security, locale, 1, 1000, None)
             .await?;
-        Ok(items)
+        Ok(categories.items)
     }
 
     #[instrument(skip(self, security))]
@@ -249,7 +249,7 @@ impl CategoryService {
         locale: &str,
         fallback_locale: Option<&str>,
     ) -> ForumResult<Vec<CategoryListItem>> {
-        let (items, _) = self
+        let categories = self
             .list_paginated_with_locale_fallback(
                 tenant_id,
                 security,
@@ -259,7 +259,7 @@ impl CategoryService {
                 fallback_locale,
             )
             .await?;
-        Ok(items)
+        Ok(categories.items)
     }
 
     #[instrument(skip(self, security))]
@@ -271,16 +271,19 @@ impl CategoryService {
         page: u64,
         per_page: u64,
         fallback_locale: Option<&str>,
-    ) -> ForumResult<(Vec<CategoryListItem>, u64)> {
+    ) -> ForumResult<Page<CategoryListItem>> {
         enforce_scope(&security, Resource::ForumCategories, Action::List)?;
         let locale = normalize_locale(locale)?;
         let fallback_locale = fallback_locale.map(normalize_locale).transpose()?;
-        let paginator = forum_category::Entity::find()
-            .filter(forum_category::Column::TenantId.eq(tenant_id))
-            .order_by_asc(forum_category::Column::Position)
-            .paginate(&self.db, per_page.max(1));
-        let total = paginator.num_items().await?;
-        let categories = paginator.fetch_page(page.saturating_sub(1)).await?;
+        let (categories, total) = fetch_with_total(
+            &self.db,
+            forum_category::Entity::find()
+                .filter(forum_category::Column::TenantId.eq(tenant_id))
+                .order_by_asc(forum_category::Column::Position),
+            page.saturating_sub(1) * per_page.max(1),
+            per_page.max(1),
+        )
+        .await?;
         let category_ids: Vec<Uuid> = categories.iter().map(|item| item.id).collect();
         let translations_by_category_id = self
             .load_translations_map_for_categories(&category_ids)
@@ -334,7 +337,11 @@ impl CategoryService {
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            page.saturating_sub(1) * per_page.max(1),
+        ))
     }
 
     pub(crate) async fn ensure_exists_in_tx(
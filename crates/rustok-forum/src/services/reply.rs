@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+    EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
 };
 use tracing::instrument;
 use uuid::Uuid;
@@ -11,7 +11,9 @@ use uuid::Uuid;
 use rustok_content::{
     normalize_locale_code, resolve_by_locale_with_fallback, PLATFORM_FALLBACK_LOCALE,
 };
-use rustok_core::{prepare_content_payload, Action, Resource, SecurityContext};
+use rustok_core::{
+    fetch_with_total, prepare_content_payload, Action, Page, Resource, SecurityContext,
+};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 
@@ -262,7 +264,7 @@ impl ReplyService {
         security: SecurityContext,
         topic_id: Uuid,
         filter: ListRepliesFilter,
-    ) -> ForumResult<(Vec<ReplyListItem>, u64)> {
+    ) -> ForumResult<Page<ReplyListItem>> {
         self.list_for_topic_with_locale_fallback(tenant_id, security, topic_id, filter, None)
             .await
     }
@@ -275,7 +277,7 @@ impl ReplyService {
         topic_id: Uuid,
         filter: ListRepliesFilter,
         fallback_locale: Option<&str>,
-    ) -> ForumResult<(Vec<ReplyListItem>, u64)> {
+    ) -> ForumResult<Page<ReplyListItem>> {
         enforce_scope(&security, Resource::ForumReplies, Action::List)?;
         let locale = filter
             .locale
@@ -284,49 +286,44 @@ impl ReplyService {
         let locale = normalize_locale(&locale)?;
         let fallback_locale = fallback_locale.map(normalize_locale).transpose()?;
 
-        let (replies, total) = self
+        let replies = self
             .fetch_reply_page(tenant_id, topic_id, filter.page, filter.per_page, None)
             .await?;
         let solution_reply_id = self.load_solution_reply_id_for_topic(topic_id).await?;
-        let reply_ids: Vec<Uuid> = replies.iter().map(|reply| reply.id).collect();
+        let reply_ids: Vec<Uuid> = replies.items.iter().map(|reply| reply.id).collect();
         let bodies_map = self.load_bodies_map(&reply_ids).await?;
         let vote_summaries = VoteService::new(self.db.clone())
             .reply_vote_summaries(tenant_id, &reply_ids, security.user_id)
             .await?;
 
-        let items = replies
-            .into_iter()
-            .map(|reply| {
-                let bodies = bodies_map.get(&reply.id).cloned().unwrap_or_default();
-                let resolved = resolve_reply_body(&bodies, &locale, fallback_locale.as_deref());
-                let content = resolved
-                    .item
-                    .map(|body| body.body.clone())
-                    .unwrap_or_default();
-                let preview: String = content.chars().take(200).collect();
-                ReplyListItem {
-                    id: reply.id,
-                    locale: locale.clone(),
-                    effective_locale: resolved.effective_locale,
-                    topic_id: reply.topic_id,
-                    author_id: reply.author_id,
-                    content_preview: preview,
-                    status: reply.status,
-                    vote_score: vote_summaries
-                        .get(&reply.id)
-                        .map(|summary| summary.score)
-                        .unwrap_or_default(),
-                    current_user_vote: vote_summaries
-                        .get(&reply.id)
-                        .and_then(|summary| summary.current_user_vote),
-                    is_solution: Some(reply.id) == solution_reply_id,
-                    parent_reply_id: reply.parent_reply_id,
-                    created_at: reply.created_at.to_rfc3339(),
-                }
-            })
-            .collect();
-
-        Ok((items, total))
+        Ok(replies.map(|reply| {
+            let bodies = bodies_map.get(&reply.id).cloned().unwrap_or_default();
+            let resolved = resolve_reply_body(&bodies, &locale, fallback_locale.as_deref());
+            let content = resolved
+                .item
+                .map(|body| body.body.clone())
+                .unwrap_or_default();
+            let preview: String = content.chars().take(200).collect();
+            ReplyListItem {
+                id: reply.id,
+                locale: locale.clone(),
+                effective_locale: resolved.effective_locale,
+                topic_id: reply.topic_id,
+                author_id: reply.author_id,
+                content_preview: preview,
+                status: reply.status,
+                vote_score: vote_summaries
+                    .get(&reply.id)
+                    .map(|summary| summary.score)
+                    .unwrap_or_default(),
+                current_user_vote: vote_summaries
+                    .get(&reply.id)
+                    .and_then(|summary| summary.current_user_vote),
+                is_solution: Some(reply.id) == solution_reply_id,
+                parent_reply_id: reply.parent_reply_id,
+                created_at: reply.created_at.to_rfc3339(),
+            }
+        }))
     }
 
     #[instrument(skip(self, security))]
@@ -337,7 +334,7 @@ impl ReplyService {
         topic_id: Uuid,
         filter: ListRepliesFilter,
         fallback_locale: Option<&str>,
-    ) -> ForumResult<(Vec<ReplyResponse>, u64)> {
+    ) -> ForumResult<Page<ReplyResponse>> {
         self.list_response_for_topic_by_statuses_with_locale_fallback(
             tenant_id,
             security,
@@ -358,7 +355,7 @@ impl ReplyService {
         filter: ListRepliesFilter,
         fallback_locale: Option<&str>,
         statuses: Option<&[&str]>,
-    ) -> ForumResult<(Vec<ReplyResponse>, u64)> {
+    ) -> ForumResult<Page<ReplyResponse>> {
         enforce_scope(&security, Resource::ForumReplies, Action::List)?;
         let locale = filter
             .locale
@@ -366,32 +363,27 @@ impl ReplyService {
             .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string());
         let locale = normalize_locale(&locale)?;
         let fallback_locale = fallback_locale.map(normalize_locale).transpose()?;
-        let (replies, total) = self
+        let replies = self
             .fetch_reply_page(tenant_id, topic_id, filter.page, filter.per_page, statuses)
             .await?;
         let solution_reply_id = self.load_solution_reply_id_for_topic(topic_id).await?;
-        let reply_ids: Vec<Uuid> = replies.iter().map(|reply| reply.id).collect();
+        let reply_ids: Vec<Uuid> = replies.items.iter().map(|reply| reply.id).collect();
         let bodies_map = self.load_bodies_map(&reply_ids).await?;
         let vote_summaries = VoteService::new(self.db.clone())
             .reply_vote_summaries(tenant_id, &reply_ids, security.user_id)
             .await?;
 
-        let items = replies
-            .into_iter()
-            .map(|reply| {
-                let reply_id = reply.id;
-                to_reply_response(
-                    reply,
-                    bodies_map.get(&reply_id).cloned().unwrap_or_default(),
-                    vote_summaries.get(&reply_id).copied().unwrap_or_default(),
-                    solution_reply_id,
-                    &locale,
-                    fallback_locale.as_deref(),
-                )
-            })
-            .collect();
-
-        Ok((items, total))
+        Ok(replies.map(|reply| {
+            let reply_id = reply.id;
+            to_reply_response(
+                reply,
+                bodies_map.get(&reply_id).cloned().unwrap_or_default(),
+                vote_summaries.get(&reply_id).copied().unwrap_or_default(),
+                solution_reply_id,
+                &locale,
+                fallback_locale.as_deref(),
+            )
+        }))
     }
 
     pub(crate) async fn find_reply(
@@ -457,7 +449,7 @@ impl ReplyService {
         page: u64,
         per_page: u64,
         statuses: Option<&[&str]>,
-    ) -> ForumResult<(Vec<forum_reply::Model>, u64)> {
+    ) -> ForumResult<Page<forum_reply::Model>> {
         let mut query = forum_reply::Entity::find()
             .filter(forum_reply::Column::TenantId.eq(tenant_id))
             .filter(forum_reply::Column::TopicId.eq(topic_id))
@@ -473,10 +465,18 @@ impl ReplyService {
             }
         }
 
-        let paginator = query.paginate(&self.db, per_page.max(1));
-        let total = paginator.num_items().await?;
-        let replies = paginator.fetch_page(page.saturating_sub(1)).await?;
-        Ok((replies, total))
+        let (replies, total) = fetch_with_total(
+            &self.db,
+            query,
+            page.saturating_sub(1) * per_page.max(1),
+            per_page.max(1),
+        )
+        .await?;
+        Ok(Page::offset(
+            replies,
+            total,
+            page.saturating_sub(1) * per_page.max(1),
+        ))
     }
 
     async fn load_bodies_map(
@@ -748,7 +748,7 @@ mod tests {
                 .expect("reply should be created");
         }
 
-        let (replies, total) = service
+        let replies = service
             .list_response_for_topic_with_locale_fallback(
                 tenant_id,
                 security,
@@ -763,9 +763,10 @@ mod tests {
             .await
             .expect("reply list should load");
 
-        assert_eq!(total, 3);
-        assert_eq!(replies.len(), 3);
+        assert_eq!(replies.total, Some(3));
+        assert_eq!(replies.items.len(), 3);
         let contents = replies
+            .items
             .into_iter()
             .map(|reply| reply.content)
             .collect::<Vec<_>>();
@@ -25,8 +25,8 @@ use sea_orm::{
     sea_query::{Expr, Query, SelectStatement},
     ActiveModelTrait,
     ActiveValue::Set,
-    ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Select, TransactionTrait,
+    ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter,
+    QueryOrder, Select, TransactionTrait,
 };
 use serde_json::Value;
 use tracing::instrument;
@@ -34,10 +34,12 @@ use uuid::Uuid;
 
 use rustok_content::{
     available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
-    PLATFORM_FALLBACK_LOCALE,
+    MetadataVisibility, PLATFORM_FALLBACK_LOCALE,
 };
 use rustok_core::field_schema::{CustomFieldsSchema, FieldDefinition, FieldType, ValidationRule};
-use rustok_core::{prepare_content_payload, Action, Resource, SecurityContext};
+use rustok_core::{
+    fetch_with_total, prepare_content_payload, Action, Page, Resource, SecurityContext,
+};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 use rustok_taxonomy::{TaxonomyService, TaxonomyTermKind};
@@ -73,11 +75,22 @@ fn map_flex_cleanup_error(error: rustok_core::field_schema::FlexError) -> ForumE
 pub struct TopicService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
+    metadata_visibility: MetadataVisibility,
 }
 
 impl TopicService {
     pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
-        Self { db, event_bus }
+        Self {
+            db,
+            event_bus,
+            metadata_visibility: MetadataVisibility::default(),
+        }
+    }
+
+    /// Override which metadata keys topic reads and listings treat as internal.
+    pub fn with_metadata_visibility(mut self, metadata_visibility: MetadataVisibility) -> Self {
+        self.metadata_visibility = metadata_visibility;
+        self
     }
 
     #[instrument(skip(self, security, input))]
@@ -206,7 +219,7 @@ impl TopicService {
         let topic = self.find_topic(tenant_id, topic_id).await?;
         let translations = self.load_translations(topic_id).await?;
         let channel_slugs = self.load_channel_slugs(topic_id).await?;
-        let metadata = self
+        let mut metadata = self
             .resolve_topic_metadata(
                 tenant_id,
                 topic.id,
@@ -215,6 +228,7 @@ impl TopicService {
                 fallback_locale.as_deref(),
             )
             .await?;
+        self.metadata_visibility.apply(&security, &mut metadata);
         let tags = self
             .load_topic_tags(tenant_id, topic.id, &locale, fallback_locale.as_deref())
             .await?;
@@ -399,7 +413,7 @@ impl TopicService {
         tenant_id: Uuid,
         security: SecurityContext,
         filter: ListTopicsFilter,
-    ) -> ForumResult<(Vec<TopicListItem>, u64)> {
+    ) -> ForumResult<Page<TopicListItem>> {
         self.list_with_locale_fallback(tenant_id, security, filter, None)
             .await
     }
@@ -411,7 +425,7 @@ impl TopicService {
         security: SecurityContext,
         filter: ListTopicsFilter,
         fallback_locale: Option<&str>,
-    ) -> ForumResult<(Vec<TopicListItem>, u64)> {
+    ) -> ForumResult<Page<TopicListItem>> {
         enforce_scope(&security, Resource::ForumTopics, Action::List)?;
         let locale = filter
             .locale
@@ -429,14 +443,17 @@ impl TopicService {
             select = select.filter(forum_topic::Column::Status.eq(status));
         }
 
-        let paginator = select
-            .order_by_desc(forum_topic::Column::IsPinned)
-            .order_by_desc(forum_topic::Column::LastReplyAt)
-            .order_by_desc(forum_topic::Column::UpdatedAt)
-            .paginate(&self.db, filter.per_page.max(1));
-        let total = paginator.num_items().await?;
-        let topics = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
-        let items = self
+        let (topics, total) = fetch_with_total(
+            &self.db,
+            select
+                .order_by_desc(forum_topic::Column::IsPinned)
+                .order_by_desc(forum_topic::Column::LastReplyAt)
+                .order_by_desc(forum_topic::Column::UpdatedAt),
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+            filter.per_page.max(1),
+        )
+        .await?;
+        let mut items = self
             .hydrate_topic_list_items(
                 tenant_id,
                 security.user_id,
@@ -445,8 +462,16 @@ impl TopicService {
                 fallback_locale.as_deref(),
             )
             .await?;
+        for item in &mut items {
+            self.metadata_visibility
+                .apply(&security, &mut item.metadata);
+        }
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+        ))
     }
 
     #[instrument(skip(self, security))]
@@ -457,7 +482,7 @@ impl TopicService {
         filter: ListTopicsFilter,
         fallback_locale: Option<&str>,
         channel_slug: Option<&str>,
-    ) -> ForumResult<(Vec<TopicListItem>, u64)> {
+    ) -> ForumResult<Page<TopicListItem>> {
         enforce_scope(&security, Resource::ForumTopics, Action::List)?;
         let locale = filter
             .locale
@@ -474,14 +499,17 @@ impl TopicService {
         }
         select = apply_public_topic_channel_filter(select, channel_slug);
 
-        let paginator = select
-            .order_by_desc(forum_topic::Column::IsPinned)
-            .order_by_desc(forum_topic::Column::LastReplyAt)
-            .order_by_desc(forum_topic::Column::UpdatedAt)
-            .paginate(&self.db, filter.per_page.max(1));
-        let total = paginator.num_items().await?;
-        let topics = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
-        let items = self
+        let (topics, total) = fetch_with_total(
+            &self.db,
+            select
+                .order_by_desc(forum_topic::Column::IsPinned)
+                .order_by_desc(forum_topic::Column::LastReplyAt)
+                .order_by_desc(forum_topic::Column::UpdatedAt),
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+            filter.per_page.max(1),
+        )
+        .await?;
+        let mut items = self
             .hydrate_topic_list_items(
                 tenant_id,
                 security.user_id,
@@ -490,8 +518,16 @@ impl TopicService {
                 fallback_locale.as_deref(),
             )
             .await?;
+        for item in &mut items {
+            self.metadata_visibility
+                .apply(&security, &mut item.metadata);
+        }
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+        ))
     }
 
     pub(crate) async fn find_topic(
@@ -192,22 +192,22 @@ async fn category_list_exposes_requested_effective_and_available_locales() {
         .await
         .expect("ru category translation should be saved");
 
-    let (items, total) = service
+    let categories = service
         .list_paginated_with_locale_fallback(tenant_id, admin, "fr-FR", 1, 20, Some("ru"))
         .await
         .expect("category list should load");
 
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].requested_locale, "fr-FR");
-    assert_eq!(items[0].locale, "fr-FR");
-    assert_eq!(items[0].effective_locale, "ru");
+    assert_eq!(categories.total, Some(1));
+    assert_eq!(categories.items.len(), 1);
+    assert_eq!(categories.items[0].requested_locale, "fr-FR");
+    assert_eq!(categories.items[0].locale, "fr-FR");
+    assert_eq!(categories.items[0].effective_locale, "ru");
     assert_eq!(
-        items[0].available_locales,
+        categories.items[0].available_locales,
         vec!["en".to_string(), "ru".to_string()]
     );
-    assert_eq!(items[0].name, "Общее");
-    assert_eq!(items[0].slug, "obschee");
+    assert_eq!(categories.items[0].name, "Общее");
+    assert_eq!(categories.items[0].slug, "obschee");
 }
 
 #[tokio::test]
@@ -275,7 +275,7 @@ async fn topic_list_exposes_requested_effective_and_available_locales() {
         .await
         .expect("ru topic translation should be saved");
 
-    let (items, total) = topic_service
+    let topics = topic_service
         .list_with_locale_fallback(
             tenant_id,
             admin,
@@ -291,17 +291,17 @@ async fn topic_list_exposes_requested_effective_and_available_locales() {
         .await
         .expect("topic list should load");
 
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].requested_locale, "fr-FR");
-    assert_eq!(items[0].locale, "fr-FR");
-    assert_eq!(items[0].effective_locale, "ru");
+    assert_eq!(topics.total, Some(1));
+    assert_eq!(topics.items.len(), 1);
+    assert_eq!(topics.items[0].requested_locale, "fr-FR");
+    assert_eq!(topics.items[0].locale, "fr-FR");
+    assert_eq!(topics.items[0].effective_locale, "ru");
     assert_eq!(
-        items[0].available_locales,
+        topics.items[0].available_locales,
         vec!["en".to_string(), "ru".to_string()]
     );
-    assert_eq!(items[0].title, "Общая тема");
-    assert_eq!(items[0].slug, "general-thread");
+    assert_eq!(topics.items[0].title, "Общая тема");
+    assert_eq!(topics.items[0].slug, "general-thread");
 }
 
 #[tokio::test]
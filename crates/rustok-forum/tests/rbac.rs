@@ -192,7 +192,7 @@ async fn customer_permissions_are_enforced_in_forum_services() {
         .expect_err("customer should not delete replies");
     assert!(matches!(denied_reply_delete, ForumError::Forbidden(_)));
 
-    let (topics, total) = topic_service
+    let topics = topic_service
         .list(
             tenant_id,
             customer,
@@ -206,8 +206,8 @@ async fn customer_permissions_are_enforced_in_forum_services() {
         )
         .await
         .expect("customer list should still work");
-    assert_eq!(total, 1);
-    assert_eq!(topics.len(), 1);
+    assert_eq!(topics.total, Some(1));
+    assert_eq!(topics.items.len(), 1);
 }
 
 #[tokio::test]
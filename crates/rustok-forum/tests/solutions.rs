@@ -141,7 +141,7 @@ async fn mark_and_clear_solution_updates_topic_and_reply_read_paths() {
         .expect("topic should load");
     assert_eq!(topic_after_mark.solution_reply_id, Some(reply.id));
 
-    let (topics, total) = topic_service
+    let topics = topic_service
         .list(
             tenant_id,
             customer.clone(),
@@ -155,8 +155,8 @@ async fn mark_and_clear_solution_updates_topic_and_reply_read_paths() {
         )
         .await
         .expect("topic list should load");
-    assert_eq!(total, 1);
-    assert_eq!(topics[0].solution_reply_id, Some(reply.id));
+    assert_eq!(topics.total, Some(1));
+    assert_eq!(topics.items[0].solution_reply_id, Some(reply.id));
 
     let reply_after_mark = reply_service
         .get(tenant_id, customer.clone(), reply.id, "en")
@@ -164,7 +164,7 @@ async fn mark_and_clear_solution_updates_topic_and_reply_read_paths() {
         .expect("reply should load");
     assert!(reply_after_mark.is_solution);
 
-    let (replies, replies_total) = reply_service
+    let replies = reply_service
         .list_response_for_topic_with_locale_fallback(
             tenant_id,
             customer.clone(),
@@ -178,8 +178,8 @@ async fn mark_and_clear_solution_updates_topic_and_reply_read_paths() {
         )
         .await
         .expect("reply list should load");
-    assert_eq!(replies_total, 1);
-    assert!(replies[0].is_solution);
+    assert_eq!(replies.total, Some(1));
+    assert!(replies.items[0].is_solution);
 
     moderation_service
         .clear_solution(tenant_id, topic.id, manager)
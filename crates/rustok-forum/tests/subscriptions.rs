@@ -120,12 +120,12 @@ async fn category_and_topic_subscriptions_round_trip_through_read_paths() {
         .expect("category should load for viewer");
     assert!(category_after_subscribe.is_subscribed);
 
-    let (categories, total_categories) = category_service
+    let categories = category_service
         .list_paginated_with_locale_fallback(tenant_id, viewer.clone(), "en", 1, 20, Some("en"))
         .await
         .expect("category list should load");
-    assert_eq!(total_categories, 1);
-    assert!(categories[0].is_subscribed);
+    assert_eq!(categories.total, Some(1));
+    assert!(categories.items[0].is_subscribed);
 
     let topic_after_subscribe = topic_service
         .get(tenant_id, viewer.clone(), topic.id, "en")
@@ -133,7 +133,7 @@ async fn category_and_topic_subscriptions_round_trip_through_read_paths() {
         .expect("topic should load for viewer");
     assert!(topic_after_subscribe.is_subscribed);
 
-    let (topics, total_topics) = topic_service
+    let topics = topic_service
         .list(
             tenant_id,
             viewer.clone(),
@@ -147,8 +147,8 @@ async fn category_and_topic_subscriptions_round_trip_through_read_paths() {
         )
         .await
         .expect("topic list should load");
-    assert_eq!(total_topics, 1);
-    assert!(topics[0].is_subscribed);
+    assert_eq!(topics.total, Some(1));
+    assert!(topics.items[0].is_subscribed);
 
     let category_for_other = category_service
         .get(tenant_id, other_viewer.clone(), category.id, "en")
@@ -153,7 +153,7 @@ async fn topic_and_reply_votes_round_trip_through_read_paths() {
     assert_eq!(reply_after_vote.vote_score, -1);
     assert_eq!(reply_after_vote.current_user_vote, Some(-1));
 
-    let (topics, total_topics) = topic_service
+    let topics = topic_service
         .list(
             tenant_id,
             voter.clone(),
@@ -167,11 +167,11 @@ async fn topic_and_reply_votes_round_trip_through_read_paths() {
         )
         .await
         .expect("topic list should load");
-    assert_eq!(total_topics, 1);
-    assert_eq!(topics[0].vote_score, 1);
-    assert_eq!(topics[0].current_user_vote, Some(1));
+    assert_eq!(topics.total, Some(1));
+    assert_eq!(topics.items[0].vote_score, 1);
+    assert_eq!(topics.items[0].current_user_vote, Some(1));
 
-    let (replies, total_replies) = reply_service
+    let replies = reply_service
         .list_response_for_topic_with_locale_fallback(
             tenant_id,
             voter.clone(),
@@ -185,9 +185,9 @@ async fn topic_and_reply_votes_round_trip_through_read_paths() {
         )
         .await
         .expect("reply list should load");
-    assert_eq!(total_replies, 1);
-    assert_eq!(replies[0].vote_score, -1);
-    assert_eq!(replies[0].current_user_vote, Some(-1));
+    assert_eq!(replies.total, Some(1));
+    assert_eq!(replies.items[0].vote_score, -1);
+    assert_eq!(replies.items[0].current_user_vote, Some(-1));
 
     vote_service
         .set_topic_vote(tenant_id, topic.id, voter.clone(), -1)
@@ -1,8 +1,8 @@
 use chrono::Utc;
 use rust_decimal::Decimal;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
 };
 use serde_json::{Map, Value};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -10,7 +10,7 @@ use tracing::instrument;
 use uuid::Uuid;
 use validator::Validate;
 
-use rustok_core::{generate_id, normalize_locale_tag};
+use rustok_core::{fetch_with_total, generate_id, normalize_locale_tag, Page};
 
 use crate::dto::{
     CancelFulfillmentInput, CreateFulfillmentInput, CreateShippingOptionInput,
@@ -366,7 +366,7 @@ impl FulfillmentService {
         &self,
         tenant_id: Uuid,
         input: ListFulfillmentsInput,
-    ) -> FulfillmentResult<(Vec<FulfillmentResponse>, u64)> {
+    ) -> FulfillmentResult<Page<FulfillmentResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let offset = (page.saturating_sub(1)) * per_page;
@@ -384,20 +384,20 @@ impl FulfillmentService {
             query = query.filter(entities::fulfillment::Column::CustomerId.eq(customer_id));
         }
 
-        let total = query.clone().count(&self.db).await?;
-        let rows = query
-            .order_by_desc(entities::fulfillment::Column::CreatedAt)
-            .offset(offset)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let (rows, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::fulfillment::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
         let mut items = Vec::with_capacity(rows.len());
         for row in rows {
             items.push(self.build_fulfillment_response(row).await?);
         }
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset))
     }
 
     pub async fn ship_fulfillment(
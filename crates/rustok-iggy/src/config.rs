@@ -1,8 +1,14 @@
 use rustok_iggy_connector::{
     ConnectorConfig, ConnectorMode, EmbeddedConnectorConfig, RemoteConnectorConfig,
+    TlsConnectorConfig,
 };
+use std::time::Duration;
+
+use rustok_core::{BackoffPolicy, ConfigError};
 use serde::{Deserialize, Serialize};
 
+use crate::partitioning::PartitionStrategy;
+
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct IggyConfig {
     #[serde(default)]
@@ -19,6 +25,98 @@ pub struct IggyConfig {
     pub retention: RetentionConfig,
 }
 
+impl IggyConfig {
+    /// Reject settings that would otherwise only fail once topology setup or
+    /// the connector hits them. `IggyTransport::new` calls this before
+    /// connecting.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.topology.stream_name.trim().is_empty() {
+            return Err(invalid(
+                "topology.stream_name",
+                &self.topology.stream_name,
+                "must not be empty",
+            ));
+        }
+        if self.topology.domain_partitions < 1 {
+            return Err(invalid(
+                "topology.domain_partitions",
+                self.topology.domain_partitions,
+                "must be at least 1",
+            ));
+        }
+        if self.topology.replication_factor < 1 {
+            return Err(invalid(
+                "topology.replication_factor",
+                self.topology.replication_factor,
+                "must be at least 1",
+            ));
+        }
+
+        if let Some(dead_letter) = &self.topology.dead_letter {
+            if dead_letter.topic.trim().is_empty() {
+                return Err(invalid(
+                    "topology.dead_letter.topic",
+                    &dead_letter.topic,
+                    "must not be empty",
+                ));
+            }
+            if dead_letter.partitions < 1 {
+                return Err(invalid(
+                    "topology.dead_letter.partitions",
+                    dead_letter.partitions,
+                    "must be at least 1",
+                ));
+            }
+        }
+
+        if self.mode == IggyMode::Remote {
+            if self.remote.addresses.is_empty() {
+                return Err(ConfigError::MissingKey("remote.addresses".to_string()));
+            }
+            if let Some(address) = self
+                .remote
+                .addresses
+                .iter()
+                .find(|address| !is_host_port(address))
+            {
+                return Err(invalid("remote.addresses", address, "expected host:port"));
+            }
+            if !REMOTE_PROTOCOLS.contains(&self.remote.protocol.as_str()) {
+                return Err(invalid(
+                    "remote.protocol",
+                    &self.remote.protocol,
+                    "expected one of tcp, quic, http",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const REMOTE_PROTOCOLS: [&str; 3] = ["tcp", "quic", "http"];
+
+fn invalid(key: &str, value: impl ToString, reason: &str) -> ConfigError {
+    ConfigError::InvalidValue {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// `host:port` or `[ipv6]:port` with a non-empty host and a `u16` port.
+fn is_host_port(address: &str) -> bool {
+    let Some((host, port)) = address.rsplit_once(':') else {
+        return false;
+    };
+    let host = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host);
+
+    !host.is_empty() && !host.contains(char::is_whitespace) && port.parse::<u16>().is_ok()
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum IggyMode {
@@ -81,6 +179,20 @@ pub struct RemoteConfig {
     pub password: String,
     #[serde(default)]
     pub tls_enabled: bool,
+    /// TLS settings for `tcp`/`quic` connections; when present, TLS is used
+    /// regardless of `tls_enabled`.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// First reconnect delay after the server drops the connection.
+    #[serde(default = "default_reconnect_base_delay_ms")]
+    pub reconnect_base_delay_ms: u64,
+    /// Upper bound for the exponential reconnect delay.
+    #[serde(default = "default_reconnect_max_delay_ms")]
+    pub reconnect_max_delay_ms: u64,
+    /// Publish attempts (the first one included) before giving up with
+    /// `Error::Unavailable`.
+    #[serde(default = "default_publish_max_attempts")]
+    pub publish_max_attempts: u32,
 }
 
 impl Default for RemoteConfig {
@@ -91,10 +203,53 @@ impl Default for RemoteConfig {
             username: "iggy".to_string(),
             password: "iggy".to_string(),
             tls_enabled: false,
+            tls: None,
+            reconnect_base_delay_ms: default_reconnect_base_delay_ms(),
+            reconnect_max_delay_ms: default_reconnect_max_delay_ms(),
+            publish_max_attempts: default_publish_max_attempts(),
+        }
+    }
+}
+
+/// Certificates for a TLS-secured Iggy cluster. Files are checked when the
+/// connector connects.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+    #[serde(default)]
+    pub client_cert_path: Option<String>,
+    #[serde(default)]
+    pub client_key_path: Option<String>,
+    /// Accept any server certificate. Development only.
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+}
+
+impl RemoteConfig {
+    /// Delay schedule between reconnect attempts.
+    pub fn reconnect_backoff(&self) -> BackoffPolicy {
+        BackoffPolicy::ExponentialJittered {
+            base: Duration::from_millis(self.reconnect_base_delay_ms),
+            max: Duration::from_millis(self.reconnect_max_delay_ms),
+            multiplier: 2.0,
+            jitter: 0.1,
         }
     }
 }
 
+fn default_reconnect_base_delay_ms() -> u64 {
+    100
+}
+
+fn default_reconnect_max_delay_ms() -> u64 {
+    10_000
+}
+
+fn default_publish_max_attempts() -> u32 {
+    5
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TopologyConfig {
     #[serde(default = "default_stream_name")]
@@ -103,6 +258,12 @@ pub struct TopologyConfig {
     pub domain_partitions: u32,
     #[serde(default = "default_replication_factor")]
     pub replication_factor: u8,
+    #[serde(default)]
+    pub partition_strategy: PartitionStrategy,
+    /// Topic that receives envelopes which could not be published; disabled
+    /// when `None`.
+    #[serde(default)]
+    pub dead_letter: Option<DeadLetterConfig>,
 }
 
 impl Default for TopologyConfig {
@@ -111,10 +272,37 @@ impl Default for TopologyConfig {
             stream_name: "rustok".to_string(),
             domain_partitions: 8,
             replication_factor: 1,
+            partition_strategy: PartitionStrategy::default(),
+            dead_letter: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct DeadLetterConfig {
+    #[serde(default = "default_dead_letter_topic")]
+    pub topic: String,
+    #[serde(default = "default_dead_letter_partitions")]
+    pub partitions: u32,
+}
+
+impl Default for DeadLetterConfig {
+    fn default() -> Self {
+        Self {
+            topic: default_dead_letter_topic(),
+            partitions: default_dead_letter_partitions(),
         }
     }
 }
 
+fn default_dead_letter_topic() -> String {
+    "domain.dlq".to_string()
+}
+
+fn default_dead_letter_partitions() -> u32 {
+    1
+}
+
 fn default_stream_name() -> String {
     "rustok".to_string()
 }
@@ -158,6 +346,7 @@ impl From<&IggyConfig> for ConnectorConfig {
             tcp_port: config.embedded.tcp_port,
             http_port: config.embedded.http_port,
             persistent: config.embedded.use_binary_fallback,
+            ..Default::default()
         };
 
         let remote = RemoteConnectorConfig {
@@ -166,6 +355,12 @@ impl From<&IggyConfig> for ConnectorConfig {
             username: config.remote.username.clone(),
             password: config.remote.password.clone(),
             tls_enabled: config.remote.tls_enabled,
+            tls: config.remote.tls.as_ref().map(|tls| TlsConnectorConfig {
+                ca_cert_path: tls.ca_cert_path.clone(),
+                client_cert_path: tls.client_cert_path.clone(),
+                client_key_path: tls.client_key_path.clone(),
+                insecure_skip_verify: tls.insecure_skip_verify,
+            }),
         };
 
         ConnectorConfig {
@@ -258,6 +453,7 @@ mod tests {
         assert_eq!(connector_config.mode, ConnectorMode::Remote);
         assert_eq!(connector_config.remote.addresses, vec!["192.168.1.1:8090"]);
         assert!(connector_config.remote.tls_enabled);
+        assert!(connector_config.remote.tls.is_none());
     }
 
     #[test]
@@ -269,6 +465,8 @@ mod tests {
                 stream_name: "custom-stream".to_string(),
                 domain_partitions: 16,
                 replication_factor: 3,
+                partition_strategy: PartitionStrategy::RoundRobin,
+                dead_letter: None,
             },
             ..Default::default()
         };
@@ -280,5 +478,211 @@ mod tests {
         assert_eq!(parsed.serialization, SerializationFormat::Postcard);
         assert_eq!(parsed.topology.stream_name, "custom-stream");
         assert_eq!(parsed.topology.domain_partitions, 16);
+        assert!(matches!(
+            parsed.topology.partition_strategy,
+            PartitionStrategy::RoundRobin
+        ));
+    }
+
+    #[test]
+    fn config_to_connector_config_carries_tls() {
+        let iggy_config = IggyConfig {
+            mode: IggyMode::Remote,
+            remote: RemoteConfig {
+                protocol: "quic".to_string(),
+                tls: Some(TlsConfig {
+                    insecure_skip_verify: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let connector_config: ConnectorConfig = (&iggy_config).into();
+        let tls = connector_config.remote.tls.unwrap();
+
+        assert!(tls.insecure_skip_verify);
+        assert!(tls.ca_cert_path.is_none());
+    }
+
+    #[test]
+    fn default_configs_are_valid() {
+        IggyConfig::default().validate().unwrap();
+        IggyConfig {
+            mode: IggyMode::Remote,
+            ..Default::default()
+        }
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_invalid_fields() {
+        fn remote(remote: RemoteConfig) -> IggyConfig {
+            IggyConfig {
+                mode: IggyMode::Remote,
+                remote,
+                ..Default::default()
+            }
+        }
+        fn topology(topology: TopologyConfig) -> IggyConfig {
+            IggyConfig {
+                topology,
+                ..Default::default()
+            }
+        }
+
+        let cases = [
+            (
+                topology(TopologyConfig {
+                    stream_name: "  ".to_string(),
+                    ..Default::default()
+                }),
+                "topology.stream_name",
+            ),
+            (
+                topology(TopologyConfig {
+                    domain_partitions: 0,
+                    ..Default::default()
+                }),
+                "topology.domain_partitions",
+            ),
+            (
+                topology(TopologyConfig {
+                    replication_factor: 0,
+                    ..Default::default()
+                }),
+                "topology.replication_factor",
+            ),
+            (
+                topology(TopologyConfig {
+                    dead_letter: Some(DeadLetterConfig {
+                        topic: String::new(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                "topology.dead_letter.topic",
+            ),
+            (
+                topology(TopologyConfig {
+                    dead_letter: Some(DeadLetterConfig {
+                        partitions: 0,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                "topology.dead_letter.partitions",
+            ),
+            (
+                remote(RemoteConfig {
+                    addresses: vec!["iggy.internal".to_string()],
+                    ..Default::default()
+                }),
+                "remote.addresses",
+            ),
+            (
+                remote(RemoteConfig {
+                    addresses: vec!["iggy.internal:http".to_string()],
+                    ..Default::default()
+                }),
+                "remote.addresses",
+            ),
+            (
+                remote(RemoteConfig {
+                    addresses: vec!["127.0.0.1:8090".to_string(), ":8090".to_string()],
+                    ..Default::default()
+                }),
+                "remote.addresses",
+            ),
+            (
+                remote(RemoteConfig {
+                    protocol: "udp".to_string(),
+                    ..Default::default()
+                }),
+                "remote.protocol",
+            ),
+        ];
+
+        for (config, expected_key) in cases {
+            match config.validate() {
+                Err(ConfigError::InvalidValue { key, .. }) => {
+                    assert_eq!(key, expected_key)
+                }
+                other => panic!("expected invalid {expected_key}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_requires_remote_addresses() {
+        let config = IggyConfig {
+            mode: IggyMode::Remote,
+            remote: RemoteConfig {
+                addresses: Vec::new(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::MissingKey(key)) if key == "remote.addresses"
+        ));
+    }
+
+    #[test]
+    fn validate_ignores_remote_settings_in_embedded_mode() {
+        let config = IggyConfig {
+            remote: RemoteConfig {
+                protocol: "udp".to_string(),
+                addresses: Vec::new(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_accepts_hostnames_and_ipv6() {
+        let config = IggyConfig {
+            mode: IggyMode::Remote,
+            remote: RemoteConfig {
+                addresses: vec!["iggy.internal:8090".to_string(), "[::1]:8090".to_string()],
+                protocol: "quic".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn partition_strategy_defaults_to_by_tenant() {
+        let parsed: TopologyConfig = serde_json::from_str(r#"{"stream_name": "rustok"}"#).unwrap();
+        assert!(matches!(
+            parsed.partition_strategy,
+            PartitionStrategy::ByTenant
+        ));
+
+        let parsed: TopologyConfig =
+            serde_json::from_str(r#"{"partition_strategy": "by_entity_id"}"#).unwrap();
+        assert!(parsed.dead_letter.is_none());
+        assert!(matches!(
+            parsed.partition_strategy,
+            PartitionStrategy::ByEntityId
+        ));
+    }
+
+    #[test]
+    fn dead_letter_block_defaults_topic_and_partitions() {
+        let parsed: TopologyConfig = serde_json::from_str(r#"{"dead_letter": {}}"#).unwrap();
+
+        assert_eq!(parsed.dead_letter, Some(DeadLetterConfig::default()));
+        assert_eq!(parsed.dead_letter.unwrap().topic, "domain.dlq");
     }
 }
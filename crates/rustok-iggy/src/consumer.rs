@@ -1,9 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
-use rustok_core::Result;
+use rustok_core::{Error, Result};
+use rustok_events::EventEnvelope;
+use rustok_iggy_connector::{IggyConnector, PolledMessage};
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{info, warn};
+
+use crate::config::{IggyConfig, SerializationFormat};
+use crate::serialization::{EventSerializer, JsonSerializer, PostcardSerializer};
 
 #[derive(Debug, Default)]
 pub struct ConsumerGroupManager {
@@ -67,6 +72,218 @@ impl ConsumerGroupManager {
     }
 }
 
+/// Where an [`IggyConsumer`] starts reading each partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConsumerOffset {
+    /// From the first retained message.
+    #[default]
+    Earliest,
+    /// Only messages published after the consumer was created.
+    Latest,
+    /// From this message offset within every partition.
+    Offset(u64),
+    /// After the offsets last committed for the group; partitions without a
+    /// committed offset start from the first retained message.
+    Committed,
+}
+
+/// Messages fetched from a partition per poll.
+const POLL_BATCH: u32 = 100;
+
+struct PartitionCursor {
+    partition: u32,
+    /// Offset of the next message to read.
+    position: u64,
+    /// Offset of the last message handed out, not yet committed.
+    uncommitted: Option<u64>,
+    buffered: VecDeque<PolledMessage>,
+}
+
+/// Reads decoded [`EventEnvelope`]s from the `domain` topic, so projections
+/// can consume events published to Iggy rather than only the in-process bus.
+///
+/// Each partition is polled by offset; partitions are read round-robin and
+/// order is preserved within a partition, i.e. per tenant. Progress is
+/// stored on the server under the group name by [`IggyConsumer::commit`].
+/// Messages that fail to deserialize are skipped and counted in
+/// `rustok_event_deserialize_failures_total`.
+pub struct IggyConsumer {
+    connector: Arc<dyn IggyConnector>,
+    group: ConsumerGroup,
+    serializer: Arc<dyn EventSerializer>,
+    cursors: Vec<PartitionCursor>,
+    next_cursor: usize,
+    skipped: u64,
+}
+
+impl IggyConsumer {
+    /// Create a consumer for `group` on the `domain` topic of `config`'s
+    /// stream, using the serialization format the transport publishes with.
+    pub async fn new(
+        connector: Arc<dyn IggyConnector>,
+        config: &IggyConfig,
+        group: impl Into<String>,
+        offset: ConsumerOffset,
+    ) -> Result<Self> {
+        let partitions: Vec<u32> = (1..=config.topology.domain_partitions.max(1)).collect();
+        let group = ConsumerGroup::new(
+            group.into(),
+            config.topology.stream_name.clone(),
+            "domain".to_string(),
+        )
+        .with_partitions(partitions.clone());
+        let serializer: Arc<dyn EventSerializer> = match config.serialization {
+            SerializationFormat::Json => Arc::new(JsonSerializer),
+            SerializationFormat::Postcard => Arc::new(PostcardSerializer),
+        };
+
+        let mut cursors = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let position = match offset {
+                ConsumerOffset::Earliest => 0,
+                ConsumerOffset::Offset(position) => position,
+                ConsumerOffset::Latest => connector
+                    .partition_head(&group.stream, &group.topic, partition)
+                    .await
+                    .map_err(connector_error)?,
+                ConsumerOffset::Committed => connector
+                    .stored_offset(&group.name, &group.stream, &group.topic, partition)
+                    .await
+                    .map_err(connector_error)?
+                    .map_or(0, |committed| committed + 1),
+            };
+            cursors.push(PartitionCursor {
+                partition,
+                position,
+                uncommitted: None,
+                buffered: VecDeque::new(),
+            });
+        }
+
+        info!(
+            group = %group.name,
+            stream = %group.stream,
+            topic = %group.topic,
+            offset = ?offset,
+            "Iggy consumer created"
+        );
+        Ok(Self {
+            connector,
+            group,
+            serializer,
+            cursors,
+            next_cursor: 0,
+            skipped: 0,
+        })
+    }
+
+    pub fn group(&self) -> &ConsumerGroup {
+        &self.group
+    }
+
+    /// Messages skipped so far because they could not be deserialized.
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    /// Next envelope from any partition, or `None` once every partition is
+    /// caught up. Calling again later picks up newly published messages.
+    pub async fn next(&mut self) -> Result<Option<EventEnvelope>> {
+        let mut idle = 0;
+        while idle < self.cursors.len() {
+            let index = self.next_cursor;
+            self.next_cursor = (index + 1) % self.cursors.len();
+
+            let Some(message) = self.recv_from(index).await? else {
+                idle += 1;
+                continue;
+            };
+            idle = 0;
+
+            match self.serializer.deserialize(&message.payload) {
+                Ok(envelope) => return Ok(Some(envelope)),
+                Err(error) => {
+                    self.skipped += 1;
+                    rustok_telemetry::metrics::record_event_deserialize_failure(&self.group.name);
+                    warn!(
+                        group = %self.group.name,
+                        partition = self.cursors[index].partition,
+                        offset = message.offset,
+                        error = %error,
+                        "Skipping Iggy message that could not be deserialized"
+                    );
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Store the offset of the last message returned from each partition,
+    /// so a consumer created with [`ConsumerOffset::Committed`] resumes
+    /// after it. Call once the returned envelopes have been processed.
+    pub async fn commit(&mut self) -> Result<()> {
+        for cursor in &mut self.cursors {
+            let Some(offset) = cursor.uncommitted else {
+                continue;
+            };
+            self.connector
+                .store_offset(
+                    &self.group.name,
+                    &self.group.stream,
+                    &self.group.topic,
+                    cursor.partition,
+                    offset,
+                )
+                .await
+                .map_err(connector_error)?;
+            cursor.uncommitted = None;
+        }
+        Ok(())
+    }
+
+    /// Next raw message of one partition, polling a new batch from the
+    /// cursor's position when the buffer is empty.
+    async fn recv_from(&mut self, index: usize) -> Result<Option<PolledMessage>> {
+        let cursor = &mut self.cursors[index];
+        if cursor.buffered.is_empty() {
+            let polled = self
+                .connector
+                .poll(
+                    &self.group.stream,
+                    &self.group.topic,
+                    cursor.partition,
+                    cursor.position,
+                    POLL_BATCH,
+                )
+                .await
+                .map_err(connector_error)?;
+            cursor.buffered.extend(polled);
+        }
+
+        let Some(message) = cursor.buffered.pop_front() else {
+            return Ok(None);
+        };
+        cursor.position = message.offset + 1;
+        cursor.uncommitted = Some(message.offset);
+        Ok(Some(message))
+    }
+}
+
+impl std::fmt::Debug for IggyConsumer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IggyConsumer")
+            .field("group", &self.group)
+            .field("format", &self.serializer.format())
+            .field("skipped", &self.skipped)
+            .finish()
+    }
+}
+
+fn connector_error(error: rustok_iggy_connector::ConnectorError) -> Error {
+    Error::External(error.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +342,161 @@ mod tests {
         assert!(removed.is_some());
         assert!(manager.list_groups().await.is_empty());
     }
+
+    // Without the `iggy` feature the embedded connector keeps published
+    // messages in memory, so no `iggy-server` binary is needed.
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn iggy_consumer_skips_undecodable_messages() {
+        use rustok_events::DomainEvent;
+        use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector, PublishRequest};
+        use uuid::Uuid;
+
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-consumer-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+        let mut config = IggyConfig::default();
+        config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        config.embedded.tcp_port = 0;
+        config.embedded.http_port = 0;
+
+        let connector = Arc::new(EmbeddedConnector::new());
+        connector
+            .connect(&ConnectorConfig::from(&config))
+            .await
+            .unwrap();
+
+        let tenant_id = Uuid::new_v4();
+        let envelope = EventEnvelope::new(
+            tenant_id,
+            None,
+            DomainEvent::NodeCreated {
+                node_id: Uuid::new_v4(),
+                kind: "post".to_string(),
+                author_id: None,
+            },
+        );
+        let key = crate::partitioning::partition_key(tenant_id);
+        connector
+            .publish(PublishRequest::new(
+                "rustok",
+                "domain",
+                key.clone(),
+                b"not an envelope".to_vec(),
+                "garbage",
+            ))
+            .await
+            .unwrap();
+        connector
+            .publish(PublishRequest::new(
+                "rustok",
+                "domain",
+                key,
+                JsonSerializer.serialize(&envelope).unwrap(),
+                envelope.id.to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let failures = || {
+            rustok_telemetry::metrics::EVENT_DESERIALIZE_FAILURES_TOTAL
+                .with_label_values(&["skip-test"])
+                .get()
+        };
+        let before = failures();
+
+        let mut consumer = IggyConsumer::new(
+            connector.clone(),
+            &config,
+            "skip-test",
+            ConsumerOffset::Earliest,
+        )
+        .await
+        .unwrap();
+
+        let received = consumer.next().await.unwrap().unwrap();
+        assert_eq!(received.id, envelope.id);
+        assert!(consumer.next().await.unwrap().is_none());
+        assert_eq!(consumer.skipped(), 1);
+        assert_eq!(failures(), before + 1);
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn iggy_consumer_resumes_after_committed_offsets() {
+        use rustok_events::DomainEvent;
+        use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector};
+        use uuid::Uuid;
+
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-commit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+        let mut config = IggyConfig::default();
+        config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        config.embedded.tcp_port = 0;
+        config.embedded.http_port = 0;
+
+        let connector = Arc::new(EmbeddedConnector::new());
+        connector
+            .connect(&ConnectorConfig::from(&config))
+            .await
+            .unwrap();
+        let partitioner = crate::partitioning::Partitioner::from_config(&config.topology);
+        let tenant_id = Uuid::new_v4();
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let envelope = EventEnvelope::new(
+                tenant_id,
+                None,
+                DomainEvent::TagCreated {
+                    tag_id: Uuid::new_v4(),
+                },
+            );
+            ids.push(envelope.id);
+            crate::producer::publish(
+                connector.as_ref(),
+                &config,
+                &partitioner,
+                &JsonSerializer,
+                envelope,
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut first = IggyConsumer::new(
+            connector.clone(),
+            &config,
+            "projector",
+            ConsumerOffset::Committed,
+        )
+        .await
+        .unwrap();
+        assert_eq!(first.next().await.unwrap().unwrap().id, ids[0]);
+        assert_eq!(first.next().await.unwrap().unwrap().id, ids[1]);
+        first.commit().await.unwrap();
+
+        let mut resumed = IggyConsumer::new(
+            connector.clone(),
+            &config,
+            "projector",
+            ConsumerOffset::Committed,
+        )
+        .await
+        .unwrap();
+        assert_eq!(resumed.next().await.unwrap().unwrap().id, ids[2]);
+        assert!(resumed.next().await.unwrap().is_none());
+
+        let mut latest =
+            IggyConsumer::new(connector.clone(), &config, "tail", ConsumerOffset::Latest)
+                .await
+                .unwrap();
+        assert!(latest.next().await.unwrap().is_none());
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
 }
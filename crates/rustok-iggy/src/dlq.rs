@@ -1,24 +1,39 @@
 use std::sync::Arc;
 
-use rustok_core::Result;
+use chrono::{DateTime, Utc};
+use rustok_core::{Error, Result};
 use rustok_iggy_connector::{IggyConnector, PublishRequest};
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
-#[derive(Debug, Clone)]
+use crate::config::DeadLetterConfig;
+use crate::partitioning::calculate_partition;
+
+/// A dead-lettered message. The whole entry is stored on the DLQ topic as
+/// JSON, so the reason and timestamp travel with the original payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DlqEntry {
     pub event_id: Uuid,
     pub original_topic: String,
+    /// The message as it was sent to `original_topic`, in the transport's
+    /// serialization format, or the envelope as JSON when that serializer
+    /// rejected it.
     pub payload: Vec<u8>,
     pub error: String,
     pub retry_count: u32,
+    pub failed_at: DateTime<Utc>,
 }
 
+/// Messages fetched from a DLQ partition per poll.
+const DLQ_POLL_BATCH: u32 = 100;
+
 #[derive(Debug)]
 pub struct DlqManager {
     stream: Arc<RwLock<String>>,
     topic: Arc<RwLock<String>>,
+    partitions: Arc<RwLock<u32>>,
     max_retries: Arc<RwLock<u32>>,
 }
 
@@ -33,6 +48,17 @@ impl DlqManager {
         Self {
             stream: Arc::new(RwLock::new("rustok".to_string())),
             topic: Arc::new(RwLock::new("dlq".to_string())),
+            partitions: Arc::new(RwLock::new(1)),
+            max_retries: Arc::new(RwLock::new(3)),
+        }
+    }
+
+    /// Manager for the dead-letter topic configured on `stream`.
+    pub fn from_config(stream: &str, config: &DeadLetterConfig) -> Self {
+        Self {
+            stream: Arc::new(RwLock::new(stream.to_string())),
+            topic: Arc::new(RwLock::new(config.topic.clone())),
+            partitions: Arc::new(RwLock::new(config.partitions.max(1))),
             max_retries: Arc::new(RwLock::new(3)),
         }
     }
@@ -66,13 +92,19 @@ impl DlqManager {
             "Moving event to dead letter queue"
         );
 
-        let request = PublishRequest::new(
-            stream,
-            topic,
-            entry.event_id.to_string(),
-            entry.payload,
-            format!("dlq-{}", entry.event_id),
-        );
+        let partition_key = entry.event_id.to_string();
+        let partition = calculate_partition(&partition_key, *self.partitions.read().await) + 1;
+        let payload = serde_json::to_vec(&entry).map_err(Error::Serialization)?;
+        let request = PublishRequest {
+            partition: Some(partition),
+            ..PublishRequest::new(
+                stream,
+                topic,
+                partition_key,
+                payload,
+                format!("dlq-{}", entry.event_id),
+            )
+        };
 
         connector.publish(request).await.map_err(|e| {
             error!(error = %e, "Failed to publish to DLQ");
@@ -82,6 +114,46 @@ impl DlqManager {
         Ok(())
     }
 
+    /// Every entry currently on the DLQ topic, partition by partition, for
+    /// inspection and reprocessing. Messages that are not [`DlqEntry`] JSON
+    /// are skipped with a warning; a connector that cannot read messages
+    /// back is an error rather than an empty queue.
+    pub async fn read_entries(&self, connector: &dyn IggyConnector) -> Result<Vec<DlqEntry>> {
+        let stream = self.stream.read().await.clone();
+        let topic = self.topic.read().await.clone();
+        let partitions = *self.partitions.read().await;
+
+        let mut entries = Vec::new();
+        for partition in 1..=partitions {
+            let mut offset = 0;
+            loop {
+                let polled = connector
+                    .poll(&stream, &topic, partition, offset, DLQ_POLL_BATCH)
+                    .await
+                    .map_err(|e| Error::External(e.to_string()))?;
+                let Some(last) = polled.last() else {
+                    break;
+                };
+                offset = last.offset + 1;
+
+                for message in polled {
+                    match serde_json::from_slice::<DlqEntry>(&message.payload) {
+                        Ok(entry) => entries.push(entry),
+                        Err(e) => warn!(
+                            dlq_topic = %topic,
+                            partition,
+                            offset = message.offset,
+                            error = %e,
+                            "Skipping unreadable DLQ message"
+                        ),
+                    }
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
     pub async fn retry_from_dlq(
         &self,
         _connector: &dyn IggyConnector,
@@ -111,6 +183,14 @@ mod tests {
         assert_eq!(*manager.max_retries.blocking_read(), 3);
     }
 
+    #[test]
+    fn dlq_manager_from_config() {
+        let manager = DlqManager::from_config("events", &DeadLetterConfig::default());
+        assert_eq!(*manager.stream.blocking_read(), "events");
+        assert_eq!(*manager.topic.blocking_read(), "domain.dlq");
+        assert_eq!(*manager.partitions.blocking_read(), 1);
+    }
+
     #[test]
     fn dlq_entry_creation() {
         let entry = DlqEntry {
@@ -119,9 +199,51 @@ mod tests {
             payload: vec![1, 2, 3],
             error: "Processing failed".to_string(),
             retry_count: 2,
+            failed_at: Utc::now(),
         };
 
         assert!(!entry.payload.is_empty());
         assert_eq!(entry.retry_count, 2);
     }
+
+    // Without the `iggy` feature the embedded connector keeps published
+    // messages in memory, so no `iggy-server` binary is needed.
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn read_entries_returns_published_entries_and_fails_without_read_back() {
+        use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector, RemoteConnector};
+
+        let data_dir = std::env::temp_dir().join(format!("rustok-iggy-dlq-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+        let mut config = ConnectorConfig::default();
+        config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        config.embedded.tcp_port = 0;
+        config.embedded.http_port = 0;
+        let connector = EmbeddedConnector::new();
+        connector.connect(&config).await.unwrap();
+
+        let manager = DlqManager::from_config("rustok", &DeadLetterConfig::default());
+        let entry = DlqEntry {
+            event_id: Uuid::new_v4(),
+            original_topic: "domain".to_string(),
+            payload: vec![1, 2, 3],
+            error: "handler failed".to_string(),
+            retry_count: 3,
+            failed_at: Utc::now(),
+        };
+        manager
+            .move_to_dlq(&connector, entry.clone())
+            .await
+            .unwrap();
+
+        let entries = manager.read_entries(&connector).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event_id, entry.event_id);
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let remote = RemoteConnector::new();
+        remote.connect(&ConnectorConfig::default()).await.unwrap();
+        assert!(manager.read_entries(&remote).await.is_err());
+    }
 }
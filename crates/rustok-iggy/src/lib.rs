@@ -8,9 +8,9 @@
 //! This crate implements `EventTransport` trait and handles:
 //! - Event serialization (JSON/Postcard)
 //! - Topology management (streams, topics)
-//! - Consumer group coordination
+//! - Consumer group coordination and `IggyConsumer` for reading decoded envelopes
 //! - Dead letter queue handling
-//! - Event replay orchestration
+//! - Event replay orchestration and single-projection rebuilds
 //!
 //! Connection management (Embedded vs Remote mode) is delegated to `rustok-iggy-connector`.
 //!
@@ -52,6 +52,7 @@
 //!     topology:
 //!       stream_name: rustok
 //!       domain_partitions: 8
+//!       partition_strategy: by_tenant  # by_entity_id | round_robin
 //!     embedded:
 //!       data_dir: ./data/iggy
 //!       tcp_port: 8090
@@ -63,20 +64,27 @@ pub mod dlq;
 pub mod health;
 pub mod partitioning;
 pub mod producer;
+pub mod rebuild;
+pub mod reconnect;
 pub mod replay;
 pub mod serialization;
 pub mod topology;
 pub mod transport;
 
 pub use config::{
-    EmbeddedConfig, IggyConfig, IggyMode, RemoteConfig, RetentionConfig, SerializationFormat,
-    TopologyConfig,
+    DeadLetterConfig, EmbeddedConfig, IggyConfig, IggyMode, RemoteConfig, RetentionConfig,
+    SerializationFormat, TlsConfig, TopologyConfig,
 };
-pub use consumer::{ConsumerGroup, ConsumerGroupManager};
+pub use consumer::{ConsumerGroup, ConsumerGroupManager, ConsumerOffset, IggyConsumer};
 pub use dlq::{DlqEntry, DlqManager};
 pub use health::{health_check, HealthCheckResult, HealthStatus};
-pub use partitioning::{calculate_partition, partition_key};
-pub use replay::{ActiveReplay, ReplayConfig, ReplayManager, ReplayStatus};
+pub use partitioning::{calculate_partition, partition_key, PartitionStrategy, Partitioner};
+pub use producer::BatchPublishError;
+pub use rebuild::{ProjectionRebuilder, RebuildReport, RebuildableProjection};
+pub use reconnect::ReconnectingConnector;
+pub use replay::{
+    ActiveReplay, ReplayConfig, ReplayError, ReplayManager, ReplayReport, ReplayStatus,
+};
 pub use serialization::{EventSerializer, JsonSerializer, PostcardSerializer};
 pub use topology::TopologyManager;
 pub use transport::IggyTransport;
@@ -1,8 +1,15 @@
 use std::collections::hash_map::DefaultHasher;
+use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU32, Ordering};
 
+use rustok_events::EventEnvelope;
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use crate::config::TopologyConfig;
+
 pub fn partition_key(tenant_id: Uuid) -> String {
     tenant_id.to_string()
 }
@@ -15,6 +22,122 @@ pub fn calculate_partition(key: &str, num_partitions: u32) -> u32 {
     (hash % num_partitions as u64) as u32
 }
 
+/// How the producer spreads envelopes over the `domain_partitions` of a topic.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionStrategy {
+    /// Hash of the tenant id: per-tenant ordering, one partition per tenant.
+    #[default]
+    ByTenant,
+    /// Hash of the event's entity id (its first `*_id` field, e.g. `node_id`),
+    /// falling back to the tenant. Keeps per-entity ordering while spreading
+    /// a single busy tenant over all partitions.
+    ByEntityId,
+    /// Every partition in turn, with no ordering guarantee.
+    RoundRobin,
+    /// Zero-based partition index chosen by code, taken modulo the partition
+    /// count. Not available from configuration files.
+    #[serde(skip)]
+    Custom(fn(&EventEnvelope, u32) -> u32),
+}
+
+/// Applies a [`PartitionStrategy`] to envelopes; shared by concurrent
+/// publishes, so `RoundRobin` keeps its position in an atomic counter.
+#[derive(Debug)]
+pub struct Partitioner {
+    strategy: PartitionStrategy,
+    partitions: u32,
+    next: AtomicU32,
+}
+
+impl Partitioner {
+    pub fn new(strategy: PartitionStrategy, partitions: u32) -> Self {
+        Self {
+            strategy,
+            partitions: partitions.max(1),
+            next: AtomicU32::new(0),
+        }
+    }
+
+    pub fn from_config(topology: &TopologyConfig) -> Self {
+        Self::new(topology.partition_strategy, topology.domain_partitions)
+    }
+
+    pub fn strategy(&self) -> PartitionStrategy {
+        self.strategy
+    }
+
+    /// Key recorded on the published message.
+    pub fn partition_key(&self, envelope: &EventEnvelope) -> String {
+        match self.strategy {
+            PartitionStrategy::ByEntityId => entity_id(envelope)
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| partition_key(envelope.tenant_id)),
+            _ => partition_key(envelope.tenant_id),
+        }
+    }
+
+    /// 1-based partition for `envelope`, as used by the connector.
+    pub fn partition_for(&self, envelope: &EventEnvelope) -> u32 {
+        let index = match self.strategy {
+            PartitionStrategy::ByTenant | PartitionStrategy::ByEntityId => {
+                calculate_partition(&self.partition_key(envelope), self.partitions)
+            }
+            PartitionStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.partitions
+            }
+            PartitionStrategy::Custom(choose) => {
+                choose(envelope, self.partitions) % self.partitions
+            }
+        };
+        index + 1
+    }
+}
+
+/// First `*_id` field of the event payload that holds a UUID. Events are
+/// serialized as `{"type": .., "data": {..}}` with fields in declaration
+/// order, and the entity id is declared first by convention.
+fn entity_id(envelope: &EventEnvelope) -> Option<Uuid> {
+    #[derive(Deserialize)]
+    struct Tagged {
+        data: Option<FirstId>,
+    }
+
+    struct FirstId(Option<Uuid>);
+
+    impl<'de> Deserialize<'de> for FirstId {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(FirstIdVisitor)
+        }
+    }
+
+    struct FirstIdVisitor;
+
+    impl<'de> Visitor<'de> for FirstIdVisitor {
+        type Value = FirstId;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an event payload object")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<FirstId, A::Error> {
+            let mut found = None;
+            while let Some(key) = map.next_key::<String>()? {
+                if found.is_none() && key.ends_with("_id") {
+                    let value = map.next_value::<serde_json::Value>()?;
+                    found = value.as_str().and_then(|id| Uuid::parse_str(id).ok());
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+            Ok(FirstId(found))
+        }
+    }
+
+    let json = serde_json::to_string(&envelope.event).ok()?;
+    serde_json::from_str::<Tagged>(&json).ok()?.data?.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,4 +185,77 @@ mod tests {
             );
         }
     }
+
+    fn node_event(tenant_id: Uuid, node_id: Uuid) -> EventEnvelope {
+        EventEnvelope::new(
+            tenant_id,
+            None,
+            rustok_events::DomainEvent::NodeCreated {
+                node_id,
+                kind: "post".to_string(),
+                author_id: Some(Uuid::new_v4()),
+            },
+        )
+    }
+
+    #[test]
+    fn by_tenant_keeps_a_tenant_on_one_partition() {
+        let partitioner = Partitioner::new(PartitionStrategy::ByTenant, 8);
+        let tenant_id = Uuid::new_v4();
+
+        let first = partitioner.partition_for(&node_event(tenant_id, Uuid::new_v4()));
+        for _ in 0..50 {
+            let partition = partitioner.partition_for(&node_event(tenant_id, Uuid::new_v4()));
+            assert_eq!(partition, first);
+        }
+        assert!((1..=8).contains(&first));
+    }
+
+    #[test]
+    fn by_entity_id_uses_first_id_field() {
+        let partitioner = Partitioner::new(PartitionStrategy::ByEntityId, 8);
+        let node_id = Uuid::new_v4();
+        let envelope = node_event(Uuid::new_v4(), node_id);
+
+        assert_eq!(partitioner.partition_key(&envelope), node_id.to_string());
+        assert_eq!(
+            partitioner.partition_for(&envelope),
+            calculate_partition(&node_id.to_string(), 8) + 1
+        );
+    }
+
+    #[test]
+    fn round_robin_distributes_evenly_across_concurrent_publishers() {
+        let partitioner = std::sync::Arc::new(Partitioner::new(PartitionStrategy::RoundRobin, 8));
+        let tenant_id = Uuid::new_v4();
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let partitioner = partitioner.clone();
+                std::thread::spawn(move || {
+                    (0..200)
+                        .map(|_| partitioner.partition_for(&node_event(tenant_id, Uuid::new_v4())))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut counts = [0u32; 8];
+        for worker in workers {
+            for partition in worker.join().unwrap() {
+                counts[(partition - 1) as usize] += 1;
+            }
+        }
+
+        assert_eq!(counts, [100; 8]);
+    }
+
+    #[test]
+    fn custom_strategy_is_reduced_to_partition_range() {
+        let partitioner = Partitioner::new(PartitionStrategy::Custom(|_, _| 11), 4);
+        assert_eq!(
+            partitioner.partition_for(&node_event(Uuid::new_v4(), Uuid::new_v4())),
+            11 % 4 + 1
+        );
+    }
 }
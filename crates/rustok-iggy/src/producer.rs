@@ -1,29 +1,321 @@
-use rustok_core::Result;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use chrono::Utc;
+use rustok_core::{Error, Result};
 use rustok_events::EventEnvelope;
-use rustok_iggy_connector::PublishRequest;
+use rustok_iggy_connector::{ConnectorError, IggyConnector, PublishRequest};
+use uuid::Uuid;
 
 use crate::config::IggyConfig;
-use crate::partitioning::partition_key;
+use crate::dlq::{DlqEntry, DlqManager};
+use crate::partitioning::Partitioner;
 use crate::serialization::EventSerializer;
 
+/// `transport` label of the `rustok_event_transport_*` metrics.
+const TRANSPORT_LABEL: &str = "iggy";
+
 pub fn build_publish_request(
     config: &IggyConfig,
+    partitioner: &Partitioner,
     serializer: &dyn EventSerializer,
     envelope: EventEnvelope,
 ) -> Result<PublishRequest> {
-    let topic = determine_topic(&envelope);
-    let partition_key = partition_key(envelope.tenant_id);
-    let payload = serializer.serialize(&envelope)?;
+    request_for(config, partitioner, serializer, &envelope)
+}
+
+fn request_for(
+    config: &IggyConfig,
+    partitioner: &Partitioner,
+    serializer: &dyn EventSerializer,
+    envelope: &EventEnvelope,
+) -> Result<PublishRequest> {
+    let topic = determine_topic(envelope);
+    let partition_key = partitioner.partition_key(envelope);
+    let partition = partitioner.partition_for(envelope);
+    let payload = serializer
+        .serialize(envelope)
+        .map_err(|error| serialization_failure(envelope, error))?;
 
     Ok(PublishRequest {
         stream: config.topology.stream_name.clone(),
         topic,
         partition_key,
+        partition: Some(partition),
         payload,
         event_id: envelope.id.to_string(),
     })
 }
 
+/// Serialize `envelope` and send it to its topic on the configured stream,
+/// on the partition chosen by `partitioner`, over the connector
+/// [`crate::IggyTransport`] connected at startup.
+///
+/// Send failures surface as [`Error::External`] so the outbox relay retries
+/// the row, or as [`Error::Unavailable`] while the remote connection is being
+/// re-established; serialization failures stay [`Error::Serialization`].
+/// With `topology.dead_letter` configured, permanent failures (serialization,
+/// or a send whose reconnect attempts ran out) instead move the message to the
+/// dead-letter topic and return `Ok`; only when that publish fails too is the
+/// original error returned. Transient send failures are never dead-lettered.
+pub async fn publish(
+    connector: &dyn IggyConnector,
+    config: &IggyConfig,
+    partitioner: &Partitioner,
+    serializer: &dyn EventSerializer,
+    envelope: EventEnvelope,
+) -> Result<()> {
+    let event_type = envelope.event_type.clone();
+    let request = match request_for(config, partitioner, serializer, &envelope) {
+        Ok(request) => request,
+        Err(error) => {
+            return if dead_letter(connector, config, unserializable_entry(&envelope, &error)).await
+            {
+                Ok(())
+            } else {
+                Err(error)
+            };
+        }
+    };
+    let event_id = request.event_id.clone();
+    let topic = request.topic.clone();
+    let retained = config
+        .topology
+        .dead_letter
+        .is_some()
+        .then(|| request.clone());
+
+    let started = Instant::now();
+    let result = connector.publish(request).await;
+    record_send(&topic, 1, result.is_ok(), started);
+    let Err(error) = result else {
+        return Ok(());
+    };
+    tracing::error!(
+        event_id = %event_id,
+        event_type = %event_type,
+        error = %error,
+        "Failed to publish event to Iggy"
+    );
+
+    if let Some(request) = retained.filter(|_| is_permanent(&error)) {
+        if dead_letter(connector, config, failed_send_entry(request, &error)).await {
+            return Ok(());
+        }
+    }
+    Err(connector_failure(error))
+}
+
+/// Envelopes of a [`publish_batch`] call that did not reach Iggy.
+///
+/// `source` is the first send failure, or the first serialization failure
+/// when nothing failed to send, so converting to [`Error`] keeps an
+/// all-serialization failure non-retryable.
+#[derive(Debug, thiserror::Error)]
+#[error("{} of {total} envelopes failed to publish (indices {failed:?}): {source}", failed.len())]
+pub struct BatchPublishError {
+    pub total: usize,
+    /// Positions in the submitted batch, ascending.
+    pub failed: Vec<usize>,
+    #[source]
+    pub source: Error,
+}
+
+impl From<BatchPublishError> for Error {
+    fn from(error: BatchPublishError) -> Self {
+        let message = error.to_string();
+        match error.source {
+            Error::Serialization(_) => Error::Serialization(serde::ser::Error::custom(message)),
+            Error::Unavailable(_) => Error::Unavailable(message),
+            _ => Error::External(message),
+        }
+    }
+}
+
+/// Publish `envelopes` with one connector send per topic and partition.
+///
+/// Envelopes keep their relative order within a partition. A failed group
+/// does not stop the others; every envelope that was neither sent nor
+/// dead-lettered is listed in [`BatchPublishError::failed`]. Dead-lettering
+/// follows [`publish`]: only permanent failures go to the dead-letter topic.
+pub async fn publish_batch(
+    connector: &dyn IggyConnector,
+    config: &IggyConfig,
+    partitioner: &Partitioner,
+    serializer: &dyn EventSerializer,
+    envelopes: Vec<EventEnvelope>,
+) -> std::result::Result<(), BatchPublishError> {
+    let total = envelopes.len();
+    let mut failed = Vec::new();
+    let mut serialization_error = None;
+    let mut groups: Vec<(Vec<usize>, Vec<PublishRequest>)> = Vec::new();
+    let mut group_index: HashMap<(String, Option<u32>), usize> = HashMap::new();
+
+    for (index, envelope) in envelopes.into_iter().enumerate() {
+        match request_for(config, partitioner, serializer, &envelope) {
+            Ok(request) => {
+                let key = (request.topic.clone(), request.partition);
+                let group = *group_index.entry(key).or_insert_with(|| {
+                    groups.push((Vec::new(), Vec::new()));
+                    groups.len() - 1
+                });
+                groups[group].0.push(index);
+                groups[group].1.push(request);
+            }
+            Err(error) => {
+                if !dead_letter(connector, config, unserializable_entry(&envelope, &error)).await {
+                    failed.push(index);
+                    serialization_error.get_or_insert(error);
+                }
+            }
+        }
+    }
+
+    let mut send_error = None;
+    for (indices, requests) in groups {
+        let topic = requests[0].topic.clone();
+        let partition = requests[0].partition;
+        let retained = config
+            .topology
+            .dead_letter
+            .is_some()
+            .then(|| requests.clone());
+        let started = Instant::now();
+        let result = connector.publish_batch(requests).await;
+        record_send(&topic, indices.len(), result.is_ok(), started);
+        let Err(error) = result else {
+            continue;
+        };
+        tracing::error!(
+            topic = %topic,
+            partition = ?partition,
+            messages = indices.len(),
+            error = %error,
+            "Failed to publish event batch to Iggy"
+        );
+
+        let failed_before = failed.len();
+        match retained.filter(|_| is_permanent(&error)) {
+            Some(requests) => {
+                for (index, request) in indices.into_iter().zip(requests) {
+                    let entry = failed_send_entry(request, &error);
+                    if !dead_letter(connector, config, entry).await {
+                        failed.push(index);
+                    }
+                }
+            }
+            None => failed.extend(indices),
+        }
+        if failed.len() > failed_before {
+            send_error.get_or_insert(connector_failure(error));
+        }
+    }
+
+    match send_error.or(serialization_error) {
+        None => Ok(()),
+        Some(source) => {
+            failed.sort_unstable();
+            Err(BatchPublishError {
+                total,
+                failed,
+                source,
+            })
+        }
+    }
+}
+
+fn record_send(topic: &str, events: usize, success: bool, started: Instant) {
+    rustok_telemetry::metrics::record_transport_publish(
+        TRANSPORT_LABEL,
+        topic,
+        events as u64,
+        success,
+        started.elapsed().as_secs_f64(),
+    );
+}
+
+/// Send failures that retrying the outbox row cannot fix: the reconnecting
+/// connector already spent its attempts on the message. Anything else is
+/// transient and goes back to the outbox relay.
+fn is_permanent(error: &ConnectorError) -> bool {
+    matches!(error, ConnectorError::RetriesExhausted(_))
+}
+
+fn failed_send_entry(request: PublishRequest, error: &ConnectorError) -> DlqEntry {
+    DlqEntry {
+        event_id: Uuid::parse_str(&request.event_id).unwrap_or_default(),
+        original_topic: request.topic,
+        payload: request.payload,
+        error: error.to_string(),
+        retry_count: 0,
+        failed_at: Utc::now(),
+    }
+}
+
+/// Entry for an envelope the configured serializer rejected. The envelope is
+/// kept as JSON so it can still be inspected.
+fn unserializable_entry(envelope: &EventEnvelope, error: &Error) -> DlqEntry {
+    DlqEntry {
+        event_id: envelope.id,
+        original_topic: determine_topic(envelope),
+        payload: serde_json::to_vec(envelope).unwrap_or_default(),
+        error: error.to_string(),
+        retry_count: 0,
+        failed_at: Utc::now(),
+    }
+}
+
+/// Move a permanently failed message to the configured dead-letter topic.
+/// Returns `false` when dead-lettering is disabled or the DLQ publish failed
+/// too, in which case the caller still owns the failure.
+async fn dead_letter(connector: &dyn IggyConnector, config: &IggyConfig, entry: DlqEntry) -> bool {
+    let Some(dead_letter) = &config.topology.dead_letter else {
+        return false;
+    };
+    let event_id = entry.event_id;
+
+    match DlqManager::from_config(&config.topology.stream_name, dead_letter)
+        .move_to_dlq(connector, entry)
+        .await
+    {
+        Ok(()) => true,
+        Err(dlq_error) => {
+            tracing::error!(
+                event_id = %event_id,
+                error = %dlq_error,
+                "Failed to dead-letter event; reporting the original failure"
+            );
+            false
+        }
+    }
+}
+
+fn connector_failure(error: ConnectorError) -> Error {
+    match error {
+        ConnectorError::Unavailable(message) => Error::Unavailable(message),
+        error @ ConnectorError::RetriesExhausted(_) => Error::Unavailable(error.to_string()),
+        other => Error::External(other.to_string()),
+    }
+}
+
+/// A serialization failure is permanent for this envelope, so it is always
+/// reported as [`Error::Serialization`]; the outbox relay fails such rows
+/// immediately instead of retrying them.
+fn serialization_failure(envelope: &EventEnvelope, error: Error) -> Error {
+    tracing::error!(
+        event_id = %envelope.id,
+        event_type = %envelope.event_type,
+        error = %error,
+        "Failed to serialize event envelope"
+    );
+    rustok_telemetry::metrics::record_event_serialize_failure(&envelope.event_type);
+
+    match error {
+        Error::Serialization(error) => Error::Serialization(error),
+        other => Error::Serialization(serde::ser::Error::custom(other)),
+    }
+}
+
 fn determine_topic(envelope: &EventEnvelope) -> String {
     if is_system_event(&envelope.event_type) {
         "system".to_string()
@@ -43,8 +335,13 @@ mod tests {
     use super::*;
     use crate::serialization::JsonSerializer;
     use rustok_events::{DomainEvent, EventEnvelope};
+    use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector};
     use uuid::Uuid;
 
+    fn partitioner() -> Partitioner {
+        Partitioner::from_config(&IggyConfig::default().topology)
+    }
+
     fn create_test_envelope(event_type: &str) -> EventEnvelope {
         let event = if is_system_event(event_type) {
             DomainEvent::ReindexRequested {
@@ -68,7 +365,8 @@ mod tests {
         let serializer = JsonSerializer;
         let envelope = create_test_envelope("node.created");
 
-        let request = build_publish_request(&config, &serializer, envelope.clone()).unwrap();
+        let request =
+            build_publish_request(&config, &partitioner(), &serializer, envelope.clone()).unwrap();
 
         assert_eq!(request.stream, "rustok");
         assert_eq!(request.topic, "domain");
@@ -77,6 +375,66 @@ mod tests {
         assert!(!request.payload.is_empty());
     }
 
+    struct FailingSerializer;
+
+    impl EventSerializer for FailingSerializer {
+        fn format(&self) -> crate::config::SerializationFormat {
+            crate::config::SerializationFormat::Postcard
+        }
+
+        fn serialize(&self, _envelope: &EventEnvelope) -> Result<Vec<u8>> {
+            Err(Error::External("unsupported field".to_string()))
+        }
+
+        fn deserialize(&self, _payload: &[u8]) -> Result<EventEnvelope> {
+            unreachable!("not used by the producer")
+        }
+    }
+
+    #[test]
+    fn serialization_failure_is_non_retryable_and_counted() {
+        let envelope = create_test_envelope("node.created");
+        let failures = || {
+            rustok_telemetry::metrics::EVENT_SERIALIZE_FAILURES_TOTAL
+                .with_label_values(&[envelope.event_type.as_str()])
+                .get()
+        };
+        let before = failures();
+
+        let error = build_publish_request(
+            &IggyConfig::default(),
+            &partitioner(),
+            &FailingSerializer,
+            envelope.clone(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, Error::Serialization(_)));
+        assert!(error.to_string().contains("unsupported field"));
+        assert!(failures() > before);
+    }
+
+    #[test]
+    fn build_publish_request_sets_partition_from_strategy() {
+        let partitioner = Partitioner::new(crate::PartitionStrategy::RoundRobin, 4);
+        let config = IggyConfig::default();
+
+        let partitions: Vec<_> = (0..4)
+            .map(|_| {
+                build_publish_request(
+                    &config,
+                    &partitioner,
+                    &JsonSerializer,
+                    create_test_envelope("node.created"),
+                )
+                .unwrap()
+                .partition
+            })
+            .collect();
+
+        assert_eq!(partitions, vec![Some(1), Some(2), Some(3), Some(4)]);
+    }
+
     #[test]
     fn determine_topic_routes_domain_events() {
         let envelope = create_test_envelope("node.created");
@@ -113,8 +471,562 @@ mod tests {
         let config = IggyConfig::default();
         let serializer = JsonSerializer;
 
-        let request = build_publish_request(&config, &serializer, envelope).unwrap();
+        let request =
+            build_publish_request(&config, &partitioner(), &serializer, envelope).unwrap();
 
         assert_eq!(request.partition_key, tenant_id.to_string());
     }
+
+    #[test]
+    fn payload_carries_event_source_and_schema_uri() {
+        let envelope = create_test_envelope("node.created");
+        let request = build_publish_request(
+            &IggyConfig::default(),
+            &partitioner(),
+            &JsonSerializer,
+            envelope,
+        )
+        .unwrap();
+
+        let payload: serde_json::Value = serde_json::from_slice(&request.payload).unwrap();
+        assert_eq!(payload["source"], "rustok-content");
+        assert_eq!(
+            payload["schema_uri"],
+            "urn:rustok:event-schema:node.created:v1"
+        );
+    }
+
+    // Without the `iggy` feature the embedded connector keeps published
+    // messages in memory, so no `iggy-server` binary is needed.
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn publish_round_trips_through_embedded_connector() {
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-producer-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut iggy_config = IggyConfig::default();
+        iggy_config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        iggy_config.embedded.tcp_port = 0;
+        iggy_config.embedded.http_port = 0;
+
+        let connector = EmbeddedConnector::new();
+        connector
+            .connect(&ConnectorConfig::from(&iggy_config))
+            .await
+            .unwrap();
+
+        let envelope = create_test_envelope("node.created");
+        publish(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &JsonSerializer,
+            envelope.clone(),
+        )
+        .await
+        .unwrap();
+
+        let mut received = Vec::new();
+        for partition in 1..=iggy_config.topology.domain_partitions {
+            let mut subscriber = connector
+                .subscribe(&iggy_config.topology.stream_name, "domain", partition)
+                .await
+                .unwrap();
+            while let Some(payload) = subscriber.recv().await.unwrap() {
+                received.push(JsonSerializer.deserialize(&payload).unwrap());
+            }
+        }
+
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].id, envelope.id);
+        assert_eq!(received[0].tenant_id, envelope.tenant_id);
+        assert_eq!(received[0].event_type, envelope.event_type);
+        assert_eq!(
+            serde_json::to_value(&received[0]).unwrap(),
+            serde_json::to_value(&envelope).unwrap()
+        );
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[tokio::test]
+    async fn publish_surfaces_connector_failures() {
+        let connector = EmbeddedConnector::new();
+        let envelope = create_test_envelope("node.created");
+
+        let error = publish(
+            &connector,
+            &IggyConfig::default(),
+            &partitioner(),
+            &JsonSerializer,
+            envelope,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, Error::External(_)));
+    }
+
+    /// Connector with a fixed per-send latency that records every send and
+    /// fails sends to `failing_partition`.
+    #[derive(Default)]
+    struct RecordingConnector {
+        latency: std::time::Duration,
+        failing_partition: Option<u32>,
+        sends: std::sync::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl IggyConnector for RecordingConnector {
+        async fn connect(
+            &self,
+            _config: &ConnectorConfig,
+        ) -> std::result::Result<(), ConnectorError> {
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn publish(
+            &self,
+            request: PublishRequest,
+        ) -> std::result::Result<(), ConnectorError> {
+            self.publish_batch(vec![request]).await
+        }
+
+        async fn publish_batch(
+            &self,
+            requests: Vec<PublishRequest>,
+        ) -> std::result::Result<(), ConnectorError> {
+            tokio::time::sleep(self.latency).await;
+            self.sends.lock().unwrap().push(requests.len());
+            if requests[0].partition == self.failing_partition {
+                return Err(ConnectorError::Publish("partition leader gone".to_string()));
+            }
+            Ok(())
+        }
+
+        async fn subscribe(
+            &self,
+            _stream: &str,
+            _topic: &str,
+            _partition: u32,
+        ) -> std::result::Result<Box<dyn rustok_iggy_connector::MessageSubscriber>, ConnectorError>
+        {
+            Err(ConnectorError::Subscribe("not supported".to_string()))
+        }
+
+        async fn shutdown(&self) -> std::result::Result<(), ConnectorError> {
+            Ok(())
+        }
+    }
+
+    fn envelopes_for_tenants(tenants: &[Uuid], per_tenant: usize) -> Vec<EventEnvelope> {
+        (0..per_tenant)
+            .flat_map(|_| tenants.iter())
+            .map(|tenant_id| {
+                EventEnvelope::new(
+                    *tenant_id,
+                    None,
+                    DomainEvent::NodeCreated {
+                        node_id: Uuid::new_v4(),
+                        kind: "post".to_string(),
+                        author_id: None,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn publish_batch_delivers_every_envelope_in_partition_order() {
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-batch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut iggy_config = IggyConfig::default();
+        iggy_config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        iggy_config.embedded.tcp_port = 0;
+        iggy_config.embedded.http_port = 0;
+
+        let connector = EmbeddedConnector::new();
+        connector
+            .connect(&ConnectorConfig::from(&iggy_config))
+            .await
+            .unwrap();
+
+        let tenants: Vec<_> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let envelopes = envelopes_for_tenants(&tenants, 8);
+        publish_batch(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &JsonSerializer,
+            envelopes.clone(),
+        )
+        .await
+        .unwrap();
+
+        let mut received = Vec::new();
+        for partition in 1..=iggy_config.topology.domain_partitions {
+            let mut subscriber = connector
+                .subscribe(&iggy_config.topology.stream_name, "domain", partition)
+                .await
+                .unwrap();
+            while let Some(payload) = subscriber.recv().await.unwrap() {
+                received.push(JsonSerializer.deserialize(&payload).unwrap());
+            }
+        }
+
+        assert_eq!(received.len(), envelopes.len());
+        for tenant_id in &tenants {
+            let sent: Vec<_> = envelopes
+                .iter()
+                .filter(|envelope| envelope.tenant_id == *tenant_id)
+                .map(|envelope| envelope.id)
+                .collect();
+            let arrived: Vec<_> = received
+                .iter()
+                .filter(|envelope| envelope.tenant_id == *tenant_id)
+                .map(|envelope| envelope.id)
+                .collect();
+            assert_eq!(arrived, sent);
+        }
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[tokio::test]
+    async fn publish_batch_reports_failed_indices() {
+        let partitioner = Partitioner::new(crate::PartitionStrategy::RoundRobin, 2);
+        let connector = RecordingConnector {
+            failing_partition: Some(2),
+            ..Default::default()
+        };
+        let envelopes = envelopes_for_tenants(&[Uuid::new_v4()], 6);
+
+        let error = publish_batch(
+            &connector,
+            &IggyConfig::default(),
+            &partitioner,
+            &JsonSerializer,
+            envelopes,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.total, 6);
+        assert_eq!(error.failed, vec![1, 3, 5]);
+        assert_eq!(*connector.sends.lock().unwrap(), vec![3, 3]);
+        assert!(matches!(Error::from(error), Error::External(_)));
+    }
+
+    #[tokio::test]
+    async fn publish_batch_serialization_failures_stay_non_retryable() {
+        let connector = RecordingConnector::default();
+        let envelopes = envelopes_for_tenants(&[Uuid::new_v4()], 3);
+
+        let error = publish_batch(
+            &connector,
+            &IggyConfig::default(),
+            &partitioner(),
+            &FailingSerializer,
+            envelopes,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.failed, vec![0, 1, 2]);
+        assert!(connector.sends.lock().unwrap().is_empty());
+        assert!(matches!(Error::from(error), Error::Serialization(_)));
+    }
+
+    #[tokio::test]
+    async fn publish_batch_needs_fewer_round_trips_than_single_publishes() {
+        let latency = std::time::Duration::from_millis(2);
+        let tenants: Vec<_> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let envelopes = envelopes_for_tenants(&tenants, 16);
+        let config = IggyConfig::default();
+
+        let single = RecordingConnector {
+            latency,
+            ..Default::default()
+        };
+        let started = std::time::Instant::now();
+        for envelope in envelopes.clone() {
+            publish(&single, &config, &partitioner(), &JsonSerializer, envelope)
+                .await
+                .unwrap();
+        }
+        let single_elapsed = started.elapsed();
+
+        let batched = RecordingConnector {
+            latency,
+            ..Default::default()
+        };
+        let started = std::time::Instant::now();
+        publish_batch(
+            &batched,
+            &config,
+            &partitioner(),
+            &JsonSerializer,
+            envelopes,
+        )
+        .await
+        .unwrap();
+        let batch_elapsed = started.elapsed();
+
+        let single_sends = single.sends.lock().unwrap().len();
+        let batch_sends = batched.sends.lock().unwrap().len();
+        assert_eq!(single_sends, 64);
+        // One send per tenant partition.
+        assert!(batch_sends <= tenants.len(), "{batch_sends} batch sends");
+        assert_eq!(batched.sends.lock().unwrap().iter().sum::<usize>(), 64);
+        assert!(
+            batch_elapsed < single_elapsed,
+            "batch {batch_elapsed:?} vs single {single_elapsed:?}"
+        );
+    }
+
+    /// Embedded connector whose `domain` topic refuses every publish with
+    /// the error built by the second field.
+    #[cfg(not(feature = "iggy"))]
+    struct DomainDownConnector(EmbeddedConnector, fn(String) -> ConnectorError);
+
+    #[cfg(not(feature = "iggy"))]
+    #[async_trait::async_trait]
+    impl IggyConnector for DomainDownConnector {
+        async fn connect(
+            &self,
+            config: &ConnectorConfig,
+        ) -> std::result::Result<(), ConnectorError> {
+            self.0.connect(config).await
+        }
+
+        fn is_connected(&self) -> bool {
+            self.0.is_connected()
+        }
+
+        async fn publish(
+            &self,
+            request: PublishRequest,
+        ) -> std::result::Result<(), ConnectorError> {
+            if request.topic == "domain" {
+                return Err((self.1)("message too large".to_string()));
+            }
+            self.0.publish(request).await
+        }
+
+        async fn subscribe(
+            &self,
+            stream: &str,
+            topic: &str,
+            partition: u32,
+        ) -> std::result::Result<Box<dyn rustok_iggy_connector::MessageSubscriber>, ConnectorError>
+        {
+            self.0.subscribe(stream, topic, partition).await
+        }
+
+        async fn poll(
+            &self,
+            stream: &str,
+            topic: &str,
+            partition: u32,
+            offset: u64,
+            count: u32,
+        ) -> std::result::Result<Vec<rustok_iggy_connector::PolledMessage>, ConnectorError>
+        {
+            self.0.poll(stream, topic, partition, offset, count).await
+        }
+
+        async fn shutdown(&self) -> std::result::Result<(), ConnectorError> {
+            self.0.shutdown().await
+        }
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    async fn dead_letter_connector(
+        name: &str,
+        error: fn(String) -> ConnectorError,
+    ) -> (DomainDownConnector, IggyConfig, std::path::PathBuf) {
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut iggy_config = IggyConfig::default();
+        iggy_config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        iggy_config.embedded.tcp_port = 0;
+        iggy_config.embedded.http_port = 0;
+        iggy_config.topology.dead_letter = Some(crate::config::DeadLetterConfig::default());
+
+        let connector = DomainDownConnector(EmbeddedConnector::new(), error);
+        connector
+            .connect(&ConnectorConfig::from(&iggy_config))
+            .await
+            .unwrap();
+        (connector, iggy_config, data_dir)
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    async fn dead_letter_entries(
+        connector: &DomainDownConnector,
+        config: &IggyConfig,
+    ) -> Vec<DlqEntry> {
+        DlqManager::from_config(
+            &config.topology.stream_name,
+            config.topology.dead_letter.as_ref().unwrap(),
+        )
+        .read_entries(connector)
+        .await
+        .unwrap()
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn exhausted_publish_lands_in_dead_letter_topic_with_reason() {
+        let (connector, iggy_config, data_dir) =
+            dead_letter_connector("dlq-exhausted", ConnectorError::RetriesExhausted).await;
+
+        let envelope = create_test_envelope("node.created");
+        let before = Utc::now();
+        publish(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &JsonSerializer,
+            envelope.clone(),
+        )
+        .await
+        .unwrap();
+
+        let entries = dead_letter_entries(&connector, &iggy_config).await;
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.event_id, envelope.id);
+        assert_eq!(entry.original_topic, "domain");
+        assert!(entry.error.contains("message too large"));
+        assert!(entry.failed_at >= before);
+        assert_eq!(
+            JsonSerializer.deserialize(&entry.payload).unwrap().id,
+            envelope.id
+        );
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn transient_publish_failure_is_returned_for_relay_retry() {
+        let (connector, iggy_config, data_dir) =
+            dead_letter_connector("dlq-transient", ConnectorError::Publish).await;
+
+        let error = publish(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &JsonSerializer,
+            create_test_envelope("node.created"),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, Error::External(_)));
+        assert!(dead_letter_entries(&connector, &iggy_config)
+            .await
+            .is_empty());
+
+        let envelopes = envelopes_for_tenants(&[Uuid::new_v4()], 2);
+        let error = publish_batch(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &JsonSerializer,
+            envelopes,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.failed, vec![0, 1]);
+        assert!(dead_letter_entries(&connector, &iggy_config)
+            .await
+            .is_empty());
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[cfg(not(feature = "iggy"))]
+    #[tokio::test]
+    async fn unserializable_envelope_is_dead_lettered_as_json() {
+        let (connector, iggy_config, data_dir) =
+            dead_letter_connector("dlq-serialize", ConnectorError::Publish).await;
+
+        let envelope = create_test_envelope("node.created");
+        publish(
+            &connector,
+            &iggy_config,
+            &partitioner(),
+            &FailingSerializer,
+            envelope.clone(),
+        )
+        .await
+        .unwrap();
+
+        let entries = dead_letter_entries(&connector, &iggy_config).await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event_id, envelope.id);
+        assert!(entries[0].error.contains("unsupported field"));
+        let stored: EventEnvelope = serde_json::from_slice(&entries[0].payload).unwrap();
+        assert_eq!(stored.id, envelope.id);
+
+        connector.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[tokio::test]
+    async fn publish_records_transport_metrics() {
+        let published = || {
+            rustok_telemetry::metrics::EVENT_TRANSPORT_PUBLISHED_TOTAL
+                .with_label_values(&[TRANSPORT_LABEL, "system"])
+                .get()
+        };
+        let dropped = || {
+            rustok_telemetry::metrics::EVENT_TRANSPORT_DROPPED_TOTAL
+                .with_label_values(&[TRANSPORT_LABEL, "system"])
+                .get()
+        };
+        let published_before = published();
+
+        publish(
+            &RecordingConnector::default(),
+            &IggyConfig::default(),
+            &partitioner(),
+            &JsonSerializer,
+            create_test_envelope("index.reindex_requested"),
+        )
+        .await
+        .unwrap();
+
+        assert!(published() > published_before);
+
+        let dropped_before = dropped();
+        publish(
+            &EmbeddedConnector::new(),
+            &IggyConfig::default(),
+            &partitioner(),
+            &JsonSerializer,
+            create_test_envelope("index.reindex_requested"),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(dropped() > dropped_before);
+    }
 }
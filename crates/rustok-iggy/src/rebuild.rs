@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rustok_core::Result;
+use rustok_events::{DomainEvent, EventEnvelope};
+use rustok_iggy_connector::IggyConnector;
+use serde::Serialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::{IggyConfig, SerializationFormat};
+use crate::replay::{ReplayConfig, ReplayManager, ReplayStatus};
+use crate::serialization::{EventSerializer, JsonSerializer, PostcardSerializer};
+
+/// Emit progress metrics every this many applied events.
+const PROGRESS_REPORT_EVERY: u64 = 1_000;
+
+/// Messages read from a partition per poll while rebuilding.
+const REBUILD_POLL_BATCH: u32 = 500;
+
+/// A projection that can be rebuilt from event history.
+///
+/// A rebuild writes into a fresh shadow copy of the projection and only
+/// replaces the live one in [`commit_rebuild`](Self::commit_rebuild), so
+/// readers never observe a half-built projection.
+#[async_trait]
+pub trait RebuildableProjection: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn handles(&self, event: &DomainEvent) -> bool;
+
+    /// Create an empty shadow table (dropping any leftover from a failed run).
+    async fn begin_rebuild(&self) -> Result<()>;
+
+    /// Apply one historical event to the shadow table. Called without
+    /// deduplication: replayed events are applied even if already seen live.
+    async fn apply_rebuild(&self, envelope: &EventEnvelope) -> Result<()>;
+
+    /// Atomically swap the shadow table in place of the live projection.
+    async fn commit_rebuild(&self) -> Result<()>;
+
+    /// Drop the shadow table after a failed rebuild; the live projection is untouched.
+    async fn abort_rebuild(&self) -> Result<()>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RebuildReport {
+    pub replay_id: Uuid,
+    pub projection: String,
+    /// Messages read from the stream.
+    pub scanned: u64,
+    /// Events applied to the projection.
+    pub replayed: u64,
+    /// Events skipped because they predate `from` or the projection ignores them.
+    pub skipped: u64,
+    pub duration_ms: u64,
+}
+
+/// Replays domain history through a single registered projection.
+///
+/// Other handlers never see the replayed events: nothing is re-published to
+/// the transport and no consumer group offsets move.
+pub struct ProjectionRebuilder {
+    connector: Arc<dyn IggyConnector>,
+    config: IggyConfig,
+    serializer: Arc<dyn EventSerializer>,
+    replays: ReplayManager,
+    projections: HashMap<&'static str, Arc<dyn RebuildableProjection>>,
+}
+
+impl ProjectionRebuilder {
+    pub fn new(connector: Arc<dyn IggyConnector>, config: IggyConfig) -> Self {
+        let serializer: Arc<dyn EventSerializer> = match config.serialization {
+            SerializationFormat::Json => Arc::new(JsonSerializer),
+            SerializationFormat::Postcard => Arc::new(PostcardSerializer),
+        };
+
+        Self {
+            connector,
+            config,
+            serializer,
+            replays: ReplayManager::new(),
+            projections: HashMap::new(),
+        }
+    }
+
+    pub fn register(mut self, projection: Arc<dyn RebuildableProjection>) -> Self {
+        self.projections.insert(projection.name(), projection);
+        self
+    }
+
+    pub fn replays(&self) -> &ReplayManager {
+        &self.replays
+    }
+
+    /// Rebuild `projection_name` from the domain topic, optionally starting
+    /// at events with `timestamp >= from`.
+    pub async fn rebuild_projection(
+        &self,
+        projection_name: &str,
+        from: Option<DateTime<Utc>>,
+    ) -> Result<RebuildReport> {
+        let projection = self
+            .projections
+            .get(projection_name)
+            .cloned()
+            .ok_or_else(|| {
+                rustok_core::Error::NotFound(format!("projection `{projection_name}`"))
+            })?;
+
+        let replay_config = ReplayConfig::new(
+            self.config.topology.stream_name.clone(),
+            "domain".to_string(),
+        );
+        let replay_id = self
+            .replays
+            .start_replay(self.connector.as_ref(), replay_config)
+            .await?;
+
+        info!(
+            replay_id = %replay_id,
+            projection = projection_name,
+            from = ?from,
+            "Starting projection rebuild"
+        );
+
+        let result = match projection.begin_rebuild().await {
+            Ok(()) => self.replay_into(projection.as_ref(), replay_id, from).await,
+            Err(error) => Err(error),
+        };
+        let result = match result {
+            Ok(report) => projection.commit_rebuild().await.map(|()| report),
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(report) => {
+                self.replays
+                    .finish_replay(replay_id, ReplayStatus::Completed)
+                    .await;
+                info!(
+                    replay_id = %replay_id,
+                    projection = projection_name,
+                    replayed = report.replayed,
+                    skipped = report.skipped,
+                    duration_ms = report.duration_ms,
+                    "Projection rebuild completed"
+                );
+                Ok(report)
+            }
+            Err(error) => {
+                if let Err(abort_error) = projection.abort_rebuild().await {
+                    warn!(
+                        projection = projection_name,
+                        error = %abort_error,
+                        "Failed to drop projection rebuild shadow"
+                    );
+                }
+                self.replays
+                    .finish_replay(replay_id, ReplayStatus::Failed)
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn replay_into(
+        &self,
+        projection: &dyn RebuildableProjection,
+        replay_id: Uuid,
+        from: Option<DateTime<Utc>>,
+    ) -> Result<RebuildReport> {
+        let started = Instant::now();
+        let mut report = RebuildReport {
+            replay_id,
+            projection: projection.name().to_string(),
+            scanned: 0,
+            replayed: 0,
+            skipped: 0,
+            duration_ms: 0,
+        };
+        let mut last_reported = 0;
+
+        for partition in 1..=self.config.topology.domain_partitions.max(1) {
+            let mut offset = 0;
+            loop {
+                let polled = self
+                    .connector
+                    .poll(
+                        &self.config.topology.stream_name,
+                        "domain",
+                        partition,
+                        offset,
+                        REBUILD_POLL_BATCH,
+                    )
+                    .await
+                    .map_err(|error| rustok_core::Error::External(error.to_string()))?;
+                let Some(last) = polled.last() else {
+                    break;
+                };
+                offset = last.offset + 1;
+
+                for message in polled {
+                    report.scanned += 1;
+                    let envelope = self.serializer.deserialize(&message.payload)?;
+                    if from.is_some_and(|from| envelope.timestamp < from)
+                        || !projection.handles(&envelope.event)
+                    {
+                        report.skipped += 1;
+                        continue;
+                    }
+
+                    projection.apply_rebuild(&envelope).await?;
+                    report.replayed += 1;
+
+                    if report.replayed - last_reported >= PROGRESS_REPORT_EVERY {
+                        record_progress(
+                            projection.name(),
+                            report.replayed - last_reported,
+                            &started,
+                            report.replayed,
+                        );
+                        last_reported = report.replayed;
+                    }
+                }
+            }
+        }
+
+        record_progress(
+            projection.name(),
+            report.replayed - last_reported,
+            &started,
+            report.replayed,
+        );
+        report.duration_ms = started.elapsed().as_millis() as u64;
+        Ok(report)
+    }
+}
+
+fn record_progress(projection: &str, delta: u64, started: &Instant, total: u64) {
+    let elapsed = started.elapsed().as_secs_f64();
+    let rate = if elapsed > 0.0 {
+        total as f64 / elapsed
+    } else {
+        0.0
+    };
+    rustok_telemetry::metrics::record_projection_rebuild_progress(projection, delta, rate);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustok_iggy_connector::{
+        ConnectorConfig, ConnectorError, MessageSubscriber, PolledMessage, PublishRequest,
+    };
+    use tokio::sync::Mutex;
+
+    /// Serves `messages` from partition 1; every other partition is empty.
+    struct FixtureConnector {
+        messages: Vec<Vec<u8>>,
+    }
+
+    #[async_trait]
+    impl IggyConnector for FixtureConnector {
+        async fn connect(
+            &self,
+            _config: &ConnectorConfig,
+        ) -> std::result::Result<(), ConnectorError> {
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn publish(
+            &self,
+            _request: PublishRequest,
+        ) -> std::result::Result<(), ConnectorError> {
+            Ok(())
+        }
+
+        async fn subscribe(
+            &self,
+            _stream: &str,
+            _topic: &str,
+            _partition: u32,
+        ) -> std::result::Result<Box<dyn MessageSubscriber>, ConnectorError> {
+            Err(ConnectorError::Unavailable("fixture".to_string()))
+        }
+
+        async fn poll(
+            &self,
+            _stream: &str,
+            _topic: &str,
+            partition: u32,
+            offset: u64,
+            count: u32,
+        ) -> std::result::Result<Vec<PolledMessage>, ConnectorError> {
+            if partition != 1 {
+                return Ok(Vec::new());
+            }
+            Ok(self
+                .messages
+                .iter()
+                .enumerate()
+                .skip(offset as usize)
+                .take(count as usize)
+                .map(|(offset, payload)| PolledMessage {
+                    offset: offset as u64,
+                    payload: payload.clone(),
+                })
+                .collect())
+        }
+
+        async fn shutdown(&self) -> std::result::Result<(), ConnectorError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingProjection {
+        log: Mutex<Vec<String>>,
+        fail_on_apply: bool,
+    }
+
+    #[async_trait]
+    impl RebuildableProjection for RecordingProjection {
+        fn name(&self) -> &'static str {
+            "nodes"
+        }
+
+        fn handles(&self, event: &DomainEvent) -> bool {
+            matches!(event, DomainEvent::NodeCreated { .. })
+        }
+
+        async fn begin_rebuild(&self) -> Result<()> {
+            self.log.lock().await.push("begin".to_string());
+            Ok(())
+        }
+
+        async fn apply_rebuild(&self, envelope: &EventEnvelope) -> Result<()> {
+            if self.fail_on_apply {
+                return Err(rustok_core::Error::External("boom".to_string()));
+            }
+            self.log.lock().await.push(envelope.event_type.clone());
+            Ok(())
+        }
+
+        async fn commit_rebuild(&self) -> Result<()> {
+            self.log.lock().await.push("commit".to_string());
+            Ok(())
+        }
+
+        async fn abort_rebuild(&self) -> Result<()> {
+            self.log.lock().await.push("abort".to_string());
+            Ok(())
+        }
+    }
+
+    fn node_created(timestamp: DateTime<Utc>) -> Vec<u8> {
+        let mut envelope = EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::NodeCreated {
+                node_id: Uuid::new_v4(),
+                kind: "post".to_string(),
+                author_id: None,
+            },
+        );
+        envelope.timestamp = timestamp;
+        JsonSerializer.serialize(&envelope).unwrap()
+    }
+
+    fn tag_created() -> Vec<u8> {
+        let envelope = EventEnvelope::new(
+            Uuid::new_v4(),
+            None,
+            DomainEvent::TagCreated {
+                tag_id: Uuid::new_v4(),
+            },
+        );
+        JsonSerializer.serialize(&envelope).unwrap()
+    }
+
+    fn rebuilder(
+        messages: Vec<Vec<u8>>,
+        projection: Arc<RecordingProjection>,
+    ) -> ProjectionRebuilder {
+        ProjectionRebuilder::new(
+            Arc::new(FixtureConnector { messages }),
+            IggyConfig::default(),
+        )
+        .register(projection)
+    }
+
+    #[tokio::test]
+    async fn rebuild_applies_only_matching_events_after_from_and_swaps() {
+        let cutoff = Utc::now();
+        let projection = Arc::new(RecordingProjection::default());
+        let rebuilder = rebuilder(
+            vec![
+                node_created(cutoff - chrono::Duration::hours(1)),
+                node_created(cutoff + chrono::Duration::seconds(1)),
+                tag_created(),
+            ],
+            projection.clone(),
+        );
+
+        let report = rebuilder
+            .rebuild_projection("nodes", Some(cutoff))
+            .await
+            .unwrap();
+
+        assert_eq!(report.scanned, 3);
+        assert_eq!(report.replayed, 1);
+        assert_eq!(report.skipped, 2);
+        assert_eq!(
+            *projection.log.lock().await,
+            vec!["begin", "node.created", "commit"]
+        );
+        assert_eq!(
+            rebuilder
+                .replays()
+                .get_replay_status(report.replay_id)
+                .await,
+            Some(ReplayStatus::Completed)
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_rebuild_aborts_shadow_and_marks_replay_failed() {
+        let projection = Arc::new(RecordingProjection {
+            fail_on_apply: true,
+            ..Default::default()
+        });
+        let rebuilder = rebuilder(vec![node_created(Utc::now())], projection.clone());
+
+        assert!(rebuilder.rebuild_projection("nodes", None).await.is_err());
+        assert_eq!(*projection.log.lock().await, vec!["begin", "abort"]);
+    }
+
+    #[tokio::test]
+    async fn unknown_projection_is_not_found() {
+        let rebuilder = rebuilder(Vec::new(), Arc::new(RecordingProjection::default()));
+        let error = rebuilder
+            .rebuild_projection("missing", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, rustok_core::Error::NotFound(_)));
+    }
+}
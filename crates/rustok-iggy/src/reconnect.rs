@@ -0,0 +1,468 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rustok_core::BackoffPolicy;
+use rustok_iggy_connector::{
+    ConnectorConfig, ConnectorError, IggyConnector, MessageSubscriber, PolledMessage,
+    PublishRequest,
+};
+use tracing::{info, warn};
+
+use crate::config::RemoteConfig;
+
+/// Operation label for `rustok_retry_attempts_total`.
+const RETRY_OPERATION: &str = "iggy_publish";
+
+/// Connector wrapper that re-establishes a dropped connection and retries the
+/// failed publish.
+///
+/// A publish that fails with a connection-level error reconnects after a
+/// [`BackoffPolicy`] delay and tries again, up to `max_attempts` in total.
+/// While one publish is reconnecting, concurrent publishes fail fast with
+/// [`ConnectorError::Unavailable`] instead of piling up behind it. A publish
+/// that runs out of attempts fails with [`ConnectorError::RetriesExhausted`].
+pub struct ReconnectingConnector {
+    inner: Arc<dyn IggyConnector>,
+    config: ConnectorConfig,
+    backoff: BackoffPolicy,
+    max_attempts: u32,
+    reconnecting: AtomicBool,
+}
+
+impl ReconnectingConnector {
+    pub fn new(
+        inner: Arc<dyn IggyConnector>,
+        config: ConnectorConfig,
+        backoff: BackoffPolicy,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            inner,
+            config,
+            backoff,
+            max_attempts: max_attempts.max(1),
+            reconnecting: AtomicBool::new(false),
+        }
+    }
+
+    pub fn from_remote_config(
+        inner: Arc<dyn IggyConnector>,
+        config: ConnectorConfig,
+        remote: &RemoteConfig,
+    ) -> Self {
+        Self::new(
+            inner,
+            config,
+            remote.reconnect_backoff(),
+            remote.publish_max_attempts,
+        )
+    }
+
+    pub fn is_reconnecting(&self) -> bool {
+        self.reconnecting.load(Ordering::Acquire)
+    }
+
+    async fn reconnect(&self, attempt: u32) {
+        tokio::time::sleep(self.backoff.delay_for_attempt(attempt)).await;
+        rustok_telemetry::metrics::record_retry_attempt(RETRY_OPERATION);
+
+        // A failed shutdown of the broken client does not prevent a new one.
+        let _ = self.inner.shutdown().await;
+        match self.inner.connect(&self.config).await {
+            Ok(()) => info!(attempt, "Reconnected to Iggy"),
+            Err(error) => warn!(attempt, error = %error, "Iggy reconnect failed"),
+        }
+    }
+
+    async fn send(&self, outgoing: &Outgoing) -> Result<(), ConnectorError> {
+        match outgoing {
+            Outgoing::One(request) => self.inner.publish(request.clone()).await,
+            Outgoing::Batch(requests) => self.inner.publish_batch(requests.clone()).await,
+        }
+    }
+
+    async fn send_with_reconnect(&self, outgoing: Outgoing) -> Result<(), ConnectorError> {
+        if self.is_reconnecting() {
+            return Err(ConnectorError::Unavailable(
+                "reconnecting to Iggy".to_string(),
+            ));
+        }
+
+        let mut guard = None;
+        let mut attempt = 1;
+        loop {
+            let error = match self.send(&outgoing).await {
+                Ok(()) => return Ok(()),
+                Err(error) if is_disconnect(&error) => error,
+                Err(error) => return Err(error),
+            };
+
+            if attempt >= self.max_attempts {
+                return Err(ConnectorError::RetriesExhausted(format!(
+                    "publish failed after {attempt} attempts: {error}"
+                )));
+            }
+
+            if guard.is_none() {
+                if self
+                    .reconnecting
+                    .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+                {
+                    return Err(ConnectorError::Unavailable(format!(
+                        "reconnecting to Iggy after: {error}"
+                    )));
+                }
+                guard = Some(ReconnectingGuard(&self.reconnecting));
+            }
+
+            warn!(
+                attempt,
+                max_attempts = self.max_attempts,
+                event_id = %outgoing.event_id(),
+                messages = outgoing.messages(),
+                error = %error,
+                "Iggy publish failed, reconnecting"
+            );
+            self.reconnect(attempt).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Errors after which the connection is assumed broken. The connector
+/// reports SDK transport failures as `Connection`; `Publish` errors such as
+/// validation or oversize rejections leave the connection alone.
+fn is_disconnect(error: &ConnectorError) -> bool {
+    matches!(
+        error,
+        ConnectorError::Connection(_) | ConnectorError::NotConnected | ConnectorError::Timeout(_)
+    )
+}
+
+enum Outgoing {
+    One(PublishRequest),
+    Batch(Vec<PublishRequest>),
+}
+
+impl Outgoing {
+    fn event_id(&self) -> &str {
+        match self {
+            Outgoing::One(request) => &request.event_id,
+            Outgoing::Batch(requests) => requests
+                .first()
+                .map_or("", |request| request.event_id.as_str()),
+        }
+    }
+
+    fn messages(&self) -> usize {
+        match self {
+            Outgoing::One(_) => 1,
+            Outgoing::Batch(requests) => requests.len(),
+        }
+    }
+}
+
+struct ReconnectingGuard<'a>(&'a AtomicBool);
+
+impl Drop for ReconnectingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+#[async_trait]
+impl IggyConnector for ReconnectingConnector {
+    async fn connect(&self, config: &ConnectorConfig) -> Result<(), ConnectorError> {
+        self.inner.connect(config).await
+    }
+
+    fn is_connected(&self) -> bool {
+        !self.is_reconnecting() && self.inner.is_connected()
+    }
+
+    async fn publish(&self, request: PublishRequest) -> Result<(), ConnectorError> {
+        self.send_with_reconnect(Outgoing::One(request)).await
+    }
+
+    async fn publish_batch(&self, requests: Vec<PublishRequest>) -> Result<(), ConnectorError> {
+        self.send_with_reconnect(Outgoing::Batch(requests)).await
+    }
+
+    async fn subscribe(
+        &self,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+    ) -> Result<Box<dyn MessageSubscriber>, ConnectorError> {
+        self.inner.subscribe(stream, topic, partition).await
+    }
+
+    async fn poll(
+        &self,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+        offset: u64,
+        count: u32,
+    ) -> Result<Vec<PolledMessage>, ConnectorError> {
+        self.inner
+            .poll(stream, topic, partition, offset, count)
+            .await
+    }
+
+    async fn partition_head(
+        &self,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+    ) -> Result<u64, ConnectorError> {
+        self.inner.partition_head(stream, topic, partition).await
+    }
+
+    async fn store_offset(
+        &self,
+        consumer: &str,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+        offset: u64,
+    ) -> Result<(), ConnectorError> {
+        self.inner
+            .store_offset(consumer, stream, topic, partition, offset)
+            .await
+    }
+
+    async fn stored_offset(
+        &self,
+        consumer: &str,
+        stream: &str,
+        topic: &str,
+        partition: u32,
+    ) -> Result<Option<u64>, ConnectorError> {
+        self.inner
+            .stored_offset(consumer, stream, topic, partition)
+            .await
+    }
+
+    async fn shutdown(&self) -> Result<(), ConnectorError> {
+        self.inner.shutdown().await
+    }
+}
+
+impl std::fmt::Debug for ReconnectingConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingConnector")
+            .field("backoff", &self.backoff)
+            .field("max_attempts", &self.max_attempts)
+            .field("reconnecting", &self.is_reconnecting())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    /// Remote server stand-in whose connection drops once, after `drop_after`
+    /// publishes, and comes back on the next successful `connect`.
+    #[derive(Default)]
+    struct FlakyConnector {
+        connected: AtomicBool,
+        published: AtomicU32,
+        drop_after: u32,
+        dropped: AtomicBool,
+        /// Reconnects that fail before one succeeds.
+        failing_connects: AtomicU32,
+        reconnect_delay: Duration,
+    }
+
+    #[async_trait]
+    impl IggyConnector for FlakyConnector {
+        async fn connect(&self, _config: &ConnectorConfig) -> Result<(), ConnectorError> {
+            tokio::time::sleep(self.reconnect_delay).await;
+            if self
+                .failing_connects
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(ConnectorError::Connection("connection refused".to_string()));
+            }
+            self.connected.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            self.connected.load(Ordering::SeqCst)
+        }
+
+        async fn publish(&self, _request: PublishRequest) -> Result<(), ConnectorError> {
+            if !self.connected.load(Ordering::SeqCst) {
+                return Err(ConnectorError::NotConnected);
+            }
+            if self.published.load(Ordering::SeqCst) == self.drop_after
+                && !self.dropped.swap(true, Ordering::SeqCst)
+            {
+                self.connected.store(false, Ordering::SeqCst);
+                return Err(ConnectorError::Connection(
+                    "connection reset by peer".to_string(),
+                ));
+            }
+            self.published.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn subscribe(
+            &self,
+            _stream: &str,
+            _topic: &str,
+            _partition: u32,
+        ) -> Result<Box<dyn MessageSubscriber>, ConnectorError> {
+            Err(ConnectorError::Subscribe("not supported".to_string()))
+        }
+
+        async fn shutdown(&self) -> Result<(), ConnectorError> {
+            self.connected.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn request() -> PublishRequest {
+        PublishRequest::simple("tenant", vec![1, 2, 3], "event")
+    }
+
+    fn connected(flaky: FlakyConnector, max_attempts: u32) -> Arc<ReconnectingConnector> {
+        flaky.connected.store(true, Ordering::SeqCst);
+        Arc::new(ReconnectingConnector::new(
+            Arc::new(flaky),
+            ConnectorConfig::default(),
+            BackoffPolicy::Fixed(Duration::from_millis(1)),
+            max_attempts,
+        ))
+    }
+
+    fn retries() -> u64 {
+        rustok_telemetry::metrics::RETRY_ATTEMPTS_TOTAL
+            .with_label_values(&[RETRY_OPERATION])
+            .get()
+    }
+
+    #[tokio::test]
+    async fn publish_succeeds_after_dropped_connection_is_reestablished() {
+        let connector = connected(
+            FlakyConnector {
+                drop_after: 1,
+                failing_connects: AtomicU32::new(1),
+                ..Default::default()
+            },
+            5,
+        );
+        let before = retries();
+
+        connector.publish(request()).await.unwrap();
+        // The server drops the connection here; the first reconnect is
+        // refused, the second one succeeds and the publish goes through.
+        connector.publish(request()).await.unwrap();
+
+        assert!(!connector.is_reconnecting());
+        assert!(connector.is_connected());
+        assert!(retries() >= before + 2);
+    }
+
+    #[tokio::test]
+    async fn publish_gives_up_after_max_attempts() {
+        let connector = connected(
+            FlakyConnector {
+                drop_after: 0,
+                failing_connects: AtomicU32::new(u32::MAX),
+                ..Default::default()
+            },
+            3,
+        );
+
+        let error = connector.publish(request()).await.unwrap_err();
+
+        assert!(matches!(error, ConnectorError::RetriesExhausted(_)));
+        assert!(!connector.is_reconnecting());
+    }
+
+    /// Connected server that rejects every message without dropping the
+    /// connection, and counts how often it is asked to reconnect.
+    #[derive(Default)]
+    struct RejectingConnector {
+        connects: AtomicU32,
+    }
+
+    #[async_trait]
+    impl IggyConnector for RejectingConnector {
+        async fn connect(&self, _config: &ConnectorConfig) -> Result<(), ConnectorError> {
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn publish(&self, _request: PublishRequest) -> Result<(), ConnectorError> {
+            Err(ConnectorError::Publish("message too large".to_string()))
+        }
+
+        async fn subscribe(
+            &self,
+            _stream: &str,
+            _topic: &str,
+            _partition: u32,
+        ) -> Result<Box<dyn MessageSubscriber>, ConnectorError> {
+            Err(ConnectorError::Subscribe("not supported".to_string()))
+        }
+
+        async fn shutdown(&self) -> Result<(), ConnectorError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn rejected_publish_is_returned_without_reconnecting() {
+        let inner = Arc::new(RejectingConnector::default());
+        let connector = ReconnectingConnector::new(
+            inner.clone(),
+            ConnectorConfig::default(),
+            BackoffPolicy::Fixed(Duration::from_millis(1)),
+            5,
+        );
+
+        let error = connector.publish(request()).await.unwrap_err();
+
+        assert!(matches!(error, ConnectorError::Publish(_)));
+        assert_eq!(inner.connects.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn concurrent_publish_is_unavailable_while_reconnecting() {
+        let connector = connected(
+            FlakyConnector {
+                drop_after: 0,
+                reconnect_delay: Duration::from_millis(100),
+                ..Default::default()
+            },
+            5,
+        );
+
+        let reconnecting = {
+            let connector = connector.clone();
+            tokio::spawn(async move { connector.publish(request()).await })
+        };
+        while !connector.is_reconnecting() {
+            tokio::task::yield_now().await;
+        }
+
+        let error = connector.publish(request()).await.unwrap_err();
+        assert!(matches!(error, ConnectorError::Unavailable(_)));
+
+        reconnecting.await.unwrap().unwrap();
+    }
+}
@@ -1,18 +1,30 @@
+use std::ops::{Bound, RangeBounds};
 use std::sync::Arc;
+use std::time::Instant;
 
-use rustok_core::Result;
+use rustok_core::events::EventHandler;
+use rustok_core::{Error, Result};
 use rustok_iggy_connector::IggyConnector;
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::serialization::EventSerializer;
+
+/// Messages fetched from a partition per poll during a replay.
+const REPLAY_POLL_BATCH: u32 = 500;
+
 #[derive(Debug, Clone)]
 pub struct ReplayConfig {
     pub stream: String,
     pub topic: String,
+    /// First offset to replay in each partition (inclusive); `None` is the start.
     pub from_offset: Option<u64>,
+    /// Offset to stop before in each partition (exclusive); `None` is the head.
     pub to_offset: Option<u64>,
     pub consumer_group: Option<String>,
+    /// Partitions `1..=partitions` of the topic are replayed.
+    pub partitions: u32,
 }
 
 impl Default for ReplayConfig {
@@ -23,6 +35,7 @@ impl Default for ReplayConfig {
             from_offset: None,
             to_offset: None,
             consumer_group: None,
+            partitions: 8,
         }
     }
 }
@@ -46,10 +59,72 @@ impl ReplayConfig {
         self
     }
 
+    /// Set both bounds from a range, e.g. `3..7` or `3..` (to the head).
+    pub fn offsets(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.from_offset = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => Some(start.saturating_add(1)),
+            Bound::Unbounded => None,
+        };
+        self.to_offset = match range.end_bound() {
+            Bound::Included(&end) => Some(end.saturating_add(1)),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        self
+    }
+
     pub fn consumer_group(mut self, group: String) -> Self {
         self.consumer_group = Some(group);
         self
     }
+
+    pub fn partitions(mut self, partitions: u32) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    fn contains(&self, offset: u64) -> bool {
+        self.from_offset.is_none_or(|from| offset >= from)
+            && self.to_offset.is_none_or(|to| offset < to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayReport {
+    pub replay_id: Uuid,
+    /// Envelopes delivered to the handler.
+    pub replayed: u64,
+    /// Envelopes in range that the handler does not handle.
+    pub skipped: u64,
+    pub duration_ms: u64,
+}
+
+/// A replay that stopped part-way. Partitions are replayed in ascending
+/// order, so a caller resumes from `partition` at `last_processed_offset + 1`
+/// (or from the range start when nothing was processed there yet).
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "replay {replay_id} failed in partition {partition} at offset {failed_offset:?} \
+     (last processed offset: {last_processed_offset:?}, replayed: {replayed}): {source}"
+)]
+pub struct ReplayError {
+    pub replay_id: Uuid,
+    pub partition: u32,
+    /// Offset of the message that failed; `None` if the partition could not be read.
+    pub failed_offset: Option<u64>,
+    /// Last offset of `partition` the handler completed, if any.
+    pub last_processed_offset: Option<u64>,
+    /// Envelopes delivered before the failure, across all partitions.
+    pub replayed: u64,
+    #[source]
+    pub source: Error,
+}
+
+impl From<ReplayError> for Error {
+    fn from(error: ReplayError) -> Self {
+        Error::External(error.to_string())
+    }
 }
 
 #[derive(Debug)]
@@ -122,6 +197,16 @@ impl ReplayManager {
             .map(|r| r.status)
     }
 
+    /// Record the terminal status of a replay started with [`Self::start_replay`].
+    pub async fn finish_replay(&self, replay_id: Uuid, status: ReplayStatus) -> bool {
+        let mut replays = self.active_replays.write().await;
+        if let Some(replay) = replays.iter_mut().find(|r| r.id == replay_id) {
+            replay.status = status;
+            return true;
+        }
+        false
+    }
+
     pub async fn cancel_replay(&self, replay_id: Uuid) -> bool {
         let mut replays = self.active_replays.write().await;
         if let Some(replay) = replays.iter_mut().find(|r| r.id == replay_id) {
@@ -133,6 +218,161 @@ impl ReplayManager {
     }
 }
 
+impl ReplayManager {
+    /// Re-dispatch the envelopes in `config`'s offset range through `handler`,
+    /// e.g. to rebuild a read projection after a schema change.
+    ///
+    /// Unlike the live dispatcher there are no retries: the first handler or
+    /// decode error stops the replay and is returned as a [`ReplayError`].
+    pub async fn replay_from(
+        &self,
+        connector: &dyn IggyConnector,
+        serializer: &dyn EventSerializer,
+        config: ReplayConfig,
+        handler: &dyn EventHandler,
+    ) -> std::result::Result<ReplayReport, ReplayError> {
+        let started = Instant::now();
+        let replay_id = self
+            .start_replay(connector, config.clone())
+            .await
+            .map_err(|source| ReplayError {
+                replay_id: Uuid::nil(),
+                partition: 0,
+                failed_offset: None,
+                last_processed_offset: None,
+                replayed: 0,
+                source,
+            })?;
+        let mut report = ReplayReport {
+            replay_id,
+            replayed: 0,
+            skipped: 0,
+            duration_ms: 0,
+        };
+
+        for partition in 1..=config.partitions.max(1) {
+            if let Err(error) = replay_partition(
+                connector,
+                serializer,
+                &config,
+                handler,
+                partition,
+                &mut report,
+            )
+            .await
+            {
+                self.finish_replay(replay_id, ReplayStatus::Failed).await;
+                warn!(
+                    replay_id = %replay_id,
+                    handler = handler.name(),
+                    error = %error,
+                    "Event replay failed"
+                );
+                return Err(error);
+            }
+        }
+
+        report.duration_ms = started.elapsed().as_millis() as u64;
+        self.finish_replay(replay_id, ReplayStatus::Completed).await;
+        info!(
+            replay_id = %replay_id,
+            handler = handler.name(),
+            replayed = report.replayed,
+            skipped = report.skipped,
+            duration_ms = report.duration_ms,
+            "Event replay completed"
+        );
+        Ok(report)
+    }
+}
+
+async fn replay_partition(
+    connector: &dyn IggyConnector,
+    serializer: &dyn EventSerializer,
+    config: &ReplayConfig,
+    handler: &dyn EventHandler,
+    partition: u32,
+    report: &mut ReplayReport,
+) -> std::result::Result<(), ReplayError> {
+    let replay_id = report.replay_id;
+    let mut last_processed_offset = None;
+    let fail = |failed_offset, last_processed_offset, replayed, source| ReplayError {
+        replay_id,
+        partition,
+        failed_offset,
+        last_processed_offset,
+        replayed,
+        source,
+    };
+
+    // Read by offset, so replay never scans the part of the partition
+    // before the range. A connector that cannot read messages back fails
+    // the replay rather than reporting an empty one.
+    let mut offset = config.from_offset.unwrap_or(0);
+    loop {
+        if config.to_offset.is_some_and(|to| offset >= to) {
+            break;
+        }
+        let polled = connector
+            .poll(
+                &config.stream,
+                &config.topic,
+                partition,
+                offset,
+                REPLAY_POLL_BATCH,
+            )
+            .await
+            .map_err(|error| {
+                fail(
+                    Some(offset),
+                    last_processed_offset,
+                    report.replayed,
+                    connector_error(error),
+                )
+            })?;
+        let Some(last) = polled.last() else {
+            break;
+        };
+        let next_offset = last.offset + 1;
+
+        for message in polled {
+            if !config.contains(message.offset) {
+                continue;
+            }
+            let envelope = serializer.deserialize(&message.payload).map_err(|error| {
+                fail(
+                    Some(message.offset),
+                    last_processed_offset,
+                    report.replayed,
+                    error,
+                )
+            })?;
+
+            if handler.handles(&envelope.event) {
+                handler.handle(&envelope).await.map_err(|error| {
+                    fail(
+                        Some(message.offset),
+                        last_processed_offset,
+                        report.replayed,
+                        error,
+                    )
+                })?;
+                report.replayed += 1;
+            } else {
+                report.skipped += 1;
+            }
+            last_processed_offset = Some(message.offset);
+        }
+        offset = next_offset;
+    }
+
+    Ok(())
+}
+
+fn connector_error(error: rustok_iggy_connector::ConnectorError) -> Error {
+    Error::External(error.to_string())
+}
+
 pub async fn replay_events(connector: &dyn IggyConnector, config: ReplayConfig) -> Result<Uuid> {
     let manager = ReplayManager::new();
     manager.start_replay(connector, config).await
@@ -170,4 +410,225 @@ mod tests {
         let manager = ReplayManager::new();
         assert!(manager.active_replays.read().await.is_empty());
     }
+
+    #[test]
+    fn replay_config_offsets_from_range() {
+        let bounded = ReplayConfig::default().offsets(3..7);
+        assert_eq!((bounded.from_offset, bounded.to_offset), (Some(3), Some(7)));
+
+        let inclusive = ReplayConfig::default().offsets(3..=6);
+        assert_eq!(
+            (inclusive.from_offset, inclusive.to_offset),
+            (Some(3), Some(7))
+        );
+
+        let open = ReplayConfig::default().offsets(5..);
+        assert_eq!((open.from_offset, open.to_offset), (Some(5), None));
+    }
+
+    // Without the `iggy` feature the embedded connector keeps published
+    // messages in memory, so no `iggy-server` binary is needed.
+    #[cfg(not(feature = "iggy"))]
+    mod embedded {
+        use super::*;
+        use crate::config::IggyConfig;
+        use crate::serialization::JsonSerializer;
+        use async_trait::async_trait;
+        use rustok_core::events::HandlerResult;
+        use rustok_events::{DomainEvent, EventEnvelope};
+        use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector};
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct RecordingHandler {
+            seen: Mutex<Vec<Uuid>>,
+            fail_on: Option<Uuid>,
+        }
+
+        #[async_trait]
+        impl EventHandler for RecordingHandler {
+            fn name(&self) -> &'static str {
+                "replay_recorder"
+            }
+
+            fn handles(&self, _event: &DomainEvent) -> bool {
+                true
+            }
+
+            async fn handle(&self, envelope: &EventEnvelope) -> HandlerResult {
+                if self.fail_on == Some(envelope.id) {
+                    return Err(Error::External("projection unavailable".to_string()));
+                }
+                self.seen.lock().unwrap().push(envelope.id);
+                Ok(())
+            }
+        }
+
+        /// Publishes `count` events of one tenant, so they share a partition
+        /// and take offsets `0..count`.
+        async fn publish_events(
+            name: &str,
+            count: usize,
+        ) -> (EmbeddedConnector, IggyConfig, Vec<EventEnvelope>) {
+            let data_dir = std::env::temp_dir().join(format!(
+                "rustok-iggy-replay-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&data_dir);
+            let mut config = IggyConfig::default();
+            config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+            config.embedded.tcp_port = 0;
+            config.embedded.http_port = 0;
+
+            let connector = EmbeddedConnector::new();
+            connector
+                .connect(&ConnectorConfig::from(&config))
+                .await
+                .unwrap();
+
+            let partitioner = crate::partitioning::Partitioner::from_config(&config.topology);
+            let tenant_id = Uuid::new_v4();
+            let mut envelopes = Vec::with_capacity(count);
+            for _ in 0..count {
+                let envelope = EventEnvelope::new(
+                    tenant_id,
+                    None,
+                    DomainEvent::NodeCreated {
+                        node_id: Uuid::new_v4(),
+                        kind: "post".to_string(),
+                        author_id: None,
+                    },
+                );
+                crate::producer::publish(
+                    &connector,
+                    &config,
+                    &partitioner,
+                    &JsonSerializer,
+                    envelope.clone(),
+                )
+                .await
+                .unwrap();
+                envelopes.push(envelope);
+            }
+
+            (connector, config, envelopes)
+        }
+
+        async fn cleanup(connector: EmbeddedConnector, config: IggyConfig) {
+            connector.shutdown().await.unwrap();
+            let _ = std::fs::remove_dir_all(&config.embedded.data_dir);
+        }
+
+        fn replay_config(config: &IggyConfig) -> ReplayConfig {
+            ReplayConfig::new(config.topology.stream_name.clone(), "domain".to_string())
+                .partitions(config.topology.domain_partitions)
+        }
+
+        #[tokio::test]
+        async fn replay_from_delivers_bounded_range() {
+            let (connector, config, envelopes) = publish_events("bounded", 10).await;
+            let handler = RecordingHandler::default();
+            let manager = ReplayManager::new();
+
+            let report = manager
+                .replay_from(
+                    &connector,
+                    &JsonSerializer,
+                    replay_config(&config).offsets(3..7),
+                    &handler,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(report.replayed, 4);
+            let expected: Vec<Uuid> = envelopes[3..7].iter().map(|e| e.id).collect();
+            assert_eq!(*handler.seen.lock().unwrap(), expected);
+            assert_eq!(
+                manager.get_replay_status(report.replay_id).await,
+                Some(ReplayStatus::Completed)
+            );
+
+            cleanup(connector, config).await;
+        }
+
+        #[tokio::test]
+        async fn replay_from_open_range_runs_to_head() {
+            let (connector, config, _) = publish_events("open", 10).await;
+            let handler = RecordingHandler::default();
+
+            let report = ReplayManager::new()
+                .replay_from(
+                    &connector,
+                    &JsonSerializer,
+                    replay_config(&config).offsets(7..),
+                    &handler,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(report.replayed, 3);
+
+            cleanup(connector, config).await;
+        }
+
+        #[tokio::test]
+        async fn replay_fails_when_connector_cannot_read_back() {
+            use rustok_iggy_connector::RemoteConnector;
+
+            let connector = RemoteConnector::new();
+            connector
+                .connect(&ConnectorConfig::default())
+                .await
+                .unwrap();
+            let manager = ReplayManager::new();
+
+            let error = manager
+                .replay_from(
+                    &connector,
+                    &JsonSerializer,
+                    ReplayConfig::default(),
+                    &RecordingHandler::default(),
+                )
+                .await
+                .unwrap_err();
+
+            assert_eq!(error.partition, 1);
+            assert_eq!(error.replayed, 0);
+            assert_eq!(
+                manager.get_replay_status(error.replay_id).await,
+                Some(ReplayStatus::Failed)
+            );
+        }
+
+        #[tokio::test]
+        async fn replay_failure_reports_last_processed_offset() {
+            let (connector, config, envelopes) = publish_events("failure", 10).await;
+            let handler = RecordingHandler {
+                fail_on: Some(envelopes[5].id),
+                ..Default::default()
+            };
+            let manager = ReplayManager::new();
+
+            let error = manager
+                .replay_from(
+                    &connector,
+                    &JsonSerializer,
+                    replay_config(&config).offsets(3..),
+                    &handler,
+                )
+                .await
+                .unwrap_err();
+
+            assert_eq!(error.failed_offset, Some(5));
+            assert_eq!(error.last_processed_offset, Some(4));
+            assert_eq!(error.replayed, 2);
+            assert_eq!(
+                manager.get_replay_status(error.replay_id).await,
+                Some(ReplayStatus::Failed)
+            );
+
+            cleanup(connector, config).await;
+        }
+    }
 }
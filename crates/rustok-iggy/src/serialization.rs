@@ -6,6 +6,7 @@ use crate::config::SerializationFormat;
 pub trait EventSerializer: Send + Sync {
     fn format(&self) -> SerializationFormat;
     fn serialize(&self, envelope: &EventEnvelope) -> Result<Vec<u8>>;
+    fn deserialize(&self, payload: &[u8]) -> Result<EventEnvelope>;
 }
 
 #[derive(Debug, Default)]
@@ -19,6 +20,13 @@ impl EventSerializer for JsonSerializer {
     fn serialize(&self, envelope: &EventEnvelope) -> Result<Vec<u8>> {
         Ok(serde_json::to_vec(envelope)?)
     }
+
+    /// Decodes through [`rustok_events::decode_envelope`], so envelopes
+    /// written with an older schema version are upcast first.
+    fn deserialize(&self, payload: &[u8]) -> Result<EventEnvelope> {
+        let envelope = serde_json::from_slice(payload)?;
+        Ok(rustok_events::decode_envelope(envelope).map_err(serde_json::Error::from)?)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -32,6 +40,10 @@ impl EventSerializer for PostcardSerializer {
     fn serialize(&self, envelope: &EventEnvelope) -> Result<Vec<u8>> {
         postcard::to_stdvec(envelope).map_err(|err| rustok_core::Error::External(err.to_string()))
     }
+
+    fn deserialize(&self, payload: &[u8]) -> Result<EventEnvelope> {
+        postcard::from_bytes(payload).map_err(|err| rustok_core::Error::External(err.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +114,25 @@ mod tests {
         assert_eq!(envelope.id, deserialized.id);
         assert_eq!(envelope.tenant_id, deserialized.tenant_id);
     }
+
+    #[test]
+    fn json_deserialize_decodes_v1_envelope_through_upcasters() {
+        let serializer = JsonSerializer;
+        let envelope = create_test_envelope();
+        let mut stored = serde_json::to_value(&envelope).unwrap();
+        stored["schema_version"] = 1.into();
+
+        let decoded = serializer
+            .deserialize(&serde_json::to_vec(&stored).unwrap())
+            .unwrap();
+        assert_eq!(decoded.id, envelope.id);
+        assert_eq!(decoded.event, envelope.event);
+
+        stored["schema_version"] = 7.into();
+        let error = serializer
+            .deserialize(&serde_json::to_vec(&stored).unwrap())
+            .unwrap_err();
+        assert!(matches!(error, rustok_core::Error::Serialization(_)));
+        assert!(error.to_string().contains("newer than the current"));
+    }
 }
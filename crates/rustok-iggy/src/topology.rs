@@ -11,6 +11,7 @@ pub struct TopologyManager {
     stream_name: Arc<RwLock<String>>,
     domain_topic: Arc<RwLock<String>>,
     system_topic: Arc<RwLock<String>>,
+    dead_letter_topic: Arc<RwLock<Option<String>>>,
     partitions: Arc<RwLock<u32>>,
     initialized: Arc<RwLock<bool>>,
 }
@@ -27,6 +28,7 @@ impl TopologyManager {
             stream_name: Arc::new(RwLock::new(String::new())),
             domain_topic: Arc::new(RwLock::new(String::new())),
             system_topic: Arc::new(RwLock::new(String::new())),
+            dead_letter_topic: Arc::new(RwLock::new(None)),
             partitions: Arc::new(RwLock::new(0)),
             initialized: Arc::new(RwLock::new(false)),
         }
@@ -35,7 +37,7 @@ impl TopologyManager {
     pub async fn ensure_topology(
         &self,
         config: &IggyConfig,
-        _connector: &dyn IggyConnector,
+        connector: &dyn IggyConnector,
     ) -> rustok_core::Result<()> {
         let stream_name = config.topology.stream_name.clone();
         let partitions = config.topology.domain_partitions;
@@ -47,12 +49,30 @@ impl TopologyManager {
             domain_retention_days = config.retention.domain_max_age_days,
             system_retention_days = config.retention.system_max_age_days,
             dlq_retention_days = config.retention.dlq_max_age_days,
+            dead_letter_topic = config.topology.dead_letter.as_ref().map(|dlq| dlq.topic.as_str()),
             "Ensuring iggy topology"
         );
 
+        if let Some(dead_letter) = &config.topology.dead_letter {
+            connector
+                .ensure_topic(&stream_name, &dead_letter.topic, dead_letter.partitions)
+                .await
+                .map_err(|error| {
+                    rustok_core::Error::External(format!(
+                        "failed to provision dead-letter topic {}: {error}",
+                        dead_letter.topic
+                    ))
+                })?;
+        }
+
         *self.stream_name.write().await = stream_name.clone();
         *self.domain_topic.write().await = "domain".to_string();
         *self.system_topic.write().await = "system".to_string();
+        *self.dead_letter_topic.write().await = config
+            .topology
+            .dead_letter
+            .as_ref()
+            .map(|dead_letter| dead_letter.topic.clone());
         *self.partitions.write().await = partitions;
         *self.initialized.write().await = true;
 
@@ -71,6 +91,11 @@ impl TopologyManager {
         self.system_topic.read().await.clone()
     }
 
+    /// `None` when dead-lettering is disabled.
+    pub async fn dead_letter_topic(&self) -> Option<String> {
+        self.dead_letter_topic.read().await.clone()
+    }
+
     pub async fn is_initialized(&self) -> bool {
         *self.initialized.read().await
     }
@@ -100,6 +125,24 @@ mod tests {
         assert_eq!(manager.stream_name().await, "rustok");
         assert_eq!(manager.domain_topic().await, "domain");
         assert_eq!(manager.system_topic().await, "system");
+        assert_eq!(manager.dead_letter_topic().await, None);
+    }
+
+    #[tokio::test]
+    async fn topology_manager_provisions_dead_letter_topic() {
+        let manager = TopologyManager::new();
+        let mut config = IggyConfig::default();
+        config.topology.dead_letter = Some(crate::config::DeadLetterConfig::default());
+
+        manager
+            .ensure_topology(&config, &MockConnector)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manager.dead_letter_topic().await.as_deref(),
+            Some("domain.dlq")
+        );
     }
 
     struct MockConnector;
@@ -4,11 +4,16 @@ use async_trait::async_trait;
 use tracing::{error, info};
 
 use crate::config::{IggyConfig, IggyMode};
-use crate::consumer::ConsumerGroupManager;
-use crate::producer;
+use crate::consumer::{ConsumerGroupManager, ConsumerOffset, IggyConsumer};
+use crate::dlq::{DlqEntry, DlqManager};
+use crate::partitioning::Partitioner;
+use crate::producer::{self, BatchPublishError};
+use crate::rebuild::ProjectionRebuilder;
+use crate::reconnect::ReconnectingConnector;
+use crate::replay::{ReplayConfig, ReplayError, ReplayManager, ReplayReport};
 use crate::serialization::{EventSerializer, JsonSerializer, PostcardSerializer};
 use crate::topology::TopologyManager;
-use rustok_core::events::{EventTransport, ReliabilityLevel};
+use rustok_core::events::{EventHandler, EventTransport, ReliabilityLevel};
 use rustok_core::Result;
 use rustok_events::EventEnvelope;
 use rustok_iggy_connector::{ConnectorConfig, EmbeddedConnector, IggyConnector, RemoteConnector};
@@ -18,11 +23,18 @@ pub struct IggyTransport {
     connector: Arc<dyn IggyConnector>,
     topology: TopologyManager,
     consumers: ConsumerGroupManager,
+    replays: ReplayManager,
+    partitioner: Partitioner,
     serializer: Arc<dyn EventSerializer>,
 }
 
 impl IggyTransport {
     pub async fn new(config: IggyConfig) -> Result<Self> {
+        config.validate().map_err(|error| {
+            error!(error = %error, "Invalid Iggy configuration");
+            rustok_core::Error::Validation(error.to_string())
+        })?;
+
         let connector: Arc<dyn IggyConnector> = match config.mode {
             IggyMode::Remote => Arc::new(RemoteConnector::new()),
             IggyMode::Embedded => Arc::new(EmbeddedConnector::new()),
@@ -38,6 +50,15 @@ impl IggyTransport {
                 rustok_core::Error::External(error.to_string())
             })?;
 
+        let connector: Arc<dyn IggyConnector> = match config.mode {
+            IggyMode::Remote => Arc::new(ReconnectingConnector::from_remote_config(
+                connector,
+                connector_config,
+                &config.remote,
+            )),
+            IggyMode::Embedded => connector,
+        };
+
         let topology = TopologyManager::new();
         topology
             .ensure_topology(&config, connector.as_ref())
@@ -55,11 +76,15 @@ impl IggyTransport {
             "Iggy transport initialized"
         );
 
+        let partitioner = Partitioner::from_config(&config.topology);
+
         Ok(Self {
             config,
             connector,
             topology,
             consumers: ConsumerGroupManager::new(),
+            replays: ReplayManager::new(),
+            partitioner,
             serializer,
         })
     }
@@ -87,6 +112,17 @@ impl IggyTransport {
         self.consumers.ensure_group(group).await
     }
 
+    /// Consumer for `group` on the domain topic, reading with this
+    /// transport's connection and serialization format.
+    pub async fn consumer(&self, group: &str, offset: ConsumerOffset) -> Result<IggyConsumer> {
+        let consumer =
+            IggyConsumer::new(self.connector.clone(), &self.config, group, offset).await?;
+        self.consumers
+            .ensure_group(consumer.group().clone())
+            .await?;
+        Ok(consumer)
+    }
+
     pub async fn replay(&self) -> Result<()> {
         if !self.topology.is_initialized().await {
             return Err(rustok_core::Error::External(
@@ -97,6 +133,62 @@ impl IggyTransport {
         Ok(())
     }
 
+    /// Re-dispatch `offsets` of every partition of `topic` through `handler`;
+    /// see [`ReplayManager::replay_from`].
+    pub async fn replay_from(
+        &self,
+        topic: &str,
+        offsets: impl std::ops::RangeBounds<u64>,
+        handler: &dyn EventHandler,
+    ) -> std::result::Result<ReplayReport, ReplayError> {
+        let config = ReplayConfig::new(self.config.topology.stream_name.clone(), topic.to_string())
+            .offsets(offsets)
+            .partitions(self.config.topology.domain_partitions);
+
+        self.replays
+            .replay_from(self.connector.as_ref(), &*self.serializer, config, handler)
+            .await
+    }
+
+    pub fn replays(&self) -> &ReplayManager {
+        &self.replays
+    }
+
+    /// Rebuilder bound to this transport's connection and topology.
+    pub fn projection_rebuilder(&self) -> ProjectionRebuilder {
+        ProjectionRebuilder::new(self.connector.clone(), self.config.clone())
+    }
+
+    /// Publish `envelopes` with one send per topic and partition, reporting
+    /// which of them failed. [`EventTransport::publish_batch`] flattens the
+    /// result into a single [`rustok_core::Error`].
+    pub async fn try_publish_batch(
+        &self,
+        envelopes: Vec<EventEnvelope>,
+    ) -> std::result::Result<(), BatchPublishError> {
+        producer::publish_batch(
+            self.connector.as_ref(),
+            &self.config,
+            &self.partitioner,
+            &*self.serializer,
+            envelopes,
+        )
+        .await
+    }
+
+    /// Entries on the configured dead-letter topic, or an empty list when
+    /// dead-lettering is disabled.
+    pub async fn dead_letter_entries(&self) -> Result<Vec<DlqEntry>> {
+        match &self.config.topology.dead_letter {
+            Some(dead_letter) => {
+                DlqManager::from_config(&self.config.topology.stream_name, dead_letter)
+                    .read_entries(self.connector.as_ref())
+                    .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
     pub fn config(&self) -> &IggyConfig {
         &self.config
     }
@@ -109,14 +201,18 @@ impl IggyTransport {
 #[async_trait]
 impl EventTransport for IggyTransport {
     async fn publish(&self, envelope: EventEnvelope) -> Result<()> {
-        let request = producer::build_publish_request(&self.config, &*self.serializer, envelope)?;
-
-        self.connector.publish(request).await.map_err(|error| {
-            error!(error = %error, "Failed to publish event to Iggy");
-            rustok_core::Error::External(error.to_string())
-        })?;
+        producer::publish(
+            self.connector.as_ref(),
+            &self.config,
+            &self.partitioner,
+            &*self.serializer,
+            envelope,
+        )
+        .await
+    }
 
-        Ok(())
+    async fn publish_batch(&self, events: Vec<EventEnvelope>) -> Result<()> {
+        self.try_publish_batch(events).await.map_err(Into::into)
     }
 
     fn reliability_level(&self) -> ReliabilityLevel {
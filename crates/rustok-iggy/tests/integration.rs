@@ -2,7 +2,7 @@
 //!
 //! These tests require a running Iggy backend or use mock implementations.
 
-use rustok_iggy::config::{IggyConfig, IggyMode, SerializationFormat};
+use rustok_iggy::config::{IggyConfig, IggyMode, RemoteConfig, SerializationFormat};
 use rustok_iggy::transport::IggyTransport;
 
 type TestResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
@@ -53,10 +53,26 @@ async fn test_iggy_transport_postcard() -> TestResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_iggy_transport_rejects_invalid_config_before_connecting() {
+    let config = IggyConfig {
+        mode: IggyMode::Remote,
+        remote: RemoteConfig {
+            protocol: "udp".to_string(),
+            ..RemoteConfig::default()
+        },
+        ..IggyConfig::default()
+    };
+
+    let error = IggyTransport::new(config).await.unwrap_err();
+
+    assert!(matches!(error, rustok_core::Error::Validation(_)));
+}
+
 mod config_tests {
     use rustok_iggy::config::{
         EmbeddedConfig, IggyConfig, IggyMode, RemoteConfig, RetentionConfig, SerializationFormat,
-        TopologyConfig,
+        TlsConfig, TopologyConfig,
     };
 
     #[test]
@@ -76,11 +92,13 @@ mod config_tests {
                 username: "admin".to_string(),
                 password: "secret".to_string(),
                 tls_enabled: true,
+                ..RemoteConfig::default()
             },
             topology: TopologyConfig {
                 stream_name: "production".to_string(),
                 domain_partitions: 32,
                 replication_factor: 3,
+                ..TopologyConfig::default()
             },
             retention: RetentionConfig {
                 domain_max_age_days: 90,
@@ -117,6 +135,34 @@ topology:
         assert_eq!(parsed.serialization, SerializationFormat::Json);
         assert_eq!(parsed.topology.stream_name, "test-stream");
         assert_eq!(parsed.topology.domain_partitions, 16);
+        assert!(parsed.remote.tls.is_none());
+    }
+
+    #[test]
+    fn config_yaml_parsing_with_tls() {
+        let yaml = r#"
+mode: remote
+remote:
+  addresses: ["iggy.internal:8090"]
+  protocol: tcp
+  username: rustok
+  tls:
+    ca_cert_path: /etc/iggy/ca.pem
+    client_cert_path: /etc/iggy/client.pem
+    client_key_path: /etc/iggy/client.key
+"#;
+
+        let parsed: IggyConfig = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(
+            parsed.remote.tls,
+            Some(TlsConfig {
+                ca_cert_path: Some("/etc/iggy/ca.pem".to_string()),
+                client_cert_path: Some("/etc/iggy/client.pem".to_string()),
+                client_key_path: Some("/etc/iggy/client.key".to_string()),
+                insecure_skip_verify: false,
+            })
+        );
     }
 
     #[test]
@@ -224,3 +270,83 @@ mod partitioning_tests {
         }
     }
 }
+
+// Without the `iggy` feature the embedded backend keeps published messages in
+// memory, so these run without an `iggy-server` binary.
+#[cfg(not(feature = "iggy"))]
+mod consumer_tests {
+    use rustok_core::events::EventTransport;
+    use rustok_events::{DomainEvent, EventEnvelope};
+    use rustok_iggy::config::IggyConfig;
+    use rustok_iggy::transport::IggyTransport;
+    use rustok_iggy::ConsumerOffset;
+    use uuid::Uuid;
+
+    fn embedded_config(name: &str) -> IggyConfig {
+        let data_dir =
+            std::env::temp_dir().join(format!("rustok-iggy-it-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut config = IggyConfig::default();
+        config.embedded.data_dir = data_dir.to_string_lossy().into_owned();
+        config.embedded.tcp_port = 0;
+        config.embedded.http_port = 0;
+        config
+    }
+
+    fn node_created(tenant_id: Uuid) -> EventEnvelope {
+        EventEnvelope::new(
+            tenant_id,
+            None,
+            DomainEvent::NodeCreated {
+                node_id: Uuid::new_v4(),
+                kind: "post".to_string(),
+                author_id: None,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn consumer_reads_events_published_through_the_transport() {
+        let config = embedded_config("consumer");
+        let transport = IggyTransport::new(config.clone()).await.unwrap();
+
+        let tenant_id = Uuid::new_v4();
+        let first = node_created(tenant_id);
+        let second = node_created(tenant_id);
+        transport.publish(first.clone()).await.unwrap();
+        transport.publish(second.clone()).await.unwrap();
+
+        let mut earliest = transport
+            .consumer("projections", ConsumerOffset::Earliest)
+            .await
+            .unwrap();
+        let mut latest = transport
+            .consumer("live", ConsumerOffset::Latest)
+            .await
+            .unwrap();
+        let mut from_second = transport
+            .consumer("resume", ConsumerOffset::Offset(1))
+            .await
+            .unwrap();
+
+        assert_eq!(earliest.next().await.unwrap().unwrap().id, first.id);
+        assert_eq!(earliest.next().await.unwrap().unwrap().id, second.id);
+        assert!(earliest.next().await.unwrap().is_none());
+
+        assert!(latest.next().await.unwrap().is_none());
+
+        assert_eq!(from_second.next().await.unwrap().unwrap().id, second.id);
+        assert!(from_second.next().await.unwrap().is_none());
+
+        let third = node_created(tenant_id);
+        transport.publish(third.clone()).await.unwrap();
+
+        assert_eq!(earliest.next().await.unwrap().unwrap().id, third.id);
+        assert_eq!(latest.next().await.unwrap().unwrap().id, third.id);
+        assert_eq!(earliest.skipped(), 0);
+
+        transport.shutdown().await.unwrap();
+        let _ = std::fs::remove_dir_all(&config.embedded.data_dir);
+    }
+}
@@ -46,11 +46,15 @@ struct NodeRow {
     author_name: Option<String>,
 }
 
+/// Live table the content indexer writes to.
+pub const INDEX_CONTENT_TABLE: &str = "index_content";
+
 /// Content indexer - listens to events and updates index_content table
 #[derive(Clone)]
 pub struct ContentIndexer {
     db: DatabaseConnection,
     runtime: IndexerRuntimeConfig,
+    table: &'static str,
 }
 
 impl ContentIndexer {
@@ -59,7 +63,18 @@ impl ContentIndexer {
     }
 
     pub fn with_runtime(db: DatabaseConnection, runtime: IndexerRuntimeConfig) -> Self {
-        Self { db, runtime }
+        Self {
+            db,
+            runtime,
+            table: INDEX_CONTENT_TABLE,
+        }
+    }
+
+    /// Write rows into `table` instead of `index_content`, e.g. a rebuild
+    /// shadow created with `LIKE index_content`.
+    pub fn with_table(mut self, table: &'static str) -> Self {
+        self.table = table;
+        self
     }
 
     fn backend(&self) -> DatabaseBackend {
@@ -204,8 +219,9 @@ impl ContentIndexer {
 
         let stmt = Statement::from_sql_and_values(
             self.backend(),
-            r#"
-            INSERT INTO index_content (
+            format!(
+                r#"
+            INSERT INTO {} (
                 id, tenant_id, node_id, locale, kind, status,
                 title, slug, excerpt, body, body_format,
                 author_id, author_name, author_avatar,
@@ -257,6 +273,8 @@ impl ContentIndexer {
                 updated_at = EXCLUDED.updated_at,
                 indexed_at = NOW()
             "#,
+                self.table
+            ),
             vec![
                 model.id.into(),
                 model.tenant_id.into(),
@@ -304,7 +322,7 @@ impl ContentIndexer {
     async fn delete_node_from_index(&self, node_id: Uuid) -> IndexResult<()> {
         let stmt = Statement::from_sql_and_values(
             self.backend(),
-            "DELETE FROM index_content WHERE node_id = $1",
+            format!("DELETE FROM {} WHERE node_id = $1", self.table),
             vec![node_id.into()],
         );
         self.db
@@ -319,7 +337,10 @@ impl ContentIndexer {
     async fn delete_node_locale_from_index(&self, node_id: Uuid, locale: &str) -> IndexResult<()> {
         let stmt = Statement::from_sql_and_values(
             self.backend(),
-            "DELETE FROM index_content WHERE node_id = $1 AND locale = $2",
+            format!(
+                "DELETE FROM {} WHERE node_id = $1 AND locale = $2",
+                self.table
+            ),
             vec![node_id.into(), locale.into()],
         );
         self.db
@@ -487,6 +508,7 @@ impl EventHandler for ContentIndexer {
             | DomainEvent::NodeTranslationUpdated { .. }
             | DomainEvent::NodePublished { .. }
             | DomainEvent::NodeUnpublished { .. }
+            | DomainEvent::NodesPublished { .. }
             | DomainEvent::NodeDeleted { .. }
             | DomainEvent::BodyUpdated { .. }
             | DomainEvent::CategoryUpdated { .. } => true,
@@ -512,6 +534,12 @@ impl EventHandler for ContentIndexer {
                 self.index_one(&ctx, *node_id).await?;
             }
 
+            DomainEvent::NodesPublished { node_ids } => {
+                let _stats =
+                    run_bounded_reindex(self.clone(), &ctx, node_ids.clone(), "nodes_published")
+                        .await;
+            }
+
             DomainEvent::NodeTranslationUpdated { node_id, locale } => {
                 self.index_locale(&ctx, *node_id, locale).await?;
             }
@@ -2,6 +2,6 @@ mod indexer;
 mod model;
 mod query;
 
-pub use indexer::ContentIndexer;
+pub use indexer::{ContentIndexer, INDEX_CONTENT_TABLE};
 pub use model::IndexContentModel;
 pub use query::{ContentQuery, ContentQueryBuilder, ContentQueryService, ContentSortBy, SortOrder};
@@ -17,13 +17,26 @@ pub struct SecretRef {
     pub key: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum SecretValue {
     Plaintext { value: String },
     Reference { reference: SecretRef },
 }
 
+/// Plaintext values (passwords, database URLs) never appear in `Debug` output.
+impl std::fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plaintext { .. } => f.debug_struct("Plaintext").field("value", &"***").finish(),
+            Self::Reference { reference } => f
+                .debug_struct("Reference")
+                .field("reference", reference)
+                .finish(),
+        }
+    }
+}
+
 impl SecretValue {
     pub fn as_plaintext(&self) -> Option<&str> {
         match self {
@@ -81,6 +94,15 @@ mod tests {
         assert!(!redacted.to_string().contains("admin12345"));
     }
 
+    #[test]
+    fn plaintext_secret_is_hidden_from_debug() {
+        let secret = SecretValue::Plaintext {
+            value: "postgres://rustok:hunter2@db/rustok".to_string(),
+        };
+
+        assert_eq!(format!("{secret:?}"), r#"Plaintext { value: "***" }"#);
+    }
+
     #[test]
     fn reference_secret_keeps_reference_identity() {
         let redacted = redact_secret(&SecretValue::Reference {
@@ -9,7 +9,7 @@ pub mod services;
 pub use rustok_commerce_foundation::entities::product::ProductStatus;
 pub use services::{
     check_public_channel_inventory_request, check_variant_availability_for_public_channel,
-    extract_allowed_channel_slugs, inventory_policy_allows_backorder,
+    extract_allowed_channel_slugs, inventory_lock_order, inventory_policy_allows_backorder,
     is_allowlist_visible_for_public_channel, is_metadata_visible_for_public_channel,
     load_available_inventory_by_variant_for_public_channel,
     load_available_inventory_for_variant_in_public_channel,
@@ -19,7 +19,8 @@ pub use services::{
     AdminInventoryProductsFilter, AdminInventoryReadService, AdminInventoryVariant,
     InventoryAvailabilityCheckResult, InventoryQuantityWriteResult,
     InventoryReservationReleaseWriteResult, InventoryReservationWriteResult, InventoryService,
-    PublicChannelInventoryProjection, PublicChannelInventoryVariantProjectionInput,
+    OrderInventoryLine, PublicChannelInventoryProjection,
+    PublicChannelInventoryVariantProjectionInput,
 };
 
 pub struct InventoryModule;
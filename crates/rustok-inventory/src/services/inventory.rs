@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
-    TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -56,6 +58,35 @@ pub struct InventoryReservationReleaseWriteResult {
     pub in_stock: bool,
 }
 
+/// One paid order line to take out of stock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OrderInventoryLine {
+    pub product_id: Uuid,
+    pub variant_id: Uuid,
+    pub quantity: i32,
+}
+
+/// Merge `lines` per variant and sort them by `(product_id, variant_id)`,
+/// the order in which [`InventoryService::commit_order_inventory_in_tx`]
+/// locks inventory rows.
+pub fn inventory_lock_order(lines: &[OrderInventoryLine]) -> Vec<OrderInventoryLine> {
+    let mut merged = BTreeMap::<(Uuid, Uuid), i32>::new();
+    for line in lines {
+        *merged
+            .entry((line.product_id, line.variant_id))
+            .or_default() += line.quantity;
+    }
+
+    merged
+        .into_iter()
+        .map(|((product_id, variant_id), quantity)| OrderInventoryLine {
+            product_id,
+            variant_id,
+            quantity,
+        })
+        .collect()
+}
+
 impl InventoryQuantityWriteResult {
     #[cfg(test)]
     fn from_quantity(quantity: i32) -> Self {
@@ -201,38 +232,16 @@ impl InventoryService {
         level_active.updated_at = Set(Utc::now().into());
         level_active.update(&txn).await?;
 
-        // Create and validate event
-        let event = DomainEvent::InventoryUpdated {
-            variant_id: input.variant_id,
-            product_id: variant.product_id,
-            location_id: state.location.id,
+        self.publish_stock_decrease_events(
+            &txn,
+            tenant_id,
+            actor_id,
+            &variant,
+            state.location.id,
             old_quantity,
             new_quantity,
-        };
-        event
-            .validate()
-            .map_err(|e| CommerceError::Validation(format!("Invalid inventory event: {}", e)))?;
-
-        self.event_bus
-            .publish_in_tx(&txn, tenant_id, Some(actor_id), event)
-            .await?;
-
-        if new_quantity < self.low_stock_threshold && new_quantity > 0 {
-            // Create and validate low inventory event
-            let low_event = DomainEvent::InventoryLow {
-                variant_id: input.variant_id,
-                product_id: variant.product_id,
-                remaining: new_quantity,
-                threshold: self.low_stock_threshold,
-            };
-            low_event.validate().map_err(|e| {
-                CommerceError::Validation(format!("Invalid low inventory event: {}", e))
-            })?;
-
-            self.event_bus
-                .publish_in_tx(&txn, tenant_id, Some(actor_id), low_event)
-                .await?;
-        }
+        )
+        .await?;
 
         let inventory_policy = variant.inventory_policy.clone();
 
@@ -532,6 +541,129 @@ impl InventoryService {
         ))
     }
 
+    /// Take paid order lines out of stock inside the caller's transaction.
+    ///
+    /// Lines are processed in [`inventory_lock_order`] and every inventory
+    /// level of a line is locked with `SELECT ... FOR UPDATE` before it is
+    /// read, so two orders sharing products always lock them in the same
+    /// sequence and cannot deadlock each other. Callers that also update the
+    /// order row must do so after this call, so the order row is locked last.
+    pub async fn commit_order_inventory_in_tx<C>(
+        &self,
+        txn: &C,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        lines: &[OrderInventoryLine],
+    ) -> CommerceResult<()>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        for line in inventory_lock_order(lines) {
+            if line.quantity <= 0 {
+                continue;
+            }
+
+            let variant = self.load_variant(txn, tenant_id, line.variant_id).await?;
+            let state = self
+                .ensure_inventory_state(txn, tenant_id, &variant)
+                .await?;
+            let levels = entities::inventory_level::Entity::find()
+                .filter(
+                    entities::inventory_level::Column::InventoryItemId.eq(state.inventory_item.id),
+                )
+                .order_by_asc(entities::inventory_level::Column::Id)
+                .lock_exclusive()
+                .all(txn)
+                .await?;
+            let old_quantity: i32 = levels
+                .iter()
+                .map(|level| level.stocked_quantity - level.reserved_quantity)
+                .sum();
+            let new_quantity = old_quantity - line.quantity;
+            if new_quantity < 0 && !inventory_policy_allows_backorder(&variant.inventory_policy) {
+                return Err(CommerceError::InsufficientInventory {
+                    requested: line.quantity,
+                    available: old_quantity,
+                });
+            }
+
+            let level = levels
+                .into_iter()
+                .find(|level| level.id == state.level.id)
+                .unwrap_or(state.level);
+            let stocked_quantity = level.stocked_quantity - line.quantity;
+            let mut level_active: entities::inventory_level::ActiveModel = level.into();
+            level_active.stocked_quantity = Set(stocked_quantity);
+            level_active.updated_at = Set(Utc::now().into());
+            level_active.update(txn).await?;
+
+            self.publish_stock_decrease_events(
+                txn,
+                tenant_id,
+                actor_id,
+                &variant,
+                state.location.id,
+                old_quantity,
+                new_quantity,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Publish `InventoryUpdated` and, when stock drops under the threshold,
+    /// `InventoryLow`.
+    #[allow(clippy::too_many_arguments)]
+    async fn publish_stock_decrease_events<C>(
+        &self,
+        txn: &C,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        variant: &entities::product_variant::Model,
+        location_id: Uuid,
+        old_quantity: i32,
+        new_quantity: i32,
+    ) -> CommerceResult<()>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        // Create and validate event
+        let event = DomainEvent::InventoryUpdated {
+            variant_id: variant.id,
+            product_id: variant.product_id,
+            location_id,
+            old_quantity,
+            new_quantity,
+        };
+        event
+            .validate()
+            .map_err(|e| CommerceError::Validation(format!("Invalid inventory event: {}", e)))?;
+
+        self.event_bus
+            .publish_in_tx(txn, tenant_id, Some(actor_id), event)
+            .await?;
+
+        if new_quantity < self.low_stock_threshold && new_quantity > 0 {
+            // Create and validate low inventory event
+            let low_event = DomainEvent::InventoryLow {
+                variant_id: variant.id,
+                product_id: variant.product_id,
+                remaining: new_quantity,
+                threshold: self.low_stock_threshold,
+            };
+            low_event.validate().map_err(|e| {
+                CommerceError::Validation(format!("Invalid low inventory event: {}", e))
+            })?;
+
+            self.event_bus
+                .publish_in_tx(txn, tenant_id, Some(actor_id), low_event)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn load_variant<C>(
         &self,
         conn: &C,
@@ -815,11 +947,32 @@ fn validate_availability_request_quantity(requested_quantity: i32) -> CommerceRe
 mod tests {
     use super::{
         insufficient_reservation_items_release_error, insufficient_reserved_release_error,
-        stocked_quantity_for_available, validate_availability_request_quantity,
-        validate_release_quantity, validate_reservation_quantity, InventoryAvailabilityCheckResult,
+        inventory_lock_order, stocked_quantity_for_available,
+        validate_availability_request_quantity, validate_release_quantity,
+        validate_reservation_quantity, InventoryAvailabilityCheckResult,
         InventoryQuantityWriteResult, InventoryReservationReleaseWriteResult,
-        InventoryReservationWriteResult,
+        InventoryReservationWriteResult, OrderInventoryLine,
     };
+    use uuid::Uuid;
+
+    #[test]
+    fn lock_order_is_independent_of_line_order() {
+        let (low, high) = {
+            let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+            (a.min(b), a.max(b))
+        };
+        let line = |product_id: Uuid, quantity: i32| OrderInventoryLine {
+            product_id,
+            variant_id: product_id,
+            quantity,
+        };
+
+        let first = inventory_lock_order(&[line(high, 1), line(low, 2)]);
+        let second = inventory_lock_order(&[line(low, 2), line(high, 1), line(high, 3)]);
+
+        assert_eq!(first, vec![line(low, 2), line(high, 1)]);
+        assert_eq!(second, vec![line(low, 2), line(high, 4)]);
+    }
 
     #[test]
     fn set_quantity_preserves_reserved_units_when_targeting_available_quantity() {
@@ -4,8 +4,9 @@ mod policy;
 pub mod public_channel;
 
 pub use inventory::{
-    InventoryAvailabilityCheckResult, InventoryQuantityWriteResult,
+    inventory_lock_order, InventoryAvailabilityCheckResult, InventoryQuantityWriteResult,
     InventoryReservationReleaseWriteResult, InventoryReservationWriteResult, InventoryService,
+    OrderInventoryLine,
 };
 pub use policy::inventory_policy_allows_backorder;
 pub use public_channel::{
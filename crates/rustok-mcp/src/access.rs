@@ -11,9 +11,9 @@ use crate::alloy_tools::{
     TOOL_ALLOY_SCRIPT_HELPERS, TOOL_ALLOY_UPDATE_SCRIPT, TOOL_ALLOY_VALIDATE_SCRIPT,
 };
 use crate::tools::{
-    TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE, TOOL_FORUM_MODULE, TOOL_LIST_MODULES, TOOL_MCP_HEALTH,
-    TOOL_MCP_WHOAMI, TOOL_MODULE_DETAILS, TOOL_MODULE_EXISTS, TOOL_PAGES_MODULE,
-    TOOL_QUERY_MODULES,
+    TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE, TOOL_FORUM_MODULE, TOOL_LIST_MODULES,
+    TOOL_LIST_PERMISSIONS, TOOL_MCP_HEALTH, TOOL_MCP_WHOAMI, TOOL_MODULE_DETAILS,
+    TOOL_MODULE_EXISTS, TOOL_PAGES_MODULE, TOOL_QUERY_MODULES,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -233,6 +233,7 @@ pub fn default_tool_requirement(tool_name: &str) -> McpToolRequirement {
         TOOL_LIST_MODULES | TOOL_QUERY_MODULES => vec![Permission::MODULES_LIST.to_string()],
         TOOL_MODULE_EXISTS | TOOL_MODULE_DETAILS | TOOL_CONTENT_MODULE | TOOL_BLOG_MODULE
         | TOOL_FORUM_MODULE | TOOL_PAGES_MODULE => vec![Permission::MODULES_READ.to_string()],
+        TOOL_LIST_PERMISSIONS => vec![Permission::SETTINGS_READ.to_string()],
         TOOL_ALLOY_LIST_SCRIPTS => vec![Permission::SCRIPTS_LIST.to_string()],
         TOOL_ALLOY_GET_SCRIPT
         | TOOL_ALLOY_LIST_ENTITY_TYPES
@@ -35,9 +35,10 @@ pub use runtime::{
 pub use server::{serve_stdio, McpServerConfig, RusToKMcpServer};
 pub use tools::{
     McpHealthResponse, McpState, McpToolError, McpToolResponse, ModuleDetailsResponse, ModuleInfo,
-    ModuleListResponse, ModuleLookupRequest, ModuleLookupResponse, ModuleQueryRequest, MODULE_BLOG,
-    MODULE_CONTENT, MODULE_FORUM, MODULE_PAGES, TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE,
-    TOOL_FORUM_MODULE, TOOL_LIST_MODULES, TOOL_MCP_HEALTH, TOOL_MCP_WHOAMI, TOOL_MODULE_DETAILS,
+    ModuleListResponse, ModuleLookupRequest, ModuleLookupResponse, ModuleQueryRequest, ModuleSort,
+    PermissionCatalogResponse, RolePermissionsInfo, MODULE_BLOG, MODULE_CONTENT, MODULE_FORUM,
+    MODULE_PAGES, TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE, TOOL_FORUM_MODULE, TOOL_LIST_MODULES,
+    TOOL_LIST_PERMISSIONS, TOOL_MCP_HEALTH, TOOL_MCP_WHOAMI, TOOL_MODULE_DETAILS,
     TOOL_MODULE_EXISTS, TOOL_PAGES_MODULE, TOOL_QUERY_MODULES,
 };
 
@@ -28,12 +28,12 @@ use crate::runtime::{
     SharedMcpAccessResolver, SharedMcpAuditSink,
 };
 use crate::tools::{
-    list_modules, list_modules_filtered, module_details, module_details_by_slug, module_exists,
-    McpHealthResponse, McpState, McpToolResponse, ModuleDetailsResponse, ModuleListResponse,
-    ModuleLookupRequest, ModuleLookupResponse, ModuleQueryRequest, MODULE_BLOG, MODULE_CONTENT,
-    MODULE_FORUM, MODULE_PAGES, TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE, TOOL_FORUM_MODULE,
-    TOOL_LIST_MODULES, TOOL_MCP_HEALTH, TOOL_MCP_WHOAMI, TOOL_MODULE_DETAILS, TOOL_MODULE_EXISTS,
-    TOOL_PAGES_MODULE, TOOL_QUERY_MODULES,
+    list_modules, list_modules_filtered, list_permissions, module_details, module_details_by_slug,
+    module_exists, McpHealthResponse, McpState, McpToolResponse, ModuleDetailsResponse,
+    ModuleListResponse, ModuleLookupRequest, ModuleLookupResponse, ModuleQueryRequest, MODULE_BLOG,
+    MODULE_CONTENT, MODULE_FORUM, MODULE_PAGES, TOOL_BLOG_MODULE, TOOL_CONTENT_MODULE,
+    TOOL_FORUM_MODULE, TOOL_LIST_MODULES, TOOL_LIST_PERMISSIONS, TOOL_MCP_HEALTH, TOOL_MCP_WHOAMI,
+    TOOL_MODULE_DETAILS, TOOL_MODULE_EXISTS, TOOL_PAGES_MODULE, TOOL_QUERY_MODULES,
 };
 use alloy::storage::ScriptRegistry;
 
@@ -325,6 +325,7 @@ impl<R: ScriptRegistry + 'static> RusToKMcpServer<R> {
             TOOL_BLOG_MODULE,
             TOOL_FORUM_MODULE,
             TOOL_PAGES_MODULE,
+            TOOL_LIST_PERMISSIONS,
             TOOL_MCP_HEALTH,
             TOOL_MCP_WHOAMI,
         ];
@@ -521,6 +522,13 @@ impl<R: ScriptRegistry + Send + Sync + 'static> ServerHandler for RusToKMcpServe
                     content,
                 )]))
             }
+            TOOL_LIST_PERMISSIONS => {
+                let result = list_permissions();
+                let content = Self::serialize_response(McpToolResponse::success(result))?;
+                Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+                    content,
+                )]))
+            }
             TOOL_MCP_HEALTH => {
                 let tool_count = self.available_tool_names().len();
                 let result = self.health_response(tool_count);
@@ -834,7 +842,7 @@ impl<R: ScriptRegistry + Send + Sync + 'static> ServerHandler for RusToKMcpServe
             ),
             Tool::new(
                 TOOL_QUERY_MODULES,
-                "List modules with filters and pagination",
+                "List modules with filters (slug prefix, dependency, name substring), sorting and pagination; returns total and next_offset",
                 module_query_schema,
             ),
             Tool::new(
@@ -867,6 +875,11 @@ impl<R: ScriptRegistry + Send + Sync + 'static> ServerHandler for RusToKMcpServe
                 "Fetch pages module metadata",
                 empty_schema.clone(),
             ),
+            Tool::new(
+                TOOL_LIST_PERMISSIONS,
+                "List every RusToK permission and the permissions granted by each platform role",
+                empty_schema.clone(),
+            ),
             Tool::new(
                 TOOL_MCP_HEALTH,
                 "MCP readiness and configuration status",
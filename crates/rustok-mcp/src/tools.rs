@@ -1,5 +1,10 @@
+use std::cmp::Ordering;
+
 use rustok_core::module::RusToKModule;
+use rustok_core::permissions::{all_permissions, Permission};
+use rustok_core::rbac::permissions_for_role;
 use rustok_core::registry::ModuleRegistry;
+use rustok_core::types::UserRole;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +18,7 @@ pub const TOOL_FORUM_MODULE: &str = "forum_module";
 pub const TOOL_PAGES_MODULE: &str = "pages_module";
 pub const TOOL_MCP_HEALTH: &str = "mcp_health";
 pub const TOOL_MCP_WHOAMI: &str = "mcp_whoami";
+pub const TOOL_LIST_PERMISSIONS: &str = "list_permissions";
 
 pub const MODULE_CONTENT: &str = "content";
 pub const MODULE_BLOG: &str = "blog";
@@ -45,6 +51,10 @@ pub struct ModuleInfo {
 pub struct ModuleListResponse {
     /// List of available modules
     pub modules: Vec<ModuleInfo>,
+    /// Number of modules matching the filters, before pagination
+    pub total: usize,
+    /// Offset of the next page, absent on the last page
+    pub next_offset: Option<usize>,
 }
 
 /// Request to check if a module exists
@@ -54,6 +64,19 @@ pub struct ModuleLookupRequest {
     pub slug: String,
 }
 
+/// Sort order for module queries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleSort {
+    /// Alphabetical by slug
+    #[default]
+    Slug,
+    /// Alphabetical by name, then slug
+    Name,
+    /// Ascending version (numeric per dot-separated segment), then slug
+    Version,
+}
+
 /// Request to filter and page through modules
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ModuleQueryRequest {
@@ -61,6 +84,12 @@ pub struct ModuleQueryRequest {
     pub slug_prefix: Option<String>,
     /// Optional dependency filter
     pub dependency: Option<String>,
+    /// Optional case-insensitive substring filter on the module name
+    #[serde(default)]
+    pub name_contains: Option<String>,
+    /// Sort order, by slug when omitted
+    #[serde(default)]
+    pub sort: Option<ModuleSort>,
     /// Max number of items to return
     pub limit: Option<usize>,
     /// Offset into the module list
@@ -122,6 +151,25 @@ pub struct McpHealthResponse {
     pub identity: Option<crate::access::McpIdentity>,
 }
 
+/// Permissions granted by one platform role
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RolePermissionsInfo {
+    /// Role slug (`super_admin`, `admin`, `manager`, `customer`)
+    pub role: String,
+    /// Granted `resource:action` permissions; `resource:manage` implies every
+    /// other action on that resource
+    pub permissions: Vec<String>,
+}
+
+/// Response containing the permission catalog and the role mapping
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PermissionCatalogResponse {
+    /// Every `resource:action` pair the platform defines
+    pub permissions: Vec<String>,
+    /// Static permission mapping of every platform role
+    pub roles: Vec<RolePermissionsInfo>,
+}
+
 impl<T> McpToolResponse<T> {
     pub fn success(data: T) -> Self {
         Self {
@@ -164,9 +212,13 @@ pub async fn list_modules(state: &McpState) -> ModuleListResponse {
         .list()
         .into_iter()
         .map(to_module_info)
-        .collect();
+        .collect::<Vec<_>>();
 
-    ModuleListResponse { modules }
+    ModuleListResponse {
+        total: modules.len(),
+        modules,
+        next_offset: None,
+    }
 }
 
 /// List registered modules with filtering and pagination
@@ -174,31 +226,83 @@ pub async fn list_modules_filtered(
     state: &McpState,
     request: ModuleQueryRequest,
 ) -> ModuleListResponse {
-    let modules = state.registry.list();
-    let filtered = modules.into_iter().filter(|module| {
-        let slug = module.slug();
-        if let Some(prefix) = request.slug_prefix.as_deref() {
-            if !slug.starts_with(prefix) {
-                return false;
+    let name_contains = request
+        .name_contains
+        .as_deref()
+        .map(str::to_lowercase)
+        .filter(|needle| !needle.is_empty());
+    let mut modules: Vec<_> = state
+        .registry
+        .list()
+        .into_iter()
+        .filter(|module| {
+            if let Some(prefix) = request.slug_prefix.as_deref() {
+                if !module.slug().starts_with(prefix) {
+                    return false;
+                }
             }
-        }
-        if let Some(dependency) = request.dependency.as_deref() {
-            if !module.dependencies().iter().any(|dep| dep == &dependency) {
-                return false;
+            if let Some(dependency) = request.dependency.as_deref() {
+                if !module.dependencies().iter().any(|dep| dep == &dependency) {
+                    return false;
+                }
+            }
+            if let Some(needle) = name_contains.as_deref() {
+                if !module.name().to_lowercase().contains(needle) {
+                    return false;
+                }
             }
+            true
+        })
+        .collect();
+
+    // `list()` is ordered by slug and the sort is stable, so slug breaks ties.
+    match request.sort.unwrap_or_default() {
+        ModuleSort::Slug => {}
+        ModuleSort::Name => modules.sort_by(|left, right| left.name().cmp(right.name())),
+        ModuleSort::Version => {
+            modules.sort_by(|left, right| compare_versions(left.version(), right.version()))
         }
-        true
-    });
+    }
 
+    let total = modules.len();
     let offset = request.offset.unwrap_or(0);
     let limit = request.limit.unwrap_or(usize::MAX);
-    let modules = filtered
+    let end = offset.saturating_add(limit);
+    let modules = modules
+        .into_iter()
         .skip(offset)
         .take(limit)
         .map(to_module_info)
         .collect();
 
-    ModuleListResponse { modules }
+    ModuleListResponse {
+        modules,
+        total,
+        next_offset: (end < total).then_some(end),
+    }
+}
+
+/// Compare dot-separated versions numerically where both segments are
+/// numbers, so `0.10.0` sorts after `0.9.0`.
+fn compare_versions(left: &str, right: &str) -> Ordering {
+    let mut left_segments = left.split('.');
+    let mut right_segments = right.split('.');
+    loop {
+        match (left_segments.next(), right_segments.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(left), Some(right)) => {
+                let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+                    (Ok(left), Ok(right)) => left.cmp(&right),
+                    _ => left.cmp(right),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
 }
 
 /// Check if a module exists by slug
@@ -228,3 +332,24 @@ pub fn module_details_by_slug(state: &McpState, slug: &str) -> ModuleDetailsResp
         module,
     }
 }
+
+/// List every permission and the permissions granted by each role
+pub fn list_permissions() -> PermissionCatalogResponse {
+    let render = |permissions: Vec<Permission>| {
+        permissions
+            .iter()
+            .map(Permission::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    PermissionCatalogResponse {
+        permissions: render(all_permissions()),
+        roles: UserRole::ALL
+            .into_iter()
+            .map(|role| RolePermissionsInfo {
+                role: role.to_string(),
+                permissions: render(permissions_for_role(role)),
+            })
+            .collect(),
+    }
+}
@@ -4,12 +4,13 @@ use sea_orm_migration::MigrationTrait;
 use rustok_core::module::{MigrationSource, RusToKModule};
 use rustok_core::registry::ModuleRegistry;
 use rustok_mcp::tools::{
-    list_modules, list_modules_filtered, module_details, module_exists, McpState, McpToolResponse,
-    ModuleLookupRequest, ModuleQueryRequest,
+    list_modules, list_modules_filtered, list_permissions, module_details, module_exists, McpState,
+    McpToolResponse, ModuleLookupRequest, ModuleQueryRequest, ModuleSort,
 };
 
 struct DemoModule;
 struct ExtraModule;
+struct ZetaModule;
 
 impl MigrationSource for DemoModule {
     fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
@@ -23,6 +24,12 @@ impl MigrationSource for ExtraModule {
     }
 }
 
+impl MigrationSource for ZetaModule {
+    fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
+        Vec::new()
+    }
+}
+
 #[async_trait]
 impl RusToKModule for DemoModule {
     fn slug(&self) -> &'static str {
@@ -69,6 +76,29 @@ impl RusToKModule for ExtraModule {
     }
 }
 
+#[async_trait]
+impl RusToKModule for ZetaModule {
+    fn slug(&self) -> &'static str {
+        "alpha"
+    }
+
+    fn name(&self) -> &'static str {
+        "Zeta"
+    }
+
+    fn description(&self) -> &'static str {
+        "Module whose name and version sort differently from its slug"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.10.0"
+    }
+
+    fn dependencies(&self) -> &[&'static str] {
+        &[]
+    }
+}
+
 #[tokio::test]
 async fn list_modules_returns_registered_metadata() {
     let registry = ModuleRegistry::new().register(DemoModule);
@@ -172,6 +202,8 @@ async fn list_modules_filtered_applies_filters_and_pagination() {
         ModuleQueryRequest {
             slug_prefix: Some("d".to_string()),
             dependency: Some("core".to_string()),
+            name_contains: None,
+            sort: None,
             limit: Some(1),
             offset: Some(0),
         },
@@ -194,6 +226,8 @@ async fn list_modules_filtered_paginates_results() {
         ModuleQueryRequest {
             slug_prefix: None,
             dependency: None,
+            name_contains: None,
+            sort: None,
             limit: Some(1),
             offset: Some(1),
         },
@@ -203,3 +237,93 @@ async fn list_modules_filtered_paginates_results() {
     assert_eq!(response.modules.len(), 1);
     assert_eq!(response.modules[0].slug, "extra");
 }
+
+#[test]
+fn list_permissions_returns_catalog_and_role_mapping() {
+    let response = list_permissions();
+
+    assert!(response.permissions.contains(&"users:create".to_string()));
+    assert!(response
+        .permissions
+        .contains(&"ai:tasks:text:run".to_string()));
+
+    let roles: Vec<_> = response
+        .roles
+        .iter()
+        .map(|role| role.role.as_str())
+        .collect();
+    assert_eq!(roles, vec!["super_admin", "admin", "manager", "customer"]);
+    for role in &response.roles {
+        assert!(role
+            .permissions
+            .iter()
+            .all(|permission| response.permissions.contains(permission)));
+    }
+}
+
+#[tokio::test]
+async fn list_modules_filtered_reports_total_and_next_offset() {
+    let registry = ModuleRegistry::new()
+        .register(DemoModule)
+        .register(ExtraModule)
+        .register(ZetaModule);
+    let state = Box::leak(Box::new(McpState { registry }));
+    let page = |offset| ModuleQueryRequest {
+        slug_prefix: None,
+        dependency: None,
+        name_contains: None,
+        sort: None,
+        limit: Some(2),
+        offset: Some(offset),
+    };
+
+    let first = list_modules_filtered(state, page(0)).await;
+    assert_eq!(first.total, 3);
+    assert_eq!(first.next_offset, Some(2));
+
+    let last = list_modules_filtered(state, page(2)).await;
+    assert_eq!(last.total, 3);
+    assert_eq!(last.modules.len(), 1);
+    assert_eq!(last.next_offset, None);
+}
+
+#[tokio::test]
+async fn list_modules_filtered_sorts_and_filters_by_name() {
+    let registry = ModuleRegistry::new()
+        .register(DemoModule)
+        .register(ExtraModule)
+        .register(ZetaModule);
+    let state = Box::leak(Box::new(McpState { registry }));
+    let query = |name_contains: Option<&str>, sort| ModuleQueryRequest {
+        slug_prefix: None,
+        dependency: None,
+        name_contains: name_contains.map(str::to_string),
+        sort,
+        limit: None,
+        offset: None,
+    };
+    let slugs = |response: rustok_mcp::ModuleListResponse| {
+        response
+            .modules
+            .into_iter()
+            .map(|module| module.slug)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        slugs(list_modules_filtered(state, query(None, None)).await),
+        vec!["alpha", "demo", "extra"]
+    );
+    assert_eq!(
+        slugs(list_modules_filtered(state, query(None, Some(ModuleSort::Name))).await),
+        vec!["demo", "extra", "alpha"]
+    );
+    assert_eq!(
+        slugs(list_modules_filtered(state, query(None, Some(ModuleSort::Version))).await),
+        vec!["demo", "extra", "alpha"]
+    );
+
+    let filtered = list_modules_filtered(state, query(Some("ZET"), None)).await;
+    assert_eq!(filtered.total, 1);
+    assert_eq!(slugs(filtered), vec!["alpha"]);
+}
@@ -599,13 +599,14 @@ async fn media_library_native(page: i32, per_page: i32) -> Result<MediaListPaylo
         let service = rustok_media::MediaService::new(app_ctx.db.clone(), storage);
         let limit = per_page.clamp(1, 100) as u64;
         let offset = (page.max(1) - 1) as u64 * limit;
-        let (items, total) = service
+        let media = service
             .list(tenant.id, limit, offset)
             .await
             .map_err(ServerFnError::new)?;
 
         Ok(MediaListPayload {
-            items: items
+            items: media
+                .items
                 .into_iter()
                 .map(|item| MediaListItem {
                     id: item.id.to_string(),
@@ -622,7 +623,7 @@ async fn media_library_native(page: i32, per_page: i32) -> Result<MediaListPaylo
                     created_at: item.created_at.to_rfc3339(),
                 })
                 .collect(),
-            total,
+            total: media.total.unwrap_or_default(),
         })
     }
     #[cfg(not(feature = "ssr"))]
@@ -115,12 +115,15 @@ pub async fn list(
     let storage = storage_from_ctx(&ctx)?;
     let service = MediaService::new(ctx.db.clone(), storage);
     let limit = params.limit.clamp(1, 100);
-    let (items, total) = service
+    let media = service
         .list(tenant.id, limit, params.offset)
         .await
         .map_err(media_error)?;
 
-    Ok(Json(MediaListResponse { items, total }))
+    Ok(Json(MediaListResponse {
+        items: media.items,
+        total: media.total.unwrap_or_default(),
+    }))
 }
 
 /// Get a single media asset by ID.
@@ -26,14 +26,14 @@ impl MediaQuery {
 
         let service = MediaService::new(db.clone(), storage.clone());
         let (offset, limit) = pagination.normalize()?;
-        let (items, total) = service
+        let media = service
             .list(tenant_id, limit as u64, offset as u64)
             .await
             .map_err(|error| async_graphql::Error::new(error.to_string()))?;
 
         Ok(GqlMediaList {
-            items: items.into_iter().map(Into::into).collect(),
-            total: total as i64,
+            items: media.items.into_iter().map(Into::into).collect(),
+            total: media.total.unwrap_or_default() as i64,
         })
     }
 
@@ -1,11 +1,10 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
 };
 use uuid::Uuid;
 
-use rustok_core::generate_id;
+use rustok_core::{fetch_with_total, generate_id, Page};
 use rustok_storage::StorageService;
 
 use crate::{
@@ -99,20 +98,17 @@ impl MediaService {
         Ok(self.to_item(model))
     }
 
-    pub async fn list(
-        &self,
-        tenant_id: Uuid,
-        limit: u64,
-        offset: u64,
-    ) -> Result<(Vec<MediaItem>, u64)> {
+    pub async fn list(&self, tenant_id: Uuid, limit: u64, offset: u64) -> Result<Page<MediaItem>> {
         let query = MediaEntity::find()
             .filter(MediaCol::TenantId.eq(tenant_id))
             .order_by_desc(MediaCol::CreatedAt);
 
-        let total = query.clone().count(&self.db).await?;
-        let items: Vec<crate::entities::media::Model> =
-            query.limit(limit).offset(offset).all(&self.db).await?;
-        Ok((items.into_iter().map(|m| self.to_item(m)).collect(), total))
+        let (items, total) = fetch_with_total(&self.db, query, offset, limit).await?;
+        Ok(Page::offset(
+            items.into_iter().map(|m| self.to_item(m)).collect(),
+            total,
+            offset,
+        ))
     }
 
     // ── Delete ────────────────────────────────────────────────────────────────
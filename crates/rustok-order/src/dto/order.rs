@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use rustok_core::Page;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use utoipa::ToSchema;
@@ -71,8 +72,51 @@ pub struct ListOrdersInput {
     pub per_page: u64,
     pub status: Option<String>,
     pub customer_id: Option<Uuid>,
+    /// Case-insensitive substring of the order number.
+    #[serde(default)]
+    pub order_number: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct CustomerOrderHistoryFilter {
+    /// Only return orders in one of these statuses; empty means all statuses.
+    #[serde(default)]
+    pub statuses: Vec<String>,
+    /// Page size, clamped to `1..=100`; defaults to 20.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerOrderItemSummary {
+    pub line_item_id: Uuid,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub title: String,
+    pub quantity: i32,
+    pub total_price: Decimal,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerOrderSummary {
+    pub id: Uuid,
+    pub order_number: Option<String>,
+    pub status: String,
+    pub currency_code: String,
+    pub total_amount: Decimal,
+    pub item_count: i64,
+    pub items: Vec<CustomerOrderItemSummary>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+/// Keyset-paginated history; `total` is always `None` and `next_cursor` is
+/// `None` on the last page.
+pub type CustomerOrderHistoryPage = Page<CustomerOrderSummary>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct MarkPaidOrderInput {
     #[validate(length(min = 1, max = 191))]
@@ -181,6 +225,7 @@ pub struct ListOrderReturnsInput {
 pub struct OrderResponse {
     pub id: Uuid,
     pub tenant_id: Uuid,
+    pub order_number: Option<String>,
     pub channel_id: Option<Uuid>,
     pub channel_slug: Option<String>,
     pub customer_id: Option<Uuid>,
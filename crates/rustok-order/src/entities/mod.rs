@@ -6,3 +6,4 @@ pub mod order_line_item_translation;
 pub mod order_return;
 pub mod order_return_item;
 pub mod order_tax_line;
+pub mod sequence;
@@ -8,6 +8,9 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub tenant_id: Uuid,
+    /// Human-friendly number from [`crate::OrderNumberGenerator`]; unique per
+    /// tenant and `None` only for orders created before numbering existed.
+    pub order_number: Option<String>,
     pub channel_id: Option<Uuid>,
     pub channel_slug: Option<String>,
     pub customer_id: Option<Uuid>,
@@ -14,6 +14,8 @@ pub enum OrderError {
     OrderReturnNotFound(Uuid),
     #[error("order change {0} not found")]
     OrderChangeNotFound(Uuid),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
     #[error("invalid order status transition: {from} -> {to}")]
     InvalidTransition { from: String, to: String },
     #[error(transparent)]
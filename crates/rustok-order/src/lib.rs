@@ -8,11 +8,18 @@ pub mod entities;
 pub mod error;
 pub mod migrations;
 pub mod services;
+pub mod totals;
 
 pub use dto::*;
 pub use entities::*;
 pub use error::{OrderError, OrderResult};
-pub use services::OrderService;
+pub use services::{
+    OrderNumberGenerator, OrderService, DEFAULT_ORDER_NUMBER_TEMPLATE, ORDER_NUMBER_SEQUENCE,
+    ORDER_NUMBER_TEMPLATE_SETTING,
+};
+pub use totals::{
+    calculate_order_totals, OrderTotals, TotalsAmount, TotalsLineItem, TotalsTax, MONEY_SCALE,
+};
 
 pub struct OrderModule;
 
@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Sequences::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Sequences::TenantId).uuid().not_null())
+                    .col(ColumnDef::new(Sequences::Name).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(Sequences::LastValue)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Sequences::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(Sequences::TenantId)
+                            .col(Sequences::Name),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing orders are not backfilled: they keep a NULL order_number,
+        // which the unique (tenant_id, order_number) index allows.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(ColumnDef::new(Orders::OrderNumber).string_len(64).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_tenant_order_number")
+                    .table(Orders::Table)
+                    .col(Orders::TenantId)
+                    .col(Orders::OrderNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_orders_tenant_order_number")
+                    .table(Orders::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .drop_column(Orders::OrderNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Sequences::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Sequences {
+    Table,
+    TenantId,
+    Name,
+    LastValue,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    TenantId,
+    OrderNumber,
+}
@@ -11,6 +11,7 @@ mod m20260524_000110_create_order_returns_table;
 mod m20260529_000111_create_order_return_items_table;
 mod m20260529_000112_create_order_changes_table;
 mod m20260530_000113_add_order_return_resolution_columns;
+mod m20260603_000114_add_order_numbers;
 
 use sea_orm_migration::MigrationTrait;
 
@@ -29,5 +30,6 @@ pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260529_000111_create_order_return_items_table::Migration),
         Box::new(m20260529_000112_create_order_changes_table::Migration),
         Box::new(m20260530_000113_add_order_return_resolution_columns::Migration),
+        Box::new(m20260603_000114_add_order_numbers::Migration),
     ]
 }
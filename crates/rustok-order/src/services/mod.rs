@@ -1,3 +1,8 @@
 pub mod order;
+pub mod order_number;
 
 pub use order::OrderService;
+pub use order_number::{
+    tenant_order_number_generator, OrderNumberGenerator, DEFAULT_ORDER_NUMBER_TEMPLATE,
+    ORDER_NUMBER_SEQUENCE, ORDER_NUMBER_TEMPLATE_SETTING,
+};
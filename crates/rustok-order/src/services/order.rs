@@ -2,9 +2,10 @@ use chrono::Utc;
 use flex::{persist_localized_values, prepare_attached_values_create, resolve_attached_payload};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use sea_orm::sea_query::{Alias, Expr, Func, LikeExpr, Query, SimpleExpr};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
@@ -13,7 +14,10 @@ use uuid::Uuid;
 use validator::Validate;
 
 use rustok_core::field_schema::{CustomFieldsSchema, FieldDefinition, FieldType, ValidationRule};
-use rustok_core::{generate_id, normalize_locale_tag, PLATFORM_FALLBACK_LOCALE};
+use rustok_core::{
+    fetch_with_total, generate_id, normalize_locale_tag, Action, Page, PermissionScope, Resource,
+    SecurityContext, PLATFORM_FALLBACK_LOCALE,
+};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 
@@ -21,12 +25,15 @@ use crate::dto::{
     ApplyOrderChangeInput, CancelOrderChangeInput, CancelOrderReturnInput,
     CompleteOrderReturnInput, CreateOrderAdjustmentInput, CreateOrderChangeInput, CreateOrderInput,
     CreateOrderLineItemInput, CreateOrderReturnInput, CreateOrderTaxLineInput,
-    ListOrderChangesInput, ListOrderReturnsInput, ListOrdersInput, OrderAdjustmentResponse,
-    OrderChangeResponse, OrderLineItemResponse, OrderResponse, OrderReturnItemResponse,
-    OrderReturnResponse, OrderTaxLineResponse,
+    CustomerOrderHistoryFilter, CustomerOrderHistoryPage, CustomerOrderItemSummary,
+    CustomerOrderSummary, ListOrderChangesInput, ListOrderReturnsInput, ListOrdersInput,
+    OrderAdjustmentResponse, OrderChangeResponse, OrderLineItemResponse, OrderResponse,
+    OrderReturnItemResponse, OrderReturnResponse, OrderTaxLineResponse,
 };
 use crate::entities;
 use crate::error::{OrderError, OrderResult};
+use crate::services::order_number::{tenant_order_number_generator, OrderNumberGenerator};
+use crate::totals::{calculate_order_totals, OrderTotals, TotalsAmount, TotalsLineItem, TotalsTax};
 
 const STATUS_PENDING: &str = "pending";
 const STATUS_CONFIRMED: &str = "confirmed";
@@ -44,6 +51,7 @@ const RETURN_RESOLUTION_STORE_CREDIT: &str = "store_credit";
 const ORDER_CHANGE_STATUS_PENDING: &str = "pending";
 const ORDER_CHANGE_STATUS_APPLIED: &str = "applied";
 const ORDER_CHANGE_STATUS_CANCELLED: &str = "cancelled";
+const DEFAULT_CUSTOMER_HISTORY_LIMIT: u64 = 20;
 
 mod order_field_definitions_storage {
     rustok_core::define_field_definitions_entity!("order_field_definitions");
@@ -52,11 +60,25 @@ mod order_field_definitions_storage {
 pub struct OrderService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
+    order_numbers: OrderNumberGenerator,
 }
 
 impl OrderService {
     pub fn new(db: DatabaseConnection, event_bus: TransactionalEventBus) -> Self {
-        Self { db, event_bus }
+        Self {
+            db,
+            event_bus,
+            order_numbers: OrderNumberGenerator::default(),
+        }
+    }
+
+    /// Replace the host-wide `ORD-{YYYY}-{SEQ:6}` order number format.
+    ///
+    /// A tenant's `order_number_template` module setting still takes
+    /// precedence; this template applies to tenants without one.
+    pub fn with_order_number_generator(mut self, order_numbers: OrderNumberGenerator) -> Self {
+        self.order_numbers = order_numbers;
+        self
     }
 
     #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
@@ -106,19 +128,11 @@ impl OrderService {
             .clone()
             .unwrap_or_else(|| serde_json::json!({}));
 
-        let mut subtotal_amount = Decimal::ZERO;
         for item in &input.line_items {
             Self::validate_line_item(item)?;
-            subtotal_amount += item.unit_price * Decimal::from(item.quantity);
-        }
-        if input.shipping_total < Decimal::ZERO {
-            return Err(OrderError::Validation(
-                "shipping_total cannot be negative".to_string(),
-            ));
         }
-        let adjustment_total =
-            Self::validate_adjustments(&input.adjustments, input.line_items.len())?;
-        let tax_total = Self::validate_tax_lines(
+        Self::validate_adjustments(&input.adjustments, input.line_items.len())?;
+        Self::validate_tax_lines(
             &input.tax_lines,
             input.line_items.len(),
             currency_code.as_str(),
@@ -129,33 +143,59 @@ impl OrderService {
                 .and_then(serde_json::Value::as_bool)
                 .unwrap_or(false)
         });
-        if adjustment_total > subtotal_amount {
-            return Err(OrderError::Validation(
-                "adjustment total cannot exceed order subtotal".to_string(),
-            ));
-        }
-        let base_total = subtotal_amount - adjustment_total + input.shipping_total;
-        let total_amount = if tax_included {
-            base_total
-        } else {
-            base_total + tax_total
-        };
+        let totals = calculate_order_totals(
+            &currency_code,
+            &input
+                .line_items
+                .iter()
+                .map(|item| TotalsLineItem {
+                    currency_code: &currency_code,
+                    unit_price: item.unit_price,
+                    quantity: item.quantity,
+                })
+                .collect::<Vec<_>>(),
+            &input
+                .adjustments
+                .iter()
+                .map(|adjustment| TotalsAmount {
+                    currency_code: &currency_code,
+                    amount: adjustment.amount,
+                })
+                .collect::<Vec<_>>(),
+            &TotalsTax {
+                lines: input
+                    .tax_lines
+                    .iter()
+                    .map(|line| TotalsAmount {
+                        currency_code: &line.currency_code,
+                        amount: line.amount,
+                    })
+                    .collect(),
+                included: tax_included,
+            },
+            input.shipping_total,
+        )?;
+
+        let tenant_order_numbers = tenant_order_number_generator(&self.db, tenant_id).await?;
+        let order_numbers = tenant_order_numbers.as_ref().unwrap_or(&self.order_numbers);
 
         let order_id = generate_id();
         let now = Utc::now();
         let txn = self.db.begin().await?;
+        let order_number = order_numbers.next_in_tx(&txn, tenant_id, now).await?;
 
         entities::order::ActiveModel {
             id: Set(order_id),
             tenant_id: Set(tenant_id),
+            order_number: Set(Some(order_number)),
             channel_id: Set(channel_id),
             channel_slug: Set(channel_slug),
             customer_id: Set(input.customer_id),
             status: Set(STATUS_PENDING.to_string()),
             currency_code: Set(currency_code.clone()),
-            shipping_total: Set(input.shipping_total),
-            total_amount: Set(total_amount),
-            tax_total: Set(tax_total),
+            shipping_total: Set(totals.shipping_total),
+            total_amount: Set(totals.grand_total),
+            tax_total: Set(totals.tax_total),
             tax_included: Set(tax_included),
             metadata: Set(order_metadata),
             payment_id: Set(None),
@@ -270,7 +310,7 @@ impl OrderService {
                 DomainEvent::OrderPlaced {
                     order_id,
                     customer_id: input.customer_id,
-                    total: decimal_to_minor_units(total_amount).unwrap_or(0),
+                    total: decimal_to_minor_units(totals.grand_total).unwrap_or(0),
                     currency: currency_code,
                 },
             )
@@ -304,7 +344,7 @@ impl OrderService {
         &self,
         tenant_id: Uuid,
         input: ListOrdersInput,
-    ) -> OrderResult<(Vec<OrderResponse>, u64)> {
+    ) -> OrderResult<Page<OrderResponse>> {
         let default_locale = load_tenant_default_locale(&self.db, tenant_id).await?;
         self.list_orders_with_locale_fallback(tenant_id, input, default_locale.as_str(), None)
             .await
@@ -316,7 +356,7 @@ impl OrderService {
         input: ListOrdersInput,
         locale: &str,
         fallback_locale: Option<&str>,
-    ) -> OrderResult<(Vec<OrderResponse>, u64)> {
+    ) -> OrderResult<Page<OrderResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
 
@@ -333,21 +373,168 @@ impl OrderService {
         if let Some(customer_id) = input.customer_id {
             query = query.filter(entities::order::Column::CustomerId.eq(customer_id));
         }
+        if let Some(order_number) = input
+            .order_number
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            query = query.filter(order_number_search_condition(order_number));
+        }
 
-        let total = query.clone().count(&self.db).await?;
-        let orders = query
-            .order_by_desc(entities::order::Column::CreatedAt)
-            .offset((page - 1) * per_page)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let offset = (page - 1) * per_page;
+        let (orders, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::order::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
         let mut items = Vec::with_capacity(orders.len());
         for order in orders {
             items.push(self.build_response(order, locale, fallback_locale).await?);
         }
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset))
+    }
+
+    /// Storefront order history for a single customer, newest first.
+    ///
+    /// Customers may only read their own history; staff with tenant-wide order
+    /// access may read any customer's history. Pagination is keyset-based, so
+    /// `cursor` must be a `next_cursor` returned by a previous call.
+    #[instrument(skip(self, security, filter), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn list_customer_orders(
+        &self,
+        security: &SecurityContext,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        filter: CustomerOrderHistoryFilter,
+        cursor: Option<&str>,
+    ) -> OrderResult<CustomerOrderHistoryPage> {
+        self.ensure_customer_history_access(security, tenant_id, customer_id)
+            .await?;
+
+        let limit = filter
+            .limit
+            .unwrap_or(DEFAULT_CUSTOMER_HISTORY_LIMIT)
+            .clamp(1, 100);
+        let statuses = filter
+            .statuses
+            .iter()
+            .map(|status| status.trim().to_ascii_lowercase())
+            .filter(|status| !status.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut query = entities::order::Entity::find()
+            .filter(entities::order::Column::TenantId.eq(tenant_id))
+            .filter(entities::order::Column::CustomerId.eq(customer_id));
+        if !statuses.is_empty() {
+            query = query.filter(entities::order::Column::Status.is_in(statuses));
+        }
+        if let Some((created_at, order_id)) = cursor.map(decode_history_cursor).transpose()? {
+            query = query.filter(
+                Condition::any()
+                    .add(entities::order::Column::CreatedAt.lt(created_at))
+                    .add(
+                        Condition::all()
+                            .add(entities::order::Column::CreatedAt.eq(created_at))
+                            .add(entities::order::Column::Id.lt(order_id)),
+                    ),
+            );
+        }
+
+        let mut orders = query
+            .order_by_desc(entities::order::Column::CreatedAt)
+            .order_by_desc(entities::order::Column::Id)
+            .limit(limit + 1)
+            .all(&self.db)
+            .await?;
+        let has_more = orders.len() as u64 > limit;
+        orders.truncate(limit as usize);
+        let next_cursor = if has_more {
+            orders.last().map(encode_history_cursor)
+        } else {
+            None
+        };
+
+        let order_ids = orders.iter().map(|order| order.id).collect::<Vec<_>>();
+        let line_items = if order_ids.is_empty() {
+            Vec::new()
+        } else {
+            entities::order_line_item::Entity::find()
+                .filter(entities::order_line_item::Column::OrderId.is_in(order_ids))
+                .order_by_asc(entities::order_line_item::Column::CreatedAt)
+                .all(&self.db)
+                .await?
+        };
+        let default_locale = load_tenant_default_locale(&self.db, tenant_id).await?;
+        let titles =
+            load_line_item_titles(&self.db, &line_items, default_locale.as_str(), None).await?;
+
+        let mut items_by_order = HashMap::<Uuid, Vec<CustomerOrderItemSummary>>::new();
+        for item in line_items {
+            items_by_order
+                .entry(item.order_id)
+                .or_default()
+                .push(CustomerOrderItemSummary {
+                    line_item_id: item.id,
+                    product_id: item.product_id,
+                    variant_id: item.variant_id,
+                    title: titles.get(&item.id).cloned().unwrap_or_default(),
+                    quantity: item.quantity,
+                    total_price: item.total_price,
+                });
+        }
+
+        let items = orders
+            .into_iter()
+            .map(|order| {
+                let items = items_by_order.remove(&order.id).unwrap_or_default();
+                CustomerOrderSummary {
+                    id: order.id,
+                    order_number: order.order_number,
+                    status: order.status,
+                    currency_code: order.currency_code,
+                    total_amount: order.total_amount,
+                    item_count: items.iter().map(|item| i64::from(item.quantity)).sum(),
+                    items,
+                    created_at: order.created_at.with_timezone(&Utc),
+                    updated_at: order.updated_at.with_timezone(&Utc),
+                    paid_at: order.paid_at.map(|value| value.with_timezone(&Utc)),
+                    shipped_at: order.shipped_at.map(|value| value.with_timezone(&Utc)),
+                    delivered_at: order.delivered_at.map(|value| value.with_timezone(&Utc)),
+                    cancelled_at: order.cancelled_at.map(|value| value.with_timezone(&Utc)),
+                }
+            })
+            .collect();
+
+        Ok(Page::cursor(items, next_cursor))
+    }
+
+    async fn ensure_customer_history_access(
+        &self,
+        security: &SecurityContext,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+    ) -> OrderResult<()> {
+        match security.get_scope(Resource::Orders, Action::List) {
+            PermissionScope::All => Ok(()),
+            PermissionScope::Own => {
+                let owner = load_customer_user_id(&self.db, tenant_id, customer_id).await?;
+                if owner.is_some() && owner == security.user_id {
+                    Ok(())
+                } else {
+                    Err(OrderError::Forbidden(
+                        "customers may only read their own order history".to_string(),
+                    ))
+                }
+            }
+            PermissionScope::None => Err(OrderError::Forbidden(
+                "order history requires orders:list permission".to_string(),
+            )),
+        }
     }
 
     pub async fn confirm_order(
@@ -377,11 +564,7 @@ impl OrderService {
         payment_id: String,
         payment_method: String,
     ) -> OrderResult<OrderResponse> {
-        if payment_id.trim().is_empty() || payment_method.trim().is_empty() {
-            return Err(OrderError::Validation(
-                "payment_id and payment_method are required".to_string(),
-            ));
-        }
+        validate_payment_reference(&payment_id, &payment_method)?;
 
         self.transition_order(
             tenant_id,
@@ -398,6 +581,42 @@ impl OrderService {
         .await
     }
 
+    /// [`Self::mark_paid`] inside the caller's transaction, e.g. together with
+    /// the inventory write-off. The order row is locked here, so rows that
+    /// must be locked before it (inventory) have to be locked first. Reload
+    /// the order after commit.
+    pub async fn mark_paid_in_tx<C>(
+        &self,
+        txn: &C,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        order_id: Uuid,
+        payment_id: String,
+        payment_method: String,
+    ) -> OrderResult<()>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        validate_payment_reference(&payment_id, &payment_method)?;
+
+        self.transition_order_in_tx(
+            txn,
+            tenant_id,
+            actor_id,
+            order_id,
+            STATUS_CONFIRMED,
+            STATUS_PAID,
+            move |active, now| {
+                active.payment_id = Set(Some(payment_id));
+                active.payment_method = Set(Some(payment_method));
+                active.paid_at = Set(Some(now.into()));
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn ship_order(
         &self,
         tenant_id: Uuid,
@@ -548,11 +767,45 @@ impl OrderService {
         F: FnOnce(&mut entities::order::ActiveModel, chrono::DateTime<Utc>),
     {
         let txn = self.db.begin().await?;
+        let preferred_locale = self
+            .transition_order_in_tx(
+                &txn,
+                tenant_id,
+                actor_id,
+                order_id,
+                expected_from,
+                next_status,
+                mutate,
+            )
+            .await?;
+
+        txn.commit().await?;
+        self.get_order_with_locale_fallback(tenant_id, order_id, preferred_locale.as_str(), None)
+            .await
+    }
+
+    /// Apply a status transition in `txn` and return the order's preferred
+    /// locale for the response.
+    #[allow(clippy::too_many_arguments)]
+    async fn transition_order_in_tx<C, F>(
+        &self,
+        txn: &C,
+        tenant_id: Uuid,
+        actor_id: Uuid,
+        order_id: Uuid,
+        expected_from: &str,
+        next_status: &str,
+        mutate: F,
+    ) -> OrderResult<String>
+    where
+        C: sea_orm::ConnectionTrait,
+        F: FnOnce(&mut entities::order::ActiveModel, chrono::DateTime<Utc>),
+    {
         let existing = self
-            .load_order_model_in_tx(&txn, tenant_id, order_id)
+            .load_order_model_in_tx(txn, tenant_id, order_id)
             .await?;
         let preferred_locale = Self::preferred_order_locale_from_metadata(&existing.metadata)
-            .unwrap_or(load_tenant_default_locale(&txn, tenant_id).await?);
+            .unwrap_or(load_tenant_default_locale(txn, tenant_id).await?);
         if existing.status != expected_from {
             return Err(OrderError::InvalidTransition {
                 from: existing.status,
@@ -566,21 +819,12 @@ impl OrderService {
         active.status = Set(next_status.to_string());
         active.updated_at = Set(now.into());
         mutate(&mut active, now);
-        active.update(&txn).await?;
+        active.update(txn).await?;
 
-        self.publish_status_changed(
-            &txn,
-            tenant_id,
-            actor_id,
-            order_id,
-            &old_status,
-            next_status,
-        )
-        .await?;
+        self.publish_status_changed(txn, tenant_id, actor_id, order_id, &old_status, next_status)
+            .await?;
 
-        txn.commit().await?;
-        self.get_order_with_locale_fallback(tenant_id, order_id, preferred_locale.as_str(), None)
-            .await
+        Ok(preferred_locale)
     }
 
     async fn publish_status_changed<C>(
@@ -616,8 +860,11 @@ impl OrderService {
         tenant_id: Uuid,
         order_id: Uuid,
     ) -> OrderResult<entities::order::Model> {
-        self.load_order_model_in_tx(&self.db, tenant_id, order_id)
-            .await
+        entities::order::Entity::find_by_id(order_id)
+            .filter(entities::order::Column::TenantId.eq(tenant_id))
+            .one(&self.db)
+            .await?
+            .ok_or(OrderError::OrderNotFound(order_id))
     }
 
     async fn load_order_model_in_tx<C>(
@@ -629,8 +876,11 @@ impl OrderService {
     where
         C: sea_orm::ConnectionTrait,
     {
+        // Lock the row for the rest of the transaction so concurrent
+        // transitions of the same order serialize instead of racing.
         entities::order::Entity::find_by_id(order_id)
             .filter(entities::order::Column::TenantId.eq(tenant_id))
+            .lock_exclusive()
             .one(conn)
             .await?
             .ok_or(OrderError::OrderNotFound(order_id))
@@ -668,22 +918,22 @@ impl OrderService {
                 &order.metadata,
             )
             .await?;
-        let subtotal_amount = subtotal_amount(&line_items);
-        let adjustment_total = adjustment_total(&adjustments);
+        let totals = loaded_order_totals(&order, &line_items, &adjustments, &tax_lines);
 
         Ok(OrderResponse {
             id: order.id,
             tenant_id: order.tenant_id,
+            order_number: order.order_number,
             channel_id: order.channel_id,
             channel_slug: order.channel_slug,
             customer_id: order.customer_id,
             status: order.status,
             currency_code: order.currency_code,
-            subtotal_amount,
-            adjustment_total,
-            shipping_total: order.shipping_total,
-            total_amount: order.total_amount,
-            tax_total: order.tax_total,
+            subtotal_amount: totals.subtotal,
+            adjustment_total: totals.discount_total,
+            shipping_total: totals.shipping_total,
+            total_amount: totals.grand_total,
+            tax_total: totals.tax_total,
             tax_included: order.tax_included,
             metadata: resolved_metadata,
             payment_id: order.payment_id,
@@ -766,8 +1016,7 @@ impl OrderService {
     fn validate_adjustments(
         adjustments: &[CreateOrderAdjustmentInput],
         line_item_count: usize,
-    ) -> OrderResult<Decimal> {
-        let mut adjustment_total = Decimal::ZERO;
+    ) -> OrderResult<()> {
         for adjustment in adjustments {
             adjustment
                 .validate()
@@ -784,17 +1033,15 @@ impl OrderService {
                     )));
                 }
             }
-            adjustment_total += adjustment.amount;
         }
-        Ok(adjustment_total)
+        Ok(())
     }
 
     fn validate_tax_lines(
         tax_lines: &[CreateOrderTaxLineInput],
         line_item_count: usize,
         currency_code: &str,
-    ) -> OrderResult<Decimal> {
-        let mut tax_total = Decimal::ZERO;
+    ) -> OrderResult<()> {
         for tax_line in tax_lines {
             tax_line
                 .validate()
@@ -821,9 +1068,8 @@ impl OrderService {
                     "tax line currency_code must match order currency".to_string(),
                 ));
             }
-            tax_total += tax_line.amount;
         }
-        Ok(tax_total)
+        Ok(())
     }
 
     async fn prepare_order_custom_fields_for_create(
@@ -910,6 +1156,18 @@ impl OrderService {
     }
 }
 
+/// Case-insensitive substring match, so support can search by the tail of a
+/// number read out over the phone (`000123`) as well as the full value.
+fn order_number_search_condition(order_number: &str) -> SimpleExpr {
+    let escaped = order_number
+        .to_ascii_uppercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    Expr::expr(Func::upper(Expr::col(entities::order::Column::OrderNumber)))
+        .like(LikeExpr::new(format!("%{escaped}%")).escape('\\'))
+}
+
 fn can_cancel(status: &str) -> bool {
     matches!(
         status,
@@ -921,16 +1179,86 @@ fn decimal_to_minor_units(amount: Decimal) -> Option<i64> {
     (amount.round_dp(2) * Decimal::from(100)).to_i64()
 }
 
-fn subtotal_amount(line_items: &[entities::order_line_item::Model]) -> Decimal {
-    line_items
-        .iter()
-        .fold(Decimal::ZERO, |acc, item| acc + item.total_price)
+/// Totals of a stored order recomputed from its loaded rows.
+///
+/// Orders written before `order_tax_lines` existed only carry
+/// `orders.tax_total`, which then stands in for the missing tax lines. Rows
+/// the calculation rejects (for example a line item in another currency)
+/// are reported with the stored totals instead of failing the read.
+fn loaded_order_totals(
+    order: &entities::order::Model,
+    line_items: &[entities::order_line_item::Model],
+    adjustments: &[entities::order_adjustment::Model],
+    tax_lines: &[entities::order_tax_line::Model],
+) -> OrderTotals {
+    let legacy_tax = [TotalsAmount {
+        currency_code: &order.currency_code,
+        amount: order.tax_total,
+    }];
+    let tax_lines = if tax_lines.is_empty() && !order.tax_total.is_zero() {
+        legacy_tax.to_vec()
+    } else {
+        tax_lines
+            .iter()
+            .map(|line| TotalsAmount {
+                currency_code: &line.currency_code,
+                amount: line.amount,
+            })
+            .collect()
+    };
+
+    calculate_order_totals(
+        &order.currency_code,
+        &line_items
+            .iter()
+            .map(|item| TotalsLineItem {
+                currency_code: &item.currency_code,
+                unit_price: item.unit_price,
+                quantity: item.quantity,
+            })
+            .collect::<Vec<_>>(),
+        &adjustments
+            .iter()
+            .map(|adjustment| TotalsAmount {
+                currency_code: &adjustment.currency_code,
+                amount: adjustment.amount,
+            })
+            .collect::<Vec<_>>(),
+        &TotalsTax {
+            lines: tax_lines,
+            included: order.tax_included,
+        },
+        order.shipping_total,
+    )
+    .unwrap_or_else(|error| {
+        tracing::warn!(
+            order_id = %order.id,
+            %error,
+            "Stored order rows do not recompute; reporting stored totals"
+        );
+        stored_order_totals(order, line_items, adjustments)
+    })
 }
 
-fn adjustment_total(adjustments: &[entities::order_adjustment::Model]) -> Decimal {
-    adjustments
-        .iter()
-        .fold(Decimal::ZERO, |acc, adjustment| acc + adjustment.amount)
+/// Totals as persisted on the order row, with subtotal and discounts summed
+/// from the stored line and adjustment amounts.
+fn stored_order_totals(
+    order: &entities::order::Model,
+    line_items: &[entities::order_line_item::Model],
+    adjustments: &[entities::order_adjustment::Model],
+) -> OrderTotals {
+    OrderTotals {
+        currency_code: order.currency_code.clone(),
+        subtotal: line_items
+            .iter()
+            .fold(Decimal::ZERO, |acc, item| acc + item.total_price),
+        discount_total: adjustments
+            .iter()
+            .fold(Decimal::ZERO, |acc, adjustment| acc + adjustment.amount),
+        tax_total: order.tax_total,
+        shipping_total: order.shipping_total,
+        grand_total: order.total_amount,
+    }
 }
 
 fn map_order_change_response(change: entities::order_change::Model) -> OrderChangeResponse {
@@ -1400,16 +1728,69 @@ fn select_order_line_item_title(
         .map(|row| row.title.clone())
 }
 
+async fn load_customer_user_id<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    customer_id: Uuid,
+) -> OrderResult<Option<Uuid>>
+where
+    C: ConnectionTrait,
+{
+    // Built with sea-query so placeholders match the backend (`$1` on Postgres).
+    let query = Query::select()
+        .column(Alias::new("user_id"))
+        .from(Alias::new("customers"))
+        .and_where(Expr::col(Alias::new("id")).eq(customer_id))
+        .and_where(Expr::col(Alias::new("tenant_id")).eq(tenant_id))
+        .to_owned();
+    let row = conn
+        .query_one(conn.get_database_backend().build(&query))
+        .await?;
+
+    Ok(row.and_then(|row| row.try_get::<Option<Uuid>>("", "user_id").ok().flatten()))
+}
+
+fn encode_history_cursor(order: &entities::order::Model) -> String {
+    format!(
+        "{}|{}",
+        order
+            .created_at
+            .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+        order.id
+    )
+}
+
+fn decode_history_cursor(
+    cursor: &str,
+) -> OrderResult<(chrono::DateTime<chrono::FixedOffset>, Uuid)> {
+    let invalid = || OrderError::Validation(format!("invalid order history cursor: {cursor}"));
+    let (created_at, order_id) = cursor.split_once('|').ok_or_else(invalid)?;
+    let created_at = chrono::DateTime::parse_from_rfc3339(created_at).map_err(|_| invalid())?;
+    let order_id = Uuid::parse_str(order_id).map_err(|_| invalid())?;
+    Ok((created_at, order_id))
+}
+
+fn validate_payment_reference(payment_id: &str, payment_method: &str) -> OrderResult<()> {
+    if payment_id.trim().is_empty() || payment_method.trim().is_empty() {
+        return Err(OrderError::Validation(
+            "payment_id and payment_method are required".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 async fn load_tenant_default_locale<C>(conn: &C, tenant_id: Uuid) -> OrderResult<String>
 where
     C: ConnectionTrait,
 {
+    let query = Query::select()
+        .column(Alias::new("default_locale"))
+        .from(Alias::new("tenants"))
+        .and_where(Expr::col(Alias::new("id")).eq(tenant_id))
+        .to_owned();
     let row = conn
-        .query_one(Statement::from_sql_and_values(
-            conn.get_database_backend(),
-            "SELECT default_locale FROM tenants WHERE id = ?",
-            vec![tenant_id.into()],
-        ))
+        .query_one(conn.get_database_backend().build(&query))
         .await?;
 
     let default_locale = row
@@ -1469,7 +1850,7 @@ impl OrderService {
         &self,
         tenant_id: Uuid,
         input: ListOrderChangesInput,
-    ) -> OrderResult<(Vec<OrderChangeResponse>, u64)> {
+    ) -> OrderResult<Page<OrderChangeResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let mut query = entities::order_change::Entity::find()
@@ -1492,12 +1873,12 @@ impl OrderService {
             }
         }
 
-        let paginator = query.paginate(&self.db, per_page);
-        let total = paginator.num_items().await?;
-        let rows = paginator.fetch_page(page.saturating_sub(1)).await?;
-        Ok((
+        let (rows, total) =
+            fetch_with_total(&self.db, query, (page - 1) * per_page, per_page).await?;
+        Ok(Page::offset(
             rows.into_iter().map(map_order_change_response).collect(),
             total,
+            (page - 1) * per_page,
         ))
     }
 
@@ -1796,7 +2177,7 @@ impl OrderService {
         &self,
         tenant_id: Uuid,
         input: ListOrderReturnsInput,
-    ) -> OrderResult<(Vec<OrderReturnResponse>, u64)> {
+    ) -> OrderResult<Page<OrderReturnResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let mut query = entities::order_return::Entity::find()
@@ -1811,9 +2192,8 @@ impl OrderService {
                 query = query.filter(entities::order_return::Column::Status.eq(normalized_status));
             }
         }
-        let paginator = query.paginate(&self.db, per_page);
-        let total = paginator.num_items().await?;
-        let rows = paginator.fetch_page(page.saturating_sub(1)).await?;
+        let (rows, total) =
+            fetch_with_total(&self.db, query, (page - 1) * per_page, per_page).await?;
         let return_ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
         let mut items_by_return_id: HashMap<Uuid, Vec<entities::order_return_item::Model>> =
             HashMap::new();
@@ -1831,7 +2211,7 @@ impl OrderService {
                     .push(item);
             }
         }
-        Ok((
+        Ok(Page::offset(
             rows.into_iter()
                 .map(|row| {
                     let items = items_by_return_id.remove(&row.id).unwrap_or_default();
@@ -1839,6 +2219,7 @@ impl OrderService {
                 })
                 .collect(),
             total,
+            (page - 1) * per_page,
         ))
     }
 
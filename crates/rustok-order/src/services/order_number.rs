@@ -0,0 +1,322 @@
+use chrono::{DateTime, Datelike, Utc};
+use sea_orm::sea_query::{Alias, Expr, OnConflict, Query};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, QuerySelect, Set};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::entities::sequence;
+use crate::error::{OrderError, OrderResult};
+
+/// Template used when the host does not configure one: `ORD-2024-000123`.
+pub const DEFAULT_ORDER_NUMBER_TEMPLATE: &str = "ORD-{YYYY}-{SEQ:6}";
+
+/// Name of the per-tenant row in `sequences` that backs order numbers.
+pub const ORDER_NUMBER_SEQUENCE: &str = "order_number";
+
+/// Key in the `order` module's tenant settings (`tenant_modules.settings`)
+/// that overrides the host template for one tenant.
+pub const ORDER_NUMBER_TEMPLATE_SETTING: &str = "order_number_template";
+
+const ORDER_MODULE_SLUG: &str = "order";
+
+const MAX_SEQUENCE_WIDTH: usize = 18;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Year,
+    ShortYear,
+    Month,
+    Day,
+    Sequence { width: usize },
+}
+
+/// Formats human-friendly order numbers from a tenant-scoped sequence.
+///
+/// Templates mix literal text with placeholders: `{YYYY}`, `{YY}`, `{MM}`,
+/// `{DD}` (order creation date, UTC) and `{SEQ}` / `{SEQ:N}` (sequence value,
+/// zero-padded to `N` digits). `{SEQ}` is required because the sequence is
+/// the only part that makes numbers unique. The sequence never resets, so a
+/// template without a date still yields unique numbers across years.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderNumberGenerator {
+    template: String,
+    segments: Vec<Segment>,
+}
+
+impl Default for OrderNumberGenerator {
+    fn default() -> Self {
+        Self::new(DEFAULT_ORDER_NUMBER_TEMPLATE).expect("default order number template is valid")
+    }
+}
+
+impl OrderNumberGenerator {
+    pub fn new(template: &str) -> OrderResult<Self> {
+        let segments = parse_template(template)?;
+        Ok(Self {
+            template: template.to_string(),
+            segments,
+        })
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Render the number for `sequence` as of `created_at`.
+    pub fn format(&self, sequence: i64, created_at: DateTime<Utc>) -> String {
+        let mut number = String::with_capacity(self.template.len() + 8);
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(text) => number.push_str(text),
+                Segment::Year => number.push_str(&format!("{:04}", created_at.year())),
+                Segment::ShortYear => {
+                    number.push_str(&format!("{:02}", created_at.year().rem_euclid(100)))
+                }
+                Segment::Month => number.push_str(&format!("{:02}", created_at.month())),
+                Segment::Day => number.push_str(&format!("{:02}", created_at.day())),
+                Segment::Sequence { width } => {
+                    number.push_str(&format!("{sequence:0width$}", width = *width))
+                }
+            }
+        }
+        number
+    }
+
+    /// Allocate the next tenant sequence value and format it.
+    ///
+    /// Must run inside the transaction that inserts the order: the sequence
+    /// row stays locked (`SELECT ... FOR UPDATE`) until that transaction ends,
+    /// so concurrent orders of one tenant are numbered one after another and
+    /// a rolled-back order gives its number back.
+    pub async fn next_in_tx<C>(
+        &self,
+        conn: &C,
+        tenant_id: Uuid,
+        created_at: DateTime<Utc>,
+    ) -> OrderResult<String>
+    where
+        C: ConnectionTrait,
+    {
+        let value = next_sequence_value(conn, tenant_id, ORDER_NUMBER_SEQUENCE).await?;
+        Ok(self.format(value, created_at))
+    }
+}
+
+/// Generator configured for `tenant_id` through [`ORDER_NUMBER_TEMPLATE_SETTING`].
+///
+/// Returns `None` when the tenant has no override. An invalid template is
+/// logged and ignored so that checkout keeps numbering orders with the host
+/// template instead of failing.
+pub async fn tenant_order_number_generator<C>(
+    conn: &C,
+    tenant_id: Uuid,
+) -> OrderResult<Option<OrderNumberGenerator>>
+where
+    C: ConnectionTrait,
+{
+    let query = Query::select()
+        .column(Alias::new("settings"))
+        .from(Alias::new("tenant_modules"))
+        .and_where(Expr::col(Alias::new("tenant_id")).eq(tenant_id))
+        .and_where(Expr::col(Alias::new("module_slug")).eq(ORDER_MODULE_SLUG))
+        .to_owned();
+    let row = conn
+        .query_one(conn.get_database_backend().build(&query))
+        .await?;
+
+    let Some(template) = row
+        .and_then(|row| row.try_get::<Value>("", "settings").ok())
+        .and_then(|settings| {
+            settings
+                .get(ORDER_NUMBER_TEMPLATE_SETTING)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|template| !template.is_empty())
+                .map(str::to_string)
+        })
+    else {
+        return Ok(None);
+    };
+
+    match OrderNumberGenerator::new(&template) {
+        Ok(generator) => Ok(Some(generator)),
+        Err(error) => {
+            tracing::warn!(
+                tenant_id = %tenant_id,
+                error = %error,
+                "ignoring tenant order number template"
+            );
+            Ok(None)
+        }
+    }
+}
+
+async fn next_sequence_value<C>(conn: &C, tenant_id: Uuid, name: &str) -> OrderResult<i64>
+where
+    C: ConnectionTrait,
+{
+    let current = match lock_sequence(conn, tenant_id, name).await? {
+        Some(row) => row,
+        None => {
+            // First order of the tenant: create the row, tolerating a
+            // concurrent creator, then lock whichever row won.
+            sequence::Entity::insert(sequence::ActiveModel {
+                tenant_id: Set(tenant_id),
+                name: Set(name.to_string()),
+                last_value: Set(0),
+                updated_at: Set(Utc::now().into()),
+            })
+            .on_conflict(
+                OnConflict::columns([sequence::Column::TenantId, sequence::Column::Name])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec_without_returning(conn)
+            .await?;
+
+            lock_sequence(conn, tenant_id, name).await?.ok_or_else(|| {
+                OrderError::Validation(format!("sequence `{name}` could not be initialized"))
+            })?
+        }
+    };
+
+    let next = current.last_value + 1;
+    let mut active: sequence::ActiveModel = current.into();
+    active.last_value = Set(next);
+    active.updated_at = Set(Utc::now().into());
+    active.update(conn).await?;
+
+    Ok(next)
+}
+
+async fn lock_sequence<C>(
+    conn: &C,
+    tenant_id: Uuid,
+    name: &str,
+) -> OrderResult<Option<sequence::Model>>
+where
+    C: ConnectionTrait,
+{
+    Ok(sequence::Entity::find_by_id((tenant_id, name.to_string()))
+        .lock_exclusive()
+        .one(conn)
+        .await?)
+}
+
+fn parse_template(template: &str) -> OrderResult<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        literal.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            return Err(template_error(template, "unclosed `{`"));
+        };
+        let placeholder = &rest[start + 1..start + end];
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(std::mem::take(&mut literal)));
+        }
+        segments.push(parse_placeholder(template, placeholder)?);
+        rest = &rest[start + end + 1..];
+    }
+    if rest.contains('}') {
+        return Err(template_error(template, "unmatched `}`"));
+    }
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Sequence { .. }))
+    {
+        return Err(template_error(template, "`{SEQ}` placeholder is required"));
+    }
+
+    Ok(segments)
+}
+
+fn parse_placeholder(template: &str, placeholder: &str) -> OrderResult<Segment> {
+    match placeholder {
+        "YYYY" => Ok(Segment::Year),
+        "YY" => Ok(Segment::ShortYear),
+        "MM" => Ok(Segment::Month),
+        "DD" => Ok(Segment::Day),
+        "SEQ" => Ok(Segment::Sequence { width: 1 }),
+        _ => {
+            let width = placeholder
+                .strip_prefix("SEQ:")
+                .and_then(|width| width.parse::<usize>().ok())
+                .filter(|width| (1..=MAX_SEQUENCE_WIDTH).contains(width))
+                .ok_or_else(|| {
+                    template_error(
+                        template,
+                        &format!("unknown placeholder `{{{placeholder}}}`"),
+                    )
+                })?;
+            Ok(Segment::Sequence { width })
+        }
+    }
+}
+
+fn template_error(template: &str, reason: &str) -> OrderError {
+    OrderError::Validation(format!(
+        "invalid order number template `{template}`: {reason}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn created_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 7, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn default_template_formats_year_and_padded_sequence() {
+        let generator = OrderNumberGenerator::default();
+
+        assert_eq!(generator.format(123, created_at()), "ORD-2024-000123");
+        assert_eq!(
+            generator.format(1_234_567, created_at()),
+            "ORD-2024-1234567"
+        );
+    }
+
+    #[test]
+    fn custom_template_supports_all_placeholders() {
+        let generator = OrderNumberGenerator::new("{YY}{MM}{DD}/{SEQ}").unwrap();
+
+        assert_eq!(generator.format(42, created_at()), "240307/42");
+    }
+
+    #[test]
+    fn template_without_sequence_is_rejected() {
+        assert!(matches!(
+            OrderNumberGenerator::new("ORD-{YYYY}"),
+            Err(OrderError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_templates_are_rejected() {
+        for template in [
+            "ORD-{SEQ",
+            "ORD-SEQ}",
+            "ORD-{SEQ:0}",
+            "ORD-{SEQ:x}",
+            "{ID}-{SEQ}",
+        ] {
+            assert!(
+                OrderNumberGenerator::new(template).is_err(),
+                "{template} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Order total arithmetic.
+//!
+//! Order creation and the order read-side both derive subtotal, discounts,
+//! tax, shipping and the grand total from the same rows, so the arithmetic
+//! lives in one pure function without database access. Callers load or
+//! validate their data and pass plain amounts in.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{OrderError, OrderResult};
+
+/// Decimal places every total is rounded to.
+pub const MONEY_SCALE: u32 = 2;
+
+/// A line item as seen by the totals calculation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TotalsLineItem<'a> {
+    pub currency_code: &'a str,
+    pub unit_price: Decimal,
+    pub quantity: i32,
+}
+
+impl TotalsLineItem<'_> {
+    /// Unrounded `unit_price * quantity`.
+    pub fn line_total(&self) -> Decimal {
+        self.unit_price * Decimal::from(self.quantity)
+    }
+}
+
+/// A currency-tagged amount: a discount, a tax line or shipping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TotalsAmount<'a> {
+    pub currency_code: &'a str,
+    pub amount: Decimal,
+}
+
+/// Tax lines of an order and whether their amounts are already part of the
+/// item prices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TotalsTax<'a> {
+    pub lines: Vec<TotalsAmount<'a>>,
+    pub included: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderTotals {
+    pub currency_code: String,
+    pub subtotal: Decimal,
+    pub discount_total: Decimal,
+    pub tax_total: Decimal,
+    pub shipping_total: Decimal,
+    /// `subtotal - discount_total + shipping_total`, plus `tax_total` unless
+    /// tax is included in the item prices.
+    pub grand_total: Decimal,
+}
+
+/// Compute the totals of an order in `currency_code`.
+///
+/// Each component is rounded to [`MONEY_SCALE`] places (half away from zero)
+/// before the grand total is formed, so the grand total always equals the sum
+/// of the displayed components. Amounts in another currency, negative
+/// amounts, non-positive quantities and discounts exceeding the subtotal are
+/// rejected with [`OrderError::Validation`].
+pub fn calculate_order_totals(
+    currency_code: &str,
+    items: &[TotalsLineItem<'_>],
+    discounts: &[TotalsAmount<'_>],
+    tax: &TotalsTax<'_>,
+    shipping: Decimal,
+) -> OrderResult<OrderTotals> {
+    let currency_code = currency_code.trim().to_ascii_uppercase();
+
+    let mut subtotal = Decimal::ZERO;
+    for item in items {
+        ensure_currency(&currency_code, item.currency_code, "line item")?;
+        if item.quantity <= 0 {
+            return Err(OrderError::Validation(
+                "line item quantity must be greater than zero".to_string(),
+            ));
+        }
+        ensure_non_negative(item.unit_price, "unit_price")?;
+        subtotal += item.line_total();
+    }
+
+    let discount_total = sum_amounts(&currency_code, discounts, "adjustment")?;
+    let tax_total = sum_amounts(&currency_code, &tax.lines, "tax line")?;
+    ensure_non_negative(shipping, "shipping_total")?;
+
+    let subtotal = round_money(subtotal);
+    let discount_total = round_money(discount_total);
+    let tax_total = round_money(tax_total);
+    let shipping_total = round_money(shipping);
+    if discount_total > subtotal {
+        return Err(OrderError::Validation(
+            "adjustment total cannot exceed order subtotal".to_string(),
+        ));
+    }
+
+    let net_total = subtotal - discount_total + shipping_total;
+    let grand_total = if tax.included {
+        net_total
+    } else {
+        net_total + tax_total
+    };
+
+    Ok(OrderTotals {
+        currency_code,
+        subtotal,
+        discount_total,
+        tax_total,
+        shipping_total,
+        grand_total,
+    })
+}
+
+fn round_money(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(MONEY_SCALE, RoundingStrategy::MidpointAwayFromZero)
+}
+
+fn sum_amounts(
+    currency_code: &str,
+    amounts: &[TotalsAmount<'_>],
+    kind: &str,
+) -> OrderResult<Decimal> {
+    let mut total = Decimal::ZERO;
+    for amount in amounts {
+        ensure_currency(currency_code, amount.currency_code, kind)?;
+        ensure_non_negative(amount.amount, kind)?;
+        total += amount.amount;
+    }
+    Ok(total)
+}
+
+fn ensure_currency(expected: &str, actual: &str, kind: &str) -> OrderResult<()> {
+    if actual.trim().eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(OrderError::Validation(format!(
+            "{kind} currency `{actual}` does not match order currency `{expected}`"
+        )))
+    }
+}
+
+fn ensure_non_negative(amount: Decimal, field: &str) -> OrderResult<()> {
+    if amount < Decimal::ZERO {
+        return Err(OrderError::Validation(format!(
+            "{field} cannot be negative"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn item(unit_price: &str, quantity: i32) -> TotalsLineItem<'static> {
+        TotalsLineItem {
+            currency_code: "EUR",
+            unit_price: dec(unit_price),
+            quantity,
+        }
+    }
+
+    fn amount(value: &str) -> TotalsAmount<'static> {
+        TotalsAmount {
+            currency_code: "EUR",
+            amount: dec(value),
+        }
+    }
+
+    fn tax(lines: &[&str], included: bool) -> TotalsTax<'static> {
+        TotalsTax {
+            lines: lines.iter().map(|value| amount(value)).collect(),
+            included,
+        }
+    }
+
+    #[test]
+    fn sums_items_discounts_tax_and_shipping() {
+        let totals = calculate_order_totals(
+            "eur",
+            &[item("19.99", 2), item("4.00", 1)],
+            &[amount("3.98")],
+            &tax(&["2.50", "1.25"], false),
+            dec("5.00"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            totals,
+            OrderTotals {
+                currency_code: "EUR".to_string(),
+                subtotal: dec("43.98"),
+                discount_total: dec("3.98"),
+                tax_total: dec("3.75"),
+                shipping_total: dec("5.00"),
+                grand_total: dec("48.75"),
+            }
+        );
+    }
+
+    #[test]
+    fn included_tax_is_reported_but_not_added() {
+        let totals = calculate_order_totals(
+            "EUR",
+            &[item("120.00", 1)],
+            &[],
+            &tax(&["20.00"], true),
+            Decimal::ZERO,
+        )
+        .unwrap();
+
+        assert_eq!(totals.tax_total, dec("20.00"));
+        assert_eq!(totals.grand_total, dec("120.00"));
+    }
+
+    #[test]
+    fn zero_items_total_only_shipping_and_tax() {
+        let empty =
+            calculate_order_totals("EUR", &[], &[], &TotalsTax::default(), Decimal::ZERO).unwrap();
+        assert_eq!(empty.subtotal, Decimal::ZERO);
+        assert_eq!(empty.grand_total, Decimal::ZERO);
+
+        let shipping_only =
+            calculate_order_totals("EUR", &[], &[], &tax(&["0.95"], false), dec("4.99")).unwrap();
+        assert_eq!(shipping_only.grand_total, dec("5.94"));
+    }
+
+    #[test]
+    fn rounds_components_half_away_from_zero_before_summing() {
+        let totals = calculate_order_totals(
+            "EUR",
+            &[item("0.333", 3), item("0.0025", 2)],
+            &[amount("0.125")],
+            &tax(&["0.0049", "0.0001"], false),
+            dec("1.005"),
+        )
+        .unwrap();
+
+        assert_eq!(totals.subtotal, dec("1.00"));
+        assert_eq!(totals.discount_total, dec("0.13"));
+        assert_eq!(totals.tax_total, dec("0.01"));
+        assert_eq!(totals.shipping_total, dec("1.01"));
+        assert_eq!(
+            totals.grand_total,
+            totals.subtotal - totals.discount_total + totals.shipping_total + totals.tax_total
+        );
+        assert_eq!(totals.grand_total, dec("1.89"));
+    }
+
+    #[test]
+    fn rejects_amounts_in_another_currency() {
+        let usd_item = TotalsLineItem {
+            currency_code: "USD",
+            ..item("1.00", 1)
+        };
+        let usd_amount = TotalsAmount {
+            currency_code: "usd",
+            ..amount("1.00")
+        };
+
+        let cases = [
+            calculate_order_totals(
+                "EUR",
+                &[usd_item],
+                &[],
+                &TotalsTax::default(),
+                Decimal::ZERO,
+            ),
+            calculate_order_totals(
+                "EUR",
+                &[item("5.00", 1)],
+                &[usd_amount],
+                &TotalsTax::default(),
+                Decimal::ZERO,
+            ),
+            calculate_order_totals(
+                "EUR",
+                &[item("5.00", 1)],
+                &[],
+                &TotalsTax {
+                    lines: vec![usd_amount],
+                    included: false,
+                },
+                Decimal::ZERO,
+            ),
+        ];
+        for result in cases {
+            assert!(
+                matches!(result, Err(OrderError::Validation(ref message)) if message.contains("does not match order currency")),
+                "unexpected result: {result:?}"
+            );
+        }
+
+        assert!(calculate_order_totals(
+            " eur ",
+            &[TotalsLineItem {
+                currency_code: "Eur",
+                ..item("1.00", 1)
+            }],
+            &[],
+            &TotalsTax::default(),
+            Decimal::ZERO,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        let no_tax = TotalsTax::default();
+        for (result, expected) in [
+            (
+                calculate_order_totals("EUR", &[item("-1.00", 1)], &[], &no_tax, Decimal::ZERO),
+                "unit_price cannot be negative",
+            ),
+            (
+                calculate_order_totals("EUR", &[item("1.00", 0)], &[], &no_tax, Decimal::ZERO),
+                "quantity must be greater than zero",
+            ),
+            (
+                calculate_order_totals("EUR", &[item("1.00", 1)], &[], &no_tax, dec("-0.01")),
+                "shipping_total cannot be negative",
+            ),
+            (
+                calculate_order_totals(
+                    "EUR",
+                    &[item("1.00", 1)],
+                    &[amount("-0.50")],
+                    &no_tax,
+                    Decimal::ZERO,
+                ),
+                "adjustment cannot be negative",
+            ),
+            (
+                calculate_order_totals(
+                    "EUR",
+                    &[item("10.00", 1)],
+                    &[amount("6.00"), amount("4.01")],
+                    &no_tax,
+                    Decimal::ZERO,
+                ),
+                "adjustment total cannot exceed order subtotal",
+            ),
+        ] {
+            match result {
+                Err(OrderError::Validation(message)) => {
+                    assert!(message.contains(expected), "{message}")
+                }
+                other => panic!("expected validation error `{expected}`, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn discount_equal_to_subtotal_leaves_shipping_and_tax() {
+        let totals = calculate_order_totals(
+            "EUR",
+            &[item("10.00", 1)],
+            &[amount("10.00")],
+            &tax(&["0.50"], false),
+            dec("3.00"),
+        )
+        .unwrap();
+
+        assert_eq!(totals.grand_total, dec("3.50"));
+    }
+}
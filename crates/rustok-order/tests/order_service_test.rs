@@ -1,14 +1,16 @@
 use chrono::Utc;
 use flex::attached;
 use rust_decimal::Decimal;
+use rustok_core::{SecurityContext, UserRole};
 use rustok_order::dto::{
     ApplyOrderChangeInput, CancelOrderChangeInput, CreateOrderAdjustmentInput,
     CreateOrderChangeInput, CreateOrderInput, CreateOrderLineItemInput, CreateOrderReturnInput,
-    CreateOrderReturnItemInput, ListOrderChangesInput, ListOrderReturnsInput,
+    CreateOrderReturnItemInput, CustomerOrderHistoryFilter, ListOrderChangesInput,
+    ListOrderReturnsInput, ListOrdersInput,
 };
-use rustok_order::entities::{order, order_tax_line};
+use rustok_order::entities::{order, order_line_item, order_tax_line};
 use rustok_order::error::OrderError;
-use rustok_order::services::OrderService;
+use rustok_order::services::{OrderNumberGenerator, OrderService, ORDER_NUMBER_TEMPLATE_SETTING};
 use rustok_test_utils::{db::setup_test_db, mock_transactional_event_bus};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait,
@@ -114,6 +116,130 @@ async fn create_order_persists_snapshot_and_total() {
     assert_eq!(created.total_amount, Decimal::from_str("43.98").unwrap());
 }
 
+#[tokio::test]
+async fn create_order_assigns_tenant_scoped_order_numbers() {
+    let service = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let other_tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let year = Utc::now().format("%Y").to_string();
+
+    let first = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+    let second = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+    let other_tenant = service
+        .create_order(other_tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        first.order_number.as_deref(),
+        Some(format!("ORD-{year}-000001").as_str())
+    );
+    assert_eq!(
+        second.order_number.as_deref(),
+        Some(format!("ORD-{year}-000002").as_str())
+    );
+    assert_eq!(
+        other_tenant.order_number.as_deref(),
+        Some(format!("ORD-{year}-000001").as_str())
+    );
+
+    let found = service
+        .list_orders(
+            tenant_id,
+            ListOrdersInput {
+                page: 1,
+                per_page: 20,
+                status: None,
+                customer_id: None,
+                order_number: Some(" ord-%-000002 ".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(
+        found.items.is_empty(),
+        "LIKE wildcards must be matched literally"
+    );
+
+    let found = service
+        .list_orders(
+            tenant_id,
+            ListOrdersInput {
+                page: 1,
+                per_page: 20,
+                status: None,
+                customer_id: None,
+                order_number: Some(" 000002 ".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(found.total, Some(1));
+    assert_eq!(found.items[0].id, second.id);
+}
+
+#[tokio::test]
+async fn custom_order_number_template_is_applied() {
+    let db = setup_test_db().await;
+    support::ensure_order_schema(&db).await;
+    let service = OrderService::new(db, mock_transactional_event_bus())
+        .with_order_number_generator(OrderNumberGenerator::new("SHOP{SEQ:4}").unwrap());
+
+    let created = service
+        .create_order(Uuid::new_v4(), Uuid::new_v4(), create_order_input())
+        .await
+        .unwrap();
+
+    assert_eq!(created.order_number.as_deref(), Some("SHOP0001"));
+}
+
+#[tokio::test]
+async fn tenant_order_number_template_overrides_host_template() {
+    let db = setup_test_db().await;
+    support::ensure_order_schema(&db).await;
+    let service = OrderService::new(db.clone(), mock_transactional_event_bus())
+        .with_order_number_generator(OrderNumberGenerator::new("SHOP{SEQ:4}").unwrap());
+    let tenant_id = Uuid::new_v4();
+    let other_tenant_id = Uuid::new_v4();
+    let broken_tenant_id = Uuid::new_v4();
+    for (tenant, template) in [(tenant_id, "T{SEQ:3}"), (broken_tenant_id, "NO-SEQ")] {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            "INSERT INTO tenant_modules (tenant_id, module_slug, settings) VALUES (?, 'order', ?)",
+            vec![
+                tenant.into(),
+                serde_json::json!({ ORDER_NUMBER_TEMPLATE_SETTING: template }).into(),
+            ],
+        ))
+        .await
+        .expect("tenant order settings should be inserted");
+    }
+
+    let tenant_order = service
+        .create_order(tenant_id, Uuid::new_v4(), create_order_input())
+        .await
+        .unwrap();
+    let other_order = service
+        .create_order(other_tenant_id, Uuid::new_v4(), create_order_input())
+        .await
+        .unwrap();
+    let broken_order = service
+        .create_order(broken_tenant_id, Uuid::new_v4(), create_order_input())
+        .await
+        .unwrap();
+
+    assert_eq!(tenant_order.order_number.as_deref(), Some("T001"));
+    assert_eq!(other_order.order_number.as_deref(), Some("SHOP0001"));
+    assert_eq!(broken_order.order_number.as_deref(), Some("SHOP0001"));
+}
+
 #[tokio::test]
 async fn order_tax_lines_insert_without_provider_id_use_region_default() {
     let db = setup_test_db().await;
@@ -224,6 +350,50 @@ async fn create_order_persists_typed_adjustments_and_net_total() {
         .is_none());
 }
 
+#[tokio::test]
+async fn get_order_reads_legacy_rows_with_stored_totals() {
+    let db = setup_test_db().await;
+    support::ensure_order_schema(&db).await;
+    let service = OrderService::new(db.clone(), mock_transactional_event_bus());
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let created = service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+
+    // Written before tax lines existed: the tax only lives on the order row.
+    let mut legacy: order::ActiveModel = order::Entity::find_by_id(created.id)
+        .one(&db)
+        .await
+        .unwrap()
+        .unwrap()
+        .into();
+    legacy.tax_total = Set(Decimal::from_str("4.40").unwrap());
+    legacy.total_amount = Set(Decimal::from_str("48.38").unwrap());
+    legacy.update(&db).await.unwrap();
+
+    let loaded = service.get_order(tenant_id, created.id).await.unwrap();
+    assert_eq!(loaded.tax_total, Decimal::from_str("4.40").unwrap());
+    assert_eq!(loaded.total_amount, Decimal::from_str("48.38").unwrap());
+
+    // A line item the totals calculation rejects falls back to stored totals.
+    let mut item: order_line_item::ActiveModel =
+        order_line_item::Entity::find_by_id(created.line_items[1].id)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap()
+            .into();
+    item.currency_code = Set("EUR".to_string());
+    item.update(&db).await.unwrap();
+
+    let loaded = service.get_order(tenant_id, created.id).await.unwrap();
+    assert_eq!(loaded.subtotal_amount, Decimal::from_str("43.98").unwrap());
+    assert_eq!(loaded.tax_total, Decimal::from_str("4.40").unwrap());
+    assert_eq!(loaded.total_amount, Decimal::from_str("48.38").unwrap());
+}
+
 #[tokio::test]
 async fn create_order_with_channel_persists_channel_snapshot() {
     let service = setup().await;
@@ -334,6 +504,127 @@ async fn invalid_transition_is_rejected() {
     }
 }
 
+#[tokio::test]
+async fn list_customer_orders_paginates_and_enforces_ownership() {
+    let db = setup_test_db().await;
+    support::ensure_order_schema(&db).await;
+    let service = OrderService::new(db.clone(), mock_transactional_event_bus());
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let customer_id = Uuid::new_v4();
+    let customer_user_id = Uuid::new_v4();
+    db.execute(Statement::from_sql_and_values(
+        DbBackend::Sqlite,
+        "INSERT INTO customers (id, tenant_id, user_id) VALUES (?, ?, ?)",
+        vec![
+            customer_id.into(),
+            tenant_id.into(),
+            customer_user_id.into(),
+        ],
+    ))
+    .await
+    .unwrap();
+
+    let mut created = Vec::new();
+    for _ in 0..3 {
+        let mut input = create_order_input();
+        input.customer_id = Some(customer_id);
+        created.push(
+            service
+                .create_order(tenant_id, actor_id, input)
+                .await
+                .unwrap(),
+        );
+    }
+    service
+        .create_order(tenant_id, actor_id, create_order_input())
+        .await
+        .unwrap();
+    service
+        .confirm_order(tenant_id, actor_id, created[0].id)
+        .await
+        .unwrap();
+
+    let customer = SecurityContext::new(UserRole::Customer, Some(customer_user_id));
+    let filter = CustomerOrderHistoryFilter {
+        statuses: Vec::new(),
+        limit: Some(2),
+    };
+    let first_page = service
+        .list_customer_orders(&customer, tenant_id, customer_id, filter.clone(), None)
+        .await
+        .unwrap();
+    assert_eq!(first_page.items.len(), 2);
+    assert!(first_page.has_more);
+    assert_eq!(first_page.total, None);
+    assert_eq!(first_page.items[0].item_count, 3);
+    assert_eq!(first_page.items[0].items.len(), 2);
+    let cursor = first_page
+        .next_cursor
+        .expect("first page should have a cursor");
+
+    let second_page = service
+        .list_customer_orders(&customer, tenant_id, customer_id, filter, Some(&cursor))
+        .await
+        .unwrap();
+    assert_eq!(second_page.items.len(), 1);
+    assert!(second_page.next_cursor.is_none());
+    assert!(!second_page.has_more);
+
+    let mut seen = first_page
+        .items
+        .iter()
+        .chain(second_page.items.iter())
+        .map(|order| order.id)
+        .collect::<Vec<_>>();
+    seen.sort();
+    let mut expected = created.iter().map(|order| order.id).collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(seen, expected);
+
+    let admin = SecurityContext::new(UserRole::Admin, Some(Uuid::new_v4()));
+    let confirmed = service
+        .list_customer_orders(
+            &admin,
+            tenant_id,
+            customer_id,
+            CustomerOrderHistoryFilter {
+                statuses: vec!["confirmed".to_string()],
+                limit: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(confirmed.items.len(), 1);
+    assert_eq!(confirmed.items[0].id, created[0].id);
+
+    let stranger = SecurityContext::new(UserRole::Customer, Some(Uuid::new_v4()));
+    let error = service
+        .list_customer_orders(
+            &stranger,
+            tenant_id,
+            customer_id,
+            CustomerOrderHistoryFilter::default(),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, OrderError::Forbidden(_)));
+
+    let error = service
+        .list_customer_orders(
+            &admin,
+            tenant_id,
+            customer_id,
+            CustomerOrderHistoryFilter::default(),
+            Some("not-a-cursor"),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, OrderError::Validation(_)));
+}
+
 #[tokio::test]
 async fn create_list_apply_and_cancel_order_changes() {
     let service = setup().await;
@@ -369,7 +660,7 @@ async fn create_list_apply_and_cancel_order_changes() {
     assert_eq!(created.created_by, actor_id);
     assert!(created.applied_at.is_none());
 
-    let (rows, total) = service
+    let rows = service
         .list_order_changes(
             tenant_id,
             ListOrderChangesInput {
@@ -382,9 +673,9 @@ async fn create_list_apply_and_cancel_order_changes() {
         )
         .await
         .expect("order changes should list");
-    assert_eq!(total, 1);
-    assert_eq!(rows.len(), 1);
-    assert_eq!(rows[0].id, created.id);
+    assert_eq!(rows.total, Some(1));
+    assert_eq!(rows.items.len(), 1);
+    assert_eq!(rows.items[0].id, created.id);
 
     let applied = service
         .apply_order_change(
@@ -553,7 +844,7 @@ async fn create_and_list_order_returns() {
     );
     assert_eq!(created_return.items[0].note, None);
 
-    let (rows, total) = service
+    let rows = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -565,12 +856,12 @@ async fn create_and_list_order_returns() {
         )
         .await
         .expect("returns list should succeed");
-    assert_eq!(total, 1);
-    assert_eq!(rows.len(), 1);
-    assert_eq!(rows[0].id, created_return.id);
-    assert_eq!(rows[0].items.len(), 1);
+    assert_eq!(rows.total, Some(1));
+    assert_eq!(rows.items.len(), 1);
+    assert_eq!(rows.items[0].id, created_return.id);
+    assert_eq!(rows.items[0].items.len(), 1);
     assert_eq!(
-        rows[0].items[0].line_item_id,
+        rows.items[0].items[0].line_item_id,
         created_order.line_items[0].id
     );
 }
@@ -880,7 +1171,7 @@ async fn list_order_returns_clamps_per_page_upper_bound_to_100() {
             .expect("return should be created");
     }
 
-    let (rows, total) = service
+    let rows = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -893,8 +1184,12 @@ async fn list_order_returns_clamps_per_page_upper_bound_to_100() {
         .await
         .expect("per_page upper bound should clamp");
 
-    assert_eq!(total, 101);
-    assert_eq!(rows.len(), 100, "per_page > 100 should clamp to 100 rows");
+    assert_eq!(rows.total, Some(101));
+    assert_eq!(
+        rows.items.len(),
+        100,
+        "per_page > 100 should clamp to 100 rows"
+    );
 }
 
 #[tokio::test]
@@ -936,7 +1231,7 @@ async fn list_order_returns_clamps_pagination_bounds() {
         .await
         .expect("second return should be created");
 
-    let (rows_page1, total_page1) = service
+    let rows_page1 = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -949,10 +1244,10 @@ async fn list_order_returns_clamps_pagination_bounds() {
         .await
         .expect("page/per_page lower bound should clamp");
 
-    assert_eq!(total_page1, 2);
-    assert_eq!(rows_page1.len(), 1, "per_page=0 should clamp to 1");
+    assert_eq!(rows_page1.total, Some(2));
+    assert_eq!(rows_page1.items.len(), 1, "per_page=0 should clamp to 1");
 
-    let (rows_page2, total_page2) = service
+    let rows_page2 = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -965,9 +1260,9 @@ async fn list_order_returns_clamps_pagination_bounds() {
         .await
         .expect("second page should resolve");
 
-    assert_eq!(total_page2, 2);
-    assert_eq!(rows_page2.len(), 1);
-    assert_ne!(rows_page1[0].id, rows_page2[0].id);
+    assert_eq!(rows_page2.total, Some(2));
+    assert_eq!(rows_page2.items.len(), 1);
+    assert_ne!(rows_page1.items[0].id, rows_page2.items[0].id);
 }
 
 #[tokio::test]
@@ -995,7 +1290,7 @@ async fn list_order_returns_ignores_blank_status_filter() {
         .await
         .expect("return should be created");
 
-    let (rows, total) = service
+    let rows = service
         .list_returns(
             tenant_id,
             ListOrderReturnsInput {
@@ -1008,9 +1303,9 @@ async fn list_order_returns_ignores_blank_status_filter() {
         .await
         .expect("returns list should ignore blank status filter");
 
-    assert_eq!(total, 1);
-    assert_eq!(rows.len(), 1);
-    assert_eq!(rows[0].id, created_return.id);
+    assert_eq!(rows.total, Some(1));
+    assert_eq!(rows.items.len(), 1);
+    assert_eq!(rows.items[0].id, created_return.id);
 }
 
 #[tokio::test]
@@ -1057,7 +1352,7 @@ async fn list_order_returns_applies_status_trim_and_tenant_isolation() {
         .await
         .expect("tenant B return should be created");
 
-    let (rows, total) = service
+    let rows = service
         .list_returns(
             tenant_a,
             ListOrderReturnsInput {
@@ -1070,10 +1365,10 @@ async fn list_order_returns_applies_status_trim_and_tenant_isolation() {
         .await
         .expect("tenant A filtered returns should load");
 
-    assert_eq!(total, 1);
-    assert_eq!(rows.len(), 1);
-    assert_eq!(rows[0].id, return_a.id);
-    assert_eq!(rows[0].order_id, order_a.id);
+    assert_eq!(rows.total, Some(1));
+    assert_eq!(rows.items.len(), 1);
+    assert_eq!(rows.items[0].id, return_a.id);
+    assert_eq!(rows.items[0].order_id, order_a.id);
 }
 
 #[tokio::test]
@@ -1,6 +1,6 @@
 use rustok_order::entities::{
     order, order_adjustment, order_change, order_line_item, order_line_item_translation,
-    order_return, order_return_item, order_tax_line,
+    order_return, order_return_item, order_tax_line, sequence,
 };
 use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Schema};
 
@@ -36,7 +36,60 @@ pub async fn ensure_order_schema(db: &DatabaseConnection) {
         .await
         .expect("tenants table should be created for locale resolution");
 
+    let tenant_modules_table = sea_orm::sea_query::Table::create()
+        .table(sea_orm::sea_query::Alias::new("tenant_modules"))
+        .if_not_exists()
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("tenant_id"))
+                .uuid()
+                .not_null(),
+        )
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("module_slug"))
+                .string_len(64)
+                .not_null(),
+        )
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("settings"))
+                .json()
+                .not_null(),
+        )
+        .to_owned();
+    db.execute(builder.build(&tenant_modules_table))
+        .await
+        .expect("tenant_modules table should be created for order number settings");
+
+    let customers_table = sea_orm::sea_query::Table::create()
+        .table(sea_orm::sea_query::Alias::new("customers"))
+        .if_not_exists()
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("id"))
+                .uuid()
+                .not_null()
+                .primary_key(),
+        )
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("tenant_id"))
+                .uuid()
+                .not_null(),
+        )
+        .col(
+            sea_orm::sea_query::ColumnDef::new(sea_orm::sea_query::Alias::new("user_id"))
+                .uuid()
+                .null(),
+        )
+        .to_owned();
+    db.execute(builder.build(&customers_table))
+        .await
+        .expect("customers table should be created for order history ownership checks");
+
     create_entity_table(db, &builder, schema.create_table_from_entity(order::Entity)).await;
+    create_entity_table(
+        db,
+        &builder,
+        schema.create_table_from_entity(sequence::Entity),
+    )
+    .await;
     create_entity_table(
         db,
         &builder,
@@ -0,0 +1,176 @@
+//! Tenant activity feed read back from `sys_events`.
+//!
+//! The outbox stores the whole envelope as JSON, so tenant and actor come
+//! from the payload. The tenant lookup goes through the expression index
+//! created by [`create_tenant_activity_index`]; the statement here must keep
+//! the indexed expression verbatim or the planner falls back to a scan.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, Statement};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+
+const TENANT_ACTIVITY_INDEX: &str = "idx_sys_events_tenant_created_at";
+
+/// One row of the tenant's event log as the activity feed sees it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub event_type: String,
+    pub actor_id: Option<Uuid>,
+    /// `kind:id` of the entity the event is about, see [`activity_target`].
+    pub target: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Newest `limit` events for `tenant_id`.
+pub fn audit_entries_statement(backend: DbBackend, tenant_id: Uuid, limit: i64) -> Statement {
+    let tenant_id = tenant_id.to_string();
+    match backend {
+        DbBackend::Sqlite => Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT id, event_type, payload, created_at
+            FROM sys_events
+            WHERE json_extract(payload, '$.tenant_id') = ?1
+            ORDER BY created_at DESC
+            LIMIT ?2
+            "#,
+            vec![tenant_id.into(), limit.into()],
+        ),
+        _ => Statement::from_sql_and_values(
+            backend,
+            r#"
+            SELECT id, event_type, payload, created_at
+            FROM sys_events
+            WHERE payload->>'tenant_id' = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+            vec![tenant_id.into(), limit.into()],
+        ),
+    }
+}
+
+/// Load the newest `limit` events for `tenant_id`, newest first.
+pub async fn load_audit_entries(
+    db: &impl ConnectionTrait,
+    tenant_id: Uuid,
+    limit: i64,
+) -> Result<Vec<AuditEntry>, DbErr> {
+    let statement = audit_entries_statement(db.get_database_backend(), tenant_id, limit);
+    let rows = db.query_all(statement).await?;
+    rows.into_iter()
+        .map(|row| {
+            let event_type: String = row.try_get("", "event_type")?;
+            let payload: serde_json::Value = row.try_get("", "payload")?;
+            let actor_id = payload
+                .get("actor_id")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|value| Uuid::parse_str(value).ok());
+            let target = payload
+                .pointer("/event/data")
+                .and_then(|data| activity_target(&event_type, data));
+
+            Ok(AuditEntry {
+                id: row.try_get("", "id")?,
+                event_type,
+                actor_id,
+                target,
+                created_at: row.try_get("", "created_at")?,
+            })
+        })
+        .collect()
+}
+
+/// `kind:id` of the entity an event is about. Prefers the id field named
+/// after the event's subject (`node_id` for `node.updated`), then any `*_id`.
+pub fn activity_target(event_type: &str, data: &serde_json::Value) -> Option<String> {
+    let fields = data.as_object()?;
+    let subject = event_type.split('.').next().unwrap_or_default();
+    let preferred = format!("{subject}_id");
+    let (key, value) = fields
+        .get_key_value(preferred.as_str())
+        .or_else(|| fields.iter().find(|(key, _)| key.ends_with("_id")))?;
+    let id = value.as_str()?;
+    Some(format!("{}:{id}", key.trim_end_matches("_id")))
+}
+
+/// Sentence-case label for an event type, e.g. `Node status changed` for
+/// `node.status_changed`.
+pub fn describe_event_type(event_type: &str) -> String {
+    let words = event_type.replace(['.', '_'], " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Index `sys_events` by the payload's tenant and `created_at`, matching
+/// [`audit_entries_statement`]. Other backends are left unindexed.
+pub async fn create_tenant_activity_index(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    let sql = match manager.get_database_backend() {
+        DbBackend::Postgres => format!(
+            "CREATE INDEX IF NOT EXISTS {TENANT_ACTIVITY_INDEX} \
+             ON sys_events ((payload->>'tenant_id'), created_at DESC)"
+        ),
+        DbBackend::Sqlite => format!(
+            "CREATE INDEX IF NOT EXISTS {TENANT_ACTIVITY_INDEX} \
+             ON sys_events (json_extract(payload, '$.tenant_id'), created_at DESC)"
+        ),
+        _ => return Ok(()),
+    };
+    manager.get_connection().execute_unprepared(&sql).await?;
+    Ok(())
+}
+
+pub async fn drop_tenant_activity_index(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    if !matches!(
+        manager.get_database_backend(),
+        DbBackend::Postgres | DbBackend::Sqlite
+    ) {
+        return Ok(());
+    }
+    manager
+        .get_connection()
+        .execute_unprepared(&format!("DROP INDEX IF EXISTS {TENANT_ACTIVITY_INDEX}"))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{activity_target, describe_event_type};
+
+    #[test]
+    fn describe_event_type_reads_as_sentence() {
+        assert_eq!(
+            describe_event_type("node.status_changed"),
+            "Node status changed"
+        );
+        assert_eq!(describe_event_type(""), "");
+    }
+
+    #[test]
+    fn activity_target_prefers_subject_id() {
+        let data = serde_json::json!({
+            "author_id": "a1",
+            "node_id": "n1",
+            "kind": "post",
+        });
+
+        assert_eq!(
+            activity_target("node.created", &data).as_deref(),
+            Some("node:n1")
+        );
+        assert_eq!(
+            activity_target("content.published", &data).as_deref(),
+            Some("author:a1")
+        );
+        assert_eq!(
+            activity_target("node.created", &serde_json::json!({})),
+            None
+        );
+    }
+}
@@ -2,12 +2,14 @@ use async_trait::async_trait;
 use rustok_core::module::{HealthStatus, MigrationSource, ModuleKind, RusToKModule};
 use sea_orm_migration::MigrationTrait;
 
+pub mod audit;
 pub mod entity;
 pub mod migration;
 pub mod relay;
 pub mod transactional;
 pub mod transport;
 
+pub use audit::{describe_event_type, load_audit_entries, AuditEntry};
 pub use entity::{Entity as SysEvents, Model as SysEvent};
 pub use migration::SysEventsMigration;
 pub use relay::{OutboxRelay, RelayConfig, RelayMetricsSnapshot};
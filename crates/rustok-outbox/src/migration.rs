@@ -70,7 +70,9 @@ impl MigrationTrait for SysEventsMigration {
                     .col(SysEvents::ClaimedAt)
                     .to_owned(),
             )
-            .await
+            .await?;
+
+        crate::audit::create_tenant_activity_index(manager).await
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
@@ -1,4 +1,3 @@
-use std::cmp;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -8,12 +7,12 @@ use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
     QueryOrder, QuerySelect, Set, TransactionTrait,
 };
-use serde_json::from_value;
+use serde::Serialize;
 use uuid::Uuid;
 
 use rustok_core::events::EventTransport;
-use rustok_core::{Error, Result};
-use rustok_events::EventEnvelope;
+use rustok_core::{BackoffPolicy, Error, Result};
+use rustok_events::{decode_envelope, EventEnvelope};
 
 use crate::entity;
 use crate::entity::SysEventStatus;
@@ -49,7 +48,7 @@ struct RelayMetrics {
     processed_total: AtomicU64,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize)]
 pub struct RelayMetricsSnapshot {
     pub success_total: u64,
     pub failure_total: u64,
@@ -181,7 +180,21 @@ impl OutboxRelay {
     async fn process_claimed_event(&self, model: &entity::Model) -> Result<()> {
         let started = Instant::now();
         let event_id = model.id;
-        let envelope: EventEnvelope = from_value(model.payload.clone())?;
+        let envelope: EventEnvelope = match decode_envelope(model.payload.clone()) {
+            Ok(envelope) => envelope,
+            Err(error) => {
+                tracing::error!(
+                    event_id = %event_id,
+                    event_type = %model.event_type,
+                    error = %error,
+                    "Outbox payload is not a valid event envelope"
+                );
+                self.metrics.failure_total.fetch_add(1, Ordering::Relaxed);
+                return self
+                    .mark_failed_attempt(model, Error::from(serde_json::Error::from(error)))
+                    .await;
+            }
+        };
 
         let publish_result = self.target.publish(envelope).await;
         let elapsed_ms = started.elapsed().as_millis() as u64;
@@ -198,7 +211,12 @@ impl OutboxRelay {
                 Ok(())
             }
             Err(err) => {
-                tracing::warn!(event_id = %event_id, error = %err, "Outbox event dispatch failed");
+                tracing::warn!(
+                    event_id = %event_id,
+                    event_type = %model.event_type,
+                    error = %err,
+                    "Outbox event dispatch failed"
+                );
                 self.metrics.failure_total.fetch_add(1, Ordering::Relaxed);
                 self.mark_failed_attempt(model, err).await
             }
@@ -234,10 +252,19 @@ impl OutboxRelay {
         active.claimed_by = Set(None);
         active.claimed_at = Set(None);
 
-        if retry_count >= self.config.max_attempts {
+        // A payload that cannot be (de)serialized fails the same way on every
+        // attempt; retrying it would only stall the rows behind it.
+        let retryable = !matches!(error, Error::Serialization(_));
+        if !retryable || retry_count >= self.config.max_attempts {
             active.status = Set(SysEventStatus::Failed);
             active.next_attempt_at = Set(None);
-            tracing::error!(event_id = %model.id, retry_count, "Outbox event moved to DLQ (failed)");
+            tracing::error!(
+                event_id = %model.id,
+                event_type = %model.event_type,
+                retry_count,
+                retryable,
+                "Outbox event moved to DLQ (failed)"
+            );
             self.metrics.dlq_total.fetch_add(1, Ordering::Relaxed);
         } else {
             let next_attempt_at = Utc::now() + self.backoff_duration(retry_count);
@@ -257,11 +284,12 @@ impl OutboxRelay {
     }
 
     fn backoff_duration(&self, retry_count: i32) -> chrono::Duration {
-        let attempt = retry_count.saturating_sub(1) as u32;
-        let factor = 2u128.pow(cmp::min(attempt, 16));
-        let millis = self.config.backoff_base.as_millis().saturating_mul(factor);
-        let max_ms = self.config.backoff_max.as_millis();
-        let bounded = cmp::min(millis, max_ms) as i64;
-        chrono::Duration::milliseconds(bounded)
+        let policy = BackoffPolicy::Exponential {
+            base: self.config.backoff_base,
+            max: self.config.backoff_max,
+            multiplier: 2.0,
+        };
+        let delay = policy.delay_for_attempt(retry_count.max(1) as u32);
+        chrono::Duration::milliseconds(delay.as_millis() as i64)
     }
 }
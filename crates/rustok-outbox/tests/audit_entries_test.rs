@@ -0,0 +1,101 @@
+use rustok_core::events::EventTransport;
+use rustok_events::{DomainEvent, EventEnvelope};
+use rustok_outbox::audit::audit_entries_statement;
+use rustok_outbox::{load_audit_entries, OutboxTransport, SysEventsMigration};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
+use sea_orm_migration::prelude::SchemaManager;
+use sea_orm_migration::MigrationTrait;
+use uuid::Uuid;
+
+async fn setup_test_db() -> DatabaseConnection {
+    let db_url = format!(
+        "sqlite:file:outbox_audit_{}?mode=memory&cache=shared",
+        Uuid::new_v4()
+    );
+    let mut opts = ConnectOptions::new(db_url);
+    opts.max_connections(1)
+        .min_connections(1)
+        .sqlx_logging(false);
+
+    let db = Database::connect(opts)
+        .await
+        .expect("Failed to connect test sqlite database");
+
+    let schema_manager = SchemaManager::new(&db);
+    SysEventsMigration
+        .up(&schema_manager)
+        .await
+        .expect("Failed to run outbox migration");
+
+    db
+}
+
+fn node_created(tenant_id: Uuid, actor_id: Option<Uuid>, node_id: Uuid) -> EventEnvelope {
+    EventEnvelope::new(
+        tenant_id,
+        actor_id,
+        DomainEvent::NodeCreated {
+            node_id,
+            kind: "post".to_string(),
+            author_id: None,
+        },
+    )
+}
+
+#[tokio::test]
+async fn loads_only_the_tenants_events_newest_first() {
+    let db = setup_test_db().await;
+    let transport = OutboxTransport::new(db.clone());
+    let tenant_id = Uuid::new_v4();
+    let actor_id = Uuid::new_v4();
+    let first_node = Uuid::new_v4();
+    let second_node = Uuid::new_v4();
+
+    transport
+        .publish(node_created(tenant_id, Some(actor_id), first_node))
+        .await
+        .unwrap();
+    transport
+        .publish(node_created(Uuid::new_v4(), None, Uuid::new_v4()))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    transport
+        .publish(node_created(tenant_id, None, second_node))
+        .await
+        .unwrap();
+
+    let entries = load_audit_entries(&db, tenant_id, 10).await.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].target, Some(format!("node:{second_node}")));
+    assert_eq!(entries[0].actor_id, None);
+    assert_eq!(entries[1].target, Some(format!("node:{first_node}")));
+    assert_eq!(entries[1].actor_id, Some(actor_id));
+    assert_eq!(entries[1].event_type, "node.created");
+
+    let limited = load_audit_entries(&db, tenant_id, 1).await.unwrap();
+    assert_eq!(limited, entries[..1]);
+}
+
+#[tokio::test]
+async fn tenant_lookup_uses_the_activity_index() {
+    let db = setup_test_db().await;
+    let statement = audit_entries_statement(db.get_database_backend(), Uuid::new_v4(), 20);
+    let plan = db
+        .query_all(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            format!("EXPLAIN QUERY PLAN {}", statement.sql),
+            statement.values.map(|values| values.0).unwrap_or_default(),
+        ))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| row.try_get::<String>("", "detail").unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        plan.contains("idx_sys_events_tenant_created_at"),
+        "unexpected plan: {plan}"
+    );
+}
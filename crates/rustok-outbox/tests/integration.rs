@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::OnceLock;
 
@@ -22,6 +22,7 @@ use uuid::Uuid;
 struct MockTransport {
     delivered: Mutex<Vec<Uuid>>,
     remaining_failures: Mutex<HashMap<Uuid, usize>>,
+    unserializable: Mutex<HashSet<Uuid>>,
 }
 
 impl MockTransport {
@@ -29,6 +30,10 @@ impl MockTransport {
         self.remaining_failures.lock().await.insert(event_id, n);
     }
 
+    async fn fail_serialization_for(&self, event_id: Uuid) {
+        self.unserializable.lock().await.insert(event_id);
+    }
+
     async fn delivered(&self) -> Vec<Uuid> {
         self.delivered.lock().await.clone()
     }
@@ -37,6 +42,11 @@ impl MockTransport {
 #[async_trait]
 impl EventTransport for MockTransport {
     async fn publish(&self, envelope: EventEnvelope) -> Result<()> {
+        if self.unserializable.lock().await.contains(&envelope.id) {
+            let error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+            return Err(Error::Serialization(error));
+        }
+
         let mut remaining_failures = self.remaining_failures.lock().await;
         if let Some(remaining) = remaining_failures.get_mut(&envelope.id) {
             if *remaining > 0 {
@@ -183,6 +193,44 @@ async fn relay_moves_to_dlq_on_max_retry() -> TestResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn relay_fails_unserializable_event_without_retry() -> TestResult<()> {
+    let _guard = test_lock().lock().await;
+
+    let Some(db) = setup_db().await? else {
+        return Ok(());
+    };
+    let envelope = seed_event(&db).await?;
+    let transport = Arc::new(MockTransport::default());
+    transport.fail_serialization_for(envelope.id).await;
+
+    let relay = OutboxRelay::new(db.clone(), transport).with_config(RelayConfig {
+        batch_size: 10,
+        max_attempts: 5,
+        ..Default::default()
+    });
+
+    let processed = relay.process_pending_once().await?;
+    assert_eq!(processed, 1);
+
+    let record = entity::Entity::find_by_id(envelope.id)
+        .one(&db)
+        .await?
+        .expect("event record");
+    assert_eq!(record.status, SysEventStatus::Failed);
+    assert_eq!(record.retry_count, 1);
+    assert!(record.next_attempt_at.is_none());
+    assert!(record
+        .last_error
+        .as_deref()
+        .is_some_and(|error| error.starts_with("Serialization error")));
+
+    let metrics = relay.metrics();
+    assert_eq!(metrics.dlq_total, 1);
+    assert_eq!(metrics.retry_total, 0);
+    Ok(())
+}
+
 async fn setup_db() -> TestResult<Option<DatabaseConnection>> {
     let database_url = match std::env::var("RUSTOK_OUTBOX_TEST_DATABASE_URL")
         .or_else(|_| std::env::var("DATABASE_URL"))
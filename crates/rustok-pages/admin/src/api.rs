@@ -10,6 +10,7 @@ pub type ApiError = GraphqlHttpError;
 
 const PAGES_QUERY: &str = "query PagesAdmin($filter: ListGqlPagesFilter) { pages(filter: $filter) { total items { id status template title slug updatedAt } } }";
 const PAGE_QUERY: &str = "query PageAdmin($id: UUID!) { page(id: $id) { id status template channelSlugs translation { locale title slug } body { locale content format contentJson updatedAt } blocks { id blockType position } } }";
+const PAGE_LAYOUTS_QUERY: &str = "query PageLayoutsAdmin { pageLayouts }";
 const CREATE_PAGE_MUTATION: &str = "mutation CreatePage($input: CreateGqlPageInput!) { createPage(input: $input) { id status updatedAt translation { locale title slug } } }";
 const UPDATE_PAGE_MUTATION: &str = "mutation UpdatePage($id: UUID!, $input: UpdateGqlPageInput!) { updatePage(id: $id, input: $input) { id status updatedAt translation { locale title slug } } }";
 const PUBLISH_PAGE_MUTATION: &str =
@@ -23,6 +24,12 @@ struct PagesResponse {
     pages: PageList,
 }
 
+#[derive(Debug, Deserialize)]
+struct PageLayoutsResponse {
+    #[serde(rename = "pageLayouts")]
+    page_layouts: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct CreatePageResponse {
     #[serde(rename = "createPage")]
@@ -196,6 +203,15 @@ pub async fn fetch_page(
     Ok(response.page)
 }
 
+pub async fn fetch_page_layouts(
+    token: Option<String>,
+    tenant_slug: Option<String>,
+) -> Result<Vec<String>, ApiError> {
+    let response: PageLayoutsResponse =
+        request(PAGE_LAYOUTS_QUERY, Value::Null, token, tenant_slug).await?;
+    Ok(response.page_layouts)
+}
+
 pub async fn create_page(
     token: Option<String>,
     tenant_slug: Option<String>,
@@ -51,9 +51,33 @@ pub struct PageDraftFormInput<'a> {
     pub title: &'a str,
     pub slug: &'a str,
     pub channel_slugs: &'a str,
+    pub template: &'a str,
     pub publish: bool,
 }
 
+/// Layout preselected for new pages; matches the server-side default.
+pub const DEFAULT_PAGE_LAYOUT: &str = "default";
+
+pub fn default_page_layout() -> String {
+    DEFAULT_PAGE_LAYOUT.to_string()
+}
+
+/// Dropdown options: tenant layouts plus the current value, so editing a page
+/// with a layout that was since removed from settings does not silently reset it.
+pub fn layout_options(available: &[String], current: &str) -> Vec<String> {
+    let mut options = if available.is_empty() {
+        vec![default_page_layout()]
+    } else {
+        available.to_vec()
+    };
+    if let Some(current) = optional_ui_text(current) {
+        if !options.contains(&current) {
+            options.push(current);
+        }
+    }
+    options
+}
+
 pub fn build_create_page_draft(
     input: PageDraftFormInput<'_>,
     project_data: Value,
@@ -65,7 +89,7 @@ pub fn build_create_page_draft(
         body_content: String::new(),
         body_format: GRAPESJS_FORMAT.to_string(),
         body_content_json: project_data,
-        template: Some("default".to_string()),
+        template: Some(optional_ui_text(input.template).unwrap_or_else(default_page_layout)),
         channel_slugs: parse_channel_slugs(input.channel_slugs),
         publish: input.publish,
     }
@@ -236,6 +260,7 @@ pub struct EditFormSeed {
     pub body_format: String,
     pub body_updated_at: Option<String>,
     pub existing_blocks: Vec<PageBlock>,
+    pub template: String,
 }
 
 pub fn edit_form_seed_from_page(page: &PageDetail, default_locale: &str) -> EditFormSeed {
@@ -277,6 +302,7 @@ pub fn edit_form_seed_from_page(page: &PageDetail, default_locale: &str) -> Edit
         body_format,
         body_updated_at: page.body.as_ref().map(|body| body.updated_at.clone()),
         existing_blocks: page.blocks.clone(),
+        template: page.template.clone(),
     }
 }
 
@@ -489,6 +515,7 @@ pub fn empty_edit_form_seed(default_locale: &str) -> EditFormSeed {
         body_format: GRAPESJS_FORMAT.to_string(),
         body_updated_at: None,
         existing_blocks: Vec::new(),
+        template: default_page_layout(),
     }
 }
 
@@ -526,6 +553,7 @@ mod tests {
                 title: " Landing ",
                 slug: " landing-page ",
                 channel_slugs: " web, MOBILE, web ",
+                template: " ",
                 publish: true,
             },
             project_data.clone(),
@@ -553,6 +581,7 @@ mod tests {
                 title: " ",
                 slug: "landing",
                 channel_slugs: "web",
+                template: "landing",
                 publish: false,
             },
             default_project_data(""),
@@ -700,6 +729,7 @@ mod tests {
         assert_eq!(seed.body_format, GRAPESJS_FORMAT);
         assert!(seed.body_updated_at.is_none());
         assert!(seed.existing_blocks.is_empty());
+        assert_eq!(seed.template, DEFAULT_PAGE_LAYOUT);
     }
 
     #[test]
@@ -741,6 +771,23 @@ mod tests {
             Some("2026-05-23T10:30:00Z")
         );
         assert_eq!(seed.existing_blocks.len(), 1);
+        assert_eq!(seed.template, "default");
+    }
+
+    #[test]
+    fn layout_options_keep_current_layout_selectable() {
+        let available = vec!["default".to_string(), "landing".to_string()];
+
+        assert_eq!(layout_options(&available, "landing"), available);
+        assert_eq!(
+            layout_options(&available, "retired"),
+            vec![
+                "default".to_string(),
+                "landing".to_string(),
+                "retired".to_string()
+            ]
+        );
+        assert_eq!(layout_options(&[], " "), vec!["default".to_string()]);
     }
 
     #[test]
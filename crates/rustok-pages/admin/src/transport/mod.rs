@@ -18,6 +18,13 @@ pub async fn fetch_page(
     api::fetch_page(token, tenant_slug, id).await
 }
 
+pub async fn fetch_page_layouts(
+    token: Option<String>,
+    tenant_slug: Option<String>,
+) -> Result<Vec<String>, TransportError> {
+    api::fetch_page_layouts(token, tenant_slug).await
+}
+
 pub async fn create_page(
     token: Option<String>,
     tenant_slug: Option<String>,
@@ -314,6 +314,7 @@ pub fn PagesAdmin() -> impl IntoView {
     let (body_format, set_body_format) = signal(core::GRAPESJS_FORMAT.to_string());
     let (body_updated_at, set_body_updated_at) = signal(Option::<String>::None);
     let (existing_blocks, set_existing_blocks) = signal(Vec::<PageBlock>::new());
+    let (template, set_template) = signal(core::default_page_layout());
     let (busy_key, set_busy_key) = signal(Option::<String>::None);
     let (submit_issue, set_submit_issue) = signal(Option::<WritePathIssue>::None);
 
@@ -331,6 +332,7 @@ pub fn PagesAdmin() -> impl IntoView {
                 set_body_format,
                 set_body_updated_at,
                 set_existing_blocks,
+                set_template,
                 default_locale.as_str(),
             )
         }
@@ -369,6 +371,15 @@ pub fn PagesAdmin() -> impl IntoView {
         },
     );
 
+    let layouts_resource = local_resource(
+        move || (token.get(), tenant.get()),
+        move |(token_value, tenant_value)| async move {
+            transport::fetch_page_layouts(token_value, tenant_value)
+                .await
+                .unwrap_or_default()
+        },
+    );
+
     let edit_default_locale = default_locale.clone();
     let edit_page = Callback::new(move |page_id: String| {
         let token_value = token.get_untracked();
@@ -394,6 +405,7 @@ pub fn PagesAdmin() -> impl IntoView {
                     set_body_format.set(seed.body_format);
                     set_body_updated_at.set(seed.body_updated_at);
                     set_existing_blocks.set(seed.existing_blocks);
+                    set_template.set(seed.template);
                 }
                 Ok(None) => {
                     reset_page_form(
@@ -407,6 +419,7 @@ pub fn PagesAdmin() -> impl IntoView {
                         set_body_format,
                         set_body_updated_at,
                         set_existing_blocks,
+                        set_template,
                         default_locale.as_str(),
                     );
                     set_submit_issue.set(Some(WritePathIssue::new(page_not_found_text.clone())));
@@ -423,6 +436,7 @@ pub fn PagesAdmin() -> impl IntoView {
                         set_body_format,
                         set_body_updated_at,
                         set_existing_blocks,
+                        set_template,
                         default_locale.as_str(),
                     );
                     set_submit_issue.set(Some(core::write_path_issue_with_context(
@@ -452,6 +466,7 @@ pub fn PagesAdmin() -> impl IntoView {
             set_body_format,
             set_body_updated_at,
             set_existing_blocks,
+            set_template,
             effect_default_locale.as_str(),
         ),
     });
@@ -474,12 +489,14 @@ pub fn PagesAdmin() -> impl IntoView {
         let title_value = title.get_untracked();
         let slug_value = slug.get_untracked();
         let channel_slugs_value = channel_slugs_text.get_untracked();
+        let template_value = template.get_untracked();
         let draft = core::build_create_page_draft(
             core::PageDraftFormInput {
                 locale: &locale_value,
                 title: &title_value,
                 slug: &slug_value,
                 channel_slugs: &channel_slugs_value,
+                template: &template_value,
                 publish: publish_now.get_untracked(),
             },
             project_data,
@@ -803,7 +820,27 @@ pub fn PagesAdmin() -> impl IntoView {
                                     <dt class="font-medium text-card-foreground">{body_format_label.clone()}</dt>
                                     <dd>{move || body_format.get()}</dd>
                                     <dt class="font-medium text-card-foreground">{template_label.clone()}</dt>
-                                    <dd>{"default"}</dd>
+                                    <dd>
+                                        <select
+                                            class="w-full rounded border border-input bg-background px-2 py-1 text-xs text-card-foreground"
+                                            prop:value=move || template.get()
+                                            on:change=move |ev| set_template.set(event_target_value(&ev))
+                                        >
+                                            {move || {
+                                                let available = layouts_resource.get().unwrap_or_default();
+                                                let current = template.get();
+                                                core::layout_options(&available, &current)
+                                                    .into_iter()
+                                                    .map(|layout| {
+                                                        let selected = layout == current;
+                                                        view! {
+                                                            <option value=layout.clone() selected=selected>{layout.clone()}</option>
+                                                        }
+                                                    })
+                                                    .collect_view()
+                                            }}
+                                        </select>
+                                    </dd>
                                     <dt class="font-medium text-card-foreground">{channels_count_label.clone()}</dt>
                                     <dd>{move || core::parse_channel_slugs(&channel_slugs_text.get()).len().to_string()}</dd>
                                     <dt class="font-medium text-card-foreground">{locale_property_label.clone()}</dt>
@@ -1263,6 +1300,7 @@ fn reset_page_form(
     set_body_format: WriteSignal<String>,
     set_body_updated_at: WriteSignal<Option<String>>,
     set_existing_blocks: WriteSignal<Vec<PageBlock>>,
+    set_template: WriteSignal<String>,
     default_locale: &str,
 ) {
     let seed = core::empty_edit_form_seed(default_locale);
@@ -1276,4 +1314,5 @@ fn reset_page_form(
     set_body_format.set(seed.body_format);
     set_body_updated_at.set(seed.body_updated_at);
     set_existing_blocks.set(seed.existing_blocks);
+    set_template.set(seed.template);
 }
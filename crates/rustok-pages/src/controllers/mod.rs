@@ -5,17 +5,18 @@ use axum::{
 };
 use loco_rs::{app::AppContext, controller::Routes, Error, Result};
 use rustok_api::{
-    has_any_effective_permission, loco::transactional_event_bus_from_context, AuthContext,
-    RequestContext, TenantContext,
+    has_any_effective_permission, loco::transactional_event_bus_from_context, ApiError,
+    AuthContext, RequestContext, TenantContext,
 };
-use rustok_core::{Action, Permission, Resource};
+use rustok_content::MetadataVisibility;
+use rustok_core::{Action, Permission, Resource, RichError};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    BlockResponse, BlockService, CreateBlockInput, CreatePageInput, PageResponse, PageService,
-    UpdateBlockInput, UpdatePageInput,
+    BlockResponse, BlockService, CreateBlockInput, CreatePageInput, LayoutRegistry, PageResponse,
+    PageService, PagesError, UpdateBlockInput, UpdatePageInput,
 };
 
 #[derive(Debug, Deserialize, IntoParams, ToSchema)]
@@ -55,7 +56,8 @@ pub async fn get_page(
         .locale
         .unwrap_or_else(|| request_context.locale.clone());
 
-    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
     let page = service
         .get_by_slug_with_locale_fallback(
             tenant.id,
@@ -65,7 +67,7 @@ pub async fn get_page(
             Some(tenant.default_locale.as_str()),
         )
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
 
     match page {
         Some(page) => Ok(Json(page)),
@@ -96,11 +98,12 @@ pub async fn create_page(
         ensure_pages_permission(&auth, Permission::new(Resource::Pages, Action::Publish))?;
     }
 
-    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_layout_registry(LayoutRegistry::from_tenant_settings(&tenant.settings));
     let page = service
         .create(tenant.id, auth.security_context(), input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok((StatusCode::CREATED, Json(page)))
 }
 
@@ -129,11 +132,12 @@ pub async fn update_page(
         ensure_pages_permission(&auth, Permission::new(Resource::Pages, Action::Publish))?;
     }
 
-    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx));
+    let service = PageService::new(ctx.db.clone(), transactional_event_bus_from_context(&ctx))
+        .with_layout_registry(LayoutRegistry::from_tenant_settings(&tenant.settings));
     let page = service
         .update(tenant.id, auth.security_context(), id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok(Json(page))
 }
 
@@ -160,7 +164,7 @@ pub async fn delete_page(
     service
         .delete(tenant.id, auth.security_context(), id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -190,7 +194,7 @@ pub async fn create_block(
     let block = service
         .create(tenant.id, auth.security_context(), id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok((StatusCode::CREATED, Json(block)))
 }
 
@@ -224,7 +228,7 @@ pub async fn update_block(
     let block = service
         .update(tenant.id, auth.security_context(), block_id, input)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok(Json(block))
 }
 
@@ -255,7 +259,7 @@ pub async fn delete_block(
     service
         .delete(tenant.id, auth.security_context(), block_id)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -285,7 +289,7 @@ pub async fn reorder_blocks(
     service
         .reorder(tenant.id, auth.security_context(), id, input.block_ids)
         .await
-        .map_err(|err| Error::BadRequest(err.to_string()))?;
+        .map_err(pages_http_error)?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -321,3 +325,12 @@ fn ensure_pages_permission(auth: &AuthContext, permission: Permission) -> Result
 
     Ok(())
 }
+
+/// Map a pages service error to an HTTP error with the status of its
+/// [`RichError`] kind and the stable [`rustok_core::CodedError`] code.
+fn pages_http_error(err: PagesError) -> Error {
+    let api_error = ApiError::from_error(&err);
+    let rich: RichError = err.into();
+    let status = StatusCode::from_u16(rich.status_code).unwrap_or(StatusCode::BAD_REQUEST);
+    api_error.into_loco_error(status)
+}
@@ -10,5 +10,5 @@ pub use block::{
 pub use menu::{CreateMenuInput, MenuItemInput, MenuItemResponse, MenuLocation, MenuResponse};
 pub use page::{
     CreatePageInput, ListPagesFilter, PageBodyInput, PageBodyResponse, PageListItem, PageResponse,
-    PageTranslationInput, PageTranslationResponse, UpdatePageInput,
+    PageTranslationInput, PageTranslationResponse, UpdatePageInput, PAGE_METADATA_RESERVED_KEYS,
 };
@@ -16,8 +16,19 @@ pub struct CreatePageInput {
     pub channel_slugs: Option<Vec<String>>,
     #[serde(default)]
     pub publish: bool,
+    /// Caller-owned metadata; must be a JSON object when present.
+    /// See [`PAGE_METADATA_RESERVED_KEYS`] for keys the service manages.
+    #[serde(default)]
+    pub metadata: Option<Value>,
 }
 
+/// Top-level page metadata keys owned by `PageService`.
+///
+/// Stored page metadata is always a JSON object: caller-supplied keys plus
+/// `template` (the effective template) and `seo` (per-locale meta title and
+/// description). Caller values for these keys are overwritten.
+pub const PAGE_METADATA_RESERVED_KEYS: &[&str] = &["template", "seo"];
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PageTranslationInput {
     pub locale: String,
@@ -42,6 +53,10 @@ pub struct UpdatePageInput {
     pub body: Option<PageBodyInput>,
     pub channel_slugs: Option<Vec<String>>,
     pub status: Option<ContentStatus>,
+    /// Keys shallow-merged into the stored metadata object; must be a JSON
+    /// object when present.
+    #[serde(default)]
+    pub metadata: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema, utoipa::IntoParams)]
@@ -49,6 +64,9 @@ pub struct ListPagesFilter {
     pub status: Option<ContentStatus>,
     pub template: Option<String>,
     pub locale: Option<String>,
+    /// Case-insensitive substring of a translation title or slug (any locale).
+    #[serde(default)]
+    pub search: Option<String>,
     #[serde(default = "default_page")]
     pub page: u64,
     #[serde(default = "default_per_page")]
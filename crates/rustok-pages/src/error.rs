@@ -1,4 +1,4 @@
-use rustok_core::error::{Error as CoreError, ErrorKind, RichError};
+use rustok_core::error::{CodedError, Error as CoreError, ErrorKind, RichError};
 use sea_orm::DbErr;
 use thiserror::Error;
 use uuid::Uuid;
@@ -39,6 +39,9 @@ pub enum PagesError {
     #[error("Feature disabled: {feature}")]
     FeatureDisabled { feature: String },
 
+    #[error("Unknown layout: {layout}")]
+    UnknownLayout { layout: String },
+
     #[error("Content error: {0}")]
     Content(#[from] rustok_content::ContentError),
 
@@ -141,12 +144,39 @@ impl From<PagesError> for RichError {
             .with_user_message("This feature is disabled for the current tenant")
             .with_field("feature", feature)
             .with_error_code(BUILDER_FEATURE_DISABLED_ERROR_CODE),
+            PagesError::UnknownLayout { layout } => RichError::new(
+                ErrorKind::Validation,
+                format!("Layout '{layout}' is not registered for this tenant"),
+            )
+            .with_user_message("The selected page layout is not available")
+            .with_field("layout", layout)
+            .with_error_code("UNKNOWN_LAYOUT"),
             PagesError::Content(content_err) => content_err.into(),
             PagesError::Rich(rich) => *rich,
         }
     }
 }
 
+impl CodedError for PagesError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            PagesError::Database(_) => ErrorKind::Database.api_code(),
+            PagesError::Core(core) => core.error_code(),
+            PagesError::PageNotFound(_) => "page.not_found",
+            PagesError::BlockNotFound(_) => "page.block_not_found",
+            PagesError::MenuNotFound(_) => "page.menu_not_found",
+            PagesError::DuplicateSlug { .. } => "page.duplicate_slug",
+            PagesError::CannotDeletePublished => "page.cannot_delete_published",
+            PagesError::Validation(_) => "page.validation",
+            PagesError::Forbidden(_) => "page.forbidden",
+            PagesError::FeatureDisabled { .. } => "page.feature_disabled",
+            PagesError::UnknownLayout { .. } => "page.unknown_layout",
+            PagesError::Content(content) => content.error_code(),
+            PagesError::Rich(rich) => rich.as_ref().error_code(),
+        }
+    }
+}
+
 /// Helper functions for creating common pages errors
 impl PagesError {
     /// Create a page not found error
@@ -193,6 +223,13 @@ impl PagesError {
             feature: feature.into(),
         }
     }
+
+    /// Create an unknown layout error
+    pub fn unknown_layout(layout: impl Into<String>) -> Self {
+        PagesError::UnknownLayout {
+            layout: layout.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -267,6 +304,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unknown_layout_conversion() {
+        let err = PagesError::unknown_layout("campaign");
+        let rich: RichError = err.into();
+
+        assert_eq!(rich.kind, ErrorKind::Validation);
+        assert_eq!(rich.error_code, Some("UNKNOWN_LAYOUT".to_string()));
+        assert_eq!(rich.fields.get("layout"), Some(&"campaign".to_string()));
+    }
+
     #[test]
     fn test_builder_runtime_error_catalog_is_stable() {
         let catalog = builder_runtime_error_catalog();
@@ -297,4 +344,21 @@ mod tests {
         );
         assert_eq!(FEATURE_BUILDER_PUBLISH_ENABLED, "builder.publish.enabled");
     }
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(
+            PagesError::page_not_found(Uuid::new_v4()).error_code(),
+            "page.not_found"
+        );
+        assert_eq!(
+            PagesError::unknown_layout("campaign").error_code(),
+            "page.unknown_layout"
+        );
+        assert_eq!(
+            PagesError::Content(rustok_content::ContentError::duplicate_slug("a", "en"))
+                .error_code(),
+            "content.duplicate_slug"
+        );
+    }
 }
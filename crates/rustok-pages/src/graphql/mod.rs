@@ -5,3 +5,9 @@ mod types;
 pub use mutation::PagesMutation;
 pub use query::PagesQuery;
 pub use types::*;
+
+/// Map a pages service error to a GraphQL error whose `code` extension is the
+/// stable [`rustok_core::CodedError`] code (e.g. `page.unknown_layout`).
+pub(crate) fn pages_graphql_error(err: crate::PagesError) -> async_graphql::Error {
+    rustok_api::ApiError::from_error(&err).into_graphql_error()
+}
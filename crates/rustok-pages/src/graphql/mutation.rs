@@ -10,10 +10,11 @@ use uuid::Uuid;
 
 use crate::{
     BlockService, BlockTranslationInput, BlockType, CreateBlockInput, CreatePageInput,
-    PageBodyInput, PageService, PageTranslationInput, UpdateBlockInput, UpdatePageInput,
+    LayoutRegistry, PageBodyInput, PageService, PageTranslationInput, UpdateBlockInput,
+    UpdatePageInput,
 };
 
-use super::types::*;
+use super::{pages_graphql_error, types::*};
 
 const MODULE_SLUG: &str = "pages";
 
@@ -38,7 +39,8 @@ impl PagesMutation {
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
-        let service = PageService::new(db.clone(), event_bus.clone());
+        let service = PageService::new(db.clone(), event_bus.clone())
+            .with_layout_registry(LayoutRegistry::from_tenant_settings(&tenant.settings));
         let page = service
             .create(
                 tenant_id,
@@ -73,10 +75,11 @@ impl PagesMutation {
                         .transpose()?,
                     channel_slugs: input.channel_slugs,
                     publish: input.publish.unwrap_or(false),
+                    metadata: input.metadata,
                 },
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(page.into())
     }
@@ -96,7 +99,8 @@ impl PagesMutation {
         let tenant = ctx.data::<rustok_api::TenantContext>()?;
         let tenant_id = tenant_id.unwrap_or(tenant.id);
 
-        let service = PageService::new(db.clone(), event_bus.clone());
+        let service = PageService::new(db.clone(), event_bus.clone())
+            .with_layout_registry(LayoutRegistry::from_tenant_settings(&tenant.settings));
         let page = service
             .update(
                 tenant_id,
@@ -124,10 +128,11 @@ impl PagesMutation {
                         content_json: b.content_json,
                     }),
                     status: None,
+                    metadata: input.metadata,
                 },
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(page.into())
     }
@@ -150,7 +155,7 @@ impl PagesMutation {
         let page = service
             .publish(tenant_id, auth.security_context(), id)
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(page.into())
     }
@@ -172,7 +177,7 @@ impl PagesMutation {
         let page = service
             .unpublish(tenant_id, auth.security_context(), id)
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(page.into())
     }
@@ -194,7 +199,7 @@ impl PagesMutation {
         service
             .delete(tenant_id, auth.security_context(), id)
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(true)
     }
@@ -222,7 +227,7 @@ impl PagesMutation {
                 map_create_block_input(input)?,
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(block.into())
     }
@@ -254,7 +259,7 @@ impl PagesMutation {
                 },
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(block.into())
     }
@@ -276,7 +281,7 @@ impl PagesMutation {
         service
             .delete(tenant_id, auth.security_context(), block_id)
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(true)
     }
@@ -299,7 +304,7 @@ impl PagesMutation {
         service
             .reorder(tenant_id, auth.security_context(), page_id, input.block_ids)
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         Ok(true)
     }
@@ -4,6 +4,7 @@ use rustok_api::{
     TenantContext,
 };
 use rustok_channel::ChannelService;
+use rustok_content::MetadataVisibility;
 use rustok_core::SecurityContext;
 use rustok_outbox::TransactionalEventBus;
 use rustok_telemetry::metrics;
@@ -12,9 +13,9 @@ use std::time::Instant;
 use uuid::Uuid;
 
 use crate::services::page::is_page_visible_for_channel;
-use crate::PageService;
+use crate::{LayoutRegistry, PageService};
 
-use super::types::*;
+use super::{pages_graphql_error, types::*};
 
 const MODULE_SLUG: &str = "pages";
 
@@ -39,7 +40,8 @@ impl PagesQuery {
         let tenant_id = tenant_id.unwrap_or(tenant.id);
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
-        let service = PageService::new(db.clone(), event_bus.clone());
+        let service = PageService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let page = match service
             .get_with_locale_fallback(
                 tenant_id,
@@ -85,7 +87,8 @@ impl PagesQuery {
         let tenant_id = tenant_id.unwrap_or(tenant.id);
         let locale = resolve_graphql_locale(ctx, locale.as_deref());
 
-        let service = PageService::new(db.clone(), event_bus.clone());
+        let service = PageService::new(db.clone(), event_bus.clone())
+            .with_metadata_visibility(MetadataVisibility::from_tenant_settings(&tenant.settings));
         let page = service
             .get_by_slug_with_locale_fallback(
                 tenant_id,
@@ -95,7 +98,7 @@ impl PagesQuery {
                 Some(tenant.default_locale.as_str()),
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
 
         let public_channel_slug = public_channel_slug(ctx);
         Ok(page
@@ -126,6 +129,7 @@ impl PagesQuery {
         let filter = filter.unwrap_or(ListGqlPagesFilter {
             locale: None,
             template: None,
+            search: None,
             page: Some(1),
             per_page: Some(20),
         });
@@ -147,7 +151,7 @@ impl PagesQuery {
 
         let service = PageService::new(db.clone(), event_bus.clone());
         let list_started_at = Instant::now();
-        let (items, total) = service
+        let pages = service
             .list(
                 tenant_id,
                 security,
@@ -155,12 +159,14 @@ impl PagesQuery {
                     status: None,
                     template: filter.template,
                     locale: Some(locale),
+                    search: filter.search,
                     page: filter.page.unwrap_or(1),
                     per_page: filter.per_page.unwrap_or(20),
                 },
             )
             .await
-            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+            .map_err(pages_graphql_error)?;
+        let total = pages.total.unwrap_or_default();
         metrics::record_read_path_query(
             "graphql",
             "pages.pages",
@@ -168,7 +174,7 @@ impl PagesQuery {
             list_started_at.elapsed().as_secs_f64(),
             total,
         );
-        let items = items.into_iter().map(Into::into).collect::<Vec<_>>();
+        let items = pages.items.into_iter().map(Into::into).collect::<Vec<_>>();
 
         metrics::record_read_path_budget(
             "graphql",
@@ -180,6 +186,14 @@ impl PagesQuery {
 
         Ok(GqlPageList { items, total })
     }
+
+    /// Layouts accepted as page `template` for the current tenant.
+    async fn page_layouts(&self, ctx: &Context<'_>) -> Result<Vec<String>> {
+        require_module_enabled(ctx, MODULE_SLUG).await?;
+        let tenant = ctx.data::<TenantContext>()?;
+
+        Ok(LayoutRegistry::from_tenant_settings(&tenant.settings).list_layouts())
+    }
 }
 
 fn auth_context_to_security(ctx: &Context<'_>) -> SecurityContext {
@@ -229,23 +243,27 @@ async fn list_public_visible_pages(
 ) -> Result<GqlPageList> {
     let locale = resolve_graphql_locale_fallback(filter.locale.as_deref(), default_locale);
     let service = PageService::new(db.clone(), event_bus.clone());
-    let (items, total) = service
+    let pages = service
         .list_public_visible(
             tenant_id,
             crate::ListPagesFilter {
                 status: Some(rustok_content::entities::node::ContentStatus::Published),
                 template: filter.template.clone(),
                 locale: Some(locale),
+                search: filter.search.clone(),
                 page: filter.page.unwrap_or(1).max(1),
                 per_page: filter.per_page.unwrap_or(20).clamp(1, 100),
             },
             public_channel_slug,
         )
         .await
-        .map_err(|err| async_graphql::Error::new(err.to_string()))?;
-    let items = items.into_iter().map(Into::into).collect();
+        .map_err(pages_graphql_error)?;
+    let items = pages.items.into_iter().map(Into::into).collect();
 
-    Ok(GqlPageList { items, total })
+    Ok(GqlPageList {
+        items,
+        total: pages.total.unwrap_or_default(),
+    })
 }
 
 fn resolve_graphql_locale_fallback(requested: Option<&str>, fallback: &str) -> String {
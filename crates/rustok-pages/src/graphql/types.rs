@@ -64,6 +64,7 @@ pub struct CreateGqlPageInput {
     pub blocks: Option<Vec<CreateGqlBlockInput>>,
     pub channel_slugs: Option<Vec<String>>,
     pub publish: Option<bool>,
+    pub metadata: Option<Value>,
 }
 
 #[derive(InputObject)]
@@ -72,6 +73,7 @@ pub struct UpdateGqlPageInput {
     pub template: Option<String>,
     pub body: Option<GqlPageBodyInput>,
     pub channel_slugs: Option<Vec<String>>,
+    pub metadata: Option<Value>,
 }
 
 #[derive(InputObject)]
@@ -136,6 +138,7 @@ pub struct ReorderBlocksInput {
 pub struct ListGqlPagesFilter {
     pub locale: Option<String>,
     pub template: Option<String>,
+    pub search: Option<String>,
     pub page: Option<u64>,
     pub per_page: Option<u64>,
 }
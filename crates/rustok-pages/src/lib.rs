@@ -26,6 +26,7 @@
 //!     blocks: None,
 //!     channel_slugs: None,
 //!     publish: false,
+//!     metadata: None,
 //! };
 //!
 //! let page = service.create(tenant_id, security, input).await?;
@@ -44,7 +45,7 @@ pub use dto::*;
 pub use entities::{Block, Menu, Page};
 pub use error::{PagesError, PagesResult};
 pub use graphql::{PagesMutation, PagesQuery};
-pub use services::{BlockService, MenuService, PageService};
+pub use services::{page_layouts_setting, BlockService, LayoutRegistry, MenuService, PageService};
 
 use async_trait::async_trait;
 use rustok_core::permissions::{Action, Permission, Resource};
@@ -92,6 +93,8 @@ impl RusToKModule for PagesModule {
     fn register_runtime_extensions(&self, extensions: &mut ModuleRuntimeExtensions) {
         register_seo_target_provider(extensions, seo_targets::PagesSeoTargetProvider)
             .expect("pages SEO target registration should remain unique");
+        rustok_tenant::register_tenant_setting(extensions, page_layouts_setting())
+            .expect("pages tenant settings should register once");
     }
 }
 
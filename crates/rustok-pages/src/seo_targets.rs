@@ -106,7 +106,7 @@ impl SeoTargetProvider for PagesSeoTargetProvider {
         let mut summaries = Vec::new();
 
         loop {
-            let (items, total) = service
+            let pages = service
                 .list(
                     request.tenant_id,
                     SecurityContext::system(),
@@ -114,16 +114,17 @@ impl SeoTargetProvider for PagesSeoTargetProvider {
                         status: Some(ContentStatus::Published),
                         template: None,
                         locale: Some(request.locale.to_string()),
+                        search: None,
                         page: page_number,
                         per_page: BULK_FETCH_SIZE,
                     },
                 )
                 .await?;
-            if items.is_empty() {
+            if pages.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in pages.items {
                 if let Some(summary) = load_page_summary(
                     &service,
                     request.tenant_id,
@@ -137,7 +138,7 @@ impl SeoTargetProvider for PagesSeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= pages.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
@@ -156,24 +157,25 @@ impl SeoTargetProvider for PagesSeoTargetProvider {
         let mut candidates = Vec::new();
 
         loop {
-            let (items, total) = service
+            let pages = service
                 .list_public_visible(
                     request.tenant_id,
                     ListPagesFilter {
                         status: Some(ContentStatus::Published),
                         template: None,
                         locale: Some(request.default_locale.to_string()),
+                        search: None,
                         page: page_number,
                         per_page: BULK_FETCH_SIZE,
                     },
                     None,
                 )
                 .await?;
-            if items.is_empty() {
+            if pages.items.is_empty() {
                 break;
             }
 
-            for item in items {
+            for item in pages.items {
                 if let Some(candidate) = load_page_sitemap_candidate(
                     &service,
                     request.tenant_id,
@@ -186,7 +188,7 @@ impl SeoTargetProvider for PagesSeoTargetProvider {
                 }
             }
 
-            if page_number.saturating_mul(BULK_FETCH_SIZE) >= total {
+            if page_number.saturating_mul(BULK_FETCH_SIZE) >= pages.total.unwrap_or_default() {
                 break;
             }
             page_number += 1;
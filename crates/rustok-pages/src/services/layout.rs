@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+
+use rustok_tenant::{SettingDefinition, SettingType};
+use serde_json::Value;
+
+use crate::error::{PagesError, PagesResult};
+
+/// Layout assigned to pages created without an explicit `template`.
+pub const DEFAULT_PAGE_LAYOUT: &str = "default";
+
+/// Layouts every tenant can use without extra configuration.
+pub const BUILTIN_PAGE_LAYOUTS: &[&str] = &[DEFAULT_PAGE_LAYOUT, "landing", "builder"];
+
+/// Tenant settings pointer to an optional array of extra layout names.
+pub const PAGE_LAYOUTS_SETTING: &str = "/pages/layouts";
+
+/// Dotted tenant setting key for [`PAGE_LAYOUTS_SETTING`].
+pub const PAGE_LAYOUTS_SETTING_KEY: &str = "pages.layouts";
+
+/// Declaration of [`PAGE_LAYOUTS_SETTING_KEY`] for the tenant settings
+/// schema: extra layout names on top of [`BUILTIN_PAGE_LAYOUTS`].
+pub fn page_layouts_setting() -> SettingDefinition {
+    SettingDefinition::new(
+        PAGE_LAYOUTS_SETTING_KEY,
+        SettingType::Array,
+        Value::Array(Vec::new()),
+    )
+    .with_description("Page layouts available in addition to the built-in ones")
+    .with_validator(|value| {
+        let all_names = value.as_array().is_some_and(|items| {
+            items
+                .iter()
+                .all(|item| item.as_str().is_some_and(|name| !name.trim().is_empty()))
+        });
+        if all_names {
+            Ok(())
+        } else {
+            Err("expected an array of non-empty layout names".to_string())
+        }
+    })
+}
+
+/// Set of page layouts (`template` values) accepted by create/update.
+///
+/// The registry always contains [`BUILTIN_PAGE_LAYOUTS`]; tenants extend it
+/// through `{"pages": {"layouts": [...]}}`. Stored pages keep whatever layout
+/// they already have: validation only applies to values supplied by callers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutRegistry {
+    layouts: BTreeSet<String>,
+}
+
+impl Default for LayoutRegistry {
+    fn default() -> Self {
+        Self::new(std::iter::empty::<String>())
+    }
+}
+
+impl LayoutRegistry {
+    /// Build a registry from the built-in layouts plus `extra`.
+    pub fn new(extra: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut layouts: BTreeSet<String> = BUILTIN_PAGE_LAYOUTS
+            .iter()
+            .map(|layout| layout.to_string())
+            .collect();
+        layouts.extend(
+            extra
+                .into_iter()
+                .map(Into::into)
+                .map(|layout| layout.trim().to_string())
+                .filter(|layout| !layout.is_empty()),
+        );
+        Self { layouts }
+    }
+
+    /// Build the registry from tenant settings
+    /// (`{"pages": {"layouts": [...]}}`).
+    /// Missing or malformed settings fall back to the built-in layouts.
+    pub fn from_tenant_settings(settings: &Value) -> Self {
+        let extra = settings
+            .pointer(PAGE_LAYOUTS_SETTING)
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Self::new(extra)
+    }
+
+    pub fn is_valid(&self, layout: &str) -> bool {
+        self.layouts.contains(layout)
+    }
+
+    /// Registered layouts in stable (sorted) order, e.g. for editor dropdowns.
+    pub fn list_layouts(&self) -> Vec<String> {
+        self.layouts.iter().cloned().collect()
+    }
+
+    pub fn ensure_valid(&self, layout: &str) -> PagesResult<()> {
+        if self.is_valid(layout) {
+            Ok(())
+        } else {
+            Err(PagesError::unknown_layout(layout))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builtin_layouts_are_always_registered() {
+        let registry = LayoutRegistry::default();
+
+        assert!(registry.is_valid(DEFAULT_PAGE_LAYOUT));
+        assert!(registry.is_valid("landing"));
+        assert!(!registry.is_valid("campaign"));
+        assert_eq!(
+            registry.list_layouts(),
+            vec!["builder", "default", "landing"]
+        );
+    }
+
+    #[test]
+    fn layouts_setting_accepts_only_names() {
+        let setting = page_layouts_setting();
+
+        assert!(setting.validate(&json!(["campaign"])).is_ok());
+        assert!(setting.validate(&json!(["campaign", ""])).is_err());
+        assert!(setting.validate(&json!([42])).is_err());
+    }
+
+    #[test]
+    fn tenant_settings_extend_builtin_layouts() {
+        let registry = LayoutRegistry::from_tenant_settings(&json!({
+            "pages": { "layouts": ["campaign", " docs ", "", 42] }
+        }));
+
+        assert!(registry.is_valid("campaign"));
+        assert!(registry.is_valid("docs"));
+        assert!(registry.is_valid(DEFAULT_PAGE_LAYOUT));
+        assert!(matches!(
+            registry.ensure_valid("missing"),
+            Err(PagesError::UnknownLayout { layout }) if layout == "missing"
+        ));
+    }
+
+    #[test]
+    fn malformed_settings_fall_back_to_builtins() {
+        let registry = LayoutRegistry::from_tenant_settings(&json!({
+            "pages": { "layouts": "campaign" }
+        }));
+
+        assert_eq!(registry, LayoutRegistry::default());
+    }
+}
@@ -1,9 +1,11 @@
 // Service layer for pages operations.
 pub mod block;
+pub mod layout;
 pub mod menu;
 pub mod page;
 mod rbac;
 
 pub use block::BlockService;
+pub use layout::{page_layouts_setting, LayoutRegistry};
 pub use menu::MenuService;
 pub use page::PageService;
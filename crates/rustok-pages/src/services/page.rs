@@ -1,10 +1,10 @@
 use chrono::Utc;
 use sea_orm::{
-    sea_query::{Expr, Query, SelectStatement},
+    sea_query::{Expr, Func, LikeExpr, Query, SelectStatement},
     ActiveModelTrait,
     ActiveValue::Set,
-    ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Select, TransactionTrait,
+    ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter,
+    QueryOrder, Select, TransactionTrait,
 };
 use std::collections::HashMap;
 use tracing::instrument;
@@ -12,10 +12,11 @@ use uuid::Uuid;
 
 use rustok_content::{
     available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
+    MetadataVisibility,
 };
 use rustok_core::{
-    normalize_content_format, prepare_content_payload, Action, Resource, SecurityContext,
-    CONTENT_FORMAT_GRAPESJS_V1, CONTENT_FORMAT_RT_JSON_V1,
+    fetch_with_total, normalize_content_format, prepare_content_payload, Action, Page, Resource,
+    SecurityContext, CONTENT_FORMAT_GRAPESJS_V1, CONTENT_FORMAT_RT_JSON_V1,
 };
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
@@ -26,8 +27,9 @@ use crate::error::{
     PagesError, PagesResult, FEATURE_BUILDER_ENABLED, FEATURE_BUILDER_PREVIEW_ENABLED,
     FEATURE_BUILDER_PROPERTIES_ENABLED, FEATURE_BUILDER_PUBLISH_ENABLED,
 };
+use crate::services::layout::DEFAULT_PAGE_LAYOUT;
 use crate::services::rbac::{can_read_non_public_pages, enforce_owned_scope, enforce_scope};
-use crate::services::BlockService;
+use crate::services::{BlockService, LayoutRegistry};
 use rustok_tenant::entities::tenant_module;
 
 const PAGE_KIND: &str = "page";
@@ -44,6 +46,8 @@ pub struct PageService {
     db: DatabaseConnection,
     event_bus: TransactionalEventBus,
     blocks: BlockService,
+    metadata_visibility: MetadataVisibility,
+    layout_registry: LayoutRegistry,
 }
 
 struct PreparedPageBody {
@@ -68,9 +72,27 @@ impl PageService {
             db: db.clone(),
             event_bus: event_bus.clone(),
             blocks: BlockService::new(db, event_bus),
+            metadata_visibility: MetadataVisibility::default(),
+            layout_registry: LayoutRegistry::default(),
         }
     }
 
+    /// Override which metadata keys read paths treat as internal.
+    pub fn with_metadata_visibility(mut self, metadata_visibility: MetadataVisibility) -> Self {
+        self.metadata_visibility = metadata_visibility;
+        self
+    }
+
+    /// Override which layouts create/update accept as `template`.
+    pub fn with_layout_registry(mut self, layout_registry: LayoutRegistry) -> Self {
+        self.layout_registry = layout_registry;
+        self
+    }
+
+    pub fn layout_registry(&self) -> &LayoutRegistry {
+        &self.layout_registry
+    }
+
     #[instrument(skip(self, input))]
     pub async fn create(
         &self,
@@ -83,11 +105,18 @@ impl PageService {
             enforce_scope(&security, Resource::Pages, Action::Publish)?;
         }
         validate_page_translations(&input.translations)?;
+        validate_page_metadata(input.metadata.as_ref())?;
         let template = input
             .template
             .clone()
-            .unwrap_or_else(|| "default".to_string());
-        let metadata = build_page_metadata(&template, &input.translations, None);
+            .unwrap_or_else(|| DEFAULT_PAGE_LAYOUT.to_string());
+        self.layout_registry.ensure_valid(&template)?;
+        let metadata = build_page_metadata(
+            &template,
+            &input.translations,
+            None,
+            input.metadata.as_ref(),
+        );
         let channel_slugs = normalize_channel_slugs(input.channel_slugs.as_deref().unwrap_or(&[]));
         let body = normalize_page_body_input(input.body)?;
         if body_uses_builder_capability(body.as_ref()) {
@@ -201,9 +230,9 @@ impl PageService {
         let bodies = self.load_bodies(page_id).await?;
         let blocks = self
             .blocks
-            .list_for_page(tenant_id, security, page_id)
+            .list_for_page(tenant_id, security.clone(), page_id)
             .await?;
-        self.build_page_response(
+        let mut response = self.build_page_response(
             page,
             translations,
             bodies,
@@ -213,7 +242,10 @@ impl PageService {
                 locale: locale.clone(),
                 fallback_locale,
             },
-        )
+        )?;
+        self.metadata_visibility
+            .apply(&security, &mut response.metadata);
+        Ok(response)
     }
 
     #[instrument(skip(self))]
@@ -253,9 +285,9 @@ impl PageService {
         let bodies = self.load_bodies(page.id).await?;
         let blocks = self
             .blocks
-            .list_for_page(tenant_id, security, page.id)
+            .list_for_page(tenant_id, security.clone(), page.id)
             .await?;
-        self.build_page_response(
+        let mut response = self.build_page_response(
             page,
             translations,
             bodies,
@@ -265,8 +297,10 @@ impl PageService {
                 locale: requested_locale,
                 fallback_locale: normalized_fallback_locale,
             },
-        )
-        .map(Some)
+        )?;
+        self.metadata_visibility
+            .apply(&security, &mut response.metadata);
+        Ok(Some(response))
     }
 
     #[instrument(skip(self))]
@@ -287,7 +321,7 @@ impl PageService {
         tenant_id: Uuid,
         security: SecurityContext,
         filter: ListPagesFilter,
-    ) -> PagesResult<(Vec<PageListItem>, u64)> {
+    ) -> PagesResult<Page<PageListItem>> {
         enforce_scope(&security, Resource::Pages, Action::List)?;
         let locale = filter
             .locale
@@ -300,7 +334,7 @@ impl PageService {
                 Some(ref status)
                     if status != &rustok_content::entities::node::ContentStatus::Published
             ) {
-                return Ok((Vec::new(), 0));
+                return Ok(Page::empty());
             }
             select = select.filter(page::Column::Status.eq(status_to_storage(
                 &rustok_content::entities::node::ContentStatus::Published,
@@ -312,11 +346,16 @@ impl PageService {
         if let Some(template) = filter.template {
             select = select.filter(page::Column::Template.eq(template));
         }
-        let paginator = select
-            .order_by_desc(page::Column::UpdatedAt)
-            .paginate(&self.db, filter.per_page.max(1));
-        let total = paginator.num_items().await?;
-        let pages = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
+        select = apply_page_search_filter(select, tenant_id, filter.search.as_deref());
+        let (pages, total) = fetch_with_total(
+            &self.db,
+            select
+                .order_by_desc(page::Column::UpdatedAt)
+                .order_by_asc(page::Column::Id),
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+            filter.per_page.max(1),
+        )
+        .await?;
         let page_ids: Vec<Uuid> = pages.iter().map(|item| item.id).collect();
         let translations_map = self.load_translations_map(&page_ids).await?;
         let channel_slugs_map = self.load_channel_slugs_map(&page_ids).await?;
@@ -336,7 +375,11 @@ impl PageService {
             });
         }
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+        ))
     }
 
     #[instrument(skip(self))]
@@ -345,7 +388,7 @@ impl PageService {
         tenant_id: Uuid,
         filter: ListPagesFilter,
         channel_slug: Option<&str>,
-    ) -> PagesResult<(Vec<PageListItem>, u64)> {
+    ) -> PagesResult<Page<PageListItem>> {
         let locale = filter
             .locale
             .unwrap_or_else(|| PLATFORM_FALLBACK_LOCALE.to_string());
@@ -358,13 +401,18 @@ impl PageService {
         if let Some(template) = filter.template {
             select = select.filter(page::Column::Template.eq(template));
         }
+        select = apply_page_search_filter(select, tenant_id, filter.search.as_deref());
         select = apply_public_page_channel_filter(select, tenant_id, channel_slug);
 
-        let paginator = select
-            .order_by_desc(page::Column::UpdatedAt)
-            .paginate(&self.db, filter.per_page.max(1));
-        let total = paginator.num_items().await?;
-        let pages = paginator.fetch_page(filter.page.saturating_sub(1)).await?;
+        let (pages, total) = fetch_with_total(
+            &self.db,
+            select
+                .order_by_desc(page::Column::UpdatedAt)
+                .order_by_asc(page::Column::Id),
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+            filter.per_page.max(1),
+        )
+        .await?;
         let page_ids: Vec<Uuid> = pages.iter().map(|item| item.id).collect();
         let translations_map = self.load_translations_map(&page_ids).await?;
         let channel_slugs_map = self.load_channel_slugs_map(&page_ids).await?;
@@ -384,7 +432,11 @@ impl PageService {
             });
         }
 
-        Ok((items, total))
+        Ok(Page::offset(
+            items,
+            total,
+            filter.page.saturating_sub(1) * filter.per_page.max(1),
+        ))
     }
 
     #[instrument(skip(self, input))]
@@ -415,6 +467,10 @@ impl PageService {
         if let Some(ref translations) = input.translations {
             validate_page_translations(translations)?;
         }
+        validate_page_metadata(input.metadata.as_ref())?;
+        if let Some(ref template) = input.template {
+            self.layout_registry.ensure_valid(template)?;
+        }
 
         let template = input
             .template
@@ -424,6 +480,7 @@ impl PageService {
             &template,
             input.translations.as_deref().unwrap_or(&[]),
             Some(&existing.metadata),
+            input.metadata.as_ref(),
         );
         let channel_slugs = input
             .channel_slugs
@@ -1160,15 +1217,36 @@ fn status_to_storage(status: &rustok_content::entities::node::ContentStatus) ->
     }
 }
 
+fn validate_page_metadata(metadata: Option<&serde_json::Value>) -> PagesResult<()> {
+    match metadata {
+        None | Some(serde_json::Value::Object(_)) => Ok(()),
+        Some(_) => Err(PagesError::validation(
+            "Page metadata must be a JSON object",
+        )),
+    }
+}
+
+/// Build the canonical metadata object: existing keys, then caller keys, then
+/// the service-owned [`PAGE_METADATA_RESERVED_KEYS`].
 fn build_page_metadata(
     template: &str,
     translations: &[PageTranslationInput],
     existing: Option<&serde_json::Value>,
+    input: Option<&serde_json::Value>,
 ) -> serde_json::Value {
     let mut metadata = existing
         .cloned()
         .filter(|value| value.is_object())
         .unwrap_or_else(|| serde_json::json!({}));
+    if let (Some(target), Some(serde_json::Value::Object(extra))) =
+        (metadata.as_object_mut(), input)
+    {
+        for (key, value) in extra {
+            if !PAGE_METADATA_RESERVED_KEYS.contains(&key.as_str()) {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
     metadata["template"] = serde_json::json!(template);
 
     let mut seo = serde_json::Map::new();
@@ -1236,6 +1314,42 @@ fn apply_public_page_channel_filter(
     select.filter(condition)
 }
 
+/// Keep pages with a translation whose title or slug contains `search`,
+/// ignoring case; a blank term leaves `select` unchanged.
+fn apply_page_search_filter(
+    select: Select<page::Entity>,
+    tenant_id: Uuid,
+    search: Option<&str>,
+) -> Select<page::Entity> {
+    let Some(search) = search.map(str::trim).filter(|search| !search.is_empty()) else {
+        return select;
+    };
+    let escaped = search
+        .to_lowercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    let pattern = format!("%{escaped}%");
+    let matches = |column: page_translation::Column| {
+        Expr::expr(Func::lower(Expr::col((page_translation::Entity, column))))
+            .like(LikeExpr::new(pattern.clone()).escape('\\'))
+    };
+    let matching_pages = Query::select()
+        .column(page_translation::Column::PageId)
+        .from(page_translation::Entity)
+        .and_where(
+            Expr::col((page_translation::Entity, page_translation::Column::TenantId)).eq(tenant_id),
+        )
+        .cond_where(
+            Condition::any()
+                .add(matches(page_translation::Column::Title))
+                .add(matches(page_translation::Column::Slug)),
+        )
+        .to_owned();
+
+    select.filter(Expr::col((page::Entity, page::Column::Id)).in_subquery(matching_pages))
+}
+
 fn all_page_channel_visibility_subquery(tenant_id: Uuid) -> SelectStatement {
     Query::select()
         .column(page_channel_visibility::Column::PageId)
@@ -259,13 +259,14 @@ async fn storefront_pages_native(
             })
             .map(map_page_detail);
 
-        let (items, total) = service
+        let pages = service
             .list_public_visible(
                 tenant_id,
                 RuntimeListPagesFilter {
                     status: Some(ContentStatus::Published),
                     template: None,
                     locale: Some(requested_locale),
+                    search: None,
                     page: 1,
                     per_page: 6,
                 },
@@ -277,8 +278,8 @@ async fn storefront_pages_native(
         Ok(StorefrontPagesData {
             selected_page,
             pages: PageList {
-                items: items.into_iter().map(map_page_list_item).collect(),
-                total,
+                items: pages.items.into_iter().map(map_page_list_item).collect(),
+                total: pages.total.unwrap_or_default(),
             },
         })
     }
@@ -36,6 +36,7 @@ async fn test_page_lifecycle() -> TestResult<()> {
         }),
         blocks: None,
         channel_slugs: None,
+        metadata: None,
     };
 
     let page = ctx
@@ -100,6 +101,7 @@ async fn test_create_page_rt_json_v1_sanitizes_payload() -> TestResult<()> {
         }),
         blocks: None,
         channel_slugs: None,
+        metadata: None,
     };
 
     let page = ctx
@@ -147,6 +149,7 @@ async fn test_update_page_rt_json_v1_sanitizes_payload() -> TestResult<()> {
                 }),
                 blocks: None,
                 channel_slugs: None,
+                metadata: None,
             },
         )
         .await?;
@@ -188,6 +191,7 @@ async fn test_update_page_rt_json_v1_sanitizes_payload() -> TestResult<()> {
                     })),
                 }),
                 channel_slugs: None,
+                metadata: None,
             },
         )
         .await?;
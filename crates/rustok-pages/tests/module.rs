@@ -1,6 +1,20 @@
 use rustok_core::permissions::{Action, Resource};
 use rustok_core::{MigrationSource, RusToKModule};
 use rustok_pages::PagesModule;
+use rustok_test_utils::db::setup_test_db;
+use sea_orm_migration::SchemaManager;
+
+const PAGES_TABLES: [&str; 9] = [
+    "pages",
+    "page_translations",
+    "page_bodies",
+    "page_blocks",
+    "menus",
+    "menu_translations",
+    "menu_items",
+    "menu_item_translations",
+    "page_channel_visibility",
+];
 
 #[test]
 fn module_metadata() {
@@ -35,3 +49,41 @@ fn module_has_migrations() {
     let module = PagesModule;
     assert!(!module.migrations().is_empty());
 }
+
+#[tokio::test]
+async fn migrations_apply_and_revert_cleanly() {
+    let db = setup_test_db().await;
+    let schema = SchemaManager::new(&db);
+    let migrations = PagesModule.migrations();
+
+    for migration in &migrations {
+        migration
+            .up(&schema)
+            .await
+            .expect("migration up must succeed");
+    }
+    for table in PAGES_TABLES {
+        assert!(schema.has_table(table).await.unwrap(), "{table} after up");
+    }
+
+    for migration in migrations.iter().rev() {
+        migration
+            .down(&schema)
+            .await
+            .expect("migration down must succeed");
+    }
+    for table in PAGES_TABLES {
+        assert!(
+            !schema.has_table(table).await.unwrap(),
+            "{table} after down"
+        );
+    }
+
+    // Reverting must leave nothing behind that blocks a fresh install.
+    for migration in &migrations {
+        migration
+            .up(&schema)
+            .await
+            .expect("re-applying must succeed");
+    }
+}
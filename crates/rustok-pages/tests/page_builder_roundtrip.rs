@@ -4,7 +4,7 @@ use rustok_pages::dto::{
     UpdatePageInput,
 };
 use rustok_pages::services::PageService;
-use rustok_pages::PagesModule;
+use rustok_pages::{LayoutRegistry, PagesError, PagesModule};
 use rustok_test_utils::{db::setup_test_db, helpers::admin_context, mock_transactional_event_bus};
 use sea_orm_migration::SchemaManager;
 use uuid::Uuid;
@@ -22,7 +22,8 @@ async fn setup() -> (PageService, Uuid, SecurityContext) {
 
     let event_bus = mock_transactional_event_bus();
     (
-        PageService::new(db, event_bus),
+        PageService::new(db, event_bus)
+            .with_layout_registry(LayoutRegistry::new(["legacy", "legacy-updated"])),
         Uuid::new_v4(),
         admin_context(),
     )
@@ -92,6 +93,7 @@ async fn grapesjs_body_round_trips_on_create_and_get() {
                 blocks: None,
                 channel_slugs: Some(vec!["web".to_string(), "mobile".to_string()]),
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -143,6 +145,7 @@ async fn grapesjs_body_round_trips_on_update() {
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -165,6 +168,7 @@ async fn grapesjs_body_round_trips_on_update() {
                 }),
                 channel_slugs: Some(vec!["app".to_string(), "app".to_string()]),
                 status: None,
+                metadata: None,
             },
         )
         .await
@@ -197,6 +201,7 @@ async fn legacy_block_driven_page_round_trips_without_body() {
                 blocks: Some(vec![legacy_text_block("  Legacy body via blocks  ", 0)]),
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -220,6 +225,7 @@ async fn legacy_block_driven_page_round_trips_without_body() {
                 body: None,
                 channel_slugs: None,
                 status: None,
+                metadata: None,
             },
         )
         .await
@@ -262,6 +268,7 @@ async fn grapesjs_body_update_preserves_legacy_blocks() {
                 blocks: Some(vec![legacy_text_block("Legacy block payload", 0)]),
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -284,6 +291,7 @@ async fn grapesjs_body_update_preserves_legacy_blocks() {
                 }),
                 channel_slugs: None,
                 status: None,
+                metadata: None,
             },
         )
         .await
@@ -305,3 +313,145 @@ async fn grapesjs_body_update_preserves_legacy_blocks() {
     assert_eq!(loaded.blocks.len(), 1);
     assert_eq!(loaded.blocks[0].data["text"], "Legacy block payload");
 }
+
+fn metadata_page_input(metadata: Option<serde_json::Value>) -> CreatePageInput {
+    CreatePageInput {
+        translations: vec![PageTranslationInput {
+            locale: "en".to_string(),
+            title: "Pricing".to_string(),
+            slug: Some(format!("pricing-{}", Uuid::new_v4())),
+            meta_title: None,
+            meta_description: None,
+        }],
+        template: Some("landing".to_string()),
+        body: None,
+        blocks: None,
+        channel_slugs: None,
+        publish: false,
+        metadata,
+    }
+}
+
+#[tokio::test]
+async fn non_object_metadata_is_rejected() {
+    let (service, tenant_id, security) = setup().await;
+
+    for metadata in [serde_json::json!(["hero"]), serde_json::json!("hero")] {
+        let err = service
+            .create(
+                tenant_id,
+                security.clone(),
+                metadata_page_input(Some(metadata)),
+            )
+            .await
+            .expect_err("non-object metadata must be rejected");
+        assert!(matches!(err, PagesError::Validation(_)), "{err:?}");
+    }
+
+    let created = service
+        .create(tenant_id, security.clone(), metadata_page_input(None))
+        .await
+        .expect("page without metadata should be created");
+    let err = service
+        .update(
+            tenant_id,
+            security,
+            created.id,
+            UpdatePageInput {
+                metadata: Some(serde_json::json!(42)),
+                ..UpdatePageInput::default()
+            },
+        )
+        .await
+        .expect_err("non-object metadata must be rejected on update");
+    assert!(matches!(err, PagesError::Validation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn object_metadata_is_merged_into_canonical_shape() {
+    let (service, tenant_id, security) = setup().await;
+
+    let created = service
+        .create(
+            tenant_id,
+            security.clone(),
+            metadata_page_input(Some(serde_json::json!({
+                "layout": "wide",
+                "template": "ignored",
+            }))),
+        )
+        .await
+        .expect("page with object metadata should be created");
+    assert_eq!(created.metadata["layout"], "wide");
+    assert_eq!(created.metadata["template"], "landing");
+
+    let updated = service
+        .update(
+            tenant_id,
+            security,
+            created.id,
+            UpdatePageInput {
+                metadata: Some(serde_json::json!({ "hero": true })),
+                ..UpdatePageInput::default()
+            },
+        )
+        .await
+        .expect("metadata update should succeed");
+    assert_eq!(updated.metadata["layout"], "wide");
+    assert_eq!(updated.metadata["hero"], true);
+    assert_eq!(updated.metadata["template"], "landing");
+}
+
+#[tokio::test]
+async fn unregistered_layout_is_rejected() {
+    let (service, tenant_id, security) = setup().await;
+
+    let mut input = metadata_page_input(None);
+    input.template = Some("campaign".to_string());
+    let err = service
+        .create(tenant_id, security.clone(), input)
+        .await
+        .expect_err("unknown layout must be rejected on create");
+    assert!(
+        matches!(&err, PagesError::UnknownLayout { layout } if layout == "campaign"),
+        "{err:?}"
+    );
+
+    let created = service
+        .create(tenant_id, security.clone(), metadata_page_input(None))
+        .await
+        .expect("page with a built-in layout should be created");
+    let err = service
+        .update(
+            tenant_id,
+            security,
+            created.id,
+            UpdatePageInput {
+                template: Some("campaign".to_string()),
+                ..UpdatePageInput::default()
+            },
+        )
+        .await
+        .expect_err("unknown layout must be rejected on update");
+    assert!(
+        matches!(&err, PagesError::UnknownLayout { layout } if layout == "campaign"),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn tenant_layouts_are_accepted() {
+    let (service, tenant_id, security) = setup().await;
+    let service = service.with_layout_registry(LayoutRegistry::from_tenant_settings(
+        &serde_json::json!({ "pages": { "layouts": ["campaign"] } }),
+    ));
+
+    let mut input = metadata_page_input(None);
+    input.template = Some("campaign".to_string());
+    let created = service
+        .create(tenant_id, security, input)
+        .await
+        .expect("tenant layout should be accepted");
+    assert_eq!(created.template, "campaign");
+    assert!(service.layout_registry().is_valid("campaign"));
+}
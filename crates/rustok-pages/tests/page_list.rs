@@ -0,0 +1,148 @@
+use rustok_content::entities::node::ContentStatus;
+use rustok_core::{MigrationSource, SecurityContext};
+use rustok_pages::dto::{CreatePageInput, ListPagesFilter, PageTranslationInput};
+use rustok_pages::services::PageService;
+use rustok_pages::PagesModule;
+use rustok_test_utils::{db::setup_test_db, mock_transactional_event_bus};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+
+async fn setup() -> (PageService, Uuid) {
+    let db = setup_test_db().await;
+    let module = PagesModule;
+    let schema = SchemaManager::new(&db);
+    for migration in module.migrations() {
+        migration
+            .up(&schema)
+            .await
+            .expect("failed to apply pages migrations");
+    }
+
+    let event_bus = mock_transactional_event_bus();
+    (PageService::new(db, event_bus), Uuid::new_v4())
+}
+
+async fn create_page(service: &PageService, tenant_id: Uuid, title: &str, publish: bool) -> Uuid {
+    service
+        .create(
+            tenant_id,
+            SecurityContext::system(),
+            CreatePageInput {
+                translations: vec![PageTranslationInput {
+                    locale: "en".to_string(),
+                    title: title.to_string(),
+                    slug: None,
+                    meta_title: None,
+                    meta_description: None,
+                }],
+                template: Some("default".to_string()),
+                body: None,
+                blocks: None,
+                channel_slugs: None,
+                publish,
+                metadata: None,
+            },
+        )
+        .await
+        .expect("page should be created")
+        .id
+}
+
+fn filter() -> ListPagesFilter {
+    ListPagesFilter {
+        locale: Some("en".to_string()),
+        page: 1,
+        per_page: 20,
+        ..ListPagesFilter::default()
+    }
+}
+
+#[tokio::test]
+async fn list_filters_by_status() {
+    let (service, tenant_id) = setup().await;
+    let published = create_page(&service, tenant_id, "Published", true).await;
+    let draft = create_page(&service, tenant_id, "Draft", false).await;
+
+    let pages = service
+        .list(
+            tenant_id,
+            SecurityContext::system(),
+            ListPagesFilter {
+                status: Some(ContentStatus::Draft),
+                ..filter()
+            },
+        )
+        .await
+        .expect("list should succeed");
+    assert_eq!(pages.total, Some(1));
+    assert_eq!(pages.items[0].id, draft);
+
+    let pages = service
+        .list(
+            tenant_id,
+            SecurityContext::system(),
+            ListPagesFilter {
+                status: Some(ContentStatus::Published),
+                ..filter()
+            },
+        )
+        .await
+        .expect("list should succeed");
+    assert_eq!(pages.total, Some(1));
+    assert_eq!(pages.items[0].id, published);
+}
+
+#[tokio::test]
+async fn list_searches_title_and_slug_case_insensitively() {
+    let (service, tenant_id) = setup().await;
+    let pricing = create_page(&service, tenant_id, "Pricing Plans", true).await;
+    create_page(&service, tenant_id, "About", true).await;
+    create_page(&service, Uuid::new_v4(), "Pricing elsewhere", true).await;
+
+    for search in ["pricing", "PLANS", "pricing-plans"] {
+        let pages = service
+            .list(
+                tenant_id,
+                SecurityContext::system(),
+                ListPagesFilter {
+                    search: Some(search.to_string()),
+                    ..filter()
+                },
+            )
+            .await
+            .expect("list should succeed");
+        assert_eq!(pages.total, Some(1), "search: {search}");
+        assert_eq!(pages.items[0].id, pricing, "search: {search}");
+    }
+}
+
+#[tokio::test]
+async fn list_paginates_with_total_count() {
+    let (service, tenant_id) = setup().await;
+    for index in 0..5 {
+        create_page(&service, tenant_id, &format!("Page {index}"), true).await;
+    }
+
+    let mut seen = Vec::new();
+    for page in 1..=3 {
+        let pages = service
+            .list(
+                tenant_id,
+                SecurityContext::system(),
+                ListPagesFilter {
+                    page,
+                    per_page: 2,
+                    ..filter()
+                },
+            )
+            .await
+            .expect("list should succeed");
+        assert_eq!(pages.total, Some(5));
+        assert_eq!(pages.items.len(), if page < 3 { 2 } else { 1 });
+        seen.extend(pages.items.into_iter().map(|item| item.id));
+    }
+
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 5);
+}
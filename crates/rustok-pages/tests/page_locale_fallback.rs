@@ -48,6 +48,7 @@ async fn create_translated_page(service: &PageService, tenant_id: Uuid) -> Uuid
                 blocks: None,
                 channel_slugs: None,
                 publish: true,
+                metadata: None,
             },
         )
         .await
@@ -94,6 +94,7 @@ async fn create_page(
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -133,6 +134,7 @@ async fn create_grapesjs_page(
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -299,6 +301,7 @@ async fn create_grapesjs_body_returns_feature_disabled_when_builder_toggle_is_fa
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await;
@@ -367,6 +370,7 @@ async fn create_markdown_body_is_allowed_when_builder_toggle_is_false() {
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -436,6 +440,7 @@ async fn create_and_publish_markdown_is_allowed_when_builder_disabled_but_publis
                 blocks: None,
                 channel_slugs: None,
                 publish: true,
+                metadata: None,
             },
         )
         .await
@@ -479,6 +484,7 @@ async fn create_and_publish_markdown_is_allowed_when_builder_publish_toggle_is_f
                 blocks: None,
                 channel_slugs: None,
                 publish: true,
+                metadata: None,
             },
         )
         .await
@@ -517,6 +523,7 @@ async fn publish_grapesjs_page_is_blocked_when_builder_disabled_even_if_publish_
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -563,6 +570,7 @@ async fn publish_with_foreign_page_id_returns_page_not_found_before_builder_togg
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -607,6 +615,7 @@ async fn update_to_published_is_blocked_for_existing_grapesjs_page_when_builder_
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -662,6 +671,7 @@ async fn update_to_published_markdown_is_allowed_when_builder_disabled_but_publi
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -718,6 +728,7 @@ async fn update_to_published_markdown_is_allowed_when_builder_publish_toggle_is_
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -778,6 +789,7 @@ async fn publish_forbidden_user_gets_forbidden_before_builder_toggle_errors() {
                 blocks: None,
                 channel_slugs: None,
                 publish: false,
+                metadata: None,
             },
         )
         .await
@@ -841,12 +853,12 @@ async fn pages_builder_fallback_all_on_allows_publish_and_keeps_read_list_paths(
         "grapesjs_v1"
     );
 
-    let (items, total) = page_service
+    let pages = page_service
         .list(tenant_id, security, Default::default())
         .await
         .expect("list path must remain stable in all_on profile");
-    assert_eq!(total, 1);
-    assert!(items.iter().any(|item| item.id == page.id));
+    assert_eq!(pages.total, Some(1));
+    assert!(pages.items.iter().any(|item| item.id == page.id));
 }
 
 #[tokio::test]
@@ -887,12 +899,12 @@ async fn pages_builder_fallback_publish_off_blocks_grapesjs_publish_but_keeps_re
         "grapesjs_v1"
     );
 
-    let (items, total) = page_service
+    let pages = page_service
         .list(tenant_id, security, Default::default())
         .await
         .expect("list path must remain stable when builder publish is disabled");
-    assert_eq!(total, 1);
-    assert!(items.iter().any(|item| item.id == page.id));
+    assert_eq!(pages.total, Some(1));
+    assert!(pages.items.iter().any(|item| item.id == page.id));
 }
 
 #[tokio::test]
@@ -946,12 +958,12 @@ async fn pages_builder_fallback_preview_off_blocks_preview_publish_but_keeps_rea
         "grapesjs_v1"
     );
 
-    let (items, total) = page_service
+    let pages = page_service
         .list(tenant_id, security, Default::default())
         .await
         .expect("list path must remain stable when preview is disabled");
-    assert_eq!(total, 1);
-    assert!(items.iter().any(|item| item.id == page.id));
+    assert_eq!(pages.total, Some(1));
+    assert!(pages.items.iter().any(|item| item.id == page.id));
 }
 
 #[tokio::test]
@@ -984,12 +996,12 @@ async fn pages_builder_fallback_builder_off_keeps_read_and_list_paths() {
         "grapesjs_v1"
     );
 
-    let (items, total) = page_service
+    let pages = page_service
         .list(tenant_id, security.clone(), Default::default())
         .await
         .expect("list path must remain stable when builder is disabled");
-    assert_eq!(total, 1);
-    assert!(items.iter().any(|item| item.id == page.id));
+    assert_eq!(pages.total, Some(1));
+    assert!(pages.items.iter().any(|item| item.id == page.id));
 
     let publish_result = page_service.publish(tenant_id, security, page.id).await;
     assert!(matches!(
@@ -0,0 +1,141 @@
+use rustok_core::{MigrationSource, SecurityContext};
+use rustok_pages::dto::{CreatePageInput, PageTranslationInput, UpdatePageInput};
+use rustok_pages::services::PageService;
+use rustok_pages::{PagesError, PagesModule};
+use rustok_test_utils::{db::setup_test_db, mock_transactional_event_bus};
+use sea_orm_migration::SchemaManager;
+use uuid::Uuid;
+
+async fn setup() -> (PageService, Uuid) {
+    let db = setup_test_db().await;
+    let module = PagesModule;
+    let schema = SchemaManager::new(&db);
+    for migration in module.migrations() {
+        migration
+            .up(&schema)
+            .await
+            .expect("failed to apply pages migrations");
+    }
+
+    let event_bus = mock_transactional_event_bus();
+    (PageService::new(db, event_bus), Uuid::new_v4())
+}
+
+fn translation(title: &str, slug: &str) -> PageTranslationInput {
+    PageTranslationInput {
+        locale: "en".to_string(),
+        title: title.to_string(),
+        slug: Some(slug.to_string()),
+        meta_title: None,
+        meta_description: None,
+    }
+}
+
+async fn create_published_page(service: &PageService, tenant_id: Uuid, slug: &str) -> Uuid {
+    service
+        .create(
+            tenant_id,
+            SecurityContext::system(),
+            CreatePageInput {
+                translations: vec![translation("Page", slug)],
+                template: Some("default".to_string()),
+                body: None,
+                blocks: None,
+                channel_slugs: None,
+                publish: true,
+                metadata: None,
+            },
+        )
+        .await
+        .expect("page should be created")
+        .id
+}
+
+#[tokio::test]
+async fn update_changes_title_and_slug() {
+    let (service, tenant_id) = setup().await;
+    let page_id = create_published_page(&service, tenant_id, "about").await;
+
+    service
+        .update(
+            tenant_id,
+            SecurityContext::system(),
+            page_id,
+            UpdatePageInput {
+                translations: Some(vec![translation("About us", "about-us")]),
+                ..UpdatePageInput::default()
+            },
+        )
+        .await
+        .expect("update should succeed");
+
+    let page = service
+        .get_by_slug(tenant_id, SecurityContext::system(), "en", "about-us")
+        .await
+        .expect("lookup should succeed")
+        .expect("page should resolve by its new slug");
+    assert_eq!(page.id, page_id);
+    assert_eq!(
+        page.translation.and_then(|translation| translation.title),
+        Some("About us".to_string())
+    );
+    assert!(service
+        .get_by_slug(tenant_id, SecurityContext::system(), "en", "about")
+        .await
+        .expect("lookup should succeed")
+        .is_none());
+}
+
+#[tokio::test]
+async fn update_rejects_slug_of_another_page() {
+    let (service, tenant_id) = setup().await;
+    create_published_page(&service, tenant_id, "pricing").await;
+    let page_id = create_published_page(&service, tenant_id, "plans").await;
+
+    let result = service
+        .update(
+            tenant_id,
+            SecurityContext::system(),
+            page_id,
+            UpdatePageInput {
+                translations: Some(vec![translation("Plans", "pricing")]),
+                ..UpdatePageInput::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(PagesError::DuplicateSlug { ref slug, ref locale })
+            if slug == "pricing" && locale == "en"
+    ));
+    let unchanged = service
+        .get_by_slug(tenant_id, SecurityContext::system(), "en", "plans")
+        .await
+        .expect("lookup should succeed")
+        .expect("page should keep its slug");
+    assert_eq!(unchanged.id, page_id);
+}
+
+#[tokio::test]
+async fn deleted_page_is_gone_by_slug_and_id() {
+    let (service, tenant_id) = setup().await;
+    let page_id = create_published_page(&service, tenant_id, "old-news").await;
+
+    service
+        .delete(tenant_id, SecurityContext::system(), page_id)
+        .await
+        .expect("delete should succeed");
+
+    assert!(service
+        .get_by_slug(tenant_id, SecurityContext::system(), "en", "old-news")
+        .await
+        .expect("lookup should succeed")
+        .is_none());
+    assert!(matches!(
+        service
+            .get(tenant_id, SecurityContext::system(), page_id)
+            .await,
+        Err(PagesError::PageNotFound(id)) if id == page_id
+    ));
+}
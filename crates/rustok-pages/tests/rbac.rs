@@ -1,3 +1,4 @@
+use rustok_content::MetadataVisibility;
 use rustok_core::{MigrationSource, SecurityContext};
 use rustok_pages::dto::{
     BlockType, CreateBlockInput, CreateMenuInput, CreatePageInput, ListPagesFilter, MenuLocation,
@@ -58,6 +59,7 @@ async fn create_page(
                 blocks: None,
                 channel_slugs: None,
                 publish,
+                metadata: None,
             },
         )
         .await
@@ -86,6 +88,7 @@ async fn manager_cannot_publish_via_create_or_update() {
                 blocks: None,
                 channel_slugs: None,
                 publish: true,
+                metadata: None,
             },
         )
         .await
@@ -136,7 +139,7 @@ async fn customer_cannot_read_drafts_and_list_only_returns_published_pages() {
         .expect("customer should read published page");
     assert_eq!(visible.id, published.id);
 
-    let (items, total) = page_service
+    let pages = page_service
         .list(
             tenant_id,
             customer,
@@ -144,15 +147,16 @@ async fn customer_cannot_read_drafts_and_list_only_returns_published_pages() {
                 status: None,
                 template: None,
                 locale: Some("en".to_string()),
+                search: None,
                 page: 1,
                 per_page: 20,
             },
         )
         .await
         .expect("customer list should succeed");
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].id, published.id);
+    assert_eq!(pages.total, Some(1));
+    assert_eq!(pages.items.len(), 1);
+    assert_eq!(pages.items[0].id, published.id);
 }
 
 #[tokio::test]
@@ -193,3 +197,33 @@ async fn customer_cannot_mutate_blocks_or_menus() {
         .expect_err("customer should not create menus");
     assert!(matches!(denied_menu, PagesError::Forbidden(_)));
 }
+
+#[tokio::test]
+async fn internal_metadata_is_hidden_from_customers_but_kept_for_staff() {
+    let (page_service, _, _, tenant_id) = setup().await;
+    let page_service = page_service.with_metadata_visibility(
+        MetadataVisibility::from_tenant_settings(&serde_json::json!({
+            "content": { "internal_metadata_keys": ["template"] }
+        })),
+    );
+    let page = create_page(&page_service, tenant_id, admin_context(), "about", true).await;
+
+    let public = page_service
+        .get(tenant_id, customer_context(), page.id)
+        .await
+        .expect("customer should read published page");
+    assert!(public.metadata.get("template").is_none());
+
+    let public_by_slug = page_service
+        .get_by_slug(tenant_id, customer_context(), "en", "about")
+        .await
+        .expect("customer should read published page by slug")
+        .expect("page should exist");
+    assert!(public_by_slug.metadata.get("template").is_none());
+
+    let staff = page_service
+        .get(tenant_id, admin_context(), page.id)
+        .await
+        .expect("admin should read page");
+    assert_eq!(staff.metadata["template"], serde_json::json!("default"));
+}
@@ -8,7 +8,7 @@ use tracing::instrument;
 use uuid::Uuid;
 use validator::Validate;
 
-use rustok_core::generate_id;
+use rustok_core::{fetch_with_total, generate_id, Page};
 
 use crate::dto::{
     AuthorizePaymentInput, CancelPaymentInput, CancelRefundInput, CapturePaymentInput,
@@ -37,6 +37,10 @@ impl PaymentService {
         Self { db }
     }
 
+    pub fn db(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
     #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
     pub async fn create_collection(
         &self,
@@ -156,7 +160,7 @@ impl PaymentService {
         &self,
         tenant_id: Uuid,
         input: ListPaymentCollectionsInput,
-    ) -> PaymentResult<(Vec<PaymentCollectionResponse>, u64)> {
+    ) -> PaymentResult<Page<PaymentCollectionResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let offset = (page.saturating_sub(1)) * per_page;
@@ -177,20 +181,20 @@ impl PaymentService {
             query = query.filter(entities::payment_collection::Column::CustomerId.eq(customer_id));
         }
 
-        let total = query.clone().count(&self.db).await?;
-        let rows = query
-            .order_by_desc(entities::payment_collection::Column::CreatedAt)
-            .offset(offset)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let (rows, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::payment_collection::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
         let mut items = Vec::with_capacity(rows.len());
         for row in rows {
             items.push(self.build_response(row).await?);
         }
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset))
     }
 
     pub async fn attach_order_to_collection(
@@ -289,7 +293,7 @@ impl PaymentService {
         &self,
         tenant_id: Uuid,
         input: ListRefundsInput,
-    ) -> PaymentResult<(Vec<RefundResponse>, u64)> {
+    ) -> PaymentResult<Page<RefundResponse>> {
         let page = input.page.max(1);
         let per_page = input.per_page.clamp(1, 100);
         let offset = (page.saturating_sub(1)) * per_page;
@@ -306,7 +310,7 @@ impl PaymentService {
                     .payment_collection_matches_order(tenant_id, collection_id, order_id)
                     .await?;
                 if !matches_order {
-                    return Ok((Vec::new(), 0));
+                    return Ok(Page::empty());
                 }
             }
 
@@ -320,7 +324,7 @@ impl PaymentService {
                 .await?;
 
             if collection_ids.is_empty() {
-                return Ok((Vec::new(), 0));
+                return Ok(Page::empty());
             }
 
             query =
@@ -331,19 +335,20 @@ impl PaymentService {
             query = query.filter(entities::refund::Column::Status.eq(normalized_status));
         }
 
-        let total = query.clone().count(&self.db).await?;
-        let rows = query
-            .order_by_desc(entities::refund::Column::CreatedAt)
-            .offset(offset)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let (rows, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::refund::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
-        Ok((
+        Ok(Page::offset(
             rows.into_iter()
                 .map(|row| self.build_refund_response(row))
                 .collect(),
             total,
+            offset,
         ))
     }
 
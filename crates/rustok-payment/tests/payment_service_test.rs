@@ -307,7 +307,7 @@ async fn refund_lifecycle_tracks_pending_completed_and_cancelled_records() {
     );
     assert_eq!(collection.refunds.len(), 2);
 
-    let (refunds, total) = service
+    let refunds = service
         .list_refunds(
             tenant_id,
             rustok_payment::dto::ListRefundsInput {
@@ -320,9 +320,9 @@ async fn refund_lifecycle_tracks_pending_completed_and_cancelled_records() {
         )
         .await
         .unwrap();
-    assert_eq!(total, 2);
-    assert_eq!(refunds[0].id, second.id);
-    assert_eq!(refunds[1].id, pending.id);
+    assert_eq!(refunds.total, Some(2));
+    assert_eq!(refunds.items[0].id, second.id);
+    assert_eq!(refunds.items[1].id, pending.id);
 }
 
 #[tokio::test]
@@ -392,6 +392,140 @@ async fn refund_amount_cannot_exceed_remaining_captured_total() {
     }
 }
 
+fn refund_input(amount: &str) -> CreateRefundInput {
+    CreateRefundInput {
+        amount: Decimal::from_str(amount).expect("valid decimal"),
+        reason: None,
+        metadata: serde_json::json!({}),
+    }
+}
+
+async fn capture_in_full(service: &PaymentService, tenant_id: Uuid, collection_id: Uuid) {
+    service
+        .authorize_collection(
+            tenant_id,
+            collection_id,
+            AuthorizePaymentInput {
+                provider_id: None,
+                provider_payment_id: None,
+                amount: None,
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+    service
+        .capture_collection(
+            tenant_id,
+            collection_id,
+            CapturePaymentInput {
+                amount: None,
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn full_refund_leaves_nothing_refundable() {
+    let service = setup().await;
+    let tenant_id = Uuid::new_v4();
+
+    let created = service
+        .create_collection(tenant_id, create_collection_input())
+        .await
+        .unwrap();
+    service
+        .authorize_collection(
+            tenant_id,
+            created.id,
+            AuthorizePaymentInput {
+                provider_id: None,
+                provider_payment_id: None,
+                amount: None,
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+    service
+        .capture_collection(
+            tenant_id,
+            created.id,
+            CapturePaymentInput {
+                amount: None,
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+
+    let refund = service
+        .create_refund(tenant_id, created.id, refund_input("99.99"))
+        .await
+        .unwrap();
+    assert_eq!(
+        refund.amount,
+        Decimal::from_str("99.99").expect("valid decimal")
+    );
+
+    let error = service
+        .create_refund(tenant_id, created.id, refund_input("0.01"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, PaymentError::Validation(ref message) if message.contains("remaining refundable amount of 0")),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn refund_requires_captured_collection() {
+    let service = setup().await;
+    let tenant_id = Uuid::new_v4();
+
+    let created = service
+        .create_collection(tenant_id, create_collection_input())
+        .await
+        .unwrap();
+    service
+        .cancel_collection(
+            tenant_id,
+            created.id,
+            CancelPaymentInput {
+                reason: Some("payment-declined".to_string()),
+                metadata: serde_json::json!({}),
+            },
+        )
+        .await
+        .unwrap();
+
+    let error = service
+        .create_refund(tenant_id, created.id, refund_input("10.00"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, PaymentError::InvalidTransition { ref from, .. } if from == "cancelled"),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn refund_of_unknown_collection_is_not_found() {
+    let service = setup().await;
+    let collection_id = Uuid::new_v4();
+
+    let error = service
+        .create_refund(Uuid::new_v4(), collection_id, refund_input("10.00"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, PaymentError::PaymentCollectionNotFound(id) if id == collection_id),
+        "unexpected error: {error:?}"
+    );
+}
+
 #[tokio::test]
 async fn list_refunds_rejects_unknown_status_filter() {
     let service = setup().await;
@@ -465,7 +599,7 @@ async fn list_refunds_accepts_case_insensitive_status_filter() {
         .await
         .unwrap();
 
-    let (items, total) = service
+    let refunds = service
         .list_refunds(
             tenant_id,
             rustok_payment::dto::ListRefundsInput {
@@ -479,9 +613,9 @@ async fn list_refunds_accepts_case_insensitive_status_filter() {
         .await
         .unwrap();
 
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].status, "pending");
+    assert_eq!(refunds.total, Some(1));
+    assert_eq!(refunds.items.len(), 1);
+    assert_eq!(refunds.items[0].status, "pending");
 }
 
 #[tokio::test]
@@ -490,9 +624,10 @@ async fn list_refunds_supports_order_id_filter() {
     let tenant_id = Uuid::new_v4();
     let actor_id = Uuid::new_v4();
 
+    support::ensure_order_schema(service.db()).await;
     let order_service = rustok_order::services::OrderService::new(
-        service.db.clone(),
-        rustok_events::MockTransactionalEventBus::new(),
+        service.db().clone(),
+        rustok_test_utils::mock_transactional_event_bus(),
     );
 
     let first_order = order_service
@@ -576,6 +711,8 @@ async fn list_refunds_supports_order_id_filter() {
         )
         .await
         .unwrap();
+    capture_in_full(&service, tenant_id, first_collection.id).await;
+    capture_in_full(&service, tenant_id, second_collection.id).await;
 
     service
         .create_refund(
@@ -602,7 +739,7 @@ async fn list_refunds_supports_order_id_filter() {
         .await
         .unwrap();
 
-    let (items, total) = service
+    let refunds = service
         .list_refunds(
             tenant_id,
             rustok_payment::dto::ListRefundsInput {
@@ -616,9 +753,9 @@ async fn list_refunds_supports_order_id_filter() {
         .await
         .unwrap();
 
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
-    assert_eq!(items[0].payment_collection_id, first_collection.id);
+    assert_eq!(refunds.total, Some(1));
+    assert_eq!(refunds.items.len(), 1);
+    assert_eq!(refunds.items[0].payment_collection_id, first_collection.id);
 }
 
 #[tokio::test]
@@ -626,7 +763,7 @@ async fn list_refunds_returns_empty_for_unknown_order_id() {
     let service = setup().await;
     let tenant_id = Uuid::new_v4();
 
-    let (items, total) = service
+    let refunds = service
         .list_refunds(
             tenant_id,
             rustok_payment::dto::ListRefundsInput {
@@ -640,8 +777,8 @@ async fn list_refunds_returns_empty_for_unknown_order_id() {
         .await
         .unwrap();
 
-    assert_eq!(total, 0);
-    assert!(items.is_empty());
+    assert_eq!(refunds.total, Some(0));
+    assert!(refunds.items.is_empty());
 }
 
 #[tokio::test]
@@ -650,9 +787,10 @@ async fn list_refunds_combined_order_and_collection_filters_require_intersection
     let tenant_id = Uuid::new_v4();
     let actor_id = Uuid::new_v4();
 
+    support::ensure_order_schema(service.db()).await;
     let order_service = rustok_order::services::OrderService::new(
-        service.db.clone(),
-        rustok_events::MockTransactionalEventBus::new(),
+        service.db().clone(),
+        rustok_test_utils::mock_transactional_event_bus(),
     );
     let first_order = order_service
         .create_order(
@@ -735,6 +873,8 @@ async fn list_refunds_combined_order_and_collection_filters_require_intersection
         )
         .await
         .unwrap();
+    capture_in_full(&service, tenant_id, first_collection.id).await;
+    capture_in_full(&service, tenant_id, second_collection.id).await;
 
     service
         .create_refund(
@@ -761,7 +901,7 @@ async fn list_refunds_combined_order_and_collection_filters_require_intersection
         .await
         .unwrap();
 
-    let (items, total) = service
+    let refunds = service
         .list_refunds(
             tenant_id,
             rustok_payment::dto::ListRefundsInput {
@@ -775,6 +915,6 @@ async fn list_refunds_combined_order_and_collection_filters_require_intersection
         .await
         .unwrap();
 
-    assert_eq!(total, 0);
-    assert!(items.is_empty());
+    assert_eq!(refunds.total, Some(0));
+    assert!(refunds.items.is_empty());
 }
@@ -1124,10 +1124,11 @@ async fn pricing_admin_bootstrap_native() -> Result<PricingAdminBootstrap, Serve
         let channel_slug = request_context
             .as_ref()
             .and_then(|ctx| sanitize_channel_slug(ctx.channel_slug.clone()));
-        let (available_channels, _) = channel_service
+        let available_channels = channel_service
             .list_channels(tenant.id, 1, 250)
             .await
-            .map_err(ServerFnError::new)?;
+            .map_err(ServerFnError::new)?
+            .items;
         let active_price_lists = service
             .list_active_price_lists_for_channel(
                 tenant.id,
@@ -1,8 +1,8 @@
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap};
@@ -10,7 +10,7 @@ use tracing::instrument;
 use uuid::Uuid;
 
 use rustok_core::events::ValidateEvent;
-use rustok_core::{generate_id, locale_tags_match, normalize_locale_tag};
+use rustok_core::{fetch_with_total, generate_id, locale_tags_match, normalize_locale_tag};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 use rustok_product::CatalogService;
@@ -1236,13 +1236,13 @@ impl PricingService {
             query = query.filter(entities::product::Column::Id.is_in(matched_ids));
         }
 
-        let total = query.clone().count(&self.db).await?;
-        let products = query
-            .order_by_desc(entities::product::Column::CreatedAt)
-            .offset(offset)
-            .limit(per_page)
-            .all(&self.db)
-            .await?;
+        let (products, total) = fetch_with_total(
+            &self.db,
+            query.order_by_desc(entities::product::Column::CreatedAt),
+            offset,
+            per_page,
+        )
+        .await?;
 
         let mut items = Vec::with_capacity(products.len());
         for product in products {
@@ -609,10 +609,11 @@ async fn storefront_pricing_native(
             transactional_event_bus_from_context(&app_ctx),
         );
         let channel_service = ChannelService::new(app_ctx.db.clone());
-        let (available_channels, _) = channel_service
+        let available_channels = channel_service
             .list_channels(tenant.id, 1, 250)
             .await
-            .map_err(ServerFnError::new)?;
+            .map_err(ServerFnError::new)?
+            .items;
         let active_price_lists = service
             .list_active_price_lists_for_channel(
                 tenant.id,
@@ -9,7 +9,9 @@ pub mod migrations;
 mod seo_targets;
 pub mod services;
 
-pub use services::{CatalogService, StorefrontProductList, StorefrontProductListItem};
+pub use services::{
+    load_product_availability, CatalogService, StorefrontProductList, StorefrontProductListItem,
+};
 
 pub struct ProductModule;
 
@@ -11,6 +11,7 @@ mod m20260405_000004_add_variant_shipping_profile_slug;
 mod m20260405_000005_add_product_shipping_profile_slug;
 mod m20260405_000006_add_is_localized_to_product_field_definitions;
 mod m20260409_000007_add_product_seller_id;
+mod m20260415_000008_add_product_audit_actors;
 
 use rustok_core::MigrationDependencyDescriptor;
 use sea_orm_migration::MigrationTrait;
@@ -28,6 +29,7 @@ pub fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         Box::new(m20260405_000005_add_product_shipping_profile_slug::Migration),
         Box::new(m20260405_000006_add_is_localized_to_product_field_definitions::Migration),
         Box::new(m20260409_000007_add_product_seller_id::Migration),
+        Box::new(m20260415_000008_add_product_audit_actors::Migration),
     ]
 }
 
@@ -0,0 +1,276 @@
+//! Purchase eligibility read model.
+//!
+//! Storefront reads and the checkout guard both ask "can this product be
+//! bought right now"; the answer lives here so publish state, sale window
+//! and stock are checked the same way everywhere.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use serde_json::Value;
+use uuid::Uuid;
+
+use rustok_commerce_foundation::dto::{Availability, UnavailableReason};
+use rustok_commerce_foundation::entities;
+use rustok_commerce_foundation::error::{CommerceError, CommerceResult};
+use rustok_core::TimeBounds;
+
+use super::catalog::{
+    is_metadata_visible_for_public_channel, load_available_inventory_by_variant_for_public_channel,
+};
+
+/// Optional `metadata.sale_window.{starts_at, ends_at}` (RFC 3339) limiting
+/// when a published product can be bought.
+fn sale_window(metadata: &Value) -> TimeBounds {
+    let bound = |key: &str| {
+        metadata
+            .pointer(&format!("/sale_window/{key}"))
+            .and_then(Value::as_str)
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .map(|value| value.with_timezone(&Utc))
+    };
+
+    TimeBounds {
+        not_before: bound("starts_at"),
+        not_after: bound("ends_at"),
+        // Sale windows are business rules, not cross-host timestamps.
+        leeway: Duration::zero(),
+    }
+}
+
+/// Evaluate purchase eligibility from already loaded rows.
+///
+/// `available_by_variant` holds sellable stock per variant as returned by the
+/// inventory projection; missing entries count as zero.
+pub fn evaluate_product_availability(
+    product: &entities::product::Model,
+    variants: &[entities::product_variant::Model],
+    available_by_variant: &HashMap<Uuid, i32>,
+    public_channel_slug: Option<&str>,
+    now: DateTime<Utc>,
+) -> Availability {
+    let available_quantity = variants
+        .iter()
+        .map(|variant| {
+            available_by_variant
+                .get(&variant.id)
+                .copied()
+                .unwrap_or(0)
+                .max(0)
+        })
+        .sum::<i32>();
+
+    match product.status {
+        entities::product::ProductStatus::Active => {}
+        entities::product::ProductStatus::Archived => {
+            return Availability::unavailable(UnavailableReason::Archived, available_quantity)
+        }
+        entities::product::ProductStatus::Draft => {
+            return Availability::unavailable(UnavailableReason::NotPublished, available_quantity)
+        }
+    }
+    let Some(published_at) = product.published_at else {
+        return Availability::unavailable(UnavailableReason::NotPublished, available_quantity);
+    };
+    if !is_metadata_visible_for_public_channel(&product.metadata, public_channel_slug) {
+        return Availability::unavailable(UnavailableReason::NotInChannel, available_quantity);
+    }
+
+    let window = sale_window(&product.metadata);
+    if published_at.with_timezone(&Utc) > now || window.is_premature_at(now) {
+        return Availability::unavailable(UnavailableReason::NotYetAvailable, available_quantity);
+    }
+    if window.is_expired_at(now) {
+        return Availability::unavailable(UnavailableReason::SaleEnded, available_quantity);
+    }
+
+    let in_stock = variants.iter().any(|variant| {
+        variant.inventory_policy.eq_ignore_ascii_case("continue")
+            || available_by_variant.get(&variant.id).copied().unwrap_or(0) > 0
+    });
+    if !in_stock {
+        return Availability::unavailable(UnavailableReason::OutOfStock, available_quantity);
+    }
+
+    Availability::available(available_quantity)
+}
+
+/// Load and evaluate purchase eligibility for one product.
+pub async fn load_product_availability(
+    db: &DatabaseConnection,
+    tenant_id: Uuid,
+    product_id: Uuid,
+    public_channel_slug: Option<&str>,
+) -> CommerceResult<Availability> {
+    let product = entities::product::Entity::find_by_id(product_id)
+        .filter(entities::product::Column::TenantId.eq(tenant_id))
+        .one(db)
+        .await?
+        .ok_or(CommerceError::ProductNotFound(product_id))?;
+    let variants = entities::product_variant::Entity::find()
+        .filter(entities::product_variant::Column::TenantId.eq(tenant_id))
+        .filter(entities::product_variant::Column::ProductId.eq(product_id))
+        .all(db)
+        .await?;
+    let variant_ids = variants
+        .iter()
+        .map(|variant| variant.id)
+        .collect::<Vec<_>>();
+    let available_by_variant = load_available_inventory_by_variant_for_public_channel(
+        db,
+        tenant_id,
+        &variant_ids,
+        public_channel_slug,
+    )
+    .await?;
+
+    Ok(evaluate_product_availability(
+        &product,
+        &variants,
+        &available_by_variant,
+        public_channel_slug,
+        Utc::now(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn product(
+        status: entities::product::ProductStatus,
+        published_at: Option<DateTime<Utc>>,
+        metadata: Value,
+    ) -> entities::product::Model {
+        let now = Utc::now();
+        entities::product::Model {
+            id: Uuid::new_v4(),
+            tenant_id: Uuid::new_v4(),
+            status,
+            seller_id: None,
+            vendor: None,
+            product_type: None,
+            shipping_profile_slug: None,
+            metadata,
+            created_at: now.into(),
+            updated_at: now.into(),
+            published_at: published_at.map(Into::into),
+            created_by: None,
+            updated_by: None,
+        }
+    }
+
+    fn variant(product_id: Uuid, inventory_policy: &str) -> entities::product_variant::Model {
+        let now = Utc::now();
+        entities::product_variant::Model {
+            id: Uuid::new_v4(),
+            product_id,
+            tenant_id: Uuid::new_v4(),
+            sku: None,
+            barcode: None,
+            shipping_profile_slug: None,
+            ean: None,
+            upc: None,
+            inventory_policy: inventory_policy.to_string(),
+            inventory_management: "manual".to_string(),
+            inventory_quantity: 0,
+            weight: None,
+            weight_unit: None,
+            option1: None,
+            option2: None,
+            option3: None,
+            position: 0,
+            created_at: now.into(),
+            updated_at: now.into(),
+        }
+    }
+
+    #[test]
+    fn reports_publish_state_before_stock() {
+        let now = Utc::now();
+        let draft = product(entities::product::ProductStatus::Draft, None, json!({}));
+        let archived = product(
+            entities::product::ProductStatus::Archived,
+            Some(now),
+            json!({}),
+        );
+        let scheduled = product(
+            entities::product::ProductStatus::Active,
+            Some(now + Duration::days(1)),
+            json!({}),
+        );
+
+        for (product, reason) in [
+            (draft, UnavailableReason::NotPublished),
+            (archived, UnavailableReason::Archived),
+            (scheduled, UnavailableReason::NotYetAvailable),
+        ] {
+            let availability =
+                evaluate_product_availability(&product, &[], &HashMap::new(), None, now);
+            assert!(!availability.purchasable);
+            assert_eq!(availability.reason, Some(reason));
+        }
+    }
+
+    #[test]
+    fn applies_sale_window_and_channel() {
+        let now = Utc::now();
+        let metadata = json!({
+            "sale_window": { "ends_at": (now - Duration::hours(1)).to_rfc3339() },
+            "channel_visibility": { "allowed_channel_slugs": ["web"] }
+        });
+        let ended = product(
+            entities::product::ProductStatus::Active,
+            Some(now - Duration::days(1)),
+            metadata,
+        );
+
+        let in_channel =
+            evaluate_product_availability(&ended, &[], &HashMap::new(), Some("web"), now);
+        let other_channel =
+            evaluate_product_availability(&ended, &[], &HashMap::new(), Some("pos"), now);
+
+        assert_eq!(in_channel.reason, Some(UnavailableReason::SaleEnded));
+        assert_eq!(other_channel.reason, Some(UnavailableReason::NotInChannel));
+    }
+
+    #[test]
+    fn stock_and_backorder_decide_purchasability() {
+        let now = Utc::now();
+        let active = product(
+            entities::product::ProductStatus::Active,
+            Some(now - Duration::days(1)),
+            json!({}),
+        );
+        let stocked = variant(active.id, "deny");
+        let empty = variant(active.id, "deny");
+        let backorder = variant(active.id, "continue");
+
+        let out_of_stock = evaluate_product_availability(
+            &active,
+            std::slice::from_ref(&empty),
+            &HashMap::from([(empty.id, -2)]),
+            None,
+            now,
+        );
+        assert_eq!(
+            out_of_stock,
+            Availability::unavailable(UnavailableReason::OutOfStock, 0)
+        );
+
+        let available = evaluate_product_availability(
+            &active,
+            &[stocked.clone(), empty],
+            &HashMap::from([(stocked.id, 3)]),
+            None,
+            now,
+        );
+        assert_eq!(available, Availability::available(3));
+
+        let backordered =
+            evaluate_product_availability(&active, &[backorder], &HashMap::new(), None, now);
+        assert!(backordered.purchasable);
+    }
+}
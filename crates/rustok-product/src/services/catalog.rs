@@ -24,6 +24,7 @@ use rustok_commerce_foundation::dto::*;
 use rustok_commerce_foundation::entities;
 use rustok_commerce_foundation::error::{CommerceError, CommerceResult};
 
+use super::availability::load_product_availability;
 use crate::entities::product_tag;
 
 const PRODUCT_SCOPE_VALUE: &str = "product";
@@ -163,6 +164,8 @@ impl CatalogService {
             } else {
                 None
             }),
+            created_by: Set(Some(actor_id)),
+            updated_by: Set(Some(actor_id)),
         };
         product.insert(&txn).await?;
         debug!("Product entity inserted");
@@ -692,6 +695,8 @@ impl CatalogService {
             created_at: product.created_at.into(),
             updated_at: product.updated_at.into(),
             published_at: product.published_at.map(Into::into),
+            created_by: product.created_by,
+            updated_by: product.updated_by,
             translations: translations
                 .into_iter()
                 .map(|translation| ProductTranslationResponse {
@@ -899,6 +904,8 @@ impl CatalogService {
         )
         .await?;
 
+        product.strip_audit_actors();
+
         Ok(Some(localize_product_response(
             product,
             locale,
@@ -906,6 +913,30 @@ impl CatalogService {
         )))
     }
 
+    /// Whether the product can be purchased right now, with the reason when it
+    /// cannot.
+    #[instrument(skip(self))]
+    pub async fn product_availability(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+    ) -> CommerceResult<Availability> {
+        self.product_availability_for_channel(tenant_id, product_id, None)
+            .await
+    }
+
+    /// [`Self::product_availability`] as seen from a storefront channel, using
+    /// only stock locations visible to it.
+    #[instrument(skip(self))]
+    pub async fn product_availability_for_channel(
+        &self,
+        tenant_id: Uuid,
+        product_id: Uuid,
+        public_channel_slug: Option<&str>,
+    ) -> CommerceResult<Availability> {
+        load_product_availability(&self.db, tenant_id, product_id, public_channel_slug).await
+    }
+
     #[instrument(skip(self, input))]
     pub async fn update_product(
         &self,
@@ -933,6 +964,7 @@ impl CatalogService {
         let existing_product = product.clone();
         let mut product_active: entities::product::ActiveModel = product.into();
         product_active.updated_at = Set(Utc::now().into());
+        product_active.updated_by = Set(Some(actor_id));
 
         let preferred_locale = input
             .translations
@@ -1103,6 +1135,7 @@ impl CatalogService {
         product_active.status = Set(entities::product::ProductStatus::Active);
         product_active.published_at = Set(Some(Utc::now().into()));
         product_active.updated_at = Set(Utc::now().into());
+        product_active.updated_by = Set(Some(actor_id));
         product_active.update(&txn).await?;
 
         self.event_bus
@@ -1140,6 +1173,7 @@ impl CatalogService {
         let mut product_active: entities::product::ActiveModel = product.into();
         product_active.status = Set(entities::product::ProductStatus::Draft);
         product_active.updated_at = Set(Utc::now().into());
+        product_active.updated_by = Set(Some(actor_id));
         product_active.update(&txn).await?;
 
         self.event_bus
@@ -2337,7 +2371,7 @@ fn is_allowlist_visible_for_public_channel(
         .any(|slug| slug == &public_channel_slug)
 }
 
-fn is_metadata_visible_for_public_channel(
+pub(super) fn is_metadata_visible_for_public_channel(
     metadata: &Value,
     public_channel_slug: Option<&str>,
 ) -> bool {
@@ -2345,7 +2379,7 @@ fn is_metadata_visible_for_public_channel(
     is_allowlist_visible_for_public_channel(&allowed_channel_slugs, public_channel_slug)
 }
 
-async fn load_available_inventory_by_variant_for_public_channel(
+pub(super) async fn load_available_inventory_by_variant_for_public_channel(
     db: &DatabaseConnection,
     tenant_id: Uuid,
     variant_ids: &[Uuid],
@@ -1,3 +1,5 @@
+pub mod availability;
 pub mod catalog;
 
+pub use availability::{evaluate_product_availability, load_product_availability};
 pub use catalog::{CatalogService, StorefrontProductList, StorefrontProductListItem};
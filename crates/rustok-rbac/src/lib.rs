@@ -32,6 +32,7 @@ pub use services::permission_resolver::{PermissionResolution, PermissionResolver
 pub use services::relation_permission_resolver::{
     invalidate_cached_permissions, resolve_permissions_from_relations,
     resolve_permissions_with_cache, PermissionCache, RelationPermissionStore,
+    PERMISSION_CACHE_METRICS_NAME,
 };
 pub use services::runtime_permission_resolver::{RoleAssignmentStore, RuntimePermissionResolver};
 
@@ -1,5 +1,11 @@
 use crate::services::permission_normalization::normalize_permissions;
 use rustok_core::Permission;
+use rustok_telemetry::metrics;
+
+/// Cache label under which permission lookups are reported in
+/// `rustok_cache_operations_total`, `rustok_cache_hit_rate` and
+/// `rustok_cache_size`.
+pub const PERMISSION_CACHE_METRICS_NAME: &str = "rbac_permissions";
 
 #[async_trait::async_trait]
 pub trait RelationPermissionStore {
@@ -35,6 +41,11 @@ pub trait PermissionCache {
     );
 
     async fn invalidate(&self, tenant_id: &uuid::Uuid, user_id: &uuid::Uuid);
+
+    /// Current number of cached entries, if the cache can report it.
+    fn entry_count(&self) -> Option<u64> {
+        None
+    }
 }
 
 pub async fn resolve_permissions_from_relations<S: RelationPermissionStore>(
@@ -72,17 +83,24 @@ where
     C: PermissionCache,
 {
     if let Some(cached_permissions) = cache.get(tenant_id, user_id).await {
+        metrics::record_cache_lookup(PERMISSION_CACHE_METRICS_NAME, true);
         return Ok(crate::PermissionResolution {
             permissions: normalize_permissions(cached_permissions),
             cache_hit: true,
         });
     }
 
+    metrics::record_cache_lookup(PERMISSION_CACHE_METRICS_NAME, false);
     let resolved_permissions =
         resolve_permissions_from_relations(store, tenant_id, user_id).await?;
     cache
         .insert(tenant_id, user_id, resolved_permissions.clone())
         .await;
+    // Misses are the only path that grows the cache, so refreshing the size
+    // here keeps the gauge current without a background task.
+    if let Some(entries) = cache.entry_count() {
+        metrics::update_cache_size(PERMISSION_CACHE_METRICS_NAME, entries as i64);
+    }
 
     Ok(crate::PermissionResolution {
         permissions: resolved_permissions,
@@ -264,6 +282,12 @@ mod tests {
             permissions: vec![Permission::USERS_READ],
         };
         let cache = StubCache::default();
+        let lookups = |result: &str| {
+            rustok_telemetry::metrics::CACHE_OPERATIONS_TOTAL
+                .with_label_values(&[super::PERMISSION_CACHE_METRICS_NAME, "get", result])
+                .get()
+        };
+        let (hits_before, misses_before) = (lookups("hit"), lookups("miss"));
 
         let first = resolve_permissions_with_cache(&store, &cache, &tenant_id, &user_id)
             .await
@@ -275,6 +299,8 @@ mod tests {
         assert!(!first.cache_hit);
         assert!(second.cache_hit);
         assert_eq!(second.permissions, vec![Permission::USERS_READ]);
+        assert!(lookups("hit") > hits_before);
+        assert!(lookups("miss") > misses_before);
     }
     #[tokio::test]
     async fn invalidate_cached_permissions_evicts_entry() {
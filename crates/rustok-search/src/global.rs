@@ -0,0 +1,266 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::engine::{SearchEngine, SearchQuery, SearchResultItem};
+use rustok_core::Result;
+
+/// Source a [`SearchHit`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchHitKind {
+    Content,
+    Product,
+}
+
+impl SearchHitKind {
+    pub const ALL: [Self; 2] = [Self::Content, Self::Product];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Content => "content",
+            Self::Product => "product",
+        }
+    }
+
+    /// `entity_type` of the search documents backing this kind.
+    pub fn entity_type(&self) -> &'static str {
+        match self {
+            Self::Content => "node",
+            Self::Product => "product",
+        }
+    }
+
+    pub fn try_from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "content" | "node" => Some(Self::Content),
+            "product" => Some(Self::Product),
+            _ => None,
+        }
+    }
+}
+
+/// One merged result. `score` is normalized to `0.0..=1.0` within its source
+/// so content and product hits are comparable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    pub id: Uuid,
+    pub title: String,
+    pub snippet: Option<String>,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlobalSearchResult {
+    pub hits: Vec<SearchHit>,
+    /// Matches across all requested kinds, before pagination.
+    pub total: u64,
+}
+
+/// Searches content and products separately and merges them into one ranked
+/// list.
+///
+/// Raw engine scores are not comparable between sources (product documents
+/// carry SKUs and prices, content documents long bodies), so each source's
+/// scores are divided by that source's best score before merging. The top hit
+/// of every source therefore scores `1.0` and neither source dominates just
+/// because its documents rank higher in absolute terms.
+pub struct GlobalSearchService {
+    engine: Arc<dyn SearchEngine>,
+}
+
+impl GlobalSearchService {
+    pub fn new(engine: Arc<dyn SearchEngine>) -> Self {
+        Self { engine }
+    }
+
+    /// Run `query` against each of `kinds` (all kinds when empty) and return
+    /// the `query.offset`/`query.limit` page of the merged ranking.
+    /// `query.entity_types` is ignored in favour of `kinds`.
+    pub async fn search(
+        &self,
+        query: SearchQuery,
+        kinds: &[SearchHitKind],
+    ) -> Result<GlobalSearchResult> {
+        let kinds = if kinds.is_empty() {
+            &SearchHitKind::ALL[..]
+        } else {
+            kinds
+        };
+        // Any hit on the requested page ranks within the first
+        // `offset + limit` of its own source.
+        let window = query.offset.saturating_add(query.limit);
+
+        let mut hits = Vec::new();
+        let mut total = 0;
+        for kind in kinds {
+            let result = self
+                .engine
+                .search(SearchQuery {
+                    entity_types: vec![kind.entity_type().to_string()],
+                    offset: 0,
+                    limit: window,
+                    ..query.clone()
+                })
+                .await?;
+            total += result.total;
+            hits.extend(normalize_scores(*kind, result.items));
+        }
+
+        hits.sort_by(|left, right| {
+            right
+                .score
+                .partial_cmp(&left.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| left.title.cmp(&right.title))
+        });
+
+        Ok(GlobalSearchResult {
+            hits: hits
+                .into_iter()
+                .skip(query.offset)
+                .take(query.limit)
+                .collect(),
+            total,
+        })
+    }
+}
+
+fn normalize_scores(kind: SearchHitKind, items: Vec<SearchResultItem>) -> Vec<SearchHit> {
+    let best = items
+        .iter()
+        .map(|item| item.score)
+        .filter(|score| score.is_finite())
+        .fold(0.0_f64, f64::max);
+
+    items
+        .into_iter()
+        .map(|item| SearchHit {
+            kind,
+            id: item.id,
+            title: item.title,
+            snippet: item.snippet,
+            score: if best > 0.0 && item.score.is_finite() {
+                (item.score / best).clamp(0.0, 1.0)
+            } else {
+                0.0
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{SearchConnectorDescriptor, SearchEngineKind, SearchResult};
+    use crate::ranking::SearchRankingProfile;
+    use async_trait::async_trait;
+
+    /// Content scores are an order of magnitude above product scores.
+    struct FixedEngine;
+
+    fn item(entity_type: &str, title: &str, score: f64) -> SearchResultItem {
+        SearchResultItem {
+            id: Uuid::new_v4(),
+            entity_type: entity_type.to_string(),
+            source_module: entity_type.to_string(),
+            title: title.to_string(),
+            snippet: None,
+            score,
+            locale: None,
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    #[async_trait]
+    impl SearchEngine for FixedEngine {
+        fn kind(&self) -> SearchEngineKind {
+            SearchEngineKind::Postgres
+        }
+
+        fn descriptor(&self) -> SearchConnectorDescriptor {
+            SearchConnectorDescriptor::postgres_default()
+        }
+
+        async fn search(&self, query: SearchQuery) -> Result<SearchResult> {
+            let items = match query.entity_types.first().map(String::as_str) {
+                Some("node") => vec![
+                    item("node", "Article A", 40.0),
+                    item("node", "Article B", 10.0),
+                ],
+                Some("product") => vec![
+                    item("product", "Product A", 2.0),
+                    item("product", "Product B", 1.5),
+                ],
+                _ => Vec::new(),
+            };
+            Ok(SearchResult {
+                total: items.len() as u64,
+                items: items.into_iter().take(query.limit).collect(),
+                took_ms: 0,
+                engine: SearchEngineKind::Postgres,
+                ranking_profile: query.ranking_profile,
+                facets: Vec::new(),
+            })
+        }
+    }
+
+    fn query(offset: usize, limit: usize) -> SearchQuery {
+        SearchQuery {
+            tenant_id: None,
+            locale: None,
+            original_query: "shoe".to_string(),
+            query: "shoe".to_string(),
+            ranking_profile: SearchRankingProfile::Balanced,
+            preset_key: None,
+            limit,
+            offset,
+            published_only: true,
+            entity_types: Vec::new(),
+            source_modules: Vec::new(),
+            statuses: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn normalizes_scores_so_sources_interleave() {
+        let service = GlobalSearchService::new(Arc::new(FixedEngine));
+
+        let result = service.search(query(0, 10), &[]).await.unwrap();
+
+        let titles: Vec<_> = result.hits.iter().map(|hit| hit.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["Article A", "Product A", "Product B", "Article B"]
+        );
+        assert_eq!(result.total, 4);
+        assert_eq!(result.hits[0].score, 1.0);
+        assert_eq!(result.hits[1].kind, SearchHitKind::Product);
+    }
+
+    #[tokio::test]
+    async fn filters_by_kind_and_paginates_merged_list() {
+        let service = GlobalSearchService::new(Arc::new(FixedEngine));
+
+        let products = service
+            .search(query(0, 10), &[SearchHitKind::Product])
+            .await
+            .unwrap();
+        assert!(products
+            .hits
+            .iter()
+            .all(|hit| hit.kind == SearchHitKind::Product));
+        assert_eq!(products.total, 2);
+
+        let second_page = service.search(query(1, 2), &[]).await.unwrap();
+        let titles: Vec<_> = second_page
+            .hits
+            .iter()
+            .map(|hit| hit.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Product A", "Product B"]);
+    }
+}
@@ -55,6 +55,7 @@ impl EventHandler for SearchIngestionHandler {
             | DomainEvent::NodeTranslationUpdated { .. }
             | DomainEvent::NodePublished { .. }
             | DomainEvent::NodeUnpublished { .. }
+            | DomainEvent::NodesPublished { .. }
             | DomainEvent::NodeDeleted { .. }
             | DomainEvent::BodyUpdated { .. }
             | DomainEvent::CategoryUpdated { .. }
@@ -90,6 +91,11 @@ impl EventHandler for SearchIngestionHandler {
                     .upsert_node(envelope.tenant_id, *node_id)
                     .await
             }
+            DomainEvent::NodesPublished { node_ids } => {
+                self.projector
+                    .upsert_nodes(envelope.tenant_id, node_ids)
+                    .await
+            }
             DomainEvent::NodeTranslationUpdated { node_id, locale }
             | DomainEvent::BodyUpdated { node_id, locale } => {
                 self.projector
@@ -238,5 +244,6 @@ fn classify_error(error: &Error) -> &'static str {
         Error::Serialization(_) => "serialization",
         Error::Scripting(_) => "scripting",
         Error::InvalidIdFormat(_) => "invalid_id",
+        Error::Unavailable(_) => "unavailable",
     }
 }
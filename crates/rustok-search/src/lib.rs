@@ -9,6 +9,7 @@ pub mod analytics;
 pub mod diagnostics;
 pub mod dictionaries;
 pub mod engine;
+pub mod global;
 pub mod ingestion;
 pub mod migrations;
 pub mod models;
@@ -34,6 +35,7 @@ pub use dictionaries::{
 };
 pub use engine::{SearchConnectorDescriptor, SearchEngine, SearchEngineKind, SearchQuery};
 pub use engine::{SearchResult, SearchResultItem};
+pub use global::{GlobalSearchResult, GlobalSearchService, SearchHit, SearchHitKind};
 pub use ingestion::SearchIngestionHandler;
 pub use models::SearchSettingsRecord;
 pub use pg_engine::PgSearchEngine;
@@ -138,6 +138,30 @@ impl SearchProjector {
         result
     }
 
+    /// Re-project several nodes in one transaction (batch publish).
+    pub async fn upsert_nodes(&self, tenant_id: Uuid, node_ids: &[Uuid]) -> Result<()> {
+        self.ensure_postgres()?;
+        let started_at = Instant::now();
+        let tx = self.begin_transaction().await?;
+        let result = async {
+            for node_id in node_ids {
+                self.delete_node_in(&tx, tenant_id, *node_id).await?;
+                self.upsert_content_documents_in(&tx, tenant_id, Some(*node_id), None, None)
+                    .await?;
+            }
+            self.commit_transaction(tx).await
+        }
+        .await;
+        record_projector_operation(
+            "upsert_nodes",
+            "node",
+            tenant_id,
+            &result,
+            started_at.elapsed(),
+        );
+        result
+    }
+
     pub async fn upsert_node_locale(
         &self,
         tenant_id: Uuid,
@@ -671,5 +695,6 @@ fn classify_error(error: &Error) -> &'static str {
         Error::Serialization(_) => "serialization",
         Error::Scripting(_) => "scripting",
         Error::InvalidIdFormat(_) => "invalid_id",
+        Error::Unavailable(_) => "unavailable",
     }
 }
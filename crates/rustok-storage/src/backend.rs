@@ -1,6 +1,8 @@
+use std::ops::Range;
+
 use async_trait::async_trait;
 
-use crate::error::Result;
+use crate::error::{Result, StorageError};
 
 /// Metadata returned after a successful upload.
 #[derive(Debug, Clone)]
@@ -32,6 +34,18 @@ pub trait StorageBackend: Send + Sync {
     /// Read the raw object bytes for private download or validation flows.
     async fn read(&self, path: &str) -> Result<bytes::Bytes>;
 
+    /// Read bytes `range.start..range.end` of the object, for ranged
+    /// downloads. `end` is clamped to the object size; an empty range or one
+    /// starting past the end is [`StorageError::InvalidRange`].
+    ///
+    /// The default reads the whole object and slices it; drivers that can
+    /// fetch a range natively override it.
+    async fn read_range(&self, path: &str, range: Range<u64>) -> Result<bytes::Bytes> {
+        let data = self.read(path).await?;
+        let range = clamp_range(path, range, data.len() as u64)?;
+        Ok(data.slice(range.start as usize..range.end as usize))
+    }
+
     /// Resolve a private download URL when the backend supports native presigning.
     async fn private_download_url(
         &self,
@@ -45,3 +59,15 @@ pub trait StorageBackend: Send + Sync {
     /// Stable backend identifier for diagnostics and persisted metadata.
     fn backend_name(&self) -> &'static str;
 }
+
+/// Validate `range` against an object of `size` bytes, clamping its end.
+pub(crate) fn clamp_range(path: &str, range: Range<u64>, size: u64) -> Result<Range<u64>> {
+    if range.start >= range.end || range.start >= size {
+        return Err(StorageError::InvalidRange {
+            path: path.to_string(),
+            start: range.start,
+            end: range.end,
+        });
+    }
+    Ok(range.start..range.end.min(size))
+}
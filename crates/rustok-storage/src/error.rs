@@ -11,6 +11,9 @@ pub enum StorageError {
     #[error("Object not found: {0}")]
     NotFound(String),
 
+    #[error("Invalid byte range {start}..{end} for {path}")]
+    InvalidRange { path: String, start: u64, end: u64 },
+
     #[error("Backend error: {0}")]
     Backend(String),
 }
@@ -1,10 +1,13 @@
+use std::io::SeekFrom;
+use std::ops::Range;
 use std::path::PathBuf;
 
 use async_trait::async_trait;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 use tracing::instrument;
 
 use crate::{
-    backend::{StorageBackend, UploadedObject},
+    backend::{clamp_range, StorageBackend, UploadedObject},
     error::{Result, StorageError},
 };
 
@@ -80,6 +83,25 @@ impl StorageBackend for LocalStorage {
         }
     }
 
+    #[instrument(skip(self), fields(path))]
+    async fn read_range(&self, path: &str, range: Range<u64>) -> Result<bytes::Bytes> {
+        let dest = self.resolve(path)?;
+        let mut file = match tokio::fs::File::open(&dest).await {
+            Ok(file) => file,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::NotFound(path.to_string()));
+            }
+            Err(error) => return Err(StorageError::Io(error)),
+        };
+        let size = file.metadata().await?.len();
+        let range = clamp_range(path, range, size)?;
+
+        file.seek(SeekFrom::Start(range.start)).await?;
+        let mut buffer = vec![0; (range.end - range.start) as usize];
+        file.read_exact(&mut buffer).await?;
+        Ok(bytes::Bytes::from(buffer))
+    }
+
     async fn private_download_url(
         &self,
         _path: &str,
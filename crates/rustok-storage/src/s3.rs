@@ -168,6 +168,42 @@ impl StorageBackend for S3Storage {
         Self::collect_bytes(output).await
     }
 
+    async fn read_range(&self, path: &str, range: std::ops::Range<u64>) -> Result<bytes::Bytes> {
+        if range.start >= range.end {
+            return Err(StorageError::InvalidRange {
+                path: path.to_string(),
+                start: range.start,
+                end: range.end,
+            });
+        }
+        let key = self.object_key(path)?;
+        // HTTP ranges are inclusive; S3 clamps the end to the object size
+        // and rejects a start past it with 416 Range Not Satisfiable.
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .range(format!("bytes={}-{}", range.start, range.end - 1))
+            .send()
+            .await
+            .map_err(|error| {
+                let status = error
+                    .raw_response()
+                    .map(|response| response.status().as_u16());
+                if status == Some(416) {
+                    StorageError::InvalidRange {
+                        path: path.to_string(),
+                        start: range.start,
+                        end: range.end,
+                    }
+                } else {
+                    StorageError::Backend(error.to_string())
+                }
+            })?;
+        Self::collect_bytes(output).await
+    }
+
     async fn private_download_url(
         &self,
         path: &str,
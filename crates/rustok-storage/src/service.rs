@@ -65,6 +65,14 @@ impl StorageService {
         self.0.read(path).await
     }
 
+    pub async fn read_range(
+        &self,
+        path: &str,
+        range: std::ops::Range<u64>,
+    ) -> Result<bytes::Bytes> {
+        self.0.read_range(path, range).await
+    }
+
     pub async fn private_download_url(
         &self,
         path: &str,
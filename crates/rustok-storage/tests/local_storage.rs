@@ -0,0 +1,54 @@
+use rustok_storage::local::LocalStorage;
+use rustok_storage::{StorageBackend, StorageError};
+use uuid::Uuid;
+
+async fn storage_with_file(data: &'static [u8]) -> (LocalStorage, std::path::PathBuf) {
+    let dir = std::env::temp_dir().join(format!("rustok-storage-{}", Uuid::new_v4()));
+    let storage = LocalStorage::new(&dir, "/media");
+    storage
+        .store(
+            "tenant/file.bin",
+            bytes::Bytes::from_static(data),
+            "application/octet-stream",
+        )
+        .await
+        .expect("store should succeed");
+    (storage, dir)
+}
+
+#[tokio::test]
+async fn read_range_returns_requested_slice() {
+    let (storage, dir) = storage_with_file(b"0123456789").await;
+
+    let middle = storage.read_range("tenant/file.bin", 2..5).await.unwrap();
+    assert_eq!(middle.as_ref(), b"234");
+    // The end is clamped to the object size, like an HTTP range.
+    let tail = storage.read_range("tenant/file.bin", 7..100).await.unwrap();
+    assert_eq!(tail.as_ref(), b"789");
+
+    tokio::fs::remove_dir_all(dir).await.unwrap();
+}
+
+#[tokio::test]
+async fn read_range_rejects_empty_and_out_of_bounds_ranges() {
+    let (storage, dir) = storage_with_file(b"0123456789").await;
+
+    assert!(matches!(
+        storage.read_range("tenant/file.bin", 10..12).await,
+        Err(StorageError::InvalidRange {
+            start: 10,
+            end: 12,
+            ..
+        })
+    ));
+    assert!(matches!(
+        storage.read_range("tenant/file.bin", 4..4).await,
+        Err(StorageError::InvalidRange { .. })
+    ));
+    assert!(matches!(
+        storage.read_range("tenant/missing.bin", 0..1).await,
+        Err(StorageError::NotFound(_))
+    ));
+
+    tokio::fs::remove_dir_all(dir).await.unwrap();
+}
@@ -3,8 +3,8 @@ use std::collections::{HashMap, HashSet};
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, JoinType, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
-    RelationTrait, TransactionTrait,
+    EntityTrait, JoinType, ModelTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    TransactionTrait,
 };
 use tracing::instrument;
 use uuid::Uuid;
@@ -13,7 +13,7 @@ use rustok_content::{
     available_locales_from, normalize_locale_code, resolve_by_locale_with_fallback,
     PLATFORM_FALLBACK_LOCALE,
 };
-use rustok_core::{Action, PermissionScope, Resource, SecurityContext};
+use rustok_core::{fetch_with_total, Action, Page, PermissionScope, Resource, SecurityContext};
 
 use crate::dto::{
     CreateTaxonomyTermInput, ListTaxonomyTermsFilter, TaxonomyScopeType, TaxonomyTermKind,
@@ -336,7 +336,7 @@ impl TaxonomyService {
         security: SecurityContext,
         filter: ListTaxonomyTermsFilter,
         fallback_locale: Option<&str>,
-    ) -> TaxonomyResult<(Vec<TaxonomyTermListItem>, u64)> {
+    ) -> TaxonomyResult<Page<TaxonomyTermListItem>> {
         enforce_scope(&security, Resource::Taxonomy, Action::List)?;
 
         let locale = filter.locale.as_deref().unwrap_or(PLATFORM_FALLBACK_LOCALE);
@@ -360,17 +360,21 @@ impl TaxonomyService {
             select = select.filter(taxonomy_term::Column::Status.eq(status));
         }
 
-        let paginator = select
-            .order_by_asc(taxonomy_term::Column::Kind)
-            .order_by_asc(taxonomy_term::Column::ScopeType)
-            .order_by_asc(taxonomy_term::Column::ScopeValue)
-            .order_by_asc(taxonomy_term::Column::CanonicalKey)
-            .paginate(&self.db, filter.per_page());
-        let total = paginator.num_items().await?;
-        let terms = paginator.fetch_page(filter.page() - 1).await?;
+        let offset = (filter.page() - 1) * filter.per_page();
+        let (terms, total) = fetch_with_total(
+            &self.db,
+            select
+                .order_by_asc(taxonomy_term::Column::Kind)
+                .order_by_asc(taxonomy_term::Column::ScopeType)
+                .order_by_asc(taxonomy_term::Column::ScopeValue)
+                .order_by_asc(taxonomy_term::Column::CanonicalKey),
+            offset,
+            filter.per_page(),
+        )
+        .await?;
 
         if terms.is_empty() {
-            return Ok((Vec::new(), total));
+            return Ok(Page::offset(Vec::new(), total, offset));
         }
 
         let term_ids: Vec<Uuid> = terms.iter().map(|term| term.id).collect();
@@ -418,7 +422,7 @@ impl TaxonomyService {
             })
             .collect();
 
-        Ok((items, total))
+        Ok(Page::offset(items, total, offset))
     }
 
     #[instrument(skip(self, txn, labels))]
@@ -1,9 +1,11 @@
 pub mod metrics;
 pub mod otel;
 
+use flate2::{write::GzEncoder, Compression};
 use lazy_static::lazy_static;
 use once_cell::sync::OnceCell;
 use prometheus::{Encoder, IntGauge, Registry, TextEncoder};
+use std::io::Write;
 use std::sync::Arc;
 use tracing_subscriber::{
     fmt, layer::SubscriberExt, EnvFilter, Layer, Registry as TracingRegistry,
@@ -25,7 +27,13 @@ impl Default for MetricsHandle {
 
 impl MetricsHandle {
     pub fn new() -> Self {
-        let registry = Registry::new();
+        Self::with_registry(Registry::new())
+    }
+
+    /// Wrap an existing registry, e.g. `prometheus::default_registry().clone()`
+    /// or one shared with other exporters. Call [`register_metrics`] on it to
+    /// expose the platform metrics.
+    pub fn with_registry(registry: Registry) -> Self {
         Self {
             registry: Arc::new(registry),
         }
@@ -39,6 +47,12 @@ impl MetricsHandle {
         String::from_utf8(buffer).unwrap_or_else(|_| String::from("Failed to encode metrics"))
     }
 
+    /// Encode the registry in Prometheus text format straight into `writer`,
+    /// without building an intermediate `String`.
+    pub fn encode_to<W: Write>(&self, writer: &mut W) -> Result<(), prometheus::Error> {
+        TextEncoder::new().encode(&self.registry.gather(), writer)
+    }
+
     pub fn registry(&self) -> &Registry {
         &self.registry
     }
@@ -56,6 +70,10 @@ pub struct TelemetryConfig {
     pub log_format: LogFormat,
     pub metrics: bool,
     pub otel: Option<otel::OtelConfig>,
+    /// Bucket overrides per histogram family; empty keeps the defaults.
+    pub histogram_buckets: metrics::HistogramBuckets,
+    /// Install [`install_panic_hook`] during init.
+    pub capture_panics: bool,
 }
 
 #[derive(Clone)]
@@ -77,9 +95,13 @@ pub enum TelemetryError {
     SubscriberAlreadySet,
     #[error("prometheus registry error: {0}")]
     Prometheus(#[from] prometheus::Error),
+    #[error("histogram buckets for '{0}' must be finite, non-empty and strictly increasing")]
+    InvalidHistogramBuckets(String),
+    #[error("histogram buckets were already fixed before telemetry init")]
+    HistogramBucketsFrozen,
 }
 
-use prometheus::{CounterVec, HistogramOpts, HistogramVec, Opts};
+use prometheus::{CounterVec, HistogramVec, Opts};
 
 lazy_static! {
     pub static ref CONTENT_OPERATIONS_TOTAL: CounterVec = CounterVec::new(
@@ -91,9 +113,10 @@ lazy_static! {
     )
     .expect("Failed to create content_operations_total");
     pub static ref CONTENT_OPERATION_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        metrics::histogram_opts(
             "rustok_content_operation_duration_seconds",
-            "Duration of content operations"
+            "Duration of content operations",
+            prometheus::DEFAULT_BUCKETS
         ),
         &["operation", "kind"]
     )
@@ -112,9 +135,10 @@ lazy_static! {
     )
     .expect("Failed to create commerce_operations_total");
     pub static ref COMMERCE_OPERATION_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        metrics::histogram_opts(
             "rustok_commerce_operation_duration_seconds",
-            "Duration of commerce operations"
+            "Duration of commerce operations",
+            prometheus::DEFAULT_BUCKETS
         ),
         &["operation", "kind"]
     )
@@ -131,9 +155,10 @@ lazy_static! {
     )
     .expect("Failed to create http_requests_total");
     pub static ref HTTP_REQUEST_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        metrics::histogram_opts(
             "rustok_http_request_duration_seconds",
-            "HTTP request duration"
+            "HTTP request duration",
+            prometheus::DEFAULT_BUCKETS
         ),
         &["method", "path"]
     )
@@ -192,33 +217,57 @@ pub fn init(config: TelemetryConfig) -> Result<TelemetryHandles, TelemetryError>
         tracing::info!("Telemetry initialized (OpenTelemetry disabled)");
     }
 
-    let metrics_handle = init_metrics_handle(config.metrics)?;
+    let metrics_handle = init_metrics_handle(config.metrics, &config.histogram_buckets)?;
+    if config.capture_panics {
+        install_panic_hook();
+    }
 
     Ok(TelemetryHandles {
         metrics: metrics_handle,
     })
 }
 
-pub fn init_metrics(metrics: bool) -> Result<TelemetryHandles, TelemetryError> {
-    let metrics_handle = init_metrics_handle(metrics)?;
+/// Initialize only the metrics registry from `config`, leaving the tracing
+/// subscriber to the caller.
+pub fn init_metrics(config: &TelemetryConfig) -> Result<TelemetryHandles, TelemetryError> {
+    let metrics_handle = init_metrics_handle(config.metrics, &config.histogram_buckets)?;
+    if config.capture_panics {
+        install_panic_hook();
+    }
     Ok(TelemetryHandles {
         metrics: metrics_handle,
     })
 }
 
-fn init_metrics_handle(metrics: bool) -> Result<Option<Arc<MetricsHandle>>, TelemetryError> {
+fn init_metrics_handle(
+    metrics: bool,
+    histogram_buckets: &metrics::HistogramBuckets,
+) -> Result<Option<Arc<MetricsHandle>>, TelemetryError> {
     if !metrics {
         return Ok(None);
     }
 
+    if !histogram_buckets.is_empty() {
+        metrics::set_histogram_buckets(histogram_buckets.clone())?;
+    }
+
     if let Some(handle) = METRICS_HANDLE.get() {
         return Ok(Some(handle.clone()));
     }
 
     let handle = Arc::new(MetricsHandle::new());
     let registry = handle.registry();
+    register_metrics(registry)?;
 
-    // Register all metrics
+    let _ = REGISTRY.set(registry.clone());
+    let _ = METRICS_HANDLE.set(handle.clone());
+    Ok(Some(handle))
+}
+
+/// Register every platform metric (crate-level and [`metrics::register_all`])
+/// with `registry`, so anything recorded through the global handles shows up
+/// when it is gathered.
+pub fn register_metrics(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(CONTENT_OPERATIONS_TOTAL.clone()))?;
     registry.register(Box::new(CONTENT_OPERATION_DURATION_SECONDS.clone()))?;
     registry.register(Box::new(CONTENT_NODES_TOTAL.clone()))?;
@@ -230,34 +279,192 @@ fn init_metrics_handle(metrics: bool) -> Result<Option<Arc<MetricsHandle>>, Tele
     registry.register(Box::new(HTTP_REQUEST_DURATION_SECONDS.clone()))?;
 
     // Register all custom metrics
-    metrics::register_all(registry)?;
+    metrics::register_all(registry)
+}
 
-    let _ = REGISTRY.set(registry.clone());
-    let _ = METRICS_HANDLE.set(handle.clone());
-    Ok(Some(handle))
+/// Wrap the current panic hook so every panic increments
+/// `rustok_panics_total` and is logged with its location before the
+/// previous hook runs. Installing more than once is a no-op.
+pub fn install_panic_hook() {
+    static INSTALLED: std::sync::Once = std::sync::Once::new();
+
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            metrics::record_panic();
+            let location = info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line()))
+                .unwrap_or_else(|| "unknown".to_string());
+            let message = info
+                .payload()
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| info.payload().downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("<non-string panic payload>");
+            tracing::error!(panic.location = %location, panic.message = %message, "panic");
+            previous(info);
+        }));
+    });
 }
 
 pub fn metrics_handle() -> Option<Arc<MetricsHandle>> {
     METRICS_HANDLE.get().cloned()
 }
 
+/// Exposition returned by [`render_metrics_or_empty`] when metrics are off:
+/// a comment only, which Prometheus parses as a valid empty scrape.
+pub const METRICS_DISABLED_PAYLOAD: &str = "# metrics disabled\n";
+
+/// Whether the global metrics registry was initialized
+/// (`TelemetryConfig.metrics` was `true`).
+pub fn metrics_enabled() -> bool {
+    REGISTRY.get().is_some()
+}
+
 pub fn render_metrics() -> Result<String, prometheus::Error> {
-    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    encode_metrics_to(&mut buffer)?;
+    Ok(String::from_utf8(buffer).unwrap_or_else(|_| String::from("Failed to encode metrics")))
+}
+
+/// Like [`render_metrics`], but yields [`METRICS_DISABLED_PAYLOAD`] instead of
+/// an error when the registry is not initialized.
+pub fn render_metrics_or_empty() -> Result<String, prometheus::Error> {
+    if !metrics_enabled() {
+        return Ok(METRICS_DISABLED_PAYLOAD.to_string());
+    }
+    render_metrics()
+}
+
+/// Stream the global registry in Prometheus text format into `writer`.
+pub fn encode_metrics_to<W: Write>(mut writer: W) -> Result<(), prometheus::Error> {
     let metric_families = REGISTRY
         .get()
         .ok_or(prometheus::Error::Msg(
             "Registry not initialized".to_string(),
         ))?
         .gather();
-    let mut buffer = Vec::new();
-    encoder.encode(&metric_families, &mut buffer)?;
-    Ok(String::from_utf8(buffer).unwrap_or_else(|_| String::from("Failed to encode metrics")))
+    TextEncoder::new().encode(&metric_families, &mut writer)
 }
 
+/// Per-tenant usage read from the global registry; `None` when metrics are off.
+pub fn tenant_usage_snapshot(tenant_id: &str) -> Option<metrics::TenantUsage> {
+    let families = REGISTRY.get()?.gather();
+    Some(metrics::tenant_usage_from_families(&families, tenant_id))
+}
+
+/// Gzip-compressed Prometheus exposition (`Content-Encoding: gzip`).
+pub fn render_metrics_gzip() -> Result<Vec<u8>, prometheus::Error> {
+    let mut encoder = gzip_writer(Vec::new());
+    encode_metrics_to(&mut encoder)?;
+    Ok(encoder.finish()?)
+}
+
+/// Gzip writer used for metrics scrapes; wrap the response buffer with it and
+/// call `finish()` once all metrics are written.
+pub fn gzip_writer<W: Write>(writer: W) -> GzEncoder<W> {
+    GzEncoder::new(writer, Compression::fast())
+}
+
+/// W3C trace ID (32 lowercase hex digits) of the current span.
+///
+/// Read from the OpenTelemetry context attached by the `tracing-opentelemetry`
+/// layer installed in [`init`]; `None` when no layer is installed or there is
+/// no active trace.
 pub fn current_trace_id() -> Option<String> {
-    let span = tracing::Span::current();
-    span.id().map(|id| id.into_u64().to_string())
+    use opentelemetry::trace::TraceContextExt;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let context = tracing::Span::current().context();
+    let span_context = context.span().span_context().clone();
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
 }
 
 #[cfg(test)]
 mod contract_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn gzip_writer_round_trips_encoded_metrics() {
+        let handle = MetricsHandle::new();
+        let gauge = IntGauge::new("rustok_test_gauge", "test gauge").unwrap();
+        gauge.set(7);
+        handle.registry().register(Box::new(gauge)).unwrap();
+
+        let mut encoder = gzip_writer(Vec::new());
+        handle.encode_to(&mut encoder).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut decoded = String::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, handle.render());
+        assert!(decoded.contains("rustok_test_gauge 7"));
+    }
+
+    #[test]
+    fn current_trace_id_is_w3c_hex_inside_otel_span() {
+        use opentelemetry::trace::TracerProvider as _;
+        use opentelemetry_sdk::trace::SdkTracerProvider;
+
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = TracingRegistry::default()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(current_trace_id(), None);
+
+            let span = tracing::info_span!("request");
+            let _entered = span.enter();
+            let trace_id = current_trace_id().expect("span should carry a trace id");
+            assert_eq!(trace_id.len(), 32);
+            assert!(trace_id
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+
+            let child = tracing::info_span!("child");
+            let _child = child.enter();
+            assert_eq!(current_trace_id(), Some(trace_id));
+        });
+    }
+
+    #[test]
+    fn current_trace_id_is_none_without_otel_layer() {
+        tracing::subscriber::with_default(TracingRegistry::default(), || {
+            let span = tracing::info_span!("request");
+            let _entered = span.enter();
+            assert_eq!(current_trace_id(), None);
+        });
+    }
+
+    #[test]
+    fn injected_registry_renders_recorded_metrics() {
+        let handle = MetricsHandle::with_registry(Registry::new());
+        register_metrics(handle.registry()).unwrap();
+
+        metrics::record_event_published("InjectedRegistryEvent", "tenant-injected");
+
+        let rendered = handle.render();
+        assert!(rendered.lines().any(|line| {
+            line.starts_with("rustok_event_bus_published_total")
+                && line.contains("InjectedRegistryEvent")
+        }));
+    }
+
+    #[test]
+    fn render_metrics_or_empty_degrades_without_registry() {
+        // No test in this crate initializes the global registry.
+        assert!(!metrics_enabled());
+        assert!(render_metrics().is_err());
+        assert_eq!(render_metrics_or_empty().unwrap(), METRICS_DISABLED_PAYLOAD);
+    }
+}
@@ -7,9 +7,98 @@
 /// - Span counts by operation
 /// - Error rates by module
 use lazy_static::lazy_static;
+use once_cell::sync::OnceCell;
+use prometheus::proto::MetricFamily;
 use prometheus::{
-    GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    GaugeVec, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry,
 };
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::TelemetryError;
+
+// ============================================================================
+// Label values
+// ============================================================================
+
+/// Circuit breaker state label (`closed`, `open`, `half_open`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl BreakerState {
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+
+    /// Value exported by `rustok_circuit_breaker_state`.
+    pub const fn gauge_value(self) -> i64 {
+        match self {
+            Self::Closed => 0,
+            Self::Open => 1,
+            Self::HalfOpen => 2,
+        }
+    }
+
+    /// Inverse of [`BreakerState::gauge_value`].
+    pub const fn from_gauge_value(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Closed),
+            1 => Some(Self::Open),
+            2 => Some(Self::HalfOpen),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome label for circuit breaker calls and cache operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpResult {
+    Success,
+    Failure,
+    /// Short-circuited by an open breaker without reaching the service.
+    Rejected,
+    Hit,
+    Miss,
+}
+
+impl OpResult {
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+            Self::Rejected => "rejected",
+            Self::Hit => "hit",
+            Self::Miss => "miss",
+        }
+    }
+}
+
+/// Why a cache entry left the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvictionReason {
+    Capacity,
+    Ttl,
+    Explicit,
+}
+
+impl EvictionReason {
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Capacity => "capacity",
+            Self::Ttl => "ttl",
+            Self::Explicit => "explicit",
+        }
+    }
+}
 
 // ============================================================================
 // EventBus Metrics
@@ -48,11 +137,11 @@ lazy_static! {
 
     /// Event processing duration in seconds
     pub static ref EVENT_BUS_PROCESSING_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_event_bus_processing_duration_seconds",
-            "Event processing duration in seconds"
-        )
-        .buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]),
+            "Event processing duration in seconds",
+            &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]
+        ),
         &["event_type", "handler"]
     )
     .expect("Failed to create event_bus_processing_duration_seconds");
@@ -69,11 +158,11 @@ lazy_static! {
 
     /// Event lag (time between publish and processing)
     pub static ref EVENT_BUS_LAG_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_event_bus_lag_seconds",
-            "Event lag in seconds (publish to processing)"
-        )
-        .buckets(vec![0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 30.0, 60.0]),
+            "Event lag in seconds (publish to processing)",
+            &[0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 30.0, 60.0]
+        ),
         &["event_type"]
     )
     .expect("Failed to create event_bus_lag_seconds");
@@ -100,14 +189,215 @@ lazy_static! {
 
     /// End-to-end dispatch latency in milliseconds
     pub static ref EVENT_DISPATCH_LATENCY_MS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_event_dispatch_latency_ms",
-            "End-to-end event dispatch latency in milliseconds"
-        )
-        .buckets(vec![1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0]),
+            "End-to-end event dispatch latency in milliseconds",
+            &[1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0]
+        ),
         &["consumer", "event_type"]
     )
     .expect("Failed to create event_dispatch_latency_ms");
+
+    /// Events routed by each forwarder routing rule
+    pub static ref EVENT_ROUTE_MATCHES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_event_route_matches_total",
+            "Total events matched by each event routing rule"
+        ),
+        &["rule"]
+    )
+    .expect("Failed to create event_route_matches_total");
+
+    /// Events that could not be serialized for a transport
+    pub static ref EVENT_SERIALIZE_FAILURES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_event_serialize_failures_total",
+            "Total events dropped to the dead-letter path because they could not be serialized"
+        ),
+        &["event_type"]
+    )
+    .expect("Failed to create event_serialize_failures_total");
+
+    /// Streamed messages a consumer skipped because they could not be decoded
+    pub static ref EVENT_DESERIALIZE_FAILURES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_event_deserialize_failures_total",
+            "Total streamed messages skipped by a consumer because they could not be deserialized"
+        ),
+        &["consumer"]
+    )
+    .expect("Failed to create event_deserialize_failures_total");
+
+    /// Events an external transport delivered to their topic
+    pub static ref EVENT_TRANSPORT_PUBLISHED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_event_transport_published_total",
+            "Total events delivered by an event transport, by topic"
+        ),
+        &["transport", "topic"]
+    )
+    .expect("Failed to create event_transport_published_total");
+
+    /// Duration of a single transport send, which may carry a batch
+    pub static ref EVENT_TRANSPORT_PUBLISH_DURATION_SECONDS: HistogramVec = HistogramVec::new(
+        histogram_opts(
+            "rustok_event_transport_publish_duration_seconds",
+            "Event transport send duration in seconds",
+            &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]
+        ),
+        &["transport", "topic"]
+    )
+    .expect("Failed to create event_transport_publish_duration_seconds");
+
+    /// Events an external transport failed to deliver to their topic
+    pub static ref EVENT_TRANSPORT_DROPPED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_event_transport_dropped_total",
+            "Total events an event transport failed to deliver to their topic"
+        ),
+        &["transport", "topic"]
+    )
+    .expect("Failed to create event_transport_dropped_total");
+
+    /// Retries of operations that recover by reconnecting, e.g. Iggy publishes
+    pub static ref RETRY_ATTEMPTS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_retry_attempts_total",
+            "Total retry attempts after a transient failure, by operation"
+        ),
+        &["operation"]
+    )
+    .expect("Failed to create retry_attempts_total");
+
+    /// Notification deliveries per channel and notification kind
+    pub static ref NOTIFICATIONS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_notifications_total",
+            "Total notification deliveries by channel, kind and outcome"
+        ),
+        &["channel", "kind", "outcome"]
+    )
+    .expect("Failed to create notifications_total");
+
+    /// Number of running event dispatcher loops
+    pub static ref DISPATCHER_RUNNING: IntGaugeVec = IntGaugeVec::new(
+        Opts::new(
+            "rustok_dispatcher_running",
+            "Number of running event dispatcher loops"
+        ),
+        &["consumer"]
+    )
+    .expect("Failed to create dispatcher_running");
+
+    /// Periodic background job runs by job name
+    pub static ref JOB_RUNS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new("rustok_job_runs_total", "Total periodic background job runs"),
+        &["job"]
+    )
+    .expect("Failed to create job_runs_total");
+
+    /// Failed periodic job runs by job name and kind (`error`, `panic`)
+    pub static ref JOB_ERRORS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_job_errors_total",
+            "Total failed periodic background job runs"
+        ),
+        &["job", "kind"]
+    )
+    .expect("Failed to create job_errors_total");
+
+    /// Duration of periodic job runs in seconds
+    pub static ref JOB_DURATION_SECONDS: HistogramVec = HistogramVec::new(
+        histogram_opts(
+            "rustok_job_duration_seconds",
+            "Duration of periodic background job runs in seconds",
+            &[0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 30.0, 120.0]
+        ),
+        &["job"]
+    )
+    .expect("Failed to create job_duration_seconds");
+
+    /// Handler invocations currently holding a dispatcher concurrency permit
+    pub static ref DISPATCHER_HANDLERS_IN_FLIGHT: IntGaugeVec = IntGaugeVec::new(
+        Opts::new(
+            "rustok_dispatcher_handlers_in_flight",
+            "Handler invocations currently running under the dispatcher concurrency limit"
+        ),
+        &["consumer"]
+    )
+    .expect("Failed to create dispatcher_handlers_in_flight");
+
+    /// Handler invocations waiting for a dispatcher concurrency permit
+    pub static ref DISPATCHER_HANDLERS_QUEUED: IntGaugeVec = IntGaugeVec::new(
+        Opts::new(
+            "rustok_dispatcher_handlers_queued",
+            "Handler invocations waiting for a dispatcher concurrency permit"
+        ),
+        &["consumer"]
+    )
+    .expect("Failed to create dispatcher_handlers_queued");
+
+    /// Events replayed into a projection during a rebuild
+    pub static ref PROJECTION_REBUILD_EVENTS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_projection_rebuild_events_total",
+            "Events replayed into a projection during a rebuild"
+        ),
+        &["projection"]
+    )
+    .expect("Failed to create projection_rebuild_events_total");
+
+    /// Current replay rate of a projection rebuild in events per second
+    pub static ref PROJECTION_REBUILD_RATE: GaugeVec = GaugeVec::new(
+        Opts::new(
+            "rustok_projection_rebuild_events_per_second",
+            "Current replay rate of a projection rebuild in events per second"
+        ),
+        &["projection"]
+    )
+    .expect("Failed to create projection_rebuild_events_per_second");
+}
+
+// ============================================================================
+// Histogram buckets
+// ============================================================================
+
+/// Bucket boundaries keyed by histogram family name
+/// (e.g. `rustok_cache_operation_duration_seconds`).
+pub type HistogramBuckets = HashMap<String, Vec<f64>>;
+
+static HISTOGRAM_BUCKETS: OnceCell<HistogramBuckets> = OnceCell::new();
+
+/// Install bucket overrides for histogram families.
+///
+/// Histograms read their buckets when first touched, so this has to run
+/// before any of them is used or registered; `init` and `init_metrics` call
+/// it first thing. Families without an override keep their defaults and
+/// names that match no histogram are ignored.
+pub fn set_histogram_buckets(overrides: HistogramBuckets) -> Result<(), TelemetryError> {
+    for (name, buckets) in &overrides {
+        let increasing = buckets.windows(2).all(|pair| pair[0] < pair[1]);
+        if buckets.is_empty() || !increasing || buckets.iter().any(|bound| !bound.is_finite()) {
+            return Err(TelemetryError::InvalidHistogramBuckets(name.clone()));
+        }
+    }
+
+    let installed = HISTOGRAM_BUCKETS.get_or_init(|| overrides.clone());
+    if *installed != overrides {
+        return Err(TelemetryError::HistogramBucketsFrozen);
+    }
+    Ok(())
+}
+
+/// Options for the histogram family `name`, using the installed override
+/// for its buckets when there is one.
+pub fn histogram_opts(name: &str, help: &str, default_buckets: &[f64]) -> HistogramOpts {
+    let buckets = HISTOGRAM_BUCKETS
+        .get_or_init(HistogramBuckets::new)
+        .get(name)
+        .cloned()
+        .unwrap_or_else(|| default_buckets.to_vec());
+    HistogramOpts::new(name, help).buckets(buckets)
 }
 
 // ============================================================================
@@ -203,11 +493,11 @@ lazy_static! {
 
     /// Cache operation duration
     pub static ref CACHE_OPERATION_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_cache_operation_duration_seconds",
-            "Cache operation duration in seconds"
-        )
-        .buckets(vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1]),
+            "Cache operation duration in seconds",
+            &[0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1]
+        ),
         &["cache", "operation"]
     )
     .expect("Failed to create cache_operation_duration_seconds");
@@ -230,11 +520,11 @@ lazy_static! {
 
     /// Span duration by operation
     pub static ref SPAN_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_span_duration_seconds",
-            "Span duration in seconds"
-        )
-        .buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+            "Span duration in seconds",
+            &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+        ),
         &["operation"]
     )
     .expect("Failed to create span_duration_seconds");
@@ -289,6 +579,13 @@ lazy_static! {
         &["module"]
     )
     .expect("Failed to create module_error_rate");
+
+    /// Panics caught by the hook from `install_panic_hook`
+    pub static ref PANICS_TOTAL: IntCounter = IntCounter::new(
+        "rustok_panics_total",
+        "Total panics observed by the process panic hook"
+    )
+    .expect("Failed to create panics_total");
 }
 
 // ============================================================================
@@ -298,11 +595,11 @@ lazy_static! {
 lazy_static! {
     /// Database query duration
     pub static ref DATABASE_QUERY_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_database_query_duration_seconds",
-            "Database query duration in seconds"
-        )
-        .buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]),
+            "Database query duration in seconds",
+            &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]
+        ),
         &["query_type", "table"]
     )
     .expect("Failed to create database_query_duration_seconds");
@@ -326,6 +623,16 @@ lazy_static! {
         &["query_type", "error_type"]
     )
     .expect("Failed to create database_query_errors_total");
+
+    /// Queries slower than the configured slow-query threshold
+    pub static ref DATABASE_SLOW_QUERIES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_db_slow_queries_total",
+            "Total database queries exceeding the slow-query threshold"
+        ),
+        &["query_type"]
+    )
+    .expect("Failed to create db_slow_queries_total");
 }
 
 // ============================================================================
@@ -345,22 +652,22 @@ lazy_static! {
 
     /// HTTP request size in bytes
     pub static ref HTTP_REQUEST_SIZE_BYTES: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_http_request_size_bytes",
-            "HTTP request size in bytes"
-        )
-        .buckets(vec![100.0, 1000.0, 10_000.0, 100_000.0, 1_000_000.0]),
+            "HTTP request size in bytes",
+            &[100.0, 1000.0, 10_000.0, 100_000.0, 1_000_000.0]
+        ),
         &["method", "endpoint"]
     )
     .expect("Failed to create http_request_size_bytes");
 
     /// HTTP response size in bytes
     pub static ref HTTP_RESPONSE_SIZE_BYTES: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_http_response_size_bytes",
-            "HTTP response size in bytes"
-        )
-        .buckets(vec![100.0, 1000.0, 10_000.0, 100_000.0, 1_000_000.0]),
+            "HTTP response size in bytes",
+            &[100.0, 1000.0, 10_000.0, 100_000.0, 1_000_000.0]
+        ),
         &["method", "endpoint"]
     )
     .expect("Failed to create http_response_size_bytes");
@@ -374,33 +681,33 @@ lazy_static! {
 
     /// Requested read-path limits before clamping
     pub static ref READ_PATH_REQUESTED_LIMIT: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_read_path_requested_limit",
-            "Requested read-path limits before clamp/default handling"
-        )
-        .buckets(vec![1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]),
+            "Requested read-path limits before clamp/default handling",
+            &[1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]
+        ),
         &["surface", "path"]
     )
     .expect("Failed to create read_path_requested_limit");
 
     /// Effective read-path limits after clamping/default handling
     pub static ref READ_PATH_EFFECTIVE_LIMIT: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_read_path_effective_limit",
-            "Effective read-path limits after clamp/default handling"
-        )
-        .buckets(vec![1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]),
+            "Effective read-path limits after clamp/default handling",
+            &[1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]
+        ),
         &["surface", "path"]
     )
     .expect("Failed to create read_path_effective_limit");
 
     /// Number of items returned by bounded read paths
     pub static ref READ_PATH_RETURNED_ITEMS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_read_path_returned_items",
-            "Items returned by bounded read paths"
-        )
-        .buckets(vec![0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]),
+            "Items returned by bounded read paths",
+            &[0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0]
+        ),
         &["surface", "path"]
     )
     .expect("Failed to create read_path_returned_items");
@@ -417,22 +724,22 @@ lazy_static! {
 
     /// Query latency inside bounded read paths
     pub static ref READ_PATH_QUERY_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_read_path_query_duration_seconds",
-            "Database/query step duration for bounded read paths"
-        )
-        .buckets(vec![0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]),
+            "Database/query step duration for bounded read paths",
+            &[0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]
+        ),
         &["surface", "path", "query"]
     )
     .expect("Failed to create read_path_query_duration_seconds");
 
     /// Rows or aggregate volume observed per bounded read-path query step
     pub static ref READ_PATH_QUERY_ROWS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_read_path_query_rows",
-            "Rows or aggregate volume observed per bounded read-path query step"
-        )
-        .buckets(vec![0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0]),
+            "Rows or aggregate volume observed per bounded read-path query step",
+            &[0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0]
+        ),
         &["surface", "path", "query"]
     )
     .expect("Failed to create read_path_query_rows");
@@ -459,11 +766,11 @@ lazy_static! {
 
     /// Reindex run duration in seconds
     pub static ref INDEX_REINDEX_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_index_reindex_duration_seconds",
-            "Duration of reindex runs in seconds"
-        )
-        .buckets(vec![0.001, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]),
+            "Duration of reindex runs in seconds",
+            &[0.001, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]
+        ),
         &["indexer", "operation"]
     )
     .expect("Failed to create index_reindex_duration_seconds");
@@ -490,22 +797,22 @@ lazy_static! {
 
     /// Search query duration in seconds.
     pub static ref SEARCH_QUERY_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_search_query_duration_seconds",
-            "Search query duration in seconds"
-        )
-        .buckets(vec![0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]),
+            "Search query duration in seconds",
+            &[0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]
+        ),
         &["surface", "engine"]
     )
     .expect("Failed to create search_query_duration_seconds");
 
     /// Search results returned by surface/engine.
     pub static ref SEARCH_RESULTS_RETURNED: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_search_results_returned",
-            "Search results returned"
-        )
-        .buckets(vec![0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0]),
+            "Search results returned",
+            &[0.0, 1.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+        ),
         &["surface", "engine"]
     )
     .expect("Failed to create search_results_returned");
@@ -542,11 +849,11 @@ lazy_static! {
 
     /// Search indexing/rebuild duration in seconds.
     pub static ref SEARCH_INDEXING_DURATION_SECONDS: HistogramVec = HistogramVec::new(
-        HistogramOpts::new(
+        histogram_opts(
             "rustok_search_indexing_duration_seconds",
-            "Search indexing and rebuild duration in seconds"
-        )
-        .buckets(vec![0.001, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]),
+            "Search indexing and rebuild duration in seconds",
+            &[0.001, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]
+        ),
         &["operation", "entity"]
     )
     .expect("Failed to create search_indexing_duration_seconds");
@@ -648,6 +955,22 @@ pub fn register_all(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(EVENT_CONSUMER_LAGGED_TOTAL.clone()))?;
     registry.register(Box::new(EVENT_CONSUMER_RESTARTED_TOTAL.clone()))?;
     registry.register(Box::new(EVENT_DISPATCH_LATENCY_MS.clone()))?;
+    registry.register(Box::new(EVENT_ROUTE_MATCHES_TOTAL.clone()))?;
+    registry.register(Box::new(EVENT_SERIALIZE_FAILURES_TOTAL.clone()))?;
+    registry.register(Box::new(EVENT_DESERIALIZE_FAILURES_TOTAL.clone()))?;
+    registry.register(Box::new(EVENT_TRANSPORT_PUBLISHED_TOTAL.clone()))?;
+    registry.register(Box::new(EVENT_TRANSPORT_PUBLISH_DURATION_SECONDS.clone()))?;
+    registry.register(Box::new(EVENT_TRANSPORT_DROPPED_TOTAL.clone()))?;
+    registry.register(Box::new(RETRY_ATTEMPTS_TOTAL.clone()))?;
+    registry.register(Box::new(NOTIFICATIONS_TOTAL.clone()))?;
+    registry.register(Box::new(DISPATCHER_RUNNING.clone()))?;
+    registry.register(Box::new(JOB_RUNS_TOTAL.clone()))?;
+    registry.register(Box::new(JOB_ERRORS_TOTAL.clone()))?;
+    registry.register(Box::new(JOB_DURATION_SECONDS.clone()))?;
+    registry.register(Box::new(DISPATCHER_HANDLERS_IN_FLIGHT.clone()))?;
+    registry.register(Box::new(DISPATCHER_HANDLERS_QUEUED.clone()))?;
+    registry.register(Box::new(PROJECTION_REBUILD_EVENTS_TOTAL.clone()))?;
+    registry.register(Box::new(PROJECTION_REBUILD_RATE.clone()))?;
 
     // Circuit Breaker
     registry.register(Box::new(CIRCUIT_BREAKER_STATE.clone()))?;
@@ -671,11 +994,13 @@ pub fn register_all(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(MODULE_ENTRYPOINT_CALLS_TOTAL.clone()))?;
     registry.register(Box::new(MODULE_ERRORS_TOTAL.clone()))?;
     registry.register(Box::new(MODULE_ERROR_RATE.clone()))?;
+    registry.register(Box::new(PANICS_TOTAL.clone()))?;
 
     // Database
     registry.register(Box::new(DATABASE_QUERY_DURATION_SECONDS.clone()))?;
     registry.register(Box::new(DATABASE_CONNECTIONS.clone()))?;
     registry.register(Box::new(DATABASE_QUERY_ERRORS_TOTAL.clone()))?;
+    registry.register(Box::new(DATABASE_SLOW_QUERIES_TOTAL.clone()))?;
 
     // HTTP
     registry.register(Box::new(HTTP_REQUESTS_BY_ENDPOINT.clone()))?;
@@ -708,17 +1033,123 @@ pub fn register_all(registry: &Registry) -> Result<(), prometheus::Error> {
     registry.register(Box::new(RATE_LIMIT_BACKEND_UNAVAILABLE_TOTAL.clone()))?;
     registry.register(Box::new(RATE_LIMIT_EXCEEDED_TOTAL.clone()))?;
 
+    // Per-tenant usage
+    registry.register(Box::new(TENANT_HTTP_REQUESTS_TOTAL.clone()))?;
+
     Ok(())
 }
 
+// ============================================================================
+// Test support
+// ============================================================================
+
+/// Zero every process-wide metric and drop all labeled series so a test can
+/// assert exact values.
+///
+/// Test-only (`test-reset` feature): the metrics are global, so calling this
+/// in production wipes data other code is still reporting, and tests that
+/// use it must not run concurrently with tests reading the same metrics.
+#[cfg(any(test, feature = "test-reset"))]
+pub fn reset_all_metrics() {
+    EVENT_BUS_PUBLISHED_TOTAL.reset();
+    EVENT_BUS_DISPATCHED_TOTAL.reset();
+    EVENT_BUS_QUEUE_DEPTH.reset();
+    EVENT_BUS_PROCESSING_DURATION_SECONDS.reset();
+    EVENT_BUS_ERRORS_TOTAL.reset();
+    EVENT_BUS_LAG_SECONDS.reset();
+    EVENT_CONSUMER_LAGGED_TOTAL.reset();
+    EVENT_CONSUMER_RESTARTED_TOTAL.reset();
+    EVENT_DISPATCH_LATENCY_MS.reset();
+    EVENT_ROUTE_MATCHES_TOTAL.reset();
+    EVENT_SERIALIZE_FAILURES_TOTAL.reset();
+    EVENT_DESERIALIZE_FAILURES_TOTAL.reset();
+    EVENT_TRANSPORT_PUBLISHED_TOTAL.reset();
+    EVENT_TRANSPORT_PUBLISH_DURATION_SECONDS.reset();
+    EVENT_TRANSPORT_DROPPED_TOTAL.reset();
+    RETRY_ATTEMPTS_TOTAL.reset();
+    NOTIFICATIONS_TOTAL.reset();
+    DISPATCHER_RUNNING.reset();
+    JOB_RUNS_TOTAL.reset();
+    JOB_ERRORS_TOTAL.reset();
+    JOB_DURATION_SECONDS.reset();
+    DISPATCHER_HANDLERS_IN_FLIGHT.reset();
+    DISPATCHER_HANDLERS_QUEUED.reset();
+    PROJECTION_REBUILD_EVENTS_TOTAL.reset();
+    PROJECTION_REBUILD_RATE.reset();
+    CIRCUIT_BREAKER_STATE.reset();
+    CIRCUIT_BREAKER_TRANSITIONS_TOTAL.reset();
+    CIRCUIT_BREAKER_CALLS_TOTAL.reset();
+    CIRCUIT_BREAKER_FAILURES.reset();
+    CACHE_OPERATIONS_TOTAL.reset();
+    CACHE_HIT_RATE.reset();
+    CACHE_SIZE.reset();
+    CACHE_EVICTIONS_TOTAL.reset();
+    CACHE_OPERATION_DURATION_SECONDS.reset();
+    SPANS_CREATED_TOTAL.reset();
+    SPAN_DURATION_SECONDS.reset();
+    SPANS_WITH_ERRORS_TOTAL.reset();
+    MODULE_ENTRYPOINT_CALLS_TOTAL.reset();
+    MODULE_ERRORS_TOTAL.reset();
+    MODULE_ERROR_RATE.reset();
+    PANICS_TOTAL.reset();
+    DATABASE_QUERY_DURATION_SECONDS.reset();
+    DATABASE_CONNECTIONS.reset();
+    DATABASE_QUERY_ERRORS_TOTAL.reset();
+    DATABASE_SLOW_QUERIES_TOTAL.reset();
+    HTTP_REQUESTS_BY_ENDPOINT.reset();
+    HTTP_REQUEST_SIZE_BYTES.reset();
+    HTTP_RESPONSE_SIZE_BYTES.reset();
+    HTTP_ACTIVE_CONNECTIONS.set(0);
+    READ_PATH_REQUESTED_LIMIT.reset();
+    READ_PATH_EFFECTIVE_LIMIT.reset();
+    READ_PATH_RETURNED_ITEMS.reset();
+    READ_PATH_LIMIT_CLAMPED_TOTAL.reset();
+    READ_PATH_QUERY_DURATION_SECONDS.reset();
+    READ_PATH_QUERY_ROWS.reset();
+    INDEX_REINDEX_RUNS_TOTAL.reset();
+    INDEX_REINDEX_ENTITIES_TOTAL.reset();
+    INDEX_REINDEX_DURATION_SECONDS.reset();
+    INDEX_REINDEX_RUNTIME_CONFIG.reset();
+    SEARCH_QUERIES_TOTAL.reset();
+    SEARCH_QUERY_DURATION_SECONDS.reset();
+    SEARCH_RESULTS_RETURNED.reset();
+    SEARCH_ZERO_RESULTS_TOTAL.reset();
+    SEARCH_SLOW_QUERIES_TOTAL.reset();
+    SEARCH_INDEXING_OPERATIONS_TOTAL.reset();
+    SEARCH_INDEXING_DURATION_SECONDS.reset();
+    SEARCH_RATE_LIMIT_OUTCOMES_TOTAL.reset();
+    SEARCH_AUDIT_EVENTS_TOTAL.reset();
+    RATE_LIMIT_BACKEND_STATUS.reset();
+    RATE_LIMIT_ACTIVE_CLIENTS.reset();
+    RATE_LIMIT_TOTAL_ENTRIES.reset();
+    RATE_LIMIT_DISTRIBUTED_MODE.reset();
+    RATE_LIMIT_BACKEND_UNAVAILABLE_TOTAL.reset();
+    RATE_LIMIT_EXCEEDED_TOTAL.reset();
+    MEDIA_UPLOADS_TOTAL.reset();
+    MEDIA_UPLOAD_BYTES_TOTAL.reset();
+    MEDIA_DELETES_TOTAL.reset();
+    MEDIA_STORAGE_HEALTH.reset();
+    crate::CONTENT_OPERATIONS_TOTAL.reset();
+    crate::CONTENT_OPERATION_DURATION_SECONDS.reset();
+    crate::COMMERCE_OPERATIONS_TOTAL.reset();
+    crate::COMMERCE_OPERATION_DURATION_SECONDS.reset();
+    crate::HTTP_REQUESTS_TOTAL.reset();
+    crate::HTTP_REQUEST_DURATION_SECONDS.reset();
+    crate::CONTENT_NODES_TOTAL.set(0);
+    crate::COMMERCE_PRODUCTS_TOTAL.set(0);
+    crate::COMMERCE_ORDERS_TOTAL.set(0);
+    TENANT_HTTP_REQUESTS_TOTAL.reset();
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
 /// Record EventBus event publication
 pub fn record_event_published(event_type: &str, tenant_id: &str) {
+    let tenant = tenant_label(tenant_id);
     EVENT_BUS_PUBLISHED_TOTAL
-        .with_label_values(&[event_type, tenant_id])
+        .with_label_values(&[event_type, tenant.as_str()])
         .inc();
 }
 
@@ -750,6 +1181,54 @@ pub fn record_event_error(event_type: &str, error_type: &str) {
         .inc();
 }
 
+/// Record an event that could not be serialized
+pub fn record_event_serialize_failure(event_type: &str) {
+    EVENT_SERIALIZE_FAILURES_TOTAL
+        .with_label_values(&[event_type])
+        .inc();
+}
+
+/// Record a streamed message `consumer` skipped because it could not be decoded
+pub fn record_event_deserialize_failure(consumer: &str) {
+    EVENT_DESERIALIZE_FAILURES_TOTAL
+        .with_label_values(&[consumer])
+        .inc();
+}
+
+/// Record one transport send of `events` events to `topic`
+pub fn record_transport_publish(
+    transport: &str,
+    topic: &str,
+    events: u64,
+    success: bool,
+    duration_secs: f64,
+) {
+    EVENT_TRANSPORT_PUBLISH_DURATION_SECONDS
+        .with_label_values(&[transport, topic])
+        .observe(duration_secs);
+    let counter: &IntCounterVec = if success {
+        &EVENT_TRANSPORT_PUBLISHED_TOTAL
+    } else {
+        &EVENT_TRANSPORT_DROPPED_TOTAL
+    };
+    counter
+        .with_label_values(&[transport, topic])
+        .inc_by(events);
+}
+
+/// Record one retry of `operation`
+pub fn record_retry_attempt(operation: &str) {
+    RETRY_ATTEMPTS_TOTAL.with_label_values(&[operation]).inc();
+}
+
+/// Record one notification delivery attempt on `channel`.
+pub fn record_notification(channel: &str, kind: &str, success: bool) {
+    let outcome = if success { "success" } else { "failure" };
+    NOTIFICATIONS_TOTAL
+        .with_label_values(&[channel, kind, outcome])
+        .inc();
+}
+
 /// Record event lag
 pub fn record_event_lag(event_type: &str, lag_secs: f64) {
     EVENT_BUS_LAG_SECONDS
@@ -778,24 +1257,92 @@ pub fn record_event_dispatch_latency_ms(consumer: &str, event_type: &str, latenc
         .observe(latency_ms);
 }
 
-/// Update circuit breaker state (0=closed, 1=open, 2=half-open)
-pub fn update_circuit_breaker_state(service: &str, state: i64) {
+/// Record that an event matched a routing rule (`default` when none did)
+pub fn record_event_route_match(rule: &str) {
+    EVENT_ROUTE_MATCHES_TOTAL.with_label_values(&[rule]).inc();
+}
+
+/// Record that an event dispatcher loop started
+pub fn record_dispatcher_started(consumer: &str) {
+    DISPATCHER_RUNNING.with_label_values(&[consumer]).inc();
+}
+
+/// Record that an event dispatcher loop stopped
+pub fn record_dispatcher_stopped(consumer: &str) {
+    DISPATCHER_RUNNING.with_label_values(&[consumer]).dec();
+}
+
+/// Record one periodic job run; `error_kind` is `Some("error" | "panic")`
+/// when the run failed
+pub fn record_job_run(job: &str, duration_secs: f64, error_kind: Option<&str>) {
+    JOB_RUNS_TOTAL.with_label_values(&[job]).inc();
+    JOB_DURATION_SECONDS
+        .with_label_values(&[job])
+        .observe(duration_secs);
+    if let Some(kind) = error_kind {
+        JOB_ERRORS_TOTAL.with_label_values(&[job, kind]).inc();
+    }
+}
+
+/// Record a handler invocation waiting for a dispatcher concurrency permit.
+///
+/// The handler gauges move by inc/dec, so dispatchers sharing a consumer
+/// label add up instead of overwriting each other.
+pub fn record_dispatcher_handler_queued(consumer: &str) {
+    DISPATCHER_HANDLERS_QUEUED
+        .with_label_values(&[consumer])
+        .inc();
+}
+
+/// Record a handler invocation that stopped waiting for a permit
+pub fn record_dispatcher_handler_dequeued(consumer: &str) {
+    DISPATCHER_HANDLERS_QUEUED
+        .with_label_values(&[consumer])
+        .dec();
+}
+
+/// Record a handler invocation that obtained its permit
+pub fn record_dispatcher_handler_started(consumer: &str) {
+    DISPATCHER_HANDLERS_IN_FLIGHT
+        .with_label_values(&[consumer])
+        .inc();
+}
+
+/// Record a handler invocation that released its permit
+pub fn record_dispatcher_handler_finished(consumer: &str) {
+    DISPATCHER_HANDLERS_IN_FLIGHT
+        .with_label_values(&[consumer])
+        .dec();
+}
+
+/// Record projection rebuild progress since the last report
+pub fn record_projection_rebuild_progress(projection: &str, replayed: u64, events_per_second: f64) {
+    PROJECTION_REBUILD_EVENTS_TOTAL
+        .with_label_values(&[projection])
+        .inc_by(replayed);
+    PROJECTION_REBUILD_RATE
+        .with_label_values(&[projection])
+        .set(events_per_second);
+}
+
+/// Update circuit breaker state
+pub fn update_circuit_breaker_state(service: &str, state: BreakerState) {
     CIRCUIT_BREAKER_STATE
         .with_label_values(&[service])
-        .set(state);
+        .set(state.gauge_value());
 }
 
 /// Record circuit breaker state transition
-pub fn record_circuit_breaker_transition(service: &str, from: &str, to: &str) {
+pub fn record_circuit_breaker_transition(service: &str, from: BreakerState, to: BreakerState) {
     CIRCUIT_BREAKER_TRANSITIONS_TOTAL
-        .with_label_values(&[service, from, to])
+        .with_label_values(&[service, from.as_label(), to.as_label()])
         .inc();
 }
 
 /// Record circuit breaker call result
-pub fn record_circuit_breaker_call(service: &str, result: &str) {
+pub fn record_circuit_breaker_call(service: &str, result: OpResult) {
     CIRCUIT_BREAKER_CALLS_TOTAL
-        .with_label_values(&[service, result])
+        .with_label_values(&[service, result.as_label()])
         .inc();
 }
 
@@ -807,21 +1354,41 @@ pub fn update_circuit_breaker_failures(service: &str, failures: i64) {
 }
 
 /// Record cache operation
-pub fn record_cache_operation(cache: &str, operation: &str, result: &str) {
+pub fn record_cache_operation(cache: &str, operation: &str, result: OpResult) {
     CACHE_OPERATIONS_TOTAL
-        .with_label_values(&[cache, operation, result])
+        .with_label_values(&[cache, operation, result.as_label()])
         .inc();
 }
 
+/// Record a cache lookup and refresh the cache's hit rate from the running
+/// hit/miss totals
+pub fn record_cache_lookup(cache: &str, hit: bool) {
+    let result = if hit { OpResult::Hit } else { OpResult::Miss };
+    record_cache_operation(cache, "get", result);
+
+    let hits = CACHE_OPERATIONS_TOTAL
+        .with_label_values(&[cache, "get", OpResult::Hit.as_label()])
+        .get();
+    let misses = CACHE_OPERATIONS_TOTAL
+        .with_label_values(&[cache, "get", OpResult::Miss.as_label()])
+        .get();
+    update_cache_hit_rate(cache, hits as f64 / (hits + misses) as f64);
+}
+
+/// Update cache hit rate (0.0 - 1.0)
+pub fn update_cache_hit_rate(cache: &str, rate: f64) {
+    CACHE_HIT_RATE.with_label_values(&[cache]).set(rate);
+}
+
 /// Update cache size
 pub fn update_cache_size(cache: &str, size: i64) {
     CACHE_SIZE.with_label_values(&[cache]).set(size);
 }
 
 /// Record cache eviction
-pub fn record_cache_eviction(cache: &str, reason: &str) {
+pub fn record_cache_eviction(cache: &str, reason: EvictionReason) {
     CACHE_EVICTIONS_TOTAL
-        .with_label_values(&[cache, reason])
+        .with_label_values(&[cache, reason.as_label()])
         .inc();
 }
 
@@ -860,6 +1427,11 @@ pub fn record_module_error(module: &str, error_type: &str, severity: &str) {
         .inc();
 }
 
+/// Record a panic
+pub fn record_panic() {
+    PANICS_TOTAL.inc();
+}
+
 /// Record module entry-point invocation path (`library`, `core_runtime`, or `bypass`).
 pub fn record_module_entrypoint_call(module: &str, entry_point: &str, path: &str) {
     MODULE_ENTRYPOINT_CALLS_TOTAL
@@ -886,6 +1458,13 @@ pub fn record_db_query_error(query_type: &str, error_type: &str) {
         .inc();
 }
 
+/// Record a query that exceeded the slow-query threshold
+pub fn record_db_slow_query(query_type: &str) {
+    DATABASE_SLOW_QUERIES_TOTAL
+        .with_label_values(&[query_type])
+        .inc();
+}
+
 /// Record runtime budgets for bounded read-paths.
 pub fn record_read_path_budget(
     surface: &str,
@@ -1131,3 +1710,395 @@ pub fn update_storage_health(driver: &str, healthy: bool) {
         .with_label_values(&[driver])
         .set(if healthy { 1 } else { 0 });
 }
+
+// ============================================================================
+// Per-tenant usage
+// ============================================================================
+
+/// Label carrying the tenant on tenant-scoped metric families.
+pub const TENANT_LABEL: &str = "tenant_id";
+
+/// Distinct tenants a process labels series with; later tenants share
+/// [`OVERFLOW_TENANT_LABEL`] so a large install cannot blow up the registry.
+pub const MAX_TENANT_LABEL_VALUES: usize = 1_000;
+
+/// [`TENANT_LABEL`] value used once [`MAX_TENANT_LABEL_VALUES`] is reached.
+pub const OVERFLOW_TENANT_LABEL: &str = "_other";
+
+lazy_static! {
+    /// HTTP requests per tenant and status class (`2xx`, `4xx`, `5xx`, ...).
+    /// Kept apart from `rustok_http_requests_total` so the tenant label is
+    /// not multiplied by every route.
+    pub static ref TENANT_HTTP_REQUESTS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rustok_tenant_http_requests_total",
+            "HTTP requests per tenant and status class"
+        ),
+        &["tenant_id", "status_class"]
+    )
+    .expect("Failed to create tenant_http_requests_total");
+
+    static ref TENANT_LABEL_VALUES: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// Value for [`TENANT_LABEL`]: `tenant_id` itself for the first
+/// [`MAX_TENANT_LABEL_VALUES`] tenants seen, [`OVERFLOW_TENANT_LABEL`] after.
+pub fn tenant_label(tenant_id: &str) -> String {
+    let mut seen = TENANT_LABEL_VALUES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if seen.contains(tenant_id) {
+        return tenant_id.to_string();
+    }
+    if seen.len() >= MAX_TENANT_LABEL_VALUES {
+        return OVERFLOW_TENANT_LABEL.to_string();
+    }
+    seen.insert(tenant_id.to_string());
+    tenant_id.to_string()
+}
+
+/// Count a finished request against `tenant_id`.
+pub fn record_tenant_http_request(tenant_id: &str, status: u16) {
+    let tenant = tenant_label(tenant_id);
+    let status_class = format!("{}xx", status / 100);
+    TENANT_HTTP_REQUESTS_TOTAL
+        .with_label_values(&[tenant.as_str(), status_class.as_str()])
+        .inc();
+}
+
+/// Per-tenant rollup of request, error and event activity.
+///
+/// Only series carrying a [`TENANT_LABEL`] label are counted: requests come
+/// from `rustok_tenant_http_requests_total` and events from
+/// `rustok_event_bus_published_total`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TenantUsage {
+    pub requests: u64,
+    /// Requests that finished with a `5xx` status.
+    pub errors: u64,
+    pub events_published: u64,
+}
+
+/// Build a [`TenantUsage`] for `tenant_id` from gathered metric families.
+pub fn tenant_usage_from_families(families: &[MetricFamily], tenant_id: &str) -> TenantUsage {
+    let mut usage = TenantUsage::default();
+
+    for family in families {
+        for metric in family.get_metric() {
+            let label = |name: &str| {
+                metric
+                    .get_label()
+                    .iter()
+                    .find(|pair| pair.name() == name)
+                    .map(|pair| pair.value())
+            };
+            if label(TENANT_LABEL) != Some(tenant_id) {
+                continue;
+            }
+
+            let value = metric.get_counter().value() as u64;
+            match family.name() {
+                "rustok_tenant_http_requests_total" => {
+                    usage.requests += value;
+                    if label("status_class") == Some("5xx") {
+                        usage.errors += value;
+                    }
+                }
+                "rustok_event_bus_published_total" => usage.events_published += value,
+                _ => {}
+            }
+        }
+    }
+
+    usage
+}
+
+// ============================================================================
+// Breaker and cache snapshot
+// ============================================================================
+
+/// Hit rate below which [`MetricsSnapshot::check_health`] warns by default.
+pub const DEFAULT_MIN_CACHE_HIT_RATE: f64 = 0.5;
+
+/// Lookups a cache needs before its hit rate is judged, so a cold cache
+/// does not warn on its first few misses.
+pub const MIN_CACHE_LOOKUPS_FOR_HEALTH: u64 = 20;
+
+/// Current state and call totals of one circuit breaker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerSnapshot {
+    pub service: String,
+    /// `None` until the breaker reports its state.
+    pub state: Option<BreakerState>,
+    pub successes: u64,
+    pub failures: u64,
+    /// Calls short-circuited by the open breaker.
+    pub rejected: u64,
+    /// `successes / (successes + failures)`; `None` before the first call
+    /// reached the service.
+    pub success_rate: Option<f64>,
+}
+
+/// Lookup totals of one cache.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheSnapshot {
+    pub cache: String,
+    pub hits: u64,
+    pub misses: u64,
+    /// `hits / (hits + misses)`; `None` when the cache had no lookups.
+    pub hit_rate: Option<f64>,
+}
+
+/// Point-in-time view of every circuit breaker and cache that has reported
+/// metrics, sorted by name, plus the process panic count.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub circuit_breakers: Vec<CircuitBreakerSnapshot>,
+    pub caches: Vec<CacheSnapshot>,
+    /// Panics recorded in `rustok_panics_total` since process start.
+    pub panics: u64,
+}
+
+impl MetricsSnapshot {
+    /// Snapshot the process-wide breaker and cache metric vectors.
+    pub fn capture() -> Self {
+        use prometheus::core::Collector;
+
+        let families: Vec<MetricFamily> = [
+            CIRCUIT_BREAKER_STATE.collect(),
+            CIRCUIT_BREAKER_CALLS_TOTAL.collect(),
+            CACHE_OPERATIONS_TOTAL.collect(),
+            PANICS_TOTAL.collect(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        Self::from_families(&families)
+    }
+
+    /// Build a snapshot from gathered metric families.
+    pub fn from_families(families: &[MetricFamily]) -> Self {
+        use std::collections::BTreeMap;
+
+        let mut breakers: BTreeMap<String, CircuitBreakerSnapshot> = BTreeMap::new();
+        let mut caches: BTreeMap<String, CacheSnapshot> = BTreeMap::new();
+        let mut panics = 0;
+
+        for family in families {
+            for metric in family.get_metric() {
+                let label = |name: &str| {
+                    metric
+                        .get_label()
+                        .iter()
+                        .find(|pair| pair.name() == name)
+                        .map(|pair| pair.value())
+                };
+
+                match family.name() {
+                    "rustok_circuit_breaker_state" => {
+                        let Some(service) = label("service") else {
+                            continue;
+                        };
+                        breakers
+                            .entry(service.to_string())
+                            .or_insert_with(|| CircuitBreakerSnapshot::empty(service))
+                            .state =
+                            BreakerState::from_gauge_value(metric.get_gauge().value() as i64);
+                    }
+                    "rustok_circuit_breaker_calls_total" => {
+                        let Some(service) = label("service") else {
+                            continue;
+                        };
+                        let breaker = breakers
+                            .entry(service.to_string())
+                            .or_insert_with(|| CircuitBreakerSnapshot::empty(service));
+                        let value = metric.get_counter().value() as u64;
+                        match label("result") {
+                            Some("success") => breaker.successes += value,
+                            Some("failure") => breaker.failures += value,
+                            Some("rejected") => breaker.rejected += value,
+                            _ => {}
+                        }
+                    }
+                    "rustok_cache_operations_total" => {
+                        let (Some(name), Some(result)) = (label("cache"), label("result")) else {
+                            continue;
+                        };
+                        if result != "hit" && result != "miss" {
+                            continue;
+                        }
+                        let cache =
+                            caches
+                                .entry(name.to_string())
+                                .or_insert_with(|| CacheSnapshot {
+                                    cache: name.to_string(),
+                                    hits: 0,
+                                    misses: 0,
+                                    hit_rate: None,
+                                });
+                        let value = metric.get_counter().value() as u64;
+                        if result == "hit" {
+                            cache.hits += value;
+                        } else {
+                            cache.misses += value;
+                        }
+                    }
+                    "rustok_panics_total" => panics += metric.get_counter().value() as u64,
+                    _ => {}
+                }
+            }
+        }
+
+        let circuit_breakers = breakers
+            .into_values()
+            .map(|mut breaker| {
+                breaker.success_rate = ratio(breaker.successes, breaker.failures);
+                breaker
+            })
+            .collect();
+        let caches = caches
+            .into_values()
+            .map(|mut cache| {
+                cache.hit_rate = ratio(cache.hits, cache.misses);
+                cache
+            })
+            .collect();
+
+        Self {
+            circuit_breakers,
+            caches,
+            panics,
+        }
+    }
+
+    /// Warnings for recorded panics, open breakers and caches whose hit rate
+    /// fell below `min_cache_hit_rate` after at least
+    /// [`MIN_CACHE_LOOKUPS_FOR_HEALTH`] lookups. Empty when everything looks
+    /// healthy.
+    pub fn check_health(&self, min_cache_hit_rate: f64) -> Vec<String> {
+        let breakers = self
+            .circuit_breakers
+            .iter()
+            .filter(|breaker| breaker.state == Some(BreakerState::Open))
+            .map(|breaker| format!("circuit breaker '{}' is open", breaker.service));
+        let caches = self
+            .caches
+            .iter()
+            .filter(|cache| cache.hits + cache.misses >= MIN_CACHE_LOOKUPS_FOR_HEALTH)
+            .filter_map(|cache| {
+                let rate = cache.hit_rate?;
+                (rate < min_cache_hit_rate).then(|| {
+                    format!(
+                        "cache '{}' hit rate {:.2} is below {:.2}",
+                        cache.cache, rate, min_cache_hit_rate
+                    )
+                })
+            });
+
+        let panics =
+            (self.panics > 0).then(|| format!("{} panic(s) recorded since start", self.panics));
+
+        panics.into_iter().chain(breakers).chain(caches).collect()
+    }
+}
+
+impl CircuitBreakerSnapshot {
+    fn empty(service: &str) -> Self {
+        Self {
+            service: service.to_string(),
+            state: None,
+            successes: 0,
+            failures: 0,
+            rejected: 0,
+            success_rate: None,
+        }
+    }
+}
+
+fn ratio(good: u64, bad: u64) -> Option<f64> {
+    let total = good + bad;
+    (total > 0).then(|| good as f64 / total as f64)
+}
+
+/// Placeholder substituted for identifier segments by [`normalize_path`].
+pub const PATH_ID_PLACEHOLDER: &str = "{id}";
+
+/// Collapse identifier segments of a request path into [`PATH_ID_PLACEHOLDER`].
+///
+/// Numeric and UUID segments (hyphenated or simple form) are replaced, the
+/// query string is dropped and a trailing slash is trimmed, so
+/// `/users/42` and `/users/0b7f…/` both map to `/users/{id}`.
+pub fn normalize_path(raw: &str) -> String {
+    let path = raw.split(['?', '#']).next().unwrap_or_default();
+    let trimmed = path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return "/".to_string();
+    }
+
+    trimmed
+        .split('/')
+        .map(|segment| {
+            if is_identifier_segment(segment) {
+                PATH_ID_PLACEHOLDER
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_identifier_segment(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+
+    if segment.bytes().all(|byte| byte.is_ascii_digit()) {
+        return true;
+    }
+
+    match segment.len() {
+        32 => segment.bytes().all(|byte| byte.is_ascii_hexdigit()),
+        36 => segment
+            .bytes()
+            .enumerate()
+            .all(|(index, byte)| match index {
+                8 | 13 | 18 | 23 => byte == b'-',
+                _ => byte.is_ascii_hexdigit(),
+            }),
+        _ => false,
+    }
+}
+
+/// `path` label of requests that did not match any route.
+///
+/// Unmatched paths come from clients (scanners, typos), so they share one
+/// constant label instead of adding a series per path.
+pub const UNMATCHED_PATH_LABEL: &str = "<unmatched>";
+
+/// Path label for HTTP metrics: the matched route template
+/// (`/api/users/{id}`) passed through [`normalize_path`], or
+/// [`UNMATCHED_PATH_LABEL`] when no route matched.
+pub fn http_path_label(route_template: Option<&str>) -> String {
+    route_template.map_or_else(|| UNMATCHED_PATH_LABEL.to_string(), normalize_path)
+}
+
+/// Record a completed HTTP request.
+///
+/// Takes the route template rather than the request path so the `path`
+/// label is always bounded by the router's set of routes.
+pub fn record_http_request(
+    method: &str,
+    route_template: Option<&str>,
+    status: u16,
+    duration_secs: f64,
+) {
+    let path = http_path_label(route_template);
+    let status = status.to_string();
+    crate::HTTP_REQUESTS_TOTAL
+        .with_label_values(&[method, path.as_str(), status.as_str()])
+        .inc();
+    crate::HTTP_REQUEST_DURATION_SECONDS
+        .with_label_values(&[method, path.as_str()])
+        .observe(duration_secs);
+}
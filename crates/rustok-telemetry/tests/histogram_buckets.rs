@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use rustok_telemetry::{metrics, LogFormat, TelemetryConfig, TelemetryError};
+
+#[test]
+fn overridden_buckets_appear_in_rendered_metrics() {
+    let config = TelemetryConfig {
+        service_name: "rustok-test".to_string(),
+        log_format: LogFormat::Json,
+        metrics: true,
+        otel: None,
+        histogram_buckets: HashMap::from([(
+            "rustok_cache_operation_duration_seconds".to_string(),
+            vec![0.002, 0.02, 0.2],
+        )]),
+        capture_panics: false,
+    };
+    rustok_telemetry::init_metrics(&config).expect("metrics should initialize");
+
+    metrics::record_cache_duration("bucket_test_cache", "get", 0.01);
+    metrics::record_event_processing_duration("BucketTest", "bucket_handler", 0.01);
+
+    let rendered = rustok_telemetry::render_metrics().unwrap();
+    let cache_buckets: Vec<&str> = rendered
+        .lines()
+        .filter(|line| line.starts_with("rustok_cache_operation_duration_seconds_bucket"))
+        .filter(|line| line.contains("bucket_test_cache"))
+        .collect();
+    assert_eq!(cache_buckets.len(), 4, "three overrides plus +Inf");
+    for bound in ["le=\"0.002\"", "le=\"0.02\"", "le=\"0.2\"", "le=\"+Inf\""] {
+        assert!(
+            cache_buckets.iter().any(|line| line.contains(bound)),
+            "missing {bound} in {cache_buckets:?}"
+        );
+    }
+    // Families without an override keep their defaults.
+    assert!(rendered.lines().any(|line| {
+        line.starts_with("rustok_event_bus_processing_duration_seconds_bucket")
+            && line.contains("le=\"0.025\"")
+    }));
+
+    // Histograms are fixed now, so a different override is refused.
+    assert!(matches!(
+        metrics::set_histogram_buckets(HashMap::from([(
+            "rustok_cache_operation_duration_seconds".to_string(),
+            vec![1.0],
+        )])),
+        Err(TelemetryError::HistogramBucketsFrozen)
+    ));
+}
+
+#[test]
+fn unordered_buckets_are_rejected() {
+    let result = metrics::set_histogram_buckets(HashMap::from([(
+        "rustok_event_bus_lag_seconds".to_string(),
+        vec![1.0, 0.5],
+    )]));
+
+    assert!(matches!(
+        result,
+        Err(TelemetryError::InvalidHistogramBuckets(name)) if name == "rustok_event_bus_lag_seconds"
+    ));
+}
@@ -10,6 +10,8 @@ fn test_telemetry_init() -> TestResult<()> {
         log_format: LogFormat::Json,
         metrics: false,
         otel: None,
+        histogram_buckets: Default::default(),
+        capture_panics: false,
     };
 
     let _handles = rustok_telemetry::init(config)?;
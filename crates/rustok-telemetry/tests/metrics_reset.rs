@@ -0,0 +1,85 @@
+/// Exact-value metric tests built on `reset_all_metrics`.
+///
+/// Kept in their own test binary and serialized, because the reset wipes
+/// process-wide metrics that other tests would be reading.
+use prometheus::Registry;
+use rustok_telemetry::metrics::{self, BreakerState};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+fn reset_lock() -> MutexGuard<'static, ()> {
+    static RESET_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    let guard = RESET_LOCK
+        .get_or_init(|| Mutex::new(()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    metrics::reset_all_metrics();
+    guard
+}
+
+#[test]
+fn counter_reads_exactly_n_after_reset() {
+    let _guard = reset_lock();
+    for _ in 0..5 {
+        metrics::record_event_published("ResetEvent", "tenant-reset");
+    }
+
+    let published = metrics::EVENT_BUS_PUBLISHED_TOTAL
+        .with_label_values(&["ResetEvent", "tenant-reset"])
+        .get();
+    assert_eq!(published, 5);
+
+    metrics::reset_all_metrics();
+    assert_eq!(
+        metrics::EVENT_BUS_PUBLISHED_TOTAL
+            .with_label_values(&["ResetEvent", "tenant-reset"])
+            .get(),
+        0
+    );
+}
+
+#[test]
+fn concurrent_increments_are_counted_exactly() {
+    let _guard = reset_lock();
+    let threads: Vec<_> = (0..10)
+        .map(|_| {
+            std::thread::spawn(|| {
+                for _ in 0..100 {
+                    metrics::record_event_published("ConcurrentEvent", "tenant-reset");
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(
+        metrics::EVENT_BUS_PUBLISHED_TOTAL
+            .with_label_values(&["ConcurrentEvent", "tenant-reset"])
+            .get(),
+        1000
+    );
+}
+
+#[test]
+fn reset_drops_labeled_series_and_zeroes_gauges() {
+    let _guard = reset_lock();
+    metrics::update_circuit_breaker_state("reset_service", BreakerState::Open);
+    metrics::record_panic();
+    metrics::HTTP_ACTIVE_CONNECTIONS.set(3);
+
+    metrics::reset_all_metrics();
+
+    let registry = Registry::new();
+    metrics::register_all(&registry).unwrap();
+    let families = registry.gather();
+    let series = |name: &str| {
+        families
+            .iter()
+            .find(|family| family.name() == name)
+            .map_or(0, |family| family.get_metric().len())
+    };
+    assert_eq!(series("rustok_circuit_breaker_state"), 0);
+    assert_eq!(metrics::PANICS_TOTAL.get(), 0);
+    assert_eq!(metrics::HTTP_ACTIVE_CONNECTIONS.get(), 0);
+}
@@ -2,7 +2,7 @@
 ///
 /// Tests metric registration, recording, and retrieval
 use prometheus::Registry;
-use rustok_telemetry::metrics;
+use rustok_telemetry::metrics::{self, BreakerState, EvictionReason, OpResult};
 
 #[test]
 fn test_register_all_metrics() {
@@ -15,8 +15,8 @@ fn test_register_all_metrics() {
     metrics::update_queue_depth("in_memory", 1);
     metrics::record_event_consumer_lagged("event_dispatcher");
     metrics::record_event_dispatch_latency_ms("event_dispatcher", "ProductCreated", 1.0);
-    metrics::update_circuit_breaker_state("redis", 0);
-    metrics::record_cache_operation("tenant_cache", "get", "hit");
+    metrics::update_circuit_breaker_state("redis", BreakerState::Closed);
+    metrics::record_cache_operation("tenant_cache", "get", OpResult::Hit);
     metrics::record_module_entrypoint_call("catalog", "create_product", "success");
 
     // Verify metrics are registered
@@ -77,18 +77,18 @@ fn test_event_bus_metrics() {
 #[test]
 fn test_circuit_breaker_metrics() {
     // Update state
-    metrics::update_circuit_breaker_state("redis", 0); // Closed
-    metrics::update_circuit_breaker_state("redis", 1); // Open
-    metrics::update_circuit_breaker_state("redis", 2); // Half-open
+    metrics::update_circuit_breaker_state("redis", BreakerState::Closed);
+    metrics::update_circuit_breaker_state("redis", BreakerState::Open);
+    metrics::update_circuit_breaker_state("redis", BreakerState::HalfOpen);
 
     // Record transitions
-    metrics::record_circuit_breaker_transition("redis", "closed", "open");
-    metrics::record_circuit_breaker_transition("redis", "open", "half_open");
+    metrics::record_circuit_breaker_transition("redis", BreakerState::Closed, BreakerState::Open);
+    metrics::record_circuit_breaker_transition("redis", BreakerState::Open, BreakerState::HalfOpen);
 
     // Record calls
-    metrics::record_circuit_breaker_call("redis", "success");
-    metrics::record_circuit_breaker_call("redis", "failure");
-    metrics::record_circuit_breaker_call("redis", "rejected");
+    metrics::record_circuit_breaker_call("redis", OpResult::Success);
+    metrics::record_circuit_breaker_call("redis", OpResult::Failure);
+    metrics::record_circuit_breaker_call("redis", OpResult::Rejected);
 
     // Update failures
     metrics::update_circuit_breaker_failures("redis", 5);
@@ -97,16 +97,19 @@ fn test_circuit_breaker_metrics() {
 #[test]
 fn test_cache_metrics() {
     // Record cache operations
-    metrics::record_cache_operation("tenant_cache", "get", "hit");
-    metrics::record_cache_operation("tenant_cache", "get", "miss");
-    metrics::record_cache_operation("tenant_cache", "set", "success");
+    metrics::record_cache_operation("tenant_cache", "get", OpResult::Hit);
+    metrics::record_cache_operation("tenant_cache", "get", OpResult::Miss);
+    metrics::record_cache_operation("tenant_cache", "set", OpResult::Success);
 
     // Update cache size
     metrics::update_cache_size("tenant_cache", 1234);
 
+    // Update hit rate
+    metrics::update_cache_hit_rate("tenant_cache", 0.5);
+
     // Record eviction
-    metrics::record_cache_eviction("tenant_cache", "ttl");
-    metrics::record_cache_eviction("tenant_cache", "capacity");
+    metrics::record_cache_eviction("tenant_cache", EvictionReason::Ttl);
+    metrics::record_cache_eviction("tenant_cache", EvictionReason::Capacity);
 
     // Record operation duration
     metrics::record_cache_duration("tenant_cache", "get", 0.001);
@@ -149,6 +152,9 @@ fn test_database_metrics() {
 
     // Record query error
     metrics::record_db_query_error("INSERT", "ConnectionError");
+
+    // Record slow query
+    metrics::record_db_slow_query("SELECT");
 }
 
 #[test]
@@ -195,25 +201,48 @@ fn test_counter_increments() {
     // Counter should have incremented 10 times
 }
 
+#[test]
+fn test_cache_lookup_updates_hit_rate() {
+    metrics::record_cache_lookup("lookup_test_cache", true);
+    metrics::record_cache_lookup("lookup_test_cache", true);
+    metrics::record_cache_lookup("lookup_test_cache", true);
+    metrics::record_cache_lookup("lookup_test_cache", false);
+
+    let rate = metrics::CACHE_HIT_RATE
+        .with_label_values(&["lookup_test_cache"])
+        .get();
+    assert!((rate - 0.75).abs() < f64::EPSILON);
+}
+
 #[test]
 fn test_circuit_breaker_state_values() {
     // Test state encoding
-    metrics::update_circuit_breaker_state("test_service", 0); // Closed
-    metrics::update_circuit_breaker_state("test_service", 1); // Open
-    metrics::update_circuit_breaker_state("test_service", 2); // Half-open
+    metrics::update_circuit_breaker_state("test_service", BreakerState::Closed);
+    metrics::update_circuit_breaker_state("test_service", BreakerState::Open);
+    metrics::update_circuit_breaker_state("test_service", BreakerState::HalfOpen);
 
-    // States should be distinct
+    assert_eq!(BreakerState::Closed.gauge_value(), 0);
+    assert_eq!(BreakerState::Open.gauge_value(), 1);
+    assert_eq!(BreakerState::HalfOpen.gauge_value(), 2);
+}
+
+#[test]
+fn test_label_values() {
+    assert_eq!(BreakerState::HalfOpen.as_label(), "half_open");
+    assert_eq!(OpResult::Rejected.as_label(), "rejected");
+    assert_eq!(OpResult::Miss.as_label(), "miss");
+    assert_eq!(EvictionReason::Explicit.as_label(), "explicit");
 }
 
 #[test]
 fn test_multiple_services() {
     // Test metrics for multiple services
-    metrics::update_circuit_breaker_state("redis", 0);
-    metrics::update_circuit_breaker_state("postgres", 0);
-    metrics::update_circuit_breaker_state("iggy", 1);
+    metrics::update_circuit_breaker_state("redis", BreakerState::Closed);
+    metrics::update_circuit_breaker_state("postgres", BreakerState::Closed);
+    metrics::update_circuit_breaker_state("iggy", BreakerState::Open);
 
-    metrics::record_cache_operation("tenant_cache", "get", "hit");
-    metrics::record_cache_operation("product_cache", "get", "miss");
+    metrics::record_cache_operation("tenant_cache", "get", OpResult::Hit);
+    metrics::record_cache_operation("product_cache", "get", OpResult::Miss);
 
     // Multiple services should coexist
 }
@@ -324,3 +353,174 @@ fn test_metric_help_text() {
         );
     }
 }
+
+#[test]
+fn test_normalize_path_collapses_identifiers_to_one_template() {
+    let paths = [
+        "/api/users/42",
+        "/api/users/7",
+        "/api/users/0b7f3a52-6f0e-4c8e-9a51-1d2c3e4f5a6b",
+        "/api/users/0B7F3A526F0E4C8E9A511D2C3E4F5A6B",
+        "/api/users/42/",
+        "/api/users/42?include=roles",
+    ];
+
+    for path in paths {
+        assert_eq!(metrics::normalize_path(path), "/api/users/{id}", "{path}");
+    }
+
+    assert_eq!(
+        metrics::normalize_path("/api/tenants/1/users/2/sessions"),
+        "/api/tenants/{id}/users/{id}/sessions"
+    );
+    assert_eq!(
+        metrics::normalize_path("/api/auth/login"),
+        "/api/auth/login"
+    );
+    assert_eq!(
+        metrics::normalize_path("/api/v2/catalog"),
+        "/api/v2/catalog"
+    );
+    assert_eq!(metrics::normalize_path("/"), "/");
+    assert_eq!(metrics::normalize_path(""), "/");
+}
+
+#[test]
+fn test_http_path_label_uses_route_template_or_constant() {
+    assert_eq!(
+        metrics::http_path_label(Some("/api/users/{id}")),
+        "/api/users/{id}"
+    );
+    assert_eq!(
+        metrics::http_path_label(None),
+        metrics::UNMATCHED_PATH_LABEL
+    );
+    assert_eq!(
+        metrics::http_path_label(Some("/api/users/{id}/")),
+        "/api/users/{id}"
+    );
+    assert_eq!(metrics::UNMATCHED_PATH_LABEL, "<unmatched>");
+}
+
+#[test]
+fn test_tenant_usage_reads_back_recorded_samples() {
+    let registry = Registry::new();
+    registry
+        .register(Box::new(metrics::TENANT_HTTP_REQUESTS_TOTAL.clone()))
+        .unwrap();
+    registry
+        .register(Box::new(metrics::EVENT_BUS_PUBLISHED_TOTAL.clone()))
+        .unwrap();
+
+    let tenant_a = "usage-readback-a".to_string();
+    let tenant_b = "usage-readback-b".to_string();
+    for _ in 0..8 {
+        metrics::record_tenant_http_request(&tenant_a, 200);
+    }
+    metrics::record_tenant_http_request(&tenant_a, 404);
+    metrics::record_tenant_http_request(&tenant_a, 503);
+    metrics::record_tenant_http_request(&tenant_a, 500);
+    metrics::record_tenant_http_request(&tenant_b, 200);
+    for _ in 0..3 {
+        metrics::record_event_published("ProductCreated", &tenant_a);
+    }
+
+    let usage = metrics::tenant_usage_from_families(&registry.gather(), &tenant_a);
+    assert_eq!(
+        usage,
+        metrics::TenantUsage {
+            requests: 11,
+            errors: 2,
+            events_published: 3,
+        }
+    );
+
+    let other = metrics::tenant_usage_from_families(&registry.gather(), &tenant_b);
+    assert_eq!(other.requests, 1);
+    assert_eq!(other.errors, 0);
+
+    let idle = metrics::tenant_usage_from_families(&registry.gather(), "tenant-idle");
+    assert_eq!(idle, metrics::TenantUsage::default());
+}
+
+#[test]
+fn test_snapshot_captures_breakers_and_caches() {
+    metrics::update_circuit_breaker_state("snapshot_payments", BreakerState::Open);
+    metrics::record_circuit_breaker_call("snapshot_payments", OpResult::Success);
+    metrics::record_circuit_breaker_call("snapshot_payments", OpResult::Failure);
+    metrics::record_circuit_breaker_call("snapshot_payments", OpResult::Failure);
+    metrics::record_circuit_breaker_call("snapshot_payments", OpResult::Rejected);
+    metrics::update_circuit_breaker_state("snapshot_search", BreakerState::Closed);
+    metrics::record_circuit_breaker_call("snapshot_search", OpResult::Success);
+
+    for _ in 0..30 {
+        metrics::record_cache_lookup("snapshot_cold_cache", false);
+    }
+    metrics::record_cache_lookup("snapshot_cold_cache", true);
+    for _ in 0..3 {
+        metrics::record_cache_lookup("snapshot_warm_cache", true);
+    }
+    metrics::record_cache_lookup("snapshot_warm_cache", false);
+
+    let snapshot = metrics::MetricsSnapshot::capture();
+
+    let payments = snapshot
+        .circuit_breakers
+        .iter()
+        .find(|breaker| breaker.service == "snapshot_payments")
+        .expect("payments breaker should be captured");
+    assert_eq!(payments.state, Some(BreakerState::Open));
+    assert_eq!((payments.successes, payments.failures), (1, 2));
+    assert_eq!(payments.rejected, 1);
+    assert_eq!(payments.success_rate, Some(1.0 / 3.0));
+    let search = snapshot
+        .circuit_breakers
+        .iter()
+        .find(|breaker| breaker.service == "snapshot_search")
+        .expect("search breaker should be captured");
+    assert_eq!(search.state, Some(BreakerState::Closed));
+    assert_eq!(search.success_rate, Some(1.0));
+
+    let warm = snapshot
+        .caches
+        .iter()
+        .find(|cache| cache.cache == "snapshot_warm_cache")
+        .expect("warm cache should be captured");
+    assert_eq!((warm.hits, warm.misses), (3, 1));
+    assert_eq!(warm.hit_rate, Some(0.75));
+    let cold = snapshot
+        .caches
+        .iter()
+        .find(|cache| cache.cache == "snapshot_cold_cache")
+        .expect("cold cache should be captured");
+    assert_eq!((cold.hits, cold.misses), (1, 30));
+
+    let warnings = snapshot.check_health(metrics::DEFAULT_MIN_CACHE_HIT_RATE);
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.contains("'snapshot_payments' is open")));
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.contains("'snapshot_cold_cache'")));
+    assert!(!warnings
+        .iter()
+        .any(|warning| warning.contains("snapshot_search") || warning.contains("snapshot_warm")));
+}
+
+#[test]
+fn test_snapshot_check_health_skips_caches_with_few_lookups() {
+    let snapshot = metrics::MetricsSnapshot {
+        circuit_breakers: Vec::new(),
+        caches: vec![metrics::CacheSnapshot {
+            cache: "fresh".to_string(),
+            hits: 0,
+            misses: 3,
+            hit_rate: Some(0.0),
+        }],
+        panics: 0,
+    };
+
+    assert!(snapshot
+        .check_health(metrics::DEFAULT_MIN_CACHE_HIT_RATE)
+        .is_empty());
+}
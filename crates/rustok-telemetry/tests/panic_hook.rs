@@ -0,0 +1,25 @@
+use rustok_telemetry::{metrics, LogFormat, TelemetryConfig};
+
+#[test]
+fn panic_hook_counts_panics_from_spawned_threads() {
+    let config = TelemetryConfig {
+        service_name: "rustok-test".to_string(),
+        log_format: LogFormat::Json,
+        metrics: false,
+        otel: None,
+        histogram_buckets: Default::default(),
+        capture_panics: true,
+    };
+    rustok_telemetry::init_metrics(&config).expect("init should succeed");
+    let before = metrics::PANICS_TOTAL.get();
+
+    let result = std::thread::spawn(|| panic!("boom")).join();
+
+    assert!(result.is_err());
+    assert_eq!(metrics::PANICS_TOTAL.get(), before + 1);
+    assert_eq!(metrics::MetricsSnapshot::capture().panics, before + 1);
+    assert!(metrics::MetricsSnapshot::capture()
+        .check_health(metrics::DEFAULT_MIN_CACHE_HIT_RATE)
+        .iter()
+        .any(|warning| warning.contains("panic")));
+}
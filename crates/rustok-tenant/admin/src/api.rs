@@ -4,7 +4,7 @@ use std::fmt::{Display, Formatter};
 
 use crate::model::TenantAdminBootstrap;
 #[cfg(feature = "ssr")]
-use crate::model::{TenantAdminModule, TenantAdminTenant};
+use crate::model::{TenantAdminModule, TenantAdminSetting, TenantAdminTenant};
 #[cfg(feature = "ssr")]
 use std::collections::HashMap;
 
@@ -33,6 +33,12 @@ pub async fn fetch_bootstrap() -> Result<TenantAdminBootstrap, ApiError> {
     tenant_bootstrap_native().await.map_err(Into::into)
 }
 
+pub async fn update_setting(key: String, value: serde_json::Value) -> Result<(), ApiError> {
+    tenant_update_setting_native(key, value)
+        .await
+        .map_err(Into::into)
+}
+
 #[server(prefix = "/api/fn", endpoint = "tenant/bootstrap")]
 async fn tenant_bootstrap_native() -> Result<TenantAdminBootstrap, ServerFnError> {
     #[cfg(feature = "ssr")]
@@ -40,8 +46,10 @@ async fn tenant_bootstrap_native() -> Result<TenantAdminBootstrap, ServerFnError
         use leptos::prelude::expect_context;
         use loco_rs::app::AppContext;
         use rustok_api::{has_any_effective_permission, AuthContext, TenantContext};
+        use rustok_core::ModuleRuntimeExtensions;
         use rustok_core::{ModuleRegistry, Permission};
-        use rustok_tenant::TenantService;
+        use rustok_tenant::{settings_schema_from_extensions, TenantService};
+        use std::sync::Arc;
 
         let app_ctx = expect_context::<AppContext>();
         let registry = expect_context::<ModuleRegistry>();
@@ -115,6 +123,28 @@ async fn tenant_bootstrap_native() -> Result<TenantAdminBootstrap, ServerFnError
             .collect::<Vec<_>>();
         modules.sort_by(|left, right| left.slug.cmp(&right.slug));
 
+        let settings = app_ctx
+            .shared_store
+            .get::<Arc<ModuleRuntimeExtensions>>()
+            .and_then(|extensions| settings_schema_from_extensions(&extensions))
+            .map(|schema| {
+                schema
+                    .definitions()
+                    .map(|definition| {
+                        let stored = definition.stored_value(&tenant_record.settings);
+                        TenantAdminSetting {
+                            key: definition.key().to_string(),
+                            value_type: definition.value_type().as_str().to_string(),
+                            description: definition.description().map(str::to_string),
+                            value: definition
+                                .redacted(stored.unwrap_or_else(|| definition.default_value())),
+                            is_default: stored.is_none(),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
         Ok(TenantAdminBootstrap {
             tenant: TenantAdminTenant {
                 id: tenant_record.id.to_string(),
@@ -126,6 +156,7 @@ async fn tenant_bootstrap_native() -> Result<TenantAdminBootstrap, ServerFnError
                 updated_at: tenant_record.updated_at,
             },
             modules,
+            settings,
         })
     }
     #[cfg(not(feature = "ssr"))]
@@ -135,3 +166,55 @@ async fn tenant_bootstrap_native() -> Result<TenantAdminBootstrap, ServerFnError
         ))
     }
 }
+
+#[server(prefix = "/api/fn", endpoint = "tenant/settings/update")]
+async fn tenant_update_setting_native(
+    key: String,
+    value: serde_json::Value,
+) -> Result<(), ServerFnError> {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos::prelude::expect_context;
+        use loco_rs::app::AppContext;
+        use rustok_api::{has_any_effective_permission, AuthContext, TenantContext};
+        use rustok_core::{ModuleRuntimeExtensions, Permission};
+        use rustok_tenant::{settings_schema_from_extensions, TenantService};
+        use std::sync::Arc;
+
+        let app_ctx = expect_context::<AppContext>();
+        let auth = leptos_axum::extract::<AuthContext>()
+            .await
+            .map_err(ServerFnError::new)?;
+        let tenant = leptos_axum::extract::<TenantContext>()
+            .await
+            .map_err(ServerFnError::new)?;
+
+        if !has_any_effective_permission(
+            &auth.permissions,
+            &[Permission::TENANTS_UPDATE, Permission::TENANTS_MANAGE],
+        ) {
+            return Err(ServerFnError::new(
+                "tenant settings update requires tenants:(update|manage)",
+            ));
+        }
+
+        let schema = app_ctx
+            .shared_store
+            .get::<Arc<ModuleRuntimeExtensions>>()
+            .and_then(|extensions| settings_schema_from_extensions(&extensions))
+            .unwrap_or_default();
+        TenantService::new(app_ctx.db.clone())
+            .with_settings_schema(schema)
+            .set_setting(tenant.id, &key, value)
+            .await
+            .map(|_| ())
+            .map_err(ServerFnError::new)
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = (key, value);
+        Err(ServerFnError::new(
+            "rustok-tenant-admin requires the `ssr` feature for native settings updates",
+        ))
+    }
+}
@@ -3,10 +3,12 @@ mod i18n;
 mod model;
 
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use leptos_auth::hooks::{use_tenant, use_token};
 use rustok_api::UiRouteContext;
 
 use crate::i18n::t;
+use crate::model::TenantAdminSetting;
 
 fn local_resource<S, Fut, T>(
     source: impl Fn() -> S + 'static,
@@ -113,6 +115,8 @@ pub fn TenantAdmin() -> impl IntoView {
                                         .collect_view()}
                                 </div>
                             </section>
+
+                            <SettingsSection settings=bootstrap.settings locale=locale.clone() />
                         }
                         .into_any(),
                         Err(err) => view! {
@@ -137,3 +141,150 @@ fn InfoCard(label: String, value: String) -> impl IntoView {
         </div>
     }
 }
+
+#[component]
+fn SettingsSection(settings: Vec<TenantAdminSetting>, locale: Option<String>) -> impl IntoView {
+    view! {
+        <section class="rounded-2xl border border-border bg-card p-6 shadow-sm">
+            <h2 class="text-lg font-semibold text-card-foreground">
+                {t(locale.as_deref(), "tenant.settings.title", "Tenant Settings")}
+            </h2>
+            <p class="text-sm text-muted-foreground">
+                {t(locale.as_deref(), "tenant.settings.subtitle", "Settings declared by modules; values are validated against the declared type.")}
+            </p>
+            <div class="mt-4 grid gap-3">
+                {if settings.is_empty() {
+                    view! {
+                        <div class="text-sm text-muted-foreground">
+                            {t(locale.as_deref(), "tenant.settings.empty", "No module declares tenant settings.")}
+                        </div>
+                    }
+                    .into_any()
+                } else {
+                    settings
+                        .into_iter()
+                        .map(|setting| view! { <SettingRow setting=setting locale=locale.clone() /> })
+                        .collect_view()
+                        .into_any()
+                }}
+            </div>
+        </section>
+    }
+}
+
+/// Text form of a setting value for the input element.
+fn setting_input_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Parse input text back into the declared type; the server validates again.
+fn parse_setting_input(value_type: &str, raw: &str) -> Result<serde_json::Value, String> {
+    match value_type {
+        "string" => Ok(serde_json::Value::String(raw.to_string())),
+        "boolean" => Ok(serde_json::Value::Bool(raw == "true")),
+        _ => serde_json::from_str(raw.trim()).map_err(|error| error.to_string()),
+    }
+}
+
+#[component]
+fn SettingRow(setting: TenantAdminSetting, locale: Option<String>) -> impl IntoView {
+    let TenantAdminSetting {
+        key,
+        value_type,
+        description,
+        value,
+        is_default,
+    } = setting;
+    let (raw, set_raw) = signal(setting_input_text(&value));
+    let (status, set_status) = signal(None::<Result<(), String>>);
+    let saved_label = t(locale.as_deref(), "tenant.settings.saved", "Saved");
+    let default_label = t(locale.as_deref(), "tenant.settings.default", "default");
+    let save_label = t(locale.as_deref(), "tenant.settings.save", "Save");
+
+    let save = {
+        let key = key.clone();
+        let value_type = value_type.clone();
+        move |_| {
+            let key = key.clone();
+            match parse_setting_input(&value_type, &raw.get_untracked()) {
+                Ok(value) => spawn_local(async move {
+                    let result = api::update_setting(key, value)
+                        .await
+                        .map_err(|error| error.to_string());
+                    set_status.set(Some(result));
+                }),
+                Err(error) => set_status.set(Some(Err(error))),
+            }
+        }
+    };
+
+    let input = match value_type.as_str() {
+        "boolean" => view! {
+            <input
+                type="checkbox"
+                prop:checked=move || raw.get() == "true"
+                on:change=move |ev| set_raw.set(event_target_checked(&ev).to_string())
+            />
+        }
+        .into_any(),
+        "integer" | "number" => view! {
+            <input
+                type="number"
+                class="rounded-md border border-border bg-background px-3 py-1 text-sm"
+                prop:value=move || raw.get()
+                on:input=move |ev| set_raw.set(event_target_value(&ev))
+            />
+        }
+        .into_any(),
+        "object" | "array" => view! {
+            <textarea
+                class="min-h-20 rounded-md border border-border bg-background px-3 py-1 font-mono text-xs"
+                prop:value=move || raw.get()
+                on:input=move |ev| set_raw.set(event_target_value(&ev))
+            ></textarea>
+        }
+        .into_any(),
+        _ => view! {
+            <input
+                type="text"
+                class="rounded-md border border-border bg-background px-3 py-1 text-sm"
+                prop:value=move || raw.get()
+                on:input=move |ev| set_raw.set(event_target_value(&ev))
+            />
+        }
+        .into_any(),
+    };
+
+    view! {
+        <div class="rounded-xl border border-border bg-background px-4 py-3">
+            <div class="flex flex-col gap-2 lg:flex-row lg:items-center lg:justify-between">
+                <div>
+                    <div class="font-medium text-card-foreground">{key}</div>
+                    <div class="text-xs text-muted-foreground">
+                        {if is_default { format!("{value_type} · {default_label}") } else { value_type.clone() }}
+                    </div>
+                    {description.map(|description| view! {
+                        <div class="mt-1 text-sm text-muted-foreground">{description}</div>
+                    })}
+                </div>
+                <div class="flex flex-wrap items-center gap-2">
+                    {input}
+                    <button
+                        type="button"
+                        class="rounded-md border border-border px-3 py-1 text-xs"
+                        on:click=save
+                    >
+                        {save_label}
+                    </button>
+                </div>
+            </div>
+            {move || status.get().map(|result| match result {
+                Ok(()) => view! { <div class="mt-2 text-xs text-muted-foreground">{saved_label.clone()}</div> }.into_any(),
+                Err(error) => view! { <div class="mt-2 text-xs text-destructive">{error}</div> }.into_any(),
+            })}
+        </div>
+    }
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 pub struct TenantAdminBootstrap {
     pub tenant: TenantAdminTenant,
     pub modules: Vec<TenantAdminModule>,
+    #[serde(default)]
+    pub settings: Vec<TenantAdminSetting>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,3 +28,13 @@ pub struct TenantAdminModule {
     pub enabled: bool,
     pub source: String,
 }
+
+/// Declared tenant setting with its effective value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantAdminSetting {
+    pub key: String,
+    pub value_type: String,
+    pub description: Option<String>,
+    pub value: serde_json::Value,
+    pub is_default: bool,
+}
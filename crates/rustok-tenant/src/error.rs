@@ -8,6 +8,10 @@ pub enum TenantError {
     SlugAlreadyExists(String),
     #[error("invalid tenant settings schema: {0}")]
     InvalidSettingsSchema(String),
+    #[error("unknown tenant setting '{0}'")]
+    UnknownSetting(String),
+    #[error("invalid value for tenant setting '{key}': {reason}")]
+    InvalidSetting { key: String, reason: String },
     #[error("failed to publish tenant event: {0}")]
     EventPublish(String),
     #[error("database error: {0}")]
@@ -10,6 +10,10 @@ pub use dto::{
 };
 pub use error::TenantError;
 pub use services::TenantService;
+pub use settings_schema::{
+    register_tenant_setting, settings_schema_from_extensions, SettingDefinition, SettingDescriptor,
+    SettingType, SettingsSchema,
+};
 
 use async_trait::async_trait;
 use rustok_core::module::{HealthStatus, MigrationSource, ModuleKind, RusToKModule};
@@ -1,11 +1,13 @@
+use std::sync::Arc;
+
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set,
+    TransactionTrait,
 };
 use tracing::instrument;
 use uuid::Uuid;
 
-use rustok_core::generate_id;
+use rustok_core::{fetch_with_total, generate_id, Page};
 use rustok_events::DomainEvent;
 use rustok_outbox::TransactionalEventBus;
 
@@ -15,13 +17,14 @@ use crate::dto::{
 use crate::entities::tenant::{self, ActiveModel as TenantActiveModel};
 use crate::entities::tenant_module::{self, ActiveModel as TenantModuleActiveModel};
 use crate::error::TenantError;
-use crate::settings_schema::validate_tenant_settings;
+use crate::settings_schema::{validate_tenant_settings, write_setting, SettingsSchema};
 
 pub type TenantResult<T> = Result<T, TenantError>;
 
 pub struct TenantService {
     db: DatabaseConnection,
     event_bus: Option<TransactionalEventBus>,
+    settings_schema: Arc<SettingsSchema>,
 }
 
 impl TenantService {
@@ -29,6 +32,7 @@ impl TenantService {
         Self {
             db,
             event_bus: None,
+            settings_schema: Arc::default(),
         }
     }
 
@@ -36,9 +40,21 @@ impl TenantService {
         Self {
             db,
             event_bus: Some(event_bus),
+            settings_schema: Arc::default(),
         }
     }
 
+    /// Use `schema` for [`Self::get_setting`] / [`Self::set_setting`].
+    /// Without it every key is unknown.
+    pub fn with_settings_schema(mut self, schema: Arc<SettingsSchema>) -> Self {
+        self.settings_schema = schema;
+        self
+    }
+
+    pub fn settings_schema(&self) -> &SettingsSchema {
+        &self.settings_schema
+    }
+
     #[instrument(skip(self, input), fields(slug = %input.slug))]
     pub async fn create_tenant(&self, input: CreateTenantInput) -> TenantResult<TenantResponse> {
         let txn = self.db.begin().await?;
@@ -71,7 +87,7 @@ impl TenantService {
 
         txn.commit().await?;
 
-        Ok(to_tenant_response(model))
+        Ok(self.tenant_response(model))
     }
 
     #[instrument(skip(self), fields(tenant_id = %tenant_id))]
@@ -80,7 +96,7 @@ impl TenantService {
             .one(&self.db)
             .await?
             .ok_or(TenantError::NotFound)?;
-        Ok(to_tenant_response(model))
+        Ok(self.tenant_response(model))
     }
 
     #[instrument(skip(self), fields(slug = %slug))]
@@ -90,7 +106,7 @@ impl TenantService {
             .one(&self.db)
             .await?
             .ok_or(TenantError::NotFound)?;
-        Ok(to_tenant_response(model))
+        Ok(self.tenant_response(model))
     }
 
     #[instrument(skip(self, input), fields(tenant_id = %tenant_id))]
@@ -107,6 +123,7 @@ impl TenantService {
             .ok_or(TenantError::NotFound)?;
 
         let now = chrono::Utc::now().into();
+        let stored_settings = existing.settings.clone();
         let mut active: tenant::ActiveModel = existing.into();
         if let Some(name) = input.name {
             active.name = Set(name);
@@ -117,7 +134,9 @@ impl TenantService {
         if let Some(is_active) = input.is_active {
             active.is_active = Set(is_active);
         }
-        if let Some(settings) = input.settings {
+        if let Some(mut settings) = input.settings {
+            self.settings_schema
+                .keep_write_only_fields(&stored_settings, &mut settings);
             validate_tenant_settings(&settings)?;
             active.settings = Set(settings);
         }
@@ -130,19 +149,83 @@ impl TenantService {
 
         txn.commit().await?;
 
-        Ok(to_tenant_response(model))
+        Ok(self.tenant_response(model))
+    }
+
+    /// Stored value of a declared setting, or its declared default when the
+    /// tenant has not set it.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, key = %key))]
+    pub async fn get_setting(&self, tenant_id: Uuid, key: &str) -> TenantResult<serde_json::Value> {
+        let definition = self.settings_schema.definition(key)?;
+        let model = tenant::Entity::find_by_id(tenant_id)
+            .one(&self.db)
+            .await?
+            .ok_or(TenantError::NotFound)?;
+
+        Ok(definition.redacted(
+            definition
+                .stored_value(&model.settings)
+                .unwrap_or_else(|| definition.default_value()),
+        ))
+    }
+
+    /// Validate `value` against the declared setting and store it under the
+    /// dotted `key` of the tenant settings.
+    #[instrument(skip(self, value), fields(tenant_id = %tenant_id, key = %key))]
+    pub async fn set_setting(
+        &self,
+        tenant_id: Uuid,
+        key: &str,
+        mut value: serde_json::Value,
+    ) -> TenantResult<TenantResponse> {
+        let definition = self.settings_schema.definition(key)?;
+
+        let txn = self.db.begin().await?;
+        let existing = tenant::Entity::find_by_id(tenant_id)
+            .one(&txn)
+            .await?
+            .ok_or(TenantError::NotFound)?;
+        definition.keep_write_only_fields(definition.stored_value(&existing.settings), &mut value);
+        definition.validate(&value)?;
+
+        let mut settings = existing.settings.clone();
+        write_setting(&mut settings, key, value);
+        validate_tenant_settings(&settings)?;
+
+        let mut active: tenant::ActiveModel = existing.into();
+        active.settings = Set(settings);
+        active.updated_at = Set(chrono::Utc::now().into());
+        let model = active.update(&txn).await?;
+
+        self.publish_event_in_tx(&txn, tenant_id, DomainEvent::TenantUpdated { tenant_id })
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(self.tenant_response(model))
     }
 
     pub async fn list_tenants(
         &self,
         page: u64,
         per_page: u64,
-    ) -> TenantResult<(Vec<TenantResponse>, u64)> {
-        let paginator = tenant::Entity::find().paginate(&self.db, per_page);
-        let total = paginator.num_items().await?;
-        let models = paginator.fetch_page(page.saturating_sub(1)).await?;
-        let items = models.into_iter().map(to_tenant_response).collect();
-        Ok((items, total))
+    ) -> TenantResult<Page<TenantResponse>> {
+        let (models, total) = fetch_with_total(
+            &self.db,
+            tenant::Entity::find(),
+            page.saturating_sub(1) * per_page,
+            per_page,
+        )
+        .await?;
+        let items = models
+            .into_iter()
+            .map(|model| self.tenant_response(model))
+            .collect();
+        Ok(Page::offset(
+            items,
+            total,
+            page.saturating_sub(1) * per_page,
+        ))
     }
 
     /// Deprecated low-level tenant override writer.
@@ -231,6 +314,13 @@ impl TenantService {
         Ok(modules.into_iter().map(to_module_response).collect())
     }
 
+    /// Response for `m` with write-only setting fields removed.
+    fn tenant_response(&self, m: tenant::Model) -> TenantResponse {
+        let mut response = to_tenant_response(m);
+        self.settings_schema.redact(&mut response.settings);
+        response
+    }
+
     async fn publish_event_in_tx<C>(
         &self,
         txn: &C,
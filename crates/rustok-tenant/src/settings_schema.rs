@@ -1,4 +1,10 @@
-use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use rustok_core::ModuleRuntimeExtensions;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
 use crate::error::TenantError;
 
@@ -76,11 +82,354 @@ fn validate_value(value: &Value, depth: usize) -> Result<(), TenantError> {
     Ok(())
 }
 
+/// Value type of a declared tenant setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Object,
+    Array,
+}
+
+impl SettingType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+        }
+    }
+
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::Object => value.is_object(),
+            Self::Array => value.is_array(),
+        }
+    }
+}
+
+type SettingValidator = Arc<dyn Fn(&Value) -> Result<(), String> + Send + Sync>;
+
+/// One tenant setting declared by a module: a dotted key into
+/// `tenants.settings` (`"catalog.default_currency"`), its type, default and
+/// optional extra validation.
+#[derive(Clone)]
+pub struct SettingDefinition {
+    key: String,
+    value_type: SettingType,
+    default: Value,
+    description: Option<String>,
+    validator: Option<SettingValidator>,
+    write_only_fields: Vec<String>,
+}
+
+impl SettingDefinition {
+    pub fn new(key: impl Into<String>, value_type: SettingType, default: Value) -> Self {
+        Self {
+            key: key.into(),
+            value_type,
+            default,
+            description: None,
+            validator: None,
+            write_only_fields: Vec::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Extra check run after the type check; the error message is returned
+    /// to the caller as the rejection reason.
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Fields of an object setting that are accepted on writes but never
+    /// read back, such as signing secrets. [`Self::redacted`] drops them and
+    /// a write that omits one keeps the stored value.
+    pub fn with_write_only_fields(mut self, fields: &[&str]) -> Self {
+        self.write_only_fields = fields.iter().map(|field| field.to_string()).collect();
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value_type(&self) -> SettingType {
+        self.value_type
+    }
+
+    pub fn default_value(&self) -> &Value {
+        &self.default
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Value stored for this setting in a tenant's settings object, if any.
+    pub fn stored_value<'a>(&self, settings: &'a Value) -> Option<&'a Value> {
+        read_setting(settings, &self.key)
+    }
+
+    /// `value` without its write-only fields, for returning to clients.
+    pub fn redacted(&self, value: &Value) -> Value {
+        let mut value = value.clone();
+        if let Some(object) = value.as_object_mut() {
+            for field in &self.write_only_fields {
+                object.remove(field);
+            }
+        }
+        value
+    }
+
+    /// Copy write-only fields that `value` omits from the `stored` value, so
+    /// a client can resubmit a redacted read without clearing them.
+    pub fn keep_write_only_fields(&self, stored: Option<&Value>, value: &mut Value) {
+        let (Some(stored), Some(object)) =
+            (stored.and_then(Value::as_object), value.as_object_mut())
+        else {
+            return;
+        };
+        for field in &self.write_only_fields {
+            if let (false, Some(kept)) = (object.contains_key(field), stored.get(field)) {
+                object.insert(field.clone(), kept.clone());
+            }
+        }
+    }
+
+    pub fn validate(&self, value: &Value) -> Result<(), TenantError> {
+        if !self.value_type.matches(value) {
+            return Err(TenantError::InvalidSetting {
+                key: self.key.clone(),
+                reason: format!("expected {}", self.value_type.as_str()),
+            });
+        }
+
+        if let Some(validator) = &self.validator {
+            validator(value).map_err(|reason| TenantError::InvalidSetting {
+                key: self.key.clone(),
+                reason,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn descriptor(&self) -> SettingDescriptor {
+        SettingDescriptor {
+            key: self.key.clone(),
+            value_type: self.value_type,
+            default: self.default.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for SettingDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SettingDefinition")
+            .field("key", &self.key)
+            .field("value_type", &self.value_type)
+            .field("default", &self.default)
+            .field("has_validator", &self.validator.is_some())
+            .field("write_only_fields", &self.write_only_fields)
+            .finish()
+    }
+}
+
+/// Serializable view of a [`SettingDefinition`] for admin UIs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SettingDescriptor {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub value_type: SettingType,
+    pub default: Value,
+    pub description: Option<String>,
+}
+
+/// Registry of declared tenant settings.
+///
+/// Modules register their settings at startup; `TenantService::set_setting`
+/// rejects keys that are not registered and values that fail the declared
+/// type or validator, and `TenantService::get_setting` falls back to the
+/// declared default.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsSchema {
+    settings: BTreeMap<String, SettingDefinition>,
+}
+
+impl SettingsSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a setting. Fails on malformed or duplicate keys and on a
+    /// default that does not pass its own validation.
+    pub fn register(&mut self, definition: SettingDefinition) -> Result<(), TenantError> {
+        validate_setting_key(definition.key())?;
+        if self.settings.contains_key(definition.key()) {
+            return Err(TenantError::InvalidSettingsSchema(format!(
+                "setting '{}' is already registered",
+                definition.key()
+            )));
+        }
+        definition.validate(definition.default_value())?;
+
+        self.settings
+            .insert(definition.key().to_string(), definition);
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&SettingDefinition> {
+        self.settings.get(key)
+    }
+
+    /// Definition for `key`, or [`TenantError::UnknownSetting`].
+    pub fn definition(&self, key: &str) -> Result<&SettingDefinition, TenantError> {
+        self.get(key)
+            .ok_or_else(|| TenantError::UnknownSetting(key.to_string()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty()
+    }
+
+    /// Declared settings ordered by key.
+    pub fn definitions(&self) -> impl Iterator<Item = &SettingDefinition> {
+        self.settings.values()
+    }
+
+    /// Drop the write-only fields of every declared setting from a tenant's
+    /// settings object before it leaves the service.
+    pub fn redact(&self, settings: &mut Value) {
+        for definition in self.definitions() {
+            if definition.write_only_fields.is_empty() {
+                continue;
+            }
+            if let Some(value) = read_setting_mut(settings, definition.key()) {
+                *value = definition.redacted(value);
+            }
+        }
+    }
+
+    /// Carry write-only fields from `stored` into a replacement `settings`
+    /// object wherever the replacement omits them.
+    pub fn keep_write_only_fields(&self, stored: &Value, settings: &mut Value) {
+        for definition in self.definitions() {
+            if definition.write_only_fields.is_empty() {
+                continue;
+            }
+            if let Some(value) = read_setting_mut(settings, definition.key()) {
+                definition.keep_write_only_fields(definition.stored_value(stored), value);
+            }
+        }
+    }
+
+    /// Serializable form of [`Self::definitions`].
+    pub fn descriptors(&self) -> Vec<SettingDescriptor> {
+        self.definitions()
+            .map(SettingDefinition::descriptor)
+            .collect()
+    }
+}
+
+/// Declare a tenant setting from `RusToKModule::register_runtime_extensions`.
+pub fn register_tenant_setting(
+    extensions: &mut ModuleRuntimeExtensions,
+    definition: SettingDefinition,
+) -> Result<(), TenantError> {
+    let schema = extensions
+        .get_or_insert_with::<Arc<SettingsSchema>, _>(|| Arc::new(SettingsSchema::default()));
+    Arc::make_mut(schema).register(definition)
+}
+
+/// Settings declared by all registered modules.
+pub fn settings_schema_from_extensions(
+    extensions: &ModuleRuntimeExtensions,
+) -> Option<Arc<SettingsSchema>> {
+    extensions.get::<Arc<SettingsSchema>>().cloned()
+}
+
+fn validate_setting_key(key: &str) -> Result<(), TenantError> {
+    let valid = !key.is_empty()
+        && key.len() <= MAX_KEY_LENGTH
+        && key.split('.').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(TenantError::InvalidSettingsSchema(format!(
+            "setting key '{key}' must be dot-separated [a-z0-9_] segments",
+        )))
+    }
+}
+
+/// Value at dotted `key` inside a settings object.
+fn read_setting<'a>(settings: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(settings, |current, segment| current.get(segment))
+}
+
+fn read_setting_mut<'a>(settings: &'a mut Value, key: &str) -> Option<&'a mut Value> {
+    key.split('.')
+        .try_fold(settings, |current, segment| current.get_mut(segment))
+}
+
+/// Store `value` at dotted `key`, creating (or replacing non-object)
+/// intermediate objects.
+pub(crate) fn write_setting(settings: &mut Value, key: &str, value: Value) {
+    if !settings.is_object() {
+        *settings = Value::Object(Map::new());
+    }
+
+    let mut segments = key.split('.').peekable();
+    let mut current = settings;
+    while let Some(segment) = segments.next() {
+        let map = current
+            .as_object_mut()
+            .expect("intermediate setting values are objects");
+        if segments.peek().is_none() {
+            map.insert(segment.to_string(), value);
+            return;
+        }
+        let next = map
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !next.is_object() {
+            *next = Value::Object(Map::new());
+        }
+        current = next;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
 
-    use super::validate_tenant_settings;
+    use super::*;
 
     #[test]
     fn accepts_object_settings() {
@@ -130,4 +479,148 @@ mod tests {
             validate_tenant_settings(&settings).expect_err("schema must reject oversized payload");
         assert!(err.to_string().contains("payload size"));
     }
+
+    fn currency_schema() -> SettingsSchema {
+        let mut schema = SettingsSchema::new();
+        schema
+            .register(
+                SettingDefinition::new(
+                    "catalog.default_currency",
+                    SettingType::String,
+                    json!("USD"),
+                )
+                .with_validator(|value| match value.as_str() {
+                    Some(code) if code.len() == 3 => Ok(()),
+                    _ => Err("must be a 3-letter currency code".to_string()),
+                }),
+            )
+            .expect("setting should register");
+        schema
+    }
+
+    #[test]
+    fn schema_rejects_duplicate_and_malformed_keys() {
+        let mut schema = currency_schema();
+
+        let duplicate = schema.register(SettingDefinition::new(
+            "catalog.default_currency",
+            SettingType::String,
+            json!("EUR"),
+        ));
+        assert!(matches!(
+            duplicate,
+            Err(TenantError::InvalidSettingsSchema(_))
+        ));
+
+        let malformed = schema.register(SettingDefinition::new(
+            "Catalog..currency",
+            SettingType::String,
+            json!("EUR"),
+        ));
+        assert!(matches!(
+            malformed,
+            Err(TenantError::InvalidSettingsSchema(_))
+        ));
+
+        let bad_default = schema.register(SettingDefinition::new(
+            "catalog.page_size",
+            SettingType::Integer,
+            json!("ten"),
+        ));
+        assert!(matches!(
+            bad_default,
+            Err(TenantError::InvalidSetting { .. })
+        ));
+    }
+
+    #[test]
+    fn definition_checks_type_then_validator() {
+        let schema = currency_schema();
+        let definition = schema.definition("catalog.default_currency").unwrap();
+
+        assert!(definition.validate(&json!("EUR")).is_ok());
+        let wrong_type = definition.validate(&json!(978)).unwrap_err();
+        assert!(wrong_type.to_string().contains("expected string"));
+        let rejected = definition.validate(&json!("EURO")).unwrap_err();
+        assert!(rejected.to_string().contains("3-letter"));
+        assert!(matches!(
+            schema.definition("catalog.unknown"),
+            Err(TenantError::UnknownSetting(_))
+        ));
+    }
+
+    #[test]
+    fn write_only_fields_are_redacted_and_kept_on_write() {
+        let mut schema = SettingsSchema::new();
+        schema
+            .register(
+                SettingDefinition::new("notifications.webhook", SettingType::Object, json!({}))
+                    .with_write_only_fields(&["secret"]),
+            )
+            .unwrap();
+        let stored = json!({
+            "notifications": {"webhook": {"url": "https://hooks.example.com", "secret": "key"}},
+            "theme": "dark"
+        });
+
+        let mut read = stored.clone();
+        schema.redact(&mut read);
+        assert_eq!(
+            read,
+            json!({
+                "notifications": {"webhook": {"url": "https://hooks.example.com"}},
+                "theme": "dark"
+            })
+        );
+
+        let definition = schema.definition("notifications.webhook").unwrap();
+        let mut update = json!({"url": "https://hooks.example.org"});
+        definition.keep_write_only_fields(definition.stored_value(&stored), &mut update);
+        assert_eq!(
+            update,
+            json!({"url": "https://hooks.example.org", "secret": "key"})
+        );
+
+        let mut rotated = json!({"url": "https://hooks.example.org", "secret": "new"});
+        definition.keep_write_only_fields(definition.stored_value(&stored), &mut rotated);
+        assert_eq!(rotated["secret"], "new");
+    }
+
+    #[test]
+    fn modules_register_settings_through_runtime_extensions() {
+        let mut extensions = ModuleRuntimeExtensions::default();
+        register_tenant_setting(
+            &mut extensions,
+            SettingDefinition::new("blog.posts_per_page", SettingType::Integer, json!(10)),
+        )
+        .unwrap();
+        register_tenant_setting(
+            &mut extensions,
+            SettingDefinition::new("catalog.page_size", SettingType::Integer, json!(24)),
+        )
+        .unwrap();
+
+        let schema = settings_schema_from_extensions(&extensions).unwrap();
+        let keys: Vec<_> = schema.descriptors().into_iter().map(|d| d.key).collect();
+        assert_eq!(keys, vec!["blog.posts_per_page", "catalog.page_size"]);
+    }
+
+    #[test]
+    fn reads_and_writes_dotted_keys() {
+        let mut settings = json!({"catalog": "legacy", "branding": {"theme": "dark"}});
+
+        write_setting(&mut settings, "catalog.default_currency", json!("EUR"));
+        write_setting(&mut settings, "branding.logo", json!("logo.svg"));
+
+        assert_eq!(
+            read_setting(&settings, "catalog.default_currency"),
+            Some(&json!("EUR"))
+        );
+        assert_eq!(settings["branding"]["theme"], json!("dark"));
+        assert_eq!(
+            read_setting(&settings, "branding.logo"),
+            Some(&json!("logo.svg"))
+        );
+        assert_eq!(read_setting(&settings, "branding.missing"), None);
+    }
 }
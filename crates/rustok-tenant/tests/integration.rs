@@ -4,7 +4,8 @@ use rustok_outbox::entity as outbox_entity;
 use rustok_outbox::{OutboxTransport, SysEvents, TransactionalEventBus};
 use rustok_tenant::{
     entities::{tenant, tenant_module},
-    CreateTenantInput, TenantError, TenantService, ToggleModuleInput, UpdateTenantInput,
+    CreateTenantInput, SettingDefinition, SettingType, SettingsSchema, TenantError, TenantService,
+    ToggleModuleInput, UpdateTenantInput,
 };
 use sea_orm::{
     sea_query::TableCreateStatement, ConnectionTrait, Database, DatabaseConnection, DbBackend,
@@ -105,12 +106,12 @@ async fn tenant_crud_flow() {
         serde_json::json!(true)
     );
 
-    let (items, total) = service
+    let tenants = service
         .list_tenants(1, 10)
         .await
         .expect("tenant list should load");
-    assert_eq!(total, 1);
-    assert_eq!(items.len(), 1);
+    assert_eq!(tenants.total, Some(1));
+    assert_eq!(tenants.items.len(), 1);
 }
 
 #[tokio::test]
@@ -143,6 +144,147 @@ async fn reject_invalid_tenant_settings_schema() {
     assert!(matches!(err, TenantError::InvalidSettingsSchema(_)));
 }
 
+#[tokio::test]
+async fn typed_settings_validate_and_fall_back_to_defaults() {
+    let db = setup_db().await;
+    let mut schema = SettingsSchema::new();
+    schema
+        .register(SettingDefinition::new(
+            "catalog.page_size",
+            SettingType::Integer,
+            serde_json::json!(24),
+        ))
+        .expect("setting should register");
+    schema
+        .register(
+            SettingDefinition::new(
+                "checkout.guest_enabled",
+                SettingType::Boolean,
+                serde_json::json!(true),
+            )
+            .with_description("Allow checkout without an account"),
+        )
+        .expect("setting should register");
+    let service = TenantService::new(db).with_settings_schema(Arc::new(schema));
+
+    let tenant = service
+        .create_tenant(CreateTenantInput {
+            name: "Typed Settings".to_string(),
+            slug: "typed-settings".to_string(),
+            domain: None,
+        })
+        .await
+        .expect("tenant should be created");
+
+    let default = service
+        .get_setting(tenant.id, "catalog.page_size")
+        .await
+        .expect("declared setting should resolve");
+    assert_eq!(default, serde_json::json!(24));
+
+    let updated = service
+        .set_setting(tenant.id, "catalog.page_size", serde_json::json!(48))
+        .await
+        .expect("valid value should be stored");
+    assert_eq!(
+        updated.settings["catalog"]["page_size"],
+        serde_json::json!(48)
+    );
+    assert_eq!(
+        service
+            .get_setting(tenant.id, "catalog.page_size")
+            .await
+            .expect("stored setting should resolve"),
+        serde_json::json!(48)
+    );
+
+    let wrong_type = service
+        .set_setting(
+            tenant.id,
+            "checkout.guest_enabled",
+            serde_json::json!("yes"),
+        )
+        .await
+        .expect_err("string must be rejected for a boolean setting");
+    assert!(matches!(
+        wrong_type,
+        TenantError::InvalidSetting { ref key, .. } if key == "checkout.guest_enabled"
+    ));
+
+    let unknown = service
+        .set_setting(tenant.id, "catalog.pagesize", serde_json::json!(48))
+        .await
+        .expect_err("undeclared key must be rejected");
+    assert!(matches!(unknown, TenantError::UnknownSetting(_)));
+}
+
+#[tokio::test]
+async fn write_only_setting_fields_are_never_read_back() {
+    let db = setup_db().await;
+    let mut schema = SettingsSchema::new();
+    schema
+        .register(
+            SettingDefinition::new(
+                "notifications.webhook",
+                SettingType::Object,
+                serde_json::json!({}),
+            )
+            .with_write_only_fields(&["secret"])
+            .with_validator(|value| {
+                if value.get("url").is_some() && value.get("secret").is_none() {
+                    return Err("secret is required".to_string());
+                }
+                Ok(())
+            }),
+        )
+        .expect("setting should register");
+    let service = TenantService::new(db.clone()).with_settings_schema(Arc::new(schema));
+    let tenant = service
+        .create_tenant(CreateTenantInput {
+            name: "Webhooks".to_string(),
+            slug: "webhooks".to_string(),
+            domain: None,
+        })
+        .await
+        .expect("tenant should be created");
+
+    let stored = service
+        .set_setting(
+            tenant.id,
+            "notifications.webhook",
+            serde_json::json!({"url": "https://hooks.example.com", "secret": "key"}),
+        )
+        .await
+        .expect("webhook should be stored");
+    assert_eq!(
+        stored.settings["notifications"]["webhook"],
+        serde_json::json!({"url": "https://hooks.example.com"})
+    );
+
+    // Resubmitting the redacted value keeps the stored secret.
+    service
+        .set_setting(
+            tenant.id,
+            "notifications.webhook",
+            serde_json::json!({"url": "https://hooks.example.org"}),
+        )
+        .await
+        .expect("secret should be kept from the stored value");
+    assert_eq!(
+        service
+            .get_setting(tenant.id, "notifications.webhook")
+            .await
+            .unwrap(),
+        serde_json::json!({"url": "https://hooks.example.org"})
+    );
+    let model = tenant::Entity::find_by_id(tenant.id)
+        .one(&db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(model.settings["notifications"]["webhook"]["secret"], "key");
+}
+
 #[tokio::test]
 async fn module_toggle_flow_legacy() {
     let db = setup_db().await;
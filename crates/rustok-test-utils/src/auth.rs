@@ -0,0 +1,37 @@
+//! Auth testing utilities
+//!
+//! Provides a cheap password hasher so auth tests don't pay the Argon2 cost.
+
+use rustok_auth::{AuthError, PasswordHasher};
+
+const NOOP_HASH_PREFIX: &str = "noop$";
+
+/// Password hasher that stores the password verbatim behind a marker prefix.
+///
+/// Only for tests: hashes are not salted or stretched in any way.
+///
+/// # Example
+///
+/// ```rust
+/// use rustok_auth::PasswordHasher;
+/// use rustok_test_utils::auth::NoopPasswordHasher;
+///
+/// let hash = NoopPasswordHasher.hash("secret").unwrap();
+/// assert!(NoopPasswordHasher.verify("secret", &hash).unwrap());
+/// assert!(!NoopPasswordHasher.verify("other", &hash).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopPasswordHasher;
+
+impl PasswordHasher for NoopPasswordHasher {
+    fn hash(&self, password: &str) -> rustok_auth::error::Result<String> {
+        Ok(format!("{NOOP_HASH_PREFIX}{password}"))
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> rustok_auth::error::Result<bool> {
+        password_hash
+            .strip_prefix(NOOP_HASH_PREFIX)
+            .map(|stored| stored == password)
+            .ok_or(AuthError::InvalidCredentials)
+    }
+}
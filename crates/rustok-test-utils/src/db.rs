@@ -2,8 +2,13 @@
 //!
 //! Provides functions for setting up test databases with migrations.
 
-use sea_orm::{ConnectOptions, Database, DatabaseConnection, TransactionTrait};
+use rustok_core::{redact_urls_in, RedactedUrl};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, DbErr, Statement,
+    TransactionTrait,
+};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
@@ -59,10 +64,46 @@ pub async fn setup_test_db() -> DatabaseConnection {
     db
 }
 
+/// Progress reported while preparing a test database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupProgress {
+    /// Migration `index` of `total` (1-based) is about to run.
+    MigrationStarted {
+        name: String,
+        index: usize,
+        total: usize,
+    },
+    /// Migration `name` finished after `elapsed`.
+    MigrationFinished { name: String, elapsed: Duration },
+    /// `count` rows seeded so far under `label`.
+    Seeded { label: String, count: u64 },
+}
+
+/// Default progress sink: one `tracing::info!` per step.
+pub fn log_progress(progress: &SetupProgress) {
+    match progress {
+        SetupProgress::MigrationStarted { name, index, total } => {
+            tracing::info!(migration = %name, index, total, "Running test migration");
+        }
+        SetupProgress::MigrationFinished { name, elapsed } => {
+            tracing::info!(
+                migration = %name,
+                elapsed_ms = elapsed.as_millis() as u64,
+                "Test migration finished"
+            );
+        }
+        SetupProgress::Seeded { label, count } => {
+            tracing::info!(seed = %label, count, "Seeding test data");
+        }
+    }
+}
+
 /// Sets up a test database with specific migrations.
 ///
 /// This is useful when you want to test a specific module without
-/// running all migrations.
+/// running all migrations. Each migration is logged through
+/// [`log_progress`]; use [`setup_test_db_with_migrations_and_progress`] to
+/// observe progress differently.
 ///
 /// # Type Parameters
 ///
@@ -83,6 +124,16 @@ pub async fn setup_test_db() -> DatabaseConnection {
 pub async fn setup_test_db_with_migrations<M>() -> DatabaseConnection
 where
     M: sea_orm_migration::MigratorTrait,
+{
+    setup_test_db_with_migrations_and_progress::<M, _>(log_progress).await
+}
+
+/// Like [`setup_test_db_with_migrations`], reporting each migration to
+/// `on_progress` before and after it runs.
+pub async fn setup_test_db_with_migrations_and_progress<M, F>(on_progress: F) -> DatabaseConnection
+where
+    M: sea_orm_migration::MigratorTrait,
+    F: FnMut(&SetupProgress),
 {
     let lock = DB_LOCK
         .get_or_init(|| async { Arc::new(Mutex::new(())) })
@@ -104,24 +155,121 @@ where
         .await
         .expect("Failed to connect to test database");
 
-    let pending = M::get_pending_migrations(&db)
+    run_migrations_with_progress::<M, _>(&db, on_progress)
         .await
-        .expect("Failed to load pending migrations");
-    let pending_names: Vec<String> = pending
+        .unwrap_or_else(|error| panic!("Failed to run pending migrations: {error:?}"));
+
+    db
+}
+
+/// Applies pending migrations of `M` one at a time, reporting each to
+/// `on_progress`. A failure names the migration that failed.
+pub async fn run_migrations_with_progress<M, F>(
+    db: &DatabaseConnection,
+    mut on_progress: F,
+) -> Result<(), DbErr>
+where
+    M: sea_orm_migration::MigratorTrait,
+    F: FnMut(&SetupProgress),
+{
+    let pending: Vec<String> = M::get_pending_migrations(db)
+        .await?
         .into_iter()
         .map(|migration| migration.name().to_string())
         .collect();
+    let total = pending.len();
 
-    if !pending_names.is_empty() {
-        M::up(&db, None).await.unwrap_or_else(|error| {
-            panic!(
-                "Failed to run pending migrations {:?}: {error:?}",
-                pending_names
-            )
+    for (index, name) in pending.into_iter().enumerate() {
+        on_progress(&SetupProgress::MigrationStarted {
+            name: name.clone(),
+            index: index + 1,
+            total,
+        });
+        let started_at = Instant::now();
+        M::up(db, Some(1))
+            .await
+            .map_err(|error| DbErr::Migration(format!("{name}: {error}")))?;
+        on_progress(&SetupProgress::MigrationFinished {
+            name,
+            elapsed: started_at.elapsed(),
         });
     }
 
-    db
+    Ok(())
+}
+
+/// Running row count for bulk seed loops, reported every `every` rows and
+/// once more on [`SeedProgress::finish`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut progress = SeedProgress::new("products", 500);
+/// for product in products {
+///     insert(&db, product).await;
+///     progress.add(1);
+/// }
+/// progress.finish();
+/// ```
+pub struct SeedProgress<F = fn(&SetupProgress)>
+where
+    F: FnMut(&SetupProgress),
+{
+    label: String,
+    every: u64,
+    count: u64,
+    reported: u64,
+    on_progress: F,
+}
+
+impl SeedProgress {
+    /// Reports through [`log_progress`].
+    pub fn new(label: impl Into<String>, every: u64) -> Self {
+        Self::with_callback(label, every, log_progress)
+    }
+}
+
+impl<F> SeedProgress<F>
+where
+    F: FnMut(&SetupProgress),
+{
+    pub fn with_callback(label: impl Into<String>, every: u64, on_progress: F) -> Self {
+        Self {
+            label: label.into(),
+            every: every.max(1),
+            count: 0,
+            reported: 0,
+            on_progress,
+        }
+    }
+
+    /// Records `rows` more seeded rows.
+    pub fn add(&mut self, rows: u64) {
+        self.count += rows;
+        if self.count / self.every > self.reported / self.every {
+            self.report();
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Reports the final count unless it was just reported, and returns it.
+    pub fn finish(mut self) -> u64 {
+        if self.reported != self.count {
+            self.report();
+        }
+        self.count
+    }
+
+    fn report(&mut self) {
+        self.reported = self.count;
+        (self.on_progress)(&SetupProgress::Seeded {
+            label: self.label.clone(),
+            count: self.count,
+        });
+    }
 }
 
 /// Creates a test transaction that will be rolled back after the test.
@@ -155,16 +303,440 @@ where
     result
 }
 
+/// Drops every table in the connection's database (on Postgres, its current
+/// schema), as reported by the database itself rather than a fixed list.
+///
+/// Foreign keys are disabled for the duration on SQLite; on Postgres each
+/// table is dropped with `CASCADE`. With `keep_migration_table` the
+/// `seaql_migrations` tracking table is left in place.
+pub async fn clean_test_db(
+    db: &DatabaseConnection,
+    keep_migration_table: bool,
+) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let query = match backend {
+        DbBackend::Postgres => {
+            "SELECT table_name AS name FROM information_schema.tables \
+             WHERE table_schema = current_schema() AND table_type = 'BASE TABLE'"
+        }
+        DbBackend::Sqlite => {
+            "SELECT name FROM sqlite_master \
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%'"
+        }
+        DbBackend::MySql => {
+            "SELECT table_name AS name FROM information_schema.tables \
+             WHERE table_schema = DATABASE() AND table_type = 'BASE TABLE'"
+        }
+    };
+    let mut tables = Vec::new();
+    for row in db.query_all(Statement::from_string(backend, query)).await? {
+        let name: String = row.try_get("", "name")?;
+        if !(keep_migration_table && name == MIGRATION_TABLE) {
+            tables.push(name);
+        }
+    }
+
+    let (disable_fks, enable_fks) = match backend {
+        DbBackend::Sqlite => (
+            Some("PRAGMA foreign_keys = OFF"),
+            Some("PRAGMA foreign_keys = ON"),
+        ),
+        DbBackend::MySql => (
+            Some("SET FOREIGN_KEY_CHECKS = 0"),
+            Some("SET FOREIGN_KEY_CHECKS = 1"),
+        ),
+        DbBackend::Postgres => (None, None),
+    };
+    if let Some(statement) = disable_fks {
+        db.execute_unprepared(statement).await?;
+    }
+    let dropped = drop_tables(db, backend, &tables).await;
+    if let Some(statement) = enable_fks {
+        db.execute_unprepared(statement).await?;
+    }
+    dropped
+}
+
+const MIGRATION_TABLE: &str = "seaql_migrations";
+
+async fn drop_tables(
+    db: &DatabaseConnection,
+    backend: DbBackend,
+    tables: &[String],
+) -> Result<(), DbErr> {
+    for table in tables {
+        let statement = match backend {
+            DbBackend::Postgres => format!(r#"DROP TABLE IF EXISTS "{table}" CASCADE"#),
+            DbBackend::Sqlite => format!(r#"DROP TABLE IF EXISTS "{table}""#),
+            DbBackend::MySql => format!("DROP TABLE IF EXISTS `{table}`"),
+        };
+        db.execute_unprepared(&statement).await?;
+    }
+    Ok(())
+}
+
+/// Environment variable with the Postgres URL used by [`TestDb`].
+pub const TEST_DATABASE_URL_ENV: &str = "RUSTOK_TEST_DATABASE_URL";
+
+/// A migrated Postgres schema owned by one test.
+///
+/// Every `TestDb` creates its own `test_<uuid>` schema, pins `search_path`
+/// to it through the connection URL (so every pooled connection gets it) and
+/// runs the migrator there, so tests sharing one database can run in
+/// parallel without seeing each other's rows. The schema is dropped
+/// (`CASCADE`) when the value is dropped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustok_test_utils::db::TestDb;
+///
+/// #[tokio::test]
+/// async fn test_with_postgres() {
+///     let Some(test_db) = TestDb::new::<Migrator>().await else {
+///         return; // RUSTOK_TEST_DATABASE_URL is not set
+///     };
+///     let db = test_db.conn();
+///     // ...
+/// }
+/// ```
+pub struct TestDb {
+    db: DatabaseConnection,
+    url: RedactedUrl,
+    schema: String,
+}
+
+impl TestDb {
+    /// Connects to [`TEST_DATABASE_URL_ENV`], or returns `None` when it is
+    /// not set so Postgres-only tests can skip themselves.
+    pub async fn new<M>() -> Option<Self>
+    where
+        M: sea_orm_migration::MigratorTrait,
+    {
+        let url = std::env::var(TEST_DATABASE_URL_ENV).ok()?;
+        Some(Self::connect::<M>(&url).await.unwrap_or_else(|error| {
+            panic!(
+                "Failed to set up test schema: {}",
+                redact_urls_in(&format!("{error:?}"))
+            )
+        }))
+    }
+
+    /// Creates a fresh schema in the Postgres database at `url` and applies
+    /// the migrations of `M` into it.
+    pub async fn connect<M>(url: &str) -> Result<Self, DbErr>
+    where
+        M: sea_orm_migration::MigratorTrait,
+    {
+        let schema = format!("test_{}", Uuid::new_v4().simple());
+        let mut opts = ConnectOptions::new(with_search_path(url, &schema));
+        opts.max_connections(TEST_DB_MAX_CONNECTIONS)
+            .sqlx_logging(false);
+        let db = Database::connect(opts).await?;
+
+        db.execute_unprepared(&format!(r#"CREATE SCHEMA "{schema}""#))
+            .await?;
+        let test_db = Self {
+            db,
+            url: RedactedUrl::new(url),
+            schema,
+        };
+        run_migrations_with_progress::<M, _>(&test_db.db, log_progress).await?;
+
+        Ok(test_db)
+    }
+
+    pub fn conn(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+}
+
+/// Pool size of a [`TestDb`]; above one so tests can hold concurrent
+/// transactions.
+const TEST_DB_MAX_CONNECTIONS: u32 = 5;
+
+/// `url` with `search_path` set for every session opened from it.
+fn with_search_path(url: &str, schema: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{url}{separator}options=-csearch_path%3D{schema}")
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        // Drop may run inside the test's runtime (which cannot be blocked
+        // on), outside any runtime, or while unwinding, so the cleanup always
+        // gets its own thread and runtime and is joined before returning.
+        let url = self.url.expose().to_string();
+        let statement = format!(r#"DROP SCHEMA IF EXISTS "{}" CASCADE"#, self.schema);
+        let cleanup = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?;
+            runtime.block_on(async {
+                let db = Database::connect(url).await?;
+                db.execute_unprepared(&statement).await?;
+                db.close().await
+            })?;
+            Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+        });
+
+        match cleanup.join() {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => tracing::warn!(
+                schema = %self.schema,
+                error = %redact_urls_in(&error.to_string()),
+                "Failed to drop test schema"
+            ),
+            Err(_) => tracing::warn!(schema = %self.schema, "Test schema cleanup panicked"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use sea_orm_migration::prelude::*;
+
     #[tokio::test]
-    #[ignore = "requires sqlite driver feature in SeaORM"]
     async fn test_setup_test_db() {
         let db = setup_test_db().await;
 
         // Just verify we can connect.
         assert!(db.ping().await.is_ok());
+        assert_eq!(db.get_database_backend(), DbBackend::Sqlite);
+    }
+
+    struct CreateTenants;
+
+    impl MigrationName for CreateTenants {
+        fn name(&self) -> &str {
+            "m0001_create_tenants"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for CreateTenants {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("tenants"))
+                        .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+                        .col(ColumnDef::new(Alias::new("slug")).string().not_null())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new("tenants")).to_owned())
+                .await
+        }
+    }
+
+    struct TenantsMigrator;
+
+    impl MigratorTrait for TenantsMigrator {
+        fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+            vec![Box::new(CreateTenants)]
+        }
+    }
+
+    #[tokio::test]
+    async fn setup_with_migrations_creates_tables_on_in_memory_sqlite() {
+        let mut started = Vec::new();
+        let db = setup_test_db_with_migrations_and_progress::<TenantsMigrator, _>(|event| {
+            if let SetupProgress::MigrationStarted { name, .. } = event {
+                started.push(name.clone());
+            }
+        })
+        .await;
+
+        let tables = db
+            .query_all(Statement::from_string(
+                DbBackend::Sqlite,
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'tenants'",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(started, vec!["m0001_create_tenants".to_string()]);
+    }
+
+    async fn sqlite_tables(db: &DatabaseConnection) -> Vec<String> {
+        db.query_all(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        ))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| row.try_get("", "name").unwrap())
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn clean_test_db_drops_tables_linked_by_foreign_keys() {
+        let db = setup_test_db_with_migrations::<TenantsMigrator>().await;
+        for statement in [
+            "PRAGMA foreign_keys = ON",
+            "CREATE TABLE users (id TEXT PRIMARY KEY, tenant_id TEXT NOT NULL REFERENCES tenants (id))",
+            "INSERT INTO tenants (id, slug) VALUES ('t1', 'acme')",
+            "INSERT INTO users (id, tenant_id) VALUES ('u1', 't1')",
+        ] {
+            db.execute_unprepared(statement).await.unwrap();
+        }
+
+        clean_test_db(&db, false).await.unwrap();
+
+        assert!(sqlite_tables(&db).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn clean_test_db_can_keep_migration_table() {
+        let db = setup_test_db_with_migrations::<TenantsMigrator>().await;
+
+        clean_test_db(&db, true).await.unwrap();
+
+        assert_eq!(
+            sqlite_tables(&db).await,
+            vec!["seaql_migrations".to_string()]
+        );
+    }
+
+    async fn tenant_count(db: &DatabaseConnection) -> i64 {
+        db.query_one(Statement::from_string(
+            DbBackend::Postgres,
+            "SELECT COUNT(*) AS count FROM tenants",
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get("", "count")
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn parallel_test_dbs_do_not_share_rows() {
+        let (first, second) = tokio::join!(
+            TestDb::new::<TenantsMigrator>(),
+            TestDb::new::<TenantsMigrator>()
+        );
+        let (Some(first), Some(second)) = (first, second) else {
+            return;
+        };
+        assert_ne!(first.schema(), second.schema());
+
+        first
+            .conn()
+            .execute_unprepared(
+                "INSERT INTO tenants (id, slug) VALUES ('6f1c1f0e-6c55-4c4a-9e0b-0d6f6f1a2b3c', 'acme')",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tenant_count(first.conn()).await, 1);
+        assert_eq!(tenant_count(second.conn()).await, 0);
+    }
+
+    #[test]
+    fn test_db_drop_completes_outside_a_tokio_runtime() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let Some(test_db) = runtime.block_on(TestDb::new::<TenantsMigrator>()) else {
+            return;
+        };
+        let url = test_db.url.expose().to_string();
+        let schema = test_db.schema().to_string();
+
+        // A plain thread has no runtime context, like a sync test or unwinding.
+        assert!(std::thread::spawn(move || drop(test_db)).join().is_ok());
+
+        let remaining = runtime.block_on(async {
+            let db = Database::connect(url).await.unwrap();
+            db.query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT schema_name FROM information_schema.schemata WHERE schema_name = $1",
+                [schema.into()],
+            ))
+            .await
+            .unwrap()
+            .len()
+        });
+        assert_eq!(remaining, 0, "schema should be dropped before drop returns");
+    }
+
+    #[test]
+    fn search_path_is_pinned_through_the_url() {
+        assert_eq!(
+            with_search_path("postgres://localhost/rustok", "test_1"),
+            "postgres://localhost/rustok?options=-csearch_path%3Dtest_1"
+        );
+        assert_eq!(
+            with_search_path("postgres://localhost/rustok?sslmode=disable", "test_1"),
+            "postgres://localhost/rustok?sslmode=disable&options=-csearch_path%3Dtest_1"
+        );
+    }
+
+    #[tokio::test]
+    async fn every_pooled_connection_uses_the_test_schema() {
+        let Some(test_db) = TestDb::new::<TenantsMigrator>().await else {
+            return;
+        };
+
+        // Hold several transactions at once so the pool has to open more
+        // than one connection.
+        let mut transactions = Vec::new();
+        for _ in 0..3 {
+            transactions.push(test_db.conn().begin().await.unwrap());
+        }
+        for txn in &transactions {
+            let row = txn
+                .query_one(Statement::from_string(
+                    DbBackend::Postgres,
+                    "SELECT current_schema() AS schema",
+                ))
+                .await
+                .unwrap()
+                .unwrap();
+            let schema: String = row.try_get("", "schema").unwrap();
+            assert_eq!(schema, test_db.schema());
+        }
+    }
+
+    #[test]
+    fn seed_progress_reports_every_n_rows_and_on_finish() {
+        let mut seen = Vec::new();
+        let mut progress = SeedProgress::with_callback("products", 10, |event: &SetupProgress| {
+            if let SetupProgress::Seeded { count, .. } = event {
+                seen.push(*count);
+            }
+        });
+
+        for _ in 0..25 {
+            progress.add(1);
+        }
+        assert_eq!(progress.finish(), 25);
+        assert_eq!(seen, vec![10, 20, 25]);
+    }
+
+    #[test]
+    fn seed_progress_skips_duplicate_final_report() {
+        let mut seen = Vec::new();
+        let mut progress = SeedProgress::with_callback("users", 5, |event: &SetupProgress| {
+            if let SetupProgress::Seeded { count, .. } = event {
+                seen.push(*count);
+            }
+        });
+
+        progress.add(3);
+        progress.add(7);
+        progress.finish();
+        assert_eq!(seen, vec![10]);
     }
 }
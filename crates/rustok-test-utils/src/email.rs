@@ -0,0 +1,206 @@
+//! Email testing utilities
+//!
+//! Provides a recording sender that captures transactional and password-reset
+//! emails instead of delivering them.
+
+use async_trait::async_trait;
+use rustok_email::{
+    error::Result, PasswordResetEmail, PasswordResetEmailSender, TransactionalEmailSender,
+};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+/// Template id recorded for [`PasswordResetEmailSender::send_password_reset`].
+pub const PASSWORD_RESET_TEMPLATE_ID: &str = "auth/password_reset";
+
+/// An email captured by [`MockEmailSender`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentEmail {
+    pub to: String,
+    pub template_id: String,
+    /// `None` for password-reset sends, which carry no locale.
+    pub locale: Option<String>,
+    /// Template variables as passed to the sender.
+    pub vars: Value,
+}
+
+/// Email sender that records every send for later assertions.
+///
+/// Implements both [`TransactionalEmailSender`] and
+/// [`PasswordResetEmailSender`]; password resets are recorded under
+/// [`PASSWORD_RESET_TEMPLATE_ID`] with a `reset_url` variable. Clones share
+/// the same buffer.
+///
+/// # Example
+///
+/// ```rust
+/// use rustok_email::TransactionalEmailSender;
+/// use rustok_test_utils::MockEmailSender;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let sender = MockEmailSender::new();
+/// sender
+///     .send_transactional(
+///         "commerce/order_confirmed",
+///         "en",
+///         "buyer@example.com",
+///         &serde_json::json!({ "order_number": "1001" }),
+///     )
+///     .await
+///     .unwrap();
+///
+/// assert!(sender.was_template_sent("buyer@example.com", "commerce/order_confirmed"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockEmailSender {
+    sent: Arc<Mutex<Vec<SentEmail>>>,
+}
+
+impl MockEmailSender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of every captured email, in send order.
+    pub fn sent(&self) -> Vec<SentEmail> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    pub fn emails_to(&self, to: &str) -> Vec<SentEmail> {
+        self.filtered(|email| email.to == to)
+    }
+
+    pub fn emails_using_template(&self, template_id: &str) -> Vec<SentEmail> {
+        self.filtered(|email| email.template_id == template_id)
+    }
+
+    pub fn was_template_sent(&self, to: &str, template_id: &str) -> bool {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|email| email.to == to && email.template_id == template_id)
+    }
+
+    pub fn email_count(&self) -> usize {
+        self.sent.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.email_count() == 0
+    }
+
+    pub fn clear(&self) {
+        self.sent.lock().unwrap().clear();
+    }
+
+    fn filtered(&self, predicate: impl Fn(&SentEmail) -> bool) -> Vec<SentEmail> {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|email| predicate(email))
+            .cloned()
+            .collect()
+    }
+
+    fn record(&self, email: SentEmail) {
+        self.sent.lock().unwrap().push(email);
+    }
+}
+
+#[async_trait]
+impl TransactionalEmailSender for MockEmailSender {
+    async fn send_transactional(
+        &self,
+        template_id: &str,
+        locale: &str,
+        to: &str,
+        vars: &Value,
+    ) -> Result<()> {
+        self.record(SentEmail {
+            to: to.to_string(),
+            template_id: template_id.to_string(),
+            locale: Some(locale.to_string()),
+            vars: vars.clone(),
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PasswordResetEmailSender for MockEmailSender {
+    async fn send_password_reset(&self, email: PasswordResetEmail) -> Result<()> {
+        self.record(SentEmail {
+            to: email.to,
+            template_id: PASSWORD_RESET_TEMPLATE_ID.to_string(),
+            locale: None,
+            vars: serde_json::json!({ "reset_url": email.reset_url }),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn captures_template_id_locale_and_vars() {
+        let sender = MockEmailSender::new();
+        let vars = json!({ "order_number": "1001", "total": "42.00" });
+
+        sender
+            .send_transactional("commerce/order_confirmed", "de", "buyer@example.com", &vars)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sender.sent(),
+            vec![SentEmail {
+                to: "buyer@example.com".to_string(),
+                template_id: "commerce/order_confirmed".to_string(),
+                locale: Some("de".to_string()),
+                vars,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn queries_by_template_and_recipient() {
+        let sender = MockEmailSender::new();
+        for to in ["a@example.com", "b@example.com"] {
+            sender
+                .send_transactional("commerce/order_confirmed", "en", to, &json!({}))
+                .await
+                .unwrap();
+        }
+        sender
+            .send_password_reset(PasswordResetEmail {
+                to: "a@example.com".to_string(),
+                reset_url: "https://example.com/reset?token=t".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sender
+                .emails_using_template("commerce/order_confirmed")
+                .len(),
+            2
+        );
+        assert_eq!(sender.emails_to("a@example.com").len(), 2);
+        assert!(sender.was_template_sent("a@example.com", PASSWORD_RESET_TEMPLATE_ID));
+        assert!(!sender.was_template_sent("b@example.com", PASSWORD_RESET_TEMPLATE_ID));
+        assert_eq!(
+            sender.emails_using_template(PASSWORD_RESET_TEMPLATE_ID)[0].vars["reset_url"],
+            "https://example.com/reset?token=t"
+        );
+
+        sender.clear();
+        assert!(sender.is_empty());
+    }
+}
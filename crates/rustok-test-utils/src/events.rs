@@ -7,6 +7,8 @@ use rustok_events::{DomainEvent, EventEnvelope};
 use rustok_outbox::TransactionalEventBus;
 use std::any::Any;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
 use uuid::Uuid;
 
 /// Mock event transport that records events for testing.
@@ -91,6 +93,119 @@ impl Default for MockEventTransport {
     }
 }
 
+/// In-memory `EventTransport` that keeps every published envelope.
+///
+/// Clones share the same buffer, so a test can hand one clone to the code
+/// under test and assert on another. Unlike [`MockEventTransport`] it keeps
+/// whole envelopes (ids, actor, correlation) and can wait for asynchronous
+/// forwarding via [`wait_for`](Self::wait_for).
+///
+/// # Example
+///
+/// ```rust
+/// use rustok_core::EventTransport;
+/// use rustok_events::{DomainEvent, EventEnvelope};
+/// use rustok_test_utils::InMemoryTransport;
+/// use std::time::Duration;
+/// use uuid::Uuid;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = InMemoryTransport::new();
+/// let envelope = EventEnvelope::new(
+///     Uuid::new_v4(),
+///     None,
+///     DomainEvent::TagCreated { tag_id: Uuid::new_v4() },
+/// );
+/// transport.publish(envelope.clone()).await.unwrap();
+///
+/// let received = transport
+///     .wait_for(|e| e.id == envelope.id, Duration::from_secs(1))
+///     .await;
+/// assert!(received.is_some());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct InMemoryTransport {
+    published: Arc<Mutex<Vec<EventEnvelope>>>,
+    notify: Arc<Notify>,
+    reliability: ReliabilityLevel,
+}
+
+impl InMemoryTransport {
+    /// Creates a transport that reports [`ReliabilityLevel::Outbox`], the
+    /// durable level, so code paths gated on durability are exercised.
+    pub fn new() -> Self {
+        Self::with_reliability(ReliabilityLevel::Outbox)
+    }
+
+    pub fn with_reliability(reliability: ReliabilityLevel) -> Self {
+        Self {
+            published: Arc::new(Mutex::new(Vec::new())),
+            notify: Arc::new(Notify::new()),
+            reliability,
+        }
+    }
+
+    /// Returns a copy of every envelope published so far, in order.
+    pub fn published(&self) -> Vec<EventEnvelope> {
+        self.published.lock().unwrap().clone()
+    }
+
+    /// Waits until an envelope matching `predicate` has been published.
+    ///
+    /// Returns the first match, or `None` if `timeout` elapses first.
+    pub async fn wait_for<F>(&self, predicate: F, timeout: Duration) -> Option<EventEnvelope>
+    where
+        F: Fn(&EventEnvelope) -> bool,
+    {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let notified = self.notify.notified();
+            if let Some(found) = self
+                .published
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|envelope| predicate(envelope))
+                .cloned()
+            {
+                return Some(found);
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return None;
+            }
+        }
+    }
+
+    pub fn clear(&self) {
+        self.published.lock().unwrap().clear();
+    }
+}
+
+impl Default for InMemoryTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventTransport for InMemoryTransport {
+    async fn publish(&self, envelope: EventEnvelope) -> rustok_core::Result<()> {
+        self.published.lock().unwrap().push(envelope);
+        self.notify.notify_waiters();
+        Ok(())
+    }
+
+    fn reliability_level(&self) -> ReliabilityLevel {
+        self.reliability
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Creates a new TransactionalEventBus for testing.
 ///
 /// This is a convenience function for creating a TransactionalEventBus
@@ -336,6 +451,40 @@ mod tests {
         assert_eq!(bus.events_of_type("NodeUpdated").len(), 1);
     }
 
+    #[tokio::test]
+    async fn in_memory_transport_records_and_waits_for_envelopes() {
+        let transport = InMemoryTransport::new();
+        let tenant_id = Uuid::new_v4();
+        assert_eq!(transport.reliability_level(), ReliabilityLevel::Outbox);
+
+        let publisher = transport.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            publisher
+                .publish(EventEnvelope::new(
+                    tenant_id,
+                    None,
+                    DomainEvent::TagCreated {
+                        tag_id: Uuid::new_v4(),
+                    },
+                ))
+                .await
+        });
+
+        let received = transport
+            .wait_for(|e| e.tenant_id == tenant_id, Duration::from_secs(1))
+            .await
+            .expect("envelope should be published");
+        handle.await.unwrap().unwrap();
+
+        assert_eq!(received.event_type, "tag.created");
+        assert_eq!(transport.published().len(), 1);
+        assert!(transport
+            .wait_for(|e| e.tenant_id != tenant_id, Duration::from_millis(10))
+            .await
+            .is_none());
+    }
+
     #[test]
     fn test_clear_events() {
         let bus = MockEventBus::new();
@@ -1,12 +1,84 @@
 //! Test fixtures for common data types
 //!
 //! Provides builder patterns for creating test data with sensible defaults.
+//!
+//! Generated unique fields (emails, slugs, SKUs) are prefixed with the run
+//! namespace, see [`set_run_namespace`], so several test runs can share one
+//! database without unique-constraint collisions.
+
+use std::sync::{OnceLock, RwLock};
 
 use chrono::{DateTime, Utc};
 use rustok_core::{SecurityContext, UserRole};
 use serde_json::Value;
 use uuid::Uuid;
 
+/// Environment variable holding the default run namespace, e.g. a CI job id
+/// or the developer's user name.
+pub const RUN_NAMESPACE_ENV: &str = "RUSTOK_TEST_RUN_NAMESPACE";
+
+static RUN_NAMESPACE: OnceLock<RwLock<String>> = OnceLock::new();
+
+fn run_namespace_lock() -> &'static RwLock<String> {
+    RUN_NAMESPACE.get_or_init(|| {
+        RwLock::new(
+            std::env::var(RUN_NAMESPACE_ENV)
+                .map(|value| normalize_namespace(&value))
+                .unwrap_or_default(),
+        )
+    })
+}
+
+/// Sets the process-wide prefix for generated unique fields.
+///
+/// Defaults to `RUSTOK_TEST_RUN_NAMESPACE` (empty when unset). The value is
+/// lowercased and reduced to `[a-z0-9-]` so it is valid in slugs and emails.
+///
+/// # Example
+///
+/// ```rust
+/// use rustok_test_utils::fixtures::{run_namespace, set_run_namespace};
+///
+/// set_run_namespace("CI Job 42");
+/// assert_eq!(run_namespace(), "ci-job-42");
+/// ```
+pub fn set_run_namespace(namespace: &str) {
+    *run_namespace_lock()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = normalize_namespace(namespace);
+}
+
+/// Returns the current run namespace, empty when none is configured.
+pub fn run_namespace() -> String {
+    run_namespace_lock()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Prefixes `value` with the run namespace, if any.
+pub fn namespaced(value: impl Into<String>) -> String {
+    apply_namespace(&run_namespace(), value.into())
+}
+
+fn apply_namespace(namespace: &str, value: String) -> String {
+    if namespace.is_empty() {
+        value
+    } else {
+        format!("{namespace}-{value}")
+    }
+}
+
+fn normalize_namespace(namespace: &str) -> String {
+    namespace
+        .trim()
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 /// Fixture builder for creating test users.
 ///
 /// # Example
@@ -34,7 +106,7 @@ impl UserFixture {
     pub fn new() -> Self {
         Self {
             id: Uuid::new_v4(),
-            email: format!("user-{}@test.com", Uuid::new_v4()),
+            email: namespaced(format!("user-{}@test.com", Uuid::new_v4())),
             role: UserRole::Customer,
             status: "active".to_string(),
             email_verified: true,
@@ -47,7 +119,7 @@ impl UserFixture {
     pub fn admin() -> Self {
         Self {
             id: Uuid::new_v4(),
-            email: format!("admin-{}@test.com", Uuid::new_v4()),
+            email: namespaced(format!("admin-{}@test.com", Uuid::new_v4())),
             role: UserRole::Admin,
             status: "active".to_string(),
             email_verified: true,
@@ -60,7 +132,7 @@ impl UserFixture {
     pub fn super_admin() -> Self {
         Self {
             id: Uuid::new_v4(),
-            email: format!("superadmin-{}@test.com", Uuid::new_v4()),
+            email: namespaced(format!("superadmin-{}@test.com", Uuid::new_v4())),
             role: UserRole::SuperAdmin,
             status: "active".to_string(),
             email_verified: true,
@@ -73,7 +145,7 @@ impl UserFixture {
     pub fn customer() -> Self {
         Self {
             id: Uuid::new_v4(),
-            email: format!("customer-{}@test.com", Uuid::new_v4()),
+            email: namespaced(format!("customer-{}@test.com", Uuid::new_v4())),
             role: UserRole::Customer,
             status: "active".to_string(),
             email_verified: true,
@@ -86,7 +158,7 @@ impl UserFixture {
     pub fn manager() -> Self {
         Self {
             id: Uuid::new_v4(),
-            email: format!("manager-{}@test.com", Uuid::new_v4()),
+            email: namespaced(format!("manager-{}@test.com", Uuid::new_v4())),
             role: UserRole::Manager,
             status: "active".to_string(),
             email_verified: true,
@@ -193,7 +265,10 @@ impl TenantFixture {
         Self {
             id,
             name: "Test Tenant".to_string(),
-            slug: format!("tenant-{}", id.to_string().split('-').next().unwrap()),
+            slug: namespaced(format!(
+                "tenant-{}",
+                id.to_string().split('-').next().unwrap()
+            )),
             status: "active".to_string(),
             settings: serde_json::json!({}),
             created_at: Utc::now(),
@@ -526,6 +601,148 @@ pub struct TestNodeTranslation {
     pub body: Option<String>,
 }
 
+/// Builder for the real [`rustok_content::CreateNodeInput`] used by
+/// `NodeService::create`.
+///
+/// `NodeInputBuilder::default().build()` is a valid draft post with one
+/// English translation and a namespaced unique slug. The first call to
+/// [`NodeInputBuilder::translation`] replaces that default translation.
+///
+/// # Example
+///
+/// ```rust
+/// use rustok_core::BodyFormat;
+/// use rustok_test_utils::fixtures::NodeInputBuilder;
+///
+/// let input = NodeInputBuilder::default()
+///     .kind("page")
+///     .translation("en", "About", "about")
+///     .translation("ru", "О нас", "o-nas")
+///     .body("en", "# About", BodyFormat::Markdown)
+///     .build();
+/// assert_eq!(input.translations.len(), 2);
+/// ```
+#[cfg(feature = "content")]
+#[derive(Debug, Clone)]
+pub struct NodeInputBuilder {
+    kind: String,
+    status: Option<rustok_content::entities::node::ContentStatus>,
+    parent_id: Option<Uuid>,
+    author_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    metadata: Value,
+    translations: Vec<rustok_content::NodeTranslationInput>,
+    bodies: Vec<rustok_content::BodyInput>,
+}
+
+#[cfg(feature = "content")]
+impl Default for NodeInputBuilder {
+    fn default() -> Self {
+        Self {
+            kind: "post".to_string(),
+            status: Some(rustok_content::entities::node::ContentStatus::Draft),
+            parent_id: None,
+            author_id: None,
+            category_id: None,
+            metadata: serde_json::json!({}),
+            translations: Vec::new(),
+            bodies: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "content")]
+impl NodeInputBuilder {
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = kind.into();
+        self
+    }
+
+    pub fn status(mut self, status: rustok_content::entities::node::ContentStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn parent(mut self, parent_id: Uuid) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    pub fn author(mut self, author_id: Uuid) -> Self {
+        self.author_id = Some(author_id);
+        self
+    }
+
+    pub fn category(mut self, category_id: Uuid) -> Self {
+        self.category_id = Some(category_id);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Value) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Adds a translation; the slug is used as given, without namespacing.
+    pub fn translation(
+        mut self,
+        locale: impl Into<String>,
+        title: impl Into<String>,
+        slug: impl Into<String>,
+    ) -> Self {
+        self.translations
+            .push(rustok_content::NodeTranslationInput {
+                locale: locale.into(),
+                title: Some(title.into()),
+                slug: Some(slug.into()),
+                excerpt: None,
+            });
+        self
+    }
+
+    /// Adds a body for `locale`.
+    pub fn body(
+        mut self,
+        locale: impl Into<String>,
+        content: impl Into<String>,
+        format: rustok_core::BodyFormat,
+    ) -> Self {
+        self.bodies.push(rustok_content::BodyInput {
+            locale: locale.into(),
+            body: Some(content.into()),
+            format: Some(format),
+        });
+        self
+    }
+
+    pub fn build(self) -> rustok_content::CreateNodeInput {
+        let translations = if self.translations.is_empty() {
+            vec![rustok_content::NodeTranslationInput {
+                locale: "en".to_string(),
+                title: Some("Test Post".to_string()),
+                slug: Some(crate::helpers::unique_slug("test-post")),
+                excerpt: None,
+            }]
+        } else {
+            self.translations
+        };
+
+        rustok_content::CreateNodeInput {
+            kind: self.kind,
+            status: self.status,
+            parent_id: self.parent_id,
+            author_id: self.author_id,
+            category_id: self.category_id,
+            position: Some(0),
+            depth: Some(0),
+            reply_count: Some(0),
+            metadata: self.metadata,
+            translations,
+            bodies: self.bodies,
+        }
+    }
+}
+
 /// Fixture builder for creating test products.
 ///
 /// # Example
@@ -556,10 +773,10 @@ impl ProductFixture {
     pub fn new() -> Self {
         Self {
             id: Uuid::new_v4(),
-            sku: format!(
+            sku: namespaced(format!(
                 "SKU-{}",
                 Uuid::new_v4().to_string().split('-').next().unwrap()
-            ),
+            )),
             name: "Test Product".to_string(),
             description: Some("A test product description".to_string()),
             price: 99.99,
@@ -700,6 +917,60 @@ mod tests {
         assert_eq!(node.translations[0].title, "My Post");
     }
 
+    #[cfg(feature = "content")]
+    #[test]
+    fn test_node_input_builder_defaults_are_valid() {
+        use rustok_content::entities::node::ContentStatus;
+
+        let input = NodeInputBuilder::default().build();
+
+        assert_eq!(input.kind, "post");
+        assert_eq!(input.status, Some(ContentStatus::Draft));
+        assert_eq!(input.translations.len(), 1);
+        assert_eq!(input.translations[0].locale, "en");
+        assert!(input.translations[0]
+            .slug
+            .as_deref()
+            .is_some_and(|slug| slug.contains("test-post-")));
+        assert!(input.bodies.is_empty());
+    }
+
+    #[cfg(feature = "content")]
+    #[test]
+    fn test_node_input_builder_multi_locale() {
+        use rustok_content::entities::node::ContentStatus;
+        use rustok_core::BodyFormat;
+
+        let input = NodeInputBuilder::default()
+            .kind("page")
+            .status(ContentStatus::Published)
+            .translation("en", "About", "about")
+            .translation("ru", "О нас", "o-nas")
+            .body("en", "# About", BodyFormat::Markdown)
+            .body("ru", "<h1>О нас</h1>", BodyFormat::Html)
+            .metadata(serde_json::json!({"featured": true}))
+            .build();
+
+        assert_eq!(input.kind, "page");
+        assert_eq!(input.status, Some(ContentStatus::Published));
+        let translations: Vec<_> = input
+            .translations
+            .iter()
+            .map(|t| (t.locale.as_str(), t.title.as_deref(), t.slug.as_deref()))
+            .collect();
+        assert_eq!(
+            translations,
+            vec![
+                ("en", Some("About"), Some("about")),
+                ("ru", Some("О нас"), Some("o-nas")),
+            ]
+        );
+        assert_eq!(input.bodies.len(), 2);
+        assert_eq!(input.bodies[1].locale, "ru");
+        assert_eq!(input.bodies[1].format, Some(BodyFormat::Html));
+        assert_eq!(input.metadata["featured"], true);
+    }
+
     #[test]
     fn test_product_fixture() {
         let product = ProductFixture::new()
@@ -710,4 +981,15 @@ mod tests {
         assert_eq!(product.name, "My Product");
         assert_eq!(product.price, 49.99);
     }
+
+    #[test]
+    fn test_run_namespace_prefixes_generated_values() {
+        assert_eq!(normalize_namespace(" Dev/Alice_01 "), "dev-alice-01");
+        assert_eq!(normalize_namespace("--"), "");
+        assert_eq!(
+            apply_namespace("dev-alice", "post-1a2b".to_string()),
+            "dev-alice-post-1a2b"
+        );
+        assert_eq!(apply_namespace("", "post-1a2b".to_string()), "post-1a2b");
+    }
 }
@@ -5,6 +5,8 @@
 use rustok_core::{SecurityContext, UserRole};
 use uuid::Uuid;
 
+use crate::fixtures::namespaced;
+
 /// Creates a security context for a super admin user.
 ///
 /// # Example
@@ -196,7 +198,9 @@ where
 
 /// Generates a unique test ID with a prefix.
 ///
-/// This is useful for creating unique identifiers in tests.
+/// This is useful for creating unique identifiers in tests. Like the other
+/// `unique_*` helpers, the result starts with the run namespace when one is
+/// set (see [`crate::fixtures::set_run_namespace`]).
 ///
 /// # Example
 ///
@@ -207,7 +211,7 @@ where
 /// // id might be "product-550e8400-e29b-41d4-a716-446655440000"
 /// ```
 pub fn unique_test_id(prefix: &str) -> String {
-    format!("{}-{}", prefix, Uuid::new_v4())
+    namespaced(format!("{}-{}", prefix, Uuid::new_v4()))
 }
 
 /// Generates a unique email address for testing.
@@ -221,10 +225,10 @@ pub fn unique_test_id(prefix: &str) -> String {
 /// // email might be "test-550e8400@example.com"
 /// ```
 pub fn unique_email() -> String {
-    format!(
+    namespaced(format!(
         "test-{}@example.com",
         Uuid::new_v4().to_string().split('-').next().unwrap()
-    )
+    ))
 }
 
 /// Generates a unique slug for testing.
@@ -238,11 +242,11 @@ pub fn unique_email() -> String {
 /// // slug might be "post-550e8400"
 /// ```
 pub fn unique_slug(prefix: &str) -> String {
-    format!(
+    namespaced(format!(
         "{}-{}",
         prefix,
         Uuid::new_v4().to_string().split('-').next().unwrap()
-    )
+    ))
 }
 
 /// Creates a test JSON payload.
@@ -344,7 +348,7 @@ mod tests {
         let id1 = unique_test_id("test");
         let id2 = unique_test_id("test");
         assert_ne!(id1, id2);
-        assert!(id1.starts_with("test-"));
+        assert!(id1.starts_with(&namespaced("test-")));
     }
 
     #[test]
@@ -358,7 +362,7 @@ mod tests {
     #[test]
     fn test_unique_slug() {
         let slug = unique_slug("post");
-        assert!(slug.starts_with("post-"));
+        assert!(slug.starts_with(&namespaced("post-")));
     }
 
     #[tokio::test]
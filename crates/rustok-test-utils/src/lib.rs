@@ -3,8 +3,11 @@
 //! This crate provides testing utilities for RusToK modules:
 //! - Database setup and teardown utilities
 //! - Mock event bus for testing event publishing
+//! - In-memory event transport for asserting forwarded envelopes
+//! - Recording email sender for asserting templated sends
 //! - Test fixtures for common data types
 //! - Helper functions for creating test contexts
+//! - A cheap password hasher for auth tests
 //!
 //! # Example
 //!
@@ -21,13 +24,20 @@
 //! }
 //! ```
 
+pub mod auth;
 pub mod db;
+pub mod email;
 pub mod events;
 pub mod fixtures;
 pub mod helpers;
 
-pub use db::setup_test_db;
-pub use events::{mock_event_bus, mock_transactional_event_bus, MockEventBus, MockEventTransport};
+pub use auth::NoopPasswordHasher;
+pub use db::{setup_test_db, SeedProgress, SetupProgress, TestDb};
+pub use email::{MockEmailSender, SentEmail};
+pub use events::{
+    mock_event_bus, mock_transactional_event_bus, InMemoryTransport, MockEventBus,
+    MockEventTransport,
+};
 pub use helpers::*;
 
 #[cfg(test)]
@@ -1,23 +1,29 @@
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use cron::Schedule;
+use rustok_core::{PeriodicJob, PeriodicJobHandle};
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
 use serde_json::json;
-use tokio::task::JoinHandle;
+use tokio::sync::watch;
 use tracing::{error, info, warn};
 
 use crate::entities::{workflow, WorkflowEntity, WorkflowStatus};
 use crate::services::{WorkflowEngine, WorkflowService};
 
+const CRON_TICK_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Polls active workflows with cron triggers and fires them on schedule.
 ///
 /// Each workflow with `{"type": "cron", "expression": "0 * * * * *"}` trigger
-/// is checked every second against its cron expression.
+/// is checked about every second for fire times since the previous check.
 pub struct WorkflowCronScheduler {
     db: DatabaseConnection,
     engine: Arc<WorkflowEngine>,
     service: Arc<WorkflowService>,
+    last_tick: Mutex<Option<DateTime<Utc>>>,
 }
 
 impl WorkflowCronScheduler {
@@ -28,30 +34,33 @@ impl WorkflowCronScheduler {
             db,
             engine,
             service,
+            last_tick: Mutex::new(None),
         }
     }
 
-    /// Start the cron scheduler as a background task.
-    /// Returns a handle that can be aborted to stop the scheduler.
-    pub fn start(self) -> JoinHandle<()> {
-        tokio::spawn(async move {
-            info!("WorkflowCronScheduler started");
-            self.run().await;
+    /// Start the cron scheduler as a `workflow_cron` periodic job that ends
+    /// when `stop_signal` becomes `true`.
+    pub fn start(self, stop_signal: watch::Receiver<bool>) -> PeriodicJobHandle {
+        let scheduler = Arc::new(self);
+        info!("WorkflowCronScheduler started");
+        PeriodicJob::new("workflow_cron", CRON_TICK_INTERVAL, move || {
+            let scheduler = scheduler.clone();
+            async move { scheduler.tick().await }
         })
-    }
-
-    async fn run(&self) {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
-        loop {
-            interval.tick().await;
-            if let Err(e) = self.tick().await {
-                error!(error = %e, "CronScheduler tick error");
-            }
-        }
+        .with_stop_signal(stop_signal)
+        .spawn()
     }
 
     async fn tick(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let now = chrono::Utc::now();
+        let now = Utc::now();
+        // Runs are spaced from the end of the previous one, so look back to
+        // the last check instead of a fixed second to avoid skipping fires.
+        let prev = self
+            .last_tick
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .replace(now)
+            .unwrap_or(now - chrono::Duration::seconds(1));
 
         let workflows = WorkflowEntity::find()
             .filter(workflow::Column::Status.eq(WorkflowStatus::Active.to_string()))
@@ -80,8 +89,7 @@ impl WorkflowCronScheduler {
                 }
             };
 
-            // Check if cron fires at this second
-            let prev = now - chrono::Duration::seconds(1);
+            // Check if cron fired since the previous tick
             let next_after_prev = schedule.after(&prev).next();
 
             let should_fire = next_after_prev.map(|t| t <= now).unwrap_or(false);
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::Utc;
+use rustok_core::BackoffPolicy;
 use sea_orm::sea_query::Expr;
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
 use serde_json::Value;
@@ -187,8 +189,9 @@ impl WorkflowEngine {
                             continue 'steps;
                         }
                         OnError::Retry => {
-                            // Retry with exponential backoff
-                            // Configurable via step config: { "max_retries": 3, "retry_base_ms": 1000 }
+                            // Retry with jittered exponential backoff
+                            // Configurable via step config:
+                            // { "max_retries": 3, "retry_base_ms": 1000, "retry_max_ms": 60000 }
                             let max_retries = step
                                 .config
                                 .get("max_retries")
@@ -199,20 +202,30 @@ impl WorkflowEngine {
                                 .get("retry_base_ms")
                                 .and_then(Value::as_u64)
                                 .unwrap_or(1000);
+                            let retry_max_ms = step
+                                .config
+                                .get("retry_max_ms")
+                                .and_then(Value::as_u64)
+                                .unwrap_or(60_000);
+                            let backoff_policy = BackoffPolicy::ExponentialJittered {
+                                base: Duration::from_millis(retry_base_ms),
+                                max: Duration::from_millis(retry_max_ms),
+                                multiplier: 2.0,
+                                jitter: 0.1,
+                            };
 
                             let mut last_err = err_msg.clone();
                             let mut succeeded = false;
 
                             for attempt in 1..=max_retries {
-                                let backoff = retry_base_ms * (2u64.pow(attempt - 1));
+                                let backoff = backoff_policy.delay_for_attempt(attempt);
                                 warn!(
                                     step_id = %step.id,
                                     attempt = attempt,
-                                    backoff_ms = backoff,
+                                    backoff_ms = backoff.as_millis() as u64,
                                     "Retrying step after failure"
                                 );
-                                tokio::time::sleep(tokio::time::Duration::from_millis(backoff))
-                                    .await;
+                                tokio::time::sleep(backoff).await;
 
                                 match executor.execute(&step.config, context.clone()).await {
                                     Ok(out) => {